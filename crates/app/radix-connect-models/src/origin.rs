@@ -5,6 +5,7 @@ use crate::prelude::*;
     Clone,
     PartialEq,
     Eq,
+    Hash,
     Debug,
     derive_more::FromStr,
     derive_more::Display,
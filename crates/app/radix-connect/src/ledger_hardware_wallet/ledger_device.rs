@@ -0,0 +1,146 @@
+use crate::prelude::*;
+
+/// The Ledger device a request is addressed to, the Connector Extension
+/// rejects the request if the connected device has another `id`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerDevice {
+    /// The label of the device as saved in Profile, displayed by the
+    /// Connector Extension when asking the user to connect it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    pub model: LedgerHardwareWalletModel,
+
+    /// The body of the `FactorSourceIDFromHash` of the Ledger.
+    pub id: Exactly32Bytes,
+}
+
+impl LedgerDevice {
+    pub fn new(
+        name: impl Into<Option<String>>,
+        model: LedgerHardwareWalletModel,
+        id: Exactly32Bytes,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            model,
+            id,
+        }
+    }
+}
+
+impl From<LedgerHardwareWalletFactorSource> for LedgerDevice {
+    fn from(value: LedgerHardwareWalletFactorSource) -> Self {
+        Self::new(value.hint.label, value.hint.model, value.id.body)
+    }
+}
+
+impl HasSampleValues for LedgerDevice {
+    fn sample() -> Self {
+        Self::from(LedgerHardwareWalletFactorSource::sample())
+    }
+
+    fn sample_other() -> Self {
+        Self::from(LedgerHardwareWalletFactorSource::sample_other())
+    }
+}
+
+/// Information about the Ledger device connected to the Connector
+/// Extension, as returned for a `getDeviceInfo` request.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerDeviceInfo {
+    /// The body of the `FactorSourceIDFromHash` of the Ledger.
+    pub id: Exactly32Bytes,
+
+    pub model: LedgerHardwareWalletModel,
+}
+
+impl LedgerDeviceInfo {
+    pub fn new(id: Exactly32Bytes, model: LedgerHardwareWalletModel) -> Self {
+        Self { id, model }
+    }
+
+    /// The id of the `LedgerHardwareWalletFactorSource` of the device.
+    pub fn factor_source_id(&self) -> FactorSourceIDFromHash {
+        FactorSourceIDFromHash::new(
+            FactorSourceKind::LedgerHQHardwareWallet,
+            self.id,
+        )
+    }
+}
+
+impl HasSampleValues for LedgerDeviceInfo {
+    fn sample() -> Self {
+        let device = LedgerDevice::sample();
+        Self::new(device.id, device.model)
+    }
+
+    fn sample_other() -> Self {
+        let device = LedgerDevice::sample_other();
+        Self::new(device.id, device.model)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = LedgerDevice;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        assert_eq_after_json_roundtrip(
+            &SUT::new(
+                "Orange, scratched".to_owned(),
+                LedgerHardwareWalletModel::NanoSPlus,
+                Exactly32Bytes::sample(),
+            ),
+            r#"
+            {
+                "name": "Orange, scratched",
+                "model": "nanoS+",
+                "id": "deaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddead"
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_without_name() {
+        assert_eq_after_json_roundtrip(
+            &SUT::new(
+                None,
+                LedgerHardwareWalletModel::NanoX,
+                Exactly32Bytes::sample(),
+            ),
+            r#"
+            {
+                "model": "nanoX",
+                "id": "deaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddead"
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn device_info_factor_source_id() {
+        assert_eq!(
+            LedgerDeviceInfo::sample().factor_source_id(),
+            LedgerHardwareWalletFactorSource::sample().id
+        );
+    }
+}
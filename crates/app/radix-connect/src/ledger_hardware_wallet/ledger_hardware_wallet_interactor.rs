@@ -0,0 +1,597 @@
+use crate::prelude::*;
+
+/// Resolves the `LedgerDevice` of a Ledger factor source, typically by
+/// reading the `LedgerHardwareWalletFactorSource` from Profile.
+pub type LedgerDeviceResolver =
+    Arc<dyn Fn(FactorSourceIDFromHash) -> Result<LedgerDevice> + Send + Sync>;
+
+/// Signs and derives keys with Ledger devices by sending requests to the
+/// Connector Extension the device is connected to, over `transport`.
+///
+/// Implements both `SignInteractor` and `KeyDerivationInteractor`, so the same
+/// implementation is used by all hosts.
+pub struct LedgerHardwareWalletInteractor {
+    transport: Arc<dyn LedgerHardwareWalletTransport>,
+    ledger_device_resolver: LedgerDeviceResolver,
+}
+
+impl LedgerHardwareWalletInteractor {
+    pub fn new(
+        transport: Arc<dyn LedgerHardwareWalletTransport>,
+        ledger_device_resolver: LedgerDeviceResolver,
+    ) -> Self {
+        Self {
+            transport,
+            ledger_device_resolver,
+        }
+    }
+
+    /// Sends `kind` over the transport and returns the successful result
+    /// together with the id of the interaction.
+    async fn send(
+        &self,
+        kind: LedgerHardwareWalletRequestKind,
+    ) -> Result<(WalletInteractionId, LedgerHardwareWalletResponseSuccess)>
+    {
+        let request = LedgerHardwareWalletRequest::with_kind(kind);
+        let encoded_response = self
+            .transport
+            .send_ledger_request(request.serialize_to_bytes()?.into())
+            .await?;
+        let success = encoded_response
+            .deserialize::<LedgerHardwareWalletResponse>()?
+            .result_for(&request)?;
+        Ok((request.interaction_id, success))
+    }
+}
+
+fn unexpected_response(interaction_id: &WalletInteractionId) -> CommonError {
+    CommonError::LedgerHardwareWalletUnexpectedResponse {
+        interaction_id: interaction_id.0.clone(),
+    }
+}
+
+fn invalid_signature(interaction_id: &WalletInteractionId) -> CommonError {
+    CommonError::LedgerHardwareWalletInvalidSignature {
+        interaction_id: interaction_id.0.clone(),
+    }
+}
+
+impl LedgerHardwareWalletInteractor {
+    /// Reads the id and model of the Ledger device currently connected to
+    /// the Connector Extension.
+    pub async fn get_device_info(&self) -> Result<LedgerDeviceInfo> {
+        match self
+            .send(LedgerHardwareWalletRequestKind::GetDeviceInfo)
+            .await?
+        {
+            (_, LedgerHardwareWalletResponseSuccess::GetDeviceInfo(info)) => {
+                Ok(info)
+            }
+            (interaction_id, _) => Err(unexpected_response(&interaction_id)),
+        }
+    }
+
+    /// Derives the key at `derivation_path` on the Ledger device of
+    /// `factor_source_id` and displays the corresponding address on the
+    /// device, so the user can verify it.
+    pub async fn derive_and_display_address(
+        &self,
+        factor_source_id: FactorSourceIDFromHash,
+        derivation_path: DerivationPath,
+    ) -> Result<LedgerDerivedAddress> {
+        let ledger_device = (self.ledger_device_resolver)(factor_source_id)?;
+        let key_parameters = LedgerKeyParameters::from(&derivation_path);
+        match self
+            .send(LedgerHardwareWalletRequestKind::DeriveAndDisplayAddress {
+                key_parameters: key_parameters.clone(),
+                ledger_device,
+            })
+            .await?
+        {
+            (
+                _,
+                LedgerHardwareWalletResponseSuccess::DeriveAndDisplayAddress(
+                    derived_address,
+                ),
+            ) if derived_address
+                .derived_key
+                .is_derived_for(&key_parameters) =>
+            {
+                Ok(derived_address)
+            }
+            (interaction_id, _) => Err(unexpected_response(&interaction_id)),
+        }
+    }
+
+    async fn derive_public_keys(
+        &self,
+        factor_source_id: FactorSourceIDFromHash,
+        derivation_paths: IndexSet<DerivationPath>,
+    ) -> Result<IndexSet<HierarchicalDeterministicFactorInstance>> {
+        let ledger_device = (self.ledger_device_resolver)(factor_source_id)?;
+        let (interaction_id, success) = self
+            .send(LedgerHardwareWalletRequestKind::DerivePublicKeys {
+                keys_parameters: derivation_paths
+                    .iter()
+                    .map(LedgerKeyParameters::from)
+                    .collect(),
+                ledger_device,
+            })
+            .await?;
+        let LedgerHardwareWalletResponseSuccess::DerivePublicKeys(keys) =
+            success
+        else {
+            return Err(unexpected_response(&interaction_id));
+        };
+
+        derivation_paths
+            .into_iter()
+            .map(|derivation_path| {
+                let key_parameters =
+                    LedgerKeyParameters::from(&derivation_path);
+                let public_key = keys
+                    .iter()
+                    .find(|key| key.is_derived_for(&key_parameters))
+                    .ok_or_else(|| unexpected_response(&interaction_id))?
+                    .public_key()?;
+                Ok(HierarchicalDeterministicFactorInstance::with_key_and_path(
+                    factor_source_id,
+                    public_key,
+                    derivation_path,
+                ))
+            })
+            .collect()
+    }
+
+    /// Signs the payload of `input` with the keys of all of its owned factor
+    /// instances, verifying that every signature returned by the device is
+    /// made by the key of the factor instance and is valid for the hash of
+    /// the payload.
+    async fn sign_transaction<S: LedgerSignable>(
+        &self,
+        input: &TransactionSignRequestInput<S>,
+        ledger_device: LedgerDevice,
+    ) -> Result<IndexSet<HDSignature<S::ID>>> {
+        let signers = input
+            .owned_factor_instances
+            .iter()
+            .map(|owned| {
+                LedgerKeyParameters::from(&owned.value.derivation_path())
+            })
+            .collect::<Vec<_>>();
+        let (interaction_id, success) = self
+            .send(S::ledger_sign_request_kind(
+                &input.payload,
+                signers,
+                ledger_device,
+            ))
+            .await?;
+        let signatures = success
+            .into_signatures()
+            .ok_or_else(|| unexpected_response(&interaction_id))?;
+
+        let payload_id: S::ID = input.payload.clone().into();
+        let hash: Hash = payload_id.clone().into();
+        input
+            .owned_factor_instances
+            .iter()
+            .map(|owned| {
+                let key_parameters =
+                    LedgerKeyParameters::from(&owned.value.derivation_path());
+                let signature = signatures
+                    .iter()
+                    .find(|signature| {
+                        signature
+                            .derived_public_key
+                            .is_derived_for(&key_parameters)
+                    })
+                    .ok_or_else(|| unexpected_response(&interaction_id))?
+                    .signature_with_public_key()?;
+                if signature.public_key() != owned.value.public_key()
+                    || !signature.is_valid_for_hash(&hash)
+                {
+                    return Err(invalid_signature(&interaction_id));
+                }
+                HDSignature::new(
+                    HDSignatureInput::new(payload_id.clone(), owned.clone()),
+                    signature,
+                )
+            })
+            .collect()
+    }
+
+    async fn sign_with_factor_source<S: LedgerSignable>(
+        &self,
+        input: PerFactorSourceInput<S>,
+    ) -> Result<FactorOutcome<S::ID>> {
+        let ledger_device =
+            (self.ledger_device_resolver)(input.factor_source_id)?;
+
+        let mut produced_signatures = IndexSet::new();
+        for transaction in input.per_transaction.iter() {
+            match self
+                .sign_transaction(transaction, ledger_device.clone())
+                .await
+            {
+                Ok(signatures) => produced_signatures.extend(signatures),
+                Err(CommonError::LedgerHardwareWalletFailure { .. }) => {
+                    // The user rejected signing on the device, or the device
+                    // failed, signing with this factor source is neglected.
+                    return Ok(FactorOutcome::failure(input.factor_source_id));
+                }
+                Err(error) => return Err(error),
+            }
+        }
+
+        FactorOutcome::signed(produced_signatures)
+    }
+}
+
+#[async_trait::async_trait]
+impl<S: LedgerSignable> SignInteractor<S> for LedgerHardwareWalletInteractor {
+    async fn sign(
+        &self,
+        request: SignRequest<S>,
+    ) -> Result<SignResponse<S::ID>> {
+        let mut outcomes = IndexMap::new();
+        for (factor_source_id, input) in request.per_factor_source {
+            let outcome = self.sign_with_factor_source(input).await?;
+            outcomes.insert(factor_source_id, outcome);
+        }
+        SignResponse::new_from_outcomes(outcomes)
+    }
+}
+
+#[async_trait::async_trait]
+impl KeyDerivationInteractor for LedgerHardwareWalletInteractor {
+    async fn derive(
+        &self,
+        request: KeyDerivationRequest,
+    ) -> Result<KeyDerivationResponse> {
+        let mut per_factor_source = IndexMap::new();
+        for (factor_source_id, derivation_paths) in request.per_factor_source {
+            let factor_instances = self
+                .derive_public_keys(factor_source_id, derivation_paths)
+                .await?;
+            per_factor_source.insert(factor_source_id, factor_instances);
+        }
+        Ok(KeyDerivationResponse::new(per_factor_source))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = LedgerHardwareWalletInteractor;
+
+    /// A Connector Extension with a connected Ledger device backed by
+    /// `MnemonicWithPassphrase::sample_ledger()`.
+    struct MockLedgerTransport {
+        derivation_paths: Vec<DerivationPath>,
+        rejects_signing: bool,
+        responds_with_other_interaction_id: bool,
+        signs_with_other_mnemonic: bool,
+        signs_other_hash: bool,
+    }
+
+    impl MockLedgerTransport {
+        fn new(
+            derivation_paths: impl IntoIterator<Item = DerivationPath>,
+        ) -> Self {
+            Self {
+                derivation_paths: derivation_paths.into_iter().collect(),
+                rejects_signing: false,
+                responds_with_other_interaction_id: false,
+                signs_with_other_mnemonic: false,
+                signs_other_hash: false,
+            }
+        }
+
+        fn path_for(
+            &self,
+            key_parameters: &LedgerKeyParameters,
+        ) -> DerivationPath {
+            self.derivation_paths
+                .iter()
+                .find(|path| {
+                    LedgerKeyParameters::from(*path) == *key_parameters
+                })
+                .cloned()
+                .unwrap()
+        }
+
+        fn sign(
+            &self,
+            signers: Vec<LedgerKeyParameters>,
+            hash: Hash,
+        ) -> Vec<LedgerSignature> {
+            let mnemonic = if self.signs_with_other_mnemonic {
+                MnemonicWithPassphrase::sample_ledger_other()
+            } else {
+                MnemonicWithPassphrase::sample_ledger()
+            };
+            let hash = if self.signs_other_hash {
+                Hash::sample_other()
+            } else {
+                hash
+            };
+            signers
+                .iter()
+                .map(|signer| {
+                    let path = self.path_for(signer);
+                    let public_key =
+                        mnemonic.derive_public_keys([path.clone()])[0].clone();
+                    LedgerSignature::from((
+                        public_key,
+                        mnemonic.sign(&hash, &path),
+                    ))
+                })
+                .collect()
+        }
+
+        fn respond(
+            &self,
+            request: LedgerHardwareWalletRequest,
+        ) -> LedgerHardwareWalletResponse {
+            let interaction_id = if self.responds_with_other_interaction_id {
+                WalletInteractionId::sample_other()
+            } else {
+                request.interaction_id
+            };
+            let success = match request.kind {
+                LedgerHardwareWalletRequestKind::GetDeviceInfo => {
+                    LedgerHardwareWalletResponseSuccess::GetDeviceInfo(
+                        LedgerDeviceInfo::sample(),
+                    )
+                }
+                LedgerHardwareWalletRequestKind::DerivePublicKeys {
+                    keys_parameters,
+                    ..
+                } => LedgerHardwareWalletResponseSuccess::DerivePublicKeys(
+                    MnemonicWithPassphrase::sample_ledger()
+                        .derive_public_keys(
+                            keys_parameters.iter().map(|k| self.path_for(k)),
+                        )
+                        .into_iter()
+                        .map(LedgerDerivedPublicKey::from)
+                        .collect(),
+                ),
+                LedgerHardwareWalletRequestKind::SignSubintentHash {
+                    ..
+                } if self.rejects_signing => {
+                    return LedgerHardwareWalletResponse::failure(
+                        interaction_id,
+                        LedgerHardwareWalletDiscriminator::SignSubintentHash,
+                        LedgerHardwareWalletError::new(
+                            1,
+                            "User rejected signing",
+                        ),
+                    );
+                }
+                LedgerHardwareWalletRequestKind::SignSubintentHash {
+                    signers,
+                    subintent_hash,
+                    ..
+                } => LedgerHardwareWalletResponseSuccess::SignSubintentHash(
+                    self.sign(signers, Hash::from(subintent_hash)),
+                ),
+                _ => panic!("Unsupported request"),
+            };
+            LedgerHardwareWalletResponse::success(interaction_id, success)
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl LedgerHardwareWalletTransport for MockLedgerTransport {
+        async fn send_ledger_request(
+            &self,
+            encoded_request: BagOfBytes,
+        ) -> Result<BagOfBytes> {
+            let request = encoded_request.deserialize()?;
+            Ok(self.respond(request).serialize_to_bytes()?.into())
+        }
+    }
+
+    fn sut(transport: MockLedgerTransport) -> SUT {
+        SUT::new(
+            Arc::new(transport),
+            Arc::new(|_| Ok(LedgerDevice::sample())),
+        )
+    }
+
+    fn sign_request(
+        subintent: &Subintent,
+        derivation_path: DerivationPath,
+    ) -> SignRequest<Subintent> {
+        let factor_source_id = FactorSourceIDFromHash::sample_ledger();
+        let public_key = MnemonicWithPassphrase::sample_ledger()
+            .derive_public_keys([derivation_path.clone()])[0]
+            .clone();
+        let owned_factor_instance = OwnedFactorInstance::new(
+            AccountAddress::sample().into(),
+            HierarchicalDeterministicFactorInstance::new(
+                factor_source_id,
+                public_key,
+            ),
+        );
+        SignRequest::new(
+            FactorSourceKind::LedgerHQHardwareWallet,
+            IndexMap::just((
+                factor_source_id,
+                PerFactorSourceInput::new(
+                    factor_source_id,
+                    IndexSet::just(TransactionSignRequestInput::new(
+                        subintent.get_payload(),
+                        factor_source_id,
+                        IndexSet::just(owned_factor_instance),
+                    )),
+                    IndexSet::new(),
+                ),
+            )),
+        )
+    }
+
+    #[actix_rt::test]
+    async fn get_device_info() {
+        let sut = sut(MockLedgerTransport::new([]));
+
+        let device_info = sut.get_device_info().await.unwrap();
+
+        assert_eq!(device_info, LedgerDeviceInfo::sample());
+    }
+
+    #[actix_rt::test]
+    async fn derive() {
+        let derivation_path = DerivationPath::sample();
+        let sut = sut(MockLedgerTransport::new([derivation_path.clone()]));
+        let factor_source_id = FactorSourceIDFromHash::sample_ledger();
+
+        let response = sut
+            .derive(KeyDerivationRequest::new(
+                DerivationPurpose::CreatingNewAccount,
+                IndexMap::just((
+                    factor_source_id,
+                    IndexSet::just(derivation_path.clone()),
+                )),
+            ))
+            .await
+            .unwrap();
+
+        let expected = HierarchicalDeterministicFactorInstance::new(
+            factor_source_id,
+            MnemonicWithPassphrase::sample_ledger()
+                .derive_public_keys([derivation_path])[0]
+                .clone(),
+        );
+        assert_eq!(
+            response.per_factor_source,
+            IndexMap::just((factor_source_id, IndexSet::just(expected)))
+        );
+    }
+
+    #[actix_rt::test]
+    async fn sign_subintent() {
+        let derivation_path = DerivationPath::sample();
+        let sut = sut(MockLedgerTransport::new([derivation_path.clone()]));
+        let subintent = Subintent::sample();
+
+        let response = sut
+            .sign(sign_request(&subintent, derivation_path))
+            .await
+            .unwrap();
+
+        let outcome = response
+            .per_factor_outcome
+            .get(&FactorSourceIDFromHash::sample_ledger())
+            .unwrap();
+        let FactorOutcome::Signed {
+            produced_signatures: signatures,
+        } = outcome
+        else {
+            panic!("Expected signed outcome")
+        };
+        assert_eq!(signatures.len(), 1);
+        assert_eq!(signatures.first().unwrap().payload_id(), &subintent.hash());
+    }
+
+    #[actix_rt::test]
+    async fn sign_rejected_on_device_neglects_factor_source() {
+        let derivation_path = DerivationPath::sample();
+        let mut transport = MockLedgerTransport::new([derivation_path.clone()]);
+        transport.rejects_signing = true;
+        let sut = sut(transport);
+
+        let response = sut
+            .sign(sign_request(&Subintent::sample(), derivation_path))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.per_factor_outcome,
+            IndexMap::just((
+                FactorSourceIDFromHash::sample_ledger(),
+                FactorOutcome::failure(FactorSourceIDFromHash::sample_ledger())
+            ))
+        );
+    }
+
+    #[actix_rt::test]
+    async fn signature_of_other_key_fails() {
+        let derivation_path = DerivationPath::sample();
+        let mut transport = MockLedgerTransport::new([derivation_path.clone()]);
+        transport.signs_with_other_mnemonic = true;
+        let sut = sut(transport);
+
+        let result = sut
+            .sign(sign_request(&Subintent::sample(), derivation_path))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(CommonError::LedgerHardwareWalletInvalidSignature { .. })
+        ));
+    }
+
+    #[actix_rt::test]
+    async fn signature_of_other_hash_fails() {
+        let derivation_path = DerivationPath::sample();
+        let mut transport = MockLedgerTransport::new([derivation_path.clone()]);
+        transport.signs_other_hash = true;
+        let sut = sut(transport);
+
+        let result = sut
+            .sign(sign_request(&Subintent::sample(), derivation_path))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(CommonError::LedgerHardwareWalletInvalidSignature { .. })
+        ));
+    }
+
+    #[actix_rt::test]
+    async fn response_to_other_interaction_fails() {
+        let mut transport = MockLedgerTransport::new([]);
+        transport.responds_with_other_interaction_id = true;
+        let sut = sut(transport);
+
+        let result = sut.get_device_info().await;
+
+        assert!(matches!(
+            result,
+            Err(CommonError::LedgerHardwareWalletUnexpectedResponse { .. })
+        ));
+    }
+
+    #[actix_rt::test]
+    async fn transport_failure_is_propagated() {
+        struct FailingTransport;
+        #[async_trait::async_trait]
+        impl LedgerHardwareWalletTransport for FailingTransport {
+            async fn send_ledger_request(
+                &self,
+                _encoded_request: BagOfBytes,
+            ) -> Result<BagOfBytes> {
+                Err(CommonError::Unknown {
+                    error_message: "offline".to_owned(),
+                })
+            }
+        }
+        let sut = SUT::new(
+            Arc::new(FailingTransport),
+            Arc::new(|_| Ok(LedgerDevice::sample())),
+        );
+
+        let result = sut.get_device_info().await;
+
+        assert_eq!(
+            result,
+            Err(CommonError::Unknown {
+                error_message: "offline".to_owned()
+            })
+        );
+    }
+}
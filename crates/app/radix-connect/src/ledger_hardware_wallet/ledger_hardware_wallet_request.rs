@@ -0,0 +1,308 @@
+use crate::prelude::*;
+
+/// A request sent by the Wallet to a Ledger device, through the Connector
+/// Extension the device is connected to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerHardwareWalletRequest {
+    /// Identifies the request, the response of the Connector Extension
+    /// contains the same id.
+    pub interaction_id: WalletInteractionId,
+
+    #[serde(flatten)]
+    pub kind: LedgerHardwareWalletRequestKind,
+}
+
+impl LedgerHardwareWalletRequest {
+    pub fn new(
+        interaction_id: WalletInteractionId,
+        kind: LedgerHardwareWalletRequestKind,
+    ) -> Self {
+        Self {
+            interaction_id,
+            kind,
+        }
+    }
+
+    /// A request of `kind` with a new random interaction id.
+    pub fn with_kind(kind: LedgerHardwareWalletRequestKind) -> Self {
+        Self::new(WalletInteractionId(Uuid::new_v4().to_string()), kind)
+    }
+}
+
+/// How the Ledger device displays a transaction the user is asked to sign.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LedgerSignTransactionMode {
+    /// Every instruction of the manifest is displayed.
+    Verbose,
+
+    /// Only a summary of the transaction is displayed.
+    Summary,
+}
+
+impl HasSampleValues for LedgerSignTransactionMode {
+    fn sample() -> Self {
+        Self::Summary
+    }
+
+    fn sample_other() -> Self {
+        Self::Verbose
+    }
+}
+
+/// The different kinds of requests a Ledger device can handle, discriminated
+/// by the `discriminator` field in JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "discriminator", rename_all = "camelCase")]
+pub enum LedgerHardwareWalletRequestKind {
+    /// Reads the id and model of the connected device, used when adding a
+    /// new Ledger factor source.
+    GetDeviceInfo,
+
+    #[serde(rename_all = "camelCase")]
+    DerivePublicKeys {
+        keys_parameters: Vec<LedgerKeyParameters>,
+        ledger_device: LedgerDevice,
+    },
+
+    #[serde(rename_all = "camelCase")]
+    SignTransaction {
+        signers: Vec<LedgerKeyParameters>,
+        ledger_device: LedgerDevice,
+        compiled_transaction_intent: BagOfBytes,
+        mode: LedgerSignTransactionMode,
+        display_hash: bool,
+    },
+
+    #[serde(rename_all = "camelCase")]
+    SignSubintentHash {
+        signers: Vec<LedgerKeyParameters>,
+        ledger_device: LedgerDevice,
+        subintent_hash: Exactly32Bytes,
+    },
+
+    #[serde(rename_all = "camelCase")]
+    SignChallenge {
+        signers: Vec<LedgerKeyParameters>,
+        ledger_device: LedgerDevice,
+        challenge: Exactly32Bytes,
+        origin: DappOrigin,
+        #[serde(rename = "dAppDefinitionAddress")]
+        dapp_definition_address: DappDefinitionAddress,
+    },
+
+    #[serde(rename_all = "camelCase")]
+    DeriveAndDisplayAddress {
+        key_parameters: LedgerKeyParameters,
+        ledger_device: LedgerDevice,
+    },
+}
+
+impl LedgerHardwareWalletRequestKind {
+    /// The discriminator of the response expected for this request.
+    pub fn discriminator(&self) -> LedgerHardwareWalletDiscriminator {
+        match self {
+            Self::GetDeviceInfo => {
+                LedgerHardwareWalletDiscriminator::GetDeviceInfo
+            }
+            Self::DerivePublicKeys { .. } => {
+                LedgerHardwareWalletDiscriminator::DerivePublicKeys
+            }
+            Self::SignTransaction { .. } => {
+                LedgerHardwareWalletDiscriminator::SignTransaction
+            }
+            Self::SignSubintentHash { .. } => {
+                LedgerHardwareWalletDiscriminator::SignSubintentHash
+            }
+            Self::SignChallenge { .. } => {
+                LedgerHardwareWalletDiscriminator::SignChallenge
+            }
+            Self::DeriveAndDisplayAddress { .. } => {
+                LedgerHardwareWalletDiscriminator::DeriveAndDisplayAddress
+            }
+        }
+    }
+}
+
+impl HasSampleValues for LedgerHardwareWalletRequest {
+    fn sample() -> Self {
+        Self::new(
+            WalletInteractionId::sample(),
+            LedgerHardwareWalletRequestKind::DerivePublicKeys {
+                keys_parameters: vec![LedgerKeyParameters::sample()],
+                ledger_device: LedgerDevice::sample(),
+            },
+        )
+    }
+
+    fn sample_other() -> Self {
+        Self::new(
+            WalletInteractionId::sample_other(),
+            LedgerHardwareWalletRequestKind::GetDeviceInfo,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = LedgerHardwareWalletRequest;
+
+    fn ledger_device() -> LedgerDevice {
+        LedgerDevice::new(
+            "My Ledger".to_owned(),
+            LedgerHardwareWalletModel::NanoS,
+            Exactly32Bytes::sample(),
+        )
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn with_kind_generates_unique_ids() {
+        let kind = LedgerHardwareWalletRequestKind::GetDeviceInfo;
+        assert_ne!(
+            SUT::with_kind(kind.clone()).interaction_id,
+            SUT::with_kind(kind).interaction_id
+        );
+    }
+
+    #[test]
+    fn json_get_device_info() {
+        assert_eq_after_json_roundtrip(
+            &SUT::sample_other(),
+            r#"
+            {
+                "interactionId": "dededede-dede-dede-dede-dededededede",
+                "discriminator": "getDeviceInfo"
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_derive_public_keys() {
+        assert_eq_after_json_roundtrip(
+            &SUT::new(
+                WalletInteractionId::sample(),
+                LedgerHardwareWalletRequestKind::DerivePublicKeys {
+                    keys_parameters: vec![LedgerKeyParameters::sample()],
+                    ledger_device: ledger_device(),
+                },
+            ),
+            r#"
+            {
+                "interactionId": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                "discriminator": "derivePublicKeys",
+                "keysParameters": [
+                    {
+                        "curve": "curve25519",
+                        "derivationPath": "m/44H/1022H/1H/525H/1460H/0H"
+                    }
+                ],
+                "ledgerDevice": {
+                    "name": "My Ledger",
+                    "model": "nanoS",
+                    "id": "deaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddead"
+                }
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_sign_transaction() {
+        assert_eq_after_json_roundtrip(
+            &SUT::new(
+                WalletInteractionId::sample(),
+                LedgerHardwareWalletRequestKind::SignTransaction {
+                    signers: vec![LedgerKeyParameters::sample()],
+                    ledger_device: ledger_device(),
+                    compiled_transaction_intent: BagOfBytes::sample_dead(),
+                    mode: LedgerSignTransactionMode::Summary,
+                    display_hash: true,
+                },
+            ),
+            r#"
+            {
+                "interactionId": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                "discriminator": "signTransaction",
+                "signers": [
+                    {
+                        "curve": "curve25519",
+                        "derivationPath": "m/44H/1022H/1H/525H/1460H/0H"
+                    }
+                ],
+                "ledgerDevice": {
+                    "name": "My Ledger",
+                    "model": "nanoS",
+                    "id": "deaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddead"
+                },
+                "compiledTransactionIntent": "deaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddead",
+                "mode": "summary",
+                "displayHash": true
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_sign_challenge() {
+        assert_eq_after_json_roundtrip(
+            &SUT::new(
+                WalletInteractionId::sample(),
+                LedgerHardwareWalletRequestKind::SignChallenge {
+                    signers: vec![LedgerKeyParameters::sample()],
+                    ledger_device: ledger_device(),
+                    challenge: Exactly32Bytes::sample(),
+                    origin: DappOrigin::new("https://dashboard.radixdlt.com"),
+                    dapp_definition_address: AccountAddress::sample(),
+                },
+            ),
+            r#"
+            {
+                "interactionId": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                "discriminator": "signChallenge",
+                "signers": [
+                    {
+                        "curve": "curve25519",
+                        "derivationPath": "m/44H/1022H/1H/525H/1460H/0H"
+                    }
+                ],
+                "ledgerDevice": {
+                    "name": "My Ledger",
+                    "model": "nanoS",
+                    "id": "deaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddead"
+                },
+                "challenge": "deaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddead",
+                "origin": "https://dashboard.radixdlt.com",
+                "dAppDefinitionAddress": "account_rdx128y6j78mt0aqv6372evz28hrxp8mn06ccddkr7xppc88hyvynvjdwr"
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn discriminator() {
+        assert_eq!(
+            SUT::sample().kind.discriminator(),
+            LedgerHardwareWalletDiscriminator::DerivePublicKeys
+        );
+        assert_eq!(
+            SUT::sample_other().kind.discriminator(),
+            LedgerHardwareWalletDiscriminator::GetDeviceInfo
+        );
+    }
+}
@@ -0,0 +1,458 @@
+use crate::prelude::*;
+
+/// Identifies the kind of a Ledger request and of its response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LedgerHardwareWalletDiscriminator {
+    GetDeviceInfo,
+    DerivePublicKeys,
+    SignTransaction,
+    SignSubintentHash,
+    SignChallenge,
+    DeriveAndDisplayAddress,
+}
+
+/// An error reported by the Connector Extension or the Ledger device, e.g.
+/// because the user rejected signing on the device.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LedgerHardwareWalletError {
+    pub code: u32,
+    pub message: String,
+}
+
+impl LedgerHardwareWalletError {
+    pub fn new(code: u32, message: impl AsRef<str>) -> Self {
+        Self {
+            code,
+            message: message.as_ref().to_owned(),
+        }
+    }
+}
+
+impl HasSampleValues for LedgerHardwareWalletError {
+    fn sample() -> Self {
+        Self::new(2, "User rejected")
+    }
+
+    fn sample_other() -> Self {
+        Self::new(1, "Device locked")
+    }
+}
+
+impl From<LedgerHardwareWalletError> for CommonError {
+    fn from(value: LedgerHardwareWalletError) -> Self {
+        CommonError::LedgerHardwareWalletFailure {
+            code: value.code,
+            message: value.message,
+        }
+    }
+}
+
+/// The successful result of a Ledger request, one variant per
+/// `LedgerHardwareWalletDiscriminator`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LedgerHardwareWalletResponseSuccess {
+    GetDeviceInfo(LedgerDeviceInfo),
+    DerivePublicKeys(Vec<LedgerDerivedPublicKey>),
+    SignTransaction(Vec<LedgerSignature>),
+    SignSubintentHash(Vec<LedgerSignature>),
+    SignChallenge(Vec<LedgerSignature>),
+    DeriveAndDisplayAddress(LedgerDerivedAddress),
+}
+
+impl LedgerHardwareWalletResponseSuccess {
+    pub fn discriminator(&self) -> LedgerHardwareWalletDiscriminator {
+        match self {
+            Self::GetDeviceInfo(_) => {
+                LedgerHardwareWalletDiscriminator::GetDeviceInfo
+            }
+            Self::DerivePublicKeys(_) => {
+                LedgerHardwareWalletDiscriminator::DerivePublicKeys
+            }
+            Self::SignTransaction(_) => {
+                LedgerHardwareWalletDiscriminator::SignTransaction
+            }
+            Self::SignSubintentHash(_) => {
+                LedgerHardwareWalletDiscriminator::SignSubintentHash
+            }
+            Self::SignChallenge(_) => {
+                LedgerHardwareWalletDiscriminator::SignChallenge
+            }
+            Self::DeriveAndDisplayAddress(_) => {
+                LedgerHardwareWalletDiscriminator::DeriveAndDisplayAddress
+            }
+        }
+    }
+
+    /// The signatures of a response to any of the sign requests.
+    pub fn into_signatures(self) -> Option<Vec<LedgerSignature>> {
+        match self {
+            Self::SignTransaction(signatures)
+            | Self::SignSubintentHash(signatures)
+            | Self::SignChallenge(signatures) => Some(signatures),
+            _ => None,
+        }
+    }
+}
+
+/// A response of the Connector Extension to a `LedgerHardwareWalletRequest`
+/// with the same `interaction_id`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    try_from = "LedgerHardwareWalletResponseJSON",
+    into = "LedgerHardwareWalletResponseJSON"
+)]
+pub struct LedgerHardwareWalletResponse {
+    pub interaction_id: WalletInteractionId,
+    pub discriminator: LedgerHardwareWalletDiscriminator,
+    pub outcome: std::result::Result<
+        LedgerHardwareWalletResponseSuccess,
+        LedgerHardwareWalletError,
+    >,
+}
+
+impl LedgerHardwareWalletResponse {
+    pub fn success(
+        interaction_id: WalletInteractionId,
+        success: LedgerHardwareWalletResponseSuccess,
+    ) -> Self {
+        Self {
+            interaction_id,
+            discriminator: success.discriminator(),
+            outcome: Ok(success),
+        }
+    }
+
+    pub fn failure(
+        interaction_id: WalletInteractionId,
+        discriminator: LedgerHardwareWalletDiscriminator,
+        error: LedgerHardwareWalletError,
+    ) -> Self {
+        Self {
+            interaction_id,
+            discriminator,
+            outcome: Err(error),
+        }
+    }
+
+    /// The successful result of `request`, failing if this is not a response
+    /// to `request` or if the Ledger reported an error.
+    pub fn result_for(
+        self,
+        request: &LedgerHardwareWalletRequest,
+    ) -> Result<LedgerHardwareWalletResponseSuccess> {
+        if self.interaction_id != request.interaction_id
+            || self.discriminator != request.kind.discriminator()
+        {
+            return Err(CommonError::LedgerHardwareWalletUnexpectedResponse {
+                interaction_id: request.interaction_id.to_string(),
+            });
+        }
+        self.outcome.map_err(CommonError::from)
+    }
+}
+
+/// The JSON representation of `LedgerHardwareWalletResponse`, where the
+/// type of `success` depends on `discriminator`.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LedgerHardwareWalletResponseJSON {
+    interaction_id: WalletInteractionId,
+    discriminator: LedgerHardwareWalletDiscriminator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    success: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<LedgerHardwareWalletError>,
+}
+
+impl TryFrom<LedgerHardwareWalletResponseJSON>
+    for LedgerHardwareWalletResponse
+{
+    type Error = serde_json::Error;
+
+    fn try_from(
+        value: LedgerHardwareWalletResponseJSON,
+    ) -> std::result::Result<Self, Self::Error> {
+        use LedgerHardwareWalletDiscriminator as D;
+        use LedgerHardwareWalletResponseSuccess as S;
+
+        let outcome = match (value.success, value.error) {
+            (_, Some(error)) => Err(error),
+            (Some(success), None) => Ok(match value.discriminator {
+                D::GetDeviceInfo => {
+                    S::GetDeviceInfo(serde_json::from_value(success)?)
+                }
+                D::DerivePublicKeys => {
+                    S::DerivePublicKeys(serde_json::from_value(success)?)
+                }
+                D::SignTransaction => {
+                    S::SignTransaction(serde_json::from_value(success)?)
+                }
+                D::SignSubintentHash => {
+                    S::SignSubintentHash(serde_json::from_value(success)?)
+                }
+                D::SignChallenge => {
+                    S::SignChallenge(serde_json::from_value(success)?)
+                }
+                D::DeriveAndDisplayAddress => {
+                    S::DeriveAndDisplayAddress(serde_json::from_value(success)?)
+                }
+            }),
+            (None, None) => {
+                return Err(de::Error::custom(
+                    "Expected either 'success' or 'error'",
+                ))
+            }
+        };
+
+        Ok(Self {
+            interaction_id: value.interaction_id,
+            discriminator: value.discriminator,
+            outcome,
+        })
+    }
+}
+
+impl From<LedgerHardwareWalletResponse> for LedgerHardwareWalletResponseJSON {
+    fn from(value: LedgerHardwareWalletResponse) -> Self {
+        use LedgerHardwareWalletResponseSuccess as S;
+
+        let (success, error) = match value.outcome {
+            Ok(success) => (
+                Some(
+                    match success {
+                        S::GetDeviceInfo(info) => serde_json::to_value(info),
+                        S::DerivePublicKeys(keys) => serde_json::to_value(keys),
+                        S::SignTransaction(signatures)
+                        | S::SignSubintentHash(signatures)
+                        | S::SignChallenge(signatures) => {
+                            serde_json::to_value(signatures)
+                        }
+                        S::DeriveAndDisplayAddress(address) => {
+                            serde_json::to_value(address)
+                        }
+                    }
+                    .expect("Should always be able to serialize to JSON"),
+                ),
+                None,
+            ),
+            Err(error) => (None, Some(error)),
+        };
+
+        Self {
+            interaction_id: value.interaction_id,
+            discriminator: value.discriminator,
+            success,
+            error,
+        }
+    }
+}
+
+impl HasSampleValues for LedgerHardwareWalletResponse {
+    fn sample() -> Self {
+        Self::success(
+            WalletInteractionId::sample(),
+            LedgerHardwareWalletResponseSuccess::DerivePublicKeys(vec![
+                LedgerDerivedPublicKey::sample(),
+            ]),
+        )
+    }
+
+    fn sample_other() -> Self {
+        Self::failure(
+            WalletInteractionId::sample_other(),
+            LedgerHardwareWalletDiscriminator::SignTransaction,
+            LedgerHardwareWalletError::sample(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = LedgerHardwareWalletResponse;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn json_get_device_info() {
+        assert_eq_after_json_roundtrip(
+            &SUT::success(
+                WalletInteractionId::sample(),
+                LedgerHardwareWalletResponseSuccess::GetDeviceInfo(
+                    LedgerDeviceInfo::new(
+                        Exactly32Bytes::sample(),
+                        LedgerHardwareWalletModel::NanoX,
+                    ),
+                ),
+            ),
+            r#"
+            {
+                "interactionId": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                "discriminator": "getDeviceInfo",
+                "success": {
+                    "id": "deaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddead",
+                    "model": "nanoX"
+                }
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_sign_subintent_hash() {
+        assert_eq_after_json_roundtrip(
+            &SUT::success(
+                WalletInteractionId::sample(),
+                LedgerHardwareWalletResponseSuccess::SignSubintentHash(vec![
+                    LedgerSignature::new(
+                        LedgerDerivedPublicKey::new(
+                            SLIP10Curve::Curve25519,
+                            "m/44H/1022H/1H/525H/1460H/0H",
+                            BagOfBytes::sample_aced(),
+                        ),
+                        BagOfBytes::sample_dead(),
+                    ),
+                ]),
+            ),
+            r#"
+            {
+                "interactionId": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                "discriminator": "signSubintentHash",
+                "success": [
+                    {
+                        "derivedPublicKey": {
+                            "curve": "curve25519",
+                            "derivationPath": "m/44H/1022H/1H/525H/1460H/0H",
+                            "publicKey": "acedacedacedacedacedacedacedacedacedacedacedacedacedacedacedaced"
+                        },
+                        "signature": "deaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddead"
+                    }
+                ]
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_error() {
+        assert_eq_after_json_roundtrip(
+            &SUT::sample_other(),
+            r#"
+            {
+                "interactionId": "dededede-dede-dede-dede-dededededede",
+                "discriminator": "signTransaction",
+                "error": {
+                    "code": 2,
+                    "message": "User rejected"
+                }
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_without_success_or_error_fails() {
+        assert!(serde_json::from_value::<SUT>(json!({
+            "interactionId": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+            "discriminator": "getDeviceInfo"
+        }))
+        .is_err());
+    }
+
+    #[test]
+    fn json_success_of_wrong_type_fails() {
+        assert!(serde_json::from_value::<SUT>(json!({
+            "interactionId": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+            "discriminator": "getDeviceInfo",
+            "success": []
+        }))
+        .is_err());
+    }
+
+    #[test]
+    fn result_for_request() {
+        let request = LedgerHardwareWalletRequest::sample();
+        assert_eq!(
+            SUT::sample().result_for(&request),
+            Ok(LedgerHardwareWalletResponseSuccess::DerivePublicKeys(vec![
+                LedgerDerivedPublicKey::sample()
+            ]))
+        );
+    }
+
+    #[test]
+    fn result_for_other_request_fails() {
+        let request = LedgerHardwareWalletRequest::sample_other();
+        assert_eq!(
+            SUT::sample().result_for(&request),
+            Err(CommonError::LedgerHardwareWalletUnexpectedResponse {
+                interaction_id: request.interaction_id.to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn result_for_failure() {
+        let response = SUT::sample_other();
+        let request = LedgerHardwareWalletRequest::new(
+            response.interaction_id.clone(),
+            LedgerHardwareWalletRequestKind::SignSubintentHash {
+                signers: vec![LedgerKeyParameters::sample()],
+                ledger_device: LedgerDevice::sample(),
+                subintent_hash: Exactly32Bytes::sample(),
+            },
+        );
+        assert_eq!(
+            response.result_for(&request),
+            Err(CommonError::LedgerHardwareWalletUnexpectedResponse {
+                interaction_id: request.interaction_id.to_string()
+            })
+        );
+
+        let request = LedgerHardwareWalletRequest::new(
+            request.interaction_id,
+            LedgerHardwareWalletRequestKind::SignTransaction {
+                signers: vec![LedgerKeyParameters::sample()],
+                ledger_device: LedgerDevice::sample(),
+                compiled_transaction_intent: BagOfBytes::sample_dead(),
+                mode: LedgerSignTransactionMode::Summary,
+                display_hash: true,
+            },
+        );
+        assert_eq!(
+            SUT::sample_other().result_for(&request),
+            Err(CommonError::LedgerHardwareWalletFailure {
+                code: 2,
+                message: "User rejected".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn into_signatures() {
+        assert_eq!(
+            LedgerHardwareWalletResponseSuccess::SignChallenge(vec![
+                LedgerSignature::sample()
+            ])
+            .into_signatures(),
+            Some(vec![LedgerSignature::sample()])
+        );
+        assert_eq!(
+            LedgerHardwareWalletResponseSuccess::DerivePublicKeys(Vec::new())
+                .into_signatures(),
+            None
+        );
+    }
+}
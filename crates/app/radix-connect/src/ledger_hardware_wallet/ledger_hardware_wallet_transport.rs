@@ -0,0 +1,15 @@
+use crate::prelude::*;
+
+/// A transport of Ledger requests to the Connector Extension the Ledger
+/// device is connected to, typically a WebRTC data channel of a linked
+/// `P2PLink`.
+#[async_trait::async_trait]
+pub trait LedgerHardwareWalletTransport: Send + Sync {
+    /// Sends the JSON encoded `LedgerHardwareWalletRequest` and awaits the
+    /// JSON encoded `LedgerHardwareWalletResponse` with the same interaction
+    /// id.
+    async fn send_ledger_request(
+        &self,
+        encoded_request: BagOfBytes,
+    ) -> Result<BagOfBytes>;
+}
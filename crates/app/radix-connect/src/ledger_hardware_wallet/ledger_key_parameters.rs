@@ -0,0 +1,285 @@
+use crate::prelude::*;
+
+/// The curve and derivation path of a key on a Ledger device, with the path
+/// in BIP32 notation, e.g. `"m/44H/1022H/1H/525H/1460H/0H"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerKeyParameters {
+    pub curve: SLIP10Curve,
+    pub derivation_path: String,
+}
+
+impl LedgerKeyParameters {
+    pub fn new(curve: SLIP10Curve, derivation_path: impl AsRef<str>) -> Self {
+        Self {
+            curve,
+            derivation_path: derivation_path.as_ref().to_owned(),
+        }
+    }
+}
+
+impl From<&DerivationPath> for LedgerKeyParameters {
+    fn from(value: &DerivationPath) -> Self {
+        Self::new(value.curve(), value.to_bip32_string())
+    }
+}
+
+impl HasSampleValues for LedgerKeyParameters {
+    fn sample() -> Self {
+        Self::from(&DerivationPath::sample())
+    }
+
+    fn sample_other() -> Self {
+        Self::from(&DerivationPath::sample_other())
+    }
+}
+
+/// A public key derived by a Ledger device.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerDerivedPublicKey {
+    pub curve: SLIP10Curve,
+    pub derivation_path: String,
+    pub public_key: BagOfBytes,
+}
+
+impl LedgerDerivedPublicKey {
+    pub fn new(
+        curve: SLIP10Curve,
+        derivation_path: impl AsRef<str>,
+        public_key: impl Into<BagOfBytes>,
+    ) -> Self {
+        Self {
+            curve,
+            derivation_path: derivation_path.as_ref().to_owned(),
+            public_key: public_key.into(),
+        }
+    }
+
+    /// Whether this key was derived for `key_parameters`.
+    pub fn is_derived_for(&self, key_parameters: &LedgerKeyParameters) -> bool {
+        self.curve == key_parameters.curve
+            && self.derivation_path == key_parameters.derivation_path
+    }
+
+    /// The derived key, failing if it is not a valid key on `curve`.
+    pub fn public_key(&self) -> Result<PublicKey> {
+        let bytes = self.public_key.bytes();
+        match self.curve {
+            SLIP10Curve::Curve25519 => {
+                Ed25519PublicKey::try_from(bytes).map(PublicKey::from)
+            }
+            SLIP10Curve::Secp256k1 => {
+                Secp256k1PublicKey::try_from(bytes).map(PublicKey::from)
+            }
+        }
+    }
+}
+
+impl From<HierarchicalDeterministicPublicKey> for LedgerDerivedPublicKey {
+    fn from(value: HierarchicalDeterministicPublicKey) -> Self {
+        Self::new(
+            value.public_key.curve(),
+            value.derivation_path.to_bip32_string(),
+            value.public_key.to_bytes(),
+        )
+    }
+}
+
+impl HasSampleValues for LedgerDerivedPublicKey {
+    fn sample() -> Self {
+        Self::from(HierarchicalDeterministicPublicKey::sample())
+    }
+
+    fn sample_other() -> Self {
+        Self::from(HierarchicalDeterministicPublicKey::sample_other())
+    }
+}
+
+/// A signature produced by a Ledger device, with the public key of the
+/// signer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerSignature {
+    pub derived_public_key: LedgerDerivedPublicKey,
+    pub signature: BagOfBytes,
+}
+
+impl LedgerSignature {
+    pub fn new(
+        derived_public_key: LedgerDerivedPublicKey,
+        signature: impl Into<BagOfBytes>,
+    ) -> Self {
+        Self {
+            derived_public_key,
+            signature: signature.into(),
+        }
+    }
+
+    /// The signature with the public key of the signer, failing if the
+    /// signature is not valid for the curve of the key.
+    pub fn signature_with_public_key(&self) -> Result<SignatureWithPublicKey> {
+        match self.derived_public_key.public_key()? {
+            PublicKey::Ed25519(public_key) => {
+                Ed25519Signature::try_from(self.signature.clone())
+                    .map(|signature| (public_key, signature).into())
+            }
+            PublicKey::Secp256k1(public_key) => {
+                Secp256k1Signature::try_from(self.signature.clone())
+                    .map(|signature| (public_key, signature).into())
+            }
+        }
+    }
+}
+
+impl From<(HierarchicalDeterministicPublicKey, SignatureWithPublicKey)>
+    for LedgerSignature
+{
+    fn from(
+        (public_key, signature): (
+            HierarchicalDeterministicPublicKey,
+            SignatureWithPublicKey,
+        ),
+    ) -> Self {
+        Self::new(
+            LedgerDerivedPublicKey::from(public_key),
+            signature.signature().to_bytes(),
+        )
+    }
+}
+
+impl HasSampleValues for LedgerSignature {
+    fn sample() -> Self {
+        Self::new(
+            LedgerDerivedPublicKey::sample(),
+            Ed25519Signature::sample().to_bytes(),
+        )
+    }
+
+    fn sample_other() -> Self {
+        Self::new(
+            LedgerDerivedPublicKey::sample_other(),
+            Ed25519Signature::sample_other().to_bytes(),
+        )
+    }
+}
+
+/// An address derived and displayed by a Ledger device, so the user can
+/// verify it on the device.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerDerivedAddress {
+    pub derived_key: LedgerDerivedPublicKey,
+    pub address: String,
+}
+
+impl LedgerDerivedAddress {
+    pub fn new(
+        derived_key: LedgerDerivedPublicKey,
+        address: impl AsRef<str>,
+    ) -> Self {
+        Self {
+            derived_key,
+            address: address.as_ref().to_owned(),
+        }
+    }
+}
+
+impl HasSampleValues for LedgerDerivedAddress {
+    fn sample() -> Self {
+        Self::new(
+            LedgerDerivedPublicKey::sample(),
+            AccountAddress::sample().to_string(),
+        )
+    }
+
+    fn sample_other() -> Self {
+        Self::new(
+            LedgerDerivedPublicKey::sample_other(),
+            AccountAddress::sample_other().to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equality() {
+        assert_eq!(
+            LedgerKeyParameters::sample(),
+            LedgerKeyParameters::sample()
+        );
+        assert_eq!(LedgerSignature::sample(), LedgerSignature::sample());
+        assert_eq!(
+            LedgerDerivedAddress::sample_other(),
+            LedgerDerivedAddress::sample_other()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(
+            LedgerKeyParameters::sample(),
+            LedgerKeyParameters::sample_other()
+        );
+        assert_ne!(LedgerSignature::sample(), LedgerSignature::sample_other());
+        assert_ne!(
+            LedgerDerivedAddress::sample(),
+            LedgerDerivedAddress::sample_other()
+        );
+    }
+
+    #[test]
+    fn key_parameters_json() {
+        assert_eq_after_json_roundtrip(
+            &LedgerKeyParameters::sample(),
+            r#"
+            {
+                "curve": "curve25519",
+                "derivationPath": "m/44H/1022H/1H/525H/1460H/0H"
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn derived_public_key_is_derived_for() {
+        let path = DerivationPath::sample();
+        let sut = LedgerDerivedPublicKey::new(
+            path.curve(),
+            path.to_bip32_string(),
+            Ed25519PublicKey::sample().to_bytes(),
+        );
+        assert!(sut.is_derived_for(&LedgerKeyParameters::from(&path)));
+        assert!(!sut.is_derived_for(&LedgerKeyParameters::sample_other()));
+    }
+
+    #[test]
+    fn derived_public_key_curve_mismatch() {
+        let sut = LedgerDerivedPublicKey::new(
+            SLIP10Curve::Secp256k1,
+            "m/44H/1022H/0H/0/0H",
+            Ed25519PublicKey::sample().to_bytes(),
+        );
+        assert!(sut.public_key().is_err());
+    }
+
+    #[test]
+    fn signature_roundtrip() {
+        let mwp = MnemonicWithPassphrase::sample_ledger();
+        let path = DerivationPath::sample();
+        let hash = hash_of([0xde, 0xad]);
+        let public_key = mwp
+            .derive_public_keys([path.clone()])
+            .into_iter()
+            .next()
+            .unwrap();
+        let signature = mwp.sign(&hash, &path);
+
+        let sut = LedgerSignature::from((public_key, signature.clone()));
+
+        assert_eq!(sut.signature_with_public_key().unwrap(), signature);
+    }
+}
@@ -0,0 +1,124 @@
+use crate::prelude::*;
+
+/// A `Signable` which can be signed by a Ledger device.
+pub trait LedgerSignable: Signable {
+    /// The request asking `ledger_device` to sign `payload` with the keys of
+    /// `signers`.
+    fn ledger_sign_request_kind(
+        payload: &Self::Payload,
+        signers: Vec<LedgerKeyParameters>,
+        ledger_device: LedgerDevice,
+    ) -> LedgerHardwareWalletRequestKind;
+}
+
+impl LedgerSignable for TransactionIntent {
+    fn ledger_sign_request_kind(
+        payload: &Self::Payload,
+        signers: Vec<LedgerKeyParameters>,
+        ledger_device: LedgerDevice,
+    ) -> LedgerHardwareWalletRequestKind {
+        LedgerHardwareWalletRequestKind::SignTransaction {
+            signers,
+            ledger_device,
+            compiled_transaction_intent: payload.bytes(),
+            mode: LedgerSignTransactionMode::Summary,
+            display_hash: true,
+        }
+    }
+}
+
+impl LedgerSignable for Subintent {
+    fn ledger_sign_request_kind(
+        payload: &Self::Payload,
+        signers: Vec<LedgerKeyParameters>,
+        ledger_device: LedgerDevice,
+    ) -> LedgerHardwareWalletRequestKind {
+        let subintent_hash: SubintentHash = payload.clone().into();
+        LedgerHardwareWalletRequestKind::SignSubintentHash {
+            signers,
+            ledger_device,
+            subintent_hash: Exactly32Bytes::from(Hash::from(subintent_hash)),
+        }
+    }
+}
+
+impl LedgerSignable for AuthIntent {
+    fn ledger_sign_request_kind(
+        payload: &Self::Payload,
+        signers: Vec<LedgerKeyParameters>,
+        ledger_device: LedgerDevice,
+    ) -> LedgerHardwareWalletRequestKind {
+        LedgerHardwareWalletRequestKind::SignChallenge {
+            signers,
+            ledger_device,
+            challenge: payload.challenge_nonce,
+            origin: payload.origin.clone(),
+            dapp_definition_address: payload.dapp_definition_address,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transaction_intent() {
+        let intent = TransactionIntent::sample();
+        let kind = TransactionIntent::ledger_sign_request_kind(
+            &intent.get_payload(),
+            vec![LedgerKeyParameters::sample()],
+            LedgerDevice::sample(),
+        );
+        assert_eq!(
+            kind,
+            LedgerHardwareWalletRequestKind::SignTransaction {
+                signers: vec![LedgerKeyParameters::sample()],
+                ledger_device: LedgerDevice::sample(),
+                compiled_transaction_intent: intent.compile().bytes(),
+                mode: LedgerSignTransactionMode::Summary,
+                display_hash: true,
+            }
+        );
+    }
+
+    #[test]
+    fn subintent() {
+        let subintent = Subintent::sample();
+        let kind = Subintent::ledger_sign_request_kind(
+            &subintent.get_payload(),
+            vec![LedgerKeyParameters::sample()],
+            LedgerDevice::sample(),
+        );
+        assert_eq!(
+            kind,
+            LedgerHardwareWalletRequestKind::SignSubintentHash {
+                signers: vec![LedgerKeyParameters::sample()],
+                ledger_device: LedgerDevice::sample(),
+                subintent_hash: Exactly32Bytes::from(Hash::from(
+                    subintent.hash()
+                )),
+            }
+        );
+    }
+
+    #[test]
+    fn auth_intent() {
+        let auth_intent = AuthIntent::sample();
+        let kind = AuthIntent::ledger_sign_request_kind(
+            &auth_intent,
+            vec![LedgerKeyParameters::sample()],
+            LedgerDevice::sample(),
+        );
+        assert_eq!(
+            kind,
+            LedgerHardwareWalletRequestKind::SignChallenge {
+                signers: vec![LedgerKeyParameters::sample()],
+                ledger_device: LedgerDevice::sample(),
+                challenge: auth_intent.challenge_nonce,
+                origin: auth_intent.origin,
+                dapp_definition_address: auth_intent.dapp_definition_address,
+            }
+        );
+    }
+}
@@ -0,0 +1,15 @@
+mod ledger_device;
+mod ledger_hardware_wallet_interactor;
+mod ledger_hardware_wallet_request;
+mod ledger_hardware_wallet_response;
+mod ledger_hardware_wallet_transport;
+mod ledger_key_parameters;
+mod ledger_signable;
+
+pub use ledger_device::*;
+pub use ledger_hardware_wallet_interactor::*;
+pub use ledger_hardware_wallet_request::*;
+pub use ledger_hardware_wallet_response::*;
+pub use ledger_hardware_wallet_transport::*;
+pub use ledger_key_parameters::*;
+pub use ledger_signable::*;
@@ -1,5 +1,7 @@
 mod interaction_id;
+mod ledger_hardware_wallet;
 mod p2p_links;
+mod p2p_messages;
 mod wallet_account;
 mod wallet_interaction;
 mod wallet_persona;
@@ -11,8 +13,10 @@ mod mobile;
 pub mod prelude {
     // RE-EXPORT MODULES
     pub use crate::interaction_id::*;
+    pub use crate::ledger_hardware_wallet::*;
     pub use crate::mobile::*;
     pub use crate::p2p_links::*;
+    pub use crate::p2p_messages::*;
     pub use crate::wallet_account::*;
     pub use crate::wallet_interaction::*;
     pub use crate::wallet_persona::*;
@@ -27,10 +31,12 @@ pub mod prelude {
     pub(crate) use entity_foundation::prelude::*;
     pub(crate) use hash::prelude::*;
     pub(crate) use identified_vec_of::prelude::*;
+    pub(crate) use key_derivation_traits::prelude::*;
     pub(crate) use prelude::prelude::*;
     pub(crate) use profile_persona_data::prelude::*;
     pub(crate) use profile_security_structures::prelude::*;
     pub(crate) use radix_connect_models::prelude::*;
+    pub(crate) use signing_traits::prelude::*;
 
     pub(crate) use transaction_models::prelude::*;
 
@@ -40,6 +46,8 @@ pub mod prelude {
     };
     pub(crate) use serde_with::{serde_as, DisplayFromStr};
 
+    pub(crate) use indexmap::{IndexMap, IndexSet};
+
     // STD DEPENDENCIES
     pub(crate) use std::collections::HashMap;
 
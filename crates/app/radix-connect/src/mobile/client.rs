@@ -22,6 +22,9 @@ pub struct RadixConnectMobile {
     /// when users send multiple requests to the same session without validating the first one.
     new_sessions:
         RwLock<HashMap<SessionID, HashMap<WalletInteractionId, Session>>>,
+
+    /// For how long a stored session can remain unused before it expires and is removed.
+    session_idle_timeout_seconds: RwLock<i64>,
 }
 
 impl RadixConnectMobile {
@@ -33,6 +36,9 @@ impl RadixConnectMobile {
             wallet_interactions_transport,
             session_storage,
             new_sessions: RwLock::new(HashMap::new()),
+            session_idle_timeout_seconds: RwLock::new(
+                Self::DEFAULT_SESSION_IDLE_TIMEOUT_SECONDS,
+            ),
         }
     }
 }
@@ -128,11 +134,15 @@ impl RadixConnectMobile {
             self.load_session(wallet_response.session_id).await.ok();
 
         let is_in_flight_session = in_flight_session.is_some();
-        let session = existing_session.or(in_flight_session).ok_or(
-            CommonError::RadixConnectMobileSessionNotFound {
+        let session = existing_session
+            .map(|mut session| {
+                session.last_used_at = Timestamp::now_utc();
+                session
+            })
+            .or(in_flight_session)
+            .ok_or(CommonError::RadixConnectMobileSessionNotFound {
                 session_id: session_id.to_string(),
-            },
-        )?;
+            })?;
 
         let is_success_response = wallet_response.response.is_success();
 
@@ -160,8 +170,9 @@ impl RadixConnectMobile {
             }
         });
 
-        if is_in_flight_session && is_success_response {
-            // We do consider a session to be validated once user did send a successful interaction back.
+        if !is_in_flight_session || is_success_response {
+            // We do consider a session to be validated once user did send a successful interaction back,
+            // established sessions are saved to record when they were last used.
             self.save_session(session).await?;
         }
 
@@ -169,6 +180,72 @@ impl RadixConnectMobile {
     }
 }
 
+impl RadixConnectMobile {
+    /// Sessions unused for 30 days expire by default.
+    pub const DEFAULT_SESSION_IDLE_TIMEOUT_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+    /// Changes for how long a session can remain unused before it expires.
+    pub fn set_session_idle_timeout_seconds(&self, seconds: i64) {
+        *self
+            .session_idle_timeout_seconds
+            .write()
+            .expect("Stopped using RadixConnectMobile, lock poisoned") =
+            seconds;
+    }
+
+    fn session_idle_timeout_seconds(&self) -> i64 {
+        *self
+            .session_idle_timeout_seconds
+            .read()
+            .expect("Stopped using RadixConnectMobile, lock poisoned")
+    }
+
+    /// Returns all established sessions which have not expired, most recently
+    /// used first. Expired sessions are removed from the session storage.
+    pub async fn sessions(
+        &self,
+    ) -> Result<Vec<RadixConnectMobileSessionSummary>> {
+        let mut sessions = Vec::new();
+        for session_id in self.session_storage.load_session_ids().await? {
+            if let Some(session) =
+                self.load_unexpired_session(session_id).await?
+            {
+                sessions.push(RadixConnectMobileSessionSummary::from(session));
+            }
+        }
+        sessions.sort_by(|a, b| b.last_used_at.cmp(&a.last_used_at));
+        Ok(sessions)
+    }
+
+    /// Revokes the session identified by `session_id`, any subsequent request
+    /// of the dApp for that session requires the origin to be validated again.
+    pub async fn revoke_session(&self, session_id: SessionID) -> Result<()> {
+        _ = self.new_sessions.try_write().map(|mut new_sessions| {
+            new_sessions.remove(&session_id);
+        });
+        self.session_storage.delete_session(session_id).await
+    }
+
+    /// Revokes all sessions established with the dApp identified by
+    /// `dapp_definition_address`.
+    ///
+    /// Sessions stored before their dApp definition address was recorded
+    /// cannot be matched and are not revoked, they expire after being idle
+    /// instead, see `Session::migrate_legacy_timestamps`.
+    pub async fn revoke_sessions_for_dapp(
+        &self,
+        dapp_definition_address: DappDefinitionAddress,
+    ) -> Result<()> {
+        for session in self.sessions().await? {
+            if session.dapp_definition_address == Some(dapp_definition_address)
+            {
+                self.revoke_session(session.session_id).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
 impl RadixConnectMobile {
     const HKDF_KEY_DERIVATION_INFO: &'static str = "RCfM";
 
@@ -200,6 +277,8 @@ impl RadixConnectMobile {
             request.public_key,
             request.identity_public_key,
             wallet_public_key,
+            request.dapp_definition_address,
+            Timestamp::now_utc(),
         );
 
         // 5. Save the session in memory until validated
@@ -219,13 +298,41 @@ impl RadixConnectMobile {
 
 impl RadixConnectMobile {
     async fn load_session(&self, session_id: SessionID) -> Result<Session> {
-        let session_bytes =
-            self.session_storage.load_session(session_id).await?.ok_or(
-                CommonError::RadixConnectMobileSessionNotFound {
-                    session_id: session_id.to_string(),
-                },
-            )?;
-        session_bytes.deserialize()
+        self.load_unexpired_session(session_id).await?.ok_or(
+            CommonError::RadixConnectMobileSessionNotFound {
+                session_id: session_id.to_string(),
+            },
+        )
+    }
+
+    /// Loads the session identified by `session_id`, deleting it from the
+    /// session storage and returning `None` if it has expired.
+    ///
+    /// Sessions stored before their timestamps were recorded are migrated,
+    /// considering them last used now, and saved.
+    async fn load_unexpired_session(
+        &self,
+        session_id: SessionID,
+    ) -> Result<Option<Session>> {
+        let Some(session_bytes) =
+            self.session_storage.load_session(session_id).await?
+        else {
+            return Ok(None);
+        };
+        let mut session: Session = session_bytes.deserialize()?;
+        if session.migrate_legacy_timestamps(Timestamp::now_utc()) {
+            self.save_session(session.clone()).await?;
+        }
+
+        if session.is_expired(
+            self.session_idle_timeout_seconds(),
+            Timestamp::now_utc(),
+        ) {
+            self.session_storage.delete_session(session_id).await?;
+            return Ok(None);
+        }
+
+        Ok(Some(session))
     }
 
     async fn save_session(&self, session: Session) -> Result<()> {
@@ -313,6 +420,15 @@ mod tests {
                     session_id: session_id.to_string(),
                 })
         }
+
+        async fn delete_session(&self, session_id: SessionID) -> Result<()> {
+            self.sessions.lock().unwrap().remove(&session_id);
+            Ok(())
+        }
+
+        async fn load_session_ids(&self) -> Result<Vec<SessionID>> {
+            Ok(self.sessions.lock().unwrap().keys().cloned().collect())
+        }
     }
 
     #[allow(clippy::upper_case_acronyms)]
@@ -409,6 +525,13 @@ mod tests {
             )
             .unwrap(),
             wallet_public_key,
+            request_params
+                .dapp_definition_address
+                .clone()
+                .unwrap()
+                .parse::<DappDefinitionAddress>()
+                .unwrap(),
+            created_session.created_at,
         );
 
         // Assert that the proper session was created
@@ -741,4 +864,203 @@ mod tests {
             (saved_session.clone(), failure_response)
         );
     }
+
+    fn session_last_used_at(
+        session: Session,
+        last_used_at: Timestamp,
+    ) -> Session {
+        let mut session = session;
+        session.last_used_at = last_used_at;
+        session
+    }
+
+    #[actix_rt::test]
+    async fn sessions_are_listed_most_recently_used_first() {
+        let mock_session_storage = Arc::new(MockSessionStorage::new());
+        let sut = SUT::init(
+            Arc::new(MockWalletInteractionTransport::succeeding()),
+            mock_session_storage.clone(),
+        );
+        sut.set_session_idle_timeout_seconds(i64::MAX);
+        let older =
+            session_last_used_at(Session::sample(), Timestamp::sample());
+        let newer = session_last_used_at(
+            Session::sample_other(),
+            Timestamp::sample_other(),
+        );
+        for session in [older.clone(), newer.clone()] {
+            mock_session_storage
+                .sessions
+                .lock()
+                .unwrap()
+                .insert(session.id, session);
+        }
+
+        let sessions = sut.sessions().await.unwrap();
+
+        pretty_assertions::assert_eq!(
+            sessions,
+            vec![
+                RadixConnectMobileSessionSummary::from(newer),
+                RadixConnectMobileSessionSummary::from(older)
+            ]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn expired_sessions_are_deleted() {
+        let mock_session_storage = Arc::new(MockSessionStorage::new());
+        let sut = SUT::init(
+            Arc::new(MockWalletInteractionTransport::succeeding()),
+            mock_session_storage.clone(),
+        );
+        let expired =
+            session_last_used_at(Session::sample(), Timestamp::sample());
+        let active =
+            session_last_used_at(Session::sample_other(), Timestamp::now_utc());
+        for session in [expired.clone(), active.clone()] {
+            mock_session_storage
+                .sessions
+                .lock()
+                .unwrap()
+                .insert(session.id, session);
+        }
+
+        let sessions = sut.sessions().await.unwrap();
+
+        pretty_assertions::assert_eq!(
+            sessions,
+            vec![RadixConnectMobileSessionSummary::from(active.clone())]
+        );
+        pretty_assertions::assert_eq!(
+            mock_session_storage
+                .sessions
+                .lock()
+                .unwrap()
+                .keys()
+                .cloned()
+                .collect::<Vec<_>>(),
+            vec![active.id]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn legacy_sessions_are_migrated_instead_of_expired() {
+        let mock_session_storage = Arc::new(MockSessionStorage::new());
+        let sut = SUT::init(
+            Arc::new(MockWalletInteractionTransport::succeeding()),
+            mock_session_storage.clone(),
+        );
+        let mut legacy = Session::sample();
+        legacy.created_at = Session::LEGACY_TIMESTAMP;
+        legacy.last_used_at = Session::LEGACY_TIMESTAMP;
+        mock_session_storage
+            .sessions
+            .lock()
+            .unwrap()
+            .insert(legacy.id, legacy.clone());
+        let before = Timestamp::now_utc();
+
+        let sessions = sut.sessions().await.unwrap();
+
+        pretty_assertions::assert_eq!(sessions.len(), 1);
+        let migrated = mock_session_storage
+            .sessions
+            .lock()
+            .unwrap()
+            .get(&legacy.id)
+            .cloned()
+            .unwrap();
+        assert!(migrated.created_at >= before);
+        assert!(migrated.last_used_at >= before);
+    }
+
+    #[actix_rt::test]
+    async fn response_for_expired_session_fails() {
+        let mock_session_storage = Arc::new(MockSessionStorage::new());
+        let sut = SUT::init(
+            Arc::new(MockWalletInteractionTransport::succeeding()),
+            mock_session_storage.clone(),
+        );
+        let session = Session::sample();
+        mock_session_storage
+            .sessions
+            .lock()
+            .unwrap()
+            .insert(session.id, session.clone());
+
+        let result = sut
+            .send_dapp_interaction_response(
+                RadixConnectMobileWalletResponse::new(
+                    session.id,
+                    WalletToDappInteractionResponse::sample(),
+                ),
+            )
+            .await;
+
+        pretty_assertions::assert_eq!(
+            result,
+            Err(CommonError::RadixConnectMobileSessionNotFound {
+                session_id: session.id.to_string()
+            })
+        );
+        pretty_assertions::assert_eq!(
+            mock_session_storage.sessions.lock().unwrap().len(),
+            0
+        );
+    }
+
+    #[actix_rt::test]
+    async fn revoke_session() {
+        let mock_session_storage = Arc::new(MockSessionStorage::new());
+        let sut = SUT::init(
+            Arc::new(MockWalletInteractionTransport::succeeding()),
+            mock_session_storage.clone(),
+        );
+        let now = Timestamp::now_utc();
+        let session = session_last_used_at(Session::sample(), now);
+        let other = session_last_used_at(Session::sample_other(), now);
+        for session in [session.clone(), other.clone()] {
+            mock_session_storage
+                .sessions
+                .lock()
+                .unwrap()
+                .insert(session.id, session);
+        }
+
+        sut.revoke_session(session.id).await.unwrap();
+
+        pretty_assertions::assert_eq!(
+            sut.sessions().await.unwrap(),
+            vec![RadixConnectMobileSessionSummary::from(other)]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn revoke_sessions_for_dapp() {
+        let mock_session_storage = Arc::new(MockSessionStorage::new());
+        let sut = SUT::init(
+            Arc::new(MockWalletInteractionTransport::succeeding()),
+            mock_session_storage.clone(),
+        );
+        let now = Timestamp::now_utc();
+        let session = session_last_used_at(Session::sample(), now);
+        let other = session_last_used_at(Session::sample_other(), now);
+        for session in [session.clone(), other.clone()] {
+            mock_session_storage
+                .sessions
+                .lock()
+                .unwrap()
+                .insert(session.id, session);
+        }
+
+        sut.revoke_sessions_for_dapp(DappDefinitionAddress::sample_other())
+            .await
+            .unwrap();
+
+        pretty_assertions::assert_eq!(
+            sut.sessions().await.unwrap(),
+            vec![RadixConnectMobileSessionSummary::from(session)]
+        );
+    }
 }
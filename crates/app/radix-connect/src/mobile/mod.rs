@@ -4,6 +4,7 @@ mod relay_service;
 mod session;
 mod session_dapp_request;
 mod session_storage;
+mod session_summary;
 mod session_wallet_response;
 
 pub use client::*;
@@ -11,4 +12,5 @@ pub use deep_link_parsing::*;
 pub use session::*;
 pub use session_dapp_request::*;
 pub use session_storage::*;
+pub use session_summary::*;
 pub use session_wallet_response::*;
@@ -37,6 +37,23 @@ pub struct Session {
     /// The wallet's public key used to generate the encryption_key.
     /// It is kept in the session and then send along with all of the Wallet's request so that the dApp can regenerate the encryption key if needed.
     pub wallet_public_key: KeyAgreementPublicKey,
+
+    /// The address of the dApp definition the session was established with, `None` for
+    /// sessions established before it was recorded.
+    #[serde(default)]
+    pub dapp_definition_address: Option<DappDefinitionAddress>,
+
+    /// When the session was established, for sessions stored before this was recorded
+    /// the time they were migrated, see `Session::migrate_legacy_timestamps`.
+    #[serde(default = "Session::legacy_timestamp")]
+    pub created_at: Timestamp,
+
+    /// When the session was last used to respond to the dApp, used to expire idle sessions.
+    /// For sessions stored before this was recorded the time they were migrated, so that
+    /// they get a full idle period before they expire - they cannot be revoked per dApp as
+    /// their `dapp_definition_address` is unknown.
+    #[serde(default = "Session::legacy_timestamp")]
+    pub last_used_at: Timestamp,
 }
 
 impl Session {
@@ -47,6 +64,8 @@ impl Session {
         dapp_public_key: KeyAgreementPublicKey,
         dapp_identity_public_key: Ed25519PublicKey,
         wallet_public_key: KeyAgreementPublicKey,
+        dapp_definition_address: impl Into<Option<DappDefinitionAddress>>,
+        created_at: Timestamp,
     ) -> Self {
         Self {
             id: id.into(),
@@ -55,7 +74,47 @@ impl Session {
             dapp_public_key,
             dapp_identity_public_key,
             wallet_public_key,
+            dapp_definition_address: dapp_definition_address.into(),
+            created_at,
+            last_used_at: created_at,
+        }
+    }
+}
+
+impl Session {
+    /// The `created_at` and `last_used_at` of sessions stored before they were recorded,
+    /// until they are migrated, see `Session::migrate_legacy_timestamps`.
+    pub const LEGACY_TIMESTAMP: Timestamp = Timestamp::UNIX_EPOCH;
+
+    fn legacy_timestamp() -> Timestamp {
+        Self::LEGACY_TIMESTAMP
+    }
+
+    /// Sets the `created_at` and `last_used_at` of a session stored before they were
+    /// recorded to `now`, the time of migration. Returns whether the session was migrated,
+    /// in which case it should be saved.
+    pub fn migrate_legacy_timestamps(&mut self, now: Timestamp) -> bool {
+        let mut migrated = false;
+        if self.created_at == Self::LEGACY_TIMESTAMP {
+            self.created_at = now;
+            migrated = true;
+        }
+        if self.last_used_at == Self::LEGACY_TIMESTAMP {
+            self.last_used_at = now;
+            migrated = true;
         }
+        migrated
+    }
+
+    /// Whether the session has not been used for more than
+    /// `idle_timeout_seconds` at `now`.
+    pub fn is_expired(
+        &self,
+        idle_timeout_seconds: i64,
+        now: Timestamp,
+    ) -> bool {
+        now.duration_since(self.last_used_at).whole_seconds()
+            > idle_timeout_seconds
     }
 }
 
@@ -89,6 +148,8 @@ impl HasSampleValues for Session {
             KeyAgreementPublicKey::sample(),
             Ed25519PublicKey::sample(),
             KeyAgreementPublicKey::sample(),
+            DappDefinitionAddress::sample(),
+            Timestamp::sample(),
         )
     }
 
@@ -100,6 +161,8 @@ impl HasSampleValues for Session {
             KeyAgreementPublicKey::sample_other(),
             Ed25519PublicKey::sample_other(),
             KeyAgreementPublicKey::sample_other(),
+            DappDefinitionAddress::sample_other(),
+            Timestamp::sample_other(),
         )
     }
 }
@@ -156,4 +219,54 @@ mod tests {
             Err(CommonError::RadixConnectMobileDappOriginMismatch)
         );
     }
+
+    #[test]
+    fn is_expired() {
+        let sut = SUT::sample();
+        let idle_timeout_seconds = 60;
+        let now = sut.last_used_at;
+        assert!(!sut.is_expired(idle_timeout_seconds, now));
+
+        let later = Timestamp::parse("2023-09-11T16:06:56Z").unwrap();
+        assert!(!sut.is_expired(idle_timeout_seconds, later));
+
+        let much_later = Timestamp::parse("2023-09-11T16:06:57Z").unwrap();
+        assert!(sut.is_expired(idle_timeout_seconds, much_later));
+    }
+
+    #[test]
+    fn decode_session_without_dapp_definition_and_timestamps() {
+        let mut json = serde_json::to_value(SUT::sample()).unwrap();
+        let object = json.as_object_mut().unwrap();
+        object.remove("dapp_definition_address");
+        object.remove("created_at");
+        object.remove("last_used_at");
+
+        let sut = serde_json::from_value::<SUT>(json).unwrap();
+
+        assert_eq!(sut.dapp_definition_address, None);
+        assert_eq!(sut.created_at, SUT::LEGACY_TIMESTAMP);
+        assert_eq!(sut.last_used_at, SUT::LEGACY_TIMESTAMP);
+    }
+
+    #[test]
+    fn migrate_legacy_timestamps() {
+        let mut sut = SUT::sample();
+        sut.created_at = SUT::LEGACY_TIMESTAMP;
+        sut.last_used_at = SUT::LEGACY_TIMESTAMP;
+        let now = Timestamp::sample_other();
+
+        assert!(sut.migrate_legacy_timestamps(now));
+
+        assert_eq!(sut.created_at, now);
+        assert_eq!(sut.last_used_at, now);
+        assert!(!sut.is_expired(60, now));
+        // Migrating again is a no-op
+        assert!(!sut.migrate_legacy_timestamps(Timestamp::now_utc()));
+        assert_eq!(sut.last_used_at, now);
+        assert!(sut.is_expired(
+            RadixConnectMobile::DEFAULT_SESSION_IDLE_TIMEOUT_SECONDS,
+            Timestamp::now_utc()
+        ));
+    }
 }
@@ -15,4 +15,10 @@ pub trait RadixConnectMobileSessionStorage: Send + Sync {
         &self,
         session_id: SessionID,
     ) -> Result<Option<BagOfBytes>>;
+
+    /// Deletes the session identified by the session id from the storage.
+    async fn delete_session(&self, session_id: SessionID) -> Result<()>;
+
+    /// Loads the ids of all sessions saved in the storage.
+    async fn load_session_ids(&self) -> Result<Vec<SessionID>>;
 }
@@ -0,0 +1,67 @@
+use crate::prelude::*;
+
+/// A summary of an established Radix Connect Mobile session, without any of
+/// the key material, suitable to display to the user.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RadixConnectMobileSessionSummary {
+    pub session_id: SessionID,
+    pub origin: DappOrigin,
+    pub dapp_definition_address: Option<DappDefinitionAddress>,
+    pub created_at: Timestamp,
+    pub last_used_at: Timestamp,
+}
+
+impl From<Session> for RadixConnectMobileSessionSummary {
+    fn from(value: Session) -> Self {
+        let SessionOrigin::WebDapp(origin) = value.origin;
+        Self {
+            session_id: value.id,
+            origin,
+            dapp_definition_address: value.dapp_definition_address,
+            created_at: value.created_at,
+            last_used_at: value.last_used_at,
+        }
+    }
+}
+
+impl HasSampleValues for RadixConnectMobileSessionSummary {
+    fn sample() -> Self {
+        Self::from(Session::sample())
+    }
+
+    fn sample_other() -> Self {
+        Self::from(Session::sample_other())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = RadixConnectMobileSessionSummary;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn from_session() {
+        let sut = SUT::sample();
+        assert_eq!(sut.session_id, SessionID::sample());
+        assert_eq!(sut.origin, DappOrigin::sample());
+        assert_eq!(
+            sut.dapp_definition_address,
+            Some(DappDefinitionAddress::sample())
+        );
+        assert_eq!(sut.created_at, Timestamp::sample());
+        assert_eq!(sut.last_used_at, Timestamp::sample());
+    }
+}
@@ -0,0 +1,5 @@
+mod p2p_message_codec;
+mod p2p_message_package;
+
+pub use p2p_message_codec::*;
+pub use p2p_message_package::*;
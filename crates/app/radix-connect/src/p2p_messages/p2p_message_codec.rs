@@ -0,0 +1,688 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::engine::Engine as _;
+use encryption::{EncryptionKey, EncryptionScheme, VersionedEncryption};
+
+use crate::prelude::*;
+
+/// The max number of message bytes in a single `P2PMessagePackage::Chunk`,
+/// as specified by CAP-36.
+pub const P2P_MESSAGE_CHUNK_SIZE: usize = 15441;
+
+/// The max number of chunks of a single incoming message, i.e. messages are at
+/// most ~15 MB.
+pub const P2P_MESSAGE_MAX_CHUNK_COUNT: u64 = 1000;
+
+/// The max number of incoming messages being reassembled at the same time.
+pub const P2P_MAX_INCOMING_MESSAGES: usize = 16;
+
+/// The max number of chunks - summed over all incoming messages being
+/// reassembled - a peer can make us wait for.
+pub const P2P_MAX_INCOMING_CHUNK_COUNT: u64 = 2 * P2P_MESSAGE_MAX_CHUNK_COUNT;
+
+/// Incoming messages which have not received a package for this long are
+/// dropped.
+pub const P2P_INCOMING_MESSAGE_TIMEOUT_SECONDS: i64 = 60;
+
+/// Transport agnostic encoding of messages sent over a `P2PLink`, so that the
+/// host only has to move opaque bytes over WebRTC.
+///
+/// Splits outgoing messages into CAP-36 packages, reassembles incoming
+/// packages into messages verifying their hash, and encrypts/decrypts the
+/// messages sent via the Signaling Server using the password of the link.
+pub struct P2PMessageCodec {
+    password: RadixConnectPassword,
+
+    /// The messages for which we have received the `MetaData` package but not
+    /// yet all chunks, bounded by `P2P_MAX_INCOMING_MESSAGES` and
+    /// `P2P_MAX_INCOMING_CHUNK_COUNT`.
+    incoming: RwLock<HashMap<String, IncomingP2PMessage>>,
+}
+
+struct IncomingP2PMessage {
+    chunk_count: u64,
+    hash_of_message: Exactly32Bytes,
+    message_byte_count: u64,
+    chunks: HashMap<u64, Vec<u8>>,
+    last_received_at: Timestamp,
+}
+
+/// An outgoing message split into encoded packages, to be sent in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct P2POutgoingMessage {
+    pub message_id: String,
+    pub packages: Vec<BagOfBytes>,
+}
+
+/// The outcome of receiving a single package.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum P2PIncomingPackageOutcome {
+    /// More chunks are needed to reassemble the message.
+    AwaitingChunks { message_id: String },
+
+    /// All chunks were received and the hash of the message verified, the
+    /// host should send `confirmation` back to the peer.
+    MessageReceived {
+        message_id: String,
+        message: BagOfBytes,
+        confirmation: BagOfBytes,
+    },
+
+    /// All chunks were received but the hash of the reassembled message did
+    /// not match, the host should send `error` back to the peer.
+    MessageCorrupted {
+        message_id: String,
+        error: BagOfBytes,
+    },
+
+    /// The peer confirmed it received the message we sent.
+    MessageConfirmed { message_id: String },
+
+    /// The peer failed to receive the message we sent.
+    MessageFailed {
+        message_id: String,
+        error: P2PReceiveMessageError,
+    },
+}
+
+impl P2PMessageCodec {
+    pub fn new(password: RadixConnectPassword) -> Self {
+        Self {
+            password,
+            incoming: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl P2PMessageCodec {
+    /// Splits `message` into a `MetaData` package followed by `Chunk`
+    /// packages of at most `P2P_MESSAGE_CHUNK_SIZE` bytes each.
+    pub fn split(&self, message: impl AsRef<[u8]>) -> P2POutgoingMessage {
+        self.split_with_message_id(Uuid::new_v4().to_string(), message)
+    }
+
+    fn split_with_message_id(
+        &self,
+        message_id: String,
+        message: impl AsRef<[u8]>,
+    ) -> P2POutgoingMessage {
+        let message = message.as_ref();
+        let chunks = message.chunks(P2P_MESSAGE_CHUNK_SIZE).collect::<Vec<_>>();
+
+        let meta_data = P2PMessagePackage::MetaData {
+            message_id: message_id.clone(),
+            chunk_count: chunks.len() as u64,
+            hash_of_message: Exactly32Bytes::from(hash_of(message)),
+            message_byte_count: message.len() as u64,
+        };
+        let packages = std::iter::once(meta_data)
+            .chain(chunks.into_iter().enumerate().map(|(index, chunk)| {
+                P2PMessagePackage::Chunk {
+                    message_id: message_id.clone(),
+                    chunk_index: index as u64,
+                    chunk_data: STANDARD.encode(chunk),
+                }
+            }))
+            .map(Self::encode)
+            .collect();
+
+        P2POutgoingMessage {
+            message_id,
+            packages,
+        }
+    }
+
+    /// Handles a package received from the peer, returning the reassembled
+    /// message once all chunks have been received.
+    ///
+    /// Fails if the `MetaData` of a message announces more chunks than
+    /// needed for its byte count or than `P2P_MESSAGE_MAX_CHUNK_COUNT`, or if
+    /// accepting it would exceed the limits of messages being reassembled.
+    /// Messages not receiving any package for
+    /// `P2P_INCOMING_MESSAGE_TIMEOUT_SECONDS` are dropped.
+    pub fn receive(
+        &self,
+        package: impl AsRef<[u8]>,
+    ) -> Result<P2PIncomingPackageOutcome> {
+        self.receive_at(package, Timestamp::now_utc())
+    }
+
+    fn receive_at(
+        &self,
+        package: impl AsRef<[u8]>,
+        now: Timestamp,
+    ) -> Result<P2PIncomingPackageOutcome> {
+        let package = package.as_ref().deserialize::<P2PMessagePackage>()?;
+        match package {
+            P2PMessagePackage::MetaData {
+                message_id,
+                chunk_count,
+                hash_of_message,
+                message_byte_count,
+            } => {
+                self.receive_meta_data(
+                    &message_id,
+                    IncomingP2PMessage {
+                        chunk_count,
+                        hash_of_message,
+                        message_byte_count,
+                        chunks: HashMap::new(),
+                        last_received_at: now,
+                    },
+                )?;
+                Ok(self.reassemble_if_complete(message_id))
+            }
+            P2PMessagePackage::Chunk {
+                message_id,
+                chunk_index,
+                chunk_data,
+            } => {
+                self.receive_chunk(&message_id, chunk_index, chunk_data, now)?;
+                Ok(self.reassemble_if_complete(message_id))
+            }
+            P2PMessagePackage::ReceiveMessageConfirmation { message_id } => {
+                Ok(P2PIncomingPackageOutcome::MessageConfirmed { message_id })
+            }
+            P2PMessagePackage::ReceiveMessageError { message_id, error } => {
+                Ok(P2PIncomingPackageOutcome::MessageFailed {
+                    message_id,
+                    error,
+                })
+            }
+        }
+    }
+
+    fn receive_meta_data(
+        &self,
+        message_id: &str,
+        message: IncomingP2PMessage,
+    ) -> Result<()> {
+        let expected_chunk_count = message
+            .message_byte_count
+            .div_ceil(P2P_MESSAGE_CHUNK_SIZE as u64);
+        if message.chunk_count != expected_chunk_count
+            || message.chunk_count > P2P_MESSAGE_MAX_CHUNK_COUNT
+        {
+            return Err(CommonError::InvalidP2PMessageMetaData {
+                message_id: message_id.to_owned(),
+                chunk_count: message.chunk_count,
+                message_byte_count: message.message_byte_count,
+            });
+        }
+
+        let mut incoming = self.write_incoming();
+        incoming.retain(|id, incoming_message| {
+            id != message_id
+                && message
+                    .last_received_at
+                    .duration_since(incoming_message.last_received_at)
+                    .whole_seconds()
+                    < P2P_INCOMING_MESSAGE_TIMEOUT_SECONDS
+        });
+
+        let incoming_chunk_count = incoming
+            .values()
+            .map(|incoming_message| incoming_message.chunk_count)
+            .sum::<u64>();
+        if incoming.len() >= P2P_MAX_INCOMING_MESSAGES
+            || incoming_chunk_count + message.chunk_count
+                > P2P_MAX_INCOMING_CHUNK_COUNT
+        {
+            return Err(CommonError::TooManyIncomingP2PMessages {
+                message_id: message_id.to_owned(),
+            });
+        }
+
+        incoming.insert(message_id.to_owned(), message);
+        Ok(())
+    }
+
+    fn receive_chunk(
+        &self,
+        message_id: &str,
+        chunk_index: u64,
+        chunk_data: String,
+        now: Timestamp,
+    ) -> Result<()> {
+        let mut incoming = self.write_incoming();
+        let message = incoming.get_mut(message_id).ok_or_else(|| {
+            CommonError::P2PMessageChunkForUnknownMessage {
+                message_id: message_id.to_owned(),
+            }
+        })?;
+
+        let invalid_chunk = || CommonError::InvalidP2PMessageChunk {
+            message_id: message_id.to_owned(),
+            chunk_index,
+        };
+        if chunk_index >= message.chunk_count {
+            return Err(invalid_chunk());
+        }
+        let bytes = STANDARD.decode(chunk_data).map_err(|_| invalid_chunk())?;
+        if bytes.len() > P2P_MESSAGE_CHUNK_SIZE {
+            return Err(invalid_chunk());
+        }
+        message.chunks.insert(chunk_index, bytes);
+        message.last_received_at = now;
+        Ok(())
+    }
+
+    fn reassemble_if_complete(
+        &self,
+        message_id: String,
+    ) -> P2PIncomingPackageOutcome {
+        let mut incoming = self.write_incoming();
+        let is_complete = incoming.get(&message_id).is_some_and(|message| {
+            message.chunks.len() as u64 == message.chunk_count
+        });
+        if !is_complete {
+            return P2PIncomingPackageOutcome::AwaitingChunks { message_id };
+        }
+        let message = incoming
+            .remove(&message_id)
+            .expect("Checked above that message is present");
+
+        let bytes = (0..message.chunk_count)
+            .flat_map(|index| message.chunks[&index].clone())
+            .collect::<Vec<_>>();
+
+        let is_intact = bytes.len() as u64 == message.message_byte_count
+            && Exactly32Bytes::from(hash_of(&bytes)) == message.hash_of_message;
+
+        if is_intact {
+            P2PIncomingPackageOutcome::MessageReceived {
+                confirmation: Self::encode(
+                    P2PMessagePackage::ReceiveMessageConfirmation {
+                        message_id: message_id.clone(),
+                    },
+                ),
+                message_id,
+                message: BagOfBytes::from(bytes),
+            }
+        } else {
+            P2PIncomingPackageOutcome::MessageCorrupted {
+                error: Self::encode(P2PMessagePackage::ReceiveMessageError {
+                    message_id: message_id.clone(),
+                    error: P2PReceiveMessageError::MessageHashesMismatch,
+                }),
+                message_id,
+            }
+        }
+    }
+
+    fn write_incoming(
+        &self,
+    ) -> std::sync::RwLockWriteGuard<'_, HashMap<String, IncomingP2PMessage>>
+    {
+        self.incoming
+            .write()
+            .expect("Stopped using P2PMessageCodec, lock poisoned")
+    }
+
+    fn encode(package: P2PMessagePackage) -> BagOfBytes {
+        BagOfBytes::from(
+            package
+                .serialize_to_bytes()
+                .expect("P2PMessagePackage is always JSON serializable"),
+        )
+    }
+}
+
+impl P2PMessageCodec {
+    /// Encrypts a message to be sent via the Signaling Server, using the
+    /// password of the link as key.
+    pub fn encrypt_signaling_message(
+        &self,
+        plaintext: impl AsRef<[u8]>,
+    ) -> BagOfBytes {
+        let mut encryption_key = EncryptionKey::from(self.password.value);
+        BagOfBytes::from(
+            EncryptionScheme::default().encrypt(plaintext, &mut encryption_key),
+        )
+    }
+
+    /// Decrypts a message received via the Signaling Server, using the
+    /// password of the link as key.
+    pub fn decrypt_signaling_message(
+        &self,
+        cipher_text: impl AsRef<[u8]>,
+    ) -> Result<BagOfBytes> {
+        let mut decryption_key = EncryptionKey::from(self.password.value);
+        EncryptionScheme::default()
+            .decrypt(cipher_text, &mut decryption_key)
+            .map(BagOfBytes::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = P2PMessageCodec;
+
+    fn sut() -> SUT {
+        SUT::new(RadixConnectPassword::sample())
+    }
+
+    fn message(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i % 256) as u8).collect()
+    }
+
+    fn decode(package: &BagOfBytes) -> P2PMessagePackage {
+        package.bytes().deserialize().unwrap()
+    }
+
+    #[test]
+    fn split_small_message_is_meta_data_and_one_chunk() {
+        let msg = message(10);
+        let outgoing = sut().split_with_message_id("abc".to_owned(), &msg);
+        assert_eq!(outgoing.packages.len(), 2);
+        assert_eq!(
+            decode(&outgoing.packages[0]),
+            P2PMessagePackage::MetaData {
+                message_id: "abc".to_owned(),
+                chunk_count: 1,
+                hash_of_message: Exactly32Bytes::from(hash_of(&msg)),
+                message_byte_count: 10,
+            }
+        );
+        assert_eq!(
+            decode(&outgoing.packages[1]),
+            P2PMessagePackage::Chunk {
+                message_id: "abc".to_owned(),
+                chunk_index: 0,
+                chunk_data: STANDARD.encode(&msg),
+            }
+        );
+    }
+
+    #[test]
+    fn split_large_message_in_chunks() {
+        let outgoing = sut().split(message(P2P_MESSAGE_CHUNK_SIZE * 2 + 1));
+        assert_eq!(outgoing.packages.len(), 4);
+        assert!(outgoing
+            .packages
+            .iter()
+            .map(decode)
+            .all(|p| p.message_id() == outgoing.message_id));
+    }
+
+    #[test]
+    fn split_then_receive_roundtrip() {
+        let sender = sut();
+        let receiver = sut();
+        let msg = message(P2P_MESSAGE_CHUNK_SIZE * 3 + 7);
+        let outgoing = sender.split(&msg);
+
+        let (last, awaiting) = outgoing.packages.split_last().unwrap();
+        for package in awaiting {
+            assert_eq!(
+                receiver.receive(package).unwrap(),
+                P2PIncomingPackageOutcome::AwaitingChunks {
+                    message_id: outgoing.message_id.clone()
+                }
+            );
+        }
+        let P2PIncomingPackageOutcome::MessageReceived {
+            message_id,
+            message,
+            confirmation,
+        } = receiver.receive(last).unwrap()
+        else {
+            panic!("Expected message to be received")
+        };
+        assert_eq!(message_id, outgoing.message_id);
+        assert_eq!(message.to_vec(), msg);
+
+        assert_eq!(
+            sender.receive(confirmation).unwrap(),
+            P2PIncomingPackageOutcome::MessageConfirmed {
+                message_id: outgoing.message_id
+            }
+        );
+    }
+
+    #[test]
+    fn chunks_out_of_order_are_reassembled() {
+        let receiver = sut();
+        let msg = message(P2P_MESSAGE_CHUNK_SIZE + 1);
+        let outgoing = sut().split(&msg);
+
+        receiver.receive(&outgoing.packages[0]).unwrap();
+        receiver.receive(&outgoing.packages[2]).unwrap();
+        let outcome = receiver.receive(&outgoing.packages[1]).unwrap();
+
+        assert!(matches!(
+            outcome,
+            P2PIncomingPackageOutcome::MessageReceived { message, .. } if message.to_vec() == msg
+        ));
+    }
+
+    #[test]
+    fn hash_mismatch_results_in_error_package() {
+        let sender = sut();
+        let receiver = sut();
+        let meta_data = SUT::encode(P2PMessagePackage::MetaData {
+            message_id: "abc".to_owned(),
+            chunk_count: 1,
+            hash_of_message: Exactly32Bytes::sample(),
+            message_byte_count: 3,
+        });
+        let chunk = SUT::encode(P2PMessagePackage::Chunk {
+            message_id: "abc".to_owned(),
+            chunk_index: 0,
+            chunk_data: STANDARD.encode([1, 2, 3]),
+        });
+
+        receiver.receive(meta_data).unwrap();
+        let P2PIncomingPackageOutcome::MessageCorrupted { message_id, error } =
+            receiver.receive(chunk).unwrap()
+        else {
+            panic!("Expected message to be corrupted")
+        };
+        assert_eq!(message_id, "abc");
+
+        assert_eq!(
+            sender.receive(error).unwrap(),
+            P2PIncomingPackageOutcome::MessageFailed {
+                message_id: "abc".to_owned(),
+                error: P2PReceiveMessageError::MessageHashesMismatch
+            }
+        );
+    }
+
+    #[test]
+    fn chunk_for_unknown_message_is_err() {
+        let outgoing = sut().split(message(3));
+        assert_eq!(
+            sut().receive(&outgoing.packages[1]),
+            Err(CommonError::P2PMessageChunkForUnknownMessage {
+                message_id: outgoing.message_id
+            })
+        );
+    }
+
+    #[test]
+    fn chunk_index_out_of_bounds_is_err() {
+        let receiver = sut();
+        let outgoing = receiver.split(message(3));
+        receiver.receive(&outgoing.packages[0]).unwrap();
+        let chunk = SUT::encode(P2PMessagePackage::Chunk {
+            message_id: outgoing.message_id.clone(),
+            chunk_index: 1,
+            chunk_data: STANDARD.encode([1]),
+        });
+
+        assert_eq!(
+            receiver.receive(chunk),
+            Err(CommonError::InvalidP2PMessageChunk {
+                message_id: outgoing.message_id,
+                chunk_index: 1
+            })
+        );
+    }
+
+    fn meta_data(message_id: &str, chunk_count: u64) -> BagOfBytes {
+        SUT::encode(P2PMessagePackage::MetaData {
+            message_id: message_id.to_owned(),
+            chunk_count,
+            hash_of_message: Exactly32Bytes::sample(),
+            message_byte_count: chunk_count * P2P_MESSAGE_CHUNK_SIZE as u64,
+        })
+    }
+
+    #[test]
+    fn chunk_count_not_matching_byte_count_is_err() {
+        let package = SUT::encode(P2PMessagePackage::MetaData {
+            message_id: "abc".to_owned(),
+            chunk_count: 2,
+            hash_of_message: Exactly32Bytes::sample(),
+            message_byte_count: 3,
+        });
+        assert_eq!(
+            sut().receive(package),
+            Err(CommonError::InvalidP2PMessageMetaData {
+                message_id: "abc".to_owned(),
+                chunk_count: 2,
+                message_byte_count: 3
+            })
+        );
+    }
+
+    #[test]
+    fn chunk_count_above_max_is_err() {
+        let chunk_count = P2P_MESSAGE_MAX_CHUNK_COUNT + 1;
+        assert_eq!(
+            sut().receive(meta_data("abc", chunk_count)),
+            Err(CommonError::InvalidP2PMessageMetaData {
+                message_id: "abc".to_owned(),
+                chunk_count,
+                message_byte_count: chunk_count * P2P_MESSAGE_CHUNK_SIZE as u64
+            })
+        );
+    }
+
+    #[test]
+    fn too_many_incoming_messages_is_err() {
+        let receiver = sut();
+        for i in 0..P2P_MAX_INCOMING_MESSAGES {
+            receiver.receive(meta_data(&i.to_string(), 1)).unwrap();
+        }
+        assert_eq!(
+            receiver.receive(meta_data("abc", 1)),
+            Err(CommonError::TooManyIncomingP2PMessages {
+                message_id: "abc".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn too_many_incoming_chunks_is_err() {
+        let receiver = sut();
+        receiver
+            .receive(meta_data("a", P2P_MESSAGE_MAX_CHUNK_COUNT))
+            .unwrap();
+        receiver
+            .receive(meta_data("b", P2P_MESSAGE_MAX_CHUNK_COUNT))
+            .unwrap();
+        assert_eq!(
+            receiver.receive(meta_data("c", 1)),
+            Err(CommonError::TooManyIncomingP2PMessages {
+                message_id: "c".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn resent_meta_data_does_not_count_twice() {
+        let receiver = sut();
+        for _ in 0..P2P_MAX_INCOMING_MESSAGES + 1 {
+            receiver.receive(meta_data("abc", 1)).unwrap();
+        }
+    }
+
+    #[test]
+    fn stale_incoming_messages_are_evicted() {
+        use std::ops::Add;
+        use std::time::Duration;
+
+        let receiver = sut();
+        let start = Timestamp::sample();
+        for i in 0..P2P_MAX_INCOMING_MESSAGES {
+            receiver
+                .receive_at(meta_data(&i.to_string(), 1), start)
+                .unwrap();
+        }
+
+        let later = start.add(Duration::from_secs(
+            P2P_INCOMING_MESSAGE_TIMEOUT_SECONDS as u64,
+        ));
+        assert_eq!(
+            receiver.receive_at(meta_data("abc", 1), later),
+            Ok(P2PIncomingPackageOutcome::AwaitingChunks {
+                message_id: "abc".to_owned()
+            })
+        );
+
+        let chunk = SUT::encode(P2PMessagePackage::Chunk {
+            message_id: "0".to_owned(),
+            chunk_index: 0,
+            chunk_data: STANDARD.encode([1]),
+        });
+        assert_eq!(
+            receiver.receive_at(chunk, later),
+            Err(CommonError::P2PMessageChunkForUnknownMessage {
+                message_id: "0".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn oversized_chunk_is_err() {
+        let receiver = sut();
+        receiver.receive(meta_data("abc", 1)).unwrap();
+        let chunk = SUT::encode(P2PMessagePackage::Chunk {
+            message_id: "abc".to_owned(),
+            chunk_index: 0,
+            chunk_data: STANDARD.encode(message(P2P_MESSAGE_CHUNK_SIZE + 1)),
+        });
+
+        assert_eq!(
+            receiver.receive(chunk),
+            Err(CommonError::InvalidP2PMessageChunk {
+                message_id: "abc".to_owned(),
+                chunk_index: 0
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_package_is_err() {
+        assert!(matches!(
+            sut().receive(b"not json"),
+            Err(CommonError::FailedToDeserializeJSONToValue { .. })
+        ));
+    }
+
+    #[test]
+    fn signaling_message_roundtrip() {
+        let msg = b"offer".to_vec();
+        let encrypted = sut().encrypt_signaling_message(&msg);
+        assert_ne!(encrypted.to_vec(), msg);
+        assert_eq!(
+            sut()
+                .decrypt_signaling_message(&encrypted)
+                .unwrap()
+                .to_vec(),
+            msg
+        );
+    }
+
+    #[test]
+    fn signaling_message_wrong_password_is_err() {
+        let encrypted = sut().encrypt_signaling_message(b"offer");
+        assert!(SUT::new(RadixConnectPassword::sample_other())
+            .decrypt_signaling_message(encrypted)
+            .is_err());
+    }
+}
@@ -0,0 +1,162 @@
+use crate::prelude::*;
+
+/// A package sent over a WebRTC data channel between the Wallet and the
+/// Connector Extension, as specified by CAP-36.
+///
+/// A message is sent as one `MetaData` package followed by `chunk_count`
+/// `Chunk` packages, the receiving side responds with either a
+/// `ReceiveMessageConfirmation` or a `ReceiveMessageError`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "packageType", rename_all = "camelCase")]
+pub enum P2PMessagePackage {
+    #[serde(rename_all = "camelCase")]
+    MetaData {
+        message_id: String,
+        chunk_count: u64,
+        hash_of_message: Exactly32Bytes,
+        message_byte_count: u64,
+    },
+
+    #[serde(rename_all = "camelCase")]
+    Chunk {
+        message_id: String,
+        chunk_index: u64,
+        /// Base64 encoded bytes of the chunk.
+        chunk_data: String,
+    },
+
+    #[serde(rename_all = "camelCase")]
+    ReceiveMessageConfirmation { message_id: String },
+
+    #[serde(rename_all = "camelCase")]
+    ReceiveMessageError {
+        message_id: String,
+        error: P2PReceiveMessageError,
+    },
+}
+
+/// The reason the receiving side failed to receive a message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum P2PReceiveMessageError {
+    /// The hash of the reassembled message did not match `hash_of_message`
+    /// of the `MetaData` package.
+    MessageHashesMismatch,
+}
+
+impl P2PMessagePackage {
+    pub fn message_id(&self) -> &str {
+        match self {
+            Self::MetaData { message_id, .. }
+            | Self::Chunk { message_id, .. }
+            | Self::ReceiveMessageConfirmation { message_id }
+            | Self::ReceiveMessageError { message_id, .. } => message_id,
+        }
+    }
+}
+
+impl HasSampleValues for P2PMessagePackage {
+    fn sample() -> Self {
+        Self::MetaData {
+            message_id: WalletInteractionId::sample().0,
+            chunk_count: 1,
+            hash_of_message: Exactly32Bytes::sample(),
+            message_byte_count: 2,
+        }
+    }
+
+    fn sample_other() -> Self {
+        Self::ReceiveMessageError {
+            message_id: WalletInteractionId::sample_other().0,
+            error: P2PReceiveMessageError::MessageHashesMismatch,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = P2PMessagePackage;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn message_id() {
+        assert_eq!(SUT::sample().message_id(), WalletInteractionId::sample().0);
+    }
+
+    #[test]
+    fn json_roundtrip_meta_data() {
+        assert_eq_after_json_roundtrip(
+            &SUT::sample(),
+            r#"
+            {
+                "packageType": "metaData",
+                "messageId": "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                "chunkCount": 1,
+                "hashOfMessage": "deaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddead",
+                "messageByteCount": 2
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_chunk() {
+        assert_eq_after_json_roundtrip(
+            &SUT::Chunk {
+                message_id: "abc".to_owned(),
+                chunk_index: 0,
+                chunk_data: "3q0=".to_owned(),
+            },
+            r#"
+            {
+                "packageType": "chunk",
+                "messageId": "abc",
+                "chunkIndex": 0,
+                "chunkData": "3q0="
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_confirmation() {
+        assert_eq_after_json_roundtrip(
+            &SUT::ReceiveMessageConfirmation {
+                message_id: "abc".to_owned(),
+            },
+            r#"
+            {
+                "packageType": "receiveMessageConfirmation",
+                "messageId": "abc"
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_error() {
+        assert_eq_after_json_roundtrip(
+            &SUT::sample_other(),
+            r#"
+            {
+                "packageType": "receiveMessageError",
+                "messageId": "dededede-dede-dede-dede-dededededede",
+                "error": "messageHashesMismatch"
+            }
+            "#,
+        );
+    }
+}
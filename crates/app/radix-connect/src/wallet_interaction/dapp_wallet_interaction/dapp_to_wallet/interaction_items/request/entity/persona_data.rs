@@ -11,6 +11,15 @@ pub struct DappToWalletInteractionPersonaDataRequestItem {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub number_of_requested_phone_numbers: Option<RequestedQuantity>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number_of_requested_postal_addresses: Option<RequestedQuantity>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number_of_requested_dates_of_birth: Option<RequestedQuantity>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number_of_requested_companies: Option<RequestedQuantity>,
 }
 
 impl DappToWalletInteractionPersonaDataRequestItem {
@@ -25,6 +34,27 @@ impl DappToWalletInteractionPersonaDataRequestItem {
                 number_of_requested_email_addresses.into(),
             number_of_requested_phone_numbers:
                 number_of_requested_phone_numbers.into(),
+            number_of_requested_postal_addresses: None,
+            number_of_requested_dates_of_birth: None,
+            number_of_requested_companies: None,
+        }
+    }
+
+    /// Returns `self` requesting the postal addresses, dates of birth and
+    /// companies passed.
+    pub fn with_extended_entries(
+        self,
+        number_of_requested_postal_addresses: impl Into<Option<RequestedQuantity>>,
+        number_of_requested_dates_of_birth: impl Into<Option<RequestedQuantity>>,
+        number_of_requested_companies: impl Into<Option<RequestedQuantity>>,
+    ) -> Self {
+        Self {
+            number_of_requested_postal_addresses:
+                number_of_requested_postal_addresses.into(),
+            number_of_requested_dates_of_birth:
+                number_of_requested_dates_of_birth.into(),
+            number_of_requested_companies: number_of_requested_companies.into(),
+            ..self
         }
     }
 }
@@ -64,4 +94,28 @@ mod tests {
     fn inequality() {
         assert_ne!(SUT::sample(), SUT::sample_other());
     }
+
+    #[test]
+    fn json_roundtrip_extended_entries() {
+        let sut = SUT::new(None, None, None).with_extended_entries(
+            RequestedQuantity::at_least(1),
+            RequestedQuantity::exactly(1),
+            None,
+        );
+        assert_eq_after_json_roundtrip(
+            &sut,
+            r#"
+            {
+                "numberOfRequestedPostalAddresses": {
+                    "quantifier": "atLeast",
+                    "quantity": 1
+                },
+                "numberOfRequestedDatesOfBirth": {
+                    "quantifier": "exactly",
+                    "quantity": 1
+                }
+            }
+            "#,
+        );
+    }
 }
@@ -9,6 +9,12 @@ pub struct WalletToDappInteractionPersonaDataRequestResponseItem {
     pub email_addresses: Option<Vec<PersonaDataEntryEmailAddress>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub phone_numbers: Option<Vec<PersonaDataEntryPhoneNumber>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_addresses: Option<Vec<PersonaDataEntryPostalAddress>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dates_of_birth: Option<Vec<PersonaDataEntryDateOfBirth>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub companies: Option<Vec<PersonaDataEntryCompany>>,
 }
 
 impl WalletToDappInteractionPersonaDataRequestResponseItem {
@@ -21,6 +27,25 @@ impl WalletToDappInteractionPersonaDataRequestResponseItem {
             name: name.into(),
             email_addresses: email_addresses.into(),
             phone_numbers: phone_numbers.into(),
+            postal_addresses: None,
+            dates_of_birth: None,
+            companies: None,
+        }
+    }
+
+    /// Returns `self` responding with the postal addresses, dates of birth
+    /// and companies passed.
+    pub fn with_extended_entries(
+        self,
+        postal_addresses: impl Into<Option<Vec<PersonaDataEntryPostalAddress>>>,
+        dates_of_birth: impl Into<Option<Vec<PersonaDataEntryDateOfBirth>>>,
+        companies: impl Into<Option<Vec<PersonaDataEntryCompany>>>,
+    ) -> Self {
+        Self {
+            postal_addresses: postal_addresses.into(),
+            dates_of_birth: dates_of_birth.into(),
+            companies: companies.into(),
+            ..self
         }
     }
 }
@@ -60,4 +85,27 @@ mod tests {
     fn inequality() {
         assert_ne!(SUT::sample(), SUT::sample_other());
     }
+
+    #[test]
+    fn json_roundtrip_extended_entries() {
+        let sut = SUT::new(None, None, None).with_extended_entries(
+            None,
+            vec![PersonaDataEntryDateOfBirth::sample()],
+            vec![PersonaDataEntryCompany::sample()],
+        );
+        assert_eq_after_json_roundtrip(
+            &sut,
+            r#"
+            {
+                "datesOfBirth": ["1939-05-27"],
+                "companies": [
+                    {
+                        "name": "Wayne Enterprises",
+                        "title": "CEO"
+                    }
+                ]
+            }
+            "#,
+        );
+    }
 }
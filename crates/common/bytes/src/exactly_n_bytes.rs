@@ -380,3 +380,24 @@ decl_exactly_n_bytes!(
     60,
     "deaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddead", // expected sample value for tests
 );
+
+decl_exactly_n_bytes!(
+    /// 8 bytes, used as fingerprint of a public key, e.g. to identify the
+    /// decryptor of an encrypted transaction message.
+    8,
+    "deaddeaddeaddead", // expected sample value for tests
+);
+
+decl_exactly_n_bytes!(
+    /// 16 bytes, used as 128 bit symmetric keys, e.g. the key used to encrypt
+    /// a transaction message before it is wrapped for each decryptor.
+    16,
+    "deaddeaddeaddeaddeaddeaddeaddead", // expected sample value for tests
+);
+
+decl_exactly_n_bytes!(
+    /// 24 bytes, used by AES key wrapped (RFC 3394) 128 bit keys, e.g. the
+    /// per decryptor wrapped key of an encrypted transaction message.
+    24,
+    "deaddeaddeaddeaddeaddeaddeaddeaddeaddeaddeaddead", // expected sample value for tests
+);
@@ -24,10 +24,18 @@ pub enum MetadataKey {
     DappDefinitions,
     Validator,
     Pool,
+    ClaimedWebsites,
+    ClaimedEntities,
 }
 
 pub fn dapp_metadata_keys() -> Vec<MetadataKey> {
-    vec![MetadataKey::Name, MetadataKey::IconUrl]
+    vec![
+        MetadataKey::Name,
+        MetadataKey::IconUrl,
+        MetadataKey::AccountType,
+        MetadataKey::ClaimedWebsites,
+        MetadataKey::ClaimedEntities,
+    ]
 }
 
 impl From<MetadataKey> for String {
@@ -87,5 +95,7 @@ mod tests {
         eq(IconUrl, "icon_url");
         eq(Description, "description");
         eq(Tags, "tags");
+        eq(ClaimedWebsites, "claimed_websites");
+        eq(ClaimedEntities, "claimed_entities");
     }
 }
@@ -468,6 +468,39 @@ impl Decimal {
         self.native().checked_powi(exp).map(|n| n.into())
     }
 
+    /// Multiplication: `self * rhs`, returns `None` if it overflows.
+    ///
+    /// ```
+    /// use numeric::prelude::*;
+    /// #[allow(clippy::upper_case_acronyms)]
+    /// type SUT = Decimal192;
+    ///
+    /// assert_eq!(SUT::two().checked_mul(SUT::three()), Some(SUT::six()));
+    /// assert_eq!(SUT::max().checked_mul(SUT::two()), None);
+    /// ```
+    ///
+    #[inline]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        ScryptoCheckedMul::checked_mul(self, rhs)
+    }
+
+    /// Division: `self / rhs`, returns `None` if `rhs` is zero or if it
+    /// overflows.
+    ///
+    /// ```
+    /// use numeric::prelude::*;
+    /// #[allow(clippy::upper_case_acronyms)]
+    /// type SUT = Decimal192;
+    ///
+    /// assert_eq!(SUT::six().checked_div(SUT::three()), Some(SUT::two()));
+    /// assert_eq!(SUT::one().checked_div(SUT::zero()), None);
+    /// ```
+    ///
+    #[inline]
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.native().checked_div(rhs.native()).map(Self::from)
+    }
+
     /// Creates the Decimal `10^exponent`
     ///
     /// ```
@@ -8,6 +8,7 @@ pub mod prelude {
     pub(crate) use bytes::prelude::*;
 
     pub(crate) use radix_common::math::{
+        traits::CheckedDiv as ScryptoCheckedDiv,
         traits::CheckedMul as ScryptoCheckedMul, Decimal as ScryptoDecimal192,
         RoundingMode as ScryptoRoundingMode,
     };
@@ -1105,6 +1105,157 @@ pub enum CommonError {
 
     #[error("Address book entry already exists with alias: {alias}")]
     AddressBookEntryAlreadyExists { alias: String } = 10323,
+
+    #[error("Encrypted message must have at least one recipient")]
+    EncryptedMessageWithoutRecipients = 10324,
+
+    #[error("Encrypted message has no decryptor for public key: {public_key}")]
+    EncryptedMessageNoDecryptorForPublicKey { public_key: String } = 10325,
+
+    #[error("Failed to decrypt encrypted message")]
+    FailedToDecryptEncryptedMessage = 10326,
+
+    #[error("Encrypted message is not addressed to account: {address}")]
+    EncryptedMessageNotForAccount { address: String } = 10327,
+
+    #[error("Invalid password based key derivation parameters: {underlying}")]
+    InvalidPasswordBasedKeyDerivationParameters { underlying: String } = 10328,
+
+    #[error("Committed transaction at state version {state_version} has no intent hash")]
+    GatewayCommittedTransactionWithoutIntentHash { state_version: u64 } = 10329,
+
+    #[error("Gateway returned invalid state for pool {pool_address}")]
+    InvalidPoolStateFromGateway { pool_address: String } = 10330,
+
+    #[error("Resource {resource_address} is not in pool {pool_address}")]
+    ResourceNotInPool {
+        resource_address: String,
+        pool_address: String,
+    } = 10331,
+
+    #[error("Missing contribution of resource {resource_address} to pool {pool_address}")]
+    MissingPoolContribution {
+        resource_address: String,
+        pool_address: String,
+    } = 10332,
+
+    #[error(
+        "Gave up polling status of transaction {hash}, waited for too long"
+    )]
+    TransactionPollingTimedOut { hash: String } = 10333,
+
+    #[error("Polling status of transaction {hash} was cancelled")]
+    TransactionPollingCancelled { hash: String } = 10334,
+
+    #[error(
+        "Dapp interaction is not a request for accounts, persona data or login"
+    )]
+    DappInteractionIsNotARequest = 10335,
+
+    #[error("Decision does not fulfill the dApp request, reason: {reason}")]
+    DappInteractionDecisionDoesNotFulfillRequest { reason: String } = 10336,
+
+    #[error("Received P2P message chunk for unknown message {message_id}")]
+    P2PMessageChunkForUnknownMessage { message_id: String } = 10337,
+
+    #[error("Invalid P2P message chunk {chunk_index} of message {message_id}")]
+    InvalidP2PMessageChunk {
+        message_id: String,
+        chunk_index: u64,
+    } = 10338,
+
+    #[error("Ledger hardware wallet failed, code: {code}, message: {message}")]
+    LedgerHardwareWalletFailure { code: u32, message: String } = 10339,
+
+    #[error("Unexpected response from Ledger hardware wallet for interaction {interaction_id}")]
+    LedgerHardwareWalletUnexpectedResponse { interaction_id: String } = 10340,
+
+    #[error("Unknown SLIP39 word.")]
+    UnknownSLIP39Word = 10341,
+
+    #[error("Invalid SLIP39 share: {reason}")]
+    InvalidSLIP39Share { reason: String } = 10342,
+
+    #[error("SLIP39 shares do not belong to the same secret.")]
+    SLIP39SharesMismatch = 10343,
+
+    #[error("Insufficient SLIP39 shares to recover the secret, {reason}")]
+    InsufficientSLIP39Shares { reason: String } = 10344,
+
+    #[error("SLIP39 shares failed to recover the secret, digest mismatch.")]
+    SLIP39ShareDigestMismatch = 10345,
+
+    #[error("Invalid SLIP39 parameters: {reason}")]
+    InvalidSLIP39Parameters { reason: String } = 10346,
+
+    #[error("Cannot merge Profiles with different ProfileIDs.")]
+    CannotMergeProfilesWithDifferentIDs = 10347,
+
+    #[error("Invalid number of Profile merge conflict resolutions, expected: {expected}, found: {found}")]
+    InvalidProfileMergeResolutionCount { expected: u64, found: u64 } = 10348,
+
+    #[error("Invalid payment request: {reason}")]
+    InvalidPaymentRequest { reason: String } = 10349,
+
+    #[error("Payment request RNS domain '{expected}' does not match resolved receiver domain '{found}'")]
+    PaymentRequestRnsDomainMismatch { expected: String, found: String } = 10350,
+
+    #[error("No FX rate available for fiat currency: {currency}")]
+    FiatCurrencyFxRateUnavailable { currency: String } = 10351,
+
+    #[error("Account {address} is already watched")]
+    WatchOnlyAccountAlreadyExists { address: String } = 10352,
+
+    #[error("Account {address} is controlled by this wallet, it cannot be watch-only")]
+    WatchOnlyAccountIsControlledByWallet { address: String } = 10353,
+
+    #[error("Invalid PersonaData - company name empty")]
+    PersonaDataInvalidCompanyNameEmpty = 10354,
+
+    #[error("Invalid PersonaData - date of birth, got: {bad_value}")]
+    PersonaDataInvalidDateOfBirth { bad_value: String } = 10355,
+
+    #[error(
+        "Invalid PersonaData - postal address country code, got: {bad_value}"
+    )]
+    PersonaDataInvalidPostalAddressCountryCode { bad_value: String } = 10356,
+
+    #[error("Invalid PersonaData - postal address missing field: {field}")]
+    PersonaDataInvalidPostalAddressMissingField { field: String } = 10357,
+
+    #[error("Invalid PersonaData - postal address unexpected field: {field}")]
+    PersonaDataInvalidPostalAddressUnexpectedField { field: String } = 10358,
+
+    #[error("Gateway cannot filter transactions by manifest class: {kind}")]
+    GatewayUnsupportedManifestClass { kind: String } = 10359,
+
+    #[error("dApp origin failed verification: {origin}")]
+    DappOriginNotVerified { origin: String } = 10360,
+
+    #[error("Invalid P2P message metadata of message {message_id}, chunk count: {chunk_count}, byte count: {message_byte_count}")]
+    InvalidP2PMessageMetaData {
+        message_id: String,
+        chunk_count: u64,
+        message_byte_count: u64,
+    } = 10361,
+
+    #[error("Too many incoming P2P messages, rejected message {message_id}")]
+    TooManyIncomingP2PMessages { message_id: String } = 10362,
+
+    #[error("Ledger hardware wallet returned an invalid signature in response to interaction {interaction_id}")]
+    LedgerHardwareWalletInvalidSignature { interaction_id: String } = 10363,
+
+    #[error("Payment request expired at {expiry} (seconds since Unix epoch)")]
+    PaymentRequestExpired { expiry: i64 } = 10364,
+
+    #[error("No public key known to encrypt a message for account: {address}")]
+    EncryptedMessageRecipientPublicKeysUnknown { address: String } = 10365,
+
+    #[error("The Profile has changed since it was merged, merge it again")]
+    ProfileChangedSinceMerge = 10366,
+
+    #[error("Overflow computing the pool units of a contribution to pool: {pool_address}")]
+    LiquidityPoolUnitsOverflow { pool_address: String } = 10367,
 }
 
 impl CommonError {
@@ -53,6 +53,19 @@ impl From<Timestamp> for Instant {
     }
 }
 
+impl From<Instant> for Timestamp {
+    fn from(value: Instant) -> Self {
+        let duration = std::time::Duration::from_secs(
+            value.seconds_since_unix_epoch.unsigned_abs(),
+        );
+        if value.seconds_since_unix_epoch >= 0 {
+            Timestamp::UNIX_EPOCH + duration
+        } else {
+            Timestamp::UNIX_EPOCH - duration
+        }
+    }
+}
+
 impl HasSampleValues for Instant {
     fn sample() -> Self {
         // matches Timestamp::sample()
@@ -124,4 +137,19 @@ mod tests {
         let instant = Instant::from(timestamp);
         assert_eq!(instant.seconds_since_unix_epoch, 300);
     }
+
+    #[test]
+    fn to_timestamp() {
+        let test = |i: i64| {
+            assert_eq!(
+                Instant::from(Timestamp::from(Instant::from(i)))
+                    .seconds_since_unix_epoch,
+                i
+            )
+        };
+        test(-10);
+        test(0);
+        test(300);
+        test(1694448356);
+    }
 }
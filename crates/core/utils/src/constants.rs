@@ -28,6 +28,17 @@ pub const MIN_REQUIRED_XRD_FOR_ACCOUNT_DELETION: f64 = 4.0;
 /// This means that there will be a 2s delay after first call, a 3s delay after second call, 4s after third and so on.
 pub const POLLING_DELAY_INCREMENT_IN_SECONDS: u64 = 1;
 
+/// The initial delay between transaction status polling requests, which is
+/// doubled after each request, up to `POLLING_MAX_DELAY_IN_SECONDS`.
+pub const POLLING_INITIAL_BACKOFF_DELAY_IN_SECONDS: u64 = 2;
+
+/// The maximum delay between transaction status polling requests.
+pub const POLLING_MAX_DELAY_IN_SECONDS: u64 = 30;
+
+/// The maximum accumulated delay of transaction status polling, after which
+/// we give up polling.
+pub const POLLING_MAX_TOTAL_DELAY_IN_SECONDS: u64 = 600;
+
 /// Number of minutes per day.
 pub const MINUTES_PER_DAY: u32 = 24 * 60;
 
@@ -40,6 +51,10 @@ pub const GATEWAY_CHUNK_ENTITIES_BY_ROLE_REQUIREMENT_LOOKUP: u64 = 50;
 /// Number of new MFA factor instances to derive and store when requested.
 pub const NUMBER_OF_NEW_MFA_FACTOR_INSTANCES_TO_DERIVE_AND_STORE: u64 = 3;
 
+/// Default number of consecutive unused account addresses after which an
+/// account recovery scan stops.
+pub const ACCOUNT_RECOVERY_SCAN_DEFAULT_GAP_LIMIT: u32 = 50;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,132 @@
+use crate::prelude::*;
+use crypto::keys::x25519::{
+    PublicKey as X25519PublicKey, SecretKey as X25519PrivateKey,
+};
+use crypto::signatures::ed25519::{
+    PublicKey as IotaEd25519PublicKey, SecretKey as IotaEd25519PrivateKey,
+};
+
+impl Ed25519PrivateKey {
+    /// The X25519 private key birationally equivalent to this Ed25519 private
+    /// key, used to perform key agreement (ECDH) with the owner of some
+    /// `Ed25519PublicKey`.
+    pub fn to_key_agreement_private_key(&self) -> KeyAgreementPrivateKey {
+        let seed: [u8; 32] = self
+            .to_bytes()
+            .try_into()
+            .expect("Ed25519 private keys are always 32 bytes.");
+        let ed25519 = IotaEd25519PrivateKey::from_bytes(&seed);
+        X25519PrivateKey::from(&ed25519).into()
+    }
+
+    /// Performs ECDH over Curve25519 between this key and `other` by
+    /// converting both keys to their X25519 (Montgomery) form.
+    pub fn shared_secret_from_key_agreement(
+        &self,
+        other: &Ed25519PublicKey,
+    ) -> Result<Exactly32Bytes> {
+        let other = other.to_key_agreement_public_key()?;
+        let shared_secret = self
+            .to_key_agreement_private_key()
+            .shared_secret_from_key_agreement(&other);
+        Ok(Exactly32Bytes::from(shared_secret.as_bytes()))
+    }
+}
+
+impl Ed25519PublicKey {
+    /// The X25519 public key birationally equivalent to this Ed25519 public
+    /// key, used to perform key agreement (ECDH) with the owner of this key.
+    pub fn to_key_agreement_public_key(&self) -> Result<KeyAgreementPublicKey> {
+        let bytes: [u8; 32] = self
+            .to_bytes()
+            .try_into()
+            .expect("Ed25519 public keys are always 32 bytes.");
+
+        IotaEd25519PublicKey::try_from_bytes(bytes)
+            .ok()
+            .and_then(|ed25519| X25519PublicKey::try_from(&ed25519).ok())
+            .map(KeyAgreementPublicKey::from)
+            .ok_or(CommonError::InvalidKeyAgreementPublicKeyFromBytes {
+                bad_value: self.to_hex(),
+            })
+    }
+}
+
+impl Secp256k1PrivateKey {
+    /// Performs ECDH over secp256k1 between this key and `other`, returning
+    /// the x-coordinate of the shared point.
+    pub fn shared_secret_from_key_agreement(
+        &self,
+        other: &Secp256k1PublicKey,
+    ) -> Exactly32Bytes {
+        let private_key = k256::SecretKey::from_slice(&self.to_bytes())
+            .expect("Secp256k1PrivateKey is always a valid k256 SecretKey.");
+        let public_key = k256::PublicKey::from_sec1_bytes(&other.to_bytes())
+            .expect("Secp256k1PublicKey is always a valid k256 PublicKey.");
+
+        let shared_secret = k256::ecdh::diffie_hellman(
+            private_key.to_nonzero_scalar(),
+            public_key.as_affine(),
+        );
+
+        Exactly32Bytes::try_from(shared_secret.raw_secret_bytes().as_slice())
+            .expect("secp256k1 shared secret is always 32 bytes.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ed25519_key_agreement_public_key_from_private_and_public_match() {
+        let private_key = Ed25519PrivateKey::sample_alice();
+        assert_eq!(
+            private_key.to_key_agreement_private_key().public_key(),
+            private_key
+                .public_key()
+                .to_key_agreement_public_key()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn ed25519_shared_secret_is_symmetric() {
+        let alice = Ed25519PrivateKey::sample_alice();
+        let bob = Ed25519PrivateKey::sample_bob();
+        assert_eq!(
+            alice
+                .shared_secret_from_key_agreement(&bob.public_key())
+                .unwrap(),
+            bob.shared_secret_from_key_agreement(&alice.public_key())
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn ed25519_shared_secret_differs_per_counterparty() {
+        let alice = Ed25519PrivateKey::sample_alice();
+        assert_ne!(
+            alice
+                .shared_secret_from_key_agreement(
+                    &Ed25519PrivateKey::sample_bob().public_key()
+                )
+                .unwrap(),
+            alice
+                .shared_secret_from_key_agreement(
+                    &Ed25519PrivateKey::sample_fade().public_key()
+                )
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn secp256k1_shared_secret_is_symmetric() {
+        let alice = Secp256k1PrivateKey::sample_alice();
+        let bob = Secp256k1PrivateKey::sample_bob();
+        assert_eq!(
+            alice.shared_secret_from_key_agreement(&bob.public_key()),
+            bob.shared_secret_from_key_agreement(&alice.public_key())
+        );
+    }
+}
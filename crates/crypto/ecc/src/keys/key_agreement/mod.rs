@@ -1,3 +1,4 @@
+mod diffie_hellman;
 mod private_key;
 mod public_key;
 
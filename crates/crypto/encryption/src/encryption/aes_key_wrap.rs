@@ -0,0 +1,107 @@
+use crate::prelude::*;
+
+use aes_kw::KekAes256;
+
+/// AES Key Wrap (RFC 3394) using a 256 bit key encryption key (KEK), used to
+/// wrap 128 bit symmetric keys, e.g. the key of an encrypted transaction
+/// message, once per decryptor.
+#[derive(
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    derive_more::Display,
+    derive_more::Debug,
+)]
+pub struct AesKeyWrap256 {}
+
+impl AesKeyWrap256 {
+    pub const DESCRIPTION: &'static str = "AESKW-256";
+}
+
+impl AesKeyWrap256 {
+    /// Wraps `key` using `key_encryption_key`, the output is 8 bytes longer
+    /// than the input due to the integrity check value.
+    pub fn wrap(
+        key: &Exactly16Bytes,
+        key_encryption_key: &EncryptionKey,
+    ) -> Exactly24Bytes {
+        let kek = KekAes256::from(*key_encryption_key.0.bytes());
+        let mut wrapped = [0u8; 24];
+        kek.wrap(key.bytes(), &mut wrapped)
+            .expect("Wrapping 16 bytes into 24 bytes should never fail.");
+        Exactly24Bytes::from(&wrapped)
+    }
+
+    /// Unwraps `wrapped_key` using `key_encryption_key`, fails if the
+    /// integrity check fails, i.e. if the wrong KEK was used.
+    pub fn unwrap(
+        wrapped_key: &Exactly24Bytes,
+        key_encryption_key: &EncryptionKey,
+    ) -> Result<Exactly16Bytes> {
+        let kek = KekAes256::from(*key_encryption_key.0.bytes());
+        let mut key = [0u8; 16];
+        kek.unwrap(wrapped_key.bytes(), &mut key).map_err(|e| {
+            error!("Failed to AES unwrap key - error: {:?}", e);
+            CommonError::AESDecryptionFailed
+        })?;
+        Ok(Exactly16Bytes::from(&key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = AesKeyWrap256;
+
+    fn rfc3394_kek() -> EncryptionKey {
+        EncryptionKey::from(
+            Exactly32Bytes::from_hex(
+                "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+            )
+            .unwrap(),
+        )
+    }
+
+    fn rfc3394_key() -> Exactly16Bytes {
+        Exactly16Bytes::from_hex("00112233445566778899aabbccddeeff").unwrap()
+    }
+
+    #[test]
+    fn wrap_rfc3394_test_vector() {
+        // RFC 3394 section 4.3: Wrap 128 bits of Key Data with a 256-bit KEK
+        assert_eq!(
+            SUT::wrap(&rfc3394_key(), &rfc3394_kek()).to_hex(),
+            "64e8c3f9ce0f5ba263e9777905818a2a93c8191e7d6e8ae7"
+        );
+    }
+
+    #[test]
+    fn unwrap_rfc3394_test_vector() {
+        let wrapped = Exactly24Bytes::from_hex(
+            "64e8c3f9ce0f5ba263e9777905818a2a93c8191e7d6e8ae7",
+        )
+        .unwrap();
+        assert_eq!(SUT::unwrap(&wrapped, &rfc3394_kek()), Ok(rfc3394_key()));
+    }
+
+    #[test]
+    fn roundtrip() {
+        let kek = EncryptionKey::generate();
+        let key = Exactly16Bytes::generate();
+        let wrapped = SUT::wrap(&key, &kek);
+        assert_eq!(SUT::unwrap(&wrapped, &kek), Ok(key));
+    }
+
+    #[test]
+    fn unwrap_with_wrong_kek_fails() {
+        let wrapped = SUT::wrap(&rfc3394_key(), &rfc3394_kek());
+        assert_eq!(
+            SUT::unwrap(&wrapped, &EncryptionKey::sample()),
+            Err(CommonError::AESDecryptionFailed)
+        );
+    }
+}
@@ -1,5 +1,6 @@
 mod aes_gcm_256;
 mod aes_gcm_sealed_box;
+mod aes_key_wrap;
 mod encryption_key;
 mod encryption_scheme;
 mod encryption_scheme_version;
@@ -8,6 +9,7 @@ mod versioned_encryption;
 
 pub use aes_gcm_256::*;
 pub use aes_gcm_sealed_box::*;
+pub use aes_key_wrap::*;
 pub use encryption_key::*;
 pub use encryption_scheme::*;
 pub use encryption_scheme_version::*;
@@ -5,6 +5,7 @@ use crate::prelude::*;
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum PasswordBasedKeyDerivationScheme {
     Version1(PbHkdfSha256),
+    Version2(PbArgon2id),
 }
 
 impl Serialize for PasswordBasedKeyDerivationScheme {
@@ -12,10 +13,22 @@ impl Serialize for PasswordBasedKeyDerivationScheme {
     where
         S: Serializer,
     {
-        let mut state = serializer
-            .serialize_struct("PasswordBasedKeyDerivationScheme", 2)?;
+        let field_count = match self {
+            Self::Version1(_) => 2,
+            Self::Version2(_) => 6,
+        };
+        let mut state = serializer.serialize_struct(
+            "PasswordBasedKeyDerivationScheme",
+            field_count,
+        )?;
         state.serialize_field("description", &self.description())?;
         state.serialize_field("version", &self.version())?;
+        if let Self::Version2(scheme) = self {
+            state.serialize_field("salt", &scheme.salt())?;
+            state.serialize_field("memoryCost", &scheme.memory_cost())?;
+            state.serialize_field("iterations", &scheme.iterations())?;
+            state.serialize_field("parallelism", &scheme.parallelism())?;
+        }
         state.end()
     }
 }
@@ -25,11 +38,37 @@ impl<'de> Deserialize<'de> for PasswordBasedKeyDerivationScheme {
         deserializer: D,
     ) -> Result<Self, D::Error> {
         #[derive(Deserialize, Serialize)]
+        #[serde(rename_all = "camelCase")]
         struct Wrapper {
             version: PasswordBasedKeyDerivationSchemeVersion,
+            salt: Option<Exactly16Bytes>,
+            memory_cost: Option<u32>,
+            iterations: Option<u32>,
+            parallelism: Option<u32>,
+        }
+        let wrapper = Wrapper::deserialize(deserializer)?;
+        match wrapper.version {
+            PasswordBasedKeyDerivationSchemeVersion::Version1 => {
+                Ok(Self::version1())
+            }
+            PasswordBasedKeyDerivationSchemeVersion::Version2 => {
+                let salt = wrapper
+                    .salt
+                    .ok_or_else(|| de::Error::missing_field("salt"))?;
+                let memory_cost = wrapper
+                    .memory_cost
+                    .ok_or_else(|| de::Error::missing_field("memoryCost"))?;
+                let iterations = wrapper
+                    .iterations
+                    .ok_or_else(|| de::Error::missing_field("iterations"))?;
+                let parallelism = wrapper
+                    .parallelism
+                    .ok_or_else(|| de::Error::missing_field("parallelism"))?;
+                PbArgon2id::new(salt, memory_cost, iterations, parallelism)
+                    .map(Self::Version2)
+                    .map_err(de::Error::custom)
+            }
         }
-        Wrapper::deserialize(deserializer)
-            .and_then(|w| Self::try_from(w.version).map_err(de::Error::custom))
     }
 }
 
@@ -37,10 +76,18 @@ impl PasswordBasedKeyDerivationScheme {
     pub fn version1() -> Self {
         Self::Version1(PbHkdfSha256::default())
     }
+
+    /// Argon2id with a new random salt and default cost parameters.
+    pub fn version2() -> Self {
+        Self::Version2(PbArgon2id::with_random_salt())
+    }
 }
+
 impl Default for PasswordBasedKeyDerivationScheme {
+    /// New encryptions use the memory-hard `Version2`, older `Version1`
+    /// encryptions can still be decrypted.
     fn default() -> Self {
-        Self::version1()
+        Self::version2()
     }
 }
 
@@ -50,6 +97,9 @@ impl VersionedPasswordBasedKeyDerivation for PasswordBasedKeyDerivationScheme {
             PasswordBasedKeyDerivationScheme::Version1(scheme) => {
                 scheme.kdf(password)
             }
+            PasswordBasedKeyDerivationScheme::Version2(scheme) => {
+                scheme.kdf(password)
+            }
         }
     }
 }
@@ -65,25 +115,12 @@ impl std::fmt::Display for PasswordBasedKeyDerivationScheme {
     }
 }
 
-impl TryFrom<PasswordBasedKeyDerivationSchemeVersion>
-    for PasswordBasedKeyDerivationScheme
-{
-    type Error = CommonError;
-    fn try_from(
-        value: PasswordBasedKeyDerivationSchemeVersion,
-    ) -> Result<Self> {
-        match value {
-            PasswordBasedKeyDerivationSchemeVersion::Version1 => {
-                Ok(Self::version1())
-            }
-        }
-    }
-}
 impl VersionOfAlgorithm for PasswordBasedKeyDerivationScheme {
     type Version = PasswordBasedKeyDerivationSchemeVersion;
     fn version(&self) -> Self::Version {
         match self {
             Self::Version1(scheme) => scheme.version(),
+            Self::Version2(scheme) => scheme.version(),
         }
     }
     fn description(&self) -> String {
@@ -91,6 +128,9 @@ impl VersionOfAlgorithm for PasswordBasedKeyDerivationScheme {
             PasswordBasedKeyDerivationScheme::Version1(scheme) => {
                 scheme.description()
             }
+            PasswordBasedKeyDerivationScheme::Version2(scheme) => {
+                scheme.description()
+            }
         }
     }
 }
@@ -104,7 +144,7 @@ mod tests {
 
     #[test]
     fn json_() {
-        let model = SUT::default();
+        let model = SUT::version1();
         assert_eq_after_json_roundtrip(
             &model,
             r#"
@@ -118,12 +158,12 @@ mod tests {
 
     #[test]
     fn display() {
-        assert_eq!(format!("{}", SUT::default()), "PasswordBasedKeyDerivationScheme: Version1 (HKDFSHA256-with-UTF8-encoding-of-password-no-salt-no-info)");
+        assert_eq!(format!("{}", SUT::version1()), "PasswordBasedKeyDerivationScheme: Version1 (HKDFSHA256-with-UTF8-encoding-of-password-no-salt-no-info)");
     }
 
     #[test]
     fn kdf() {
-        let sut = SUT::default();
+        let sut = SUT::version1();
         let test = |pwd: &str, exp: &str| {
             let key = sut.kdf(pwd);
             assert_eq!(key.to_hex(), exp);
@@ -145,4 +185,107 @@ mod tests {
             "eb70f01dede9afafa449eee1b1286504e1f62388b3f7dd4f956697b0e828fe18",
         );
     }
+
+    #[test]
+    fn default_is_version2() {
+        assert_eq!(
+            SUT::default().version(),
+            PasswordBasedKeyDerivationSchemeVersion::Version2
+        );
+    }
+
+    #[test]
+    fn json_version2() {
+        let model = SUT::Version2(PbArgon2id::sample());
+        assert_eq_after_json_roundtrip(
+            &model,
+            r#"
+        {
+			"version": 2,
+			"description": "Argon2id-with-UTF8-encoding-of-password-random-salt",
+			"salt": "deaddeaddeaddeaddeaddeaddeaddead",
+			"memoryCost": 19456,
+			"iterations": 2,
+			"parallelism": 1
+		}
+        "#,
+        );
+    }
+
+    #[test]
+    fn json_version2_missing_salt_is_err() {
+        assert_json_fails::<SUT>(
+            r#"
+        {
+			"version": 2,
+			"description": "Argon2id-with-UTF8-encoding-of-password-random-salt",
+			"memoryCost": 19456,
+			"iterations": 2,
+			"parallelism": 1
+		}
+        "#,
+        );
+    }
+
+    #[test]
+    fn json_version2_invalid_params_is_err() {
+        assert_json_fails::<SUT>(
+            r#"
+        {
+			"version": 2,
+			"description": "Argon2id-with-UTF8-encoding-of-password-random-salt",
+			"salt": "deaddeaddeaddeaddeaddeaddeaddead",
+			"memoryCost": 19456,
+			"iterations": 0,
+			"parallelism": 1
+		}
+        "#,
+        );
+    }
+
+    #[test]
+    fn json_version2_memory_cost_above_max_is_err() {
+        assert_json_fails::<SUT>(
+            r#"
+        {
+			"version": 2,
+			"description": "Argon2id-with-UTF8-encoding-of-password-random-salt",
+			"salt": "deaddeaddeaddeaddeaddeaddeaddead",
+			"memoryCost": 4294967295,
+			"iterations": 2,
+			"parallelism": 1
+		}
+        "#,
+        );
+    }
+
+    #[test]
+    fn json_version2_iterations_above_max_is_err() {
+        assert_json_fails::<SUT>(
+            r#"
+        {
+			"version": 2,
+			"description": "Argon2id-with-UTF8-encoding-of-password-random-salt",
+			"salt": "deaddeaddeaddeaddeaddeaddeaddead",
+			"memoryCost": 19456,
+			"iterations": 4294967295,
+			"parallelism": 1
+		}
+        "#,
+        );
+    }
+
+    #[test]
+    fn display_version2() {
+        assert_eq!(format!("{}", SUT::version2()), "PasswordBasedKeyDerivationScheme: Version2 (Argon2id-with-UTF8-encoding-of-password-random-salt)");
+    }
+
+    #[test]
+    fn kdf_version2() {
+        let sut = SUT::Version2(PbArgon2id::sample());
+        assert_eq!(
+            sut.kdf("Radix Rules!").to_hex(),
+            "beb070262ae52820f4b7a56342142c957a2249dd99b7a3c61f1e370a0b142306"
+        );
+    }
 }
@@ -16,4 +16,9 @@ pub enum PasswordBasedKeyDerivationSchemeVersion {
     /// A simple password based key derivation algorithm using HKDF<SHA256> with no salt or info.
     /// Description: `"HKDFSHA256-with-UTF8-encoding-of-password-no-salt-no-info"`
     Version1 = 1,
+
+    /// A memory-hard password based key derivation algorithm using Argon2id
+    /// with a random salt, salt and cost parameters are serialized.
+    /// Description: `"Argon2id-with-UTF8-encoding-of-password-random-salt"`
+    Version2 = 2,
 }
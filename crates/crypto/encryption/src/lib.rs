@@ -1,11 +1,13 @@
 mod encryption;
 mod key_derivation;
+mod pb_argon2id;
 mod pb_hkdf_sha256;
 mod versioned_algorithm;
 
 pub mod prelude {
     pub use crate::encryption::*;
     pub use crate::key_derivation::*;
+    pub use crate::pb_argon2id::*;
     pub use crate::pb_hkdf_sha256::*;
     pub use crate::versioned_algorithm::*;
 
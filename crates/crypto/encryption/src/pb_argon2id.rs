@@ -0,0 +1,295 @@
+use crate::prelude::*;
+
+use argon2::{Algorithm, Argon2, Params, Version};
+
+/// A memory-hard password based key derivation scheme, using `Argon2id`
+/// (RFC 9106) with the UTF8 encoding of the password as input and a random
+/// salt. The salt and cost parameters are stored next to the encrypted
+/// payload, so that it can be decrypted later.
+#[derive(
+    Clone, PartialEq, Eq, Hash, derive_more::Display, derive_more::Debug,
+)]
+#[debug(
+    "{} (m: {}, t: {}, p: {})",
+    self.description(),
+    self.memory_cost,
+    self.iterations,
+    self.parallelism
+)]
+#[display("{}", self.description())]
+pub struct PbArgon2id {
+    /// Random salt, unique per encryption.
+    salt: Exactly16Bytes,
+
+    /// Memory size in KiB.
+    memory_cost: u32,
+
+    /// Number of passes over the memory.
+    iterations: u32,
+
+    /// Degree of parallelism (number of lanes).
+    parallelism: u32,
+}
+
+impl PbArgon2id {
+    pub const DESCRIPTION: &'static str =
+        "Argon2id-with-UTF8-encoding-of-password-random-salt";
+
+    /// Memory cost used for new encryptions, 19 MiB, as recommended by OWASP.
+    pub const DEFAULT_MEMORY_COST: u32 = Params::DEFAULT_M_COST;
+
+    /// Number of iterations used for new encryptions.
+    pub const DEFAULT_ITERATIONS: u32 = Params::DEFAULT_T_COST;
+
+    /// Degree of parallelism used for new encryptions.
+    pub const DEFAULT_PARALLELISM: u32 = Params::DEFAULT_P_COST;
+
+    /// Upper bound of the memory cost we accept, 256 MiB, so that a crafted
+    /// encrypted backup cannot make us allocate unbounded memory.
+    pub const MAX_MEMORY_COST: u32 = 256 * 1024;
+
+    /// Upper bound of the number of iterations we accept.
+    pub const MAX_ITERATIONS: u32 = 10;
+
+    /// Upper bound of the degree of parallelism we accept.
+    pub const MAX_PARALLELISM: u32 = 8;
+
+    /// Creates a new scheme with the given salt and cost parameters, returns
+    /// `Err` if the parameters are not accepted by Argon2 or exceed our
+    /// upper bounds.
+    pub fn new(
+        salt: Exactly16Bytes,
+        memory_cost: u32,
+        iterations: u32,
+        parallelism: u32,
+    ) -> Result<Self> {
+        let sut = Self {
+            salt,
+            memory_cost,
+            iterations,
+            parallelism,
+        };
+        sut.params()?;
+        Ok(sut)
+    }
+
+    /// Creates a new scheme with a freshly generated random salt and the
+    /// default cost parameters.
+    pub fn with_random_salt() -> Self {
+        Self::new(
+            Exactly16Bytes::generate(),
+            Self::DEFAULT_MEMORY_COST,
+            Self::DEFAULT_ITERATIONS,
+            Self::DEFAULT_PARALLELISM,
+        )
+        .expect("Default Argon2 params should always be valid.")
+    }
+
+    pub fn salt(&self) -> Exactly16Bytes {
+        self.salt
+    }
+
+    pub fn memory_cost(&self) -> u32 {
+        self.memory_cost
+    }
+
+    pub fn iterations(&self) -> u32 {
+        self.iterations
+    }
+
+    pub fn parallelism(&self) -> u32 {
+        self.parallelism
+    }
+
+    fn params(&self) -> Result<Params> {
+        let exceeded = |name: &str, value: u32, max: u32| {
+            CommonError::InvalidPasswordBasedKeyDerivationParameters {
+                underlying: format!("{} {} exceeds max {}", name, value, max),
+            }
+        };
+        if self.memory_cost > Self::MAX_MEMORY_COST {
+            return Err(exceeded(
+                "memory cost",
+                self.memory_cost,
+                Self::MAX_MEMORY_COST,
+            ));
+        }
+        if self.iterations > Self::MAX_ITERATIONS {
+            return Err(exceeded(
+                "iterations",
+                self.iterations,
+                Self::MAX_ITERATIONS,
+            ));
+        }
+        if self.parallelism > Self::MAX_PARALLELISM {
+            return Err(exceeded(
+                "parallelism",
+                self.parallelism,
+                Self::MAX_PARALLELISM,
+            ));
+        }
+        Params::new(
+            self.memory_cost,
+            self.iterations,
+            self.parallelism,
+            Some(32),
+        )
+        .map_err(|e| {
+            CommonError::InvalidPasswordBasedKeyDerivationParameters {
+                underlying: e.to_string(),
+            }
+        })
+    }
+}
+
+impl Default for PbArgon2id {
+    fn default() -> Self {
+        Self::with_random_salt()
+    }
+}
+
+impl VersionOfAlgorithm for PbArgon2id {
+    type Version = PasswordBasedKeyDerivationSchemeVersion;
+
+    fn description(&self) -> String {
+        Self::DESCRIPTION.to_owned()
+    }
+
+    fn version(&self) -> Self::Version {
+        Self::Version::Version2
+    }
+}
+
+impl VersionedPasswordBasedKeyDerivation for PbArgon2id {
+    fn kdf(&self, password: impl AsRef<str>) -> Exactly32Bytes {
+        // Params have been validated in `new`
+        let params = self.params().expect("Valid Argon2 params");
+        let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
+
+        let mut okm = [0u8; 32]; // 32-byte buffer for the symmetric key
+        argon2
+            .hash_password_into(
+                password.as_ref().as_bytes(),
+                self.salt.as_ref(),
+                &mut okm,
+            )
+            .expect("Argon2 should never fail with valid params and salt.");
+
+        let key = Exactly32Bytes::from(&okm);
+        okm.zeroize();
+        key
+    }
+}
+
+impl HasSampleValues for PbArgon2id {
+    fn sample() -> Self {
+        Self::new(
+            Exactly16Bytes::sample(),
+            Self::DEFAULT_MEMORY_COST,
+            Self::DEFAULT_ITERATIONS,
+            Self::DEFAULT_PARALLELISM,
+        )
+        .unwrap()
+    }
+
+    fn sample_other() -> Self {
+        Self::new(
+            Exactly16Bytes::from_str("00112233445566778899aabbccddeeff")
+                .unwrap(),
+            Self::DEFAULT_MEMORY_COST,
+            Self::DEFAULT_ITERATIONS,
+            Self::DEFAULT_PARALLELISM,
+        )
+        .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = PbArgon2id;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn random_salt() {
+        assert_ne!(SUT::default(), SUT::default());
+    }
+
+    #[test]
+    fn invalid_params() {
+        assert!(matches!(
+            SUT::new(Exactly16Bytes::sample(), 1, 1, 1),
+            Err(
+                CommonError::InvalidPasswordBasedKeyDerivationParameters { .. }
+            )
+        ));
+        assert!(matches!(
+            SUT::new(Exactly16Bytes::sample(), 19456, 0, 1),
+            Err(
+                CommonError::InvalidPasswordBasedKeyDerivationParameters { .. }
+            )
+        ));
+    }
+
+    #[test]
+    fn params_above_max_are_err() {
+        let test = |m: u32, t: u32, p: u32| {
+            assert!(matches!(
+                SUT::new(Exactly16Bytes::sample(), m, t, p),
+                Err(
+                    CommonError::InvalidPasswordBasedKeyDerivationParameters { .. }
+                )
+            ));
+        };
+        test(SUT::MAX_MEMORY_COST + 1, 2, 1);
+        test(u32::MAX, 2, 1);
+        test(19456, SUT::MAX_ITERATIONS + 1, 1);
+        test(19456, 2, SUT::MAX_PARALLELISM + 1);
+    }
+
+    #[test]
+    fn params_at_max_are_ok() {
+        assert!(SUT::new(
+            Exactly16Bytes::sample(),
+            SUT::MAX_MEMORY_COST,
+            SUT::MAX_ITERATIONS,
+            SUT::MAX_PARALLELISM,
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn kdf() {
+        let test = |sut: SUT, pwd: &str, exp: &str| {
+            let key = sut.kdf(pwd);
+            assert_eq!(key.to_hex(), exp);
+        };
+        test(
+            SUT::sample(),
+            "Radix Rules!",
+            "beb070262ae52820f4b7a56342142c957a2249dd99b7a3c61f1e370a0b142306",
+        );
+        test(
+            SUT::sample(),
+            "",
+            "3c2b2d50de147da5ec9b67f760aced0a162db098c7f3720c7189233cdbb982aa",
+        );
+        test(
+            SUT::sample_other(),
+            "babylon",
+            "96a2b9d73367964ddfee599ff3181aa394a1ee9487cae8bd0eabd92a17fba9c0",
+        );
+    }
+}
@@ -0,0 +1,403 @@
+use crate::prelude::*;
+
+/// Helps the user entering a mnemonic phrase word by word, e.g. when
+/// importing a seed phrase written down on paper.
+///
+/// * `autocomplete` finds the words of the word list starting with what the
+///   user has typed so far.
+/// * `suggestions` finds the words closest to an unknown word, e.g.
+///   "abandn" -> "abandon".
+/// * `checksum_repairs` finds valid mnemonics one typo away from a phrase
+///   consisting of known words but with an invalid checksum, i.e. having a
+///   single word replaced or two adjacent words swapped.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MnemonicInputAssistant {
+    pub language: BIP39Language,
+    wordlist: Vec<BIP39Word>,
+}
+
+impl Default for MnemonicInputAssistant {
+    fn default() -> Self {
+        Self::new(BIP39Language::default())
+    }
+}
+
+impl MnemonicInputAssistant {
+    /// The maximum edit distance between an unknown word and the words
+    /// returned by `suggestions`.
+    pub const MAX_SUGGESTION_EDIT_DISTANCE: usize = 2;
+
+    pub fn new(language: BIP39Language) -> Self {
+        Self {
+            language,
+            wordlist: language.wordlist(),
+        }
+    }
+
+    /// The words of the word list starting with `prefix`, in word list
+    /// order. Returns no words for an empty `prefix`.
+    pub fn autocomplete(&self, prefix: impl AsRef<str>) -> Vec<BIP39Word> {
+        let prefix = normalized(prefix);
+        if prefix.is_empty() {
+            return Vec::new();
+        }
+        self.wordlist
+            .iter()
+            .filter(|w| w.word.starts_with(&prefix))
+            .cloned()
+            .collect()
+    }
+
+    /// The words of the word list at most `MAX_SUGGESTION_EDIT_DISTANCE`
+    /// edits away from `word`, closest first. Substitutions, insertions,
+    /// deletions and transpositions of two adjacent characters count as one
+    /// edit each.
+    ///
+    /// If `word` is in the word list, it is the only suggestion.
+    pub fn suggestions(&self, word: impl AsRef<str>) -> Vec<BIP39Word> {
+        let word = normalized(word);
+        if word.is_empty() {
+            return Vec::new();
+        }
+        if let Ok(known) = BIP39Word::new(&word, self.language) {
+            return vec![known];
+        }
+        self.wordlist
+            .iter()
+            .filter_map(|candidate| {
+                let distance = edit_distance(&word, &candidate.word);
+                (distance <= Self::MAX_SUGGESTION_EDIT_DISTANCE)
+                    .then_some((distance, candidate))
+            })
+            .sorted_by_key(|(distance, candidate)| {
+                (*distance, candidate.index.inner)
+            })
+            .map(|(_, candidate)| candidate.clone())
+            .collect()
+    }
+
+    /// Valid mnemonics found by replacing a single word of `words`, or by
+    /// swapping two adjacent words, for `words` failing the checksum.
+    ///
+    /// If `words` already form a valid mnemonic, it is the only one returned.
+    ///
+    /// Several mnemonics are typically found, use
+    /// `checksum_repair_matching` if the mnemonic is known to belong to a
+    /// specific factor source.
+    pub fn checksum_repairs(
+        &self,
+        words: &[BIP39Word],
+    ) -> Result<Vec<Mnemonic>> {
+        self.validate(words)?;
+        Ok(self.candidates(words).collect())
+    }
+
+    /// The first mnemonic found by `checksum_repairs` for which `is_match`
+    /// returns `true`, e.g. having the `FactorSourceIDFromHash` of the
+    /// factor source being recovered.
+    pub fn checksum_repair_matching(
+        &self,
+        words: &[BIP39Word],
+        is_match: impl Fn(&Mnemonic) -> bool,
+    ) -> Result<Option<Mnemonic>> {
+        self.validate(words)?;
+        Ok(self.candidates(words).find(|mnemonic| is_match(mnemonic)))
+    }
+
+    fn validate(&self, words: &[BIP39Word]) -> Result<()> {
+        BIP39WordCount::from_count(words.len())?;
+        if words.iter().any(|w| w.language != self.language) {
+            return Err(CommonError::InvalidMnemonicPhrase);
+        }
+        Ok(())
+    }
+
+    fn candidates<'a>(
+        &'a self,
+        words: &'a [BIP39Word],
+    ) -> impl Iterator<Item = Mnemonic> + 'a {
+        let valid = self.mnemonic(words.to_vec());
+        let swaps = (1..words.len())
+            .filter(move |&i| words[i - 1] != words[i])
+            .map(move |i| {
+                let mut candidate = words.to_vec();
+                candidate.swap(i - 1, i);
+                candidate
+            });
+        let substitutions = (0..words.len()).flat_map(move |i| {
+            self.wordlist
+                .iter()
+                .filter(move |replacement| **replacement != words[i])
+                .map(move |replacement| {
+                    let mut candidate = words.to_vec();
+                    candidate[i] = replacement.clone();
+                    candidate
+                })
+        });
+
+        let repairs = valid
+            .is_none()
+            .then(move || {
+                swaps
+                    .chain(substitutions)
+                    .filter_map(move |candidate| self.mnemonic(candidate))
+            })
+            .into_iter()
+            .flatten();
+
+        valid.into_iter().chain(repairs)
+    }
+
+    fn mnemonic(&self, words: Vec<BIP39Word>) -> Option<Mnemonic> {
+        let phrase = words.iter().map(|w| w.word.as_str()).join(" ");
+        Mnemonic::from(&phrase, self.language).ok()
+    }
+}
+
+fn normalized(word: impl AsRef<str>) -> String {
+    word.as_ref().trim().to_lowercase()
+}
+
+/// The optimal string alignment distance between `lhs` and `rhs`, i.e. the
+/// Levenshtein distance also counting a transposition of two adjacent
+/// characters as a single edit.
+fn edit_distance(lhs: &str, rhs: &str) -> usize {
+    let lhs = lhs.chars().collect_vec();
+    let rhs = rhs.chars().collect_vec();
+    let mut distances = vec![vec![0; rhs.len() + 1]; lhs.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+    for i in 1..=lhs.len() {
+        for j in 1..=rhs.len() {
+            let cost = usize::from(lhs[i - 1] != rhs[j - 1]);
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+            if i > 1
+                && j > 1
+                && lhs[i - 1] == rhs[j - 2]
+                && lhs[i - 2] == rhs[j - 1]
+            {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+    distances[lhs.len()][rhs.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = MnemonicInputAssistant;
+
+    fn words(phrase: &str) -> Vec<BIP39Word> {
+        phrase
+            .split(' ')
+            .map(|w| BIP39Word::english(w).unwrap())
+            .collect()
+    }
+
+    fn phrases(mnemonics: Vec<Mnemonic>) -> Vec<String> {
+        mnemonics.into_iter().map(|m| m.phrase()).collect()
+    }
+
+    /// `mnemonic` with the word at `index` replaced by the last word of the
+    /// word list failing the checksum.
+    fn mistyped(
+        sut: &SUT,
+        mnemonic: &Mnemonic,
+        index: usize,
+    ) -> Vec<BIP39Word> {
+        sut.wordlist
+            .iter()
+            .rev()
+            .map(|replacement| {
+                let mut words = mnemonic.words.clone();
+                words[index] = replacement.clone();
+                words
+            })
+            .find(|words| sut.mnemonic(words.clone()).is_none())
+            .unwrap()
+    }
+
+    /// `mnemonic` with the first two adjacent words failing the checksum
+    /// when swapped, swapped.
+    fn swapped(sut: &SUT, mnemonic: &Mnemonic) -> Vec<BIP39Word> {
+        (1..mnemonic.words.len())
+            .map(|i| {
+                let mut words = mnemonic.words.clone();
+                words.swap(i - 1, i);
+                words
+            })
+            .find(|words| sut.mnemonic(words.clone()).is_none())
+            .unwrap()
+    }
+
+    #[test]
+    fn edit_distances() {
+        assert_eq!(edit_distance("abandon", "abandon"), 0);
+        assert_eq!(edit_distance("abandn", "abandon"), 1);
+        assert_eq!(edit_distance("abadnon", "abandon"), 1);
+        assert_eq!(edit_distance("abxndon", "abandon"), 1);
+        assert_eq!(edit_distance("", "zoo"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn autocomplete() {
+        assert_eq!(
+            SUT::default()
+                .autocomplete("abl")
+                .into_iter()
+                .map(|w| w.word)
+                .collect_vec(),
+            vec!["able"]
+        );
+        assert_eq!(
+            SUT::default()
+                .autocomplete("zo")
+                .into_iter()
+                .map(|w| w.word)
+                .collect_vec(),
+            vec!["zone", "zoo"]
+        );
+    }
+
+    #[test]
+    fn autocomplete_is_case_insensitive_and_trims() {
+        assert_eq!(
+            SUT::default().autocomplete(" ZOO "),
+            vec![BIP39Word::english("zoo").unwrap()]
+        );
+    }
+
+    #[test]
+    fn autocomplete_empty_prefix() {
+        assert!(SUT::default().autocomplete("").is_empty());
+    }
+
+    #[test]
+    fn autocomplete_unknown_prefix() {
+        assert!(SUT::default().autocomplete("xyz").is_empty());
+    }
+
+    #[test]
+    fn autocomplete_french() {
+        let sut = SUT::new(BIP39Language::French);
+        let completions = sut.autocomplete("aba");
+        assert!(!completions.is_empty());
+        assert!(completions
+            .iter()
+            .all(|w| w.language == BIP39Language::French));
+    }
+
+    #[test]
+    fn suggestions_of_known_word() {
+        assert_eq!(
+            SUT::default().suggestions("zoo"),
+            vec![BIP39Word::english("zoo").unwrap()]
+        );
+    }
+
+    #[test]
+    fn suggestions_closest_first() {
+        let suggestions = SUT::default().suggestions("abandn");
+        assert_eq!(
+            suggestions.first().unwrap(),
+            &BIP39Word::english("abandon").unwrap()
+        );
+    }
+
+    #[test]
+    fn suggestions_of_transposition() {
+        let suggestions = SUT::default().suggestions("ozo");
+        assert_eq!(
+            suggestions.first().unwrap(),
+            &BIP39Word::english("zoo").unwrap()
+        );
+    }
+
+    #[test]
+    fn suggestions_of_word_far_from_all_words() {
+        assert!(SUT::default().suggestions("qqqqqqqqqq").is_empty());
+    }
+
+    #[test]
+    fn checksum_repairs_of_valid_mnemonic() {
+        let mnemonic = Mnemonic::sample();
+        assert_eq!(
+            SUT::default().checksum_repairs(&mnemonic.words),
+            Ok(vec![mnemonic])
+        );
+    }
+
+    #[test]
+    fn checksum_repairs_of_swapped_words() {
+        let sut = SUT::default();
+        let mnemonic = Mnemonic::sample_device_12_words();
+        let swapped = swapped(&sut, &mnemonic);
+
+        let repairs = sut.checksum_repairs(&swapped).unwrap();
+
+        assert!(phrases(repairs).contains(&mnemonic.phrase()));
+    }
+
+    #[test]
+    fn checksum_repairs_of_replaced_word() {
+        let sut = SUT::default();
+        let mnemonic = Mnemonic::sample_device_12_words();
+        let typo = mistyped(&sut, &mnemonic, 5);
+
+        let repairs = sut.checksum_repairs(&typo).unwrap();
+
+        assert!(repairs.len() > 1);
+        assert!(phrases(repairs).contains(&mnemonic.phrase()));
+    }
+
+    #[test]
+    fn checksum_repair_matching() {
+        let sut = SUT::default();
+        let mnemonic = Mnemonic::sample_device();
+        let typo = mistyped(&sut, &mnemonic, 0);
+
+        let repair = sut
+            .checksum_repair_matching(&typo, |candidate| {
+                candidate.words[0] == mnemonic.words[0]
+            })
+            .unwrap();
+
+        assert_eq!(repair, Some(mnemonic));
+    }
+
+    #[test]
+    fn checksum_repair_matching_none() {
+        let sut = SUT::default();
+        let typo = mistyped(&sut, &Mnemonic::sample_device_12_words(), 0);
+
+        assert_eq!(sut.checksum_repair_matching(&typo, |_| false), Ok(None));
+    }
+
+    #[test]
+    fn checksum_repairs_invalid_word_count() {
+        assert_eq!(
+            SUT::default().checksum_repairs(&words("zoo zoo zoo")),
+            Err(CommonError::InvalidBIP39WordCount { bad_value: 3 })
+        );
+    }
+
+    #[test]
+    fn checksum_repairs_wrong_language() {
+        let sut = SUT::new(BIP39Language::French);
+        assert_eq!(
+            sut.checksum_repairs(&Mnemonic::sample_device_12_words().words),
+            Err(CommonError::InvalidMnemonicPhrase)
+        );
+    }
+}
@@ -4,6 +4,7 @@ mod bip39_seed;
 mod bip39_word;
 mod bip39_word_count;
 mod mnemonic;
+mod mnemonic_input_assistant;
 
 pub use bip39_entropy::*;
 pub use bip39_passphrase::*;
@@ -11,3 +12,4 @@ pub use bip39_seed::*;
 pub use bip39_word::*;
 pub use bip39_word_count::*;
 pub use mnemonic::*;
+pub use mnemonic_input_assistant::*;
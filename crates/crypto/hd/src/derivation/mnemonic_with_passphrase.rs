@@ -101,6 +101,19 @@ impl MnemonicWithPassphrase {
         signature
     }
 
+    /// Derives the private key at `derivation_path`, used for key agreement,
+    /// e.g. to decrypt a transaction message encrypted for the public key at
+    /// that path.
+    pub fn derive_private_key(
+        &self,
+        derivation_path: &DerivationPath,
+    ) -> PrivateKey {
+        let mut bip39_seed = self.to_seed();
+        let private_key = bip39_seed.derive_private_key(derivation_path);
+        bip39_seed.zeroize();
+        private_key.private_key
+    }
+
     /// Returns `true` if this MnemonicWithPassphrase successfully validates all `hd_keys`, that is to say,
     /// that all the HierarchicalDeterministicPublicKey were indeed crated by this MnemonicWithPassphrase.
     pub fn validate_public_keys(
@@ -313,6 +326,17 @@ mod tests {
         assert!(signature.is_valid_for_hash(&msg));
     }
 
+    #[test]
+    fn derive_private_key() {
+        let sut = SUT::sample();
+        let path = DerivationPath::sample();
+        let private_key = sut.derive_private_key(&path);
+        assert_eq!(
+            private_key.public_key(),
+            sut.derive_public_keys([path])[0].public_key
+        );
+    }
+
     #[test]
     fn validation_cap26_account_paths_fail_wrong_mnemonic() {
         let sut = SUT::sample();
@@ -5,6 +5,7 @@ mod bip39;
 mod bip44;
 mod cap26;
 mod derivation;
+mod slip39;
 
 mod has_key_kind;
 
@@ -23,6 +24,7 @@ pub mod prelude {
     pub use crate::cap26::*;
     pub use crate::derivation::*;
     pub use crate::has_key_kind::*;
+    pub use crate::slip39::*;
 
     pub(crate) use enum_as_inner::EnumAsInner;
     pub(crate) use serde::{Deserialize, Serialize};
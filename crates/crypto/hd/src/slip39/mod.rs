@@ -0,0 +1,15 @@
+mod slip39_checksum;
+mod slip39_cipher;
+mod slip39_group;
+mod slip39_shamir;
+mod slip39_share;
+mod slip39_shares;
+mod slip39_wordlist;
+
+pub(crate) use slip39_checksum::*;
+pub(crate) use slip39_cipher::*;
+pub use slip39_group::*;
+pub(crate) use slip39_shamir::*;
+pub use slip39_share::*;
+pub use slip39_shares::*;
+pub(crate) use slip39_wordlist::*;
@@ -0,0 +1,96 @@
+/// The number of words of the RS1024 checksum at the end of a SLIP39 share.
+pub(crate) const SLIP39_CHECKSUM_WORD_COUNT: usize = 3;
+
+const GENERATOR: [u32; 10] = [
+    0xE0E040, 0x1C1C080, 0x3838100, 0x7070200, 0xE0E0009, 0x1C0C2412,
+    0x38086C24, 0x3090FC48, 0x21B1F890, 0x3F3F120,
+];
+
+/// The customization string of the checksum, which depends on whether the
+/// share is extendable or not.
+fn customization_string(is_extendable: bool) -> &'static [u8] {
+    if is_extendable {
+        b"shamir_extendable"
+    } else {
+        b"shamir"
+    }
+}
+
+fn polymod(values: impl IntoIterator<Item = u32>) -> u32 {
+    values.into_iter().fold(1, |checksum, value| {
+        let top = checksum >> 20;
+        let checksum = ((checksum & 0xFFFFF) << 10) ^ value;
+        GENERATOR
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| (top >> i) & 1 == 1)
+            .fold(checksum, |checksum, (_, generator)| checksum ^ generator)
+    })
+}
+
+fn customized(
+    is_extendable: bool,
+    word_indices: &[u16],
+) -> impl Iterator<Item = u32> + '_ {
+    customization_string(is_extendable)
+        .iter()
+        .map(|b| *b as u32)
+        .chain(word_indices.iter().map(|i| *i as u32))
+}
+
+/// The RS1024 checksum words of `word_indices`, as specified by SLIP39.
+pub(crate) fn slip39_checksum(
+    is_extendable: bool,
+    word_indices: &[u16],
+) -> [u16; SLIP39_CHECKSUM_WORD_COUNT] {
+    let checksum = polymod(
+        customized(is_extendable, word_indices)
+            .chain([0; SLIP39_CHECKSUM_WORD_COUNT]),
+    ) ^ 1;
+    std::array::from_fn(|i| {
+        ((checksum >> (10 * (SLIP39_CHECKSUM_WORD_COUNT - 1 - i))) & 1023)
+            as u16
+    })
+}
+
+/// Whether `word_indices`, ending with the checksum words, have a valid
+/// RS1024 checksum.
+pub(crate) fn slip39_is_checksum_valid(
+    is_extendable: bool,
+    word_indices: &[u16],
+) -> bool {
+    polymod(customized(is_extendable, word_indices)) == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksum_roundtrip() {
+        let data = [1u16, 2, 3, 1000, 1023, 0];
+        for is_extendable in [false, true] {
+            let checksum = slip39_checksum(is_extendable, &data);
+            let word_indices = data
+                .iter()
+                .chain(checksum.iter())
+                .cloned()
+                .collect::<Vec<_>>();
+            assert!(slip39_is_checksum_valid(is_extendable, &word_indices));
+            assert!(!slip39_is_checksum_valid(!is_extendable, &word_indices));
+        }
+    }
+
+    #[test]
+    fn single_word_change_invalidates_checksum() {
+        let data = [1u16, 2, 3, 1000, 1023, 0];
+        let checksum = slip39_checksum(false, &data);
+        let mut word_indices = data
+            .iter()
+            .chain(checksum.iter())
+            .cloned()
+            .collect::<Vec<_>>();
+        word_indices[2] = 4;
+        assert!(!slip39_is_checksum_valid(false, &word_indices));
+    }
+}
@@ -0,0 +1,143 @@
+use crate::prelude::*;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// The total number of PBKDF2 iterations of the Feistel cipher for
+/// iteration exponent `0`, doubled for every increment of the exponent.
+const BASE_ITERATION_COUNT: u32 = 10000;
+
+/// The number of rounds of the Feistel cipher.
+const ROUND_COUNT: u8 = 4;
+
+fn pbkdf2_hmac_sha256(
+    password: &[u8],
+    salt: &[u8],
+    iterations: u32,
+    length: usize,
+) -> Vec<u8> {
+    let prf = Hmac::<Sha256>::new_from_slice(password)
+        .expect("HMAC accepts keys of any length");
+    let mut output = Vec::with_capacity(length);
+    for block_index in 1..=(length.div_ceil(32) as u32) {
+        let mut mac = prf.clone();
+        mac.update(salt);
+        mac.update(&block_index.to_be_bytes());
+        let mut u: [u8; 32] = mac.finalize().into_bytes().into();
+        let mut block = u;
+        for _ in 1..iterations {
+            let mut mac = prf.clone();
+            mac.update(&u);
+            u = mac.finalize().into_bytes().into();
+            block.iter_mut().zip(u.iter()).for_each(|(b, u)| *b ^= u);
+        }
+        output.extend_from_slice(&block);
+    }
+    output.truncate(length);
+    output
+}
+
+/// The parameters of the Feistel cipher encrypting the master secret,
+/// shared by all shares of the same master secret.
+pub(crate) struct SLIP39Cipher<'a> {
+    passphrase: &'a [u8],
+    iteration_exponent: u8,
+    salt: Vec<u8>,
+}
+
+impl<'a> SLIP39Cipher<'a> {
+    pub(crate) fn new(
+        passphrase: &'a [u8],
+        iteration_exponent: u8,
+        identifier: u16,
+        is_extendable: bool,
+    ) -> Self {
+        let salt = if is_extendable {
+            Vec::new()
+        } else {
+            [b"shamir".as_slice(), &identifier.to_be_bytes()].concat()
+        };
+        Self {
+            passphrase,
+            iteration_exponent,
+            salt,
+        }
+    }
+
+    fn round_function(&self, round: u8, right: &[u8]) -> Vec<u8> {
+        let password = [&[round], self.passphrase].concat();
+        let salt = [self.salt.as_slice(), right].concat();
+        pbkdf2_hmac_sha256(
+            &password,
+            &salt,
+            (BASE_ITERATION_COUNT << self.iteration_exponent)
+                / ROUND_COUNT as u32,
+            right.len(),
+        )
+    }
+
+    fn feistel(
+        &self,
+        input: &[u8],
+        rounds: impl Iterator<Item = u8>,
+    ) -> Vec<u8> {
+        let (left, right) = input.split_at(input.len() / 2);
+        let (mut left, mut right) = (left.to_vec(), right.to_vec());
+        for round in rounds {
+            let f = self.round_function(round, &right);
+            let new_right = left
+                .iter()
+                .zip(f.iter())
+                .map(|(l, f)| l ^ f)
+                .collect::<Vec<_>>();
+            left = std::mem::replace(&mut right, new_right);
+        }
+        [right, left].concat()
+    }
+
+    /// Encrypts `master_secret` into the encrypted master secret.
+    pub(crate) fn encrypt(&self, master_secret: &[u8]) -> Vec<u8> {
+        self.feistel(master_secret, 0..ROUND_COUNT)
+    }
+
+    /// Decrypts `encrypted_master_secret` into the master secret.
+    pub(crate) fn decrypt(&self, encrypted_master_secret: &[u8]) -> Vec<u8> {
+        self.feistel(encrypted_master_secret, (0..ROUND_COUNT).rev())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pbkdf2_rfc7914_vector() {
+        assert_eq!(
+            hex_encode(pbkdf2_hmac_sha256(b"passwd", b"salt", 1, 64)),
+            "55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc49ca9cccf179b645991664b39d77ef317c71b845b1e30bd509112041d3a19783"
+        );
+    }
+
+    #[test]
+    fn encrypt_decrypt_roundtrip() {
+        let master_secret =
+            hex_decode("bb54aac4b89dc868ba37d9cc21b2cece").unwrap();
+        for is_extendable in [false, true] {
+            let sut = SLIP39Cipher::new(b"TREZOR", 0, 7945, is_extendable);
+            let encrypted = sut.encrypt(&master_secret);
+            assert_ne!(encrypted, master_secret);
+            assert_eq!(sut.decrypt(&encrypted), master_secret);
+        }
+    }
+
+    #[test]
+    fn wrong_passphrase_decrypts_to_other_secret() {
+        let master_secret =
+            hex_decode("bb54aac4b89dc868ba37d9cc21b2cece").unwrap();
+        let encrypted = SLIP39Cipher::new(b"TREZOR", 0, 7945, false)
+            .encrypt(&master_secret);
+        assert_ne!(
+            SLIP39Cipher::new(b"", 0, 7945, false).decrypt(&encrypted),
+            master_secret
+        );
+    }
+}
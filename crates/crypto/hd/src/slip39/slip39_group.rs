@@ -0,0 +1,90 @@
+use crate::prelude::*;
+
+/// The configuration of one group of SLIP39 shares, `member_threshold` of
+/// its `member_count` shares being required to recover the group secret.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SLIP39Group {
+    pub member_threshold: u8,
+    pub member_count: u8,
+}
+
+impl SLIP39Group {
+    pub fn new(member_threshold: u8, member_count: u8) -> Self {
+        Self {
+            member_threshold,
+            member_count,
+        }
+    }
+
+    pub(crate) fn validate(&self) -> Result<()> {
+        if self.member_threshold == 0
+            || self.member_threshold > self.member_count
+            || self.member_count > 16
+        {
+            return Err(CommonError::InvalidSLIP39Parameters {
+                reason: format!(
+                    "invalid member threshold {} of {} members",
+                    self.member_threshold, self.member_count
+                ),
+            });
+        }
+        if self.member_threshold == 1 && self.member_count > 1 {
+            return Err(CommonError::InvalidSLIP39Parameters {
+                reason: "use 1-of-1 instead of 1-of-N member sharing"
+                    .to_owned(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl HasSampleValues for SLIP39Group {
+    fn sample() -> Self {
+        Self::new(2, 3)
+    }
+
+    fn sample_other() -> Self {
+        Self::new(1, 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = SLIP39Group;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn valid() {
+        assert!(SUT::sample().validate().is_ok());
+        assert!(SUT::sample_other().validate().is_ok());
+        assert!(SUT::new(16, 16).validate().is_ok());
+    }
+
+    #[test]
+    fn invalid() {
+        for sut in [
+            SUT::new(0, 1),
+            SUT::new(3, 2),
+            SUT::new(2, 17),
+            SUT::new(1, 3),
+        ] {
+            assert!(matches!(
+                sut.validate(),
+                Err(CommonError::InvalidSLIP39Parameters { .. })
+            ));
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use crate::prelude::*;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// The x-coordinate of the share holding the digest of the secret.
+const DIGEST_INDEX: u8 = 254;
+
+/// The x-coordinate of the share holding the secret.
+const SECRET_INDEX: u8 = 255;
+
+/// The number of bytes of the digest of the secret.
+const DIGEST_LENGTH: usize = 4;
+
+/// The exponentiation and logarithm tables of GF(256) with the Rijndael
+/// polynomial `x^8 + x^4 + x^3 + x + 1`, using `x + 1` as generator.
+const fn exp_and_log_tables() -> ([u8; 255], [u8; 256]) {
+    let mut exp = [0u8; 255];
+    let mut log = [0u8; 256];
+    let mut poly: u16 = 1;
+    let mut i = 0;
+    while i < 255 {
+        exp[i] = poly as u8;
+        log[poly as usize] = i as u8;
+        // Multiply `poly` by the generator `x + 1`.
+        poly = (poly << 1) ^ poly;
+        if poly & 0x100 != 0 {
+            poly ^= 0x11B;
+        }
+        i += 1;
+    }
+    (exp, log)
+}
+
+const EXP_AND_LOG: ([u8; 255], [u8; 256]) = exp_and_log_tables();
+
+fn exp(power: usize) -> u8 {
+    EXP_AND_LOG.0[power % 255]
+}
+
+fn log(value: u8) -> usize {
+    EXP_AND_LOG.1[value as usize] as usize
+}
+
+/// A point `(x, y)` of the polynomials used to share a secret, `y` being
+/// one byte of each polynomial per byte of the secret.
+#[derive(Zeroize, Clone, PartialEq, Eq)]
+pub(crate) struct SLIP39RawShare {
+    pub(crate) x: u8,
+    pub(crate) y: Vec<u8>,
+}
+
+impl SLIP39RawShare {
+    pub(crate) fn new(x: u8, y: Vec<u8>) -> Self {
+        Self { x, y }
+    }
+}
+
+/// The value at `x` of the polynomials through `shares` using Lagrange
+/// interpolation in GF(256).
+fn interpolate(shares: &[SLIP39RawShare], x: u8) -> Vec<u8> {
+    if let Some(share) = shares.iter().find(|s| s.x == x) {
+        return share.y.clone();
+    }
+
+    let log_product: usize = shares.iter().map(|s| log(s.x ^ x)).sum();
+    let mut result = vec![0u8; shares[0].y.len()];
+    for share in shares {
+        let log_denominator: usize = log(share.x ^ x)
+            + shares
+                .iter()
+                .filter(|other| other.x != share.x)
+                .map(|other| log(other.x ^ share.x))
+                .sum::<usize>();
+        let log_basis =
+            (log_product + 255 * shares.len() - log_denominator) % 255;
+        for (r, y) in result.iter_mut().zip(share.y.iter()) {
+            if *y != 0 {
+                *r ^= exp(log(*y) + log_basis);
+            }
+        }
+    }
+    result
+}
+
+fn digest(random_part: &[u8], secret: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(random_part)
+        .expect("HMAC accepts keys of any length");
+    mac.update(secret);
+    mac.finalize().into_bytes()[..DIGEST_LENGTH].to_vec()
+}
+
+/// Random bytes of `length` using a cryptographically secure random
+/// generator.
+pub(crate) fn slip39_random_bytes(length: usize) -> Vec<u8> {
+    std::iter::repeat_with(generate_32_bytes)
+        .flatten()
+        .take(length)
+        .collect()
+}
+
+/// Splits `secret` into `share_count` shares, any `threshold` of which
+/// recover it, as specified by SLIP39.
+pub(crate) fn slip39_split_secret(
+    threshold: u8,
+    share_count: u8,
+    secret: &[u8],
+) -> Result<Vec<SLIP39RawShare>> {
+    if threshold == 0 || threshold > share_count {
+        return Err(CommonError::InvalidSLIP39Parameters {
+            reason: format!(
+                "threshold {} must be between 1 and the share count {}",
+                threshold, share_count
+            ),
+        });
+    }
+    if share_count > 16 {
+        return Err(CommonError::InvalidSLIP39Parameters {
+            reason: format!("share count {} exceeds 16", share_count),
+        });
+    }
+
+    if threshold == 1 {
+        return Ok((0..share_count)
+            .map(|x| SLIP39RawShare::new(x, secret.to_vec()))
+            .collect());
+    }
+
+    let random_share_count = threshold - 2;
+    let mut shares = (0..random_share_count)
+        .map(|x| SLIP39RawShare::new(x, slip39_random_bytes(secret.len())))
+        .collect::<Vec<_>>();
+
+    let random_part = slip39_random_bytes(secret.len() - DIGEST_LENGTH);
+    let mut digest_share = digest(&random_part, secret);
+    digest_share.extend(random_part);
+
+    let mut base_shares = shares.clone();
+    base_shares.push(SLIP39RawShare::new(DIGEST_INDEX, digest_share));
+    base_shares.push(SLIP39RawShare::new(SECRET_INDEX, secret.to_vec()));
+
+    shares.extend(
+        (random_share_count..share_count)
+            .map(|x| SLIP39RawShare::new(x, interpolate(&base_shares, x))),
+    );
+    base_shares.zeroize();
+    Ok(shares)
+}
+
+/// Recovers the secret from `threshold` shares created by
+/// `slip39_split_secret`, verifying its digest.
+pub(crate) fn slip39_recover_secret(
+    threshold: u8,
+    shares: &[SLIP39RawShare],
+) -> Result<Vec<u8>> {
+    if shares.len() < threshold as usize || shares.is_empty() {
+        return Err(CommonError::InsufficientSLIP39Shares {
+            reason: format!(
+                "{} of {} shares provided",
+                shares.len(),
+                threshold
+            ),
+        });
+    }
+    if threshold == 1 {
+        return Ok(shares[0].y.clone());
+    }
+
+    let shares = &shares[..threshold as usize];
+    let secret = interpolate(shares, SECRET_INDEX);
+    let digest_share = interpolate(shares, DIGEST_INDEX);
+    let (expected_digest, random_part) = digest_share.split_at(DIGEST_LENGTH);
+    if digest(random_part, &secret) != expected_digest {
+        return Err(CommonError::SLIP39ShareDigestMismatch);
+    }
+    Ok(secret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exp_log_roundtrip() {
+        for value in 1..=255u8 {
+            assert_eq!(exp(log(value)), value);
+        }
+    }
+
+    #[test]
+    fn split_and_recover_any_threshold_subset() {
+        let secret = hex_decode("bb54aac4b89dc868ba37d9cc21b2cece").unwrap();
+        let shares = slip39_split_secret(3, 5, &secret).unwrap();
+        assert_eq!(shares.len(), 5);
+        for (a, b, c) in [(0, 1, 2), (4, 2, 0), (1, 3, 4)] {
+            let subset =
+                [shares[a].clone(), shares[b].clone(), shares[c].clone()];
+            assert_eq!(slip39_recover_secret(3, &subset), Ok(secret.clone()));
+        }
+    }
+
+    #[test]
+    fn threshold_one_shares_are_the_secret() {
+        let secret = vec![0xde; 16];
+        let shares = slip39_split_secret(1, 3, &secret).unwrap();
+        assert!(shares.iter().all(|s| s.y == secret));
+        assert_eq!(slip39_recover_secret(1, &shares[2..]), Ok(secret));
+    }
+
+    #[test]
+    fn insufficient_shares() {
+        let shares = slip39_split_secret(2, 3, &[0xde; 16]).unwrap();
+        assert!(matches!(
+            slip39_recover_secret(2, &shares[..1]),
+            Err(CommonError::InsufficientSLIP39Shares { .. })
+        ));
+    }
+
+    #[test]
+    fn tampered_share_fails_digest() {
+        let mut shares = slip39_split_secret(2, 3, &[0xde; 16]).unwrap();
+        shares[0].y[0] ^= 1;
+        assert_eq!(
+            slip39_recover_secret(2, &shares),
+            Err(CommonError::SLIP39ShareDigestMismatch)
+        );
+    }
+
+    #[test]
+    fn invalid_threshold() {
+        assert!(matches!(
+            slip39_split_secret(4, 3, &[0xde; 16]),
+            Err(CommonError::InvalidSLIP39Parameters { .. })
+        ));
+        assert!(matches!(
+            slip39_split_secret(0, 3, &[0xde; 16]),
+            Err(CommonError::InvalidSLIP39Parameters { .. })
+        ));
+        assert!(matches!(
+            slip39_split_secret(2, 17, &[0xde; 16]),
+            Err(CommonError::InvalidSLIP39Parameters { .. })
+        ));
+    }
+}
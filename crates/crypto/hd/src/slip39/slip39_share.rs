@@ -0,0 +1,353 @@
+use crate::prelude::*;
+
+/// The number of bits of each word of a SLIP39 share.
+const RADIX_BITS: usize = 10;
+
+/// The number of words encoding the identifier, extendable flag, iteration
+/// exponent, group and member parameters of a share.
+const METADATA_WORD_COUNT: usize = 4;
+
+/// The minimum number of words of a share, for a 128 bits master secret.
+const MIN_WORD_COUNT: usize = 20;
+
+/// One share of a master secret split using SLIP39 Shamir's Secret-Sharing,
+/// represented to the user as a mnemonic of 20 or 33 words.
+///
+/// All shares of the same master secret have the same `identifier`,
+/// `is_extendable`, `iteration_exponent`, `group_threshold` and
+/// `group_count`.
+#[derive(Zeroize, Clone, PartialEq, Eq, Hash, derive_more::Debug)]
+#[debug("{:?}", self.partially_obfuscated_string())]
+pub struct SLIP39Share {
+    /// Random 15 bit identifier shared by all shares of a master secret.
+    pub identifier: u16,
+
+    /// Whether the encryption of the master secret is independent of the
+    /// `identifier`, allowing shares to be added to an existing sharing.
+    pub is_extendable: bool,
+
+    /// The exponent of the number of PBKDF2 iterations used to encrypt the
+    /// master secret.
+    pub iteration_exponent: u8,
+
+    /// The index of the group of this share, `0..group_count`.
+    pub group_index: u8,
+
+    /// The number of groups required to recover the master secret.
+    pub group_threshold: u8,
+
+    /// The total number of groups.
+    pub group_count: u8,
+
+    /// The index of this share within its group.
+    pub member_index: u8,
+
+    /// The number of shares of the group required to recover the group
+    /// secret.
+    pub member_threshold: u8,
+
+    /// The share value, as many bytes as the master secret.
+    pub value: Vec<u8>,
+}
+
+impl SLIP39Share {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        identifier: u16,
+        is_extendable: bool,
+        iteration_exponent: u8,
+        group_index: u8,
+        group_threshold: u8,
+        group_count: u8,
+        member_index: u8,
+        member_threshold: u8,
+        value: Vec<u8>,
+    ) -> Self {
+        Self {
+            identifier,
+            is_extendable,
+            iteration_exponent,
+            group_index,
+            group_threshold,
+            group_count,
+            member_index,
+            member_threshold,
+            value,
+        }
+    }
+
+    pub fn partially_obfuscated_string(&self) -> String {
+        format!(
+            "SLIP39 share {} of group {} ({})",
+            self.member_index, self.group_index, self.identifier
+        )
+    }
+
+    /// Whether `self` and `other` are shares of the same master secret.
+    pub(crate) fn has_same_common_parameters(&self, other: &Self) -> bool {
+        self.identifier == other.identifier
+            && self.is_extendable == other.is_extendable
+            && self.iteration_exponent == other.iteration_exponent
+            && self.group_threshold == other.group_threshold
+            && self.group_count == other.group_count
+            && self.value.len() == other.value.len()
+    }
+
+    fn metadata_bits(&self) -> u64 {
+        (self.identifier as u64) << 25
+            | (self.is_extendable as u64) << 24
+            | (self.iteration_exponent as u64) << 20
+            | (self.group_index as u64) << 16
+            | ((self.group_threshold - 1) as u64) << 12
+            | ((self.group_count - 1) as u64) << 8
+            | (self.member_index as u64) << 4
+            | (self.member_threshold - 1) as u64
+    }
+
+    fn word_indices(&self) -> Vec<u16> {
+        let metadata = self.metadata_bits();
+        let mut word_indices = (0..METADATA_WORD_COUNT)
+            .rev()
+            .map(|i| ((metadata >> (i * RADIX_BITS)) & 1023) as u16)
+            .collect::<Vec<_>>();
+
+        // The value is left padded with zero bits to a multiple of 10 bits.
+        let value_bit_count = self.value.len() * 8;
+        let padding_bit_count =
+            value_bit_count.div_ceil(RADIX_BITS) * RADIX_BITS - value_bit_count;
+        let bits = std::iter::repeat(false).take(padding_bit_count).chain(
+            self.value.iter().flat_map(|byte| {
+                (0..8).rev().map(move |i| (byte >> i) & 1 == 1)
+            }),
+        );
+        word_indices.extend(bits.collect::<Vec<_>>().chunks(RADIX_BITS).map(
+            |chunk| {
+                chunk.iter().fold(0u16, |acc, bit| (acc << 1) | *bit as u16)
+            },
+        ));
+
+        let checksum = slip39_checksum(self.is_extendable, &word_indices);
+        word_indices.extend(checksum);
+        word_indices
+    }
+
+    /// The words of this share.
+    pub fn words(&self) -> Vec<&'static str> {
+        self.word_indices()
+            .into_iter()
+            .map(|i| SLIP39_WORDLIST[i as usize])
+            .collect()
+    }
+
+    /// The words of this share joined by spaces.
+    pub fn phrase(&self) -> String {
+        self.words().join(" ")
+    }
+
+    /// Parses a share from its words, validating its checksum and padding.
+    pub fn from_words(
+        words: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Result<Self> {
+        let word_indices = words
+            .into_iter()
+            .map(|w| slip39_word_index(w.as_ref().trim().to_lowercase()))
+            .collect::<Result<Vec<_>>>()?;
+        Self::from_word_indices(&word_indices)
+    }
+
+    /// Parses a share from its words separated by whitespace.
+    pub fn from_phrase(phrase: impl AsRef<str>) -> Result<Self> {
+        Self::from_words(phrase.as_ref().split_whitespace())
+    }
+
+    fn from_word_indices(word_indices: &[u16]) -> Result<Self> {
+        let invalid = |reason: &str| CommonError::InvalidSLIP39Share {
+            reason: reason.to_owned(),
+        };
+        if word_indices.len() < MIN_WORD_COUNT {
+            return Err(invalid("too few words"));
+        }
+
+        let metadata = word_indices[..METADATA_WORD_COUNT]
+            .iter()
+            .fold(0u64, |acc, i| (acc << RADIX_BITS) | *i as u64);
+        let is_extendable = (metadata >> 24) & 1 == 1;
+        if !slip39_is_checksum_valid(is_extendable, word_indices) {
+            return Err(invalid("invalid checksum"));
+        }
+
+        let value_words = &word_indices[METADATA_WORD_COUNT
+            ..word_indices.len() - SLIP39_CHECKSUM_WORD_COUNT];
+        let value_bit_count = value_words.len() * RADIX_BITS;
+        let padding_bit_count = value_bit_count % 16;
+        if padding_bit_count > 8 {
+            return Err(invalid("invalid word count"));
+        }
+        let bits = value_words
+            .iter()
+            .flat_map(|word| {
+                (0..RADIX_BITS).rev().map(move |i| (word >> i) & 1 == 1)
+            })
+            .collect::<Vec<_>>();
+        let (padding, value_bits) = bits.split_at(padding_bit_count);
+        if padding.iter().any(|bit| *bit) {
+            return Err(invalid("invalid padding"));
+        }
+        let value = value_bits
+            .chunks(8)
+            .map(|chunk| {
+                chunk.iter().fold(0u8, |acc, bit| (acc << 1) | *bit as u8)
+            })
+            .collect::<Vec<_>>();
+
+        let share = Self::new(
+            (metadata >> 25) as u16,
+            is_extendable,
+            ((metadata >> 20) & 15) as u8,
+            ((metadata >> 16) & 15) as u8,
+            ((metadata >> 12) & 15) as u8 + 1,
+            ((metadata >> 8) & 15) as u8 + 1,
+            ((metadata >> 4) & 15) as u8,
+            (metadata & 15) as u8 + 1,
+            value,
+        );
+        if share.group_threshold > share.group_count {
+            return Err(invalid("group threshold exceeds group count"));
+        }
+        Ok(share)
+    }
+}
+
+impl FromStr for SLIP39Share {
+    type Err = CommonError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_phrase(s)
+    }
+}
+
+impl HasSampleValues for SLIP39Share {
+    /// The single share of the first official SLIP39 test vector.
+    fn sample() -> Self {
+        Self::from_phrase("duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision keyboard").expect("Valid share")
+    }
+
+    /// A share of the "basic sharing 2-of-3" official SLIP39 test vector.
+    fn sample_other() -> Self {
+        Self::from_phrase("shadow pistol academic always adequate wildlife fancy gross oasis cylinder mustang wrist rescue view short owner flip making coding armed").expect("Valid share")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = SLIP39Share;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn parse_sample() {
+        let sut = SUT::sample();
+        assert_eq!(sut.identifier, 7945);
+        assert!(!sut.is_extendable);
+        assert_eq!(sut.iteration_exponent, 0);
+        assert_eq!(sut.group_index, 0);
+        assert_eq!(sut.group_threshold, 1);
+        assert_eq!(sut.group_count, 1);
+        assert_eq!(sut.member_index, 0);
+        assert_eq!(sut.member_threshold, 1);
+        assert_eq!(sut.value.len(), 16);
+    }
+
+    #[test]
+    fn phrase_roundtrip() {
+        let test = |sut: SUT| {
+            assert_eq!(SUT::from_phrase(sut.phrase()), Ok(sut));
+        };
+        test(SUT::sample());
+        test(SUT::sample_other());
+        test(SUT::new(
+            32767,
+            true,
+            15,
+            15,
+            16,
+            16,
+            15,
+            16,
+            vec![0xff; 32],
+        ));
+        test(SUT::new(0, false, 0, 0, 1, 1, 0, 1, vec![0x00; 16]));
+    }
+
+    #[test]
+    fn word_counts() {
+        assert_eq!(SUT::sample().words().len(), 20);
+        assert_eq!(
+            SUT::new(0, false, 0, 0, 1, 1, 0, 1, vec![0x00; 32])
+                .words()
+                .len(),
+            33
+        );
+    }
+
+    #[test]
+    fn from_str_is_case_insensitive() {
+        assert_eq!(
+            SUT::from_str(&SUT::sample().phrase().to_uppercase()),
+            Ok(SUT::sample())
+        );
+    }
+
+    #[test]
+    fn debug_does_not_reveal_words() {
+        let debug = format!("{:?}", SUT::sample());
+        assert!(!debug.contains("duckling"));
+        assert_eq!(debug, "\"SLIP39 share 0 of group 0 (7945)\"");
+    }
+
+    #[test]
+    fn invalid_checksum() {
+        assert_eq!(
+            SUT::from_phrase("duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision kidney"),
+            Err(CommonError::InvalidSLIP39Share { reason: "invalid checksum".to_owned() })
+        );
+    }
+
+    #[test]
+    fn invalid_padding() {
+        assert_eq!(
+            SUT::from_phrase("duckling enlarge academic academic email result length solution fridge kidney coal piece deal husband erode duke ajar music cargo fitness"),
+            Err(CommonError::InvalidSLIP39Share { reason: "invalid padding".to_owned() })
+        );
+    }
+
+    #[test]
+    fn unknown_word() {
+        assert_eq!(
+            SUT::from_phrase("abandon enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision keyboard"),
+            Err(CommonError::UnknownSLIP39Word)
+        );
+    }
+
+    #[test]
+    fn too_few_words() {
+        assert_eq!(
+            SUT::from_phrase("duckling enlarge academic academic"),
+            Err(CommonError::InvalidSLIP39Share {
+                reason: "too few words".to_owned()
+            })
+        );
+    }
+}
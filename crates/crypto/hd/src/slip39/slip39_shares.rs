@@ -0,0 +1,419 @@
+use crate::prelude::*;
+use std::collections::BTreeMap;
+
+/// The iteration exponent used by `MnemonicWithPassphrase::to_slip39_shares`,
+/// the same default as the SLIP39 reference implementation.
+pub const SLIP39_DEFAULT_ITERATION_EXPONENT: u8 = 1;
+
+fn validate_passphrase(passphrase: &str) -> Result<()> {
+    if !passphrase.bytes().all(|b| (32..=126).contains(&b)) {
+        return Err(CommonError::InvalidSLIP39Parameters {
+            reason: "passphrase must only contain printable ASCII characters"
+                .to_owned(),
+        });
+    }
+    Ok(())
+}
+
+/// Splits `master_secret` into groups of SLIP39 shares, as specified by
+/// `groups`, `group_threshold` of which are required to recover it.
+///
+/// The master secret is encrypted with `passphrase` before being split,
+/// recovering it with another passphrase yields another, valid looking,
+/// master secret.
+pub fn slip39_generate_shares(
+    master_secret: &[u8],
+    passphrase: impl AsRef<str>,
+    group_threshold: u8,
+    groups: &[SLIP39Group],
+    iteration_exponent: u8,
+    is_extendable: bool,
+) -> Result<Vec<Vec<SLIP39Share>>> {
+    let passphrase = passphrase.as_ref();
+    validate_passphrase(passphrase)?;
+    if master_secret.len() < 16 || master_secret.len() % 2 != 0 {
+        return Err(CommonError::InvalidSLIP39Parameters {
+            reason: format!(
+                "master secret must be an even number of bytes, at least 16, was {}",
+                master_secret.len()
+            ),
+        });
+    }
+    if iteration_exponent > 15 {
+        return Err(CommonError::InvalidSLIP39Parameters {
+            reason: format!(
+                "iteration exponent {} exceeds 15",
+                iteration_exponent
+            ),
+        });
+    }
+    groups.iter().try_for_each(SLIP39Group::validate)?;
+    let group_count = u8::try_from(groups.len()).unwrap_or(u8::MAX);
+
+    let identifier = u16::from_be_bytes(generate_byte_array::<2>()) & 0x7FFF;
+    let encrypted_master_secret = SLIP39Cipher::new(
+        passphrase.as_bytes(),
+        iteration_exponent,
+        identifier,
+        is_extendable,
+    )
+    .encrypt(master_secret);
+
+    let group_secrets = slip39_split_secret(
+        group_threshold,
+        group_count,
+        &encrypted_master_secret,
+    )?;
+
+    groups
+        .iter()
+        .zip(group_secrets)
+        .map(|(group, group_secret)| {
+            let member_shares = slip39_split_secret(
+                group.member_threshold,
+                group.member_count,
+                &group_secret.y,
+            )?;
+            Ok(member_shares
+                .into_iter()
+                .map(|member_share| {
+                    SLIP39Share::new(
+                        identifier,
+                        is_extendable,
+                        iteration_exponent,
+                        group_secret.x,
+                        group_threshold,
+                        group_count,
+                        member_share.x,
+                        group.member_threshold,
+                        member_share.y.clone(),
+                    )
+                })
+                .collect())
+        })
+        .collect()
+}
+
+/// Recovers the master secret from `shares` generated by
+/// `slip39_generate_shares` with `passphrase`.
+///
+/// Shares of groups with fewer shares than their member threshold are
+/// ignored, and so are shares exceeding the thresholds.
+pub fn slip39_combine_shares(
+    shares: &[SLIP39Share],
+    passphrase: impl AsRef<str>,
+) -> Result<Vec<u8>> {
+    let passphrase = passphrase.as_ref();
+    validate_passphrase(passphrase)?;
+    let Some(first) = shares.first() else {
+        return Err(CommonError::InsufficientSLIP39Shares {
+            reason: "no shares provided".to_owned(),
+        });
+    };
+    if shares.iter().any(|s| !s.has_same_common_parameters(first)) {
+        return Err(CommonError::SLIP39SharesMismatch);
+    }
+
+    let mut groups = BTreeMap::<u8, BTreeMap<u8, &SLIP39Share>>::new();
+    for share in shares {
+        let members = groups.entry(share.group_index).or_default();
+        if let Some(existing) = members.insert(share.member_index, share)
+            && existing != share
+        {
+            return Err(CommonError::SLIP39SharesMismatch);
+        }
+    }
+
+    let mut group_secrets = Vec::new();
+    for (group_index, members) in groups.iter() {
+        let member_threshold = members
+            .values()
+            .next()
+            .map(|s| s.member_threshold)
+            .expect("Groups are never empty");
+        if members
+            .values()
+            .any(|s| s.member_threshold != member_threshold)
+        {
+            return Err(CommonError::SLIP39SharesMismatch);
+        }
+        if members.len() < member_threshold as usize {
+            continue;
+        }
+        let member_shares = members
+            .values()
+            .map(|s| SLIP39RawShare::new(s.member_index, s.value.clone()))
+            .collect::<Vec<_>>();
+        group_secrets.push(SLIP39RawShare::new(
+            *group_index,
+            slip39_recover_secret(member_threshold, &member_shares)?,
+        ));
+    }
+
+    if group_secrets.len() < first.group_threshold as usize {
+        return Err(CommonError::InsufficientSLIP39Shares {
+            reason: format!(
+                "{} of {} groups complete",
+                group_secrets.len(),
+                first.group_threshold
+            ),
+        });
+    }
+
+    let encrypted_master_secret =
+        slip39_recover_secret(first.group_threshold, &group_secrets)?;
+    Ok(SLIP39Cipher::new(
+        passphrase.as_bytes(),
+        first.iteration_exponent,
+        first.identifier,
+        first.is_extendable,
+    )
+    .decrypt(&encrypted_master_secret))
+}
+
+impl MnemonicWithPassphrase {
+    /// Splits the entropy of the mnemonic into groups of SLIP39 shares, as
+    /// specified by `groups`, `group_threshold` of which are required to
+    /// recover it.
+    ///
+    /// The passphrase is used as SLIP39 passphrase and must thus only
+    /// contain printable ASCII characters.
+    pub fn to_slip39_shares(
+        &self,
+        group_threshold: u8,
+        groups: &[SLIP39Group],
+    ) -> Result<Vec<Vec<SLIP39Share>>> {
+        slip39_generate_shares(
+            &self.mnemonic.to_entropy().to_vec(),
+            &self.passphrase.0,
+            group_threshold,
+            groups,
+            SLIP39_DEFAULT_ITERATION_EXPONENT,
+            true,
+        )
+    }
+
+    /// Recovers the mnemonic in `language` and passphrase split by
+    /// `to_slip39_shares`, recovering with the wrong passphrase yields
+    /// another mnemonic.
+    pub fn from_slip39_shares(
+        shares: &[SLIP39Share],
+        passphrase: BIP39Passphrase,
+        language: BIP39Language,
+    ) -> Result<Self> {
+        let master_secret = slip39_combine_shares(shares, &passphrase.0)?;
+        let entropy = NonEmptyMax32Bytes::try_from(master_secret)
+            .and_then(BIP39Entropy::try_from)
+            .map_err(|_| CommonError::InvalidSLIP39Share {
+                reason: "master secret is not a BIP39 entropy".to_owned(),
+            })?;
+        Ok(Self::with_passphrase(
+            Mnemonic::from_entropy_in(entropy, language),
+            passphrase,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flatten(groups: Vec<Vec<SLIP39Share>>) -> Vec<SLIP39Share> {
+        groups.into_iter().flatten().collect()
+    }
+
+    #[test]
+    fn combine_sample() {
+        assert_eq!(
+            hex_encode(
+                slip39_combine_shares(&[SLIP39Share::sample()], "TREZOR")
+                    .unwrap()
+            ),
+            "bb54aac4b89dc868ba37d9cc21b2cece"
+        );
+    }
+
+    #[test]
+    fn generate_and_combine_single_group() {
+        let master_secret = generate_32_bytes();
+        let shares = slip39_generate_shares(
+            &master_secret,
+            "",
+            1,
+            &[SLIP39Group::new(2, 3)],
+            0,
+            true,
+        )
+        .unwrap();
+        assert_eq!(shares.len(), 1);
+        assert_eq!(shares[0].len(), 3);
+        let shares = flatten(shares);
+        assert!(shares.iter().all(|s| s.words().len() == 33));
+
+        assert_eq!(
+            slip39_combine_shares(&shares[1..], ""),
+            Ok(master_secret.clone())
+        );
+        assert_eq!(
+            slip39_combine_shares(&[shares[2].clone(), shares[0].clone()], ""),
+            Ok(master_secret)
+        );
+        assert!(matches!(
+            slip39_combine_shares(&shares[..1], ""),
+            Err(CommonError::InsufficientSLIP39Shares { .. })
+        ));
+    }
+
+    #[test]
+    fn generate_and_combine_groups() {
+        let master_secret = generate_bytes::<16>();
+        let groups = slip39_generate_shares(
+            &master_secret,
+            "TREZOR",
+            2,
+            &[
+                SLIP39Group::new(1, 1),
+                SLIP39Group::new(2, 3),
+                SLIP39Group::new(3, 5),
+            ],
+            0,
+            false,
+        )
+        .unwrap();
+
+        let shares = [
+            groups[0][0].clone(),
+            groups[2][4].clone(),
+            groups[2][1].clone(),
+            groups[2][2].clone(),
+            // Incomplete group is ignored.
+            groups[1][0].clone(),
+        ];
+        assert_eq!(
+            slip39_combine_shares(&shares, "TREZOR"),
+            Ok(master_secret.clone())
+        );
+        assert!(matches!(
+            slip39_combine_shares(&shares[1..], "TREZOR"),
+            Err(CommonError::InsufficientSLIP39Shares { .. })
+        ));
+        assert_ne!(slip39_combine_shares(&shares, "OTHER"), Ok(master_secret));
+    }
+
+    #[test]
+    fn shares_of_different_secrets_mismatch() {
+        let generate = || {
+            flatten(
+                slip39_generate_shares(
+                    &[0xde; 16],
+                    "",
+                    1,
+                    &[SLIP39Group::new(2, 2)],
+                    0,
+                    true,
+                )
+                .unwrap(),
+            )
+        };
+        let (a, b) = (generate(), generate());
+        assert_eq!(
+            slip39_combine_shares(&[a[0].clone(), b[1].clone()], ""),
+            Err(CommonError::SLIP39SharesMismatch)
+        );
+    }
+
+    #[test]
+    fn duplicate_member_index_with_other_value_mismatch() {
+        let shares = flatten(
+            slip39_generate_shares(
+                &[0xde; 16],
+                "",
+                1,
+                &[SLIP39Group::new(2, 2)],
+                0,
+                true,
+            )
+            .unwrap(),
+        );
+        let mut tampered = shares[0].clone();
+        tampered.value[0] ^= 1;
+        assert_eq!(
+            slip39_combine_shares(&[shares[0].clone(), tampered], ""),
+            Err(CommonError::SLIP39SharesMismatch)
+        );
+    }
+
+    #[test]
+    fn invalid_parameters() {
+        let invalid = |result: Result<Vec<Vec<SLIP39Share>>>| {
+            assert!(matches!(
+                result,
+                Err(CommonError::InvalidSLIP39Parameters { .. })
+            ))
+        };
+        let group = [SLIP39Group::sample()];
+        invalid(slip39_generate_shares(&[0; 15], "", 1, &group, 0, true));
+        invalid(slip39_generate_shares(&[0; 17], "", 1, &group, 0, true));
+        invalid(slip39_generate_shares(&[0; 16], "", 2, &group, 0, true));
+        invalid(slip39_generate_shares(&[0; 16], "", 1, &group, 16, true));
+        invalid(slip39_generate_shares(&[0; 16], "å", 1, &group, 0, true));
+        invalid(slip39_generate_shares(
+            &[0; 16],
+            "",
+            1,
+            &[SLIP39Group::new(1, 2)],
+            0,
+            true,
+        ));
+    }
+
+    #[test]
+    fn mnemonic_with_passphrase_roundtrip() {
+        let test = |sut: MnemonicWithPassphrase| {
+            let groups = sut
+                .to_slip39_shares(
+                    2,
+                    &[SLIP39Group::new(2, 3), SLIP39Group::new(1, 1)],
+                )
+                .unwrap();
+            let shares = [groups[0][0].clone(), groups[0][2].clone()]
+                .into_iter()
+                .chain(groups[1].clone())
+                .collect::<Vec<_>>();
+            let recovered = MnemonicWithPassphrase::from_slip39_shares(
+                &shares,
+                sut.passphrase.clone(),
+                sut.mnemonic.language,
+            )
+            .unwrap();
+            assert_eq!(recovered, sut);
+            assert_eq!(recovered.to_seed(), sut.to_seed());
+        };
+        test(MnemonicWithPassphrase::sample_device());
+        test(MnemonicWithPassphrase::sample_device_12_words());
+        test(MnemonicWithPassphrase::with_passphrase(
+            Mnemonic::sample_ledger(),
+            BIP39Passphrase::new("radix"),
+        ));
+    }
+
+    #[test]
+    fn mnemonic_with_wrong_passphrase() {
+        let sut = MnemonicWithPassphrase::with_passphrase(
+            Mnemonic::sample_device(),
+            BIP39Passphrase::new("radix"),
+        );
+        let shares = flatten(
+            sut.to_slip39_shares(1, &[SLIP39Group::new(1, 1)]).unwrap(),
+        );
+
+        let recovered = MnemonicWithPassphrase::from_slip39_shares(
+            &shares,
+            BIP39Passphrase::default(),
+            BIP39Language::English,
+        )
+        .unwrap();
+
+        assert_ne!(recovered.mnemonic, sut.mnemonic);
+    }
+}
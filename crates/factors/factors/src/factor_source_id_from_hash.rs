@@ -142,6 +142,17 @@ impl FactorSourceIDFromHash {
             mnemonic_with_passphrase,
         )
     }
+
+    /// Whether this is the ID of the factor source of kind `self.kind`
+    /// created from `mnemonic_with_passphrase`, e.g. used to filter the
+    /// repairs of a mistyped mnemonic suggested by `MnemonicInputAssistant`.
+    pub fn is_id_of(
+        &self,
+        mnemonic_with_passphrase: &MnemonicWithPassphrase,
+    ) -> bool {
+        Self::from_mnemonic_with_passphrase(self.kind, mnemonic_with_passphrase)
+            == *self
+    }
 }
 
 impl FactorSourceIDFromHash {
@@ -318,6 +329,45 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn is_id_of() {
+        assert!(SUT::sample_device()
+            .is_id_of(&MnemonicWithPassphrase::sample_device()));
+        assert!(!SUT::sample_device()
+            .is_id_of(&MnemonicWithPassphrase::sample_device_other()));
+        assert!(!SUT::sample_ledger()
+            .is_id_of(&MnemonicWithPassphrase::sample_device()));
+    }
+
+    #[test]
+    fn repair_mistyped_mnemonic_of_factor_source() {
+        let mnemonic_with_passphrase = MnemonicWithPassphrase::sample_device();
+        let words = BIP39Language::English
+            .wordlist()
+            .into_iter()
+            .rev()
+            .map(|replacement| {
+                let mut words = mnemonic_with_passphrase.mnemonic.words.clone();
+                words[0] = replacement;
+                words
+            })
+            .find(|words| Mnemonic::from_words(words.clone()).is_err())
+            .unwrap();
+
+        let repaired = MnemonicInputAssistant::default()
+            .checksum_repair_matching(&words, |mnemonic| {
+                SUT::sample_device().is_id_of(
+                    &MnemonicWithPassphrase::with_passphrase(
+                        mnemonic.clone(),
+                        mnemonic_with_passphrase.passphrase.clone(),
+                    ),
+                )
+            })
+            .unwrap();
+
+        assert_eq!(repaired, Some(mnemonic_with_passphrase.mnemonic));
+    }
+
     #[test]
     fn debug() {
         assert_eq!(
@@ -2,4 +2,5 @@ mod extensions_endpoints;
 mod paths;
 mod state_endpoints;
 mod status_endpoints;
+mod stream_endpoints;
 mod transaction_endpoints;
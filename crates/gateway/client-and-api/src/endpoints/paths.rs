@@ -12,6 +12,7 @@ impl GatewayClient {
     pub const PATH_TRANSACTION_STATUS: &'static str = "transaction/status";
     pub const PATH_TRANSACTION_SUBINTENT_STATUS: &'static str =
         "transaction/subintent-status";
+    pub const PATH_STREAM_TRANSACTIONS: &'static str = "stream/transactions";
     pub const PATH_STATE_ENTITY_DETAILS: &str = "state/entity/details";
     pub const PATH_STATE_ENTITY_PAGE_FUNGIBLES: &str =
         "state/entity/page/fungibles/";
@@ -58,7 +58,6 @@ impl GatewayClient {
     /// See [the Gateway API docs for details][doc].
     ///
     /// [doc]: https://radix-babylon-gateway-api.redoc.ly/#operation/EntityNonFungibleResourceVaultPage
-    #[allow(dead_code)]
     pub async fn state_entity_page_non_fungible_vaults(
         &self,
         request: StateEntityPageNonFungibleVaultsRequest,
@@ -84,7 +83,6 @@ impl GatewayClient {
     /// See [the Gateway API docs for details][doc].
     ///
     /// [doc]: https://radix-babylon-gateway-api.redoc.ly/#operation/EntityNonFungibleIdsPage
-    #[allow(dead_code)]
     pub async fn state_entity_page_non_fungible_vault_ids(
         &self,
         request: StateEntityPageNonFungibleVaultIdsRequest,
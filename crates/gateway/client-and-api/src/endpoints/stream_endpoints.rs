@@ -0,0 +1,17 @@
+use crate::prelude::*;
+
+impl GatewayClient {
+    /// Returns the committed transactions matching the filters of the
+    /// request, one page at a time.
+    ///
+    /// See [the Gateway API docs for details][doc].
+    ///
+    /// [doc]: https://radix-babylon-gateway-api.redoc.ly/#operation/StreamTransactions
+    pub async fn stream_transactions(
+        &self,
+        request: StreamTransactionsRequest,
+    ) -> Result<StreamTransactionsResponse> {
+        self.post(Self::PATH_STREAM_TRANSACTIONS, request, res_id)
+            .await
+    }
+}
@@ -0,0 +1,504 @@
+use crate::prelude::*;
+
+impl GatewayClient {
+    /// Fetches the holdings of each account, in the same order as
+    /// `account_addresses`, all read at the same ledger state.
+    ///
+    /// Liquid Stake Units (LSU), pool units and stake claim NFTs are
+    /// identified by the `native_resource_details` returned by the Gateway.
+    /// LSUs and pool units are resolved into the resources they can be
+    /// redeemed for, and stake claims into the XRD they can be claimed for.
+    /// The ids of all non fungibles held are fetched, across all vaults of
+    /// each account.
+    pub async fn fetch_accounts_holdings(
+        &self,
+        account_addresses: impl IntoIterator<Item = AccountAddress>,
+    ) -> Result<AccountsHoldings> {
+        let ledger_state = self.gateway_status().await?.ledger_state;
+        let state_version = ledger_state.state_version;
+        let ledger_state_selector = LedgerStateSelector::from(ledger_state);
+
+        let mut resources_per_account =
+            IndexMap::<AccountAddress, FetchResourcesOutput>::new();
+        for account_address in account_addresses {
+            let resources = self
+                .fetch_all_resources(
+                    account_address,
+                    ledger_state_selector.clone(),
+                )
+                .await?;
+            resources_per_account.insert(account_address, resources);
+        }
+
+        let fungible_resource_addresses = resources_per_account
+            .values()
+            .flat_map(|resources| resources.fungibles.iter())
+            .filter_map(|item| item.as_global())
+            .filter(|item| !item.amount.is_zero())
+            .map(|item| item.resource_address);
+        let non_fungible_resource_addresses = resources_per_account
+            .values()
+            .flat_map(|resources| resources.non_fungibles.iter())
+            .filter_map(|item| item.as_global())
+            .filter(|item| item.amount > 0)
+            .map(|item| item.resource_address);
+        let native_resource_details = self
+            .fetch_native_resource_details(
+                fungible_resource_addresses
+                    .chain(non_fungible_resource_addresses)
+                    .collect::<IndexSet<_>>(),
+                ledger_state_selector.clone(),
+            )
+            .await?;
+
+        let mut accounts = Vec::with_capacity(resources_per_account.len());
+        for (account_address, resources) in resources_per_account {
+            let fungibles = resources
+                .fungibles
+                .iter()
+                .filter_map(|item| item.as_global())
+                .filter(|item| !item.amount.is_zero())
+                .map(|item| {
+                    FungibleHolding::new(
+                        item.resource_address,
+                        item.amount,
+                        native_resource_details.get(&item.resource_address),
+                    )
+                })
+                .collect_vec();
+
+            let mut non_fungibles = Vec::<NonFungibleHolding>::new();
+            for item in resources
+                .non_fungibles
+                .iter()
+                .filter_map(|item| item.as_global())
+                .filter(|item| item.amount > 0)
+            {
+                let resource_address =
+                    NonFungibleResourceAddress::new(item.resource_address)?;
+                if let Some(NativeResourceDetails::ValidatorClaimNft(_)) =
+                    native_resource_details.get(&item.resource_address)
+                {
+                    let claims = self
+                        .fetch_stake_claims(
+                            account_address,
+                            resource_address,
+                            ledger_state_selector.clone(),
+                        )
+                        .await?;
+                    non_fungibles.extend(claims.into_iter().map(|claim| {
+                        NonFungibleHolding::stake_claim(resource_address, claim)
+                    }));
+                    continue;
+                }
+                let ids = self
+                    .fetch_non_fungible_ids_of_account(
+                        account_address,
+                        resource_address,
+                        ledger_state_selector.clone(),
+                    )
+                    .await?;
+                non_fungibles.extend(ids.into_iter().map(|local_id| {
+                    NonFungibleHolding::new(NonFungibleGlobalId::new(
+                        resource_address,
+                        local_id,
+                    ))
+                }));
+            }
+
+            accounts.push(AccountHoldings::new(
+                account_address,
+                fungibles,
+                non_fungibles,
+            ));
+        }
+
+        Ok(AccountsHoldings::new(state_version, accounts))
+    }
+
+    /// Fetches the ids of all non fungibles of `resource_address` held by
+    /// the account of `account_address`, across all of its vaults.
+    pub(crate) async fn fetch_non_fungible_ids_of_account(
+        &self,
+        account_address: AccountAddress,
+        resource_address: NonFungibleResourceAddress,
+        ledger_state_selector: LedgerStateSelector,
+    ) -> Result<Vec<NonFungibleLocalId>> {
+        let address = Address::from(account_address);
+        let vaults = self
+            .load_all_pages(
+                None,
+                ledger_state_selector.clone(),
+                |cursor, ledger_state_selector| {
+                    let request = StateEntityPageNonFungibleVaultsRequest::new(
+                        address,
+                        resource_address.0,
+                        ledger_state_selector,
+                        cursor,
+                        GATEWAY_PAGE_REQUEST_LIMIT,
+                        StateEntityNonFungibleResourceVaultsPageOptIns::include(
+                        ),
+                    );
+                    self.state_entity_page_non_fungible_vaults(request)
+                },
+            )
+            .await?;
+
+        let mut ids = Vec::<NonFungibleLocalId>::new();
+        for vault in vaults {
+            ids.extend(vault.items.unwrap_or_default());
+            if let Some(next_cursor) = vault.next_cursor {
+                let remaining = self
+                    .load_all_pages(
+                        next_cursor,
+                        ledger_state_selector.clone(),
+                        |cursor, ledger_state_selector| {
+                            let request =
+                                StateEntityPageNonFungibleVaultIdsRequest::new(
+                                    address,
+                                    vault.vault_address,
+                                    resource_address.0,
+                                    ledger_state_selector,
+                                    cursor,
+                                    GATEWAY_PAGE_REQUEST_LIMIT,
+                                );
+                            self.state_entity_page_non_fungible_vault_ids(
+                                request,
+                            )
+                        },
+                    )
+                    .await?;
+                ids.extend(remaining);
+            }
+        }
+        Ok(ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use profile_gateway::prelude::Gateway;
+
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = GatewayClient;
+
+    fn mock_status_response() -> MockNetworkingDriverResponse {
+        MockNetworkingDriverResponse::new_success(GatewayStatusResponse {
+            ledger_state: LedgerState::new(
+                "stokenet",
+                80577579,
+                "2024-10-07T15:41:07.259Z",
+                41500,
+                894,
+            ),
+        })
+    }
+
+    fn mock_account_response() -> MockNetworkingDriverResponse {
+        let item = StateEntityDetailsResponseItem::new(
+            AccountAddress::sample_stokenet().into(),
+            FungibleResourcesCollection::new(
+                None,
+                None,
+                vec![
+                    FungibleResourcesCollectionItem::Global(
+                        FungibleResourcesCollectionItemGloballyAggregated::new(
+                            ResourceAddress::sample_stokenet_xrd(),
+                            Decimal192::from(100),
+                        ),
+                    ),
+                    FungibleResourcesCollectionItem::Global(
+                        FungibleResourcesCollectionItemGloballyAggregated::new(
+                            ResourceAddress::sample_stokenet_candy(),
+                            Decimal192::from(10),
+                        ),
+                    ),
+                    FungibleResourcesCollectionItem::Global(
+                        FungibleResourcesCollectionItemGloballyAggregated::new(
+                            ResourceAddress::sample_stokenet_gum(),
+                            Decimal192::zero(),
+                        ),
+                    ),
+                ],
+            ),
+            NonFungibleResourcesCollection::new(
+                None,
+                None,
+                vec![NonFungibleResourcesCollectionItem::Global(
+                    NonFungibleResourcesCollectionItemGloballyAggregated::new(
+                        ResourceAddress::sample_stokenet_nft_gc_membership(),
+                        1,
+                    ),
+                )],
+            ),
+            EntityMetadataCollection::empty(),
+            None,
+        );
+        MockNetworkingDriverResponse::new_success(
+            StateEntityDetailsResponse::new(LedgerState::sample(), vec![item]),
+        )
+    }
+
+    fn mock_resources_response() -> MockNetworkingDriverResponse {
+        let fungible = |details: NativeResourceDetails| {
+            StateEntityDetailsResponseItemDetails::FungibleResource(
+                StateEntityDetailsResponseFungibleResourceDetails::native(
+                    ComponentEntityRoleAssignments::sample(),
+                    details,
+                ),
+            )
+        };
+        let pool_unit = NativeResourceDetails::TwoResourcePoolUnit(
+            NativeResourcePoolUnitValue::new(
+                PoolAddress::sample_stokenet_bi_pool(),
+                [
+                    NativeResourceRedemptionValueItem::new(
+                        ResourceAddress::sample_stokenet_xrd(),
+                        Decimal192::from(2),
+                    ),
+                    NativeResourceRedemptionValueItem::new(
+                        ResourceAddress::sample_stokenet_gum(),
+                        Decimal192::from(5),
+                    ),
+                ],
+            ),
+        );
+        let items = [
+            (
+                ResourceAddress::sample_stokenet_xrd(),
+                fungible(NativeResourceDetails::Xrd),
+            ),
+            (
+                ResourceAddress::sample_stokenet_candy(),
+                fungible(pool_unit),
+            ),
+        ]
+        .into_iter()
+        .map(|(address, details)| {
+            StateEntityDetailsResponseItem::new(
+                address.into(),
+                None,
+                None,
+                EntityMetadataCollection::empty(),
+                details,
+            )
+        })
+        .collect_vec();
+        MockNetworkingDriverResponse::new_success(
+            StateEntityDetailsResponse::new(LedgerState::sample(), items),
+        )
+    }
+
+    fn mock_vaults_response() -> MockNetworkingDriverResponse {
+        MockNetworkingDriverResponse::new_success(PageResponse::new(
+            LedgerState::sample(),
+            1,
+            None,
+            vec![serde_json::from_value::<
+                NonFungibleResourcesCollectionItemVaultAggregatedVaultItem,
+            >(serde_json::json!({
+                "total_count": 1,
+                "vault_address": "internal_vault_tdx_2_1nzqzqc0mg53pm4kwucdp6x3fjdt3534vwl8dlnkw7shl7nc9dkh8nk",
+                "last_updated_at_state_version": 80577579,
+                "items": [NonFungibleLocalId::integer(1)],
+            }))
+            .unwrap()],
+        ))
+    }
+
+    #[actix_rt::test]
+    async fn accounts_holdings() {
+        let mock_driver = MockNetworkingDriver::new_with_responses_and_spy(
+            vec![
+                mock_status_response(),
+                mock_account_response(),
+                mock_resources_response(),
+                mock_vaults_response(),
+            ],
+            |request, count| {
+                let path = match count {
+                    0 => GatewayClient::PATH_STATUS_GATEWAY_STATUS,
+                    1 | 2 => GatewayClient::PATH_STATE_ENTITY_DETAILS,
+                    3 => GatewayClient::PATH_STATE_ENTITY_PAGE_NON_FUNGIBLE_VAULTS,
+                    _ => panic!("Unexpected request count: {}", count),
+                };
+                assert!(request.url.as_str().ends_with(path));
+            },
+        );
+        let sut = SUT::with_gateway(Arc::new(mock_driver), Gateway::stokenet());
+
+        let holdings = sut
+            .fetch_accounts_holdings([AccountAddress::sample_stokenet()])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            holdings,
+            AccountsHoldings::new(80577579, [AccountHoldings::sample()])
+        );
+    }
+
+    #[actix_rt::test]
+    async fn accounts_holdings_stake_claims_are_claimable_for_xrd() {
+        let claim_nft_address: NonFungibleResourceAddress =
+            "resource_tdx_2_1ng3g2nj5pfpmdphgz0nrh8z0gtqcxx5z5dn48t85ar0z0zjhefufaw"
+                .parse()
+                .unwrap();
+        let claims = [
+            StakeClaimNonFungible::sample(),
+            StakeClaimNonFungible::sample_other(),
+        ];
+        let account_response = MockNetworkingDriverResponse::new_success(
+            StateEntityDetailsResponse::new(
+                LedgerState::sample(),
+                vec![StateEntityDetailsResponseItem::new(
+                    AccountAddress::sample_stokenet().into(),
+                    FungibleResourcesCollection::new(None, None, vec![]),
+                    NonFungibleResourcesCollection::new(
+                        None,
+                        None,
+                        vec![NonFungibleResourcesCollectionItem::Global(
+                            NonFungibleResourcesCollectionItemGloballyAggregated::new(
+                                claim_nft_address.0,
+                                2,
+                            ),
+                        )],
+                    ),
+                    EntityMetadataCollection::empty(),
+                    None,
+                )],
+            ),
+        );
+        let resources_response = MockNetworkingDriverResponse::new_success(
+            StateEntityDetailsResponse::new(
+                LedgerState::sample(),
+                vec![StateEntityDetailsResponseItem::new(
+                    claim_nft_address.0.into(),
+                    None,
+                    None,
+                    EntityMetadataCollection::empty(),
+                    StateEntityDetailsResponseItemDetails::NonFungibleResource(
+                        StateEntityDetailsResponseNonFungibleResourceDetails::native(
+                            ComponentEntityRoleAssignments::sample(),
+                            NativeResourceDetails::ValidatorClaimNft(
+                                NativeResourceValidatorClaimNftValue::new(
+                                    ValidatorAddress::sample_stokenet(),
+                                ),
+                            ),
+                        ),
+                    ),
+                )],
+            ),
+        );
+        let vaults_response =
+            MockNetworkingDriverResponse::new_success(PageResponse::new(
+                LedgerState::sample(),
+                1,
+                None,
+                vec![serde_json::from_value::<
+                    NonFungibleResourcesCollectionItemVaultAggregatedVaultItem,
+                >(serde_json::json!({
+                    "total_count": 2,
+                    "vault_address": "internal_vault_tdx_2_1nzqzqc0mg53pm4kwucdp6x3fjdt3534vwl8dlnkw7shl7nc9dkh8nk",
+                    "last_updated_at_state_version": 80577579,
+                    "items": claims.iter().map(|claim| claim.id.clone()).collect_vec(),
+                }))
+                .unwrap()],
+            ));
+        let data_response = MockNetworkingDriverResponse::new_success(
+            StateNonFungibleDataResponse {
+                ledger_state: LedgerState::sample(),
+                resource_address: claim_nft_address.0,
+                non_fungible_ids: claims
+                    .iter()
+                    .map(|claim| {
+                        serde_json::from_value(serde_json::json!({
+                            "non_fungible_id": claim.id,
+                            "is_burned": false,
+                            "last_updated_at_state_version": 80577579,
+                            "data": {
+                                "programmatic_json": {
+                                    "kind": "Tuple",
+                                    "type_name": "UnstakeData",
+                                    "fields": [
+                                        {
+                                            "kind": "U64",
+                                            "field_name": "claim_epoch",
+                                            "value": claim.claim_epoch.0.to_string()
+                                        },
+                                        {
+                                            "kind": "Decimal",
+                                            "field_name": "claim_amount",
+                                            "value": claim.claim_amount.to_string()
+                                        }
+                                    ]
+                                }
+                            }
+                        }))
+                        .unwrap()
+                    })
+                    .collect_vec(),
+            },
+        );
+        let mock_driver = MockNetworkingDriver::new_with_responses(vec![
+            mock_status_response(),
+            account_response,
+            resources_response,
+            vaults_response,
+            data_response,
+        ]);
+        let sut = SUT::with_gateway(Arc::new(mock_driver), Gateway::stokenet());
+
+        let holdings = sut
+            .fetch_accounts_holdings([AccountAddress::sample_stokenet()])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            holdings.accounts[0].non_fungibles,
+            claims
+                .into_iter()
+                .map(|claim| NonFungibleHolding::stake_claim(
+                    claim_nft_address,
+                    claim
+                ))
+                .collect_vec()
+        );
+        assert_eq!(
+            holdings.accounts[0].non_fungibles[0].claimable,
+            Some(UnderlyingResourceAmount::new(
+                ResourceAddress::sample_stokenet_xrd(),
+                StakeClaimNonFungible::sample().claim_amount,
+            ))
+        );
+    }
+
+    #[actix_rt::test]
+    async fn accounts_holdings_no_accounts() {
+        let mock_driver = MockNetworkingDriver::new_with_responses(vec![
+            mock_status_response(),
+        ]);
+        let sut = SUT::with_gateway(Arc::new(mock_driver), Gateway::stokenet());
+
+        let holdings = sut.fetch_accounts_holdings([]).await.unwrap();
+
+        assert_eq!(holdings, AccountsHoldings::new(80577579, []));
+    }
+
+    #[actix_rt::test]
+    async fn accounts_holdings_failure() {
+        let mock_driver = MockNetworkingDriver::new_always_failing();
+        let sut = SUT::with_gateway(Arc::new(mock_driver), Gateway::stokenet());
+
+        let result = sut
+            .fetch_accounts_holdings([AccountAddress::sample_stokenet()])
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommonError::NetworkResponseBadCode { code: 500 })
+        );
+    }
+}
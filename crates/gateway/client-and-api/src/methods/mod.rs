@@ -4,6 +4,10 @@
 //! `/state/entity/details` and then refining the response.
 
 mod chunk;
+mod holdings_methods;
 mod page_methods;
+mod pool_methods;
+mod staking_methods;
 mod state_methods;
+mod stream_methods;
 mod transaction_methods;
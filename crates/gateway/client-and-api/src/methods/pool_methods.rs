@@ -0,0 +1,213 @@
+use crate::prelude::*;
+
+impl GatewayClient {
+    /// Fetches the current state of the native pool at `pool_address`: the
+    /// amount of each resource it holds and the total supply of its pool
+    /// unit, both read at the same ledger state.
+    pub async fn fetch_pool_state(
+        &self,
+        pool_address: PoolAddress,
+    ) -> Result<LiquidityPoolState> {
+        let invalid_state = || CommonError::InvalidPoolStateFromGateway {
+            pool_address: pool_address.to_string(),
+        };
+
+        let pool_response = self
+            .state_entity_details(StateEntityDetailsRequest::new(
+                vec![pool_address.into()],
+                None,
+                None,
+            ))
+            .await?;
+        let pool_item = pool_response
+            .items
+            .into_iter()
+            .find(|item| item.address == Address::from(pool_address))
+            .ok_or(CommonError::EntityNotFound)?;
+
+        let pool_unit_resource_address = pool_item
+            .details
+            .as_ref()
+            .and_then(|details| details.as_component())
+            .and_then(|component| {
+                component
+                    .try_decode_state::<PoolFieldStateValue>()
+                    .ok()
+                    .flatten()
+            })
+            .map(|state| state.pool_unit_resource_address)
+            .ok_or_else(invalid_state)?;
+
+        let reserves = pool_item
+            .fungible_resources
+            .map(|fungibles| fungibles.items)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|item| {
+                PoolResourceAmount::new(item.resource_address(), item.amount())
+            })
+            .collect_vec();
+
+        let pool_unit_response = self
+            .state_entity_details(StateEntityDetailsRequest::new(
+                vec![pool_unit_resource_address.into()],
+                pool_response.ledger_state.map(LedgerStateSelector::from),
+                None,
+            ))
+            .await?;
+        let pool_unit_total_supply = pool_unit_response
+            .items
+            .into_iter()
+            .find(|item| {
+                item.address == Address::from(pool_unit_resource_address)
+            })
+            .and_then(|item| item.details)
+            .and_then(|details| details.into_fungible_resource().ok())
+            .and_then(|details| details.total_supply)
+            .ok_or_else(invalid_state)?;
+
+        Ok(LiquidityPoolState::new(
+            pool_address,
+            pool_unit_resource_address,
+            pool_unit_total_supply,
+            reserves,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use profile_gateway::prelude::Gateway;
+
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = GatewayClient;
+
+    fn mock_pool_response(
+        expected: &LiquidityPoolState,
+        state: Option<String>,
+    ) -> MockNetworkingDriverResponse {
+        let item = StateEntityDetailsResponseItem::new(
+            expected.pool_address.into(),
+            FungibleResourcesCollection::new(
+                None,
+                None,
+                expected
+                    .reserves
+                    .iter()
+                    .map(|reserve| {
+                        FungibleResourcesCollectionItem::global(
+                            reserve.resource_address,
+                            reserve.amount,
+                        )
+                    })
+                    .collect_vec(),
+            ),
+            None,
+            EntityMetadataCollection::empty(),
+            StateEntityDetailsResponseItemDetails::Component(
+                StateEntityDetailsResponseComponentDetails {
+                    role_assignments: None,
+                    state,
+                },
+            ),
+        );
+        MockNetworkingDriverResponse::new_success(
+            StateEntityDetailsResponse::new(LedgerState::sample(), vec![item]),
+        )
+    }
+
+    fn mock_pool_unit_response(
+        expected: &LiquidityPoolState,
+    ) -> MockNetworkingDriverResponse {
+        let item = StateEntityDetailsResponseItem::new(
+            expected.pool_unit_resource_address.into(),
+            None,
+            None,
+            EntityMetadataCollection::empty(),
+            StateEntityDetailsResponseItemDetails::FungibleResource(
+                StateEntityDetailsResponseFungibleResourceDetails::with_total_supply(
+                    ComponentEntityRoleAssignments::sample(),
+                    expected.pool_unit_total_supply,
+                ),
+            ),
+        );
+        MockNetworkingDriverResponse::new_success(
+            StateEntityDetailsResponse::new(LedgerState::sample(), vec![item]),
+        )
+    }
+
+    fn pool_state_json(expected: &LiquidityPoolState) -> String {
+        serde_json::to_string(&PoolFieldStateValue {
+            pool_unit_resource_address: expected.pool_unit_resource_address,
+        })
+        .unwrap()
+    }
+
+    #[actix_rt::test]
+    async fn fetch_pool_state() {
+        let expected = LiquidityPoolState::sample();
+        let mock_driver = MockNetworkingDriver::new_with_responses_and_spy(
+            vec![
+                mock_pool_response(&expected, Some(pool_state_json(&expected))),
+                mock_pool_unit_response(&expected),
+            ],
+            |request, count| {
+                assert!(request
+                    .url
+                    .as_str()
+                    .ends_with(GatewayClient::PATH_STATE_ENTITY_DETAILS));
+                let body = serde_json::from_slice::<StateEntityDetailsRequest>(
+                    &request.body,
+                )
+                .unwrap();
+                if count == 1 {
+                    assert_eq!(
+                        body.at_ledger_state,
+                        Some(LedgerStateSelector::from(LedgerState::sample()))
+                    );
+                }
+            },
+        );
+        let sut = SUT::with_gateway(Arc::new(mock_driver), Gateway::stokenet());
+
+        let state = sut.fetch_pool_state(expected.pool_address).await.unwrap();
+
+        assert_eq!(state, expected);
+    }
+
+    #[actix_rt::test]
+    async fn fetch_pool_state_without_state_fails() {
+        let expected = LiquidityPoolState::sample();
+        let mock_driver =
+            MockNetworkingDriver::new_with_responses(vec![mock_pool_response(
+                &expected, None,
+            )]);
+        let sut = SUT::with_gateway(Arc::new(mock_driver), Gateway::stokenet());
+
+        let result = sut.fetch_pool_state(expected.pool_address).await;
+
+        assert_eq!(
+            result,
+            Err(CommonError::InvalidPoolStateFromGateway {
+                pool_address: expected.pool_address.to_string(),
+            })
+        );
+    }
+
+    #[actix_rt::test]
+    async fn fetch_pool_state_failure() {
+        let mock_driver = MockNetworkingDriver::new_always_failing();
+        let sut = SUT::with_gateway(Arc::new(mock_driver), Gateway::stokenet());
+
+        let result = sut
+            .fetch_pool_state(LiquidityPoolState::sample().pool_address)
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommonError::NetworkResponseBadCode { code: 500 })
+        );
+    }
+}
@@ -0,0 +1,460 @@
+use crate::prelude::*;
+
+impl GatewayClient {
+    /// Fetches the staking overview of the account of `account_address`:
+    /// the XRD staked per validator, valued using the redemption value of
+    /// the Liquid Stake Units (LSU) held, and the pending and claimable
+    /// unstakes, read from the claim NFTs held.
+    ///
+    /// LSUs and claim NFTs are identified by the `native_resource_details`
+    /// returned by the Gateway, which - unlike metadata - cannot be spoofed.
+    /// All requests are made against the same ledger state.
+    pub async fn fetch_account_staking_overview(
+        &self,
+        account_address: AccountAddress,
+    ) -> Result<AccountStakingOverview> {
+        let ledger_state = self.gateway_status().await?.ledger_state;
+        let current_epoch = Epoch::new(ledger_state.epoch);
+        let ledger_state_selector = LedgerStateSelector::from(ledger_state);
+
+        let resources = self
+            .fetch_all_resources(account_address, ledger_state_selector.clone())
+            .await?;
+
+        let fungible_amounts = resources
+            .fungibles
+            .iter()
+            .filter_map(|item| item.as_global())
+            .filter(|item| !item.amount.is_zero())
+            .map(|item| (item.resource_address, item.amount))
+            .collect::<IndexMap<_, _>>();
+
+        let non_fungible_addresses = resources
+            .non_fungibles
+            .iter()
+            .filter_map(|item| item.as_global())
+            .filter(|item| item.amount > 0)
+            .map(|item| item.resource_address)
+            .collect::<IndexSet<_>>();
+
+        let native_resource_details = self
+            .fetch_native_resource_details(
+                fungible_amounts
+                    .keys()
+                    .chain(non_fungible_addresses.iter())
+                    .cloned(),
+                ledger_state_selector.clone(),
+            )
+            .await?;
+
+        let mut validators =
+            IndexMap::<ValidatorAddress, ValidatorStakingOverview>::new();
+
+        for (resource_address, details) in native_resource_details {
+            match details {
+                NativeResourceDetails::ValidatorLiquidStakeUnit(lsu) => {
+                    let Some(stake_units) =
+                        fungible_amounts.get(&resource_address).cloned()
+                    else {
+                        continue;
+                    };
+                    let liquid_stake = ValidatorLiquidStake::new(
+                        resource_address,
+                        stake_units,
+                        lsu.xrd_redemption_value(),
+                    );
+                    validators
+                        .entry(lsu.validator_address)
+                        .or_insert_with(|| {
+                            ValidatorStakingOverview::new(
+                                lsu.validator_address,
+                                None,
+                                None,
+                            )
+                        })
+                        .liquid_stake = Some(liquid_stake);
+                }
+                NativeResourceDetails::ValidatorClaimNft(claim_nft) => {
+                    if !non_fungible_addresses.contains(&resource_address) {
+                        continue;
+                    }
+                    let resource_address =
+                        NonFungibleResourceAddress::new(resource_address)?;
+                    let claims = self
+                        .fetch_stake_claims(
+                            account_address,
+                            resource_address,
+                            ledger_state_selector.clone(),
+                        )
+                        .await?;
+                    if claims.is_empty() {
+                        continue;
+                    }
+                    let stake_claims = ValidatorStakeClaims::new(
+                        resource_address,
+                        claims,
+                        current_epoch,
+                    );
+                    validators
+                        .entry(claim_nft.validator_address)
+                        .or_insert_with(|| {
+                            ValidatorStakingOverview::new(
+                                claim_nft.validator_address,
+                                None,
+                                None,
+                            )
+                        })
+                        .stake_claims = Some(stake_claims);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(AccountStakingOverview::new(
+            account_address,
+            current_epoch,
+            validators.into_values(),
+        ))
+    }
+
+    /// Fetches the `native_resource_details` of each resource, resources
+    /// without any are left out.
+    pub(crate) async fn fetch_native_resource_details(
+        &self,
+        resource_addresses: impl IntoIterator<Item = ResourceAddress>,
+        ledger_state_selector: LedgerStateSelector,
+    ) -> Result<IndexMap<ResourceAddress, NativeResourceDetails>> {
+        self.batch_fetch_chunking(
+            GATEWAY_ENTITY_DETAILS_CHUNK_ADDRESSES,
+            resource_addresses,
+            |addresses| {
+                StateEntityDetailsRequest::new(
+                    addresses.into_iter().map(Address::from).collect_vec(),
+                    ledger_state_selector.clone(),
+                    None,
+                )
+            },
+            |req| self.state_entity_details(req),
+            |responses| {
+                let map = responses
+                    .into_iter()
+                    .flat_map(|response| response.items)
+                    .filter_map(|item| {
+                        let resource_address = *item.address.as_resource()?;
+                        let details =
+                            item.details?.native_resource_details()?.clone();
+                        Some((resource_address, details))
+                    })
+                    .collect();
+                Ok(map)
+            },
+        )
+        .await
+    }
+
+    /// Fetches the claims of all claim NFTs of `resource_address` held by
+    /// the account of `account_address`, across all of its vaults.
+    pub(crate) async fn fetch_stake_claims(
+        &self,
+        account_address: AccountAddress,
+        resource_address: NonFungibleResourceAddress,
+        ledger_state_selector: LedgerStateSelector,
+    ) -> Result<Vec<StakeClaimNonFungible>> {
+        let ids = self
+            .fetch_non_fungible_ids_of_account(
+                account_address,
+                resource_address,
+                ledger_state_selector.clone(),
+            )
+            .await?;
+
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.batch_fetch_chunking(
+            GATEWAY_CHUNK_NON_FUNGIBLES as usize,
+            ids,
+            |ids| {
+                StateNonFungibleDataRequest::new(
+                    resource_address,
+                    ids,
+                    ledger_state_selector.clone(),
+                )
+            },
+            |req| self.state_non_fungible_data(req),
+            |responses| {
+                let claims = responses
+                    .into_iter()
+                    .flat_map(|response| response.non_fungible_ids)
+                    .filter_map(|item| StakeClaimNonFungible::from_data(&item))
+                    .collect_vec();
+                Ok(claims)
+            },
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use profile_gateway::prelude::Gateway;
+
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = GatewayClient;
+
+    fn lsu_address() -> ResourceAddress {
+        "resource_tdx_2_1t5hpjckz9tm63gqvxsl60ejhzvnlguly77tltvywnj06s2x9wjdxjn"
+            .parse()
+            .unwrap()
+    }
+
+    fn claim_nft_address() -> NonFungibleResourceAddress {
+        "resource_tdx_2_1ng3g2nj5pfpmdphgz0nrh8z0gtqcxx5z5dn48t85ar0z0zjhefufaw"
+            .parse()
+            .unwrap()
+    }
+
+    fn mock_status_response() -> MockNetworkingDriverResponse {
+        MockNetworkingDriverResponse::new_success(GatewayStatusResponse {
+            ledger_state: LedgerState::new(
+                "stokenet",
+                80577579,
+                "2024-10-07T15:41:07.259Z",
+                41500,
+                894,
+            ),
+        })
+    }
+
+    fn mock_account_response(
+        fungibles: Vec<FungibleResourcesCollectionItem>,
+        non_fungibles: Vec<NonFungibleResourcesCollectionItem>,
+    ) -> MockNetworkingDriverResponse {
+        let item = StateEntityDetailsResponseItem::new(
+            AccountAddress::sample_stokenet().into(),
+            FungibleResourcesCollection::new(None, None, fungibles),
+            NonFungibleResourcesCollection::new(None, None, non_fungibles),
+            EntityMetadataCollection::empty(),
+            None,
+        );
+        MockNetworkingDriverResponse::new_success(
+            StateEntityDetailsResponse::new(LedgerState::sample(), vec![item]),
+        )
+    }
+
+    fn mock_resources_response(
+        details: impl IntoIterator<
+            Item = (ResourceAddress, StateEntityDetailsResponseItemDetails),
+        >,
+    ) -> MockNetworkingDriverResponse {
+        let items = details
+            .into_iter()
+            .map(|(address, details)| {
+                StateEntityDetailsResponseItem::new(
+                    address.into(),
+                    None,
+                    None,
+                    EntityMetadataCollection::empty(),
+                    details,
+                )
+            })
+            .collect_vec();
+        MockNetworkingDriverResponse::new_success(
+            StateEntityDetailsResponse::new(LedgerState::sample(), items),
+        )
+    }
+
+    fn mock_vaults_response() -> MockNetworkingDriverResponse {
+        MockNetworkingDriverResponse::new_success(PageResponse::new(
+            LedgerState::sample(),
+            1,
+            None,
+            vec![serde_json::from_value::<
+                NonFungibleResourcesCollectionItemVaultAggregatedVaultItem,
+            >(serde_json::json!({
+                "total_count": 2,
+                "vault_address": "internal_vault_tdx_2_1nzqzqc0mg53pm4kwucdp6x3fjdt3534vwl8dlnkw7shl7nc9dkh8nk",
+                "last_updated_at_state_version": 80577579,
+                "items": [
+                    StakeClaimNonFungible::sample().id,
+                    StakeClaimNonFungible::sample_other().id,
+                ],
+            }))
+            .unwrap()],
+        ))
+    }
+
+    fn claim_data_item(
+        claim: StakeClaimNonFungible,
+    ) -> StateNonFungibleDataResponseItem {
+        serde_json::from_value(serde_json::json!({
+            "non_fungible_id": claim.id,
+            "is_burned": false,
+            "last_updated_at_state_version": 80577579,
+            "data": {
+                "programmatic_json": {
+                    "kind": "Tuple",
+                    "type_name": "UnstakeData",
+                    "fields": [
+                        {
+                            "kind": "U64",
+                            "field_name": "claim_epoch",
+                            "value": claim.claim_epoch.0.to_string()
+                        },
+                        {
+                            "kind": "Decimal",
+                            "field_name": "claim_amount",
+                            "value": claim.claim_amount.to_string()
+                        }
+                    ]
+                }
+            }
+        }))
+        .unwrap()
+    }
+
+    fn mock_data_response() -> MockNetworkingDriverResponse {
+        MockNetworkingDriverResponse::new_success(
+            StateNonFungibleDataResponse {
+                ledger_state: LedgerState::sample(),
+                resource_address: claim_nft_address().0,
+                non_fungible_ids: vec![
+                    claim_data_item(StakeClaimNonFungible::sample()),
+                    claim_data_item(StakeClaimNonFungible::sample_other()),
+                ],
+            },
+        )
+    }
+
+    #[actix_rt::test]
+    async fn staking_overview() {
+        let lsu_details =
+            StateEntityDetailsResponseItemDetails::FungibleResource(
+                StateEntityDetailsResponseFungibleResourceDetails::native(
+                    ComponentEntityRoleAssignments::sample(),
+                    NativeResourceDetails::ValidatorLiquidStakeUnit(
+                        NativeResourceValidatorLiquidStakeUnitValue::new(
+                            ValidatorAddress::sample_stokenet(),
+                            [NativeResourceRedemptionValueItem::new(
+                                ResourceAddress::sample_stokenet_xrd(),
+                                Decimal192::from_str("1.05").unwrap(),
+                            )],
+                        ),
+                    ),
+                ),
+            );
+        let xrd_details =
+            StateEntityDetailsResponseItemDetails::FungibleResource(
+                StateEntityDetailsResponseFungibleResourceDetails::native(
+                    ComponentEntityRoleAssignments::sample(),
+                    NativeResourceDetails::Xrd,
+                ),
+            );
+        let claim_nft_details =
+            StateEntityDetailsResponseItemDetails::NonFungibleResource(
+                StateEntityDetailsResponseNonFungibleResourceDetails::native(
+                    ComponentEntityRoleAssignments::sample(),
+                    NativeResourceDetails::ValidatorClaimNft(
+                        NativeResourceValidatorClaimNftValue::new(
+                            ValidatorAddress::sample_stokenet_other(),
+                        ),
+                    ),
+                ),
+            );
+
+        let mock_driver = MockNetworkingDriver::new_with_responses_and_spy(
+            vec![
+                mock_status_response(),
+                mock_account_response(
+                    vec![
+                        FungibleResourcesCollectionItem::sample(),
+                        FungibleResourcesCollectionItem::Global(
+                            FungibleResourcesCollectionItemGloballyAggregated::new(
+                                lsu_address(),
+                                Decimal192::from(1000),
+                            ),
+                        ),
+                    ],
+                    vec![NonFungibleResourcesCollectionItem::Global(
+                        NonFungibleResourcesCollectionItemGloballyAggregated::new(
+                            claim_nft_address().0,
+                            2,
+                        ),
+                    )],
+                ),
+                mock_resources_response([
+                    (ResourceAddress::sample_stokenet_xrd(), xrd_details),
+                    (lsu_address(), lsu_details),
+                    (claim_nft_address().0, claim_nft_details),
+                ]),
+                mock_vaults_response(),
+                mock_data_response(),
+            ],
+            |request, count| {
+                let path = match count {
+                    0 => GatewayClient::PATH_STATUS_GATEWAY_STATUS,
+                    1 | 2 => GatewayClient::PATH_STATE_ENTITY_DETAILS,
+                    3 => GatewayClient::PATH_STATE_ENTITY_PAGE_NON_FUNGIBLE_VAULTS,
+                    4 => GatewayClient::PATH_STATE_NON_FUNGIBLE_DATA,
+                    _ => panic!("Unexpected request count: {}", count),
+                };
+                assert!(request.url.as_str().ends_with(path));
+            },
+        );
+        let sut = SUT::with_gateway(Arc::new(mock_driver), Gateway::stokenet());
+
+        let overview = sut
+            .fetch_account_staking_overview(AccountAddress::sample_stokenet())
+            .await
+            .unwrap();
+
+        assert_eq!(overview, AccountStakingOverview::sample());
+    }
+
+    #[actix_rt::test]
+    async fn staking_overview_nothing_staked() {
+        let mock_driver = MockNetworkingDriver::new_with_responses(vec![
+            mock_status_response(),
+            mock_account_response(
+                vec![FungibleResourcesCollectionItem::sample()],
+                vec![],
+            ),
+            mock_resources_response([(
+                ResourceAddress::sample_stokenet_xrd(),
+                StateEntityDetailsResponseItemDetails::FungibleResource(
+                    StateEntityDetailsResponseFungibleResourceDetails::native(
+                        ComponentEntityRoleAssignments::sample(),
+                        NativeResourceDetails::Xrd,
+                    ),
+                ),
+            )]),
+        ]);
+        let sut = SUT::with_gateway(Arc::new(mock_driver), Gateway::stokenet());
+
+        let overview = sut
+            .fetch_account_staking_overview(AccountAddress::sample_stokenet())
+            .await
+            .unwrap();
+
+        assert_eq!(overview.current_epoch, Epoch::new(41500u64));
+        assert!(overview.validators.is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn staking_overview_failure() {
+        let mock_driver = MockNetworkingDriver::new_always_failing();
+        let sut = SUT::with_gateway(Arc::new(mock_driver), Gateway::stokenet());
+
+        let result = sut
+            .fetch_account_staking_overview(AccountAddress::sample_stokenet())
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommonError::NetworkResponseBadCode { code: 500 })
+        );
+    }
+}
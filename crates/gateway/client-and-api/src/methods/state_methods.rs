@@ -268,6 +268,44 @@ impl GatewayClient {
 
         Ok(result)
     }
+
+    /// Looks up on ledger which of the `account_addresses` are active, i.e.
+    /// have been used on ledger, either by having their `owner_keys` metadata
+    /// set or by holding any resources.
+    ///
+    /// Returns the requested account addresses along with a `bool` being true
+    /// if that account is active.
+    pub async fn check_accounts_are_active(
+        &self,
+        account_addresses: impl IntoIterator<Item = AccountAddress>,
+    ) -> Result<IndexMap<AccountAddress, bool>> {
+        let requested_addresses =
+            account_addresses.into_iter().collect::<IndexSet<_>>();
+
+        let active_addresses = self
+            .batch_fetch_chunking(
+                GATEWAY_ENTITY_DETAILS_CHUNK_ADDRESSES,
+                requested_addresses.clone(),
+                StateEntityDetailsRequest::addresses_only,
+                |req| self.state_entity_details(req),
+                |responses| {
+                    Ok(responses
+                        .into_iter()
+                        .flat_map(|response| response.items)
+                        .filter(|item| item.is_active())
+                        .map(|item| item.address)
+                        .collect::<HashSet<Address>>())
+                },
+            )
+            .await?;
+
+        Ok(requested_addresses
+            .into_iter()
+            .map(|address| {
+                (address, active_addresses.contains(&Address::from(address)))
+            })
+            .collect())
+    }
 }
 
 impl GatewayClient {
@@ -301,6 +339,43 @@ impl GatewayClient {
         self.fetch_entity_metadata(address.into(), dapp_metadata_keys())
             .await
     }
+
+    /// Fetches the public keys in the `owner_keys` metadata of each account,
+    /// accounts whose `owner_keys` are unset or only hold key hashes - as by
+    /// default - are left out.
+    pub async fn fetch_accounts_owner_public_keys(
+        &self,
+        account_addresses: impl IntoIterator<Item = AccountAddress>,
+    ) -> Result<IndexMap<AccountAddress, Vec<PublicKey>>> {
+        self.batch_fetch_chunking(
+            GATEWAY_ENTITY_DETAILS_CHUNK_ADDRESSES,
+            account_addresses,
+            |addresses| {
+                StateEntityDetailsRequest::new(
+                    addresses.into_iter().map(Address::from).collect_vec(),
+                    None,
+                    StateEntityDetailsOptIns::new(Some(vec![
+                        MetadataKey::OwnerKeys,
+                    ])),
+                )
+            },
+            |req| self.state_entity_details(req),
+            |responses| {
+                let map = responses
+                    .into_iter()
+                    .flat_map(|response| response.items)
+                    .filter_map(|item| {
+                        let account_address = *item.address.as_account()?;
+                        let public_keys =
+                            item.metadata.get_owner_public_keys()?;
+                        Some((account_address, public_keys))
+                    })
+                    .collect();
+                Ok(map)
+            },
+        )
+        .await
+    }
 }
 
 impl GatewayClient {
@@ -347,6 +422,151 @@ impl GatewayClient {
         )
         .await
     }
+
+    /// Fetches the third-party deposit settings of the account as set on
+    /// ledger, i.e. its default deposit rule, its resource preferences as
+    /// the assets exception list and its authorized depositors as the
+    /// depositors allow list.
+    pub async fn fetch_account_third_party_deposits(
+        &self,
+        account_address: AccountAddress,
+        ledger_state_selector: LedgerStateSelector,
+    ) -> Result<ThirdPartyDeposits> {
+        self.fetch_accounts_third_party_deposits(
+            [account_address],
+            ledger_state_selector,
+        )
+        .await?
+        .swap_remove(&account_address)
+        .expect("Should have a result for every requested account")
+    }
+
+    /// Fetches the third-party deposit settings of each of the accounts as
+    /// set on ledger, see `fetch_account_third_party_deposits`, reading the
+    /// default deposit rules of all accounts with batched requests.
+    ///
+    /// The settings are returned per account - in the order of
+    /// `account_addresses` - together with the error of fetching them, if
+    /// any, so that failing to fetch the settings of one account does not
+    /// fail the others.
+    pub async fn fetch_accounts_third_party_deposits(
+        &self,
+        account_addresses: impl IntoIterator<Item = AccountAddress>,
+        ledger_state_selector: LedgerStateSelector,
+    ) -> Result<IndexMap<AccountAddress, Result<ThirdPartyDeposits>>> {
+        let account_addresses =
+            account_addresses.into_iter().collect::<IndexSet<_>>();
+        let responses = self
+            .batch_fetch_chunking(
+                GATEWAY_ENTITY_DETAILS_CHUNK_ADDRESSES,
+                account_addresses.clone(),
+                |addresses| {
+                    StateEntityDetailsRequest::new(
+                        addresses.into_iter().map(Address::from).collect(),
+                        ledger_state_selector.clone(),
+                        None,
+                    )
+                },
+                |req| self.state_entity_details(req),
+                Ok,
+            )
+            .await?;
+
+        let mut third_party_deposits_per_account = IndexMap::new();
+        for account_address in account_addresses {
+            let item_and_ledger_state = responses.iter().find_map(|response| {
+                response
+                    .items
+                    .iter()
+                    .find(|item| item.address == account_address.into())
+                    .map(|item| (item, response.ledger_state.clone()))
+            });
+            let third_party_deposits = match item_and_ledger_state {
+                Some((item, ledger_state)) => {
+                    self.account_third_party_deposits_from_details(
+                        account_address,
+                        item,
+                        // Read the lists at the same ledger state as the
+                        // deposit rule.
+                        ledger_state
+                            .map(LedgerStateSelector::from)
+                            .unwrap_or(ledger_state_selector.clone()),
+                    )
+                    .await
+                }
+                None => Err(CommonError::GWMissingResponseItem {
+                    item: "StateEntityDetailsResponseItem".to_owned(),
+                }),
+            };
+            third_party_deposits_per_account
+                .insert(account_address, third_party_deposits);
+        }
+
+        Ok(third_party_deposits_per_account)
+    }
+
+    async fn account_third_party_deposits_from_details(
+        &self,
+        account_address: AccountAddress,
+        item: &StateEntityDetailsResponseItem,
+        ledger_state_selector: LedgerStateSelector,
+    ) -> Result<ThirdPartyDeposits> {
+        // Virtual accounts which have never been used have no state, their
+        // deposit rule is the default one.
+        let deposit_rule = item
+            .details
+            .as_ref()
+            .and_then(|details| details.as_component())
+            .map(|component| {
+                component.try_decode_state::<AccountFieldStateValue>()
+            })
+            .transpose()
+            .map_err(|e| CommonError::Unknown {
+                error_message: format!(
+                    "Failed to decode state of account {}: {}",
+                    account_address, e
+                ),
+            })?
+            .flatten()
+            .map(|state| DepositRule::from(state.default_deposit_rule))
+            .unwrap_or_default();
+
+        let assets_exception_list = self
+            .fetch_all_account_resource_preferences(
+                account_address,
+                ledger_state_selector.clone(),
+            )
+            .await?
+            .into_iter()
+            .map(|preference| {
+                let exception_rule = match preference.status {
+                    AccountResourcePreferenceRule::Allowed => {
+                        DepositAddressExceptionRule::Allow
+                    }
+                    AccountResourcePreferenceRule::Disallowed => {
+                        DepositAddressExceptionRule::Deny
+                    }
+                };
+                AssetException::new(preference.resource_address, exception_rule)
+            })
+            .collect_vec();
+
+        let depositors_allow_list = self
+            .fetch_all_account_authorized_depositors(
+                account_address,
+                ledger_state_selector,
+            )
+            .await?
+            .into_iter()
+            .map(ResourceOrNonFungible::try_from)
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(ThirdPartyDeposits::with_rule_and_lists(
+            deposit_rule,
+            assets_exception_list,
+            depositors_allow_list,
+        ))
+    }
 }
 
 impl GatewayClient {
@@ -1104,3 +1324,342 @@ mod filter_transferable_tests {
         }
     }
 }
+
+#[cfg(test)]
+mod check_accounts_are_active_tests {
+    use crate::prelude::*;
+    use profile_gateway::prelude::Gateway;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = GatewayClient;
+
+    #[actix_rt::test]
+    async fn active_and_inactive_accounts() {
+        let active = AccountAddress::sample_stokenet();
+        let inactive = AccountAddress::sample_stokenet_other();
+
+        let response = MockNetworkingDriverResponse::new_success(
+            StateEntityDetailsResponse::new(
+                LedgerState::sample(),
+                vec![
+                    StateEntityDetailsResponseItem::new(
+                        active.into(),
+                        FungibleResourcesCollection::new(
+                            None,
+                            None,
+                            vec![FungibleResourcesCollectionItem::sample()],
+                        ),
+                        None,
+                        EntityMetadataCollection::empty(),
+                        None,
+                    ),
+                    StateEntityDetailsResponseItem::new(
+                        inactive.into(),
+                        None,
+                        None,
+                        EntityMetadataCollection::empty(),
+                        None,
+                    ),
+                ],
+            ),
+        );
+
+        let mock_driver =
+            MockNetworkingDriver::new_with_responses(vec![response]);
+        let sut = SUT::with_gateway(Arc::new(mock_driver), Gateway::stokenet());
+
+        let result = sut
+            .check_accounts_are_active([active, inactive])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result,
+            IndexMap::<AccountAddress, bool>::from_iter([
+                (active, true),
+                (inactive, false),
+            ])
+        );
+    }
+
+    #[actix_rt::test]
+    async fn failure() {
+        let mock_driver = MockNetworkingDriver::new_always_failing();
+        let sut = SUT::with_gateway(Arc::new(mock_driver), Gateway::stokenet());
+
+        let result = sut
+            .check_accounts_are_active([AccountAddress::sample_stokenet()])
+            .await
+            .expect_err("Expected an error");
+
+        assert_eq!(result, CommonError::NetworkResponseBadCode { code: 500 });
+    }
+}
+
+#[cfg(test)]
+mod fetch_account_third_party_deposits_tests {
+    use crate::prelude::*;
+    use profile_gateway::prelude::Gateway;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = GatewayClient;
+
+    fn details_response(
+        account: AccountAddress,
+        state: impl Into<Option<String>>,
+    ) -> MockNetworkingDriverResponse {
+        let details = StateEntityDetailsResponseComponentDetails {
+            role_assignments: None,
+            state: state.into(),
+        };
+        MockNetworkingDriverResponse::new_success(
+            StateEntityDetailsResponse::new(
+                LedgerState::sample(),
+                vec![StateEntityDetailsResponseItem::new(
+                    account.into(),
+                    None,
+                    None,
+                    EntityMetadataCollection::empty(),
+                    StateEntityDetailsResponseItemDetails::Component(details),
+                )],
+            ),
+        )
+    }
+
+    fn page_response<T: Serialize>(
+        items: Vec<T>,
+    ) -> MockNetworkingDriverResponse {
+        MockNetworkingDriverResponse::new_success(PageResponse::new(
+            LedgerState::sample(),
+            items.len() as u64,
+            None,
+            items,
+        ))
+    }
+
+    #[actix_rt::test]
+    async fn rule_and_lists() {
+        let account = AccountAddress::sample_stokenet();
+        let mock_driver = MockNetworkingDriver::new_with_responses(vec![
+            details_response(
+                account,
+                r#"{ "default_deposit_rule": "Reject" }"#.to_owned(),
+            ),
+            page_response(vec![
+                AccountResourcePreference::sample(),
+                AccountResourcePreference::sample_other(),
+            ]),
+            page_response(vec![AccountAuthorizedDepositor::ResourceBadge {
+                resource_address: ResourceAddress::sample_stokenet_candy(),
+            }]),
+        ]);
+        let sut = SUT::with_gateway(Arc::new(mock_driver), Gateway::stokenet());
+
+        let result = sut
+            .fetch_account_third_party_deposits(
+                account,
+                LedgerStateSelector::sample(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result,
+            ThirdPartyDeposits::with_rule_and_lists(
+                DepositRule::DenyAll,
+                [
+                    AssetException::new(
+                        ResourceAddress::sample_stokenet_xrd(),
+                        DepositAddressExceptionRule::Allow
+                    ),
+                    AssetException::new(
+                        ResourceAddress::sample_stokenet_candy(),
+                        DepositAddressExceptionRule::Deny
+                    ),
+                ],
+                [ResourceOrNonFungible::Resource {
+                    value: ResourceAddress::sample_stokenet_candy()
+                }],
+            )
+        );
+    }
+
+    #[actix_rt::test]
+    async fn without_state_has_default_rule() {
+        let account = AccountAddress::sample_stokenet();
+        let mock_driver = MockNetworkingDriver::new_with_responses(vec![
+            details_response(account, None),
+            page_response::<AccountResourcePreference>(vec![]),
+            page_response::<AccountAuthorizedDepositor>(vec![]),
+        ]);
+        let sut = SUT::with_gateway(Arc::new(mock_driver), Gateway::stokenet());
+
+        let result = sut
+            .fetch_account_third_party_deposits(
+                account,
+                LedgerStateSelector::sample(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result, ThirdPartyDeposits::new(DepositRule::AcceptAll));
+    }
+
+    #[actix_rt::test]
+    async fn batch_of_accounts_keeps_result_per_account() {
+        let account = AccountAddress::sample_stokenet();
+        let other_account = AccountAddress::sample_stokenet_other();
+        let missing_account = AccountAddress::sample_mainnet();
+        let component = |address: AccountAddress| {
+            StateEntityDetailsResponseItem::new(
+                address.into(),
+                None,
+                None,
+                EntityMetadataCollection::empty(),
+                StateEntityDetailsResponseItemDetails::Component(
+                    StateEntityDetailsResponseComponentDetails {
+                        role_assignments: None,
+                        state: None,
+                    },
+                ),
+            )
+        };
+        let mock_driver = MockNetworkingDriver::new_with_responses(vec![
+            // A single request for the deposit rules of all accounts...
+            MockNetworkingDriverResponse::new_success(
+                StateEntityDetailsResponse::new(
+                    LedgerState::sample(),
+                    vec![component(account), component(other_account)],
+                ),
+            ),
+            // ... the lists of the first account ...
+            page_response::<AccountResourcePreference>(vec![]),
+            page_response::<AccountAuthorizedDepositor>(vec![]),
+            // ... and failing to fetch the lists of the other account.
+            MockNetworkingDriverResponse::new_failing(),
+        ]);
+        let sut = SUT::with_gateway(Arc::new(mock_driver), Gateway::stokenet());
+
+        let result = sut
+            .fetch_accounts_third_party_deposits(
+                [account, other_account, missing_account],
+                LedgerStateSelector::sample(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result.keys().cloned().collect_vec(),
+            vec![account, other_account, missing_account]
+        );
+        assert_eq!(
+            result[&account],
+            Ok(ThirdPartyDeposits::new(DepositRule::AcceptAll))
+        );
+        assert!(result[&other_account].is_err());
+        assert!(matches!(
+            result[&missing_account],
+            Err(CommonError::GWMissingResponseItem { .. })
+        ));
+    }
+
+    #[actix_rt::test]
+    async fn missing_item() {
+        let mock_driver = MockNetworkingDriver::new_with_responses(vec![
+            MockNetworkingDriverResponse::new_success(
+                StateEntityDetailsResponse::new(LedgerState::sample(), vec![]),
+            ),
+        ]);
+        let sut = SUT::with_gateway(Arc::new(mock_driver), Gateway::stokenet());
+
+        let result = sut
+            .fetch_account_third_party_deposits(
+                AccountAddress::sample_stokenet(),
+                LedgerStateSelector::sample(),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(CommonError::GWMissingResponseItem { .. })
+        ));
+    }
+}
+
+#[cfg(test)]
+mod fetch_accounts_owner_public_keys_tests {
+    use crate::prelude::*;
+    use profile_gateway::prelude::Gateway;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = GatewayClient;
+
+    fn item(
+        address: AccountAddress,
+        owner_keys: serde_json::Value,
+    ) -> StateEntityDetailsResponseItem {
+        StateEntityDetailsResponseItem::new(
+            address.into(),
+            None,
+            None,
+            serde_json::from_value(serde_json::json!({
+                "items": [
+                    {
+                        "key": "owner_keys",
+                        "value": { "typed": owner_keys }
+                    }
+                ]
+            }))
+            .unwrap(),
+            None,
+        )
+    }
+
+    #[actix_rt::test]
+    async fn only_accounts_with_owner_public_keys() {
+        let with_keys = AccountAddress::sample_stokenet();
+        let with_key_hashes = AccountAddress::sample_stokenet_other();
+        let public_key = Ed25519PublicKey::sample();
+        let mock_driver = MockNetworkingDriver::new_with_responses(vec![
+            MockNetworkingDriverResponse::new_success(
+                StateEntityDetailsResponse::new(
+                    LedgerState::sample(),
+                    vec![
+                        item(
+                            with_keys,
+                            serde_json::json!({
+                                "type": "PublicKeyArray",
+                                "values": [
+                                    {
+                                        "key_type": "EddsaEd25519",
+                                        "key_hex": public_key.to_hex()
+                                    }
+                                ]
+                            }),
+                        ),
+                        item(
+                            with_key_hashes,
+                            serde_json::json!({
+                                "type": "PublicKeyHashArray"
+                            }),
+                        ),
+                    ],
+                ),
+            ),
+        ]);
+        let sut = SUT::with_gateway(Arc::new(mock_driver), Gateway::stokenet());
+
+        let result = sut
+            .fetch_accounts_owner_public_keys([with_keys, with_key_hashes])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result,
+            IndexMap::from_iter([(
+                with_keys,
+                vec![PublicKey::from(public_key)]
+            )])
+        );
+    }
+}
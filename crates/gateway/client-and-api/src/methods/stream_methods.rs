@@ -0,0 +1,97 @@
+use crate::prelude::*;
+
+impl GatewayClient {
+    /// Fetches a page of the transaction history of the account of
+    /// `account_address`, newest first, matching `filters`.
+    ///
+    /// Pass the `next_cursor` of the previous page as `cursor` to fetch the
+    /// next page, a `None` `next_cursor` means there are no more pages.
+    pub async fn fetch_transaction_history(
+        &self,
+        account_address: AccountAddress,
+        filters: TransactionHistoryFilters,
+        cursor: impl Into<Option<String>>,
+    ) -> Result<TransactionHistoryPage> {
+        let request = StreamTransactionsRequest::transaction_history(
+            account_address,
+            filters,
+            cursor,
+            GATEWAY_PAGE_REQUEST_LIMIT,
+        )?;
+        let response = self.stream_transactions(request).await?;
+        TransactionHistoryPage::from_response(response, account_address)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::fixture_gw_model;
+    use profile_gateway::prelude::Gateway;
+
+    use crate::prelude::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = GatewayClient;
+
+    fn mock_response() -> MockNetworkingDriverResponse {
+        MockNetworkingDriverResponse::new_success(
+            fixture::<StreamTransactionsResponse>(fixture_gw_model!(
+                "stream/response_stream_transactions"
+            ))
+            .unwrap(),
+        )
+    }
+
+    #[actix_rt::test]
+    async fn fetch_transaction_history() {
+        let mock_driver = MockNetworkingDriver::new_with_responses_and_spy(
+            vec![mock_response()],
+            |request, _| {
+                assert!(request
+                    .url
+                    .as_str()
+                    .ends_with(GatewayClient::PATH_STREAM_TRANSACTIONS));
+                let expected_request =
+                    StreamTransactionsRequest::transaction_history(
+                        AccountAddress::sample_mainnet(),
+                        TransactionHistoryFilters::sample_other(),
+                        "cursor".to_owned(),
+                        GATEWAY_PAGE_REQUEST_LIMIT,
+                    )
+                    .unwrap();
+                assert_network_request(request, &expected_request);
+            },
+        );
+        let sut = SUT::with_gateway(Arc::new(mock_driver), Gateway::mainnet());
+
+        let page = sut
+            .fetch_transaction_history(
+                AccountAddress::sample_mainnet(),
+                TransactionHistoryFilters::sample_other(),
+                "cursor".to_owned(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(page, TransactionHistoryPage::sample());
+    }
+
+    #[actix_rt::test]
+    async fn fetch_transaction_history_failure() {
+        let mock_driver = MockNetworkingDriver::new_always_failing();
+        let sut = SUT::with_gateway(Arc::new(mock_driver), Gateway::mainnet());
+
+        let result = sut
+            .fetch_transaction_history(
+                AccountAddress::sample_mainnet(),
+                TransactionHistoryFilters::default(),
+                None,
+            )
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommonError::NetworkResponseBadCode { code: 500 })
+        );
+    }
+}
@@ -5,3 +5,12 @@ pub enum GWPublicKey {
     Secp256k1(Secp256k1PublicKey),
     Ed25519(Ed25519PublicKey),
 }
+
+impl From<GWPublicKey> for PublicKey {
+    fn from(value: GWPublicKey) -> Self {
+        match value {
+            GWPublicKey::Secp256k1(key) => Self::Secp256k1(key),
+            GWPublicKey::Ed25519(key) => Self::Ed25519(key),
+        }
+    }
+}
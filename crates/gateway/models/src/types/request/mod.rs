@@ -3,10 +3,12 @@ mod gw_public_key;
 mod gw_public_key_serde;
 mod ledger_state_selector;
 mod state;
+mod stream;
 mod transaction;
 
 pub use extensions::*;
 pub use gw_public_key::*;
 pub use ledger_state_selector::*;
 pub use state::*;
+pub use stream::*;
 pub use transaction::*;
@@ -0,0 +1,100 @@
+use crate::prelude::*;
+
+/// The manifest class the Gateway has classified a committed transaction as,
+/// used both when filtering `/stream/transactions` and in its response.
+///
+/// See [the Gateway API docs for details][doc].
+///
+/// [doc]: https://radix-babylon-gateway-api.redoc.ly/#tag/Stream/operation/StreamTransactions
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum GWManifestClass {
+    General,
+    Transfer,
+    ValidatorStake,
+    ValidatorUnstake,
+    ValidatorClaim,
+    AccountDepositSettingsUpdate,
+    PoolContribution,
+    PoolRedemption,
+}
+
+impl TryFrom<DetailedManifestClassKind> for GWManifestClass {
+    type Error = CommonError;
+
+    /// Fails for kinds the Gateway does not classify, e.g. `DeleteAccounts`
+    /// or `SecurifyEntity`, since filtering by `General` would return
+    /// unrelated transactions.
+    fn try_from(value: DetailedManifestClassKind) -> Result<Self> {
+        match value {
+            DetailedManifestClassKind::General => Ok(Self::General),
+            DetailedManifestClassKind::Transfer => Ok(Self::Transfer),
+            DetailedManifestClassKind::ValidatorStake => {
+                Ok(Self::ValidatorStake)
+            }
+            DetailedManifestClassKind::ValidatorUnstake => {
+                Ok(Self::ValidatorUnstake)
+            }
+            DetailedManifestClassKind::ValidatorClaim => {
+                Ok(Self::ValidatorClaim)
+            }
+            DetailedManifestClassKind::AccountDepositSettingsUpdate => {
+                Ok(Self::AccountDepositSettingsUpdate)
+            }
+            DetailedManifestClassKind::PoolContribution => {
+                Ok(Self::PoolContribution)
+            }
+            DetailedManifestClassKind::PoolRedemption => {
+                Ok(Self::PoolRedemption)
+            }
+            DetailedManifestClassKind::DeleteAccounts
+            | DetailedManifestClassKind::SecurifyEntity
+            | DetailedManifestClassKind::AccessControllerRecovery
+            | DetailedManifestClassKind::AccessControllerStopTimedRecovery
+            | DetailedManifestClassKind::AccessControllerConfirmTimedRecovery
+            | DetailedManifestClassKind::AccessControllerLockPrimaryRole
+            | DetailedManifestClassKind::AccessControllerUnlockPrimaryRole => {
+                Err(CommonError::GatewayUnsupportedManifestClass {
+                    kind: format!("{:?}", value),
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = GWManifestClass;
+
+    #[test]
+    fn json_roundtrip() {
+        assert_json_value_eq_after_roundtrip(
+            &SUT::ValidatorStake,
+            serde_json::Value::String("ValidatorStake".to_owned()),
+        );
+    }
+
+    #[test]
+    fn try_from_kind() {
+        assert_eq!(
+            SUT::try_from(DetailedManifestClassKind::Transfer),
+            Ok(SUT::Transfer)
+        );
+        assert_eq!(
+            SUT::try_from(DetailedManifestClassKind::General),
+            Ok(SUT::General)
+        );
+    }
+
+    #[test]
+    fn try_from_unsupported_kind_is_err() {
+        assert_eq!(
+            SUT::try_from(DetailedManifestClassKind::SecurifyEntity),
+            Err(CommonError::GatewayUnsupportedManifestClass {
+                kind: "SecurifyEntity".to_owned()
+            })
+        );
+    }
+}
@@ -0,0 +1,7 @@
+mod manifest_class;
+mod opt_ins;
+mod stream_transactions;
+
+pub use manifest_class::*;
+pub use opt_ins::*;
+pub use stream_transactions::*;
@@ -0,0 +1,47 @@
+use crate::prelude::*;
+
+/// Opt-ins for additional, optional, fields of the transactions returned by
+/// `/stream/transactions`.
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize, /* Deserialize so we can test roundtrip of JSON vectors */
+)]
+pub struct TransactionDetailsOptIns {
+    /// If `true`, the list of global entities affected by the transaction is
+    /// returned.
+    #[serde(skip_serializing_if = "std::ops::Not::not", default)]
+    pub affected_global_entities: bool,
+
+    /// If `true`, the fungible and non-fungible balance changes of the
+    /// transaction are returned.
+    #[serde(skip_serializing_if = "std::ops::Not::not", default)]
+    pub balance_changes: bool,
+
+    /// If `true`, the manifest instructions of user transactions are returned.
+    #[serde(skip_serializing_if = "std::ops::Not::not", default)]
+    pub manifest_instructions: bool,
+}
+
+impl TransactionDetailsOptIns {
+    pub fn new(
+        affected_global_entities: bool,
+        balance_changes: bool,
+        manifest_instructions: bool,
+    ) -> Self {
+        Self {
+            affected_global_entities,
+            balance_changes,
+            manifest_instructions,
+        }
+    }
+
+    /// The opt-ins needed to build a `TransactionHistoryItem`.
+    pub fn transaction_history() -> Self {
+        Self::new(true, true, false)
+    }
+}
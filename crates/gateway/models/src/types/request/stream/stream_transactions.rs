@@ -0,0 +1,231 @@
+use crate::prelude::*;
+
+/// Limits the transactions returned by `/stream/transactions` to a kind of
+/// ledger transaction.
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StreamTransactionsRequestKindFilter {
+    User,
+    EpochChange,
+    All,
+}
+
+/// The order in which `/stream/transactions` returns transactions, by their
+/// state version.
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StreamTransactionsRequestOrder {
+    Asc,
+    Desc,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize, /* Deserialize so we can test roundtrip of JSON vectors */
+)]
+pub struct StreamTransactionsRequestManifestClassFilter {
+    pub class: GWManifestClass,
+
+    /// If `true`, only transactions where `class` is the most specific class
+    /// of the manifest are returned.
+    pub match_only_most_specific: bool,
+}
+
+impl StreamTransactionsRequestManifestClassFilter {
+    pub fn new(class: GWManifestClass, match_only_most_specific: bool) -> Self {
+        Self {
+            class,
+            match_only_most_specific,
+        }
+    }
+}
+
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize, /* Deserialize so we can test roundtrip of JSON vectors */
+)]
+pub struct StreamTransactionsRequest {
+    /// This allows for a request to be made against a historic state. If a constraint is specified,
+    /// the Gateway will resolve the request against the ledger state at that time.
+    /// If not specified, requests will be made with respect to the top of the committed ledger.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub at_ledger_state: Option<LedgerStateSelector>,
+
+    /// Lower bound of the ledger state of returned transactions, i.e. only
+    /// transactions committed at or after this ledger state are returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_ledger_state: Option<LedgerStateSelector>,
+
+    /// This cursor allows forward pagination, by providing the cursor from the previous request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+
+    /// The page size requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit_per_page: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind_filter: Option<StreamTransactionsRequestKindFilter>,
+
+    /// Only transactions whose manifest references any of these resources
+    /// are returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manifest_resources_filter: Option<Vec<ResourceAddress>>,
+
+    /// Only transactions affecting all of these global entities are returned.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub affected_global_entities_filter: Option<Vec<Address>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manifest_class_filter:
+        Option<StreamTransactionsRequestManifestClassFilter>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<StreamTransactionsRequestOrder>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub opt_ins: Option<TransactionDetailsOptIns>,
+}
+
+impl StreamTransactionsRequest {
+    /// A request for a page of the committed user transactions affecting
+    /// `account_address`, newest first, matching `filters`.
+    ///
+    /// Fails if `filters` has a `kind` the Gateway cannot filter by.
+    pub fn transaction_history(
+        account_address: AccountAddress,
+        filters: TransactionHistoryFilters,
+        cursor: impl Into<Option<String>>,
+        limit_per_page: impl Into<Option<u64>>,
+    ) -> Result<Self> {
+        let selector = |timestamp: Timestamp| {
+            LedgerStateSelector::new(
+                None,
+                timestamp.format().to_string(),
+                None,
+                None,
+            )
+        };
+        let manifest_class_filter = filters
+            .kind
+            .map(|kind| {
+                GWManifestClass::try_from(kind).map(|class| {
+                    StreamTransactionsRequestManifestClassFilter::new(
+                        class, false,
+                    )
+                })
+            })
+            .transpose()?;
+        Ok(Self {
+            at_ledger_state: filters.to_date.map(selector),
+            from_ledger_state: filters.from_date.map(selector),
+            cursor: cursor.into(),
+            limit_per_page: limit_per_page.into(),
+            kind_filter: Some(StreamTransactionsRequestKindFilter::User),
+            manifest_resources_filter: filters.resource.map(|r| vec![r]),
+            affected_global_entities_filter: Some(vec![Address::from(
+                account_address,
+            )]),
+            manifest_class_filter,
+            order: Some(StreamTransactionsRequestOrder::Desc),
+            opt_ins: Some(TransactionDetailsOptIns::transaction_history()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = StreamTransactionsRequest;
+
+    #[test]
+    fn transaction_history_no_filters() {
+        let sut = SUT::transaction_history(
+            AccountAddress::sample_mainnet(),
+            TransactionHistoryFilters::default(),
+            None,
+            25,
+        )
+        .unwrap();
+        assert_json_value_eq_after_roundtrip(
+            &sut,
+            serde_json::json!({
+                "limit_per_page": 25,
+                "kind_filter": "User",
+                "affected_global_entities_filter": [
+                    "account_rdx128y6j78mt0aqv6372evz28hrxp8mn06ccddkr7xppc88hyvynvjdwr"
+                ],
+                "order": "Desc",
+                "opt_ins": {
+                    "affected_global_entities": true,
+                    "balance_changes": true
+                }
+            }),
+        );
+    }
+
+    #[test]
+    fn transaction_history_all_filters() {
+        let sut = SUT::transaction_history(
+            AccountAddress::sample_mainnet(),
+            TransactionHistoryFilters::new(
+                Timestamp::parse("2024-01-01T00:00:00Z").unwrap(),
+                Timestamp::parse("2024-02-01T00:00:00Z").unwrap(),
+                ResourceAddress::sample_mainnet_xrd(),
+                DetailedManifestClassKind::ValidatorStake,
+            ),
+            "cursor".to_owned(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            sut.from_ledger_state.unwrap().timestamp.unwrap(),
+            "2024-01-01T00:00:00.000Z"
+        );
+        assert_eq!(
+            sut.at_ledger_state.unwrap().timestamp.unwrap(),
+            "2024-02-01T00:00:00.000Z"
+        );
+        assert_eq!(sut.cursor, Some("cursor".to_owned()));
+        assert_eq!(
+            sut.manifest_resources_filter,
+            Some(vec![ResourceAddress::sample_mainnet_xrd()])
+        );
+        assert_eq!(
+            sut.manifest_class_filter,
+            Some(StreamTransactionsRequestManifestClassFilter::new(
+                GWManifestClass::ValidatorStake,
+                false
+            ))
+        );
+    }
+
+    #[test]
+    fn transaction_history_unsupported_kind_is_err() {
+        let result = SUT::transaction_history(
+            AccountAddress::sample_mainnet(),
+            TransactionHistoryFilters::new(
+                None,
+                None,
+                None,
+                DetailedManifestClassKind::DeleteAccounts,
+            ),
+            None,
+            None,
+        );
+        assert_eq!(
+            result,
+            Err(CommonError::GatewayUnsupportedManifestClass {
+                kind: "DeleteAccounts".to_owned()
+            })
+        );
+    }
+}
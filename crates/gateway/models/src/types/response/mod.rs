@@ -4,6 +4,7 @@ mod extensions;
 mod page_response;
 mod state;
 mod status;
+mod stream;
 mod transaction;
 
 pub use ledger_state::*;
@@ -12,4 +13,5 @@ pub use extensions::*;
 pub use page_response::*;
 pub use state::*;
 pub use status::*;
+pub use stream::*;
 pub use transaction::*;
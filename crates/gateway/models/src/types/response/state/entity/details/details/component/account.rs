@@ -0,0 +1,90 @@
+use crate::prelude::*;
+
+/// JSON model of the state substate value of a native Account component.
+#[derive(Deserialize, Serialize, Clone, PartialEq, Eq, Debug)]
+pub struct AccountFieldStateValue {
+    /// The rule applied to third-party deposits of resources not listed in
+    /// the resource preferences of the account.
+    pub default_deposit_rule: AccountDefaultDepositRule,
+}
+
+/// The default deposit rule of an account, as returned by the Gateway.
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AccountDefaultDepositRule {
+    Accept,
+    Reject,
+    AllowExisting,
+}
+
+impl From<AccountDefaultDepositRule> for DepositRule {
+    fn from(value: AccountDefaultDepositRule) -> Self {
+        match value {
+            AccountDefaultDepositRule::Accept => Self::AcceptAll,
+            AccountDefaultDepositRule::Reject => Self::DenyAll,
+            AccountDefaultDepositRule::AllowExisting => Self::AcceptKnown,
+        }
+    }
+}
+
+impl HasSampleValues for AccountFieldStateValue {
+    fn sample() -> Self {
+        Self {
+            default_deposit_rule: AccountDefaultDepositRule::Accept,
+        }
+    }
+
+    fn sample_other() -> Self {
+        Self {
+            default_deposit_rule: AccountDefaultDepositRule::AllowExisting,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = AccountFieldStateValue;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn decode_account_state() {
+        let details = StateEntityDetailsResponseComponentDetails {
+            role_assignments: None,
+            state: Some(
+                r#"{ "default_deposit_rule": "AllowExisting" }"#.to_owned(),
+            ),
+        };
+        assert_eq!(
+            details.try_decode_state::<SUT>().unwrap(),
+            Some(SUT::sample_other())
+        );
+    }
+
+    #[test]
+    fn into_deposit_rule() {
+        assert_eq!(
+            DepositRule::from(AccountDefaultDepositRule::Accept),
+            DepositRule::AcceptAll
+        );
+        assert_eq!(
+            DepositRule::from(AccountDefaultDepositRule::Reject),
+            DepositRule::DenyAll
+        );
+        assert_eq!(
+            DepositRule::from(AccountDefaultDepositRule::AllowExisting),
+            DepositRule::AcceptKnown
+        );
+    }
+}
@@ -1,4 +1,8 @@
 mod access_controller;
+mod account;
 mod gw_recovery_proposal_into_scrypto_rule_set;
+mod pool;
 
 pub use access_controller::*;
+pub use account::*;
+pub use pool::*;
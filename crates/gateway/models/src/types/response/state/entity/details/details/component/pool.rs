@@ -0,0 +1,70 @@
+use crate::prelude::*;
+
+/// JSON model of the state substate value of a native One-, Two- or
+/// Multi-Resource Pool, only the fields shared by all three kinds are
+/// decoded.
+#[derive(Deserialize, Serialize, Clone, PartialEq, Eq, Debug)]
+pub struct PoolFieldStateValue {
+    /// The resource minted for contributions to the pool.
+    pub pool_unit_resource_address: ResourceAddress,
+}
+
+impl HasSampleValues for PoolFieldStateValue {
+    fn sample() -> Self {
+        Self {
+            pool_unit_resource_address: "resource_tdx_2_1t4u9jzqe69k3ghuhe4ulrmsthqyk2qw8wh6l4hjkt9r8m0736xx4qd".parse().unwrap(),
+        }
+    }
+
+    fn sample_other() -> Self {
+        Self {
+            pool_unit_resource_address: "resource_tdx_2_1thgnc84xkcjhs46pfvm9s8zn8t9kxwryvyr9x3947xpt6jxty7qn25".parse().unwrap(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = PoolFieldStateValue;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn decode_two_resource_pool_state() {
+        let details = StateEntityDetailsResponseComponentDetails {
+            role_assignments: None,
+            state: Some(
+                r#"{
+                    "vaults": [
+                        {
+                            "resource_address": "resource_tdx_2_1tknxxxxxxxxxradxrdxxxxxxxxx009923554798xxxxxxxxxtfd2jc",
+                            "vault": {
+                                "entity_type": "InternalFungibleVault",
+                                "is_global": false,
+                                "entity_address": "internal_vault_tdx_2_1tr9dv2wt2av5u9juks9x276x5qphccswqgq0g9lgpm00rzs7kzclz4"
+                            }
+                        }
+                    ],
+                    "pool_unit_resource_address": "resource_tdx_2_1t4u9jzqe69k3ghuhe4ulrmsthqyk2qw8wh6l4hjkt9r8m0736xx4qd"
+                }"#
+                .to_owned(),
+            ),
+        };
+        assert_eq!(
+            details.try_decode_state::<SUT>().unwrap(),
+            Some(SUT::sample())
+        );
+    }
+}
@@ -57,6 +57,19 @@ impl StateEntityDetailsResponseItemDetails {
         depositor_allows_all && withdrawer_allows_all
     }
 
+    /// The native resource details, if this is a native resource.
+    pub fn native_resource_details(&self) -> Option<&NativeResourceDetails> {
+        match self {
+            Self::FungibleResource(details) => {
+                details.native_resource_details.as_ref()
+            }
+            Self::NonFungibleResource(details) => {
+                details.native_resource_details.as_ref()
+            }
+            _ => None,
+        }
+    }
+
     fn role_assignments(&self) -> Option<ComponentEntityRoleAssignments> {
         match self {
             Self::FungibleResource(details) => {
@@ -243,6 +256,17 @@ mod tests {
         .0;
 
         assert!(matches!(result, SUT::FungibleResource(_)));
+        assert_eq!(
+            result.native_resource_details(),
+            Some(&NativeResourceDetails::Xrd)
+        );
+        assert_eq!(
+            result.as_fungible_resource().unwrap().total_supply,
+            Some(
+                Decimal192::from_str("1704148276154.93015940148695633")
+                    .unwrap()
+            )
+        );
 
         // Non-Fungible Resource (XRD)
         let result = fixture_and_json::<SUT>(fixture_gw_model!(
@@ -3,10 +3,44 @@ use crate::prelude::*;
 #[derive(Deserialize, Serialize, Clone, PartialEq, Eq, Debug)]
 pub struct StateEntityDetailsResponseFungibleResourceDetails {
     pub role_assignments: ComponentEntityRoleAssignments,
+
+    /// Set if this is a native resource, e.g. the Liquid Stake Unit or claim
+    /// NFT resource of a validator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub native_resource_details: Option<NativeResourceDetails>,
+
+    /// The current total supply of the resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_supply: Option<Decimal192>,
 }
 
 impl StateEntityDetailsResponseFungibleResourceDetails {
     pub fn new(role_assignments: ComponentEntityRoleAssignments) -> Self {
-        Self { role_assignments }
+        Self {
+            role_assignments,
+            native_resource_details: None,
+            total_supply: None,
+        }
+    }
+
+    pub fn native(
+        role_assignments: ComponentEntityRoleAssignments,
+        native_resource_details: NativeResourceDetails,
+    ) -> Self {
+        Self {
+            role_assignments,
+            native_resource_details: Some(native_resource_details),
+            total_supply: None,
+        }
+    }
+
+    pub fn with_total_supply(
+        role_assignments: ComponentEntityRoleAssignments,
+        total_supply: impl Into<Decimal192>,
+    ) -> Self {
+        Self {
+            total_supply: Some(total_supply.into()),
+            ..Self::new(role_assignments)
+        }
     }
 }
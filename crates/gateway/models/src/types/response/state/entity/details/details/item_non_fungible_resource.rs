@@ -3,10 +3,28 @@ use crate::prelude::*;
 #[derive(Deserialize, Serialize, Clone, PartialEq, Eq, Debug)]
 pub struct StateEntityDetailsResponseNonFungibleResourceDetails {
     pub role_assignments: ComponentEntityRoleAssignments,
+
+    /// Set if this is a native resource, e.g. the Liquid Stake Unit or claim
+    /// NFT resource of a validator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub native_resource_details: Option<NativeResourceDetails>,
 }
 
 impl StateEntityDetailsResponseNonFungibleResourceDetails {
     pub fn new(role_assignments: ComponentEntityRoleAssignments) -> Self {
-        Self { role_assignments }
+        Self {
+            role_assignments,
+            native_resource_details: None,
+        }
+    }
+
+    pub fn native(
+        role_assignments: ComponentEntityRoleAssignments,
+        native_resource_details: NativeResourceDetails,
+    ) -> Self {
+        Self {
+            role_assignments,
+            native_resource_details: Some(native_resource_details),
+        }
     }
 }
@@ -4,6 +4,7 @@ mod item_component;
 mod item_fungible_resource;
 mod item_non_fungible_resource;
 mod item_package;
+mod native_resource_details;
 pub mod role_assignments;
 
 pub use component::*;
@@ -12,4 +13,5 @@ pub use item_component::*;
 pub use item_fungible_resource::*;
 pub use item_non_fungible_resource::*;
 pub use item_package::*;
+pub use native_resource_details::*;
 pub use role_assignments::*;
@@ -0,0 +1,236 @@
+use crate::prelude::*;
+
+/// Details of native resources, i.e. resources created by the Radix Engine
+/// itself, such as the Liquid Stake Units and claim NFTs of validators.
+#[derive(Deserialize, Serialize, Clone, PartialEq, Eq, Debug, EnumAsInner)]
+#[serde(tag = "kind")]
+pub enum NativeResourceDetails {
+    Xrd,
+    ValidatorLiquidStakeUnit(NativeResourceValidatorLiquidStakeUnitValue),
+    ValidatorClaimNft(NativeResourceValidatorClaimNftValue),
+    OneResourcePoolUnit(NativeResourcePoolUnitValue),
+    TwoResourcePoolUnit(NativeResourcePoolUnitValue),
+    MultiResourcePoolUnit(NativeResourcePoolUnitValue),
+
+    /// Any other kind of native resource, e.g. owner badges, which we do not
+    /// (yet) need to inspect.
+    #[serde(other)]
+    Other,
+}
+
+impl NativeResourceDetails {
+    /// The details of the pool unit, if this is the pool unit resource of a
+    /// One-, Two- or Multi-Resource Pool.
+    pub fn pool_unit(&self) -> Option<&NativeResourcePoolUnitValue> {
+        match self {
+            Self::OneResourcePoolUnit(value)
+            | Self::TwoResourcePoolUnit(value)
+            | Self::MultiResourcePoolUnit(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// The Liquid Stake Unit (LSU) resource of `validator_address`.
+#[derive(Deserialize, Serialize, Clone, PartialEq, Eq, Debug)]
+pub struct NativeResourceValidatorLiquidStakeUnitValue {
+    pub validator_address: ValidatorAddress,
+
+    /// The amount of each resource a single LSU can be redeemed for, i.e.
+    /// the XRD staked to the validator divided by the LSU total supply.
+    pub unit_redemption_value: Vec<NativeResourceRedemptionValueItem>,
+}
+
+impl NativeResourceValidatorLiquidStakeUnitValue {
+    pub fn new(
+        validator_address: ValidatorAddress,
+        unit_redemption_value: impl IntoIterator<
+            Item = NativeResourceRedemptionValueItem,
+        >,
+    ) -> Self {
+        Self {
+            validator_address,
+            unit_redemption_value: unit_redemption_value.into_iter().collect(),
+        }
+    }
+
+    /// The amount of XRD a single LSU can be redeemed for, `0` if unknown.
+    pub fn xrd_redemption_value(&self) -> Decimal192 {
+        let xrd = ResourceAddress::xrd_on_network(
+            self.validator_address.network_id(),
+        );
+        self.unit_redemption_value
+            .iter()
+            .find(|item| item.resource_address == xrd)
+            .and_then(|item| item.amount)
+            .unwrap_or(Decimal192::zero())
+    }
+}
+
+/// The pool unit resource of the native pool at `pool_address`.
+#[derive(Deserialize, Serialize, Clone, PartialEq, Eq, Debug)]
+pub struct NativeResourcePoolUnitValue {
+    pub pool_address: PoolAddress,
+
+    /// The amount of each resource of the pool a single pool unit can be
+    /// redeemed for.
+    pub unit_redemption_value: Vec<NativeResourceRedemptionValueItem>,
+}
+
+impl NativeResourcePoolUnitValue {
+    pub fn new(
+        pool_address: PoolAddress,
+        unit_redemption_value: impl IntoIterator<
+            Item = NativeResourceRedemptionValueItem,
+        >,
+    ) -> Self {
+        Self {
+            pool_address,
+            unit_redemption_value: unit_redemption_value.into_iter().collect(),
+        }
+    }
+}
+
+/// The claim NFT resource of `validator_address`, minted when unstaking.
+#[derive(Deserialize, Serialize, Clone, PartialEq, Eq, Debug)]
+pub struct NativeResourceValidatorClaimNftValue {
+    pub validator_address: ValidatorAddress,
+}
+
+impl NativeResourceValidatorClaimNftValue {
+    pub fn new(validator_address: ValidatorAddress) -> Self {
+        Self { validator_address }
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, PartialEq, Eq, Debug)]
+pub struct NativeResourceRedemptionValueItem {
+    pub resource_address: ResourceAddress,
+
+    /// `None` if the unit has no redemption value, e.g. the total supply of
+    /// the unit is zero.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<Decimal192>,
+}
+
+impl NativeResourceRedemptionValueItem {
+    pub fn new(
+        resource_address: ResourceAddress,
+        amount: impl Into<Option<Decimal192>>,
+    ) -> Self {
+        Self {
+            resource_address,
+            amount: amount.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = NativeResourceDetails;
+
+    #[test]
+    fn json_decode_liquid_stake_unit() {
+        let json = r#"{
+            "kind": "ValidatorLiquidStakeUnit",
+            "validator_address": "validator_tdx_2_1sdtnujyn3720ymg8lakydkvc5tw4q3zecdj95akdwt9de362mvtd94",
+            "redemption_resource_count": 1,
+            "unit_redemption_value": [
+                {
+                    "resource_address": "resource_tdx_2_1tknxxxxxxxxxradxrdxxxxxxxxx009923554798xxxxxxxxxtfd2jc",
+                    "amount": "1.05"
+                }
+            ]
+        }"#;
+        let sut = serde_json::from_str::<SUT>(json).unwrap();
+        let lsu = sut.as_validator_liquid_stake_unit().unwrap();
+        assert_eq!(
+            lsu.xrd_redemption_value(),
+            Decimal192::from_str("1.05").unwrap()
+        );
+    }
+
+    #[test]
+    fn json_decode_claim_nft() {
+        let json = r#"{
+            "kind": "ValidatorClaimNft",
+            "validator_address": "validator_tdx_2_1sdtnujyn3720ymg8lakydkvc5tw4q3zecdj95akdwt9de362mvtd94"
+        }"#;
+        let sut = serde_json::from_str::<SUT>(json).unwrap();
+        assert_eq!(
+            sut,
+            SUT::ValidatorClaimNft(NativeResourceValidatorClaimNftValue::new(
+                ValidatorAddress::sample_stokenet_other()
+            ))
+        );
+    }
+
+    #[test]
+    fn json_decode_pool_units() {
+        let json = |kind: &str| {
+            format!(
+                r#"{{
+                    "kind": "{}",
+                    "pool_address": "pool_tdx_2_1c3qzq55xdg6a66kn0qsdnw2zwvvxwljx5m3cp7xcdzeym3kpnzmpcp",
+                    "redemption_resource_count": 1,
+                    "unit_redemption_value": [
+                        {{
+                            "resource_address": "resource_tdx_2_1tknxxxxxxxxxradxrdxxxxxxxxx009923554798xxxxxxxxxtfd2jc",
+                            "amount": "2.5"
+                        }}
+                    ]
+                }}"#,
+                kind
+            )
+        };
+        let expected = NativeResourcePoolUnitValue::new(
+            PoolAddress::sample_stokenet_single_pool(),
+            [NativeResourceRedemptionValueItem::new(
+                ResourceAddress::sample_stokenet_xrd(),
+                Decimal192::from_str("2.5").unwrap(),
+            )],
+        );
+        for kind in [
+            "OneResourcePoolUnit",
+            "TwoResourcePoolUnit",
+            "MultiResourcePoolUnit",
+        ] {
+            let sut = serde_json::from_str::<SUT>(&json(kind)).unwrap();
+            assert_eq!(sut.pool_unit(), Some(&expected));
+        }
+    }
+
+    #[test]
+    fn pool_unit_of_other_kinds_is_none() {
+        assert_eq!(SUT::Xrd.pool_unit(), None);
+        assert_eq!(SUT::Other.pool_unit(), None);
+    }
+
+    #[test]
+    fn json_decode_other_kinds() {
+        let xrd = serde_json::from_str::<SUT>(r#"{ "kind": "Xrd" }"#).unwrap();
+        assert_eq!(xrd, SUT::Xrd);
+
+        let json = r#"{
+            "kind": "AccountOwnerBadge",
+            "vault_address": "internal_vault_tdx_2_1nrs3a0qw5qfsx83hvkn8h6l6pfsc703gr8k5wz24gup97zxjsct0t7"
+        }"#;
+        let other = serde_json::from_str::<SUT>(json).unwrap();
+        assert_eq!(other, SUT::Other);
+    }
+
+    #[test]
+    fn xrd_redemption_value_unknown() {
+        let sut = NativeResourceValidatorLiquidStakeUnitValue::new(
+            ValidatorAddress::sample_stokenet(),
+            [NativeResourceRedemptionValueItem::new(
+                ResourceAddress::xrd_on_network(NetworkID::Stokenet),
+                None,
+            )],
+        );
+        assert_eq!(sut.xrd_redemption_value(), Decimal192::zero());
+    }
+}
@@ -36,4 +36,48 @@ impl EntityMetadataCollection {
             _ => None,
         }
     }
+
+    pub fn get_account_type(&self) -> Option<String> {
+        let typed = self.get_value(MetadataKey::AccountType)?;
+
+        match typed {
+            MetadataTypedValue::MetadataStringValue { value } => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn get_claimed_websites(&self) -> Option<Vec<String>> {
+        let typed = self.get_value(MetadataKey::ClaimedWebsites)?;
+
+        match typed {
+            MetadataTypedValue::MetadataOriginArrayValue { values } => {
+                Some(values)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn get_claimed_entities(&self) -> Option<Vec<String>> {
+        let typed = self.get_value(MetadataKey::ClaimedEntities)?;
+
+        match typed {
+            MetadataTypedValue::MetadataGlobalAddressArrayValue { values } => {
+                Some(values)
+            }
+            _ => None,
+        }
+    }
+
+    /// The public keys in the `owner_keys` metadata, `None` if it is not
+    /// set or - as by default for accounts - only holds key hashes.
+    pub fn get_owner_public_keys(&self) -> Option<Vec<PublicKey>> {
+        let typed = self.get_value(MetadataKey::OwnerKeys)?;
+
+        match typed {
+            MetadataTypedValue::MetadataPublicKeyArrayValue { values } => {
+                Some(values.into_iter().map(PublicKey::from).collect())
+            }
+            _ => None,
+        }
+    }
 }
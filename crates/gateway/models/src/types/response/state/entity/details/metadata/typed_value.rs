@@ -1,6 +1,8 @@
 use crate::prelude::*;
 
-/// Note: Current implementation only adds support for associated values of `MetadataStringValue` & `MetadataUrlValue` variants.
+/// Note: Current implementation only adds support for associated values of `MetadataStringValue`,
+/// `MetadataUrlValue`, `MetadataOriginArrayValue`, `MetadataGlobalAddressArrayValue` &
+/// `MetadataPublicKeyArrayValue` variants.
 ///
 /// Will need to add support for remaining variants if we want to check its values.
 #[derive(Deserialize, Serialize, Clone, PartialEq, Eq, Debug)]
@@ -17,7 +19,7 @@ pub enum MetadataTypedValue {
     #[serde(rename = "GlobalAddress")]
     MetadataGlobalAddressValue {},
     #[serde(rename = "GlobalAddressArray")]
-    MetadataGlobalAddressArrayValue {},
+    MetadataGlobalAddressArrayValue { values: Vec<String> },
     #[serde(rename = "I32")]
     MetadataI32Value {},
     #[serde(rename = "I32Array")]
@@ -41,11 +43,11 @@ pub enum MetadataTypedValue {
     #[serde(rename = "Origin")]
     MetadataOriginValue {},
     #[serde(rename = "OriginArray")]
-    MetadataOriginArrayValue {},
+    MetadataOriginArrayValue { values: Vec<String> },
     #[serde(rename = "PublicKey")]
     MetadataPublicKeyValue {},
     #[serde(rename = "PublicKeyArray")]
-    MetadataPublicKeyArrayValue {},
+    MetadataPublicKeyArrayValue { values: Vec<GWPublicKey> },
     #[serde(rename = "PublicKeyHash")]
     MetadataPublicKeyHashValue {},
     #[serde(rename = "PublicKeyHashArray")]
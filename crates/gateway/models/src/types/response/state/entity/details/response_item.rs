@@ -1,3 +1,5 @@
+use metadata::prelude::MetadataKey;
+
 use crate::prelude::*;
 
 #[derive(
@@ -50,6 +52,31 @@ impl StateEntityDetailsResponseItem {
         };
         details.can_be_transferred()
     }
+
+    /// Whether this entity has been used on ledger, i.e. it has its
+    /// `owner_keys` metadata set or it holds any resources.
+    ///
+    /// Virtual entities which have never been used on ledger are returned
+    /// by the Gateway without any metadata or resources.
+    pub fn is_active(&self) -> bool {
+        let has_owner_keys = self
+            .metadata
+            .items
+            .iter()
+            .any(|item| item.key == MetadataKey::OwnerKeys.to_string());
+
+        let has_fungibles = self
+            .fungible_resources
+            .as_ref()
+            .is_some_and(|collection| !collection.items.is_empty());
+
+        let has_non_fungibles = self
+            .non_fungible_resources
+            .as_ref()
+            .is_some_and(|collection| !collection.items.is_empty());
+
+        has_owner_keys || has_fungibles || has_non_fungibles
+    }
 }
 
 #[cfg(test)]
@@ -101,4 +128,55 @@ mod tests {
         );
         assert!(!sut.can_be_transferred());
     }
+
+    #[test]
+    fn is_active() {
+        // Test case: no metadata and no resources
+        let sut = SUT::new(
+            Address::sample(),
+            None,
+            None,
+            EntityMetadataCollection::empty(),
+            None,
+        );
+        assert!(!sut.is_active());
+
+        // Test case: empty resource collections
+        let sut = SUT::new(
+            Address::sample(),
+            FungibleResourcesCollection::new(None, None, vec![]),
+            NonFungibleResourcesCollection::new(None, None, vec![]),
+            EntityMetadataCollection::empty(),
+            None,
+        );
+        assert!(!sut.is_active());
+
+        // Test case: holds fungible resources
+        let sut = SUT::new(
+            Address::sample(),
+            FungibleResourcesCollection::new(
+                None,
+                None,
+                vec![FungibleResourcesCollectionItem::sample()],
+            ),
+            None,
+            EntityMetadataCollection::empty(),
+            None,
+        );
+        assert!(sut.is_active());
+
+        // Test case: holds non fungible resources
+        let sut = SUT::new(
+            Address::sample(),
+            None,
+            NonFungibleResourcesCollection::new(
+                None,
+                None,
+                vec![NonFungibleResourcesCollectionItem::sample()],
+            ),
+            EntityMetadataCollection::empty(),
+            None,
+        );
+        assert!(sut.is_active());
+    }
 }
@@ -19,6 +19,8 @@ pub trait ScryptoSborValueFieldExtraction {
         &self,
         name: &str,
     ) -> Option<NonFungibleLocalId>;
+    fn get_decimal_field(&self, name: &str) -> Option<Decimal192>;
+    fn get_u64_field(&self, name: &str) -> Option<u64>;
 
     fn first_string_field(&self) -> Option<String>;
 
@@ -115,4 +117,30 @@ impl ScryptoSborValueFieldExtraction for Vec<ProgrammaticScryptoSborValue> {
             _ => None,
         })
     }
+
+    fn get_decimal_field(&self, name: &str) -> Option<Decimal192> {
+        self.iter().find_map(|field| match field {
+            ProgrammaticScryptoSborValue::Decimal(decimal_sbor_value) => {
+                if decimal_sbor_value.field_name == Some(name.to_owned()) {
+                    Decimal192::from_str(&decimal_sbor_value.value).ok()
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        })
+    }
+
+    fn get_u64_field(&self, name: &str) -> Option<u64> {
+        self.iter().find_map(|field| match field {
+            ProgrammaticScryptoSborValue::U64(u64_sbor_value) => {
+                if u64_sbor_value.field_name == Some(name.to_owned()) {
+                    u64_sbor_value.value.parse().ok()
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        })
+    }
 }
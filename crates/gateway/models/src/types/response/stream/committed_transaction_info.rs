@@ -0,0 +1,101 @@
+use crate::prelude::*;
+
+/// A page of committed transactions returned by `/stream/transactions`.
+pub type StreamTransactionsResponse = PageResponse<CommittedTransactionInfo>;
+
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CommittedTransactionStatus {
+    Unknown,
+    CommittedSuccess,
+    CommittedFailure,
+    Pending,
+    Rejected,
+}
+
+/// A transaction committed to ledger, as returned by `/stream/transactions`,
+/// optional fields are only present if the corresponding opt-in was set.
+#[derive(
+    Deserialize,
+    Serialize, /* Serialize so we can test roundtrip of JSON vectors */
+    Clone,
+    PartialEq,
+    Eq,
+    Debug,
+)]
+pub struct CommittedTransactionInfo {
+    /// The state version of the ledger at which this transaction was committed.
+    pub state_version: u64,
+
+    pub epoch: u64,
+
+    pub round: u64,
+
+    pub round_timestamp: String,
+
+    pub transaction_status: CommittedTransactionStatus,
+
+    /// Bech32m-encoded hash of the notarized transaction payload.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_hash: Option<String>,
+
+    /// Bech32m-encoded hash of the transaction intent, only present for user
+    /// transactions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub intent_hash: Option<String>,
+
+    /// The total fee paid for the transaction, in XRD.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fee_paid: Option<Decimal192>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub affected_global_entities: Option<Vec<String>>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirmed_at: Option<Timestamp>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<GWTransactionMessage>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balance_changes: Option<TransactionBalanceChanges>,
+
+    /// The manifest classes of the transaction, ordered from the most to the
+    /// least specific.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manifest_classes: Option<Vec<GWManifestClass>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::fixture_gw_model;
+
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = StreamTransactionsResponse;
+
+    #[test]
+    fn json_test() {
+        let response = fixture::<SUT>(fixture_gw_model!(
+            "stream/response_stream_transactions"
+        ))
+        .unwrap();
+
+        assert_eq!(response.items.len(), 2);
+        assert_eq!(response.next_cursor, Some("eyJ2IjoxfQ==".to_owned()));
+
+        let transfer = response.items.first().unwrap();
+        assert_eq!(
+            transfer.transaction_status,
+            CommittedTransactionStatus::CommittedSuccess
+        );
+        assert_eq!(
+            transfer.manifest_classes,
+            Some(vec![GWManifestClass::Transfer, GWManifestClass::General])
+        );
+        assert!(transfer.message.is_some());
+    }
+}
@@ -0,0 +1,7 @@
+mod committed_transaction_info;
+mod transaction_balance_changes;
+mod transaction_message;
+
+pub use committed_transaction_info::*;
+pub use transaction_balance_changes::*;
+pub use transaction_message::*;
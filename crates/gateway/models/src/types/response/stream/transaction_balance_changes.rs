@@ -0,0 +1,86 @@
+use crate::prelude::*;
+
+/// The balance changes of a committed transaction, requires the
+/// `balance_changes` opt-in.
+#[derive(
+    Deserialize,
+    Serialize, /* Serialize so we can test roundtrip of JSON vectors */
+    Clone,
+    PartialEq,
+    Eq,
+    Debug,
+)]
+pub struct TransactionBalanceChanges {
+    /// The fee related balance changes, e.g. fee payments and royalties.
+    pub fungible_fee_balance_changes: Vec<TransactionFungibleFeeBalanceChange>,
+
+    /// The non fee related fungible balance changes.
+    pub fungible_balance_changes: Vec<TransactionFungibleBalanceChange>,
+
+    pub non_fungible_balance_changes: Vec<TransactionNonFungibleBalanceChange>,
+}
+
+#[derive(
+    Deserialize,
+    Serialize, /* Serialize so we can test roundtrip of JSON vectors */
+    Clone,
+    PartialEq,
+    Eq,
+    Debug,
+)]
+pub struct TransactionFungibleBalanceChange {
+    /// Bech32m-encoded address of the entity whose balance changed.
+    pub entity_address: String,
+
+    pub resource_address: ResourceAddress,
+
+    /// Negative for withdrawals, positive for deposits.
+    pub balance_change: Decimal192,
+}
+
+#[derive(Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TransactionFungibleFeeBalanceChangeType {
+    FeePayment,
+    FeeDistributed,
+    TipDistributed,
+    RoyaltyDistributed,
+}
+
+#[derive(
+    Deserialize,
+    Serialize, /* Serialize so we can test roundtrip of JSON vectors */
+    Clone,
+    PartialEq,
+    Eq,
+    Debug,
+)]
+pub struct TransactionFungibleFeeBalanceChange {
+    #[serde(rename = "type")]
+    pub change_type: TransactionFungibleFeeBalanceChangeType,
+
+    /// Bech32m-encoded address of the entity whose balance changed.
+    pub entity_address: String,
+
+    pub resource_address: ResourceAddress,
+
+    pub balance_change: Decimal192,
+}
+
+#[derive(
+    Deserialize,
+    Serialize, /* Serialize so we can test roundtrip of JSON vectors */
+    Clone,
+    PartialEq,
+    Eq,
+    Debug,
+)]
+pub struct TransactionNonFungibleBalanceChange {
+    /// Bech32m-encoded address of the entity whose balance changed.
+    pub entity_address: String,
+
+    pub resource_address: ResourceAddress,
+
+    pub added: Vec<NonFungibleLocalId>,
+
+    pub removed: Vec<NonFungibleLocalId>,
+}
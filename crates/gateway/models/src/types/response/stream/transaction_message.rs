@@ -0,0 +1,190 @@
+use crate::prelude::*;
+
+/// The message of a committed transaction, as returned by the Gateway.
+#[derive(
+    Deserialize,
+    Serialize, /* Serialize so we can test roundtrip of JSON vectors */
+    Clone,
+    PartialEq,
+    Eq,
+    Debug,
+)]
+#[serde(tag = "type")]
+pub enum GWTransactionMessage {
+    Plaintext {
+        mime_type: String,
+        content: GWPlaintextMessageContent,
+    },
+    Encrypted {
+        encrypted_hex: BagOfBytes,
+        curve_decryptor_sets: Vec<GWEncryptedMessageCurveDecryptorSet>,
+    },
+}
+
+#[derive(
+    Deserialize,
+    Serialize, /* Serialize so we can test roundtrip of JSON vectors */
+    Clone,
+    PartialEq,
+    Eq,
+    Debug,
+)]
+#[serde(tag = "type")]
+pub enum GWPlaintextMessageContent {
+    String { value: String },
+    Binary { value_hex: BagOfBytes },
+}
+
+#[derive(
+    Deserialize,
+    Serialize, /* Serialize so we can test roundtrip of JSON vectors */
+    Clone,
+    PartialEq,
+    Eq,
+    Debug,
+)]
+pub struct GWEncryptedMessageCurveDecryptorSet {
+    pub dh_ephemeral_public_key: GWPublicKey,
+    pub decryptors: Vec<GWEncryptedMessageDecryptor>,
+}
+
+#[derive(
+    Deserialize,
+    Serialize, /* Serialize so we can test roundtrip of JSON vectors */
+    Clone,
+    PartialEq,
+    Eq,
+    Debug,
+)]
+pub struct GWEncryptedMessageDecryptor {
+    pub public_key_fingerprint_hex: Exactly8Bytes,
+    pub aes_wrapped_key_hex: Exactly24Bytes,
+}
+
+impl From<GWPlaintextMessageContent> for MessageContents {
+    fn from(value: GWPlaintextMessageContent) -> Self {
+        match value {
+            GWPlaintextMessageContent::String { value } => {
+                Self::StringMessage { string: value }
+            }
+            GWPlaintextMessageContent::Binary { value_hex } => {
+                Self::BinaryMessage {
+                    bag_of_bytes: value_hex,
+                }
+            }
+        }
+    }
+}
+
+impl From<GWEncryptedMessageCurveDecryptorSet> for DecryptorsByCurve {
+    fn from(value: GWEncryptedMessageCurveDecryptorSet) -> Self {
+        Self::new(
+            PublicKey::from(value.dh_ephemeral_public_key),
+            value.decryptors.into_iter().map(|d| {
+                MessageDecryptor::new(
+                    PublicKeyFingerprint {
+                        bytes: d.public_key_fingerprint_hex,
+                    },
+                    d.aes_wrapped_key_hex,
+                )
+            }),
+        )
+    }
+}
+
+impl From<GWTransactionMessage> for Message {
+    fn from(value: GWTransactionMessage) -> Self {
+        match value {
+            GWTransactionMessage::Plaintext { mime_type, content } => {
+                Self::PlainText {
+                    plaintext: PlaintextMessage {
+                        mime_type,
+                        message: content.into(),
+                    },
+                }
+            }
+            GWTransactionMessage::Encrypted {
+                encrypted_hex,
+                curve_decryptor_sets,
+            } => Self::Encrypted {
+                encrypted: EncryptedMessage {
+                    encrypted: encrypted_hex,
+                    decryptors_by_curve: curve_decryptor_sets
+                        .into_iter()
+                        .map(DecryptorsByCurve::from)
+                        .collect(),
+                },
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = GWTransactionMessage;
+
+    #[test]
+    fn plaintext_string_into_message() {
+        let sut: SUT = serde_json::from_value(serde_json::json!({
+            "type": "Plaintext",
+            "mime_type": "text/plain",
+            "content": {
+                "type": "String",
+                "value": "Hello Radix"
+            }
+        }))
+        .unwrap();
+        assert_eq!(Message::from(sut), Message::plain_text("Hello Radix"));
+    }
+
+    #[test]
+    fn plaintext_binary_into_message() {
+        let sut: SUT = serde_json::from_value(serde_json::json!({
+            "type": "Plaintext",
+            "mime_type": "application/octet-stream",
+            "content": {
+                "type": "Binary",
+                "value_hex": "deadbeef"
+            }
+        }))
+        .unwrap();
+        let message = Message::from(sut);
+        let plaintext = message.as_plain_text().unwrap();
+        assert_eq!(
+            plaintext.message,
+            MessageContents::BinaryMessage {
+                bag_of_bytes: BagOfBytes::from_hex("deadbeef").unwrap()
+            }
+        );
+    }
+
+    #[test]
+    fn encrypted_into_message() {
+        let encrypted = EncryptedMessage::sample();
+        let decryptors_by_curve =
+            encrypted.decryptors_by_curve.first().unwrap();
+        let decryptor = decryptors_by_curve.decryptors.first().unwrap();
+        let sut: SUT = serde_json::from_value(serde_json::json!({
+            "type": "Encrypted",
+            "encrypted_hex": encrypted.encrypted.to_hex(),
+            "curve_decryptor_sets": [{
+                "dh_ephemeral_public_key": GWPublicKey::from(decryptors_by_curve.dh_ephemeral_public_key),
+                "decryptors": [{
+                    "public_key_fingerprint_hex": decryptor.public_key_fingerprint.bytes.to_hex(),
+                    "aes_wrapped_key_hex": decryptor.aes_wrapped_key.to_hex()
+                }]
+            }]
+        }))
+        .unwrap();
+        let message = Message::from(sut);
+        let from_gateway = message.as_encrypted().unwrap();
+        assert_eq!(from_gateway.encrypted, encrypted.encrypted);
+        assert_eq!(
+            from_gateway.decryptors_by_curve.first().unwrap(),
+            decryptors_by_curve
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use crate::prelude::*;
+
+/// The fungibles and non fungibles held by an account.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AccountHoldings {
+    pub account_address: AccountAddress,
+
+    /// The fungibles held, with a non zero amount.
+    pub fungibles: Vec<FungibleHolding>,
+
+    /// All non fungibles held.
+    pub non_fungibles: Vec<NonFungibleHolding>,
+}
+
+impl AccountHoldings {
+    pub fn new(
+        account_address: AccountAddress,
+        fungibles: impl IntoIterator<Item = FungibleHolding>,
+        non_fungibles: impl IntoIterator<Item = NonFungibleHolding>,
+    ) -> Self {
+        Self {
+            account_address,
+            fungibles: fungibles.into_iter().collect(),
+            non_fungibles: non_fungibles.into_iter().collect(),
+        }
+    }
+}
+
+/// The holdings of many accounts, all read at the same ledger state.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AccountsHoldings {
+    /// The state version of the ledger the holdings were read at.
+    pub state_version: u64,
+    pub accounts: Vec<AccountHoldings>,
+}
+
+impl AccountsHoldings {
+    pub fn new(
+        state_version: u64,
+        accounts: impl IntoIterator<Item = AccountHoldings>,
+    ) -> Self {
+        Self {
+            state_version,
+            accounts: accounts.into_iter().collect(),
+        }
+    }
+}
+
+impl HasSampleValues for AccountHoldings {
+    fn sample() -> Self {
+        Self::new(
+            AccountAddress::sample_stokenet(),
+            [FungibleHolding::sample(), FungibleHolding::sample_other()],
+            [NonFungibleHolding::sample()],
+        )
+    }
+
+    fn sample_other() -> Self {
+        Self::new(
+            AccountAddress::sample_stokenet_other(),
+            [FungibleHolding::sample()],
+            [],
+        )
+    }
+}
+
+impl HasSampleValues for AccountsHoldings {
+    fn sample() -> Self {
+        Self::new(
+            80577579,
+            [AccountHoldings::sample(), AccountHoldings::sample_other()],
+        )
+    }
+
+    fn sample_other() -> Self {
+        Self::new(80577580, [])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = AccountsHoldings;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+}
@@ -0,0 +1,255 @@
+use crate::prelude::*;
+
+/// An amount of a resource which a fungible holding is made up of, or can be
+/// redeemed for.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UnderlyingResourceAmount {
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal192,
+}
+
+impl UnderlyingResourceAmount {
+    pub fn new(
+        resource_address: ResourceAddress,
+        amount: impl Into<Decimal192>,
+    ) -> Self {
+        Self {
+            resource_address,
+            amount: amount.into(),
+        }
+    }
+}
+
+/// The kind of a fungible resource held by an account, as identified by its
+/// `native_resource_details`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FungibleHoldingKind {
+    /// Any fungible which is not an LSU or pool unit, e.g. XRD.
+    Token,
+
+    /// The Liquid Stake Unit of `validator_address`.
+    LiquidStakeUnit { validator_address: ValidatorAddress },
+
+    /// The pool unit of the native pool at `pool_address`.
+    PoolUnit { pool_address: PoolAddress },
+}
+
+/// An amount of a fungible resource held by an account.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FungibleHolding {
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal192,
+    pub kind: FungibleHoldingKind,
+
+    /// What the held `amount` is worth in terms of other resources: the
+    /// resource itself for tokens and the resources `amount` can be redeemed
+    /// for, for LSUs and pool units.
+    ///
+    /// `None` if the redemption value of any of those resources is unknown,
+    /// e.g. if the total supply of the unit is zero.
+    pub underlying: Option<Vec<UnderlyingResourceAmount>>,
+}
+
+impl FungibleHolding {
+    /// Creates a holding of `amount` of `resource_address`, using the
+    /// `native_resource_details` of the resource - if any - to resolve LSUs
+    /// and pool units into the resources they can be redeemed for.
+    pub fn new(
+        resource_address: ResourceAddress,
+        amount: impl Into<Decimal192>,
+        native_resource_details: Option<&NativeResourceDetails>,
+    ) -> Self {
+        let amount = amount.into();
+        let redeemable_for =
+            |unit_redemption_value: &[NativeResourceRedemptionValueItem]| {
+                unit_redemption_value
+                    .iter()
+                    .map(|item| {
+                        item.amount.map(|unit_amount| {
+                            UnderlyingResourceAmount::new(
+                                item.resource_address,
+                                amount * unit_amount,
+                            )
+                        })
+                    })
+                    .collect::<Option<Vec<_>>>()
+            };
+
+        let unit = native_resource_details.and_then(|details| match details {
+            NativeResourceDetails::ValidatorLiquidStakeUnit(lsu) => Some((
+                FungibleHoldingKind::LiquidStakeUnit {
+                    validator_address: lsu.validator_address,
+                },
+                lsu.unit_redemption_value.as_slice(),
+            )),
+            _ => details.pool_unit().map(|pool_unit| {
+                (
+                    FungibleHoldingKind::PoolUnit {
+                        pool_address: pool_unit.pool_address,
+                    },
+                    pool_unit.unit_redemption_value.as_slice(),
+                )
+            }),
+        });
+
+        let (kind, underlying) = match unit {
+            Some((kind, unit_redemption_value)) => {
+                (kind, redeemable_for(unit_redemption_value))
+            }
+            None => (
+                FungibleHoldingKind::Token,
+                Some(vec![UnderlyingResourceAmount::new(
+                    resource_address,
+                    amount,
+                )]),
+            ),
+        };
+
+        Self {
+            resource_address,
+            amount,
+            kind,
+            underlying,
+        }
+    }
+}
+
+impl HasSampleValues for FungibleHolding {
+    fn sample() -> Self {
+        Self::new(
+            ResourceAddress::sample_stokenet_xrd(),
+            Decimal192::from(100),
+            Some(&NativeResourceDetails::Xrd),
+        )
+    }
+
+    fn sample_other() -> Self {
+        Self::new(
+            ResourceAddress::sample_stokenet_candy(),
+            Decimal192::from(10),
+            Some(&NativeResourceDetails::TwoResourcePoolUnit(
+                NativeResourcePoolUnitValue::new(
+                    PoolAddress::sample_stokenet_bi_pool(),
+                    [
+                        NativeResourceRedemptionValueItem::new(
+                            ResourceAddress::sample_stokenet_xrd(),
+                            Decimal192::from(2),
+                        ),
+                        NativeResourceRedemptionValueItem::new(
+                            ResourceAddress::sample_stokenet_gum(),
+                            Decimal192::from(5),
+                        ),
+                    ],
+                ),
+            )),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = FungibleHolding;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn token_is_its_own_underlying() {
+        let sut = SUT::new(
+            ResourceAddress::sample_stokenet_candy(),
+            Decimal192::from(3),
+            None,
+        );
+        assert_eq!(sut.kind, FungibleHoldingKind::Token);
+        assert_eq!(
+            sut.underlying,
+            Some(vec![UnderlyingResourceAmount::new(
+                ResourceAddress::sample_stokenet_candy(),
+                Decimal192::from(3),
+            )])
+        );
+    }
+
+    #[test]
+    fn liquid_stake_unit_is_redeemed_for_xrd() {
+        let sut = SUT::new(
+            ResourceAddress::sample_stokenet_candy(),
+            Decimal192::from(1000),
+            Some(&NativeResourceDetails::ValidatorLiquidStakeUnit(
+                NativeResourceValidatorLiquidStakeUnitValue::new(
+                    ValidatorAddress::sample_stokenet(),
+                    [NativeResourceRedemptionValueItem::new(
+                        ResourceAddress::sample_stokenet_xrd(),
+                        Decimal192::from_str("1.05").unwrap(),
+                    )],
+                ),
+            )),
+        );
+        assert_eq!(
+            sut.kind,
+            FungibleHoldingKind::LiquidStakeUnit {
+                validator_address: ValidatorAddress::sample_stokenet()
+            }
+        );
+        assert_eq!(
+            sut.underlying,
+            Some(vec![UnderlyingResourceAmount::new(
+                ResourceAddress::sample_stokenet_xrd(),
+                Decimal192::from(1050),
+            )])
+        );
+    }
+
+    #[test]
+    fn pool_unit_is_redeemed_for_pool_resources() {
+        let sut = SUT::sample_other();
+        assert_eq!(
+            sut.kind,
+            FungibleHoldingKind::PoolUnit {
+                pool_address: PoolAddress::sample_stokenet_bi_pool()
+            }
+        );
+        assert_eq!(
+            sut.underlying,
+            Some(vec![
+                UnderlyingResourceAmount::new(
+                    ResourceAddress::sample_stokenet_xrd(),
+                    Decimal192::from(20),
+                ),
+                UnderlyingResourceAmount::new(
+                    ResourceAddress::sample_stokenet_gum(),
+                    Decimal192::from(50),
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn unknown_redemption_value_has_no_underlying() {
+        let sut = SUT::new(
+            ResourceAddress::sample_stokenet_candy(),
+            Decimal192::from(10),
+            Some(&NativeResourceDetails::OneResourcePoolUnit(
+                NativeResourcePoolUnitValue::new(
+                    PoolAddress::sample_stokenet_single_pool(),
+                    [NativeResourceRedemptionValueItem::new(
+                        ResourceAddress::sample_stokenet_xrd(),
+                        None,
+                    )],
+                ),
+            )),
+        );
+        assert_eq!(sut.underlying, None);
+    }
+}
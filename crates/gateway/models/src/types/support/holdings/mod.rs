@@ -0,0 +1,7 @@
+mod account_holdings;
+mod fungible_holding;
+mod non_fungible_holding;
+
+pub use account_holdings::*;
+pub use fungible_holding::*;
+pub use non_fungible_holding::*;
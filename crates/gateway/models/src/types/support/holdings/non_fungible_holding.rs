@@ -0,0 +1,97 @@
+use crate::prelude::*;
+
+/// A non fungible held by an account.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NonFungibleHolding {
+    pub global_id: NonFungibleGlobalId,
+
+    /// The XRD which can be claimed with the non fungible, if it is a stake
+    /// claim NFT of a validator, in which case it is worth that XRD. Any
+    /// other non fungible is `None`, and priced as a non fungible.
+    pub claimable: Option<UnderlyingResourceAmount>,
+}
+
+impl NonFungibleHolding {
+    /// A non fungible which is not a stake claim.
+    pub fn new(global_id: NonFungibleGlobalId) -> Self {
+        Self {
+            global_id,
+            claimable: None,
+        }
+    }
+
+    /// The stake claim NFT `claim` of the claim NFT resource
+    /// `resource_address`, worth its claimable XRD.
+    pub fn stake_claim(
+        resource_address: NonFungibleResourceAddress,
+        claim: StakeClaimNonFungible,
+    ) -> Self {
+        let xrd =
+            ResourceAddress::xrd_on_network(resource_address.0.network_id());
+        Self {
+            global_id: NonFungibleGlobalId::new(resource_address, claim.id),
+            claimable: Some(UnderlyingResourceAmount::new(
+                xrd,
+                claim.claim_amount,
+            )),
+        }
+    }
+}
+
+impl HasSampleValues for NonFungibleHolding {
+    fn sample() -> Self {
+        Self::new(NonFungibleGlobalId::new(
+            NonFungibleResourceAddress::new(
+                ResourceAddress::sample_stokenet_nft_gc_membership(),
+            )
+            .unwrap(),
+            NonFungibleLocalId::integer(1),
+        ))
+    }
+
+    fn sample_other() -> Self {
+        Self::stake_claim(
+            NonFungibleResourceAddress::sample_stokenet(),
+            StakeClaimNonFungible::new(
+                NonFungibleLocalId::integer(2),
+                Decimal192::from(150),
+                Epoch::from(41000),
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = NonFungibleHolding;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn non_fungible_is_not_claimable() {
+        assert_eq!(SUT::sample().claimable, None);
+    }
+
+    #[test]
+    fn stake_claim_is_claimable_for_xrd() {
+        assert_eq!(
+            SUT::sample_other().claimable,
+            Some(UnderlyingResourceAmount::new(
+                ResourceAddress::sample_stokenet_xrd(),
+                Decimal192::from(150),
+            ))
+        );
+    }
+}
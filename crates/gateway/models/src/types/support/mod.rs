@@ -1,9 +1,17 @@
 mod access_controller_state_details;
 mod fetch_resources_output;
 mod fetch_transferable_resources_output;
+mod holdings;
 mod non_fungible_token_data;
+mod pool;
+mod staking;
+mod transaction_history;
 
 pub use access_controller_state_details::*;
 pub use fetch_resources_output::*;
 pub use fetch_transferable_resources_output::*;
+pub use holdings::*;
 pub use non_fungible_token_data::*;
+pub use pool::*;
+pub use staking::*;
+pub use transaction_history::*;
@@ -0,0 +1,290 @@
+use crate::prelude::*;
+
+/// An amount of a resource held by a pool.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PoolResourceAmount {
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal192,
+}
+
+impl PoolResourceAmount {
+    pub fn new(
+        resource_address: ResourceAddress,
+        amount: impl Into<Decimal192>,
+    ) -> Self {
+        Self {
+            resource_address,
+            amount: amount.into(),
+        }
+    }
+}
+
+/// The state of a native One-, Two- or Multi-Resource Pool at some ledger
+/// state, used to compute the expected outcome of contributing to or
+/// redeeming from the pool.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LiquidityPoolState {
+    pub pool_address: PoolAddress,
+
+    /// The resource minted for contributions to the pool.
+    pub pool_unit_resource_address: ResourceAddress,
+
+    /// The amount of pool units in circulation.
+    pub pool_unit_total_supply: Decimal192,
+
+    /// The resources held by the pool, in the order of its vaults.
+    pub reserves: Vec<PoolResourceAmount>,
+}
+
+impl LiquidityPoolState {
+    pub fn new(
+        pool_address: PoolAddress,
+        pool_unit_resource_address: ResourceAddress,
+        pool_unit_total_supply: impl Into<Decimal192>,
+        reserves: impl IntoIterator<Item = PoolResourceAmount>,
+    ) -> Self {
+        Self {
+            pool_address,
+            pool_unit_resource_address,
+            pool_unit_total_supply: pool_unit_total_supply.into(),
+            reserves: reserves.into_iter().collect(),
+        }
+    }
+
+    /// The resources which can be contributed to the pool.
+    pub fn resource_addresses(&self) -> Vec<ResourceAddress> {
+        self.reserves.iter().map(|r| r.resource_address).collect()
+    }
+
+    /// The amount of pool units expected to be received for contributing
+    /// `contributions`, any excess of a resource beyond the current ratio
+    /// of the pool is returned as change.
+    ///
+    /// Returns `None` if it cannot be predicted, i.e. if the pool is empty,
+    /// in which case the initial contribution defines the ratio, or if not
+    /// all resources of the pool are contributed. Fails if the amounts are
+    /// so large that computing the pool units overflows.
+    pub fn pool_units_for_contribution(
+        &self,
+        contributions: &HashMap<ResourceAddress, Decimal192>,
+    ) -> Result<Option<Decimal192>> {
+        if self.pool_unit_total_supply.is_zero() {
+            return Ok(None);
+        }
+        let overflow = || CommonError::LiquidityPoolUnitsOverflow {
+            pool_address: self.pool_address.to_string(),
+        };
+
+        let mut min_ratio: Option<Decimal192> = None;
+        for reserve in &self.reserves {
+            let Some(contributed) =
+                contributions.get(&reserve.resource_address)
+            else {
+                return Ok(None);
+            };
+            if reserve.amount.is_zero() {
+                return Ok(None);
+            }
+            let ratio = contributed
+                .checked_div(reserve.amount)
+                .ok_or_else(overflow)?;
+            min_ratio = Some(min_ratio.map_or(ratio, |min| min.min(ratio)));
+        }
+
+        min_ratio
+            .map(|ratio| {
+                ratio
+                    .checked_mul(self.pool_unit_total_supply)
+                    .ok_or_else(overflow)
+            })
+            .transpose()
+    }
+
+    /// The amounts of each resource of the pool expected to be received for
+    /// redeeming `pool_units`, empty if no pool units are in circulation.
+    pub fn redemption_amounts(
+        &self,
+        pool_units: Decimal192,
+    ) -> Vec<PoolResourceAmount> {
+        if self.pool_unit_total_supply.is_zero() {
+            return Vec::new();
+        }
+        self.reserves
+            .iter()
+            .map(|reserve| {
+                PoolResourceAmount::new(
+                    reserve.resource_address,
+                    reserve.amount * pool_units / self.pool_unit_total_supply,
+                )
+            })
+            .collect()
+    }
+}
+
+impl HasSampleValues for LiquidityPoolState {
+    /// A pool of XRD and a token, with 1000 pool units for 2000 XRD and
+    /// 500 tokens.
+    fn sample() -> Self {
+        Self::new(
+            "pool_tdx_2_1c4cex49kkyxd4sscv4r9etzfr4tkm8aehwntqk4jak04zpjpelg3us"
+                .parse()
+                .unwrap(),
+            "resource_tdx_2_1t4u9jzqe69k3ghuhe4ulrmsthqyk2qw8wh6l4hjkt9r8m0736xx4qd"
+                .parse()
+                .unwrap(),
+            1000,
+            [
+                PoolResourceAmount::new(
+                    ResourceAddress::sample_stokenet_xrd(),
+                    2000,
+                ),
+                PoolResourceAmount::new(
+                    "resource_tdx_2_1t5gweua2ggcgw6u0g6xvf7l5t40mvwww8a856q0tzcjdnut7jdh84d"
+                        .parse()
+                        .unwrap(),
+                    500,
+                ),
+            ],
+        )
+    }
+
+    /// An empty pool of XRD and a token.
+    fn sample_other() -> Self {
+        Self::new(
+            "pool_tdx_2_1c5mygu9t8rlfq6j8v2ynrg60ltts2dctsghust8u2tuztrml427830"
+                .parse()
+                .unwrap(),
+            "resource_tdx_2_1thgnc84xkcjhs46pfvm9s8zn8t9kxwryvyr9x3947xpt6jxty7qn25"
+                .parse()
+                .unwrap(),
+            0,
+            [
+                PoolResourceAmount::new(
+                    ResourceAddress::sample_stokenet_xrd(),
+                    0,
+                ),
+                PoolResourceAmount::new(
+                    "resource_tdx_2_1tkx5re7dyhr8updty5spde2nsr0kpnhq953m09xg3u6hf3u6j4fsqf"
+                        .parse()
+                        .unwrap(),
+                    0,
+                ),
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = LiquidityPoolState;
+
+    fn contributions(
+        amounts: impl IntoIterator<Item = (ResourceAddress, i32)>,
+    ) -> HashMap<ResourceAddress, Decimal192> {
+        amounts
+            .into_iter()
+            .map(|(r, a)| (r, Decimal192::from(a)))
+            .collect()
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn pool_units_for_contribution_limited_by_ratio() {
+        let sut = SUT::sample();
+        let resources = sut.resource_addresses();
+        assert_eq!(
+            sut.pool_units_for_contribution(&contributions([
+                (resources[0], 200),
+                (resources[1], 100),
+            ])),
+            Ok(Some(Decimal192::from(100)))
+        );
+        assert_eq!(
+            sut.pool_units_for_contribution(&contributions([
+                (resources[0], 2000),
+                (resources[1], 50),
+            ])),
+            Ok(Some(Decimal192::from(100)))
+        );
+    }
+
+    #[test]
+    fn pool_units_for_contribution_missing_resource() {
+        let sut = SUT::sample();
+        let resources = sut.resource_addresses();
+        assert_eq!(
+            sut.pool_units_for_contribution(&contributions([(
+                resources[0],
+                200
+            )])),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn pool_units_for_contribution_empty_pool() {
+        let sut = SUT::sample_other();
+        let resources = sut.resource_addresses();
+        assert_eq!(
+            sut.pool_units_for_contribution(&contributions([
+                (resources[0], 200),
+                (resources[1], 100),
+            ])),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn pool_units_for_contribution_overflow_is_err() {
+        let mut sut = SUT::sample();
+        sut.reserves.iter_mut().for_each(|reserve| {
+            reserve.amount =
+                Decimal192::from_str("0.000000000000000001").unwrap()
+        });
+        let resources = sut.resource_addresses();
+        let contributions = resources
+            .iter()
+            .map(|resource| (*resource, Decimal192::max()))
+            .collect();
+
+        assert_eq!(
+            sut.pool_units_for_contribution(&contributions),
+            Err(CommonError::LiquidityPoolUnitsOverflow {
+                pool_address: sut.pool_address.to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn redemption_amounts() {
+        let sut = SUT::sample();
+        let resources = sut.resource_addresses();
+        assert_eq!(
+            sut.redemption_amounts(Decimal192::from(10)),
+            vec![
+                PoolResourceAmount::new(resources[0], 20),
+                PoolResourceAmount::new(resources[1], 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn redemption_amounts_empty_pool() {
+        assert!(SUT::sample_other()
+            .redemption_amounts(Decimal192::from(10))
+            .is_empty());
+    }
+}
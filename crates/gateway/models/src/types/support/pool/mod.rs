@@ -0,0 +1,3 @@
+mod liquidity_pool_state;
+
+pub use liquidity_pool_state::*;
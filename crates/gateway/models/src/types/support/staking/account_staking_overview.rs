@@ -0,0 +1,248 @@
+use crate::prelude::*;
+
+/// The Liquid Stake Units (LSU) of a validator held by an account, and the
+/// amount of XRD they can be redeemed for.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ValidatorLiquidStake {
+    /// The LSU resource of the validator.
+    pub resource_address: ResourceAddress,
+
+    /// The amount of LSU held.
+    pub stake_units: Decimal192,
+
+    /// The amount of XRD the held LSUs can be redeemed for, i.e. the amount
+    /// of XRD currently staked.
+    pub staked_xrd: Decimal192,
+}
+
+impl ValidatorLiquidStake {
+    /// Values `stake_units` using the XRD redemption value of a single LSU.
+    pub fn new(
+        resource_address: ResourceAddress,
+        stake_units: impl Into<Decimal192>,
+        xrd_redemption_value: impl Into<Decimal192>,
+    ) -> Self {
+        let stake_units = stake_units.into();
+        Self {
+            resource_address,
+            stake_units,
+            staked_xrd: stake_units * xrd_redemption_value.into(),
+        }
+    }
+}
+
+/// The claim NFTs of a validator held by an account, split by whether they
+/// can be claimed at the current epoch.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ValidatorStakeClaims {
+    /// The claim NFT resource of the validator.
+    pub resource_address: NonFungibleResourceAddress,
+
+    /// Unstakes which cannot be claimed yet.
+    pub pending: Vec<StakeClaimNonFungible>,
+
+    /// Unstakes which can be claimed now.
+    pub claimable: Vec<StakeClaimNonFungible>,
+}
+
+impl ValidatorStakeClaims {
+    pub fn new(
+        resource_address: NonFungibleResourceAddress,
+        claims: impl IntoIterator<Item = StakeClaimNonFungible>,
+        current_epoch: Epoch,
+    ) -> Self {
+        let (claimable, pending) = claims
+            .into_iter()
+            .partition(|claim| claim.is_claimable(current_epoch));
+        Self {
+            resource_address,
+            pending,
+            claimable,
+        }
+    }
+
+    /// The total amount of XRD which can be claimed now.
+    pub fn claimable_xrd(&self) -> Decimal192 {
+        Self::sum(&self.claimable)
+    }
+
+    /// The total amount of XRD which is being unstaked.
+    pub fn pending_xrd(&self) -> Decimal192 {
+        Self::sum(&self.pending)
+    }
+
+    fn sum(claims: &[StakeClaimNonFungible]) -> Decimal192 {
+        claims
+            .iter()
+            .fold(Decimal192::zero(), |acc, claim| acc + claim.claim_amount)
+    }
+}
+
+/// The stake of an account with a single validator.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ValidatorStakingOverview {
+    pub validator_address: ValidatorAddress,
+
+    /// `None` if the account holds no LSU of the validator.
+    pub liquid_stake: Option<ValidatorLiquidStake>,
+
+    /// `None` if the account holds no claim NFT of the validator.
+    pub stake_claims: Option<ValidatorStakeClaims>,
+}
+
+impl ValidatorStakingOverview {
+    pub fn new(
+        validator_address: ValidatorAddress,
+        liquid_stake: impl Into<Option<ValidatorLiquidStake>>,
+        stake_claims: impl Into<Option<ValidatorStakeClaims>>,
+    ) -> Self {
+        Self {
+            validator_address,
+            liquid_stake: liquid_stake.into(),
+            stake_claims: stake_claims.into(),
+        }
+    }
+
+    /// The amount of XRD currently staked, `0` if no LSU is held.
+    pub fn staked_xrd(&self) -> Decimal192 {
+        self.liquid_stake
+            .as_ref()
+            .map(|stake| stake.staked_xrd)
+            .unwrap_or(Decimal192::zero())
+    }
+}
+
+/// The staking overview of an account, with its stake per validator.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AccountStakingOverview {
+    pub account_address: AccountAddress,
+
+    /// The epoch at which the claimability of stake claims was determined.
+    pub current_epoch: Epoch,
+
+    /// Validators the account has staked to, or has pending or claimable
+    /// unstakes with.
+    pub validators: Vec<ValidatorStakingOverview>,
+}
+
+impl AccountStakingOverview {
+    pub fn new(
+        account_address: AccountAddress,
+        current_epoch: impl Into<Epoch>,
+        validators: impl IntoIterator<Item = ValidatorStakingOverview>,
+    ) -> Self {
+        Self {
+            account_address,
+            current_epoch: current_epoch.into(),
+            validators: validators.into_iter().collect(),
+        }
+    }
+
+    /// The total amount of XRD staked across all validators.
+    pub fn total_staked_xrd(&self) -> Decimal192 {
+        self.validators
+            .iter()
+            .fold(Decimal192::zero(), |acc, v| acc + v.staked_xrd())
+    }
+
+    /// The total amount of XRD which can be claimed now across all validators.
+    pub fn total_claimable_xrd(&self) -> Decimal192 {
+        self.validators
+            .iter()
+            .filter_map(|v| v.stake_claims.as_ref())
+            .fold(Decimal192::zero(), |acc, c| acc + c.claimable_xrd())
+    }
+}
+
+impl HasSampleValues for AccountStakingOverview {
+    /// Staked to one validator, with one pending and one claimable unstake
+    /// with another.
+    fn sample() -> Self {
+        Self::new(
+            AccountAddress::sample_stokenet(),
+            Epoch::new(41500u64),
+            [
+                ValidatorStakingOverview::new(
+                    ValidatorAddress::sample_stokenet(),
+                    ValidatorLiquidStake::new(
+                        "resource_tdx_2_1t5hpjckz9tm63gqvxsl60ejhzvnlguly77tltvywnj06s2x9wjdxjn".parse::<ResourceAddress>().unwrap(),
+                        1000,
+                        Decimal192::from_str("1.05").unwrap(),
+                    ),
+                    None,
+                ),
+                ValidatorStakingOverview::new(
+                    ValidatorAddress::sample_stokenet_other(),
+                    None,
+                    ValidatorStakeClaims::new(
+                        "resource_tdx_2_1ng3g2nj5pfpmdphgz0nrh8z0gtqcxx5z5dn48t85ar0z0zjhefufaw".parse::<NonFungibleResourceAddress>().unwrap(),
+                        [
+                            StakeClaimNonFungible::sample(),
+                            StakeClaimNonFungible::sample_other(),
+                        ],
+                        Epoch::new(41500u64),
+                    ),
+                ),
+            ],
+        )
+    }
+
+    /// Nothing staked.
+    fn sample_other() -> Self {
+        Self::new(
+            AccountAddress::sample_stokenet_other(),
+            Epoch::new(41500u64),
+            [],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = AccountStakingOverview;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn staked_xrd_uses_redemption_value() {
+        let sut = ValidatorLiquidStake::new(
+            ResourceAddress::sample(),
+            200,
+            Decimal192::from_str("1.5").unwrap(),
+        );
+        assert_eq!(sut.staked_xrd, Decimal192::from(300));
+    }
+
+    #[test]
+    fn claims_are_split_by_epoch() {
+        let sut = SUT::sample();
+        let claims = sut.validators[1].stake_claims.clone().unwrap();
+        assert_eq!(claims.claimable, vec![StakeClaimNonFungible::sample()]);
+        assert_eq!(claims.pending, vec![StakeClaimNonFungible::sample_other()]);
+        assert_eq!(claims.claimable_xrd(), Decimal192::from(100));
+        assert_eq!(claims.pending_xrd(), Decimal192::from(50));
+    }
+
+    #[test]
+    fn totals() {
+        let sut = SUT::sample();
+        assert_eq!(sut.total_staked_xrd(), Decimal192::from(1050));
+        assert_eq!(sut.total_claimable_xrd(), Decimal192::from(100));
+
+        let sut = SUT::sample_other();
+        assert_eq!(sut.total_staked_xrd(), Decimal192::zero());
+        assert_eq!(sut.total_claimable_xrd(), Decimal192::zero());
+    }
+}
@@ -0,0 +1,5 @@
+mod account_staking_overview;
+mod stake_claim_non_fungible;
+
+pub use account_staking_overview::*;
+pub use stake_claim_non_fungible::*;
@@ -0,0 +1,158 @@
+use crate::prelude::*;
+
+/// A claim NFT of a validator, received when unstaking, which can be
+/// redeemed for `claim_amount` XRD once `claim_epoch` has been reached.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StakeClaimNonFungible {
+    pub id: NonFungibleLocalId,
+
+    /// The amount of XRD which can be claimed.
+    pub claim_amount: Decimal192,
+
+    /// The epoch from which on the XRD can be claimed.
+    pub claim_epoch: Epoch,
+}
+
+impl StakeClaimNonFungible {
+    pub fn new(
+        id: NonFungibleLocalId,
+        claim_amount: impl Into<Decimal192>,
+        claim_epoch: impl Into<Epoch>,
+    ) -> Self {
+        Self {
+            id,
+            claim_amount: claim_amount.into(),
+            claim_epoch: claim_epoch.into(),
+        }
+    }
+
+    /// Decodes the claim from the data of a claim NFT, returns `None` if the
+    /// NFT is burned or its data is not that of a claim NFT.
+    pub fn from_data(item: &StateNonFungibleDataResponseItem) -> Option<Self> {
+        if item.is_burned {
+            return None;
+        }
+        let ProgrammaticScryptoSborValue::Tuple(tuple) =
+            &item.data.as_ref()?.programmatic_json
+        else {
+            return None;
+        };
+        let claim_amount = tuple.fields.get_decimal_field("claim_amount")?;
+        let claim_epoch = tuple.fields.get_u64_field("claim_epoch")?;
+        Some(Self::new(
+            item.non_fungible_id.clone(),
+            claim_amount,
+            Epoch::new(claim_epoch),
+        ))
+    }
+
+    /// Whether the XRD can be claimed at `current_epoch`.
+    pub fn is_claimable(&self, current_epoch: Epoch) -> bool {
+        self.claim_epoch <= current_epoch
+    }
+}
+
+impl HasSampleValues for StakeClaimNonFungible {
+    fn sample() -> Self {
+        Self::new(
+            NonFungibleLocalId::from_str(
+                "{1c1ce92c810094a7-65659db6a666c19c-6cea4367bb789b55-276b137712ceecce}",
+            )
+            .unwrap(),
+            100,
+            Epoch::new(41000u64),
+        )
+    }
+
+    fn sample_other() -> Self {
+        Self::new(
+            NonFungibleLocalId::from_str(
+                "{5aebd0270caf3f87-51031498741f57b5-d24fe0d62a976589-519c6a92423888cc}",
+            )
+            .unwrap(),
+            50,
+            Epoch::new(42000u64),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = StakeClaimNonFungible;
+
+    fn data_item(is_burned: bool) -> StateNonFungibleDataResponseItem {
+        let json = r#"{
+            "non_fungible_id": "{1c1ce92c810094a7-65659db6a666c19c-6cea4367bb789b55-276b137712ceecce}",
+            "is_burned": false,
+            "last_updated_at_state_version": 80577579,
+            "data": {
+                "programmatic_json": {
+                    "kind": "Tuple",
+                    "type_name": "UnstakeData",
+                    "fields": [
+                        {
+                            "kind": "String",
+                            "field_name": "name",
+                            "value": "Stake Claim"
+                        },
+                        {
+                            "kind": "U64",
+                            "type_name": "Epoch",
+                            "field_name": "claim_epoch",
+                            "value": "41000"
+                        },
+                        {
+                            "kind": "Decimal",
+                            "field_name": "claim_amount",
+                            "value": "100"
+                        }
+                    ]
+                }
+            }
+        }"#;
+        let mut item =
+            serde_json::from_str::<StateNonFungibleDataResponseItem>(json)
+                .unwrap();
+        item.is_burned = is_burned;
+        item
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn from_data() {
+        assert_eq!(SUT::from_data(&data_item(false)), Some(SUT::sample()));
+    }
+
+    #[test]
+    fn from_data_burned() {
+        assert_eq!(SUT::from_data(&data_item(true)), None);
+    }
+
+    #[test]
+    fn from_data_without_data() {
+        let mut item = data_item(false);
+        item.data = None;
+        assert_eq!(SUT::from_data(&item), None);
+    }
+
+    #[test]
+    fn is_claimable() {
+        let sut = SUT::sample();
+        assert!(!sut.is_claimable(Epoch::new(40999u64)));
+        assert!(sut.is_claimable(Epoch::new(41000u64)));
+        assert!(sut.is_claimable(Epoch::new(41001u64)));
+    }
+}
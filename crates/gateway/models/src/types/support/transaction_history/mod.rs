@@ -0,0 +1,7 @@
+mod transaction_history_filters;
+mod transaction_history_item;
+mod transaction_history_page;
+
+pub use transaction_history_filters::*;
+pub use transaction_history_item::*;
+pub use transaction_history_page::*;
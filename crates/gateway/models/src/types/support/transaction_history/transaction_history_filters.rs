@@ -0,0 +1,69 @@
+use crate::prelude::*;
+
+/// Filters applied when fetching the transaction history of an account, all
+/// filters are optional and combined with AND.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TransactionHistoryFilters {
+    /// Only transactions committed at or after this date are returned.
+    pub from_date: Option<Timestamp>,
+
+    /// Only transactions committed at or before this date are returned.
+    pub to_date: Option<Timestamp>,
+
+    /// Only transactions whose manifest references this resource are returned.
+    pub resource: Option<ResourceAddress>,
+
+    /// Only transactions of this kind are returned. Kinds which the Gateway
+    /// does not classify, e.g. `SecurifyEntity`, cannot be filtered by.
+    pub kind: Option<DetailedManifestClassKind>,
+}
+
+impl TransactionHistoryFilters {
+    pub fn new(
+        from_date: impl Into<Option<Timestamp>>,
+        to_date: impl Into<Option<Timestamp>>,
+        resource: impl Into<Option<ResourceAddress>>,
+        kind: impl Into<Option<DetailedManifestClassKind>>,
+    ) -> Self {
+        Self {
+            from_date: from_date.into(),
+            to_date: to_date.into(),
+            resource: resource.into(),
+            kind: kind.into(),
+        }
+    }
+}
+
+impl HasSampleValues for TransactionHistoryFilters {
+    fn sample() -> Self {
+        Self::default()
+    }
+
+    fn sample_other() -> Self {
+        Self::new(
+            Timestamp::parse("2024-01-01T00:00:00Z").unwrap(),
+            Timestamp::parse("2024-02-01T00:00:00Z").unwrap(),
+            ResourceAddress::sample_mainnet_xrd(),
+            DetailedManifestClassKind::Transfer,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = TransactionHistoryFilters;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+}
@@ -0,0 +1,376 @@
+use crate::prelude::*;
+
+/// A committed transaction in the transaction history of an account, with
+/// the resources withdrawn from and deposited into that account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionHistoryItem {
+    pub intent_hash: TransactionIntentHash,
+
+    /// The state version of the ledger at which this transaction was committed.
+    pub state_version: u64,
+
+    pub confirmed_at: Option<Timestamp>,
+
+    /// `false` if the transaction was committed but failed, in which case
+    /// only the fee was paid.
+    pub is_success: bool,
+
+    /// The total fee paid for the transaction, in XRD.
+    pub fee_paid: Decimal192,
+
+    /// The message of the transaction, encrypted messages are returned as
+    /// is and can be decrypted by the host.
+    pub message: Message,
+
+    /// The most specific class of the transaction manifest, as classified by
+    /// the Gateway. Only the addresses of affected validators and pools are
+    /// known, tracked amounts of stakes, claims and pool operations are left
+    /// empty.
+    pub manifest_class: Option<DetailedManifestClass>,
+
+    /// Resources withdrawn from the account, excluding fees.
+    pub withdrawals: Vec<ResourceIndicator>,
+
+    /// Resources deposited into the account.
+    pub deposits: Vec<ResourceIndicator>,
+}
+
+impl TransactionHistoryItem {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        intent_hash: TransactionIntentHash,
+        state_version: u64,
+        confirmed_at: impl Into<Option<Timestamp>>,
+        is_success: bool,
+        fee_paid: impl Into<Decimal192>,
+        message: Message,
+        manifest_class: impl Into<Option<DetailedManifestClass>>,
+        withdrawals: impl IntoIterator<Item = ResourceIndicator>,
+        deposits: impl IntoIterator<Item = ResourceIndicator>,
+    ) -> Self {
+        Self {
+            intent_hash,
+            state_version,
+            confirmed_at: confirmed_at.into(),
+            is_success,
+            fee_paid: fee_paid.into(),
+            message,
+            manifest_class: manifest_class.into(),
+            withdrawals: withdrawals.into_iter().collect(),
+            deposits: deposits.into_iter().collect(),
+        }
+    }
+
+    /// Builds the history item of `account_address` from a transaction
+    /// returned by `/stream/transactions`, requires the transaction to have
+    /// an intent hash.
+    pub fn from_info(
+        info: CommittedTransactionInfo,
+        account_address: AccountAddress,
+    ) -> Result<Self> {
+        let intent_hash = info
+            .intent_hash
+            .as_deref()
+            .map(TransactionIntentHash::from_str)
+            .transpose()?
+            .ok_or(
+                CommonError::GatewayCommittedTransactionWithoutIntentHash {
+                    state_version: info.state_version,
+                },
+            )?;
+
+        let affected_global_entities =
+            info.affected_global_entities.unwrap_or_default();
+
+        let manifest_class = info
+            .manifest_classes
+            .and_then(|classes| classes.first().copied())
+            .map(|class| {
+                Self::detailed_manifest_class(class, &affected_global_entities)
+            });
+
+        let (withdrawals, deposits) = info
+            .balance_changes
+            .map(|changes| {
+                Self::withdrawals_and_deposits(changes, account_address)
+            })
+            .unwrap_or_default();
+
+        Ok(Self::new(
+            intent_hash,
+            info.state_version,
+            info.confirmed_at,
+            info.transaction_status
+                == CommittedTransactionStatus::CommittedSuccess,
+            info.fee_paid.unwrap_or(Decimal192::zero()),
+            info.message.map(Message::from).unwrap_or(Message::None),
+            manifest_class,
+            withdrawals,
+            deposits,
+        ))
+    }
+
+    fn detailed_manifest_class(
+        class: GWManifestClass,
+        affected_global_entities: &[String],
+    ) -> DetailedManifestClass {
+        let validator_addresses = || {
+            affected_global_entities
+                .iter()
+                .filter_map(|a| ValidatorAddress::from_str(a).ok())
+                .collect_vec()
+        };
+        let pool_addresses = || {
+            affected_global_entities
+                .iter()
+                .filter_map(|a| PoolAddress::from_str(a).ok())
+                .collect_vec()
+        };
+        match class {
+            GWManifestClass::General => DetailedManifestClass::General,
+            GWManifestClass::Transfer => DetailedManifestClass::Transfer {
+                is_one_to_one_transfer: false,
+            },
+            GWManifestClass::ValidatorStake => {
+                DetailedManifestClass::ValidatorStake {
+                    validator_addresses: validator_addresses(),
+                    validator_stakes: Vec::new(),
+                }
+            }
+            GWManifestClass::ValidatorUnstake => {
+                DetailedManifestClass::ValidatorUnstake {
+                    validator_addresses: validator_addresses(),
+                    claims_non_fungible_data: Default::default(),
+                }
+            }
+            GWManifestClass::ValidatorClaim => {
+                DetailedManifestClass::ValidatorClaim {
+                    validator_addresses: validator_addresses(),
+                    validator_claims: Vec::new(),
+                }
+            }
+            GWManifestClass::AccountDepositSettingsUpdate => {
+                DetailedManifestClass::AccountDepositSettingsUpdate {
+                    resource_preferences_updates: Default::default(),
+                    deposit_mode_updates: Default::default(),
+                    authorized_depositors_added: Default::default(),
+                    authorized_depositors_removed: Default::default(),
+                }
+            }
+            GWManifestClass::PoolContribution => {
+                DetailedManifestClass::PoolContribution {
+                    pool_addresses: pool_addresses(),
+                    pool_contributions: Vec::new(),
+                }
+            }
+            GWManifestClass::PoolRedemption => {
+                DetailedManifestClass::PoolRedemption {
+                    pool_addresses: pool_addresses(),
+                    pool_redemptions: Vec::new(),
+                }
+            }
+        }
+    }
+
+    /// Splits the (non fee) balance changes of `account_address` into
+    /// withdrawals and deposits.
+    fn withdrawals_and_deposits(
+        changes: TransactionBalanceChanges,
+        account_address: AccountAddress,
+    ) -> (Vec<ResourceIndicator>, Vec<ResourceIndicator>) {
+        let account = account_address.to_string();
+        let mut withdrawals = Vec::new();
+        let mut deposits = Vec::new();
+
+        changes
+            .fungible_balance_changes
+            .into_iter()
+            .filter(|c| c.entity_address == account)
+            .for_each(|c| {
+                let indicator = ResourceIndicator::fungible(
+                    c.resource_address,
+                    FungibleResourceIndicator::new_guaranteed(
+                        c.balance_change.abs(),
+                    ),
+                );
+                if c.balance_change.is_negative() {
+                    withdrawals.push(indicator);
+                } else if c.balance_change.is_positive() {
+                    deposits.push(indicator);
+                }
+            });
+
+        changes
+            .non_fungible_balance_changes
+            .into_iter()
+            .filter(|c| c.entity_address == account)
+            .for_each(|c| {
+                if !c.removed.is_empty() {
+                    withdrawals.push(ResourceIndicator::non_fungible(
+                        c.resource_address,
+                        NonFungibleResourceIndicator::new_guaranteed(c.removed),
+                    ));
+                }
+                if !c.added.is_empty() {
+                    deposits.push(ResourceIndicator::non_fungible(
+                        c.resource_address,
+                        NonFungibleResourceIndicator::new_guaranteed(c.added),
+                    ));
+                }
+            });
+
+        (withdrawals, deposits)
+    }
+}
+
+impl HasSampleValues for TransactionHistoryItem {
+    /// A transfer of 10 XRD, receiving two NFTs, with a plaintext message.
+    fn sample() -> Self {
+        Self::new(
+            TransactionIntentHash::sample(),
+            130420042,
+            Timestamp::parse("2024-10-08T09:10:02.521Z").unwrap(),
+            true,
+            Decimal192::from_str("0.3526186233").unwrap(),
+            Message::plain_text("Thanks for dinner"),
+            DetailedManifestClass::Transfer {
+                is_one_to_one_transfer: false,
+            },
+            [ResourceIndicator::fungible(
+                ResourceAddress::sample_mainnet_xrd(),
+                FungibleResourceIndicator::new_guaranteed(
+                    Decimal192::from_str("10").unwrap(),
+                ),
+            )],
+            [ResourceIndicator::non_fungible(
+                ResourceAddress::sample_mainnet_nft_gc_membership(),
+                NonFungibleResourceIndicator::new_guaranteed(vec![
+                    NonFungibleLocalId::integer(1),
+                    NonFungibleLocalId::integer(2),
+                ]),
+            )],
+        )
+    }
+
+    /// A stake of 100 XRD to a validator, without a message.
+    fn sample_other() -> Self {
+        Self::new(
+            TransactionIntentHash::from_str(
+                "txid_rdx19rpveua6xuhvz0axu0mwpqk8fywr83atv8mkrugchvw6uuslgppqh9cnj4",
+            )
+            .unwrap(),
+            130419876,
+            Timestamp::parse("2024-10-08T09:01:40.017Z").unwrap(),
+            true,
+            Decimal192::from_str("0.4123").unwrap(),
+            Message::None,
+            DetailedManifestClass::ValidatorStake {
+                validator_addresses: vec![ValidatorAddress::sample_mainnet()],
+                validator_stakes: Vec::new(),
+            },
+            [ResourceIndicator::fungible(
+                ResourceAddress::sample_mainnet_xrd(),
+                FungibleResourceIndicator::new_guaranteed(
+                    Decimal192::from_str("100").unwrap(),
+                ),
+            )],
+            [ResourceIndicator::fungible(
+                ResourceAddress::sample_mainnet_candy(),
+                FungibleResourceIndicator::new_guaranteed(
+                    Decimal192::from_str("99.8").unwrap(),
+                ),
+            )],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::fixture_gw_model;
+
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = TransactionHistoryItem;
+
+    fn fixture_items() -> Vec<CommittedTransactionInfo> {
+        fixture::<StreamTransactionsResponse>(fixture_gw_model!(
+            "stream/response_stream_transactions"
+        ))
+        .unwrap()
+        .items
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn from_info_transfer() {
+        let info = fixture_items()[0].clone();
+        let sut =
+            SUT::from_info(info, AccountAddress::sample_mainnet()).unwrap();
+        assert_eq!(sut, SUT::sample());
+    }
+
+    #[test]
+    fn from_info_stake() {
+        let info = fixture_items()[1].clone();
+        let sut =
+            SUT::from_info(info, AccountAddress::sample_mainnet()).unwrap();
+        assert_eq!(sut, SUT::sample_other());
+    }
+
+    #[test]
+    fn from_info_recipient_of_transfer() {
+        let info = fixture_items()[0].clone();
+        let sut = SUT::from_info(info, AccountAddress::sample_mainnet_other())
+            .unwrap();
+        assert!(sut.withdrawals.is_empty());
+        assert_eq!(
+            sut.deposits,
+            vec![ResourceIndicator::fungible(
+                ResourceAddress::sample_mainnet_xrd(),
+                FungibleResourceIndicator::new_guaranteed(
+                    Decimal192::from_str("10").unwrap(),
+                ),
+            )]
+        );
+    }
+
+    #[test]
+    fn from_info_failure() {
+        let mut info = fixture_items()[0].clone();
+        info.transaction_status = CommittedTransactionStatus::CommittedFailure;
+        let sut =
+            SUT::from_info(info, AccountAddress::sample_mainnet()).unwrap();
+        assert!(!sut.is_success);
+    }
+
+    #[test]
+    fn from_info_invalid_intent_hash() {
+        let mut info = fixture_items()[0].clone();
+        info.intent_hash = Some("invalid".to_owned());
+        assert!(SUT::from_info(info, AccountAddress::sample_mainnet()).is_err());
+    }
+
+    #[test]
+    fn from_info_without_opt_ins() {
+        let mut info = fixture_items()[1].clone();
+        info.balance_changes = None;
+        info.affected_global_entities = None;
+        info.manifest_classes = None;
+        let sut =
+            SUT::from_info(info, AccountAddress::sample_mainnet()).unwrap();
+        assert_eq!(sut.manifest_class, None);
+        assert!(sut.withdrawals.is_empty());
+        assert!(sut.deposits.is_empty());
+    }
+}
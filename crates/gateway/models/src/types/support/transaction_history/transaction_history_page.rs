@@ -0,0 +1,101 @@
+use crate::prelude::*;
+
+/// A page of the transaction history of an account, newest first.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionHistoryPage {
+    pub items: Vec<TransactionHistoryItem>,
+
+    /// Cursor used to fetch the next page, `None` if this is the last page.
+    pub next_cursor: Option<String>,
+}
+
+impl TransactionHistoryPage {
+    pub fn new(
+        items: impl IntoIterator<Item = TransactionHistoryItem>,
+        next_cursor: impl Into<Option<String>>,
+    ) -> Self {
+        Self {
+            items: items.into_iter().collect(),
+            next_cursor: next_cursor.into(),
+        }
+    }
+
+    /// Builds the page from a `/stream/transactions` response, transactions
+    /// without an intent hash, i.e. non user transactions, are skipped.
+    pub fn from_response(
+        response: StreamTransactionsResponse,
+        account_address: AccountAddress,
+    ) -> Result<Self> {
+        let items = response
+            .items
+            .into_iter()
+            .filter(|info| info.intent_hash.is_some())
+            .map(|info| {
+                TransactionHistoryItem::from_info(info, account_address)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self::new(items, response.next_cursor))
+    }
+}
+
+impl HasSampleValues for TransactionHistoryPage {
+    fn sample() -> Self {
+        Self::new(
+            [
+                TransactionHistoryItem::sample(),
+                TransactionHistoryItem::sample_other(),
+            ],
+            "eyJ2IjoxfQ==".to_owned(),
+        )
+    }
+
+    fn sample_other() -> Self {
+        Self::new([TransactionHistoryItem::sample_other()], None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prelude::fixture_gw_model;
+
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = TransactionHistoryPage;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn from_response() {
+        let response = fixture::<StreamTransactionsResponse>(
+            fixture_gw_model!("stream/response_stream_transactions"),
+        )
+        .unwrap();
+        let sut =
+            SUT::from_response(response, AccountAddress::sample_mainnet())
+                .unwrap();
+        assert_eq!(sut, SUT::sample());
+    }
+
+    #[test]
+    fn from_response_skips_non_user_transactions() {
+        let mut response = fixture::<StreamTransactionsResponse>(
+            fixture_gw_model!("stream/response_stream_transactions"),
+        )
+        .unwrap();
+        response.items[0].intent_hash = None;
+        let sut =
+            SUT::from_response(response, AccountAddress::sample_mainnet())
+                .unwrap();
+        assert_eq!(sut.items, vec![TransactionHistoryItem::sample_other()]);
+    }
+}
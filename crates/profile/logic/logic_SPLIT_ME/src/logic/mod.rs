@@ -7,6 +7,7 @@ mod instances_deriving_with_factor_sources;
 mod mfa_factor_instances_logic;
 mod persona;
 mod profile_header;
+mod profile_merge;
 mod profile_network;
 mod profile_networks;
 mod profile_update;
@@ -20,6 +21,7 @@ pub use gateway::*;
 pub use mfa_factor_instances_logic::*;
 pub use persona::*;
 pub use profile_header::*;
+pub use profile_merge::*;
 pub use profile_network::*;
 pub use profile_networks::*;
 pub use profile_update::*;
@@ -12,6 +12,9 @@ impl PersonaDataIdsOfEntries for PersonaData {
         };
         full_ids.extend(self.email_addresses.ids());
         full_ids.extend(self.phone_numbers.ids());
+        full_ids.extend(self.postal_addresses.ids());
+        full_ids.extend(self.dates_of_birth.ids());
+        full_ids.extend(self.companies.ids());
         full_ids
     }
 }
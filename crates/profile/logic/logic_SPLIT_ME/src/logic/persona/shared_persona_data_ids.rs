@@ -16,6 +16,15 @@ impl SharedPersonaDataIdsOfEntries for SharedPersonaData {
         if let Some(phone_numbers) = &self.phone_numbers {
             full_ids.extend(&phone_numbers.ids);
         }
+        if let Some(postal_addresses) = &self.postal_addresses {
+            full_ids.extend(&postal_addresses.ids);
+        }
+        if let Some(dates_of_birth) = &self.dates_of_birth {
+            full_ids.extend(&dates_of_birth.ids);
+        }
+        if let Some(companies) = &self.companies {
+            full_ids.extend(&companies.ids);
+        }
         full_ids
     }
 }
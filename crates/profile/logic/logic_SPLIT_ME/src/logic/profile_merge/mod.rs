@@ -0,0 +1,7 @@
+mod profile_merge;
+mod profile_merge_conflict;
+mod profile_merging;
+
+pub use profile_merge::*;
+pub use profile_merge_conflict::*;
+pub use profile_merging::*;
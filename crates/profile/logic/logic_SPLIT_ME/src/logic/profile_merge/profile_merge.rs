@@ -0,0 +1,229 @@
+use crate::prelude::*;
+
+/// The outcome of a three-way merge of two divergent Profile snapshots, see
+/// [`ProfileMerging::merge`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProfileMerge {
+    /// The merged Profile, containing all non-conflicting changes of both
+    /// snapshots, and the local values of all `conflicts`.
+    pub merged: Profile,
+
+    /// The `last_modified` of the header of the local snapshot, used to
+    /// detect whether the local Profile has changed since it was merged.
+    pub local_last_modified: Timestamp,
+
+    /// The items changed differently in the local and remote snapshots,
+    /// which the user has to resolve.
+    pub conflicts: Vec<ProfileMergeConflict>,
+}
+
+impl ProfileMerge {
+    pub fn new(
+        merged: Profile,
+        local_last_modified: Timestamp,
+        conflicts: impl IntoIterator<Item = ProfileMergeConflict>,
+    ) -> Self {
+        Self {
+            merged,
+            local_last_modified,
+            conflicts: conflicts.into_iter().collect(),
+        }
+    }
+
+    /// Whether all changes of both snapshots were merged automatically.
+    pub fn is_conflict_free(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+
+    /// Returns the merged Profile with `conflicts` resolved by
+    /// `resolutions`, one resolution per conflict, in the same order.
+    pub fn resolve(
+        self,
+        resolutions: impl IntoIterator<Item = ProfileMergeConflictResolution>,
+    ) -> Result<Profile> {
+        let resolutions = resolutions.into_iter().collect_vec();
+        if resolutions.len() != self.conflicts.len() {
+            return Err(CommonError::InvalidProfileMergeResolutionCount {
+                expected: self.conflicts.len() as u64,
+                found: resolutions.len() as u64,
+            });
+        }
+        let mut profile = self.merged;
+        self.conflicts
+            .iter()
+            .zip(resolutions)
+            .filter(|(_, resolution)| {
+                *resolution == ProfileMergeConflictResolution::KeepRemote
+            })
+            .for_each(|(conflict, _)| conflict.keep_remote(&mut profile));
+        remove_dangling_references(&mut profile);
+        profile.update_header(None::<DeviceInfo>);
+        Ok(profile)
+    }
+}
+
+/// Removes references to personas and accounts which are not in `profile`,
+/// e.g. a persona authorized to a dApp in one snapshot but deleted in the
+/// other, from the authorized dApps of each network.
+pub(crate) fn remove_dangling_references(profile: &mut Profile) {
+    profile.networks.update_all_with(|network| {
+        let personas =
+            network.personas.ids().into_iter().cloned().collect_vec();
+        let accounts =
+            network.accounts.ids().into_iter().cloned().collect_vec();
+        network.authorized_dapps.update_all_with(|dapp| {
+            dapp.references_to_authorized_personas = dapp
+                .references_to_authorized_personas
+                .iter()
+                .filter(|persona| personas.contains(&persona.identity_address))
+                .map(|mut persona| {
+                    if let Some(shared_accounts) =
+                        persona.shared_accounts.as_mut()
+                    {
+                        shared_accounts.ids = shared_accounts
+                            .ids
+                            .iter()
+                            .filter(|address| accounts.contains(address))
+                            .collect();
+                    }
+                    persona
+                })
+                .collect();
+        });
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = ProfileMerge;
+
+    fn conflicting_account_names() -> (Account, Account, SUT) {
+        let local = Account::sample_mainnet();
+        let mut remote = local.clone();
+        remote.display_name = DisplayName::new("Remote").unwrap();
+        let sut = SUT::new(
+            Profile::sample(),
+            Timestamp::sample(),
+            [ProfileMergeConflict::Account {
+                network_id: NetworkID::Mainnet,
+                values: ProfileMergeConflictingValues::new(
+                    local.clone(),
+                    local.clone(),
+                    remote.clone(),
+                ),
+            }],
+        );
+        (local, remote, sut)
+    }
+
+    fn mainnet_account(profile: &Profile, account: &Account) -> Account {
+        profile.account_by_address(account.address).unwrap()
+    }
+
+    #[test]
+    fn is_conflict_free() {
+        assert!(SUT::new(Profile::sample(), Timestamp::sample(), [])
+            .is_conflict_free());
+        assert!(!conflicting_account_names().2.is_conflict_free());
+    }
+
+    #[test]
+    fn resolve_keep_local() {
+        let (local, _, sut) = conflicting_account_names();
+        let profile = sut
+            .resolve([ProfileMergeConflictResolution::KeepLocal])
+            .unwrap();
+        assert_eq!(mainnet_account(&profile, &local), local);
+    }
+
+    #[test]
+    fn resolve_keep_remote() {
+        let (local, remote, sut) = conflicting_account_names();
+        let profile = sut
+            .resolve([ProfileMergeConflictResolution::KeepRemote])
+            .unwrap();
+        assert_eq!(mainnet_account(&profile, &local), remote);
+    }
+
+    #[test]
+    fn resolve_updates_header() {
+        let (_, _, sut) = conflicting_account_names();
+        let before = sut.merged.header.last_modified;
+        let profile = sut
+            .resolve([ProfileMergeConflictResolution::KeepLocal])
+            .unwrap();
+        assert_ne!(profile.header.last_modified, before);
+    }
+
+    #[test]
+    fn resolve_with_wrong_number_of_resolutions() {
+        let (_, _, sut) = conflicting_account_names();
+        assert_eq!(
+            sut.resolve([]),
+            Err(CommonError::InvalidProfileMergeResolutionCount {
+                expected: 1,
+                found: 0
+            })
+        );
+    }
+
+    #[test]
+    fn resolve_removes_references_to_removed_persona() {
+        let batman = Persona::sample_mainnet_batman();
+        let mut local = batman.clone();
+        local.display_name = DisplayName::new("Local").unwrap();
+        let sut = SUT::new(
+            Profile::sample(),
+            [ProfileMergeConflict::Persona {
+                network_id: NetworkID::Mainnet,
+                values: ProfileMergeConflictingValues::new(
+                    batman.clone(),
+                    local,
+                    None,
+                ),
+            }],
+        );
+
+        let profile = sut
+            .resolve([ProfileMergeConflictResolution::KeepRemote])
+            .unwrap();
+
+        let mainnet = profile.networks.get_id(NetworkID::Mainnet).unwrap();
+        assert!(!mainnet.personas.contains_id(batman.id()));
+        assert!(mainnet.authorized_dapps.iter().all(|dapp| {
+            !dapp
+                .references_to_authorized_personas
+                .contains_id(batman.address)
+        }));
+    }
+
+    #[test]
+    fn remove_dangling_shared_accounts() {
+        let mut profile = Profile::sample();
+        let bob = Account::sample_mainnet_bob();
+        profile.networks.update_with(NetworkID::Mainnet, |network| {
+            network.accounts.remove_id(&bob.id());
+        });
+
+        let is_shared = |profile: &Profile| {
+            let mainnet = profile.networks.get_id(NetworkID::Mainnet).unwrap();
+            mainnet.authorized_dapps.iter().any(|dapp| {
+                dapp.references_to_authorized_personas
+                    .iter()
+                    .any(|persona| {
+                        persona.shared_accounts.is_some_and(|shared| {
+                            shared.ids.contains_id(bob.address)
+                        })
+                    })
+            })
+        };
+        assert!(is_shared(&profile));
+
+        remove_dangling_references(&mut profile);
+
+        assert!(!is_shared(&profile));
+    }
+}
@@ -0,0 +1,237 @@
+use crate::prelude::*;
+
+/// The values of an item of a Profile in the common base snapshot and in the
+/// local and remote snapshots which diverged from it, `None` if the item is
+/// absent from, or was removed in, that snapshot.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ProfileMergeConflictingValues<T> {
+    pub base: Option<T>,
+    pub local: Option<T>,
+    pub remote: Option<T>,
+}
+
+impl<T> ProfileMergeConflictingValues<T> {
+    pub fn new(
+        base: impl Into<Option<T>>,
+        local: impl Into<Option<T>>,
+        remote: impl Into<Option<T>>,
+    ) -> Self {
+        Self {
+            base: base.into(),
+            local: local.into(),
+            remote: remote.into(),
+        }
+    }
+}
+
+/// An item of a Profile which was changed differently in the local and the
+/// remote snapshot, which the user has to resolve by picking either value.
+///
+/// Items are identified by their `Identifiable` ID, and network scoped items
+/// also by the `NetworkID` of the network they are on.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProfileMergeConflict {
+    FactorSource(ProfileMergeConflictingValues<FactorSource>),
+    AppDisplay(ProfileMergeConflictingValues<AppDisplay>),
+    Gateways(ProfileMergeConflictingValues<SavedGateways>),
+    P2PTransportProfiles(
+        ProfileMergeConflictingValues<SavedP2PTransportProfiles>,
+    ),
+    RelayServices(ProfileMergeConflictingValues<SavedRelayServices>),
+    Security(ProfileMergeConflictingValues<Security>),
+    TransactionPreferences(
+        ProfileMergeConflictingValues<TransactionPreferences>,
+    ),
+    Account {
+        network_id: NetworkID,
+        values: ProfileMergeConflictingValues<Account>,
+    },
+    Persona {
+        network_id: NetworkID,
+        values: ProfileMergeConflictingValues<Persona>,
+    },
+    AuthorizedDapp {
+        network_id: NetworkID,
+        values: ProfileMergeConflictingValues<AuthorizedDapp>,
+    },
+    AddressBookEntry {
+        network_id: NetworkID,
+        values: ProfileMergeConflictingValues<AddressBookEntry>,
+    },
+    WatchOnlyAccount {
+        network_id: NetworkID,
+        values: ProfileMergeConflictingValues<WatchOnlyAccount>,
+    },
+}
+
+/// How the user resolved a [`ProfileMergeConflict`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ProfileMergeConflictResolution {
+    /// Keep the value of the local snapshot, which is what the merged Profile
+    /// contains before conflicts are resolved.
+    KeepLocal,
+
+    /// Replace the value with the one of the remote snapshot.
+    KeepRemote,
+}
+
+/// Replaces, inserts or removes the item of `values` in `items` to match the
+/// remote value.
+fn keep_remote_item<V>(
+    items: &mut IdentifiedVecOf<V>,
+    values: &ProfileMergeConflictingValues<V>,
+) where
+    V: Debug + PartialEq + Eq + Clone + Identifiable,
+{
+    match (&values.local, &values.remote) {
+        (_, Some(remote)) => {
+            items.insert(remote.clone());
+        }
+        (Some(local), None) => {
+            items.remove_id(&local.id());
+        }
+        (None, None) => {}
+    }
+}
+
+/// Sets `value` to the remote value of `values`, if any.
+fn keep_remote_value<T: Clone>(
+    value: &mut T,
+    values: &ProfileMergeConflictingValues<T>,
+) {
+    if let Some(remote) = &values.remote {
+        *value = remote.clone();
+    }
+}
+
+impl ProfileMergeConflict {
+    /// Resolves this conflict in `profile` by using the remote value.
+    pub(crate) fn keep_remote(&self, profile: &mut Profile) {
+        let preferences = &mut profile.app_preferences;
+        match self {
+            Self::FactorSource(values) => {
+                keep_remote_item(&mut profile.factor_sources, values)
+            }
+            Self::AppDisplay(values) => {
+                keep_remote_value(&mut preferences.display, values)
+            }
+            Self::Gateways(values) => {
+                keep_remote_value(&mut preferences.gateways, values)
+            }
+            Self::P2PTransportProfiles(values) => keep_remote_value(
+                &mut preferences.p2p_transport_profiles,
+                values,
+            ),
+            Self::RelayServices(values) => {
+                keep_remote_value(&mut preferences.relay_services, values)
+            }
+            Self::Security(values) => {
+                keep_remote_value(&mut preferences.security, values)
+            }
+            Self::TransactionPreferences(values) => {
+                keep_remote_value(&mut preferences.transaction, values)
+            }
+            Self::Account { network_id, values } => {
+                profile.networks.update_with(network_id, |network| {
+                    keep_remote_item(&mut network.accounts, values)
+                });
+            }
+            Self::Persona { network_id, values } => {
+                profile.networks.update_with(network_id, |network| {
+                    keep_remote_item(&mut network.personas, values)
+                });
+            }
+            Self::AuthorizedDapp { network_id, values } => {
+                profile.networks.update_with(network_id, |network| {
+                    keep_remote_item(&mut network.authorized_dapps, values)
+                });
+            }
+            Self::AddressBookEntry { network_id, values } => {
+                profile.networks.update_with(network_id, |network| {
+                    keep_remote_item(&mut network.address_book, values)
+                });
+            }
+            Self::WatchOnlyAccount { network_id, values } => {
+                profile.networks.update_with(network_id, |network| {
+                    keep_remote_item(&mut network.watch_only_accounts, values)
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = ProfileMergeConflict;
+
+    #[test]
+    fn keep_remote_replaces_item() {
+        let mut profile = Profile::sample();
+        let local = Account::sample_mainnet();
+        let mut remote = local.clone();
+        remote.display_name = DisplayName::new("Remote").unwrap();
+
+        SUT::Account {
+            network_id: NetworkID::Mainnet,
+            values: ProfileMergeConflictingValues::new(
+                local.clone(),
+                local.clone(),
+                remote.clone(),
+            ),
+        }
+        .keep_remote(&mut profile);
+
+        assert_eq!(
+            profile
+                .networks
+                .get_id(NetworkID::Mainnet)
+                .unwrap()
+                .accounts[0],
+            remote
+        );
+    }
+
+    #[test]
+    fn keep_remote_removes_item() {
+        let mut profile = Profile::sample();
+        let local = Account::sample_mainnet();
+
+        SUT::Account {
+            network_id: NetworkID::Mainnet,
+            values: ProfileMergeConflictingValues::new(
+                local.clone(),
+                local.clone(),
+                None,
+            ),
+        }
+        .keep_remote(&mut profile);
+
+        assert!(!profile
+            .networks
+            .get_id(NetworkID::Mainnet)
+            .unwrap()
+            .accounts
+            .contains_id(local.id()));
+    }
+
+    #[test]
+    fn keep_remote_replaces_value() {
+        let mut profile = Profile::sample();
+        let local = profile.app_preferences.security.clone();
+        let mut remote = local.clone();
+        remote.is_developer_mode_enabled =
+            IsDeveloperModeEnabled(!local.is_developer_mode_enabled.0);
+
+        SUT::Security(ProfileMergeConflictingValues::new(
+            local.clone(),
+            local,
+            remote.clone(),
+        ))
+        .keep_remote(&mut profile);
+
+        assert_eq!(profile.app_preferences.security, remote);
+    }
+}
@@ -0,0 +1,741 @@
+use crate::prelude::*;
+
+pub trait ProfileMerging {
+    /// Three-way merges `self`, the local Profile snapshot, with `remote`, a
+    /// snapshot of the same Profile changed on another device, both having
+    /// diverged from the `base` snapshot, e.g. when
+    /// `Event::ProfileUsedOnOtherDevice` was emitted.
+    ///
+    /// Changes to accounts, personas, authorized dapps and address book
+    /// entries, keyed by their `Identifiable` ID on each network, to factor
+    /// sources and to each kind of app preferences are merged automatically,
+    /// unless both snapshots changed the same item differently, in which
+    /// case the local value is kept and a [`ProfileMergeConflict`] returned.
+    ///
+    /// When factor sources were last used and when personas last logged in
+    /// to dApps are merged by keeping the latest, so items used on both
+    /// devices do not conflict. References of authorized dApps to personas
+    /// and accounts removed by either snapshot are removed.
+    ///
+    /// Resource preferences, token price services and MFA factor instances
+    /// are merged without conflicts, keeping the local value.
+    fn merge(&self, base: &Profile, remote: &Profile) -> Result<ProfileMerge>;
+}
+
+enum ThreeWayMerged<T> {
+    Merged(Option<T>),
+    Conflict(ProfileMergeConflictingValues<T>),
+}
+
+/// The value of an item after merging the changes of `local` and `remote`
+/// to `base`, `None` meaning absent or removed.
+fn three_way_merge<T: PartialEq + Clone>(
+    base: Option<&T>,
+    local: Option<&T>,
+    remote: Option<&T>,
+) -> ThreeWayMerged<T> {
+    if local == remote || remote == base {
+        ThreeWayMerged::Merged(local.cloned())
+    } else if local == base {
+        ThreeWayMerged::Merged(remote.cloned())
+    } else {
+        ThreeWayMerged::Conflict(ProfileMergeConflictingValues::new(
+            base.cloned(),
+            local.cloned(),
+            remote.cloned(),
+        ))
+    }
+}
+
+/// Merges the items by ID, in the order of `local`, followed by the items
+/// added in `remote`, keeping local values of conflicting items.
+fn merge_items<V>(
+    base: &IdentifiedVecOf<V>,
+    local: &IdentifiedVecOf<V>,
+    remote: &IdentifiedVecOf<V>,
+    on_conflict: impl FnMut(ProfileMergeConflictingValues<V>),
+) -> Vec<V>
+where
+    V: Debug + PartialEq + Eq + Clone + Identifiable,
+{
+    merge_items_keeping_latest(base, local, remote, |_, _| {}, on_conflict)
+}
+
+/// Merges the items by ID like `merge_items`, but first updates timestamps
+/// of each value of an item with the latest of all its values, using
+/// `keep_latest`, so that items only differing in timestamps, e.g. when they
+/// were used on both devices, are merged without conflicts.
+fn merge_items_keeping_latest<V>(
+    base: &IdentifiedVecOf<V>,
+    local: &IdentifiedVecOf<V>,
+    remote: &IdentifiedVecOf<V>,
+    keep_latest: impl Fn(&mut V, &V),
+    mut on_conflict: impl FnMut(ProfileMergeConflictingValues<V>),
+) -> Vec<V>
+where
+    V: Debug + PartialEq + Eq + Clone + Identifiable,
+{
+    let ids = local
+        .ids()
+        .into_iter()
+        .chain(remote.ids())
+        .cloned()
+        .collect::<IndexSet<_>>();
+    ids.into_iter()
+        .filter_map(|id| {
+            let values =
+                [base.get_id(&id), local.get_id(&id), remote.get_id(&id)];
+            let [base, local, remote] = values.map(|value| {
+                value.map(|value| {
+                    let mut value = value.clone();
+                    values
+                        .iter()
+                        .flatten()
+                        .for_each(|other| keep_latest(&mut value, other));
+                    value
+                })
+            });
+            match three_way_merge(
+                base.as_ref(),
+                local.as_ref(),
+                remote.as_ref(),
+            ) {
+                ThreeWayMerged::Merged(item) => item,
+                ThreeWayMerged::Conflict(values) => {
+                    let item = values.local.clone();
+                    on_conflict(values);
+                    item
+                }
+            }
+        })
+        .collect()
+}
+
+/// Updates when `factor_source` was last used to when `other` was, if later.
+fn keep_latest_last_used_on(
+    factor_source: &mut FactorSource,
+    other: &FactorSource,
+) {
+    let mut common = factor_source.common_properties();
+    let other_last_used_on = other.common_properties().last_used_on;
+    if other_last_used_on > common.last_used_on {
+        common.last_used_on = other_last_used_on;
+        factor_source.set_common_properties(common);
+    }
+}
+
+/// Updates the last login of each persona authorized to `dapp` to the last
+/// login of the same persona authorized to `other`, if later.
+fn keep_latest_last_login(dapp: &mut AuthorizedDapp, other: &AuthorizedDapp) {
+    dapp.references_to_authorized_personas
+        .update_all_with(|persona| {
+            if let Some(other) = other
+                .references_to_authorized_personas
+                .get_id(persona.identity_address)
+            {
+                if other.last_login > persona.last_login {
+                    persona.last_login = other.last_login;
+                }
+            }
+        });
+}
+
+/// Merges a value which is never absent, keeping the local value if
+/// conflicting.
+fn merge_value<T: PartialEq + Clone>(
+    base: &T,
+    local: &T,
+    remote: &T,
+    mut on_conflict: impl FnMut(ProfileMergeConflictingValues<T>),
+) -> T {
+    match three_way_merge(Some(base), Some(local), Some(remote)) {
+        ThreeWayMerged::Merged(value) => {
+            value.expect("Merge of present values is present")
+        }
+        ThreeWayMerged::Conflict(values) => {
+            on_conflict(values);
+            local.clone()
+        }
+    }
+}
+
+fn merge_app_preferences(
+    base: &AppPreferences,
+    local: &AppPreferences,
+    remote: &AppPreferences,
+    conflicts: &mut Vec<ProfileMergeConflict>,
+) -> AppPreferences {
+    AppPreferences {
+        display: merge_value(
+            &base.display,
+            &local.display,
+            &remote.display,
+            |values| conflicts.push(ProfileMergeConflict::AppDisplay(values)),
+        ),
+        gateways: merge_value(
+            &base.gateways,
+            &local.gateways,
+            &remote.gateways,
+            |values| conflicts.push(ProfileMergeConflict::Gateways(values)),
+        ),
+        p2p_transport_profiles: merge_value(
+            &base.p2p_transport_profiles,
+            &local.p2p_transport_profiles,
+            &remote.p2p_transport_profiles,
+            |values| {
+                conflicts
+                    .push(ProfileMergeConflict::P2PTransportProfiles(values))
+            },
+        ),
+        relay_services: merge_value(
+            &base.relay_services,
+            &local.relay_services,
+            &remote.relay_services,
+            |values| {
+                conflicts.push(ProfileMergeConflict::RelayServices(values))
+            },
+        ),
+        security: merge_value(
+            &base.security,
+            &local.security,
+            &remote.security,
+            |values| conflicts.push(ProfileMergeConflict::Security(values)),
+        ),
+        transaction: merge_value(
+            &base.transaction,
+            &local.transaction,
+            &remote.transaction,
+            |values| {
+                conflicts
+                    .push(ProfileMergeConflict::TransactionPreferences(values))
+            },
+        ),
+    }
+}
+
+fn merge_network(
+    base: &ProfileNetwork,
+    local: &ProfileNetwork,
+    remote: &ProfileNetwork,
+    conflicts: &mut Vec<ProfileMergeConflict>,
+) -> ProfileNetwork {
+    let network_id = local.id;
+    let mut merged = local.clone();
+    merged.accounts = merge_items(
+        &base.accounts,
+        &local.accounts,
+        &remote.accounts,
+        |values| {
+            conflicts.push(ProfileMergeConflict::Account { network_id, values })
+        },
+    )
+    .into_iter()
+    .collect();
+    merged.personas = merge_items(
+        &base.personas,
+        &local.personas,
+        &remote.personas,
+        |values| {
+            conflicts.push(ProfileMergeConflict::Persona { network_id, values })
+        },
+    )
+    .into_iter()
+    .collect();
+    merged.authorized_dapps = merge_items_keeping_latest(
+        &base.authorized_dapps,
+        &local.authorized_dapps,
+        &remote.authorized_dapps,
+        keep_latest_last_login,
+        |values| {
+            conflicts.push(ProfileMergeConflict::AuthorizedDapp {
+                network_id,
+                values,
+            })
+        },
+    )
+    .into_iter()
+    .collect();
+    merged.address_book = merge_items(
+        &base.address_book,
+        &local.address_book,
+        &remote.address_book,
+        |values| {
+            conflicts.push(ProfileMergeConflict::AddressBookEntry {
+                network_id,
+                values,
+            })
+        },
+    )
+    .into_iter()
+    .collect();
+    merged.watch_only_accounts = merge_items(
+        &base.watch_only_accounts,
+        &local.watch_only_accounts,
+        &remote.watch_only_accounts,
+        |values| {
+            conflicts.push(ProfileMergeConflict::WatchOnlyAccount {
+                network_id,
+                values,
+            })
+        },
+    )
+    .into_iter()
+    .collect();
+    merged.resource_preferences = merge_items(
+        &base.resource_preferences,
+        &local.resource_preferences,
+        &remote.resource_preferences,
+        |_| {},
+    )
+    .into_iter()
+    .collect();
+    merged.token_price_services = merge_value(
+        &base.token_price_services,
+        &local.token_price_services,
+        &remote.token_price_services,
+        |_| {},
+    );
+    merged.mfa_factor_instances = merge_value(
+        &base.mfa_factor_instances,
+        &local.mfa_factor_instances,
+        &remote.mfa_factor_instances,
+        |_| {},
+    );
+    merged
+}
+
+impl ProfileMerging for Profile {
+    fn merge(&self, base: &Profile, remote: &Profile) -> Result<ProfileMerge> {
+        let id = self.id();
+        if base.id() != id || remote.id() != id {
+            return Err(CommonError::CannotMergeProfilesWithDifferentIDs);
+        }
+
+        let mut conflicts = Vec::new();
+
+        let factor_sources = merge_items_keeping_latest(
+            &base.factor_sources,
+            &self.factor_sources,
+            &remote.factor_sources,
+            keep_latest_last_used_on,
+            |values| conflicts.push(ProfileMergeConflict::FactorSource(values)),
+        )
+        .into_iter()
+        .collect::<FactorSources>();
+
+        let app_preferences = merge_app_preferences(
+            &base.app_preferences,
+            &self.app_preferences,
+            &remote.app_preferences,
+            &mut conflicts,
+        );
+
+        let network_ids = self
+            .networks
+            .ids()
+            .into_iter()
+            .chain(remote.networks.ids())
+            .cloned()
+            .collect::<IndexSet<_>>();
+        let network_or_empty = |profile: &Profile, network_id: NetworkID| {
+            profile
+                .networks
+                .get_id(network_id)
+                .cloned()
+                .unwrap_or_else(|| ProfileNetwork::new_empty_on(network_id))
+        };
+        let networks = network_ids
+            .into_iter()
+            .map(|network_id| {
+                merge_network(
+                    &network_or_empty(base, network_id),
+                    &network_or_empty(self, network_id),
+                    &network_or_empty(remote, network_id),
+                    &mut conflicts,
+                )
+            })
+            .collect::<ProfileNetworks>();
+
+        let mut merged = self.clone();
+        merged.factor_sources = factor_sources;
+        merged.app_preferences = app_preferences;
+        merged.networks = networks;
+        remove_dangling_references(&mut merged);
+        merged.update_header(None::<DeviceInfo>);
+
+        Ok(ProfileMerge::new(
+            merged,
+            self.header.last_modified,
+            conflicts,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = Profile;
+
+    fn rename(account: &Account, name: &str) -> Account {
+        let mut account = account.clone();
+        account.display_name = DisplayName::new(name).unwrap();
+        account
+    }
+
+    fn update_mainnet(
+        profile: &mut SUT,
+        update: impl FnMut(&mut ProfileNetwork),
+    ) {
+        assert!(profile.networks.update_with(NetworkID::Mainnet, update));
+    }
+
+    fn mainnet(profile: &SUT) -> ProfileNetwork {
+        profile.networks.get_id(NetworkID::Mainnet).unwrap().clone()
+    }
+
+    #[test]
+    fn merge_unchanged() {
+        let base = SUT::sample();
+        let sut = base.merge(&base, &base).unwrap();
+        assert!(sut.is_conflict_free());
+        assert_eq!(sut.merged.networks, base.networks);
+        assert_eq!(sut.merged.factor_sources, base.factor_sources);
+        assert_eq!(sut.merged.app_preferences, base.app_preferences);
+        assert_eq!(sut.merged.header.id, base.header.id);
+        assert_eq!(sut.local_last_modified, base.header.last_modified);
+    }
+
+    #[test]
+    fn merge_different_ids_fails() {
+        let base = SUT::sample();
+        let other = SUT::sample_other();
+        assert_eq!(
+            base.merge(&base, &other),
+            Err(CommonError::CannotMergeProfilesWithDifferentIDs)
+        );
+        assert_eq!(
+            base.merge(&other, &base),
+            Err(CommonError::CannotMergeProfilesWithDifferentIDs)
+        );
+    }
+
+    #[test]
+    fn merge_non_conflicting_entity_changes() {
+        let base = SUT::sample();
+        let alice = Account::sample_mainnet();
+        let bob = Account::sample_mainnet_other();
+
+        let mut local = base.clone();
+        update_mainnet(&mut local, |n| {
+            n.accounts.insert(rename(&alice, "Local"));
+            n.accounts.append(Account::sample_mainnet_carol());
+            n.watch_only_accounts.append(WatchOnlyAccount::sample());
+        });
+        let mut remote = base.clone();
+        update_mainnet(&mut remote, |n| {
+            n.accounts.insert(rename(&bob, "Remote"));
+            n.personas.remove_id(&Persona::sample_mainnet_batman().id());
+            n.address_book.append(AddressBookEntry::sample());
+        });
+
+        let sut = local.merge(&base, &remote).unwrap();
+
+        assert!(sut.is_conflict_free());
+        let merged = mainnet(&sut.merged);
+        assert_eq!(
+            merged.accounts.items(),
+            vec![
+                rename(&alice, "Local"),
+                rename(&bob, "Remote"),
+                Account::sample_mainnet_carol()
+            ]
+        );
+        assert_eq!(
+            merged.personas.items(),
+            vec![Persona::sample_mainnet_satoshi()]
+        );
+        assert_eq!(
+            merged.address_book.items(),
+            vec![AddressBookEntry::sample()]
+        );
+        assert_eq!(
+            merged.watch_only_accounts.items(),
+            vec![WatchOnlyAccount::sample()]
+        );
+        assert_eq!(
+            sut.merged.header.content_hint,
+            sut.merged.networks.content_hint()
+        );
+    }
+
+    #[test]
+    fn merge_conflicting_account_changes() {
+        let base = SUT::sample();
+        let alice = Account::sample_mainnet();
+
+        let mut local = base.clone();
+        update_mainnet(&mut local, |n| {
+            n.accounts.insert(rename(&alice, "Local"));
+        });
+        let mut remote = base.clone();
+        update_mainnet(&mut remote, |n| {
+            n.accounts.insert(rename(&alice, "Remote"));
+        });
+
+        let sut = local.merge(&base, &remote).unwrap();
+
+        assert_eq!(
+            sut.conflicts,
+            vec![ProfileMergeConflict::Account {
+                network_id: NetworkID::Mainnet,
+                values: ProfileMergeConflictingValues::new(
+                    alice.clone(),
+                    rename(&alice, "Local"),
+                    rename(&alice, "Remote"),
+                ),
+            }]
+        );
+        assert_eq!(mainnet(&sut.merged).accounts[0], rename(&alice, "Local"));
+
+        let resolved = sut
+            .resolve([ProfileMergeConflictResolution::KeepRemote])
+            .unwrap();
+        assert_eq!(mainnet(&resolved).accounts[0], rename(&alice, "Remote"));
+    }
+
+    #[test]
+    fn merge_removed_locally_changed_remotely_conflicts() {
+        let base = SUT::sample();
+        let alice = Account::sample_mainnet();
+
+        let mut local = base.clone();
+        update_mainnet(&mut local, |n| {
+            n.accounts.remove_id(&alice.id());
+        });
+        let mut remote = base.clone();
+        update_mainnet(&mut remote, |n| {
+            n.accounts.insert(rename(&alice, "Remote"));
+        });
+
+        let sut = local.merge(&base, &remote).unwrap();
+
+        assert_eq!(
+            sut.conflicts,
+            vec![ProfileMergeConflict::Account {
+                network_id: NetworkID::Mainnet,
+                values: ProfileMergeConflictingValues::new(
+                    alice.clone(),
+                    None,
+                    rename(&alice, "Remote"),
+                ),
+            }]
+        );
+        assert!(!mainnet(&sut.merged).accounts.contains_id(alice.id()));
+
+        let resolved = sut
+            .resolve([ProfileMergeConflictResolution::KeepRemote])
+            .unwrap();
+        assert!(mainnet(&resolved).accounts.contains_id(alice.id()));
+    }
+
+    #[test]
+    fn merge_removed_remotely() {
+        let base = SUT::sample();
+        let bob = Account::sample_mainnet_other();
+        let mut remote = base.clone();
+        update_mainnet(&mut remote, |n| {
+            n.accounts.remove_id(&bob.id());
+        });
+
+        let sut = base.merge(&base, &remote).unwrap();
+
+        assert!(sut.is_conflict_free());
+        assert!(!mainnet(&sut.merged).accounts.contains_id(bob.id()));
+    }
+
+    #[test]
+    fn merge_network_added_remotely() {
+        let mut base = SUT::sample();
+        base.networks.remove_id(&NetworkID::Stokenet);
+        let remote = SUT::sample();
+
+        let sut = base.merge(&base, &remote).unwrap();
+
+        assert!(sut.is_conflict_free());
+        assert_eq!(
+            sut.merged.networks.get_id(NetworkID::Stokenet),
+            remote.networks.get_id(NetworkID::Stokenet)
+        );
+    }
+
+    #[test]
+    fn merge_factor_source_added_remotely() {
+        let base = SUT::sample();
+        let mut remote = base.clone();
+        let arculus = FactorSource::sample_arculus();
+        remote.factor_sources.append(arculus.clone());
+
+        let sut = base.merge(&base, &remote).unwrap();
+
+        assert!(sut.is_conflict_free());
+        assert!(sut.merged.factor_sources.contains_by_id(&arculus));
+    }
+
+    #[test]
+    fn merge_non_conflicting_app_preferences() {
+        let base = SUT::sample();
+        let mut local = base.clone();
+        local.app_preferences.display.is_currency_amount_visible =
+            !base.app_preferences.display.is_currency_amount_visible;
+        let mut remote = base.clone();
+        remote.app_preferences.security.is_developer_mode_enabled =
+            IsDeveloperModeEnabled(
+                !base.app_preferences.security.is_developer_mode_enabled.0,
+            );
+
+        let sut = local.merge(&base, &remote).unwrap();
+
+        assert!(sut.is_conflict_free());
+        assert_eq!(
+            sut.merged.app_preferences.display,
+            local.app_preferences.display
+        );
+        assert_eq!(
+            sut.merged.app_preferences.security,
+            remote.app_preferences.security
+        );
+    }
+
+    #[test]
+    fn merge_conflicting_app_preferences() {
+        let base = SUT::sample();
+        let security = &base.app_preferences.security;
+        let mut local = base.clone();
+        local.app_preferences.security.is_developer_mode_enabled =
+            IsDeveloperModeEnabled(!security.is_developer_mode_enabled.0);
+        let mut remote = base.clone();
+        remote
+            .app_preferences
+            .security
+            .is_cloud_profile_sync_enabled = IsCloudProfileSyncEnabled(
+            !security.is_cloud_profile_sync_enabled.0,
+        );
+
+        let sut = local.merge(&base, &remote).unwrap();
+
+        assert_eq!(
+            sut.conflicts,
+            vec![ProfileMergeConflict::Security(
+                ProfileMergeConflictingValues::new(
+                    security.clone(),
+                    local.app_preferences.security.clone(),
+                    remote.app_preferences.security.clone(),
+                )
+            )]
+        );
+        assert_eq!(
+            sut.merged.app_preferences.security,
+            local.app_preferences.security
+        );
+    }
+
+    #[test]
+    fn merge_factor_source_used_on_both_devices() {
+        let base = SUT::sample();
+        let factor_source = base.factor_sources[0].clone();
+        let used_on = |factor_source: &FactorSource, timestamp: &str| {
+            let mut factor_source = factor_source.clone();
+            let mut common = factor_source.common_properties();
+            common.last_used_on = Timestamp::parse(timestamp).unwrap();
+            factor_source.set_common_properties(common);
+            factor_source
+        };
+        let mut local = base.clone();
+        local
+            .factor_sources
+            .insert(used_on(&factor_source, "2030-01-01T00:00:00Z"));
+        let mut remote = base.clone();
+        remote
+            .factor_sources
+            .insert(used_on(&factor_source, "2031-01-01T00:00:00Z"));
+
+        let sut = local.merge(&base, &remote).unwrap();
+
+        assert!(sut.is_conflict_free());
+        assert_eq!(
+            sut.merged.factor_sources.get_id(factor_source.id()),
+            Some(&used_on(&factor_source, "2031-01-01T00:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn merge_dapp_logged_in_on_both_devices() {
+        let base = SUT::sample();
+        let dapp = AuthorizedDapp::sample_mainnet();
+        let logged_in = |dapp: &AuthorizedDapp, timestamp: &str| {
+            let mut dapp = dapp.clone();
+            dapp.references_to_authorized_personas
+                .update_all_with(|persona| {
+                    persona.last_login = Timestamp::parse(timestamp).unwrap()
+                });
+            dapp
+        };
+        let mut local = base.clone();
+        update_mainnet(&mut local, |n| {
+            let mut dapp = logged_in(&dapp, "2031-01-01T00:00:00Z");
+            dapp.display_name = Some("Local".to_owned());
+            n.authorized_dapps.insert(dapp);
+        });
+        let mut remote = base.clone();
+        update_mainnet(&mut remote, |n| {
+            n.authorized_dapps
+                .insert(logged_in(&dapp, "2030-01-01T00:00:00Z"));
+        });
+
+        let sut = local.merge(&base, &remote).unwrap();
+
+        assert!(sut.is_conflict_free());
+        let mut expected = logged_in(&dapp, "2031-01-01T00:00:00Z");
+        expected.display_name = Some("Local".to_owned());
+        assert_eq!(mainnet(&sut.merged).authorized_dapps[0], expected);
+    }
+
+    #[test]
+    fn merge_removes_references_to_persona_removed_remotely() {
+        let base = SUT::sample();
+        let batman = Persona::sample_mainnet_batman();
+        let dapp = AuthorizedDapp::sample_mainnet();
+
+        let mut local = base.clone();
+        update_mainnet(&mut local, |n| {
+            let mut dapp = dapp.clone();
+            dapp.display_name = Some("Local".to_owned());
+            n.authorized_dapps.insert(dapp);
+        });
+        let mut remote = base.clone();
+        update_mainnet(&mut remote, |n| {
+            n.personas.remove_id(&batman.id());
+        });
+
+        let sut = local.merge(&base, &remote).unwrap();
+
+        assert!(sut.is_conflict_free());
+        let merged = mainnet(&sut.merged);
+        assert!(merged.authorized_dapps.iter().all(|dapp| {
+            !dapp
+                .references_to_authorized_personas
+                .contains_id(batman.address)
+        }));
+        assert_eq!(
+            merged.authorized_dapps[0]
+                .references_to_authorized_personas
+                .ids()
+                .into_iter()
+                .cloned()
+                .collect_vec(),
+            vec![Persona::sample_mainnet_satoshi().address]
+        );
+    }
+}
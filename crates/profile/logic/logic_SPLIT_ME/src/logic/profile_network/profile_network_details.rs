@@ -110,7 +110,56 @@ impl AuthorizedPersonaSimpleQueryState for AuthorizedPersonaSimple {
             })
             .collect::<CollectionOfEmailAddresses>();
 
-        Ok(PersonaData::new(name, phone_numbers, email_addresses))
+        let postal_addresses = full
+            .postal_addresses
+            .collection
+            .clone()
+            .into_iter()
+            .filter(|x| {
+                shared
+                    .postal_addresses
+                    .clone()
+                    .map(|s| s.ids.clone())
+                    .unwrap_or_default()
+                    .into_iter()
+                    .contains(&x.id)
+            })
+            .collect::<CollectionOfPostalAddresses>();
+
+        let dates_of_birth = full
+            .dates_of_birth
+            .collection
+            .clone()
+            .into_iter()
+            .filter(|x| {
+                shared
+                    .dates_of_birth
+                    .clone()
+                    .map(|s| s.ids.clone())
+                    .unwrap_or_default()
+                    .into_iter()
+                    .contains(&x.id)
+            })
+            .collect::<CollectionOfDatesOfBirth>();
+
+        let companies = full
+            .companies
+            .collection
+            .clone()
+            .into_iter()
+            .filter(|x| {
+                shared
+                    .companies
+                    .clone()
+                    .map(|s| s.ids.clone())
+                    .unwrap_or_default()
+                    .into_iter()
+                    .contains(&x.id)
+            })
+            .collect::<CollectionOfCompanies>();
+
+        Ok(PersonaData::new(name, phone_numbers, email_addresses)
+            .with_extended_entries(postal_addresses, dates_of_birth, companies))
     }
 
     fn persona_from(&self, non_hidden_personas: &Personas) -> Result<Persona> {
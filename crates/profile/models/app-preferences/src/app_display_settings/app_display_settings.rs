@@ -7,7 +7,6 @@ use crate::prelude::*;
     Serialize,
     Deserialize,
     Clone,
-    Copy,
     Debug,
     PartialEq,
     Eq,
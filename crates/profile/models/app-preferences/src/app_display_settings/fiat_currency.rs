@@ -1,16 +1,88 @@
 use crate::prelude::*;
 
 /// Fiat currency to measure and display the value of some XRD or other Radix assets value/worth in.
-#[derive(
-    Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord,
-)]
-#[serde(rename_all = "lowercase")]
+///
+/// Stored in Profile as its lowercase ISO 4217 code, e.g. `"usd"`. Codes not
+/// known by this version of Sargon - e.g. written by a newer version - are
+/// preserved as `Unknown`, so that they are not lost when the Profile is
+/// saved again.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum FiatCurrency {
     /// American dollars.
     USD,
 
     /// Swedish krona.
     SEK,
+
+    /// Euro.
+    EUR,
+
+    /// British pounds.
+    GBP,
+
+    /// Japanese yen.
+    JPY,
+
+    /// Swiss francs.
+    CHF,
+
+    /// Norwegian krone.
+    NOK,
+
+    /// Danish krone.
+    DKK,
+
+    /// Polish złoty.
+    PLN,
+
+    /// Czech koruna.
+    CZK,
+
+    /// Hungarian forint.
+    HUF,
+
+    /// Canadian dollars.
+    CAD,
+
+    /// Australian dollars.
+    AUD,
+
+    /// New Zealand dollars.
+    NZD,
+
+    /// Chinese yuan renminbi.
+    CNY,
+
+    /// Hong Kong dollars.
+    HKD,
+
+    /// Singapore dollars.
+    SGD,
+
+    /// South Korean won.
+    KRW,
+
+    /// Indian rupees.
+    INR,
+
+    /// Brazilian reais.
+    BRL,
+
+    /// Mexican pesos.
+    MXN,
+
+    /// South African rand.
+    ZAR,
+
+    /// Turkish lira.
+    TRY,
+
+    /// United Arab Emirates dirhams.
+    AED,
+
+    /// A currency not known by this version of Sargon, with its code as
+    /// found in the Profile.
+    Unknown { code: String },
 }
 
 impl Default for FiatCurrency {
@@ -20,20 +92,106 @@ impl Default for FiatCurrency {
     }
 }
 
+impl FiatCurrency {
+    /// The number of places `Decimal192::formatted` is called with when
+    /// formatting an amount in a fiat currency.
+    pub const FORMATTED_TOTAL_PLACES: u8 = 8;
+
+    /// All currencies known by this version of Sargon, i.e. all but
+    /// `Unknown`.
+    pub fn all_known() -> Vec<Self> {
+        vec![
+            Self::USD,
+            Self::SEK,
+            Self::EUR,
+            Self::GBP,
+            Self::JPY,
+            Self::CHF,
+            Self::NOK,
+            Self::DKK,
+            Self::PLN,
+            Self::CZK,
+            Self::HUF,
+            Self::CAD,
+            Self::AUD,
+            Self::NZD,
+            Self::CNY,
+            Self::HKD,
+            Self::SGD,
+            Self::KRW,
+            Self::INR,
+            Self::BRL,
+            Self::MXN,
+            Self::ZAR,
+            Self::TRY,
+            Self::AED,
+        ]
+    }
+
+    /// The uppercase ISO 4217 code of the currency, e.g. `"USD"`.
+    pub fn code(&self) -> String {
+        match self {
+            Self::Unknown { code } => code.to_uppercase(),
+            _ => format!("{:?}", self),
+        }
+    }
+
+    /// The number of digits after the decimal separator of the minor unit
+    /// of the currency, as per ISO 4217, e.g. `2` for cents of `USD` and
+    /// `0` for `JPY` which has no minor unit. `2` for `Unknown` currencies.
+    pub fn minor_units(&self) -> u8 {
+        match self {
+            Self::JPY | Self::KRW => 0,
+            _ => 2,
+        }
+    }
+
+    /// A human readable, locale respecting string of `amount` in this
+    /// currency, rounded to the minor unit of the currency.
+    ///
+    /// Does not include the code or symbol of the currency.
+    pub fn formatted(
+        &self,
+        amount: Decimal192,
+        locale: LocaleConfig,
+    ) -> String {
+        amount.round(self.minor_units()).formatted(
+            locale,
+            Self::FORMATTED_TOTAL_PLACES,
+            true,
+        )
+    }
+}
+
+impl Serialize for FiatCurrency {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Unknown { code } => serializer.serialize_str(code),
+            _ => serializer.serialize_str(&self.code().to_lowercase()),
+        }
+    }
+}
+
 impl<'de> serde::Deserialize<'de> for FiatCurrency {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         let value = <String as serde::Deserialize>::deserialize(deserializer)?;
-
-        match value.as_str() {
-            v if v.eq_ignore_ascii_case("usd") => Ok(Self::USD),
-            v if v.eq_ignore_ascii_case("sek") => Ok(Self::SEK),
-            _ => {
-                Err(serde::de::Error::unknown_variant(&value, &["usd", "sek"]))
-            }
+        if value.is_empty() {
+            return Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(&value),
+                &"an ISO 4217 currency code",
+            ));
         }
+
+        Ok(Self::all_known()
+            .into_iter()
+            .find(|known| known.code().eq_ignore_ascii_case(&value))
+            .unwrap_or(Self::Unknown { code: value }))
     }
 }
 
@@ -80,4 +238,67 @@ mod tests {
         assert_eq!("USD".deserialize::<FiatCurrency>().unwrap(), SUT::USD);
         assert_eq!("SEK".deserialize::<FiatCurrency>().unwrap(), SUT::SEK);
     }
+
+    #[test]
+    fn json_roundtrip_all_known() {
+        for sut in SUT::all_known() {
+            assert_json_value_eq_after_roundtrip(
+                &sut,
+                serde_json::Value::String(sut.code().to_lowercase()),
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_code_is_preserved() {
+        let sut = "xau".deserialize::<FiatCurrency>().unwrap();
+        assert_eq!(
+            sut,
+            SUT::Unknown {
+                code: "xau".to_owned()
+            }
+        );
+        assert_eq!(sut.code(), "XAU");
+        assert_json_value_eq_after_roundtrip(
+            &sut,
+            serde_json::Value::String("xau".to_owned()),
+        );
+    }
+
+    #[test]
+    fn empty_code_is_err() {
+        assert!("".deserialize::<FiatCurrency>().is_err());
+    }
+
+    #[test]
+    fn code() {
+        assert_eq!(SUT::USD.code(), "USD");
+        assert_eq!(SUT::EUR.code(), "EUR");
+    }
+
+    #[test]
+    fn minor_units() {
+        assert_eq!(SUT::USD.minor_units(), 2);
+        assert_eq!(SUT::JPY.minor_units(), 0);
+        assert_eq!(
+            SUT::Unknown {
+                code: "xau".to_owned()
+            }
+            .minor_units(),
+            2
+        );
+    }
+
+    #[test]
+    fn formatted_rounds_to_minor_units() {
+        let amount = Decimal192::from_str("1234.5678").unwrap();
+        assert_eq!(
+            SUT::USD.formatted(amount, LocaleConfig::default()),
+            "1 234.57"
+        );
+        assert_eq!(
+            SUT::JPY.formatted(amount, LocaleConfig::default()),
+            "1 235"
+        );
+    }
 }
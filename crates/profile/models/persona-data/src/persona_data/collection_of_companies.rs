@@ -0,0 +1,29 @@
+use crate::prelude::*;
+
+declare_collection_of_identified_entry!(
+    /// A collection of [`PersonaDataIdentifiedCompany`]s, which is essentially a tuple of
+    /// `(Uuid, PersonaDataEntryCompany)`, each element is identifiable by its ID. Can be empty, can
+    /// contain elements with the same value, but under different IDs.
+    company,   // singular form
+    companies, // plural form
+    "[CEO, Wayne Enterprises, Daily Planet]",
+    "[CEO, Wayne Enterprises - 00000000-0000-0000-0000-000000000001, Daily Planet - 00000000-0000-0000-0000-000000000002]",
+    r#"
+    [
+        {
+            "id": "00000000-0000-0000-0000-000000000001",
+            "value": {
+                "name": "Wayne Enterprises",
+                "title": "CEO"
+            }
+        },
+        {
+            "id": "00000000-0000-0000-0000-000000000002",
+            "value": {
+                "name": "Daily Planet",
+                "title": ""
+            }
+        }
+    ]
+    "#
+);
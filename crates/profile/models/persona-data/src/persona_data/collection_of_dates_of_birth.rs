@@ -0,0 +1,23 @@
+use crate::prelude::*;
+
+declare_collection_of_identified_entry!(
+    /// A collection of [`PersonaDataIdentifiedDateOfBirth`]s, which is essentially a tuple of
+    /// `(Uuid, PersonaDataEntryDateOfBirth)`, each element is identifiable by its ID. Can be empty, can
+    /// contain elements with the same value, but under different IDs.
+    date_of_birth,  // singular form
+    dates_of_birth, // plural form
+    "[1939-05-27, 1940-11-27]",
+    "[1939-05-27 - 00000000-0000-0000-0000-000000000001, 1940-11-27 - 00000000-0000-0000-0000-000000000002]",
+    r#"
+    [
+        {
+            "id": "00000000-0000-0000-0000-000000000001",
+            "value": "1939-05-27"
+        },
+        {
+            "id": "00000000-0000-0000-0000-000000000002",
+            "value": "1940-11-27"
+        }
+    ]
+    "#
+);
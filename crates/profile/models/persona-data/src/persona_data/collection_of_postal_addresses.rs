@@ -0,0 +1,59 @@
+use crate::prelude::*;
+
+declare_collection_of_identified_entry!(
+    /// A collection of [`PersonaDataIdentifiedPostalAddress`]s, which is essentially a tuple of
+    /// `(Uuid, PersonaDataEntryPostalAddress)`, each element is identifiable by its ID. Can be empty, can
+    /// contain elements with the same value, but under different IDs.
+    postal_address,   // singular form
+    postal_addresses, // plural form
+    "[1007 Mountain Drive, Gotham City, NJ, 07001, US, Drottninggatan 1, 111 51, Stockholm, SE]",
+    "[1007 Mountain Drive, Gotham City, NJ, 07001, US - 00000000-0000-0000-0000-000000000001, Drottninggatan 1, 111 51, Stockholm, SE - 00000000-0000-0000-0000-000000000002]",
+    r#"
+    [
+        {
+            "id": "00000000-0000-0000-0000-000000000001",
+            "value": {
+                "countryCode": "US",
+                "fields": [
+                    {
+                        "kind": "streetLine1",
+                        "value": "1007 Mountain Drive"
+                    },
+                    {
+                        "kind": "city",
+                        "value": "Gotham City"
+                    },
+                    {
+                        "kind": "state",
+                        "value": "NJ"
+                    },
+                    {
+                        "kind": "postalCode",
+                        "value": "07001"
+                    }
+                ]
+            }
+        },
+        {
+            "id": "00000000-0000-0000-0000-000000000002",
+            "value": {
+                "countryCode": "SE",
+                "fields": [
+                    {
+                        "kind": "streetLine1",
+                        "value": "Drottninggatan 1"
+                    },
+                    {
+                        "kind": "postalCode",
+                        "value": "111 51"
+                    },
+                    {
+                        "kind": "city",
+                        "value": "Stockholm"
+                    }
+                ]
+            }
+        }
+    ]
+    "#
+);
@@ -0,0 +1,107 @@
+use crate::prelude::*;
+
+/// A company a person has chosen to associate with a Persona, and optionally
+/// their title at it, e.g. `"Wayne Enterprises"` and `"CEO"`.
+///
+/// The name of the company is never empty, the title can be left blank.
+#[derive(
+    Serialize,
+    Deserialize,
+    Clone,
+    PartialEq,
+    Hash,
+    Eq,
+    derive_more::Display,
+    derive_more::Debug,
+)]
+#[display("{}", self.full())]
+#[debug("{}", self.full())]
+#[serde(rename_all = "camelCase")]
+pub struct PersonaDataEntryCompany {
+    pub name: String,
+    pub title: String,
+}
+
+impl PersonaDataEntryCompany {
+    pub fn new(name: impl AsRef<str>, title: impl AsRef<str>) -> Result<Self> {
+        let name = name.as_ref().trim().to_string();
+        let title = title.as_ref().trim().to_string();
+        if name.is_empty() {
+            return Err(CommonError::PersonaDataInvalidCompanyNameEmpty);
+        }
+        Ok(Self { name, title })
+    }
+
+    fn full(&self) -> String {
+        if self.title.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}, {}", self.title, self.name)
+        }
+    }
+}
+
+impl HasSampleValues for PersonaDataEntryCompany {
+    fn sample() -> Self {
+        PersonaDataEntryCompany::new("Wayne Enterprises", "CEO")
+            .expect("Should have a valid Company sample")
+    }
+
+    fn sample_other() -> Self {
+        PersonaDataEntryCompany::new("Daily Planet", "")
+            .expect("Should have a valid Company sample")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = PersonaDataEntryCompany;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn new_is_trimmed() {
+        let sut = SUT::new("  Wayne Enterprises\n", " CEO ").unwrap();
+        assert_eq!(sut, SUT::sample());
+    }
+
+    #[test]
+    fn empty_name_is_err() {
+        assert_eq!(
+            SUT::new(" ", "CEO"),
+            Err(CommonError::PersonaDataInvalidCompanyNameEmpty)
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(SUT::sample().to_string(), "CEO, Wayne Enterprises");
+        assert_eq!(SUT::sample_other().to_string(), "Daily Planet");
+    }
+
+    #[test]
+    fn json_roundtrip_sample() {
+        let model = SUT::sample();
+        assert_eq_after_json_roundtrip(
+            &model,
+            r#"
+            {
+                "name": "Wayne Enterprises",
+                "title": "CEO"
+            }
+            "#,
+        )
+    }
+}
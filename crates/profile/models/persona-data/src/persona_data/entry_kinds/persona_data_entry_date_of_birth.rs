@@ -0,0 +1,183 @@
+use crate::prelude::*;
+
+/// A persons date of birth they have chosen to associate with a Persona, a
+/// calendar date without time or timezone, e.g. `1939-05-27`.
+///
+/// Stored in Profile and sent to dApps as an ISO 8601 date string, i.e.
+/// `"YYYY-MM-DD"`.
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    derive_more::Display,
+    derive_more::Debug,
+)]
+#[display("{year:04}-{month:02}-{day:02}")]
+#[debug("{year:04}-{month:02}-{day:02}")]
+pub struct PersonaDataEntryDateOfBirth {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl PersonaDataEntryDateOfBirth {
+    pub fn new(year: u16, month: u8, day: u8) -> Result<Self> {
+        let is_valid = (1..=9999).contains(&year)
+            && (1..=12).contains(&month)
+            && day >= 1
+            && day <= Self::days_in_month(year, month);
+        if !is_valid {
+            return Err(CommonError::PersonaDataInvalidDateOfBirth {
+                bad_value: format!("{year:04}-{month:02}-{day:02}"),
+            });
+        }
+        Ok(Self { year, month, day })
+    }
+
+    fn days_in_month(year: u16, month: u8) -> u8 {
+        match month {
+            4 | 6 | 9 | 11 => 30,
+            2 => {
+                let is_leap_year =
+                    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+                if is_leap_year {
+                    29
+                } else {
+                    28
+                }
+            }
+            _ => 31,
+        }
+    }
+}
+
+impl FromStr for PersonaDataEntryDateOfBirth {
+    type Err = CommonError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || CommonError::PersonaDataInvalidDateOfBirth {
+            bad_value: s.to_owned(),
+        };
+        let parts = s.trim().split('-').collect_vec();
+        let [year, month, day] = parts.as_slice() else {
+            return Err(invalid());
+        };
+        if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+            return Err(invalid());
+        }
+        let year = year.parse::<u16>().map_err(|_| invalid())?;
+        let month = month.parse::<u8>().map_err(|_| invalid())?;
+        let day = day.parse::<u8>().map_err(|_| invalid())?;
+        Self::new(year, month, day)
+    }
+}
+
+impl Serialize for PersonaDataEntryDateOfBirth {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for PersonaDataEntryDateOfBirth {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = <String as Deserialize>::deserialize(deserializer)?;
+        Self::from_str(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+impl HasSampleValues for PersonaDataEntryDateOfBirth {
+    fn sample() -> Self {
+        PersonaDataEntryDateOfBirth::new(1939, 5, 27)
+            .expect("Should have a valid DateOfBirth sample")
+    }
+
+    fn sample_other() -> Self {
+        PersonaDataEntryDateOfBirth::new(1940, 11, 27)
+            .expect("Should have a valid DateOfBirth sample")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = PersonaDataEntryDateOfBirth;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(SUT::sample().to_string(), "1939-05-27");
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("1939-05-27".parse::<SUT>().unwrap(), SUT::sample());
+    }
+
+    #[test]
+    fn leap_day() {
+        assert!(SUT::new(2000, 2, 29).is_ok());
+        assert!(SUT::new(2024, 2, 29).is_ok());
+        assert!(SUT::new(1900, 2, 29).is_err());
+        assert!(SUT::new(2023, 2, 29).is_err());
+    }
+
+    #[test]
+    fn invalid_dates_are_err() {
+        assert_eq!(
+            SUT::new(1999, 4, 31),
+            Err(CommonError::PersonaDataInvalidDateOfBirth {
+                bad_value: "1999-04-31".to_owned()
+            })
+        );
+        assert!(SUT::new(1999, 13, 1).is_err());
+        assert!(SUT::new(1999, 0, 1).is_err());
+        assert!(SUT::new(1999, 1, 0).is_err());
+        assert!(SUT::new(0, 1, 1).is_err());
+    }
+
+    #[test]
+    fn invalid_strings_are_err() {
+        for s in ["", "1939-5-27", "27-05-1939", "1939/05/27", "1939-05-27-01"]
+        {
+            assert_eq!(
+                s.parse::<SUT>(),
+                Err(CommonError::PersonaDataInvalidDateOfBirth {
+                    bad_value: s.to_owned()
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn json_roundtrip_sample() {
+        let model = SUT::sample();
+        assert_json_value_eq_after_roundtrip(&model, json!("1939-05-27"));
+    }
+
+    #[test]
+    fn json_invalid_is_err() {
+        assert_json_value_fails::<SUT>(json!("1939-02-30"));
+    }
+}
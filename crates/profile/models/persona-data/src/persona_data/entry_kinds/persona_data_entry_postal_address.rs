@@ -0,0 +1,617 @@
+use crate::prelude::*;
+
+/// A postal address a person has chosen to associate with a Persona, e.g.
+/// as shipping address for e-commerce dApps.
+///
+/// Which fields an address consists of, and in which order they are written,
+/// depends on the country, see [`PersonaDataEntryPostalAddress::field_layout`].
+/// The `fields` of an address are always in the order of the layout of its
+/// country, required fields are never empty and optional fields are omitted
+/// if empty. Fields of kinds not known by this version of Sargon are kept,
+/// after those of the layout.
+#[derive(
+    Serialize,
+    Clone,
+    PartialEq,
+    Hash,
+    Eq,
+    derive_more::Display,
+    derive_more::Debug,
+)]
+#[display("{}", self.full())]
+#[debug("{}", self.full())]
+#[serde(rename_all = "camelCase")]
+pub struct PersonaDataEntryPostalAddress {
+    /// Uppercase ISO 3166-1 alpha-2 code of the country, e.g. `"SE"`.
+    pub country_code: String,
+    pub fields: Vec<PersonaDataPostalAddressField>,
+}
+
+/// The kind of a field of a [`PersonaDataEntryPostalAddress`].
+///
+/// Stored in Profile in camelCase, e.g. `"streetLine1"`. Kinds not known by
+/// this version of Sargon - e.g. written by a newer version - are preserved
+/// as `Unknown`, so that they are not lost when the Profile is saved again.
+#[derive(Clone, Debug, PartialEq, Hash, Eq)]
+pub enum PersonaDataPostalAddressFieldKind {
+    StreetLine1,
+    StreetLine2,
+    City,
+    State,
+    Province,
+    County,
+    Prefecture,
+    PostalCode,
+
+    /// A kind not known by this version of Sargon, as found in the Profile.
+    Unknown {
+        kind: String,
+    },
+}
+
+impl PersonaDataPostalAddressFieldKind {
+    /// All kinds known by this version of Sargon.
+    pub fn all_known() -> Vec<Self> {
+        vec![
+            Self::StreetLine1,
+            Self::StreetLine2,
+            Self::City,
+            Self::State,
+            Self::Province,
+            Self::County,
+            Self::Prefecture,
+            Self::PostalCode,
+        ]
+    }
+
+    fn json_value(&self) -> &str {
+        match self {
+            Self::StreetLine1 => "streetLine1",
+            Self::StreetLine2 => "streetLine2",
+            Self::City => "city",
+            Self::State => "state",
+            Self::Province => "province",
+            Self::County => "county",
+            Self::Prefecture => "prefecture",
+            Self::PostalCode => "postalCode",
+            Self::Unknown { kind } => kind,
+        }
+    }
+
+    fn is_unknown(&self) -> bool {
+        matches!(self, Self::Unknown { .. })
+    }
+}
+
+impl Serialize for PersonaDataPostalAddressFieldKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.json_value())
+    }
+}
+
+impl<'de> Deserialize<'de> for PersonaDataPostalAddressFieldKind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = <String as Deserialize>::deserialize(deserializer)?;
+        Ok(Self::all_known()
+            .into_iter()
+            .find(|known| known.json_value() == value)
+            .unwrap_or(Self::Unknown { kind: value }))
+    }
+}
+
+/// A field of a [`PersonaDataEntryPostalAddress`], e.g. its postal code.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Hash, Eq)]
+pub struct PersonaDataPostalAddressField {
+    pub kind: PersonaDataPostalAddressFieldKind,
+    pub value: String,
+}
+
+impl PersonaDataPostalAddressField {
+    pub fn new(
+        kind: PersonaDataPostalAddressFieldKind,
+        value: impl AsRef<str>,
+    ) -> Self {
+        Self {
+            kind,
+            value: value.as_ref().trim().to_owned(),
+        }
+    }
+}
+
+/// A field in the layout of postal addresses of some country, see
+/// [`PersonaDataEntryPostalAddress::field_layout`].
+#[derive(Clone, Copy, Debug, PartialEq, Hash, Eq)]
+pub struct PersonaDataPostalAddressFieldLayout {
+    pub kind: PersonaDataPostalAddressFieldKind,
+    pub is_required: bool,
+}
+
+impl PersonaDataEntryPostalAddress {
+    /// The fields - in order - of postal addresses in the country with
+    /// `country_code`, used to validate addresses and by hosts to display
+    /// a form for them.
+    ///
+    /// Countries without a specific layout use a generic one, with the postal
+    /// code before the city, as in most of Europe. The postal code is optional
+    /// in it, since many countries - e.g. AE and HK - have none.
+    pub fn field_layout(
+        country_code: impl AsRef<str>,
+    ) -> Vec<PersonaDataPostalAddressFieldLayout> {
+        use PersonaDataPostalAddressFieldKind::*;
+        let kinds: &[(PersonaDataPostalAddressFieldKind, bool)] =
+            match country_code.as_ref().to_uppercase().as_str() {
+                "US" | "AU" => &[
+                    (StreetLine1, true),
+                    (StreetLine2, false),
+                    (City, true),
+                    (State, true),
+                    (PostalCode, true),
+                ],
+                "CA" => &[
+                    (StreetLine1, true),
+                    (StreetLine2, false),
+                    (City, true),
+                    (Province, true),
+                    (PostalCode, true),
+                ],
+                "GB" | "IE" => &[
+                    (StreetLine1, true),
+                    (StreetLine2, false),
+                    (City, true),
+                    (County, false),
+                    (PostalCode, true),
+                ],
+                "JP" => &[
+                    (PostalCode, true),
+                    (Prefecture, true),
+                    (City, true),
+                    (StreetLine1, true),
+                    (StreetLine2, false),
+                ],
+                _ => &[
+                    (StreetLine1, true),
+                    (StreetLine2, false),
+                    (PostalCode, false),
+                    (City, true),
+                ],
+            };
+        kinds
+            .iter()
+            .map(|(kind, is_required)| PersonaDataPostalAddressFieldLayout {
+                kind: kind.clone(),
+                is_required: *is_required,
+            })
+            .collect()
+    }
+
+    /// Validates `fields` against the layout of the country of `country_code`
+    /// and orders them as per the layout, followed by any non empty fields of
+    /// `Unknown` kinds.
+    pub fn new(
+        country_code: impl AsRef<str>,
+        fields: impl IntoIterator<Item = PersonaDataPostalAddressField>,
+    ) -> Result<Self> {
+        let country_code = country_code.as_ref().trim().to_uppercase();
+        if country_code.len() != 2
+            || !country_code.chars().all(|c| c.is_ascii_uppercase())
+        {
+            return Err(
+                CommonError::PersonaDataInvalidPostalAddressCountryCode {
+                    bad_value: country_code,
+                },
+            );
+        }
+
+        let layout = Self::field_layout(&country_code);
+        let fields = fields
+            .into_iter()
+            .map(|field| {
+                PersonaDataPostalAddressField::new(field.kind, field.value)
+            })
+            .collect_vec();
+        if let Some(unexpected) = fields.iter().find(|field| {
+            !field.kind.is_unknown()
+                && !layout.iter().any(|in_layout| in_layout.kind == field.kind)
+        }) {
+            return Err(
+                CommonError::PersonaDataInvalidPostalAddressUnexpectedField {
+                    field: format!("{:?}", unexpected.kind),
+                },
+            );
+        }
+
+        let mut ordered = Vec::<PersonaDataPostalAddressField>::new();
+        for in_layout in layout {
+            let mut of_kind =
+                fields.iter().filter(|field| field.kind == in_layout.kind);
+            let field = of_kind.next();
+            if of_kind.next().is_some() {
+                return Err(
+                    CommonError::PersonaDataInvalidPostalAddressUnexpectedField {
+                        field: format!("{:?}", in_layout.kind),
+                    },
+                );
+            }
+            match field.filter(|field| !field.value.is_empty()) {
+                Some(field) => ordered.push(field.clone()),
+                None if in_layout.is_required => {
+                    return Err(
+                        CommonError::PersonaDataInvalidPostalAddressMissingField {
+                            field: format!("{:?}", in_layout.kind),
+                        },
+                    );
+                }
+                None => {}
+            }
+        }
+        ordered.extend(fields.into_iter().filter(|field| {
+            field.kind.is_unknown() && !field.value.is_empty()
+        }));
+
+        Ok(Self {
+            country_code,
+            fields: ordered,
+        })
+    }
+
+    /// The value of the field of `kind`, if any.
+    pub fn field(
+        &self,
+        kind: PersonaDataPostalAddressFieldKind,
+    ) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|field| field.kind == kind)
+            .map(|field| field.value.as_str())
+    }
+
+    fn full(&self) -> String {
+        self.fields
+            .iter()
+            .map(|field| field.value.as_str())
+            .chain([self.country_code.as_str()])
+            .join(", ")
+    }
+}
+
+impl<'de> Deserialize<'de> for PersonaDataEntryPostalAddress {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Wrapper {
+            country_code: String,
+            fields: Vec<PersonaDataPostalAddressField>,
+        }
+        let wrapped = Wrapper::deserialize(deserializer)?;
+        Self::new(wrapped.country_code, wrapped.fields)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+impl HasSampleValues for PersonaDataEntryPostalAddress {
+    fn sample() -> Self {
+        use PersonaDataPostalAddressFieldKind::*;
+        PersonaDataEntryPostalAddress::new(
+            "US",
+            [
+                PersonaDataPostalAddressField::new(
+                    StreetLine1,
+                    "1007 Mountain Drive",
+                ),
+                PersonaDataPostalAddressField::new(City, "Gotham City"),
+                PersonaDataPostalAddressField::new(State, "NJ"),
+                PersonaDataPostalAddressField::new(PostalCode, "07001"),
+            ],
+        )
+        .expect("Should have a valid PostalAddress sample")
+    }
+
+    fn sample_other() -> Self {
+        use PersonaDataPostalAddressFieldKind::*;
+        PersonaDataEntryPostalAddress::new(
+            "SE",
+            [
+                PersonaDataPostalAddressField::new(
+                    StreetLine1,
+                    "Drottninggatan 1",
+                ),
+                PersonaDataPostalAddressField::new(PostalCode, "111 51"),
+                PersonaDataPostalAddressField::new(City, "Stockholm"),
+            ],
+        )
+        .expect("Should have a valid PostalAddress sample")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use PersonaDataPostalAddressFieldKind::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = PersonaDataEntryPostalAddress;
+
+    fn field(
+        kind: PersonaDataPostalAddressFieldKind,
+        value: &str,
+    ) -> PersonaDataPostalAddressField {
+        PersonaDataPostalAddressField::new(kind, value)
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn fields_are_ordered_as_layout() {
+        let sut = SUT::new(
+            "jp",
+            [
+                field(StreetLine1, "1-1 Chiyoda"),
+                field(City, "Chiyoda-ku"),
+                field(Prefecture, "Tokyo"),
+                field(PostalCode, "100-0001"),
+            ],
+        )
+        .unwrap();
+        assert_eq!(sut.country_code, "JP");
+        assert_eq!(
+            sut.fields.iter().map(|f| f.kind.clone()).collect_vec(),
+            vec![PostalCode, Prefecture, City, StreetLine1]
+        );
+    }
+
+    #[test]
+    fn empty_optional_field_is_omitted() {
+        let sut = SUT::new(
+            "GB",
+            [
+                field(StreetLine1, "221B Baker Street"),
+                field(StreetLine2, "  "),
+                field(City, "London"),
+                field(PostalCode, "NW1 6XE"),
+            ],
+        )
+        .unwrap();
+        assert_eq!(sut.field(StreetLine2), None);
+        assert_eq!(sut.field(County), None);
+        assert_eq!(sut.field(City), Some("London"));
+    }
+
+    #[test]
+    fn missing_required_field_is_err() {
+        assert_eq!(
+            SUT::new(
+                "US",
+                [
+                    field(StreetLine1, "1007 Mountain Drive"),
+                    field(City, "Gotham City"),
+                    field(PostalCode, "07001"),
+                ],
+            ),
+            Err(CommonError::PersonaDataInvalidPostalAddressMissingField {
+                field: "State".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn field_not_in_layout_is_err() {
+        assert_eq!(
+            SUT::new(
+                "SE",
+                [
+                    field(StreetLine1, "Drottninggatan 1"),
+                    field(PostalCode, "111 51"),
+                    field(City, "Stockholm"),
+                    field(State, "Stockholm"),
+                ],
+            ),
+            Err(
+                CommonError::PersonaDataInvalidPostalAddressUnexpectedField {
+                    field: "State".to_owned()
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn duplicate_field_is_err() {
+        assert_eq!(
+            SUT::new(
+                "SE",
+                [
+                    field(StreetLine1, "Drottninggatan 1"),
+                    field(PostalCode, "111 51"),
+                    field(City, "Stockholm"),
+                    field(City, "Uppsala"),
+                ],
+            ),
+            Err(
+                CommonError::PersonaDataInvalidPostalAddressUnexpectedField {
+                    field: "City".to_owned()
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn postal_code_is_optional_in_generic_layout() {
+        let sut = SUT::new(
+            "AE",
+            [
+                field(StreetLine1, "1 Sheikh Mohammed bin Rashid Blvd"),
+                field(City, "Dubai"),
+            ],
+        )
+        .unwrap();
+        assert_eq!(sut.field(PostalCode), None);
+        assert_eq!(sut.field(City), Some("Dubai"));
+    }
+
+    #[test]
+    fn unknown_field_kind_is_kept_after_layout() {
+        let unknown = PersonaDataPostalAddressFieldKind::Unknown {
+            kind: "district".to_owned(),
+        };
+        let sut = SUT::new(
+            "SE",
+            [
+                field(unknown.clone(), "Norrmalm"),
+                field(StreetLine1, "Drottninggatan 1"),
+                field(PostalCode, "111 51"),
+                field(City, "Stockholm"),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            sut.fields.iter().map(|f| f.kind.clone()).collect_vec(),
+            vec![StreetLine1, PostalCode, City, unknown]
+        );
+    }
+
+    #[test]
+    fn invalid_country_code_is_err() {
+        for code in ["", "S", "SWE", "S1"] {
+            assert_eq!(
+                SUT::new(code, Vec::<PersonaDataPostalAddressField>::new()),
+                Err(CommonError::PersonaDataInvalidPostalAddressCountryCode {
+                    bad_value: code.to_owned()
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            SUT::sample().to_string(),
+            "1007 Mountain Drive, Gotham City, NJ, 07001, US"
+        );
+    }
+
+    #[test]
+    fn json_roundtrip_sample() {
+        let model = SUT::sample_other();
+        assert_eq_after_json_roundtrip(
+            &model,
+            r#"
+            {
+                "countryCode": "SE",
+                "fields": [
+                    {
+                        "kind": "streetLine1",
+                        "value": "Drottninggatan 1"
+                    },
+                    {
+                        "kind": "postalCode",
+                        "value": "111 51"
+                    },
+                    {
+                        "kind": "city",
+                        "value": "Stockholm"
+                    }
+                ]
+            }
+            "#,
+        )
+    }
+
+    #[test]
+    fn json_roundtrip_unknown_field_kind() {
+        let json = r#"
+            {
+                "countryCode": "SE",
+                "fields": [
+                    {
+                        "kind": "streetLine1",
+                        "value": "Drottninggatan 1"
+                    },
+                    {
+                        "kind": "postalCode",
+                        "value": "111 51"
+                    },
+                    {
+                        "kind": "city",
+                        "value": "Stockholm"
+                    },
+                    {
+                        "kind": "district",
+                        "value": "Norrmalm"
+                    }
+                ]
+            }
+            "#;
+        let sut = serde_json::from_str::<SUT>(json).unwrap();
+        assert_eq!(
+            sut.field(PersonaDataPostalAddressFieldKind::Unknown {
+                kind: "district".to_owned()
+            }),
+            Some("Norrmalm")
+        );
+        assert_eq_after_json_roundtrip(&sut, json);
+    }
+
+    #[test]
+    fn json_missing_required_field_is_err() {
+        assert_json_fails::<SUT>(
+            r#"
+            {
+                "countryCode": "US",
+                "fields": [
+                    {
+                        "kind": "streetLine1",
+                        "value": "1007 Mountain Drive"
+                    },
+                    {
+                        "kind": "city",
+                        "value": "Gotham City"
+                    }
+                ]
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_is_ordered_as_layout() {
+        let sut = serde_json::from_str::<SUT>(
+            r#"
+            {
+                "countryCode": "SE",
+                "fields": [
+                    {
+                        "kind": "city",
+                        "value": "Stockholm"
+                    },
+                    {
+                        "kind": "streetLine1",
+                        "value": "Drottninggatan 1"
+                    }
+                ]
+            }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            sut.fields.iter().map(|f| f.kind.clone()).collect_vec(),
+            vec![StreetLine1, City]
+        );
+    }
+}
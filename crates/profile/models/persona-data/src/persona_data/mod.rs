@@ -1,13 +1,19 @@
+mod collection_of_companies;
+mod collection_of_dates_of_birth;
 mod collection_of_email_addresses;
 mod collection_of_phone_numbers;
+mod collection_of_postal_addresses;
 mod entry_kinds;
 mod persona_data;
 mod persona_data_entry_id;
 mod persona_data_identified_collection_types;
 mod persona_data_identified_entry_types;
 
+pub use collection_of_companies::*;
+pub use collection_of_dates_of_birth::*;
 pub use collection_of_email_addresses::*;
 pub use collection_of_phone_numbers::*;
+pub use collection_of_postal_addresses::*;
 pub use entry_kinds::*;
 pub use persona_data::*;
 pub use persona_data_entry_id::*;
@@ -52,6 +52,29 @@ pub struct PersonaData {
     /// `(Uuid, PersonaDataIdentifiedEmailAddress)`, each element is identifiable by its ID. Can be empty, can
     /// contain elements with the same value, but under different IDs.
     pub email_addresses: CollectionOfEmailAddresses,
+
+    /// A collection of [`PersonaDataIdentifiedPostalAddress`]s, which is essentially a tuple of
+    /// `(Uuid, PersonaDataEntryPostalAddress)`, each element is identifiable by its ID. Can be empty, can
+    /// contain elements with the same value, but under different IDs.
+    #[serde(
+        default,
+        skip_serializing_if = "CollectionOfPostalAddresses::is_empty"
+    )]
+    pub postal_addresses: CollectionOfPostalAddresses,
+
+    /// A collection of [`PersonaDataIdentifiedDateOfBirth`]s, which is essentially a tuple of
+    /// `(Uuid, PersonaDataEntryDateOfBirth)`, each element is identifiable by its ID. Can be empty.
+    #[serde(
+        default,
+        skip_serializing_if = "CollectionOfDatesOfBirth::is_empty"
+    )]
+    pub dates_of_birth: CollectionOfDatesOfBirth,
+
+    /// A collection of [`PersonaDataIdentifiedCompany`]s, which is essentially a tuple of
+    /// `(Uuid, PersonaDataEntryCompany)`, each element is identifiable by its ID. Can be empty, can
+    /// contain elements with the same value, but under different IDs.
+    #[serde(default, skip_serializing_if = "CollectionOfCompanies::is_empty")]
+    pub companies: CollectionOfCompanies,
 }
 
 impl PersonaData {
@@ -64,6 +87,25 @@ impl PersonaData {
             name: name.into(),
             phone_numbers,
             email_addresses,
+            postal_addresses: CollectionOfPostalAddresses::default(),
+            dates_of_birth: CollectionOfDatesOfBirth::default(),
+            companies: CollectionOfCompanies::default(),
+        }
+    }
+
+    /// Returns `self` with the postal addresses, dates of birth and companies
+    /// replaced by the ones passed.
+    pub fn with_extended_entries(
+        self,
+        postal_addresses: CollectionOfPostalAddresses,
+        dates_of_birth: CollectionOfDatesOfBirth,
+        companies: CollectionOfCompanies,
+    ) -> Self {
+        Self {
+            postal_addresses,
+            dates_of_birth,
+            companies,
+            ..self
         }
     }
 }
@@ -125,8 +167,52 @@ impl PersonaData {
             .map(|v| format!("email: {v}"))
             .join("\n");
 
+        let postal_addresses = self
+            .postal_addresses
+            .iter()
+            .map(|v| {
+                if include_id {
+                    v.dbg_string()
+                } else {
+                    v.to_string()
+                }
+            })
+            .map(|v| format!("postal address: {v}"))
+            .join("\n");
+
+        let dates_of_birth = self
+            .dates_of_birth
+            .iter()
+            .map(|v| {
+                if include_id {
+                    v.dbg_string()
+                } else {
+                    v.to_string()
+                }
+            })
+            .map(|v| format!("date of birth: {v}"))
+            .join("\n");
+
+        let companies = self
+            .companies
+            .iter()
+            .map(|v| {
+                if include_id {
+                    v.dbg_string()
+                } else {
+                    v.to_string()
+                }
+            })
+            .map(|v| format!("company: {v}"))
+            .join("\n");
+
+        let extended = [postal_addresses, dates_of_birth, companies]
+            .into_iter()
+            .filter(|v| !v.is_empty());
+
         [name.unwrap_or_default(), phones, emails]
             .into_iter()
+            .chain(extended)
             .join("\n")
     }
 }
@@ -247,6 +333,80 @@ mod tests {
         );
     }
 
+    #[test]
+    fn json_roundtrip_extended_entries() {
+        let model = PersonaData::default().with_extended_entries(
+            CollectionOfPostalAddresses::new(
+                PersonaDataIdentifiedPostalAddress::sample_other(),
+            ),
+            CollectionOfDatesOfBirth::new(
+                PersonaDataIdentifiedDateOfBirth::sample(),
+            ),
+            CollectionOfCompanies::new(PersonaDataIdentifiedCompany::sample()),
+        );
+        assert_eq_after_json_roundtrip(
+            &model,
+            r#"
+            {
+                "name": null,
+                "phoneNumbers": [],
+                "emailAddresses": [],
+                "postalAddresses": [
+                    {
+                        "id": "00000000-0000-0000-0000-000000000002",
+                        "value": {
+                            "countryCode": "SE",
+                            "fields": [
+                                {
+                                    "kind": "streetLine1",
+                                    "value": "Drottninggatan 1"
+                                },
+                                {
+                                    "kind": "postalCode",
+                                    "value": "111 51"
+                                },
+                                {
+                                    "kind": "city",
+                                    "value": "Stockholm"
+                                }
+                            ]
+                        }
+                    }
+                ],
+                "datesOfBirth": [
+                    {
+                        "id": "00000000-0000-0000-0000-000000000001",
+                        "value": "1939-05-27"
+                    }
+                ],
+                "companies": [
+                    {
+                        "id": "00000000-0000-0000-0000-000000000001",
+                        "value": {
+                            "name": "Wayne Enterprises",
+                            "title": "CEO"
+                        }
+                    }
+                ]
+            }
+            "#,
+        );
+    }
+
+    #[test]
+    fn display_extended_entries() {
+        let sut = PersonaData::sample().with_extended_entries(
+            CollectionOfPostalAddresses::new(
+                PersonaDataIdentifiedPostalAddress::sample(),
+            ),
+            CollectionOfDatesOfBirth::new(
+                PersonaDataIdentifiedDateOfBirth::sample(),
+            ),
+            CollectionOfCompanies::new(PersonaDataIdentifiedCompany::sample()),
+        );
+        assert_eq!(format!("{}", sut), "name: Bruce Batman Wayne\nphone: +46123456789\nphone: +44987654321\nemail: alan@turing.hero\nemail: satoshi@nakamoto.btc\npostal address: 1007 Mountain Drive, Gotham City, NJ, 07001, US\ndate of birth: 1939-05-27\ncompany: CEO, Wayne Enterprises");
+    }
+
     #[test]
     fn display() {
         assert_eq!(format!("{}", PersonaData::sample()), "name: Bruce Batman Wayne\nphone: +46123456789\nphone: +44987654321\nemail: alan@turing.hero\nemail: satoshi@nakamoto.btc");
@@ -91,6 +91,10 @@ macro_rules! declare_collection_of_identified_entry {
             {
                 Self::from_iter(values.into_iter().map(|v| v.into()))
             }
+
+            pub fn is_empty(&self) -> bool {
+                self.collection.is_empty()
+            }
         }
         impl $struct_name {
             fn display_string(&self) -> String {
@@ -162,6 +166,12 @@ macro_rules! declare_collection_of_identified_entry {
                 );
             }
 
+            #[test]
+            fn is_empty() {
+                assert!(SUT::default().is_empty());
+                assert!(!SUT::sample().is_empty());
+            }
+
             #[test]
             fn hash() {
                 assert_eq!(
@@ -223,3 +223,68 @@ declare_identified_entry!(
     }
     "#
 );
+
+declare_identified_entry!(
+    /// An identifiable Persona postal address. Essentially it is a tuple of a
+    /// [`(PersonaDataEntryPostalAddress, Uuid)`].
+    PostalAddress,
+    "1007 Mountain Drive, Gotham City, NJ, 07001, US",
+    "1007 Mountain Drive, Gotham City, NJ, 07001, US - 00000000-0000-0000-0000-000000000001",
+    r#"
+    {
+        "id": "00000000-0000-0000-0000-000000000001",
+        "value": {
+            "countryCode": "US",
+            "fields": [
+                {
+                    "kind": "streetLine1",
+                    "value": "1007 Mountain Drive"
+                },
+                {
+                    "kind": "city",
+                    "value": "Gotham City"
+                },
+                {
+                    "kind": "state",
+                    "value": "NJ"
+                },
+                {
+                    "kind": "postalCode",
+                    "value": "07001"
+                }
+            ]
+        }
+    }
+    "#
+);
+
+declare_identified_entry!(
+    /// An identifiable Persona date of birth. Essentially it is a tuple of a
+    /// [`(PersonaDataEntryDateOfBirth, Uuid)`].
+    DateOfBirth,
+    "1939-05-27",
+    "1939-05-27 - 00000000-0000-0000-0000-000000000001",
+    r#"
+    {
+        "id": "00000000-0000-0000-0000-000000000001",
+        "value": "1939-05-27"
+    }
+    "#
+);
+
+declare_identified_entry!(
+    /// An identifiable Persona company. Essentially it is a tuple of a
+    /// [`(PersonaDataEntryCompany, Uuid)`].
+    Company,
+    "CEO, Wayne Enterprises",
+    "CEO, Wayne Enterprises - 00000000-0000-0000-0000-000000000001",
+    r#"
+    {
+        "id": "00000000-0000-0000-0000-000000000001",
+        "value": {
+            "name": "Wayne Enterprises",
+            "title": "CEO"
+        }
+    }
+    "#
+);
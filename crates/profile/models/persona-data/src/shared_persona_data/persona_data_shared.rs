@@ -11,5 +11,22 @@ impl PersonaData {
                 self.phone_numbers.ids().into_iter().cloned(),
             ),
         )
+        .with_extended_entries(
+            Self::shared_everything_of(self.postal_addresses.ids()),
+            Self::shared_everything_of(self.dates_of_birth.ids()),
+            Self::shared_everything_of(self.companies.ids()),
+        )
+    }
+
+    /// Kinds added after the original ones are only shared if the Persona
+    /// has any entries of them.
+    fn shared_everything_of<'a>(
+        ids: impl IntoIterator<Item = &'a PersonaDataEntryID>,
+    ) -> Option<SharedToDappWithPersonaIDsOfPersonaDataEntries> {
+        let ids = ids.into_iter().cloned().collect_vec();
+        if ids.is_empty() {
+            return None;
+        }
+        Some(SharedToDappWithPersonaIDsOfPersonaDataEntries::exactly(ids))
     }
 }
@@ -26,6 +26,22 @@ pub struct SharedPersonaData {
     /// IDs of a `PersonaDataEntryPhoneNumber`s the user has shared with some dApp on some network
     /// can be `None`, or can be `Some(<EMPTY>)`.
     pub phone_numbers: Option<SharedToDappWithPersonaIDsOfPersonaDataEntries>,
+
+    /// IDs of a `PersonaDataEntryPostalAddress`es the user has shared with some dApp on some network
+    /// can be `None`, or can be `Some(<EMPTY>)`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub postal_addresses:
+        Option<SharedToDappWithPersonaIDsOfPersonaDataEntries>,
+
+    /// IDs of a `PersonaDataEntryDateOfBirth`s the user has shared with some dApp on some network
+    /// can be `None`, or can be `Some(<EMPTY>)`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dates_of_birth: Option<SharedToDappWithPersonaIDsOfPersonaDataEntries>,
+
+    /// IDs of a `PersonaDataEntryCompany`s the user has shared with some dApp on some network
+    /// can be `None`, or can be `Some(<EMPTY>)`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub companies: Option<SharedToDappWithPersonaIDsOfPersonaDataEntries>,
 }
 
 impl SharedPersonaData {
@@ -35,6 +51,9 @@ impl SharedPersonaData {
 			name: {}
 			email_addresses: {}
 			phone_numbers: {}
+			postal_addresses: {}
+			dates_of_birth: {}
+			companies: {}
 			"#,
             self.name
                 .map(|s| s.to_string())
@@ -47,6 +66,18 @@ impl SharedPersonaData {
                 .clone()
                 .map(|s| s.to_string())
                 .unwrap_or("<NONE>".to_owned()),
+            self.postal_addresses
+                .clone()
+                .map(|s| s.to_string())
+                .unwrap_or("<NONE>".to_owned()),
+            self.dates_of_birth
+                .clone()
+                .map(|s| s.to_string())
+                .unwrap_or("<NONE>".to_owned()),
+            self.companies
+                .clone()
+                .map(|s| s.to_string())
+                .unwrap_or("<NONE>".to_owned()),
         )
     }
 
@@ -63,6 +94,29 @@ impl SharedPersonaData {
             name: name.into(),
             email_addresses: email_addresses.into(),
             phone_numbers: phone_numbers.into(),
+            postal_addresses: None,
+            dates_of_birth: None,
+            companies: None,
+        }
+    }
+
+    /// Returns `self` with the shared postal addresses, dates of birth and
+    /// companies replaced by the ones passed.
+    pub fn with_extended_entries(
+        self,
+        postal_addresses: impl Into<
+            Option<SharedToDappWithPersonaIDsOfPersonaDataEntries>,
+        >,
+        dates_of_birth: impl Into<
+            Option<SharedToDappWithPersonaIDsOfPersonaDataEntries>,
+        >,
+        companies: impl Into<Option<SharedToDappWithPersonaIDsOfPersonaDataEntries>>,
+    ) -> Self {
+        Self {
+            postal_addresses: postal_addresses.into(),
+            dates_of_birth: dates_of_birth.into(),
+            companies: companies.into(),
+            ..self
         }
     }
 }
@@ -154,6 +208,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn json_roundtrip_extended_entries() {
+        let id = IDStepper::<PersonaDataEntryID>::starting_at(0);
+        let model = unsafe {
+            SUT::new(None, None, None).with_extended_entries(
+                SharedToDappWithPersonaIDsOfPersonaDataEntries::new(
+                    RequestedQuantity::exactly(1),
+                    [id.next()],
+                ),
+                SharedToDappWithPersonaIDsOfPersonaDataEntries::new(
+                    RequestedQuantity::exactly(1),
+                    [id.next()],
+                ),
+                None,
+            )
+        };
+        assert_eq_after_json_roundtrip(
+            &model,
+            r#"
+            {
+				"name": null,
+				"emailAddresses": null,
+				"phoneNumbers": null,
+				"postalAddresses": {
+					"request": {
+						"quantifier": "exactly",
+						"quantity": 1
+					},
+					"ids": [
+						"00000000-0000-0000-0000-000000000000"
+					]
+				},
+				"datesOfBirth": {
+					"request": {
+						"quantifier": "exactly",
+						"quantity": 1
+					},
+					"ids": [
+						"00000000-0000-0000-0000-000000000001"
+					]
+				}
+			}
+            "#,
+        );
+    }
+
+    #[test]
+    fn json_without_extended_entries_is_decoded() {
+        let json = r#"
+        {
+            "name": null,
+            "emailAddresses": null,
+            "phoneNumbers": null
+        }
+        "#;
+        let sut = serde_json::from_str::<SUT>(json).unwrap();
+        assert_eq!(sut, SUT::default());
+    }
+
     #[test]
     fn json_roundtrip_sample_other() {
         let model = SharedPersonaData::sample_other();
@@ -1,6 +1,7 @@
 use encryption::{
     EncryptionKey, EncryptionScheme, PasswordBasedKeyDerivationScheme,
-    VersionedEncryption, VersionedPasswordBasedKeyDerivation,
+    VersionOfAlgorithm, VersionedEncryption,
+    VersionedPasswordBasedKeyDerivation,
 };
 use prelude::fixture_profiles;
 
@@ -194,4 +195,21 @@ mod tests {
         test(Profile::sample(), password);
         test(Profile::sample_other(), password);
     }
+
+    #[test]
+    fn encrypting_defaults_to_memory_hard_kdf() {
+        let sut = SUT::encrypting(&Profile::sample(), "babylon", None, None);
+        assert_eq!(
+            sut.key_derivation_scheme.version(),
+            encryption::PasswordBasedKeyDerivationSchemeVersion::Version2
+        );
+    }
+
+    #[test]
+    fn samples_use_legacy_kdf() {
+        assert_eq!(
+            SUT::sample().key_derivation_scheme,
+            PasswordBasedKeyDerivationScheme::version1()
+        );
+    }
 }
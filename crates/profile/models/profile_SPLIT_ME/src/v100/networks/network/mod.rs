@@ -10,6 +10,8 @@ mod profile_network;
 mod resource_preferences;
 mod token_price_service;
 mod token_price_services;
+mod watch_only_account;
+mod watch_only_accounts;
 
 pub use accounts::*;
 pub use address_book::*;
@@ -23,3 +25,5 @@ pub use profile_network::*;
 pub use resource_preferences::*;
 pub use token_price_service::*;
 pub use token_price_services::*;
+pub use watch_only_account::*;
+pub use watch_only_accounts::*;
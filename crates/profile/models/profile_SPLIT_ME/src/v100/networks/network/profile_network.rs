@@ -41,6 +41,11 @@ pub struct ProfileNetwork {
     #[serde(default, skip_serializing_if = "AddressBook::is_empty")]
     pub address_book: AddressBook,
 
+    /// An ordered set of [`WatchOnlyAccounts`] on this network, which are
+    /// accounts the user tracks but does not control.
+    #[serde(default, skip_serializing_if = "WatchOnlyAccounts::is_empty")]
+    pub watch_only_accounts: WatchOnlyAccounts,
+
     /// Ordered token price service endpoints used for failover.
     #[serde(
         default = "TokenPriceServices::default",
@@ -69,6 +74,7 @@ impl ProfileNetwork {
 			authorized_dapps: {}
 			resource_preferences: {:?}
 			address_book: {:?}
+			watch_only_accounts: {:?}
 			token_price_services: {:?}
 			mfa_factor_instances: {:?}
 			"#,
@@ -78,6 +84,7 @@ impl ProfileNetwork {
             self.authorized_dapps,
             self.resource_preferences,
             self.address_book,
+            self.watch_only_accounts,
             self.token_price_services,
             self.mfa_factor_instances,
         )
@@ -159,6 +166,7 @@ impl ProfileNetwork {
             authorized_dapps,
             resource_preferences,
             address_book: AddressBook::new(),
+            watch_only_accounts: WatchOnlyAccounts::new(),
             token_price_services: TokenPriceServices::default(),
             mfa_factor_instances,
         }
@@ -298,6 +306,7 @@ mod tests {
         .unwrap();
 
         assert!(sut.address_book.is_empty());
+        assert!(sut.watch_only_accounts.is_empty());
         assert_eq!(sut.token_price_services, TokenPriceServices::default());
     }
 
@@ -312,6 +321,22 @@ mod tests {
         assert_json_roundtrip(&sut);
     }
 
+    #[test]
+    fn json_roundtrip_with_populated_watch_only_accounts() {
+        let mut sut = SUT::new_empty_on(NetworkID::Mainnet);
+        sut.watch_only_accounts
+            .try_insert_unique(WatchOnlyAccount::sample())
+            .unwrap();
+        assert_json_roundtrip(&sut);
+    }
+
+    #[test]
+    fn empty_watch_only_accounts_are_not_serialized() {
+        let sut = SUT::new_empty_on(NetworkID::Mainnet);
+        let json = serde_json::to_value(&sut).unwrap();
+        assert!(json.get("watch_only_accounts").is_none());
+    }
+
     #[test]
     fn json_roundtrip_with_populated_token_price_services() {
         let mut sut = SUT::new_empty_on(NetworkID::Mainnet);
@@ -8,6 +8,7 @@ pub struct TokenPriceService {
 
 impl TokenPriceService {
     pub const SCOPED_TOKENS_PATH: &str = "/price/tokens";
+    pub const FX_RATES_PATH: &str = "/price/fx-rates";
     pub const PRODUCTION_BASE_URL: &str =
         "https://token-price-service.radixdlt.com";
 
@@ -20,11 +21,21 @@ impl TokenPriceService {
     }
 
     pub fn scoped_tokens_url(&self) -> Result<Url> {
-        self.base_url.join(Self::SCOPED_TOKENS_PATH).map_err(|_| {
-            CommonError::InvalidURL {
+        self.url_with_path(Self::SCOPED_TOKENS_PATH)
+    }
+
+    /// The URL of the USD based FX rates of the service, used to convert
+    /// its USD prices into other fiat currencies.
+    pub fn fx_rates_url(&self) -> Result<Url> {
+        self.url_with_path(Self::FX_RATES_PATH)
+    }
+
+    fn url_with_path(&self, path: &str) -> Result<Url> {
+        self.base_url
+            .join(path)
+            .map_err(|_| CommonError::InvalidURL {
                 bad_value: self.base_url.to_string(),
-            }
-        })
+            })
     }
 }
 
@@ -81,6 +92,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn fx_rates_url_is_derived_from_base_url() {
+        let sut = SUT::sample();
+        assert_eq!(
+            sut.fx_rates_url().unwrap().as_str(),
+            "https://token-price-service.radixdlt.com/price/fx-rates"
+        );
+    }
+
     #[test]
     fn json_roundtrip() {
         let sut = SUT::sample();
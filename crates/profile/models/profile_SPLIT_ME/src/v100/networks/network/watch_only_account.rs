@@ -0,0 +1,111 @@
+use crate::prelude::*;
+
+/// An account the user wants to track - its balances, their worth and its
+/// transaction history - but which is not controlled by this wallet, i.e.
+/// no factor source in the Profile can sign for it.
+///
+/// Since it is not an [`Account`], it is never used for signing, is not
+/// part of Security Shields and is never shared with dApps.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchOnlyAccount {
+    /// The address of the watched account.
+    pub address: AccountAddress,
+
+    /// A name of the watched account chosen by the user.
+    pub display_name: DisplayName,
+
+    /// The visual cue the user picked for the watched account.
+    #[serde(rename = "appearanceID")]
+    pub appearance_id: AppearanceID,
+}
+
+impl WatchOnlyAccount {
+    pub fn new(
+        address: AccountAddress,
+        display_name: DisplayName,
+        appearance_id: AppearanceID,
+    ) -> Self {
+        Self {
+            address,
+            display_name,
+            appearance_id,
+        }
+    }
+}
+
+impl Identifiable for WatchOnlyAccount {
+    type ID = AccountAddress;
+
+    fn id(&self) -> Self::ID {
+        self.address
+    }
+}
+
+impl IsNetworkAware for WatchOnlyAccount {
+    fn network_id(&self) -> NetworkID {
+        self.address.network_id()
+    }
+}
+
+impl HasSampleValues for WatchOnlyAccount {
+    fn sample() -> Self {
+        Self::new(
+            AccountAddress::sample_mainnet_other(),
+            DisplayName::sample(),
+            AppearanceID::sample(),
+        )
+    }
+
+    fn sample_other() -> Self {
+        Self::new(
+            AccountAddress::sample_stokenet_other(),
+            DisplayName::sample_other(),
+            AppearanceID::sample_other(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = WatchOnlyAccount;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn id_is_address() {
+        let sut = SUT::sample();
+        assert_eq!(sut.id(), sut.address);
+    }
+
+    #[test]
+    fn network_id() {
+        assert_eq!(SUT::sample().network_id(), NetworkID::Mainnet);
+        assert_eq!(SUT::sample_other().network_id(), NetworkID::Stokenet);
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = SUT::sample();
+        assert_json_value_eq_after_roundtrip(
+            &sut,
+            serde_json::json!({
+                "address": AccountAddress::sample_mainnet_other(),
+                "displayName": DisplayName::sample(),
+                "appearanceID": AppearanceID::sample(),
+            }),
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use crate::prelude::*;
+
+decl_identified_vec_of!(
+    /// An ordered set of [`WatchOnlyAccount`]s on a network, accounts the
+    /// user tracks but which are not controlled by this wallet.
+    WatchOnlyAccounts,
+    WatchOnlyAccount
+);
+
+impl HasSampleValues for WatchOnlyAccounts {
+    fn sample() -> Self {
+        Self::just(WatchOnlyAccount::sample())
+    }
+
+    fn sample_other() -> Self {
+        Self::just(WatchOnlyAccount::sample_other())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = WatchOnlyAccounts;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn duplicate_addresses_are_rejected() {
+        let mut sut = SUT::sample();
+        let mut duplicate = WatchOnlyAccount::sample();
+        duplicate.display_name = DisplayName::sample_other();
+        assert!(sut.try_insert_unique(duplicate).is_err());
+        assert_eq!(sut.len(), 1);
+    }
+}
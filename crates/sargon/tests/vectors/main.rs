@@ -590,6 +590,127 @@ mod slip10_tests {
     }
 }
 
+/// Vectors in the format of the official SLIP-39 test vectors, `vectors.json`
+/// of `trezor/python-shamir-mnemonic`. `slip39_vectors.json` only contains 11
+/// of its 45 vectors, and should be replaced by the complete file, unmodified.
+mod slip39_tests {
+    use hmac::{Hmac, Mac};
+    use prelude::fixture_vector;
+    use sha2::{Digest, Sha256, Sha512};
+
+    use super::*;
+
+    /// `(description, mnemonics, master secret, xprv)`, an empty master
+    /// secret denoting invalid mnemonics.
+    type Vector = (String, Vec<String>, String, String);
+
+    fn base58_encode(bytes: &[u8]) -> String {
+        const ALPHABET: &[u8] =
+            b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+        let mut digits = Vec::<u8>::new();
+        for byte in bytes {
+            let mut carry = *byte as u32;
+            for digit in digits.iter_mut() {
+                carry += (*digit as u32) << 8;
+                *digit = (carry % 58) as u8;
+                carry /= 58;
+            }
+            while carry > 0 {
+                digits.push((carry % 58) as u8);
+                carry /= 58;
+            }
+        }
+        let leading_zeros = bytes.iter().take_while(|b| **b == 0).count();
+        std::iter::repeat_n('1', leading_zeros)
+            .chain(digits.iter().rev().map(|d| ALPHABET[*d as usize] as char))
+            .collect()
+    }
+
+    /// The BIP32 master private key of `seed`, serialized as an `xprv`.
+    fn bip32_master_xprv(seed: &[u8]) -> String {
+        let mut mac = Hmac::<Sha512>::new_from_slice(b"Bitcoin seed")
+            .expect("HMAC accepts keys of any length");
+        mac.update(seed);
+        let key_and_chain_code = mac.finalize().into_bytes();
+        let (key, chain_code) = key_and_chain_code.split_at(32);
+
+        // Version, followed by zero depth, parent fingerprint and index.
+        let mut xprv = vec![0x04, 0x88, 0xad, 0xe4];
+        xprv.extend([0; 9]);
+        xprv.extend(chain_code);
+        xprv.push(0);
+        xprv.extend(key);
+        let checksum = Sha256::digest(Sha256::digest(&xprv));
+        xprv.extend(&checksum[..4]);
+        base58_encode(&xprv)
+    }
+
+    fn test(vector: &Vector) {
+        let (description, mnemonics, master_secret, xprv) = vector;
+        let recovered = mnemonics
+            .iter()
+            .map(SLIP39Share::from_phrase)
+            .collect::<Result<Vec<_>>>()
+            .and_then(|shares| slip39_combine_shares(&shares, "TREZOR"));
+        if master_secret.is_empty() {
+            assert!(recovered.is_err(), "{}", description);
+        } else {
+            let recovered = recovered.unwrap();
+            assert_eq!(
+                hex_encode(&recovered),
+                *master_secret,
+                "{}",
+                description
+            );
+            assert_eq!(bip32_master_xprv(&recovered), *xprv, "{}", description);
+        }
+    }
+
+    #[test]
+    fn test_vectors() {
+        let vectors = fixture::<Vec<Vector>>(fixture_vector!("slip39_vectors"))
+            .expect("SLIP39 fixture");
+        vectors.iter().for_each(test);
+    }
+
+    #[test]
+    fn to_slip39_shares_roundtrip_through_phrases() {
+        let sut = MnemonicWithPassphrase::with_passphrase(
+            Mnemonic::sample_device(),
+            BIP39Passphrase::new("TREZOR"),
+        );
+        let groups = sut
+            .to_slip39_shares(
+                2,
+                &[SLIP39Group::new(2, 3), SLIP39Group::new(3, 5)],
+            )
+            .unwrap();
+        let shares = groups[0][1..]
+            .iter()
+            .chain(&groups[1][2..])
+            .map(|share| SLIP39Share::from_phrase(share.phrase()))
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(
+            MnemonicWithPassphrase::from_slip39_shares(
+                &shares,
+                sut.passphrase.clone(),
+                BIP39Language::English,
+            ),
+            Ok(sut.clone())
+        );
+        assert_ne!(
+            MnemonicWithPassphrase::from_slip39_shares(
+                &shares,
+                BIP39Passphrase::default(),
+                BIP39Language::English,
+            ),
+            Ok(sut)
+        );
+    }
+}
+
 mod encrypted_profile_tests {
     use std::collections::HashSet;
 
@@ -754,6 +875,295 @@ mod encrypted_profile_tests {
     }
 }
 
+mod password_based_kdf_argon2id_tests {
+    use encryption::{
+        PasswordBasedKeyDerivationScheme,
+        PasswordBasedKeyDerivationSchemeVersion, VersionOfAlgorithm,
+        VersionedPasswordBasedKeyDerivation,
+    };
+    use prelude::fixture_vector;
+
+    use super::*;
+
+    #[derive(Debug, Clone, Deserialize)]
+    struct KdfVector {
+        password: String,
+        #[serde(rename = "keyDerivationScheme")]
+        key_derivation_scheme: PasswordBasedKeyDerivationScheme,
+        key: Exactly32Bytes,
+    }
+
+    impl KdfVector {
+        fn test(&self) {
+            assert_eq!(
+                self.key_derivation_scheme.version(),
+                PasswordBasedKeyDerivationSchemeVersion::Version2
+            );
+            assert_eq!(
+                self.key_derivation_scheme.kdf(self.password.clone()),
+                self.key
+            );
+        }
+    }
+
+    #[derive(Debug, Clone, Deserialize)]
+    struct EncryptedSnapshotWithPassword {
+        password: String,
+        snapshot: EncryptedProfileSnapshot,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Fixture {
+        #[serde(rename = "kdfVectors")]
+        kdf_vectors: Vec<KdfVector>,
+        #[serde(rename = "encryptedSnapshots")]
+        encrypted_snapshots: Vec<EncryptedSnapshotWithPassword>,
+    }
+
+    impl Fixture {
+        fn test(&self) {
+            self.kdf_vectors.iter().for_each(|v| v.test());
+
+            // Same Profile as the (Version1 KDF) sample
+            let expected = EncryptedProfileSnapshot::sample()
+                .decrypt("babylon")
+                .unwrap();
+
+            self.encrypted_snapshots.iter().for_each(|x| {
+                assert_eq!(
+                    x.snapshot.key_derivation_scheme.version(),
+                    PasswordBasedKeyDerivationSchemeVersion::Version2
+                );
+                assert_eq!(
+                    x.snapshot.decrypt(x.password.clone()).unwrap(),
+                    expected
+                );
+                assert_json_roundtrip(&x.snapshot);
+            });
+        }
+    }
+
+    #[test]
+    fn test_vectors() {
+        let fixture =
+            fixture::<Fixture>(fixture_vector!("password_based_kdf_argon2id"))
+                .expect("Argon2id KDF fixture");
+
+        fixture.test();
+    }
+}
+
+/// Regression vectors generated by Sargon itself, pinning the key derivation
+/// scheme documented on `EncryptedMessage`.
+mod encrypted_transaction_message_tests {
+    use prelude::fixture_vector;
+
+    use super::*;
+
+    #[derive(Debug, Clone, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Plaintext {
+        mime_type: String,
+        string: Option<String>,
+        bytes: Option<BagOfBytes>,
+    }
+
+    impl From<Plaintext> for PlaintextMessage {
+        fn from(value: Plaintext) -> Self {
+            let message = match (value.string, value.bytes) {
+                (Some(string), None) => {
+                    MessageContents::StringMessage { string }
+                }
+                (None, Some(bag_of_bytes)) => {
+                    MessageContents::BinaryMessage { bag_of_bytes }
+                }
+                _ => panic!("Plaintext must be either a string or bytes"),
+            };
+            Self {
+                mime_type: value.mime_type,
+                message,
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Decryptor {
+        public_key_fingerprint: Exactly8Bytes,
+        aes_wrapped_key: Exactly24Bytes,
+    }
+
+    #[derive(Debug, Clone, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct DecryptorsOnCurve {
+        curve: SLIP10Curve,
+        dh_ephemeral_private_key: String,
+        dh_ephemeral_public_key: String,
+        decryptors: Vec<Decryptor>,
+    }
+
+    #[derive(Debug, Clone, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Encrypted {
+        encrypted: BagOfBytes,
+        decryptors_by_curve: Vec<DecryptorsOnCurve>,
+    }
+
+    #[derive(Debug, Clone, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Recipient {
+        curve: SLIP10Curve,
+        private_key: String,
+        public_key: String,
+        shared_secret: Exactly32Bytes,
+    }
+
+    #[derive(Debug, Clone, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Vector {
+        description: String,
+        plaintext: Plaintext,
+        encrypted_message: Encrypted,
+        recipients: Vec<Recipient>,
+    }
+
+    fn private_key(curve: SLIP10Curve, hex: &str) -> PrivateKey {
+        match curve {
+            SLIP10Curve::Curve25519 => {
+                Ed25519PrivateKey::from_str(hex).unwrap().into()
+            }
+            SLIP10Curve::Secp256k1 => {
+                Secp256k1PrivateKey::from_str(hex).unwrap().into()
+            }
+        }
+    }
+
+    fn public_key(curve: SLIP10Curve, hex: &str) -> PublicKey {
+        match curve {
+            SLIP10Curve::Curve25519 => {
+                Ed25519PublicKey::from_str(hex).unwrap().into()
+            }
+            SLIP10Curve::Secp256k1 => {
+                Secp256k1PublicKey::from_str(hex).unwrap().into()
+            }
+        }
+    }
+
+    fn shared_secret(
+        private_key: &PrivateKey,
+        other: &PublicKey,
+    ) -> Exactly32Bytes {
+        match (private_key, other) {
+            (PrivateKey::Ed25519(private_key), PublicKey::Ed25519(other)) => {
+                private_key.shared_secret_from_key_agreement(other).unwrap()
+            }
+            (
+                PrivateKey::Secp256k1(private_key),
+                PublicKey::Secp256k1(other),
+            ) => private_key.shared_secret_from_key_agreement(other),
+            _ => panic!("Keys must be on the same curve"),
+        }
+    }
+
+    impl Vector {
+        fn encrypted_message(&self) -> EncryptedMessage {
+            EncryptedMessage {
+                encrypted: self.encrypted_message.encrypted.clone(),
+                decryptors_by_curve: self
+                    .encrypted_message
+                    .decryptors_by_curve
+                    .iter()
+                    .map(|d| {
+                        DecryptorsByCurve::new(
+                            public_key(d.curve, &d.dh_ephemeral_public_key),
+                            d.decryptors.iter().map(|x| {
+                                MessageDecryptor::new(
+                                    PublicKeyFingerprint {
+                                        bytes: x.public_key_fingerprint,
+                                    },
+                                    x.aes_wrapped_key,
+                                )
+                            }),
+                        )
+                    })
+                    .collect(),
+            }
+        }
+
+        fn test(&self) {
+            let message = self.encrypted_message();
+            let expected = PlaintextMessage::from(self.plaintext.clone());
+
+            for recipient in self.recipients.iter() {
+                let recipient_private_key =
+                    private_key(recipient.curve, &recipient.private_key);
+                let recipient_public_key =
+                    public_key(recipient.curve, &recipient.public_key);
+                assert_eq!(
+                    recipient_private_key.public_key(),
+                    recipient_public_key
+                );
+
+                let ephemeral = self
+                    .encrypted_message
+                    .decryptors_by_curve
+                    .iter()
+                    .find(|d| d.curve == recipient.curve)
+                    .unwrap();
+                let ephemeral_private_key = private_key(
+                    ephemeral.curve,
+                    &ephemeral.dh_ephemeral_private_key,
+                );
+                let ephemeral_public_key = public_key(
+                    ephemeral.curve,
+                    &ephemeral.dh_ephemeral_public_key,
+                );
+                assert_eq!(
+                    ephemeral_private_key.public_key(),
+                    ephemeral_public_key
+                );
+
+                // Key agreement is symmetric and matches the vector
+                assert_eq!(
+                    shared_secret(
+                        &recipient_private_key,
+                        &ephemeral_public_key
+                    ),
+                    recipient.shared_secret,
+                    "{}",
+                    self.description
+                );
+                assert_eq!(
+                    shared_secret(
+                        &ephemeral_private_key,
+                        &recipient_public_key
+                    ),
+                    recipient.shared_secret,
+                    "{}",
+                    self.description
+                );
+
+                assert!(message.can_be_decrypted_by(&recipient_public_key));
+                assert_eq!(
+                    message.decrypt(&recipient_private_key),
+                    Ok(expected.clone()),
+                    "{}",
+                    self.description
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_vectors() {
+        let vectors = fixture::<Vec<Vector>>(fixture_vector!(
+            "encrypted_transaction_messages"
+        ))
+        .expect("Encrypted transaction messages fixture");
+        vectors.iter().for_each(|v| v.test());
+    }
+}
+
 #[cfg(test)]
 mod dapp_to_wallet_interaction_tests {
     use super::*;
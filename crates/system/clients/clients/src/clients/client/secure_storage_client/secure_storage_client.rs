@@ -48,6 +48,35 @@ impl SecureStorageClient {
             })
             .await
     }
+
+    pub async fn delete_radix_connect_mobile_session(
+        &self,
+        session_id: String,
+    ) -> Result<()> {
+        self.driver
+            .delete_data_for_key(SecureStorageKey::RadixConnectMobileSession {
+                session_id,
+            })
+            .await
+    }
+
+    /// Loads the ids of all saved Radix Connect Mobile sessions, empty if
+    /// none have been saved.
+    pub async fn load_radix_connect_mobile_session_ids(
+        &self,
+    ) -> Result<Vec<String>> {
+        self.load(SecureStorageKey::RadixConnectMobileSessionIDs)
+            .await
+            .map(|ids| ids.unwrap_or_default())
+    }
+
+    pub async fn save_radix_connect_mobile_session_ids(
+        &self,
+        session_ids: Vec<String>,
+    ) -> Result<()> {
+        self.save(SecureStorageKey::RadixConnectMobileSessionIDs, &session_ids)
+            .await
+    }
 }
 
 impl SecureStorageClient {
@@ -472,4 +501,41 @@ mod tests {
             Err(CommonError::UnableToSaveHostIdToSecureStorage)
         );
     }
+
+    #[actix_rt::test]
+    async fn radix_connect_mobile_session_ids_roundtrip() {
+        let sut = make_sut();
+        assert_eq!(
+            sut.load_radix_connect_mobile_session_ids().await,
+            Ok(Vec::new())
+        );
+
+        let ids = vec!["a".to_owned(), "b".to_owned()];
+        sut.save_radix_connect_mobile_session_ids(ids.clone())
+            .await
+            .unwrap();
+
+        assert_eq!(sut.load_radix_connect_mobile_session_ids().await, Ok(ids));
+    }
+
+    #[actix_rt::test]
+    async fn delete_radix_connect_mobile_session() {
+        let sut = make_sut();
+        let session_id = "a".to_owned();
+        sut.save_radix_connect_mobile_session(
+            session_id.clone(),
+            BagOfBytes::from(vec![0xde, 0xad]),
+        )
+        .await
+        .unwrap();
+
+        sut.delete_radix_connect_mobile_session(session_id.clone())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            sut.load_radix_connect_mobile_session(session_id).await,
+            Ok(None)
+        );
+    }
 }
@@ -0,0 +1,81 @@
+use crate::prelude::*;
+
+/// The FX rates of a token price service, as the amount of each currency one
+/// US dollar is worth.
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub struct FxRatesResponse {
+    pub rates: Vec<FxRate>,
+}
+
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub struct FxRate {
+    pub currency: FiatCurrency,
+    pub rate: f64,
+}
+
+impl FxRatesResponse {
+    /// The amount of `currency` one US dollar is worth, `None` if the rate
+    /// is missing or not positive.
+    pub fn rate_of(&self, currency: &FiatCurrency) -> Option<Decimal192> {
+        self.rates
+            .iter()
+            .find(|fx_rate| &fx_rate.currency == currency)
+            .and_then(|fx_rate| Decimal192::try_from(fx_rate.rate).ok())
+            .filter(|rate| rate.is_positive())
+    }
+}
+
+impl FungiblesPricesClient {
+    /// Fetches the amount of `currency` one US dollar is worth, trying each
+    /// of `token_price_services` in order until one of them has a rate for
+    /// `currency`.
+    pub async fn fetch_remote_fx_rate(
+        &self,
+        currency: &FiatCurrency,
+        token_price_services: impl IntoIterator<Item = TokenPriceService>,
+    ) -> Result<Decimal192> {
+        let mut last_error: Option<CommonError> = None;
+
+        for service in token_price_services {
+            let url = match service.fx_rates_url() {
+                Ok(url) => url,
+                Err(error) => {
+                    last_error = Some(error);
+                    continue;
+                }
+            };
+            match self.fetch_remote_fx_rates_from_url(url).await {
+                Ok(rates) => {
+                    if let Some(rate) = rates.rate_of(currency) {
+                        return Ok(rate);
+                    }
+                    last_error =
+                        Some(CommonError::FiatCurrencyFxRateUnavailable {
+                            currency: currency.code(),
+                        });
+                }
+                Err(error) => {
+                    last_error = Some(error);
+                }
+            }
+        }
+
+        Err(
+            last_error.unwrap_or(CommonError::FiatCurrencyFxRateUnavailable {
+                currency: currency.code(),
+            }),
+        )
+    }
+
+    async fn fetch_remote_fx_rates_from_url(
+        &self,
+        fetch_url: Url,
+    ) -> Result<FxRatesResponse> {
+        let request =
+            NetworkRequest::new_get(fetch_url).with_gateway_api_headers();
+
+        self.http_client
+            .execute_request_with_decoding(request)
+            .await
+    }
+}
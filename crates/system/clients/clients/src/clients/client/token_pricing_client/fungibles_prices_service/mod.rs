@@ -1,8 +1,10 @@
 mod cache;
+mod fx_rates;
 mod remote_fetcher;
 mod token_pricing_client;
 
 #[cfg(test)]
 mod tests;
 
+pub use fx_rates::*;
 pub use token_pricing_client::*;
@@ -1,6 +1,11 @@
 use crate::prelude::*;
 
 impl FungiblesPricesClient {
+    /// The currencies the token price services quote prices in, prices in
+    /// any other currency are converted from US dollars.
+    const NATIVELY_SUPPORTED_CURRENCIES: [FiatCurrency; 2] =
+        [FiatCurrency::USD, FiatCurrency::SEK];
+
     pub async fn fetch_remote_token_prices(
         &self,
         request_body: &FungiblePricesRequest,
@@ -12,6 +17,14 @@ impl FungiblesPricesClient {
         .await
     }
 
+    /// Fetches the prices of `request_body` from the first of
+    /// `token_price_services` which responds.
+    ///
+    /// Prices in currencies the services do not support natively are
+    /// requested in US dollars and converted into the requested currency
+    /// using the FX rate of the same service, falling back to the other
+    /// `token_price_services` in order. Prices which overflow once converted
+    /// are left out, leaving their tokens unpriced.
     pub async fn fetch_remote_token_prices_using_token_price_services(
         &self,
         request_body: &FungiblePricesRequest,
@@ -21,9 +34,10 @@ impl FungiblesPricesClient {
             return Err(CommonError::ExpectedNonEmptyCollection);
         }
 
+        let services = token_price_services.into_iter().collect::<Vec<_>>();
         let mut last_error: Option<CommonError> = None;
 
-        for service in token_price_services {
+        for (index, service) in services.iter().enumerate() {
             let url = match service.scoped_tokens_url() {
                 Ok(url) => url,
                 Err(error) => {
@@ -35,7 +49,35 @@ impl FungiblesPricesClient {
                 .fetch_remote_token_prices_from_url(request_body, url)
                 .await
             {
-                Ok(prices) => return Ok(prices),
+                Ok(prices)
+                    if Self::NATIVELY_SUPPORTED_CURRENCIES
+                        .contains(&request_body.currency) =>
+                {
+                    return Ok(prices);
+                }
+                Ok(usd_prices) => {
+                    let fx_services = std::iter::once(service.clone()).chain(
+                        services
+                            .iter()
+                            .enumerate()
+                            .filter(|(other, _)| *other != index)
+                            .map(|(_, other)| other.clone()),
+                    );
+                    let rate = self
+                        .fetch_remote_fx_rate(
+                            &request_body.currency,
+                            fx_services,
+                        )
+                        .await?;
+                    return Ok(usd_prices
+                        .into_iter()
+                        .filter_map(|(resource_address, usd_price)| {
+                            usd_price
+                                .checked_mul(rate)
+                                .map(|price| (resource_address, price))
+                        })
+                        .collect());
+                }
                 Err(error) => {
                     last_error = Some(error);
                 }
@@ -50,8 +92,21 @@ impl FungiblesPricesClient {
         request_body: &FungiblePricesRequest,
         fetch_url: Url,
     ) -> Result<PerTokenPrices> {
+        // Only send the requested currency if the service supports it,
+        // otherwise request prices in US dollars to be converted.
+        let currency = if Self::NATIVELY_SUPPORTED_CURRENCIES
+            .contains(&request_body.currency)
+        {
+            request_body.currency.clone()
+        } else {
+            FiatCurrency::USD
+        };
+        let request_body = FungiblePricesRequest {
+            currency,
+            ..request_body.clone()
+        };
         let request = NetworkRequest::new_post(fetch_url)
-            .with_serializing_body(request_body.clone())?
+            .with_serializing_body(request_body)?
             .with_gateway_api_headers();
 
         let response: ScopedTokenPricesResponse = self
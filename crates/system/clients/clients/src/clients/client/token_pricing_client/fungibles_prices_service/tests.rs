@@ -62,6 +62,21 @@ fn sample_scoped_response() -> ScopedTokenPricesResponse {
     }
 }
 
+fn sample_fx_rates_response() -> FxRatesResponse {
+    FxRatesResponse {
+        rates: vec![
+            FxRate {
+                currency: FiatCurrency::SEK,
+                rate: 10.0,
+            },
+            FxRate {
+                currency: FiatCurrency::EUR,
+                rate: 0.9,
+            },
+        ],
+    }
+}
+
 fn request_usd_with_unsorted_duplicates() -> FungiblePricesRequest {
     FungiblePricesRequest::new(
         FiatCurrency::USD,
@@ -142,12 +157,177 @@ async fn test_fetch_remote_token_prices_success() {
     assert_eq!(prices.len(), 3);
 }
 
+fn capturing_driver(
+    captured_requests: Arc<std::sync::Mutex<Vec<NetworkRequest>>>,
+) -> Arc<MockNetworkingDriver> {
+    let response = sample_scoped_response();
+    Arc::new(MockNetworkingDriver::with_lazy_responses(
+        move |request, _| {
+            captured_requests.lock().unwrap().push(request.clone());
+            let body = if request.url.path() == TokenPriceService::FX_RATES_PATH
+            {
+                serde_json::to_vec(&sample_fx_rates_response()).unwrap()
+            } else {
+                serde_json::to_vec(&response).unwrap()
+            };
+            NetworkResponse::new(200, body)
+        },
+    ))
+}
+
 #[actix_rt::test]
-async fn test_fetch_remote_token_prices_uses_requested_currency() {
+async fn test_fetch_remote_token_prices_uses_natively_supported_currency() {
     let captured_requests =
         Arc::new(std::sync::Mutex::new(Vec::<NetworkRequest>::new()));
-    let captured_requests_clone = captured_requests.clone();
+    let driver = capturing_driver(captured_requests.clone());
+
+    let http_client = Arc::new(HttpClient::new(driver));
+    let file_system = Arc::new(FileSystemClient::in_memory());
+    let sut = SUT::new(http_client, file_system);
+
+    let request = FungiblePricesRequest::new(
+        FiatCurrency::SEK,
+        vec![addr(
+            "resource_rdx1t45js47zxtau85v0tlyayerzrgfpmguftlfwfr5fxzu42qtu72tnt0",
+        )],
+        vec![],
+    );
+
+    let prices = sut.fetch_remote_token_prices(&request).await.unwrap();
+
+    // Not converted
+    assert_eq!(
+        prices.get(&addr(
+            "resource_rdx1t45js47zxtau85v0tlyayerzrgfpmguftlfwfr5fxzu42qtu72tnt0"
+        )),
+        Some(&Decimal192::from_str("0.5").unwrap())
+    );
+    let captured = captured_requests.lock().unwrap();
+    assert_eq!(captured.len(), 1);
+    let body: Vec<u8> = captured[0].body.to_vec();
+    let decoded: FungiblePricesRequest = serde_json::from_slice(&body).unwrap();
+    assert_eq!(decoded.currency, FiatCurrency::SEK);
+}
+
+#[actix_rt::test]
+async fn test_fetch_remote_token_prices_requests_usd_for_other_currencies() {
+    let captured_requests =
+        Arc::new(std::sync::Mutex::new(Vec::<NetworkRequest>::new()));
+    let driver = capturing_driver(captured_requests.clone());
+
+    let http_client = Arc::new(HttpClient::new(driver));
+    let file_system = Arc::new(FileSystemClient::in_memory());
+    let sut = SUT::new(http_client, file_system);
 
+    let request = FungiblePricesRequest::new(
+        FiatCurrency::EUR,
+        vec![addr(
+            "resource_rdx1t45js47zxtau85v0tlyayerzrgfpmguftlfwfr5fxzu42qtu72tnt0",
+        )],
+        vec![],
+    );
+
+    let _ = sut.fetch_remote_token_prices(&request).await.unwrap();
+
+    let captured = captured_requests.lock().unwrap();
+    assert_eq!(captured.len(), 2);
+    let body: Vec<u8> = captured[0].body.to_vec();
+    let decoded: FungiblePricesRequest = serde_json::from_slice(&body).unwrap();
+    assert_eq!(decoded.currency, FiatCurrency::USD);
+    assert_eq!(
+        captured[1].url.as_str(),
+        "https://token-price-service.radixdlt.com/price/fx-rates"
+    );
+}
+
+#[actix_rt::test]
+async fn test_fetch_remote_token_prices_overflowing_conversion_is_unpriced() {
+    let response = sample_scoped_response();
+    let driver = Arc::new(MockNetworkingDriver::with_lazy_responses(
+        move |request, _| {
+            let body = if request.url.path() == TokenPriceService::FX_RATES_PATH
+            {
+                serde_json::to_vec(&FxRatesResponse {
+                    rates: vec![FxRate {
+                        currency: FiatCurrency::EUR,
+                        rate: 3e39,
+                    }],
+                })
+                .unwrap()
+            } else {
+                serde_json::to_vec(&response).unwrap()
+            };
+            NetworkResponse::new(200, body)
+        },
+    ));
+    let http_client = Arc::new(HttpClient::new(driver));
+    let file_system = Arc::new(FileSystemClient::in_memory());
+    let sut = SUT::new(http_client, file_system);
+
+    let request = FungiblePricesRequest::new(
+        FiatCurrency::EUR,
+        vec![
+            addr(
+                "resource_rdx1t45js47zxtau85v0tlyayerzrgfpmguftlfwfr5fxzu42qtu72tnt0",
+            ),
+            addr(
+                "resource_rdx1t5u04cs3u2yxqkcwku7jdvdvv9cu739jsx0rdwu97682lr0rn92qdh",
+            ),
+        ],
+        vec![],
+    );
+    let prices = sut.fetch_remote_token_prices(&request).await.unwrap();
+
+    // 0.5 USD fits once converted, 1.25 USD overflows
+    assert!(prices.contains_key(&addr(
+        "resource_rdx1t45js47zxtau85v0tlyayerzrgfpmguftlfwfr5fxzu42qtu72tnt0"
+    )));
+    assert!(!prices.contains_key(&addr(
+        "resource_rdx1t5u04cs3u2yxqkcwku7jdvdvv9cu739jsx0rdwu97682lr0rn92qdh"
+    )));
+}
+
+#[actix_rt::test]
+async fn test_fetch_remote_token_prices_converts_usd_prices_using_fx_rate() {
+    let response = sample_scoped_response();
+    let driver = Arc::new(MockNetworkingDriver::with_lazy_responses(
+        move |request, _| {
+            let body = if request.url.path() == TokenPriceService::FX_RATES_PATH
+            {
+                serde_json::to_vec(&sample_fx_rates_response()).unwrap()
+            } else {
+                serde_json::to_vec(&response).unwrap()
+            };
+            NetworkResponse::new(200, body)
+        },
+    ));
+    let http_client = Arc::new(HttpClient::new(driver));
+    let file_system = Arc::new(FileSystemClient::in_memory());
+    let sut = SUT::new(http_client, file_system);
+
+    let request = FungiblePricesRequest::new(
+        FiatCurrency::EUR,
+        vec![addr(
+            "resource_rdx1t45js47zxtau85v0tlyayerzrgfpmguftlfwfr5fxzu42qtu72tnt0",
+        )],
+        vec![],
+    );
+    let prices = sut.fetch_remote_token_prices(&request).await.unwrap();
+
+    // 0.5 USD at 0.9 EUR per USD
+    assert_eq!(
+        prices.get(&addr(
+            "resource_rdx1t45js47zxtau85v0tlyayerzrgfpmguftlfwfr5fxzu42qtu72tnt0"
+        )),
+        Some(&Decimal192::from_str("0.45").unwrap())
+    );
+}
+
+#[actix_rt::test]
+async fn test_fetch_remote_token_prices_fx_rate_falls_back_to_next_service() {
+    let captured_requests =
+        Arc::new(std::sync::Mutex::new(Vec::<NetworkRequest>::new()));
+    let captured_requests_clone = captured_requests.clone();
     let response = sample_scoped_response();
 
     let driver = Arc::new(MockNetworkingDriver::with_lazy_responses(
@@ -156,8 +336,19 @@ async fn test_fetch_remote_token_prices_uses_requested_currency() {
                 .lock()
                 .unwrap()
                 .push(request.clone());
-            let body = serde_json::to_vec(&response).unwrap();
-            NetworkResponse::new(200, body)
+
+            if request.url.path() != TokenPriceService::FX_RATES_PATH {
+                let body = serde_json::to_vec(&response).unwrap();
+                NetworkResponse::new(200, body)
+            } else if request.url.host_str()
+                == Some("token-prices-primary.example")
+            {
+                NetworkResponse::new(500, vec![])
+            } else {
+                let body =
+                    serde_json::to_vec(&sample_fx_rates_response()).unwrap();
+                NetworkResponse::new(200, body)
+            }
         },
     ));
 
@@ -166,20 +357,76 @@ async fn test_fetch_remote_token_prices_uses_requested_currency() {
     let sut = SUT::new(http_client, file_system);
 
     let request = FungiblePricesRequest::new(
-        FiatCurrency::SEK,
+        FiatCurrency::EUR,
         vec![addr(
-            "resource_rdx1t45js47zxtau85v0tlyayerzrgfpmguftlfwfr5fxzu42qtu72tnt0",
+            "resource_rdx1t5u04cs3u2yxqkcwku7jdvdvv9cu739jsx0rdwu97682lr0rn92qdh",
         )],
         vec![],
     );
+    let prices = sut
+        .fetch_remote_token_prices_using_token_price_services(
+            &request,
+            token_price_services_two_endpoints(),
+        )
+        .await
+        .unwrap();
 
-    let _ = sut.fetch_remote_token_prices(&request).await.unwrap();
+    // 1.25 USD at 0.9 EUR per USD
+    assert_eq!(
+        prices.get(&addr(
+            "resource_rdx1t5u04cs3u2yxqkcwku7jdvdvv9cu739jsx0rdwu97682lr0rn92qdh"
+        )),
+        Some(&Decimal192::from_str("1.125").unwrap())
+    );
 
     let captured = captured_requests.lock().unwrap();
-    assert_eq!(captured.len(), 1);
-    let body: Vec<u8> = captured[0].body.to_vec();
-    let decoded: FungiblePricesRequest = serde_json::from_slice(&body).unwrap();
-    assert_eq!(decoded.currency, FiatCurrency::SEK);
+    let urls = captured
+        .iter()
+        .map(|request| request.url.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        urls,
+        vec![
+            "https://token-prices-primary.example/price/tokens".to_owned(),
+            "https://token-prices-primary.example/price/fx-rates".to_owned(),
+            "https://token-prices-secondary.example/price/fx-rates".to_owned(),
+        ]
+    );
+}
+
+#[actix_rt::test]
+async fn test_fetch_remote_token_prices_missing_fx_rate_is_err() {
+    let response = sample_scoped_response();
+    let driver = Arc::new(MockNetworkingDriver::with_lazy_responses(
+        move |request, _| {
+            let body = if request.url.path() == TokenPriceService::FX_RATES_PATH
+            {
+                serde_json::to_vec(&sample_fx_rates_response()).unwrap()
+            } else {
+                serde_json::to_vec(&response).unwrap()
+            };
+            NetworkResponse::new(200, body)
+        },
+    ));
+    let http_client = Arc::new(HttpClient::new(driver));
+    let file_system = Arc::new(FileSystemClient::in_memory());
+    let sut = SUT::new(http_client, file_system);
+
+    let request = FungiblePricesRequest::new(
+        FiatCurrency::JPY,
+        vec![addr(
+            "resource_rdx1t45js47zxtau85v0tlyayerzrgfpmguftlfwfr5fxzu42qtu72tnt0",
+        )],
+        vec![],
+    );
+    let result = sut.fetch_remote_token_prices(&request).await;
+
+    assert_eq!(
+        result,
+        Err(CommonError::FiatCurrencyFxRateUnavailable {
+            currency: "JPY".to_owned()
+        })
+    );
 }
 
 #[actix_rt::test]
@@ -78,6 +78,15 @@ fn make_client_with_responses(
                 let body =
                     serde_json::to_vec(&liquidity_receipts_clone).unwrap();
                 NetworkResponse::new(200, body)
+            } else if request.url.path() == TokenPriceService::FX_RATES_PATH {
+                let body = serde_json::to_vec(&FxRatesResponse {
+                    rates: vec![FxRate {
+                        currency: FiatCurrency::EUR,
+                        rate: 0.9,
+                    }],
+                })
+                .unwrap();
+                NetworkResponse::new(200, body)
             } else if request.url.as_str().contains("token-price-service") {
                 let body = serde_json::to_vec(&TokenPricesResponse {
                     tokens: token_prices_clone.clone(),
@@ -256,7 +265,7 @@ async fn test_fetch_non_fungibles_prices_multiple_nfts() {
 }
 
 #[actix_rt::test]
-async fn test_fetch_non_fungibles_prices_filters_by_currency() {
+async fn test_fetch_non_fungibles_prices_converts_to_requested_currency() {
     // Arrange
     let (xrd, _) = sample_resource_addresses();
     let (nft_resource, _) = sample_nft_resource_addresses();
@@ -276,27 +285,24 @@ async fn test_fetch_non_fungibles_prices_filters_by_currency() {
         )],
     );
 
-    // XRD has both USD and SEK prices
-    let token_prices = vec![
-        sample_token_price(xrd.clone(), 1.0, FiatCurrency::USD),
-        sample_token_price(xrd, 10.0, FiatCurrency::SEK),
-    ];
+    // XRD = $1.0, converted at 0.9 EUR per USD
+    let token_prices = vec![sample_token_price(xrd, 1.0, FiatCurrency::USD)];
 
     let sut = make_client_with_responses(vec![liquidity_receipt], token_prices);
     let addresses = HashSet::from([nft_global_id.clone()]);
 
-    // Act - request SEK prices
+    // Act - request EUR prices
     let result = sut
-        .fetch_nft_fiat_values(0, addresses, FiatCurrency::SEK, false)
+        .fetch_nft_fiat_values(0, addresses, FiatCurrency::EUR, false)
         .await;
 
-    // Assert - should use SEK price: 100 XRD at 10.0 SEK = 1000 SEK
+    // Assert - should use EUR price: 100 XRD at 0.9 EUR = 90 EUR
     assert!(result.is_ok());
     let prices = result.unwrap();
     assert_eq!(prices.len(), 1);
     assert_eq!(
         *prices.get(&nft_global_id).unwrap(),
-        Decimal192::from(1000.0)
+        Decimal192::from(90.0)
     );
 }
 
@@ -1,6 +1,6 @@
 use crate::prelude::*;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct UnsafeStorageClient {
     #[allow(dead_code)]
     driver: Arc<dyn UnsafeStorageDriver>,
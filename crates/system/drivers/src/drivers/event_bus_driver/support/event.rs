@@ -24,6 +24,16 @@ pub enum Event {
 
     /// The Profile was last used on another device, user ought to claim it.
     ProfileUsedOnOtherDevice(DeviceInfo),
+
+    /// A pending transaction was committed successfully, either submitted by
+    /// the wallet or as the finalizing transaction of a Pre-Authorization.
+    TransactionCommitted { intent_hash: TransactionIntentHash },
+
+    /// A pending transaction failed or was rejected by the network.
+    TransactionFailed { intent_hash: TransactionIntentHash },
+
+    /// A pending Pre-Authorization expired before being submitted.
+    PreAuthorizationExpired { subintent_hash: SubintentHash },
 }
 
 impl Event {
@@ -49,6 +59,13 @@ impl HasEventKind for Event {
             }
             Self::ProfileImported { id: _ } => EventKind::ProfileImported,
             Self::ProfileSaved => EventKind::ProfileSaved,
+            Self::TransactionCommitted { .. } => {
+                EventKind::TransactionCommitted
+            }
+            Self::TransactionFailed { .. } => EventKind::TransactionFailed,
+            Self::PreAuthorizationExpired { .. } => {
+                EventKind::PreAuthorizationExpired
+            }
         }
     }
 }
@@ -109,6 +126,24 @@ mod tests {
             },
             EventKind::GatewayChangedCurrent,
         );
+        test(
+            SUT::TransactionCommitted {
+                intent_hash: TransactionIntentHash::sample(),
+            },
+            EventKind::TransactionCommitted,
+        );
+        test(
+            SUT::TransactionFailed {
+                intent_hash: TransactionIntentHash::sample(),
+            },
+            EventKind::TransactionFailed,
+        );
+        test(
+            SUT::PreAuthorizationExpired {
+                subintent_hash: SubintentHash::sample(),
+            },
+            EventKind::PreAuthorizationExpired,
+        );
         let change = EventProfileModified::AccountAdded {
             address: AccountAddress::sample(),
         };
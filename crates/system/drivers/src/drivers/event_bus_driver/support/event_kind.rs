@@ -62,6 +62,24 @@ pub enum EventKind {
 
     /// Security structures have been updated
     SecurityStructuresUpdated,
+
+    /// A pending Pre-Authorization expired before being submitted.
+    PreAuthorizationExpired,
+
+    /// A pending transaction was committed successfully.
+    TransactionCommitted,
+
+    /// A pending transaction failed or was rejected.
+    TransactionFailed,
+
+    /// Profile updated with a new watch-only account.
+    WatchOnlyAccountAdded,
+
+    /// An existing watch-only account has been updated
+    WatchOnlyAccountUpdated,
+
+    /// A watch-only account has been removed from Profile.
+    WatchOnlyAccountRemoved,
 }
 
 impl EventKind {
@@ -162,6 +180,21 @@ impl EventKind {
                 | FactorSourcesUpdated
         )
     }
+
+    /// If hosts should fetch the watch-only account list due to an action
+    /// which triggered the event of this kind to be emitted.
+    pub fn affects_watch_only_accounts(&self) -> bool {
+        use EventKind::*;
+        matches!(
+            *self,
+            Booted
+                | ProfileImported
+                | WatchOnlyAccountAdded
+                | WatchOnlyAccountUpdated
+                | WatchOnlyAccountRemoved
+                | GatewayChangedCurrent
+        )
+    }
 }
 
 impl HasSampleValues for EventKind {
@@ -217,7 +250,13 @@ mod tests {
                 | PersonaAdded
                 | PersonasAdded
                 | PersonasUpdated
-                | PersonaUpdated => {
+                | PersonaUpdated
+                | PreAuthorizationExpired
+                | TransactionCommitted
+                | TransactionFailed
+                | WatchOnlyAccountAdded
+                | WatchOnlyAccountUpdated
+                | WatchOnlyAccountRemoved => {
                     assert!(!affects)
                 }
             })
@@ -248,7 +287,13 @@ mod tests {
                 | AccountAdded
                 | AccountsAdded
                 | AccountUpdated
-                | AccountsUpdated => {
+                | AccountsUpdated
+                | PreAuthorizationExpired
+                | TransactionCommitted
+                | TransactionFailed
+                | WatchOnlyAccountAdded
+                | WatchOnlyAccountUpdated
+                | WatchOnlyAccountRemoved => {
                     assert!(!affects)
                 }
             })
@@ -279,7 +324,13 @@ mod tests {
                 | PersonaAdded
                 | PersonasAdded
                 | PersonasUpdated
-                | PersonaUpdated => assert!(!affects),
+                | PersonaUpdated
+                | PreAuthorizationExpired
+                | TransactionCommitted
+                | TransactionFailed
+                | WatchOnlyAccountAdded
+                | WatchOnlyAccountUpdated
+                | WatchOnlyAccountRemoved => assert!(!affects),
             })
     }
 
@@ -310,7 +361,13 @@ mod tests {
                 | PersonaAdded
                 | PersonasAdded
                 | PersonaUpdated
-                | PersonasUpdated => assert!(!affects),
+                | PersonasUpdated
+                | PreAuthorizationExpired
+                | TransactionCommitted
+                | TransactionFailed
+                | WatchOnlyAccountAdded
+                | WatchOnlyAccountUpdated
+                | WatchOnlyAccountRemoved => assert!(!affects),
             })
     }
 
@@ -339,7 +396,13 @@ mod tests {
                 | PersonaAdded
                 | PersonasAdded
                 | PersonaUpdated
-                | PersonasUpdated => assert!(!affects),
+                | PersonasUpdated
+                | PreAuthorizationExpired
+                | TransactionCommitted
+                | TransactionFailed
+                | WatchOnlyAccountAdded
+                | WatchOnlyAccountUpdated
+                | WatchOnlyAccountRemoved => assert!(!affects),
             })
     }
 
@@ -367,7 +430,48 @@ mod tests {
                 | PersonaAdded
                 | PersonasAdded
                 | PersonaUpdated
-                | PersonasUpdated => assert!(!affects),
+                | PersonasUpdated
+                | PreAuthorizationExpired
+                | TransactionCommitted
+                | TransactionFailed
+                | WatchOnlyAccountAdded
+                | WatchOnlyAccountUpdated
+                | WatchOnlyAccountRemoved => assert!(!affects),
+            })
+    }
+
+    #[test]
+    fn event_kind_affects_watch_only_accounts() {
+        use EventKind::*;
+        SUT::all()
+            .into_iter()
+            .map(|sut| (sut, sut.affects_watch_only_accounts()))
+            .for_each(|(sut, affects)| match sut {
+                Booted
+                | ProfileImported
+                | WatchOnlyAccountAdded
+                | WatchOnlyAccountUpdated
+                | WatchOnlyAccountRemoved
+                | GatewayChangedCurrent => assert!(affects),
+                ProfileUsedOnOtherDevice
+                | ProfileSaved
+                | SecurityStructureAdded
+                | SecurityStructuresUpdated
+                | FactorSourceAdded
+                | FactorSourcesAdded
+                | FactorSourceUpdated
+                | FactorSourcesUpdated
+                | AccountAdded
+                | AccountsAdded
+                | AccountUpdated
+                | AccountsUpdated
+                | PersonaAdded
+                | PersonasAdded
+                | PersonaUpdated
+                | PersonasUpdated
+                | PreAuthorizationExpired
+                | TransactionCommitted
+                | TransactionFailed => assert!(!affects),
             })
     }
 }
@@ -44,6 +44,15 @@ pub enum EventProfileModified {
 
     /// Security structures have been updated
     SecurityStructuresUpdated { ids: Vec<SecurityStructureID> },
+
+    /// A new watch-only account with `address` was inserted into the active profile
+    WatchOnlyAccountAdded { address: AccountAddress },
+
+    /// An existing watch-only account has been updated
+    WatchOnlyAccountUpdated { address: AccountAddress },
+
+    /// The watch-only account with `address` was removed from the active profile
+    WatchOnlyAccountRemoved { address: AccountAddress },
 }
 
 impl HasEventKind for EventProfileModified {
@@ -77,6 +86,15 @@ impl HasEventKind for EventProfileModified {
             Self::SecurityStructuresUpdated { ids: _ } => {
                 EventKind::SecurityStructuresUpdated
             }
+            Self::WatchOnlyAccountAdded { address: _ } => {
+                EventKind::WatchOnlyAccountAdded
+            }
+            Self::WatchOnlyAccountUpdated { address: _ } => {
+                EventKind::WatchOnlyAccountUpdated
+            }
+            Self::WatchOnlyAccountRemoved { address: _ } => {
+                EventKind::WatchOnlyAccountRemoved
+            }
         }
     }
 }
@@ -259,5 +277,26 @@ mod tests {
             },
             EventKind::SecurityStructuresUpdated,
         );
+
+        test(
+            SUT::WatchOnlyAccountAdded {
+                address: AccountAddress::sample(),
+            },
+            EventKind::WatchOnlyAccountAdded,
+        );
+
+        test(
+            SUT::WatchOnlyAccountUpdated {
+                address: AccountAddress::sample(),
+            },
+            EventKind::WatchOnlyAccountUpdated,
+        );
+
+        test(
+            SUT::WatchOnlyAccountRemoved {
+                address: AccountAddress::sample(),
+            },
+            EventKind::WatchOnlyAccountRemoved,
+        );
     }
 }
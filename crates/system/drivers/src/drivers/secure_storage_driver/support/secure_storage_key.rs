@@ -10,6 +10,9 @@ pub enum SecureStorageKey {
     RadixConnectMobileSession {
         session_id: String,
     },
+    /// The ids of all saved Radix Connect Mobile sessions, allowing them to
+    /// be listed.
+    RadixConnectMobileSessionIDs,
     ProfileSnapshot {
         // Note:
         // `profile_id` is only meant to be used by the iOS Host for backward compatibility.
@@ -39,6 +42,10 @@ impl PartialEq<SecureStorageKey> for SecureStorageKey {
                 SecureStorageKey::RadixConnectMobileSession { session_id: a },
                 SecureStorageKey::RadixConnectMobileSession { session_id: b },
             ) => a == b,
+            (
+                SecureStorageKey::RadixConnectMobileSessionIDs,
+                SecureStorageKey::RadixConnectMobileSessionIDs,
+            ) => true,
             _ => false,
         }
     }
@@ -60,6 +67,9 @@ impl Hash for SecureStorageKey {
                 "radix_connect_mobile_session".hash(state);
                 session_id.hash(state);
             }
+            SecureStorageKey::RadixConnectMobileSessionIDs => {
+                "radix_connect_mobile_session_ids".hash(state);
+            }
             // Note: `profile_id` is not used for computing the hash, as it is only forwarded as additional payload to the iOS Host.
             SecureStorageKey::ProfileSnapshot { .. } => {
                 "profile_snapshot".hash(state);
@@ -79,6 +89,8 @@ impl SecureStorageKey {
                 } => format!("device_factor_source_{}", factor_source_id),
                 SecureStorageKey::RadixConnectMobileSession { session_id } =>
                     format!("radix_connect_mobile_session_{}", session_id),
+                SecureStorageKey::RadixConnectMobileSessionIDs =>
+                    "radix_connect_mobile_session_ids".to_owned(),
                 SecureStorageKey::ProfileSnapshot { .. } =>
                     "profile_snapshot".to_owned(),
             }
@@ -126,5 +138,9 @@ mod tests {
             SecureStorageKey::load_profile_snapshot().identifier(),
             "secure_storage_key_profile_snapshot"
         );
+        assert_eq!(
+            SecureStorageKey::RadixConnectMobileSessionIDs.identifier(),
+            "secure_storage_key_radix_connect_mobile_session_ids"
+        );
     }
 }
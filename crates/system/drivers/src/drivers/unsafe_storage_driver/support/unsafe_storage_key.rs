@@ -3,6 +3,8 @@ use crate::prelude::*;
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum UnsafeStorageKey {
     FactorSourceUserHasWrittenDown,
+    PendingTransactions,
+    ApplyShieldTransactionsQueue,
 }
 
 impl UnsafeStorageKey {
@@ -12,6 +14,10 @@ impl UnsafeStorageKey {
             match self {
                 UnsafeStorageKey::FactorSourceUserHasWrittenDown =>
                     "factor_source_user_has_written_down".to_owned(),
+                UnsafeStorageKey::PendingTransactions =>
+                    "pending_transactions".to_owned(),
+                UnsafeStorageKey::ApplyShieldTransactionsQueue =>
+                    "apply_shield_transactions_queue".to_owned(),
             }
         )
     }
@@ -27,5 +33,13 @@ mod tests {
             UnsafeStorageKey::FactorSourceUserHasWrittenDown.identifier(),
             "unsafe_storage_key_factor_source_user_has_written_down"
         );
+        assert_eq!(
+            UnsafeStorageKey::PendingTransactions.identifier(),
+            "unsafe_storage_key_pending_transactions"
+        );
+        assert_eq!(
+            UnsafeStorageKey::ApplyShieldTransactionsQueue.identifier(),
+            "unsafe_storage_key_apply_shield_transactions_queue"
+        );
     }
 }
@@ -0,0 +1,5 @@
+mod sargon_os_account_recovery_scan;
+mod support;
+
+pub use sargon_os_account_recovery_scan::*;
+pub use support::*;
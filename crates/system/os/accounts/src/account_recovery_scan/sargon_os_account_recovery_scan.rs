@@ -0,0 +1,468 @@
+use crate::prelude::*;
+
+#[async_trait::async_trait]
+pub trait OsAccountRecoveryScan {
+    async fn account_recovery_scan(
+        &self,
+        factor_source: FactorSource,
+        network_id: NetworkID,
+        gap_limit: u32,
+    ) -> Result<AccountRecoveryScanOutcome>;
+}
+
+// ==================
+// Account Recovery Scan (Public)
+// ==================
+#[async_trait::async_trait]
+impl OsAccountRecoveryScan for SargonOS {
+    /// Scans the ledger for accounts controlled by `factor_source` on
+    /// `network_id`.
+    ///
+    /// Account VECIs are derived with the `KeysCollector` in batches of
+    /// `gap_limit` consecutive indices, starting at index `0`, and the Gateway
+    /// is queried for which of the derived addresses are active on ledger.
+    /// The scan stops as soon as a whole batch contains no active account,
+    /// i.e. after `gap_limit` consecutive unused addresses.
+    ///
+    /// Active accounts are then split into the ones still controlled by the
+    /// derived factor instance and the ones controlled by an access
+    /// controller. Accounts deleted on ledger and accounts already present in
+    /// Profile are not part of the outcome, so that the recovered accounts can
+    /// be added to Profile as is.
+    ///
+    /// See [`ACCOUNT_RECOVERY_SCAN_DEFAULT_GAP_LIMIT`] for a sensible default
+    /// `gap_limit`.
+    async fn account_recovery_scan(
+        &self,
+        factor_source: FactorSource,
+        network_id: NetworkID,
+        gap_limit: u32,
+    ) -> Result<AccountRecoveryScanOutcome> {
+        let factor_source_id = factor_source.id_from_hash();
+        let gateway_client = self.gateway_client_with(network_id);
+
+        let accounts_in_profile = self
+            .profile()
+            .ok()
+            .and_then(|profile| profile.networks.get_id(network_id).cloned())
+            .map(|network| {
+                network
+                    .accounts
+                    .iter()
+                    .map(|a| a.address)
+                    .collect::<IndexSet<_>>()
+            })
+            .unwrap_or_default();
+
+        let mut unsecurified_accounts = Vec::<Account>::new();
+        let mut securified_accounts =
+            Vec::<AccountRecoveredBehindAccessController>::new();
+
+        let mut start_index = 0u32;
+        while gap_limit > 0 {
+            let accounts = self
+                .derive_accounts_for_recovery_scan(
+                    &factor_source,
+                    network_id,
+                    start_index..start_index.saturating_add(gap_limit),
+                )
+                .await?;
+
+            let is_active_per_address = gateway_client
+                .check_accounts_are_active(accounts.iter().map(|a| a.address))
+                .await?;
+
+            let active_accounts = accounts
+                .into_iter()
+                .filter(|a| {
+                    is_active_per_address
+                        .get(&a.address)
+                        .copied()
+                        .unwrap_or(false)
+                })
+                .collect_vec();
+
+            if active_accounts.is_empty() {
+                break;
+            }
+
+            let badge_owner_per_account = gateway_client
+                .fetch_entities_badge_owners(
+                    network_id,
+                    active_accounts
+                        .iter()
+                        .map(|a| AddressOfAccountOrPersona::from(a.address)),
+                )
+                .await?;
+
+            for account in active_accounts {
+                if accounts_in_profile.contains(&account.address) {
+                    continue;
+                }
+
+                let badge_owner = badge_owner_per_account
+                    .get(&AddressOfAccountOrPersona::from(account.address))
+                    .cloned()
+                    .flatten();
+
+                match badge_owner {
+                    Some(Address::AccessController(
+                        access_controller_address,
+                    )) => {
+                        securified_accounts.push(
+                            AccountRecoveredBehindAccessController::new(
+                                account,
+                                access_controller_address,
+                            ),
+                        );
+                    }
+                    Some(Address::Account(owner))
+                        if owner == account.address =>
+                    {
+                        // The account holds its own owner badge, it is deleted.
+                        continue;
+                    }
+                    _ => unsecurified_accounts.push(account),
+                }
+            }
+
+            start_index = start_index.saturating_add(gap_limit);
+        }
+
+        Ok(AccountRecoveryScanOutcome::new(
+            factor_source_id,
+            unsecurified_accounts,
+            securified_accounts,
+        ))
+    }
+}
+
+// ==================
+// Account Recovery Scan (Internal)
+// ==================
+impl SargonOS {
+    /// Derives the account VECIs of `factor_source` at each index in
+    /// `indices` (in the unsecurified local key space) and returns the
+    /// corresponding unsaved accounts, ordered by index.
+    async fn derive_accounts_for_recovery_scan(
+        &self,
+        factor_source: &FactorSource,
+        network_id: NetworkID,
+        indices: std::ops::Range<u32>,
+    ) -> Result<Vec<Account>> {
+        let factor_source_id = factor_source.id_from_hash();
+
+        let derivation_paths = indices
+            .map(|index| {
+                UnsecurifiedHardened::from_local_key_space(index).map(|index| {
+                    DerivationPath::from(AccountPath::new(
+                        network_id,
+                        CAP26KeyKind::TransactionSigning,
+                        index,
+                    ))
+                })
+            })
+            .collect::<Result<IndexSet<_>>>()?;
+
+        let collector = KeysCollector::new(
+            vec![factor_source.clone()],
+            IndexMap::just((factor_source_id, derivation_paths.clone())),
+            self.keys_derivation_interactor(),
+            DerivationPurpose::AccountRecovery,
+        )?;
+
+        let derived_per_path = collector
+            .collect_keys()
+            .await?
+            .factors_by_source
+            .get(&factor_source_id)
+            .cloned()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|instance| (instance.derivation_path(), instance))
+            .collect::<HashMap<_, _>>();
+
+        derivation_paths
+            .into_iter()
+            .filter_map(|path| derived_per_path.get(&path).cloned())
+            .map(|instance| {
+                HDFactorInstanceAccountCreation::new(instance).map(|veci| {
+                    Account::with_veci_and_name(veci, DisplayName::default())
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_rt::time::timeout;
+    use radix_common::prelude::ACCOUNT_OWNER_BADGE as SCRYPTO_ACCOUNT_OWNER_BADGE;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = SargonOS;
+
+    #[actix_rt::test]
+    async fn scan_finds_unsecurified_and_securified_accounts() {
+        // ARRANGE
+        let gap_limit = 2;
+        let (sut, factor_source, expected_addresses) = boot(4, |addresses| {
+            vec![
+                // First batch, index 0 is unsecurified, index 1 securified
+                mock_entity_details_response(
+                    addresses[0..2].to_vec(),
+                    vec![addresses[0], addresses[1]],
+                ),
+                mock_location_response(vec![(
+                    addresses[1],
+                    AccessControllerAddress::sample_stokenet().into(),
+                )]),
+                // Second batch, index 3 is deleted
+                mock_entity_details_response(
+                    addresses[2..4].to_vec(),
+                    vec![addresses[3]],
+                ),
+                mock_location_response(vec![(
+                    addresses[3],
+                    addresses[3].into(),
+                )]),
+                // Third batch, none active
+                mock_entity_details_response(vec![], vec![]),
+            ]
+        })
+        .await;
+
+        // ACT
+        let outcome = sut
+            .account_recovery_scan(
+                factor_source.clone(),
+                NetworkID::Stokenet,
+                gap_limit,
+            )
+            .await
+            .unwrap();
+
+        // ASSERT
+        assert_eq!(outcome.factor_source_id, factor_source.id_from_hash());
+        assert_eq!(
+            outcome
+                .unsecurified_accounts
+                .iter()
+                .map(|a| a.address)
+                .collect_vec(),
+            vec![expected_addresses[0]]
+        );
+        assert_eq!(
+            outcome
+                .securified_accounts
+                .iter()
+                .map(|a| (a.account.address, a.access_controller_address))
+                .collect_vec(),
+            vec![(
+                expected_addresses[1],
+                AccessControllerAddress::sample_stokenet()
+            )]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn scan_without_active_accounts_is_empty() {
+        // ARRANGE
+        let (sut, factor_source, _) = boot(2, |addresses| {
+            vec![mock_entity_details_response(addresses, vec![])]
+        })
+        .await;
+
+        // ACT
+        let outcome = sut
+            .account_recovery_scan(factor_source, NetworkID::Stokenet, 2)
+            .await
+            .unwrap();
+
+        // ASSERT
+        assert!(outcome.is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn scan_skips_accounts_already_in_profile() {
+        // ARRANGE
+        let (sut, factor_source, addresses) = boot(2, |addresses| {
+            vec![
+                mock_entity_details_response(
+                    addresses.clone(),
+                    addresses.clone(),
+                ),
+                mock_location_response(vec![]),
+                mock_entity_details_response(vec![], vec![]),
+            ]
+        })
+        .await;
+        let accounts = sut
+            .derive_accounts_for_recovery_scan(
+                &factor_source,
+                NetworkID::Stokenet,
+                0..1,
+            )
+            .await
+            .unwrap();
+        sut.add_accounts(Accounts::from_iter(accounts))
+            .await
+            .unwrap();
+
+        // ACT
+        let outcome = sut
+            .account_recovery_scan(factor_source, NetworkID::Stokenet, 2)
+            .await
+            .unwrap();
+
+        // ASSERT
+        assert_eq!(
+            outcome
+                .all_accounts()
+                .iter()
+                .map(|a| a.address)
+                .collect_vec(),
+            vec![addresses[1]]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn scan_with_zero_gap_limit_is_empty() {
+        // ARRANGE
+        let (sut, factor_source, _) = boot(0, |_| vec![]).await;
+
+        // ACT
+        let outcome = sut
+            .account_recovery_scan(factor_source, NetworkID::Stokenet, 0)
+            .await
+            .unwrap();
+
+        // ASSERT
+        assert!(outcome.is_empty());
+    }
+
+    /// Boots the OS with the responses built by `responses` from the
+    /// Stokenet account addresses of a sample device factor source at the
+    /// first `address_count` indices. Returns the OS, the factor source and
+    /// those addresses.
+    async fn boot(
+        address_count: u32,
+        responses: impl FnOnce(
+            Vec<AccountAddress>,
+        ) -> Vec<MockNetworkingDriverResponse>,
+    ) -> (Arc<SUT>, FactorSource, Vec<AccountAddress>) {
+        let mnemonic_with_passphrase = MnemonicWithPassphrase::sample();
+        let factor_source_id =
+            FactorSourceIDFromHash::from_mnemonic_with_passphrase(
+                FactorSourceKind::Device,
+                &mnemonic_with_passphrase,
+            );
+        let factor_source =
+            FactorSource::from(DeviceFactorSource::babylon_with_hint(
+                factor_source_id,
+                DeviceFactorSourceHint::sample(),
+            ));
+
+        let addresses = mnemonic_with_passphrase
+            .derive_public_keys((0..address_count).map(|index| {
+                DerivationPath::from(AccountPath::new(
+                    NetworkID::Stokenet,
+                    CAP26KeyKind::TransactionSigning,
+                    UnsecurifiedHardened::from_local_key_space(index).unwrap(),
+                ))
+            }))
+            .into_iter()
+            .map(|key| {
+                AccountAddress::new_from_public_key(
+                    key.public_key,
+                    NetworkID::Stokenet,
+                )
+            })
+            .collect_vec();
+
+        let mock_driver = MockNetworkingDriver::new_with_responses(responses(
+            addresses.clone(),
+        ));
+        let sut = timeout(
+            SARGON_OS_TEST_MAX_ASYNC_DURATION,
+            SUT::boot_test_with_networking_driver(Arc::new(mock_driver)),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        sut.secure_storage
+            .save_mnemonic_with_passphrase(
+                &mnemonic_with_passphrase,
+                &factor_source_id,
+            )
+            .await
+            .unwrap();
+
+        (sut, factor_source, addresses)
+    }
+
+    /// Responds with an entity details item for each of `addresses`, where
+    /// only the `active` ones hold a resource.
+    fn mock_entity_details_response(
+        addresses: Vec<AccountAddress>,
+        active: Vec<AccountAddress>,
+    ) -> MockNetworkingDriverResponse {
+        let items = addresses
+            .into_iter()
+            .map(|address| {
+                let fungibles = active.contains(&address).then(|| {
+                    FungibleResourcesCollection::new(
+                        None,
+                        None,
+                        vec![FungibleResourcesCollectionItem::sample()],
+                    )
+                });
+                StateEntityDetailsResponseItem::new(
+                    address.into(),
+                    fungibles,
+                    None,
+                    EntityMetadataCollection::empty(),
+                    None,
+                )
+            })
+            .collect_vec();
+
+        MockNetworkingDriverResponse::new_success(
+            StateEntityDetailsResponse::new(
+                LedgerState::sample_stokenet(),
+                items,
+            ),
+        )
+    }
+
+    /// Responds with the location of the owner badge of each account.
+    fn mock_location_response(
+        owners: Vec<(AccountAddress, Address)>,
+    ) -> MockNetworkingDriverResponse {
+        MockNetworkingDriverResponse::new_success(
+            StateNonFungibleLocationResponse {
+                ledger_state: LedgerState::sample_stokenet(),
+                resource_address: ResourceAddress::new_from_node_id(
+                    SCRYPTO_ACCOUNT_OWNER_BADGE,
+                    NetworkID::Stokenet,
+                )
+                .unwrap(),
+                non_fungible_ids: owners
+                    .into_iter()
+                    .map(|(account, owner)| {
+                        StateNonFungibleLocationResponseItem {
+                            non_fungible_id: NonFungibleLocalId::from(account),
+                            is_burned: false,
+                            last_updated_at_state_version: 0,
+                            owning_vault_address: VaultAddress::sample_stokenet(
+                            ),
+                            owning_vault_parent_ancestor_address: Some(owner),
+                            owning_vault_global_ancestor_address: Some(owner),
+                        }
+                    })
+                    .collect_vec(),
+            },
+        )
+    }
+}
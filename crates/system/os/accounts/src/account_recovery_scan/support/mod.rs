@@ -0,0 +1,3 @@
+mod outcome;
+
+pub use outcome::*;
@@ -0,0 +1,129 @@
+use crate::prelude::*;
+
+/// An account found during an account recovery scan, which is active on ledger
+/// but has been securified, i.e. its owner badge is held by an access controller.
+///
+/// The `account` is in its unsecurified form, controlled by the derived
+/// factor instance, since the security structure of the access controller
+/// cannot be recovered from the factor source alone.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct AccountRecoveredBehindAccessController {
+    pub account: Account,
+    pub access_controller_address: AccessControllerAddress,
+}
+
+impl AccountRecoveredBehindAccessController {
+    pub fn new(
+        account: Account,
+        access_controller_address: AccessControllerAddress,
+    ) -> Self {
+        Self {
+            account,
+            access_controller_address,
+        }
+    }
+}
+
+impl HasSampleValues for AccountRecoveredBehindAccessController {
+    fn sample() -> Self {
+        Self::new(Account::sample(), AccessControllerAddress::sample())
+    }
+
+    fn sample_other() -> Self {
+        Self::new(
+            Account::sample_other(),
+            AccessControllerAddress::sample_other(),
+        )
+    }
+}
+
+/// The outcome of an account recovery scan of a factor source.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct AccountRecoveryScanOutcome {
+    /// The factor source which was scanned.
+    pub factor_source_id: FactorSourceIDFromHash,
+
+    /// Accounts active on ledger which are still controlled by the
+    /// derived factor instance.
+    pub unsecurified_accounts: Accounts,
+
+    /// Accounts active on ledger which are controlled by an access controller.
+    pub securified_accounts: Vec<AccountRecoveredBehindAccessController>,
+}
+
+impl AccountRecoveryScanOutcome {
+    pub fn new(
+        factor_source_id: FactorSourceIDFromHash,
+        unsecurified_accounts: impl IntoIterator<Item = Account>,
+        securified_accounts: impl IntoIterator<
+            Item = AccountRecoveredBehindAccessController,
+        >,
+    ) -> Self {
+        Self {
+            factor_source_id,
+            unsecurified_accounts: unsecurified_accounts.into_iter().collect(),
+            securified_accounts: securified_accounts.into_iter().collect(),
+        }
+    }
+
+    /// All recovered accounts, unsecurified ones first.
+    pub fn all_accounts(&self) -> Accounts {
+        self.unsecurified_accounts
+            .iter()
+            .chain(self.securified_accounts.iter().map(|s| s.account.clone()))
+            .collect()
+    }
+
+    /// Whether the scan found no accounts at all.
+    pub fn is_empty(&self) -> bool {
+        self.unsecurified_accounts.is_empty()
+            && self.securified_accounts.is_empty()
+    }
+}
+
+impl HasSampleValues for AccountRecoveryScanOutcome {
+    fn sample() -> Self {
+        Self::new(
+            FactorSourceIDFromHash::sample(),
+            [Account::sample()],
+            [AccountRecoveredBehindAccessController::sample_other()],
+        )
+    }
+
+    fn sample_other() -> Self {
+        Self::new(FactorSourceIDFromHash::sample_other(), [], [])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = AccountRecoveryScanOutcome;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn all_accounts() {
+        assert_eq!(
+            SUT::sample().all_accounts(),
+            Accounts::from_iter([Account::sample(), Account::sample_other()])
+        );
+    }
+
+    #[test]
+    fn is_empty() {
+        assert!(!SUT::sample().is_empty());
+        assert!(SUT::sample_other().is_empty());
+    }
+}
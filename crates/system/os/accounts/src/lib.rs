@@ -1,15 +1,23 @@
+mod account_recovery_scan;
 mod delete_account;
+mod sargon_os_portfolio_valuation;
+mod sargon_os_staking_overview;
 mod sargon_os_sync_entities;
 mod support;
 
 pub mod prelude {
+    pub use super::account_recovery_scan::*;
     pub use super::delete_account::*;
+    pub use super::sargon_os_portfolio_valuation::*;
+    pub use super::sargon_os_staking_overview::*;
     pub use super::sargon_os_sync_entities::*;
     pub use super::support::*;
 
     pub(crate) use addresses::prelude::*;
     pub(crate) use error::prelude::*;
     pub(crate) use gateway_client_and_api::prelude::*;
+    pub(crate) use key_derivation_traits::prelude::*;
+    pub(crate) use keys_collector::prelude::*;
     pub(crate) use manifests::prelude::*;
     pub use prelude::prelude::*;
     pub(crate) use profile_logic::prelude::*;
@@ -0,0 +1,461 @@
+use crate::prelude::*;
+
+#[async_trait::async_trait]
+pub trait OsPortfolioValuation {
+    async fn portfolio_valuation(
+        &self,
+        force_fetch: bool,
+    ) -> Result<PortfolioValuation>;
+}
+
+#[async_trait::async_trait]
+impl OsPortfolioValuation for SargonOS {
+    /// Values the assets held by all non-hidden accounts and all watch-only
+    /// accounts on the current network, per account and in total, in the
+    /// `FiatCurrency` of the display settings of the Profile.
+    ///
+    /// Liquid Stake Units and pool units are resolved - through the Gateway -
+    /// into the resources they can be redeemed for, and stake claim NFTs into
+    /// the XRD they can be claimed for, which are then priced together with
+    /// all other fungibles. Other non fungibles are priced using the non
+    /// fungible prices service.
+    ///
+    /// Assets which could not be priced are not part of the worth, and are
+    /// listed in `unpriced_resources`. If prices can not be converted into
+    /// the currency at all - e.g. if it is `Unknown` - every asset is
+    /// unpriced.
+    async fn portfolio_valuation(
+        &self,
+        force_fetch: bool,
+    ) -> Result<PortfolioValuation> {
+        let currency = self
+            .profile()?
+            .app_preferences
+            .display
+            .fiat_currency_price_target;
+        let account_addresses = self
+            .accounts_on_current_network()?
+            .iter()
+            .map(|account| account.address)
+            .chain(
+                self.watch_only_accounts_on_current_network()?
+                    .iter()
+                    .map(|watch_only_account| watch_only_account.address),
+            )
+            .collect_vec();
+        if account_addresses.is_empty() {
+            return Ok(PortfolioValuation::new(
+                currency,
+                [],
+                &HashMap::new(),
+                &HashMap::new(),
+            ));
+        }
+
+        let holdings = self
+            .gateway_client()?
+            .fetch_accounts_holdings(account_addresses)
+            .await?;
+
+        let tokens = holdings
+            .accounts
+            .iter()
+            .flat_map(|account| {
+                account
+                    .fungibles
+                    .iter()
+                    .filter_map(|fungible| fungible.underlying.as_ref())
+                    .flatten()
+                    .chain(
+                        account
+                            .non_fungibles
+                            .iter()
+                            .filter_map(|nft| nft.claimable.as_ref()),
+                    )
+            })
+            .map(|underlying| underlying.resource_address)
+            .collect::<HashSet<_>>();
+        let fungible_prices = if tokens.is_empty() {
+            HashMap::new()
+        } else {
+            self.fetch_fungible_fiat_values(
+                tokens,
+                HashSet::new(),
+                currency.clone(),
+                force_fetch,
+            )
+            .await
+            .or_else(unpriced_if_fx_rate_unavailable)?
+        };
+
+        let nft_ids = holdings
+            .accounts
+            .iter()
+            .flat_map(|account| account.non_fungibles.iter())
+            .filter(|nft| nft.claimable.is_none())
+            .map(|nft| nft.global_id.clone())
+            .collect::<HashSet<_>>();
+        let non_fungible_prices = if nft_ids.is_empty() {
+            HashMap::new()
+        } else {
+            self.fetch_nft_fiat_values(nft_ids, currency.clone(), force_fetch)
+                .await
+                .or_else(unpriced_if_fx_rate_unavailable)?
+        };
+
+        Ok(PortfolioValuation::new(
+            currency,
+            holdings.accounts,
+            &fungible_prices,
+            &non_fungible_prices,
+        ))
+    }
+}
+
+/// No prices, leaving all assets unpriced, if the failure to price them is
+/// that no FX rate is available for the currency.
+fn unpriced_if_fx_rate_unavailable<K>(
+    error: CommonError,
+) -> Result<HashMap<K, Decimal192>> {
+    match error {
+        CommonError::FiatCurrencyFxRateUnavailable { currency } => {
+            warn!("No FX rate for {}, leaving assets unpriced", currency);
+            Ok(HashMap::new())
+        }
+        error => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_rt::time::timeout;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = SargonOS;
+
+    async fn boot(mock_driver: MockNetworkingDriver) -> Arc<SUT> {
+        let req = SUT::boot_test_with_networking_driver(Arc::new(mock_driver));
+        timeout(SARGON_OS_TEST_MAX_ASYNC_DURATION, req)
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
+    async fn boot_with_alice(mock_driver: MockNetworkingDriver) -> Arc<SUT> {
+        let os = boot(mock_driver).await;
+        os.import_wallet(
+            &Profile::with(
+                Header::sample(),
+                FactorSources::sample(),
+                AppPreferences::sample(),
+                ProfileNetworks::just(ProfileNetwork::new_with_accounts(
+                    NetworkID::Mainnet,
+                    [Account::sample_mainnet_alice()],
+                )),
+            ),
+            true,
+        )
+        .await
+        .unwrap();
+        os
+    }
+
+    async fn boot_watching_alice(
+        mock_driver: MockNetworkingDriver,
+    ) -> Arc<SUT> {
+        let os = boot(mock_driver).await;
+        let mut network = ProfileNetwork::new_empty_on(NetworkID::Mainnet);
+        network
+            .watch_only_accounts
+            .try_insert_unique(WatchOnlyAccount::new(
+                Account::sample_mainnet_alice().address,
+                DisplayName::sample(),
+                AppearanceID::sample(),
+            ))
+            .unwrap();
+        os.import_wallet(
+            &Profile::with(
+                Header::sample(),
+                FactorSources::sample(),
+                AppPreferences::sample(),
+                ProfileNetworks::just(network),
+            ),
+            true,
+        )
+        .await
+        .unwrap();
+        os
+    }
+
+    fn lsu() -> ResourceAddress {
+        "resource_rdx1t45js47zxtau85v0tlyayerzrgfpmguftlfwfr5fxzu42qtu72tnt0"
+            .parse()
+            .unwrap()
+    }
+
+    fn pool_unit() -> ResourceAddress {
+        "resource_rdx1tkk83magp3gjyxrpskfsqwkg4g949rmcjee4tu2xmw93ltw2cz94sq"
+            .parse()
+            .unwrap()
+    }
+
+    fn unpriced_token() -> ResourceAddress {
+        "resource_rdx1t5u04cs3u2yxqkcwku7jdvdvv9cu739jsx0rdwu97682lr0rn92qdh"
+            .parse()
+            .unwrap()
+    }
+
+    fn mock_status_response() -> MockNetworkingDriverResponse {
+        MockNetworkingDriverResponse::new_success(GatewayStatusResponse {
+            ledger_state: LedgerState::new(
+                "mainnet",
+                80577579,
+                "2024-10-07T15:41:07.259Z",
+                41500,
+                894,
+            ),
+        })
+    }
+
+    fn mock_account_response() -> MockNetworkingDriverResponse {
+        let fungible = |resource_address: ResourceAddress, amount: i32| {
+            FungibleResourcesCollectionItem::Global(
+                FungibleResourcesCollectionItemGloballyAggregated::new(
+                    resource_address,
+                    Decimal192::from(amount),
+                ),
+            )
+        };
+        let item = StateEntityDetailsResponseItem::new(
+            Account::sample_mainnet_alice().address.into(),
+            FungibleResourcesCollection::new(
+                None,
+                None,
+                vec![
+                    fungible(ResourceAddress::sample_mainnet_xrd(), 100),
+                    fungible(lsu(), 10),
+                    fungible(pool_unit(), 4),
+                    fungible(unpriced_token(), 7),
+                ],
+            ),
+            NonFungibleResourcesCollection::new(None, None, vec![]),
+            EntityMetadataCollection::empty(),
+            None,
+        );
+        MockNetworkingDriverResponse::new_success(
+            StateEntityDetailsResponse::new(LedgerState::sample(), vec![item]),
+        )
+    }
+
+    fn mock_resources_response() -> MockNetworkingDriverResponse {
+        let fungible = |resource_address: ResourceAddress,
+                        details: NativeResourceDetails| {
+            StateEntityDetailsResponseItem::new(
+                resource_address.into(),
+                None,
+                None,
+                EntityMetadataCollection::empty(),
+                StateEntityDetailsResponseItemDetails::FungibleResource(
+                    StateEntityDetailsResponseFungibleResourceDetails::native(
+                        ComponentEntityRoleAssignments::sample(),
+                        details,
+                    ),
+                ),
+            )
+        };
+        let items = vec![
+            fungible(
+                ResourceAddress::sample_mainnet_xrd(),
+                NativeResourceDetails::Xrd,
+            ),
+            fungible(
+                lsu(),
+                NativeResourceDetails::ValidatorLiquidStakeUnit(
+                    NativeResourceValidatorLiquidStakeUnitValue::new(
+                        ValidatorAddress::sample_mainnet(),
+                        [NativeResourceRedemptionValueItem::new(
+                            ResourceAddress::sample_mainnet_xrd(),
+                            Decimal192::from_str("1.5").unwrap(),
+                        )],
+                    ),
+                ),
+            ),
+            fungible(
+                pool_unit(),
+                NativeResourceDetails::TwoResourcePoolUnit(
+                    NativeResourcePoolUnitValue::new(
+                        PoolAddress::sample_mainnet_bi_pool(),
+                        [
+                            NativeResourceRedemptionValueItem::new(
+                                ResourceAddress::sample_mainnet_xrd(),
+                                Decimal192::from(1),
+                            ),
+                            NativeResourceRedemptionValueItem::new(
+                                ResourceAddress::sample_mainnet_candy(),
+                                Decimal192::from(3),
+                            ),
+                        ],
+                    ),
+                ),
+            ),
+        ];
+        MockNetworkingDriverResponse::new_success(
+            StateEntityDetailsResponse::new(LedgerState::sample(), items),
+        )
+    }
+
+    fn mock_prices_response() -> MockNetworkingDriverResponse {
+        MockNetworkingDriverResponse::new_success(serde_json::json!({
+            "tokens": [
+                {
+                    "resource_address": ResourceAddress::sample_mainnet_xrd(),
+                    "usd_price": 2.0,
+                },
+                {
+                    "resource_address": ResourceAddress::sample_mainnet_candy(),
+                    "usd_price": 0.5,
+                },
+            ],
+            "lsus": [],
+        }))
+    }
+
+    #[actix_rt::test]
+    async fn no_accounts() {
+        let os = boot(MockNetworkingDriver::new_always_failing()).await;
+
+        let result = os.portfolio_valuation(true).await;
+
+        assert_eq!(
+            result,
+            Ok(PortfolioValuation::new(
+                FiatCurrency::USD,
+                [],
+                &HashMap::new(),
+                &HashMap::new(),
+            ))
+        );
+    }
+
+    #[actix_rt::test]
+    async fn failure() {
+        let os =
+            boot_with_alice(MockNetworkingDriver::new_always_failing()).await;
+
+        let result = os.portfolio_valuation(true).await;
+
+        assert_eq!(
+            result,
+            Err(CommonError::NetworkResponseBadCode { code: 500 })
+        );
+    }
+
+    #[actix_rt::test]
+    async fn values_tokens_lsus_and_pool_units() {
+        let mock_driver = MockNetworkingDriver::new_with_responses(vec![
+            mock_status_response(),
+            mock_account_response(),
+            mock_resources_response(),
+            mock_prices_response(),
+        ]);
+        let os = boot_with_alice(mock_driver).await;
+
+        let valuation = os
+            .with_timeout(|x| x.portfolio_valuation(true))
+            .await
+            .unwrap();
+
+        // 100 XRD * 2 + 10 LSU * 1.5 XRD * 2
+        //   + 4 PU * (1 XRD * 2 + 3 CANDY * 0.5)
+        assert_eq!(valuation.worth, Decimal192::from(244));
+        assert_eq!(valuation.currency, FiatCurrency::USD);
+        assert_eq!(valuation.unpriced_resources, vec![unpriced_token()]);
+
+        let account = &valuation.accounts[0];
+        assert_eq!(
+            account.account_address,
+            Account::sample_mainnet_alice().address
+        );
+        assert_eq!(
+            account
+                .fungibles
+                .iter()
+                .map(|fungible| fungible.worth)
+                .collect_vec(),
+            vec![
+                Some(Decimal192::from(200)),
+                Some(Decimal192::from(30)),
+                Some(Decimal192::from(14)),
+                None,
+            ]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn values_watch_only_accounts() {
+        let mock_driver = MockNetworkingDriver::new_with_responses(vec![
+            mock_status_response(),
+            mock_account_response(),
+            mock_resources_response(),
+            mock_prices_response(),
+        ]);
+        let os = boot_watching_alice(mock_driver).await;
+
+        let valuation = os
+            .with_timeout(|x| x.portfolio_valuation(true))
+            .await
+            .unwrap();
+
+        assert_eq!(valuation.worth, Decimal192::from(244));
+        assert_eq!(
+            valuation
+                .accounts
+                .iter()
+                .map(|account| account.account_address)
+                .collect_vec(),
+            vec![Account::sample_mainnet_alice().address]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn unknown_currency_leaves_assets_unpriced() {
+        let mock_driver = MockNetworkingDriver::new_with_responses(vec![
+            mock_status_response(),
+            mock_account_response(),
+            mock_resources_response(),
+            mock_prices_response(),
+            MockNetworkingDriverResponse::new_success(serde_json::json!({
+                "rates": [],
+            })),
+        ]);
+        let os = boot_with_alice(mock_driver).await;
+        let currency = FiatCurrency::Unknown {
+            code: "XYZ".to_owned(),
+        };
+        os.update_profile_with(|profile| {
+            profile.app_preferences.display.fiat_currency_price_target =
+                currency.clone();
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        let valuation = os
+            .with_timeout(|x| x.portfolio_valuation(true))
+            .await
+            .unwrap();
+
+        assert_eq!(valuation.currency, currency);
+        assert_eq!(valuation.worth, Decimal192::zero());
+        assert_eq!(
+            valuation.unpriced_resources,
+            vec![
+                ResourceAddress::sample_mainnet_xrd(),
+                lsu(),
+                pool_unit(),
+                unpriced_token(),
+            ]
+        );
+    }
+}
@@ -0,0 +1,301 @@
+use crate::prelude::*;
+
+#[async_trait::async_trait]
+pub trait OsStakingOverview {
+    async fn staking_overview_of_accounts(
+        &self,
+        account_addresses: Vec<AccountAddress>,
+    ) -> Result<Vec<AccountStakingOverview>>;
+}
+
+#[async_trait::async_trait]
+impl OsStakingOverview for SargonOS {
+    /// Fetches the staking overview of each account, in the same order as
+    /// `account_addresses`: the XRD staked per validator, and the pending
+    /// and claimable unstakes.
+    ///
+    /// Use `StakeClaim::claimable_in` to get the stake claims which can be
+    /// claimed now.
+    async fn staking_overview_of_accounts(
+        &self,
+        account_addresses: Vec<AccountAddress>,
+    ) -> Result<Vec<AccountStakingOverview>> {
+        let mut overviews = Vec::with_capacity(account_addresses.len());
+        for account_address in account_addresses {
+            let gateway_client =
+                self.gateway_client_with(account_address.network_id());
+            let overview = gateway_client
+                .fetch_account_staking_overview(account_address)
+                .await?;
+            overviews.push(overview);
+        }
+        Ok(overviews)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_rt::time::timeout;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = SargonOS;
+
+    async fn boot(mock_driver: MockNetworkingDriver) -> Arc<SUT> {
+        let req = SUT::boot_test_with_networking_driver(Arc::new(mock_driver));
+        timeout(SARGON_OS_TEST_MAX_ASYNC_DURATION, req)
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
+    fn lsu() -> ResourceAddress {
+        "resource_rdx1t45js47zxtau85v0tlyayerzrgfpmguftlfwfr5fxzu42qtu72tnt0"
+            .parse()
+            .unwrap()
+    }
+
+    fn claim_nft() -> NonFungibleResourceAddress {
+        NonFungibleResourceAddress::sample_mainnet()
+    }
+
+    fn mock_status_response() -> MockNetworkingDriverResponse {
+        MockNetworkingDriverResponse::new_success(GatewayStatusResponse {
+            ledger_state: LedgerState::new(
+                "mainnet",
+                80577579,
+                "2024-10-07T15:41:07.259Z",
+                41500,
+                894,
+            ),
+        })
+    }
+
+    fn mock_account_response() -> MockNetworkingDriverResponse {
+        let item = StateEntityDetailsResponseItem::new(
+            Account::sample_mainnet_alice().address.into(),
+            FungibleResourcesCollection::new(
+                None,
+                None,
+                vec![FungibleResourcesCollectionItem::Global(
+                    FungibleResourcesCollectionItemGloballyAggregated::new(
+                        lsu(),
+                        Decimal192::from(10),
+                    ),
+                )],
+            ),
+            NonFungibleResourcesCollection::new(
+                None,
+                None,
+                vec![NonFungibleResourcesCollectionItem::Global(
+                    NonFungibleResourcesCollectionItemGloballyAggregated::new(
+                        claim_nft().0,
+                        2,
+                    ),
+                )],
+            ),
+            EntityMetadataCollection::empty(),
+            None,
+        );
+        MockNetworkingDriverResponse::new_success(
+            StateEntityDetailsResponse::new(LedgerState::sample(), vec![item]),
+        )
+    }
+
+    fn mock_resources_response() -> MockNetworkingDriverResponse {
+        let lsu_item = StateEntityDetailsResponseItem::new(
+            lsu().into(),
+            None,
+            None,
+            EntityMetadataCollection::empty(),
+            StateEntityDetailsResponseItemDetails::FungibleResource(
+                StateEntityDetailsResponseFungibleResourceDetails::native(
+                    ComponentEntityRoleAssignments::sample(),
+                    NativeResourceDetails::ValidatorLiquidStakeUnit(
+                        NativeResourceValidatorLiquidStakeUnitValue::new(
+                            ValidatorAddress::sample_mainnet(),
+                            [NativeResourceRedemptionValueItem::new(
+                                ResourceAddress::sample_mainnet_xrd(),
+                                Decimal192::from_str("1.5").unwrap(),
+                            )],
+                        ),
+                    ),
+                ),
+            ),
+        );
+        let claim_nft_item = StateEntityDetailsResponseItem::new(
+            claim_nft().0.into(),
+            None,
+            None,
+            EntityMetadataCollection::empty(),
+            StateEntityDetailsResponseItemDetails::NonFungibleResource(
+                StateEntityDetailsResponseNonFungibleResourceDetails::native(
+                    ComponentEntityRoleAssignments::sample(),
+                    NativeResourceDetails::ValidatorClaimNft(
+                        NativeResourceValidatorClaimNftValue::new(
+                            ValidatorAddress::sample_mainnet_other(),
+                        ),
+                    ),
+                ),
+            ),
+        );
+        MockNetworkingDriverResponse::new_success(
+            StateEntityDetailsResponse::new(
+                LedgerState::sample(),
+                vec![lsu_item, claim_nft_item],
+            ),
+        )
+    }
+
+    fn mock_vaults_response() -> MockNetworkingDriverResponse {
+        MockNetworkingDriverResponse::new_success(PageResponse::new(
+            LedgerState::sample(),
+            1,
+            None,
+            vec![serde_json::from_value::<
+                NonFungibleResourcesCollectionItemVaultAggregatedVaultItem,
+            >(serde_json::json!({
+                "total_count": 2,
+                "vault_address": "internal_vault_rdx1nqutf8slj3qyasr8jepflggtycdxjzvnkawm8zahmax5xs0eetyehf",
+                "last_updated_at_state_version": 80577579,
+                "items": [
+                    StakeClaimNonFungible::sample().id,
+                    StakeClaimNonFungible::sample_other().id,
+                ],
+            }))
+            .unwrap()],
+        ))
+    }
+
+    fn claim_data_item(
+        claim: StakeClaimNonFungible,
+    ) -> StateNonFungibleDataResponseItem {
+        serde_json::from_value(serde_json::json!({
+            "non_fungible_id": claim.id,
+            "is_burned": false,
+            "last_updated_at_state_version": 80577579,
+            "data": {
+                "programmatic_json": {
+                    "kind": "Tuple",
+                    "type_name": "UnstakeData",
+                    "fields": [
+                        {
+                            "kind": "U64",
+                            "field_name": "claim_epoch",
+                            "value": claim.claim_epoch.0.to_string()
+                        },
+                        {
+                            "kind": "Decimal",
+                            "field_name": "claim_amount",
+                            "value": claim.claim_amount.to_string()
+                        }
+                    ]
+                }
+            }
+        }))
+        .unwrap()
+    }
+
+    fn mock_claims_response() -> MockNetworkingDriverResponse {
+        MockNetworkingDriverResponse::new_success(
+            StateNonFungibleDataResponse {
+                ledger_state: LedgerState::sample(),
+                resource_address: claim_nft().0,
+                non_fungible_ids: vec![
+                    claim_data_item(StakeClaimNonFungible::sample()),
+                    claim_data_item(StakeClaimNonFungible::sample_other()),
+                ],
+            },
+        )
+    }
+
+    #[actix_rt::test]
+    async fn values_lsus_and_splits_stake_claims() {
+        let mock_driver = MockNetworkingDriver::new_with_responses(vec![
+            mock_status_response(),
+            mock_account_response(),
+            mock_resources_response(),
+            mock_vaults_response(),
+            mock_claims_response(),
+        ]);
+        let os = boot(mock_driver).await;
+        let account_address = Account::sample_mainnet_alice().address;
+
+        let overviews = os
+            .with_timeout(|x| {
+                x.staking_overview_of_accounts(vec![account_address])
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(overviews.len(), 1);
+        let overview = &overviews[0];
+        assert_eq!(overview.account_address, account_address);
+        assert_eq!(overview.current_epoch, Epoch::new(41500u64));
+
+        // 10 LSU * 1.5 XRD
+        assert_eq!(overview.total_staked_xrd(), Decimal192::from(15));
+        let liquid_stake = overview
+            .validators
+            .iter()
+            .find(|v| v.validator_address == ValidatorAddress::sample_mainnet())
+            .and_then(|v| v.liquid_stake.clone())
+            .unwrap();
+        assert_eq!(liquid_stake.resource_address, lsu());
+        assert_eq!(liquid_stake.stake_units, Decimal192::from(10));
+
+        // Claimable at epoch 41000, pending until epoch 42000.
+        let stake_claims = overview
+            .validators
+            .iter()
+            .find(|v| {
+                v.validator_address == ValidatorAddress::sample_mainnet_other()
+            })
+            .and_then(|v| v.stake_claims.clone())
+            .unwrap();
+        assert_eq!(
+            stake_claims.claimable,
+            vec![StakeClaimNonFungible::sample()]
+        );
+        assert_eq!(
+            stake_claims.pending,
+            vec![StakeClaimNonFungible::sample_other()]
+        );
+        assert_eq!(overview.total_claimable_xrd(), Decimal192::from(100));
+        assert_eq!(stake_claims.pending_xrd(), Decimal192::from(50));
+        assert_eq!(
+            StakeClaim::claimable_in(overview),
+            vec![StakeClaim::new(
+                ValidatorAddress::sample_mainnet_other(),
+                claim_nft(),
+                [StakeClaimNonFungible::sample().id],
+                100,
+            )]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn no_accounts() {
+        let os = boot(MockNetworkingDriver::new_always_failing()).await;
+
+        let result = os.staking_overview_of_accounts(Vec::new()).await;
+
+        assert_eq!(result, Ok(Vec::new()));
+    }
+
+    #[actix_rt::test]
+    async fn failure() {
+        let os = boot(MockNetworkingDriver::new_always_failing()).await;
+
+        let result = os
+            .staking_overview_of_accounts(vec![
+                AccountAddress::sample_stokenet(),
+            ])
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommonError::NetworkResponseBadCode { code: 500 })
+        );
+    }
+}
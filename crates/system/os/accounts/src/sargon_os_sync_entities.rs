@@ -2,8 +2,9 @@ use crate::prelude::*;
 
 #[async_trait::async_trait]
 pub trait OsSyncEntitiesStateOnLedger {
-    async fn wip_sync_entities_state_on_ledger(
+    async fn sync_entities_state_on_ledger(
         &self,
+        mode: EntitySyncMode,
     ) -> Result<EntitySyncOutcome>;
 
     async fn sync_accounts_deleted_on_ledger(&self) -> Result<bool>;
@@ -19,121 +20,259 @@ pub trait OsSyncEntitiesStateOnLedger {
 // ==================
 #[async_trait::async_trait]
 impl OsSyncEntitiesStateOnLedger for SargonOS {
-    /// **WORK IN PROGRESS**
-    ///
-    /// Syncs entities in profile with on ledger state.
-    /// Returns a summary report of the actions performed.
+    /// Syncs entities in profile on the current network with their state on
+    /// ledger. Returns a summary report of the differences found and - in
+    /// `EntitySyncMode::Apply` - of the actions performed on profile.
     ///
     /// Checks performed on entities are:
     /// 1. Checks if active accounts on profile are deleted on ledger.
     ///    Action => to mark them as tombstoned
     /// 2. Checks if entities with provisional shield are securified on ledger.
     ///    Action => to mark them as securified
-    /// 3. more checks will be developed...
-    async fn wip_sync_entities_state_on_ledger(
+    /// 3. Checks if entities are controlled on ledger by an access controller
+    ///    unknown to profile, i.e. the shield was changed from another wallet.
+    ///    Reported only
+    /// 4. Checks if a timed recovery is in progress on the access controller
+    ///    of securified entities.
+    ///    Reported only
+    /// 5. Checks if the third-party deposit settings of non deleted accounts
+    ///    differ on ledger.
+    ///    Action => to replace the on ledger settings in profile.
+    ///    Accounts whose settings could not be fetched are reported as
+    ///    failures, without failing the sync of the other entities.
+    async fn sync_entities_state_on_ledger(
         &self,
+        mode: EntitySyncMode,
     ) -> Result<EntitySyncOutcome> {
-        let mut entities = Vec::<AddressOfAccountOrPersona>::new();
-        entities.extend(self.accounts_on_current_network().map(
-            |accounts| {
-                accounts
-                    .iter()
-                    .map(|a| AddressOfAccountOrPersona::from(a.address))
-                    .collect_vec()
-            },
-        )?);
-        entities.extend(self.personas_on_current_network().map(
-            |personas| {
-                personas
+        let accounts = self.accounts_on_current_network()?;
+        let entities = accounts
+            .iter()
+            .map(AccountOrPersona::from)
+            .chain(
+                self.personas_on_current_network()?
                     .iter()
-                    .map(|p| AddressOfAccountOrPersona::from(p.address))
-                    .collect_vec()
-            },
-        )?);
+                    .map(AccountOrPersona::from),
+            )
+            .collect_vec();
 
         let (gateway_client, network_id) = self.gateway_client_on()?;
 
         // Fetch ancestor addresses
         let badge_owner_per_entity = gateway_client
-            .fetch_entities_badge_owners(network_id, entities)
+            .fetch_entities_badge_owners(
+                network_id,
+                entities.iter().map(|e| e.address()),
+            )
             .await?;
 
-        // Collect sync actions based on the profile state
-        let mut sync_actions = IndexSet::<EntitySyncAction>::new();
-        for (entity_address, maybe_badge_owner) in badge_owner_per_entity {
-            let Some(ancestor_address) = maybe_badge_owner else {
-                continue;
-            };
-
-            let sync_action = match ancestor_address {
-                Address::AccessController(
-                    access_controller_ancestor_address,
-                ) => {
-                    let entity = self.entity_by_address(entity_address)?;
-
-                    if !entity.is_securified() {
-                        EntitySyncAction::ToSecurify(
-                            entity_address,
-                            access_controller_ancestor_address,
-                        )
-                    } else {
-                        // TODO check if it needs to update security state
-                        // currently returns no update
-                        continue;
-                    }
+        // Collect the changes derived from the badge owners, iterating in
+        // the order of profile since the owners are unordered.
+        let mut changes_per_entity =
+            IndexMap::<AddressOfAccountOrPersona, Vec<EntitySyncChange>>::new();
+        let mut access_controller_per_entity = IndexMap::<
+            AddressOfAccountOrPersona,
+            AccessControllerAddress,
+        >::new();
+        let mut deleted_accounts = IndexSet::<AccountAddress>::new();
+        for entity in entities.iter() {
+            let entity_address = entity.address();
+            let changes = changes_per_entity.entry(entity_address).or_default();
+            match badge_owner_per_entity
+                .get(&entity_address)
+                .cloned()
+                .flatten()
+            {
+                Some(Address::AccessController(access_controller_address)) => {
+                    access_controller_per_entity
+                        .insert(entity_address, access_controller_address);
+                    changes.extend(security_state_change(
+                        entity,
+                        access_controller_address,
+                    ));
                 }
-                Address::Account(account_ancestor_address) => {
-                    if AddressOfAccountOrPersona::from(account_ancestor_address)
-                        == entity_address
-                    {
-                        EntitySyncAction::ToTombstone(account_ancestor_address)
-                    } else {
+                Some(Address::Account(account_address))
+                    if AddressOfAccountOrPersona::from(account_address)
+                        == entity_address =>
+                {
+                    deleted_accounts.insert(account_address);
+                    changes.push(EntitySyncChange::AccountDeleted {
+                        account_address,
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        // Look for timed recoveries in progress on the access controllers
+        if !access_controller_per_entity.is_empty() {
+            let access_controllers = access_controller_per_entity
+                .values()
+                .cloned()
+                .collect::<IndexSet<_>>();
+            let timed_recovery_per_access_controller = gateway_client
+                .fetch_access_controllers_details(
+                    access_controllers.into_iter().collect(),
+                )
+                .await?
+                .into_iter()
+                .filter_map(|details| {
+                    allow_timed_recovery_after(&details)
+                        .map(|instant| (details.address, instant))
+                })
+                .collect::<IndexMap<_, _>>();
+
+            for (entity_address, access_controller_address) in
+                access_controller_per_entity
+            {
+                let Some(allow_timed_recovery_after) =
+                    timed_recovery_per_access_controller
+                        .get(&access_controller_address)
+                else {
+                    continue;
+                };
+                changes_per_entity.entry(entity_address).or_default().push(
+                    EntitySyncChange::TimedRecoveryInProgress {
+                        entity_address,
+                        access_controller_address,
+                        allow_timed_recovery_after: *allow_timed_recovery_after,
+                    },
+                );
+            }
+        }
+
+        // Compare the third-party deposit settings of non deleted accounts,
+        // collecting the accounts whose settings could not be fetched
+        // instead of failing the whole sync.
+        let mut failures = Vec::<EntitySyncFailure>::new();
+        let accounts_to_compare = accounts
+            .iter()
+            .filter(|a| !deleted_accounts.contains(&a.address))
+            .collect_vec();
+        if !accounts_to_compare.is_empty() {
+            let fetched = async {
+                let ledger_state =
+                    gateway_client.gateway_status().await?.ledger_state;
+                gateway_client
+                    .fetch_accounts_third_party_deposits(
+                        accounts_to_compare.iter().map(|a| a.address),
+                        ledger_state.into(),
+                    )
+                    .await
+            }
+            .await;
+            // If the batched request fails, every account fails.
+            let third_party_deposits_per_account = match fetched {
+                Ok(per_account) => per_account.into_values().collect_vec(),
+                Err(error) => accounts_to_compare
+                    .iter()
+                    .map(|_| Err(error.clone()))
+                    .collect_vec(),
+            };
+            for (account, third_party_deposits) in accounts_to_compare
+                .into_iter()
+                .zip(third_party_deposits_per_account)
+            {
+                let third_party_deposits = match third_party_deposits {
+                    Ok(third_party_deposits) => third_party_deposits,
+                    Err(error) => {
+                        warn!(
+                            "Failed to fetch third-party deposits of {}: {:?}",
+                            account.address, error
+                        );
+                        failures.push(EntitySyncFailure::new(
+                            account.address,
+                            error,
+                        ));
                         continue;
                     }
-                }
-                _ => {
+                };
+                if is_third_party_deposits_in_sync(
+                    &account.on_ledger_settings.third_party_deposits,
+                    &third_party_deposits,
+                ) {
                     continue;
                 }
-            };
+                changes_per_entity
+                    .entry(account.address.into())
+                    .or_default()
+                    .push(EntitySyncChange::OnLedgerSettingsChanged {
+                        account_address: account.address,
+                        on_ledger_settings: OnLedgerSettings::new(
+                            third_party_deposits,
+                        ),
+                    });
+            }
+        }
 
-            sync_actions.insert(sync_action);
+        let changes = changes_per_entity.into_values().flatten().collect_vec();
+
+        let has_applicable_changes = changes.iter().any(|change| {
+            !matches!(
+                change,
+                EntitySyncChange::ShieldChangedElsewhere { .. }
+                    | EntitySyncChange::TimedRecoveryInProgress { .. }
+            )
+        });
+        if mode == EntitySyncMode::Preview || !has_applicable_changes {
+            return Ok(EntitySyncOutcome::new(
+                changes,
+                IndexSet::new(),
+                failures,
+            ));
         }
 
-        if !sync_actions.is_empty() {
-            // Perform sync
-            self.update_profile_with(|profile| {
-                let mut actions_performed = IndexSet::<EntitySyncActionPerformed>::new();
-
-                for action in &sync_actions {
-                    match action {
-                        EntitySyncAction::ToTombstone(account_address) => {
-                            profile.networks.tombstone_account(account_address);
-                            actions_performed.insert(EntitySyncActionPerformed::SomeEntitiesTombstoned);
-                        }
-                        EntitySyncAction::ToSecurify(
-                            entity_address,
-                            access_controller_address
-                        ) => {
-                            profile.mark_entity_as_securified(
-                                *access_controller_address,
-                                *entity_address
-                            )?;
-
-                            // TODO What if not in provisional state?
-                            // 1. Should we not early return error, perform sync in all
-                            //    remaining entities and
-                            // 2. report that this entity is in bad state?
-
-                            actions_performed.insert(EntitySyncActionPerformed::SomeEntitiesSecurified);
-                        }
+        // Perform sync
+        self.update_profile_with(|profile| {
+            let mut actions_performed =
+                IndexSet::<EntitySyncActionPerformed>::new();
+
+            for change in &changes {
+                match change {
+                    EntitySyncChange::AccountDeleted { account_address } => {
+                        profile.networks.tombstone_account(account_address);
+                        actions_performed.insert(
+                            EntitySyncActionPerformed::SomeEntitiesTombstoned,
+                        );
+                    }
+                    EntitySyncChange::EntitySecurified {
+                        entity_address,
+                        access_controller_address,
+                    } => {
+                        profile.mark_entity_as_securified(
+                            *access_controller_address,
+                            *entity_address,
+                        )?;
+                        actions_performed.insert(
+                            EntitySyncActionPerformed::SomeEntitiesSecurified,
+                        );
                     }
+                    EntitySyncChange::OnLedgerSettingsChanged {
+                        account_address,
+                        on_ledger_settings,
+                    } => {
+                        profile.networks.update_account(
+                            account_address,
+                            |account| {
+                                account.on_ledger_settings =
+                                    on_ledger_settings.clone();
+                            },
+                        );
+                        actions_performed.insert(
+                            EntitySyncActionPerformed::SomeAccountsOnLedgerSettingsUpdated,
+                        );
+                    }
+                    EntitySyncChange::ShieldChangedElsewhere { .. }
+                    | EntitySyncChange::TimedRecoveryInProgress { .. } => {}
                 }
+            }
 
-                Ok(EntitySyncOutcome::new(actions_performed))
-            }).await
-        } else {
-            Ok(EntitySyncOutcome::no_action())
-        }
+            Ok(EntitySyncOutcome::new(
+                changes.clone(),
+                actions_performed,
+                failures.clone(),
+            ))
+        })
+        .await
     }
 
     /// Checks all active accounts in current network on ledger, if any of them are deleted.
@@ -191,6 +330,89 @@ impl OsSyncEntitiesStateOnLedger for SargonOS {
     }
 }
 
+/// The change of the security state of `entity`, which is controlled on
+/// ledger by `access_controller_address`, if any.
+fn security_state_change(
+    entity: &AccountOrPersona,
+    access_controller_address: AccessControllerAddress,
+) -> Option<EntitySyncChange> {
+    let entity_address = entity.address();
+    match entity.security_state() {
+        EntitySecurityState::Unsecured { .. } => {
+            // Only a provisional shield prepared by this wallet carries the
+            // factor instances needed to mark the entity as securified.
+            if entity.get_provisional().is_some() {
+                Some(EntitySyncChange::EntitySecurified {
+                    entity_address,
+                    access_controller_address,
+                })
+            } else {
+                Some(EntitySyncChange::ShieldChangedElsewhere {
+                    entity_address,
+                    access_controller_address,
+                })
+            }
+        }
+        EntitySecurityState::Securified { value } => {
+            if value.access_controller_address() == access_controller_address {
+                None
+            } else {
+                Some(EntitySyncChange::ShieldChangedElsewhere {
+                    entity_address,
+                    access_controller_address,
+                })
+            }
+        }
+    }
+}
+
+/// The instant after which the recovery initiated with the recovery role on
+/// the access controller can be confirmed, if such a recovery is in progress.
+fn allow_timed_recovery_after(
+    details: &AccessControllerStateDetails,
+) -> Option<Instant> {
+    let allow_after = details
+        .state
+        .recovery_role_recovery_attempt
+        .as_ref()?
+        .allow_timed_recovery_after
+        .as_ref()?;
+    match allow_after.unix_timestamp_seconds.parse::<i64>() {
+        Ok(seconds) => Some(Instant::from(seconds)),
+        Err(_) => {
+            warn!(
+                "Invalid timed recovery instant {} on access controller {}",
+                allow_after.unix_timestamp_seconds, details.address
+            );
+            None
+        }
+    }
+}
+
+/// Whether the third-party deposit settings of an account in profile match
+/// the ones on ledger, regardless of the order of the lists. Lists unknown
+/// to profile are never in sync.
+fn is_third_party_deposits_in_sync(
+    in_profile: &ThirdPartyDeposits,
+    on_ledger: &ThirdPartyDeposits,
+) -> bool {
+    let exceptions = |deposits: &ThirdPartyDeposits| {
+        deposits
+            .assets_exception_list
+            .as_ref()
+            .map(|list| list.iter().collect::<IndexSet<AssetException>>())
+    };
+    let depositors = |deposits: &ThirdPartyDeposits| {
+        deposits.depositors_allow_list.as_ref().map(|list| {
+            list.iter().collect::<IndexSet<ResourceOrNonFungible>>()
+        })
+    };
+
+    in_profile.deposit_rule == on_ledger.deposit_rule
+        && exceptions(in_profile) == exceptions(on_ledger)
+        && depositors(in_profile) == depositors(on_ledger)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -202,19 +424,6 @@ mod tests {
     #[allow(clippy::upper_case_acronyms)]
     type SUT = SargonOS;
 
-    impl EntitySyncAction {
-        fn entity_address(&self) -> AddressOfAccountOrPersona {
-            match self {
-                EntitySyncAction::ToTombstone(account_address) => {
-                    AddressOfAccountOrPersona::from(*account_address)
-                }
-                EntitySyncAction::ToSecurify(entity_address, _) => {
-                    *entity_address
-                }
-            }
-        }
-    }
-
     #[actix_rt::test]
     async fn test_sync_accounts_deleted_on_ledger() {
         // ARRANGE
@@ -340,38 +549,260 @@ mod tests {
     async fn test_sync_entities_on_ledger() {
         // ARRANGE
         let account_deleted_on_ledger = Account::sample_mainnet_alice();
-        let account_active_on_ledger = Account::sample_mainnet_bob();
         let account_securified_on_ledger = Account::sample_mainnet_carol();
         let persona_securified_on_ledger = Persona::sample_mainnet();
-        let all_initial_accounts = vec![
-            account_deleted_on_ledger.clone(),
-            account_active_on_ledger.clone(),
-            account_securified_on_ledger.clone(),
-        ];
-        let mock_driver = MockNetworkingDriver::new_with_responses(
-            mock_location_responses(vec![
-                EntitySyncAction::ToTombstone(
-                    account_deleted_on_ledger.address,
-                ),
-                EntitySyncAction::ToSecurify(
-                    AddressOfAccountOrPersona::from(
-                        account_securified_on_ledger.address,
-                    ),
-                    AccessControllerAddress::sample_mainnet(),
-                ),
-                EntitySyncAction::ToSecurify(
-                    AddressOfAccountOrPersona::from(
-                        persona_securified_on_ledger.clone().address,
-                    ),
-                    AccessControllerAddress::sample_mainnet_other(),
+        let sut = boot_with_provisional_shields(
+            mock_responses_deleting_alice_securifying_carol_and_persona(),
+        )
+        .await;
+
+        // ACT
+        let report = sut
+            .sync_entities_state_on_ledger(EntitySyncMode::Apply)
+            .await
+            .unwrap();
+
+        // ASSERT
+        assert_eq!(
+            report.changes,
+            expected_changes_deleting_alice_securifying_carol_and_persona()
+        );
+        assert!(report
+            .actions_performed
+            .contains(&EntitySyncActionPerformed::SomeEntitiesTombstoned));
+        assert!(report
+            .actions_performed
+            .contains(&EntitySyncActionPerformed::SomeEntitiesSecurified));
+
+        let mutated_profile = sut.profile().unwrap();
+        let network = mutated_profile.current_network().unwrap();
+        assert!(network
+            .accounts
+            .iter()
+            .find(|a| a.address == account_deleted_on_ledger.address())
+            .unwrap()
+            .is_tombstoned());
+        assert!(sut
+            .account_by_address(account_securified_on_ledger.address())
+            .unwrap()
+            .is_securified());
+        assert!(sut
+            .persona_by_address(persona_securified_on_ledger.address())
+            .unwrap()
+            .is_securified());
+    }
+
+    #[actix_rt::test]
+    async fn test_sync_entities_on_ledger_continues_if_account_fails() {
+        // ARRANGE
+        let carol = Account::sample_mainnet_carol().address;
+        let mut responses =
+            mock_responses_deleting_alice_securifying_carol_and_persona();
+        // Replace the lists of Carol with a failing request
+        responses.truncate(responses.len() - 2);
+        responses.push(MockNetworkingDriverResponse::new_failing());
+        let sut = boot_with_provisional_shields(responses).await;
+
+        // ACT
+        let report = sut
+            .sync_entities_state_on_ledger(EntitySyncMode::Apply)
+            .await
+            .unwrap();
+
+        // ASSERT
+        assert_eq!(
+            report.changes,
+            expected_changes_deleting_alice_securifying_carol_and_persona()
+        );
+        assert_eq!(
+            report
+                .failures
+                .iter()
+                .map(|failure| failure.entity_address)
+                .collect_vec(),
+            vec![AddressOfAccountOrPersona::from(carol)]
+        );
+        assert!(report
+            .actions_performed
+            .contains(&EntitySyncActionPerformed::SomeEntitiesTombstoned));
+        assert!(sut.account_by_address(carol).unwrap().is_securified());
+    }
+
+    #[actix_rt::test]
+    async fn test_sync_entities_on_ledger_preview_does_not_update_profile() {
+        // ARRANGE
+        let sut = boot_with_provisional_shields(
+            mock_responses_deleting_alice_securifying_carol_and_persona(),
+        )
+        .await;
+        let profile_before_sync = sut.profile().unwrap();
+
+        // ACT
+        let report = sut
+            .sync_entities_state_on_ledger(EntitySyncMode::Preview)
+            .await
+            .unwrap();
+
+        // ASSERT
+        assert_eq!(
+            report.changes,
+            expected_changes_deleting_alice_securifying_carol_and_persona()
+        );
+        assert!(report.actions_performed.is_empty());
+        assert_eq!(sut.profile().unwrap(), profile_before_sync);
+    }
+
+    #[actix_rt::test]
+    async fn test_sync_entities_on_ledger_reports_changes_made_elsewhere() {
+        // ARRANGE
+        let account = Account::sample_mainnet_bob();
+        let access_controller_address =
+            AccessControllerAddress::sample_mainnet();
+        let mut responses = mock_location_responses(vec![(
+            account.address.into(),
+            access_controller_address.into(),
+        )]);
+        responses.extend(mock_access_controllers_responses(vec![(
+            access_controller_address,
+            AccessControllerFieldStateValue::sample_other(),
+        )]));
+        responses.push(mock_gateway_status_response());
+        responses.extend(mock_third_party_deposits_responses(
+            account.address,
+            AccountDefaultDepositRule::Reject,
+            vec![AccountResourcePreference {
+                resource_address: ResourceAddress::sample_mainnet_xrd(),
+                status: AccountResourcePreferenceRule::Allowed,
+            }],
+            vec![AccountAuthorizedDepositor::ResourceBadge {
+                resource_address: ResourceAddress::sample_mainnet_candy(),
+            }],
+        ));
+        let sut = boot_with_responses(responses).await;
+        sut.import_wallet(
+            &Profile::with(
+                Header::sample(),
+                FactorSources::sample(),
+                AppPreferences::sample(),
+                ProfileNetworks::just(ProfileNetwork::new_with_accounts(
+                    NetworkID::Mainnet,
+                    vec![account.clone()],
+                )),
+            ),
+            true,
+        )
+        .await
+        .unwrap();
+
+        // ACT
+        let report = sut
+            .sync_entities_state_on_ledger(EntitySyncMode::Apply)
+            .await
+            .unwrap();
+
+        // ASSERT
+        let expected_on_ledger_settings =
+            OnLedgerSettings::new(ThirdPartyDeposits::with_rule_and_lists(
+                DepositRule::DenyAll,
+                [AssetException::new(
+                    ResourceAddress::sample_mainnet_xrd(),
+                    DepositAddressExceptionRule::Allow,
+                )],
+                [ResourceOrNonFungible::Resource {
+                    value: ResourceAddress::sample_mainnet_candy(),
+                }],
+            ));
+        assert_eq!(
+            report,
+            EntitySyncOutcome::new(
+                [
+                    EntitySyncChange::ShieldChangedElsewhere {
+                        entity_address: account.address.into(),
+                        access_controller_address,
+                    },
+                    EntitySyncChange::TimedRecoveryInProgress {
+                        entity_address: account.address.into(),
+                        access_controller_address,
+                        allow_timed_recovery_after: Instant::from(1730999831),
+                    },
+                    EntitySyncChange::OnLedgerSettingsChanged {
+                        account_address: account.address,
+                        on_ledger_settings: expected_on_ledger_settings
+                            .clone(),
+                    },
+                ],
+                IndexSet::just(
+                    EntitySyncActionPerformed::SomeAccountsOnLedgerSettingsUpdated
                 ),
-            ]),
+                [],
+            )
+        );
+
+        let synced_account = sut.account_by_address(account.address).unwrap();
+        assert!(!synced_account.is_securified());
+        assert_eq!(
+            synced_account.on_ledger_settings,
+            expected_on_ledger_settings
+        );
+    }
+
+    #[test]
+    fn third_party_deposits_in_sync_regardless_of_order() {
+        let in_profile = ThirdPartyDeposits::with_rule_and_lists(
+            DepositRule::AcceptKnown,
+            [AssetException::sample(), AssetException::sample_other()],
+            [
+                ResourceOrNonFungible::sample(),
+                ResourceOrNonFungible::sample_other(),
+            ],
         );
+        let on_ledger = ThirdPartyDeposits::with_rule_and_lists(
+            DepositRule::AcceptKnown,
+            [AssetException::sample_other(), AssetException::sample()],
+            [
+                ResourceOrNonFungible::sample_other(),
+                ResourceOrNonFungible::sample(),
+            ],
+        );
+        assert!(is_third_party_deposits_in_sync(&in_profile, &on_ledger));
+    }
+
+    #[test]
+    fn third_party_deposits_not_in_sync() {
+        let on_ledger = ThirdPartyDeposits::sample();
+
+        let mut other_rule = on_ledger.clone();
+        other_rule.deposit_rule = DepositRule::DenyAll;
+        assert!(!is_third_party_deposits_in_sync(&other_rule, &on_ledger));
+
+        let mut unknown_lists = on_ledger.clone();
+        unknown_lists.assets_exception_list = None;
+        unknown_lists.depositors_allow_list = None;
+        assert!(!is_third_party_deposits_in_sync(&unknown_lists, &on_ledger));
+
+        assert!(!is_third_party_deposits_in_sync(
+            &ThirdPartyDeposits::sample_other(),
+            &on_ledger
+        ));
+    }
+
+    async fn boot_with_responses(
+        responses: Vec<MockNetworkingDriverResponse>,
+    ) -> SUT {
+        let mock_driver = MockNetworkingDriver::new_with_responses(responses);
         let req = SUT::boot_test_with_networking_driver(Arc::new(mock_driver));
-        let sut = timeout(SARGON_OS_TEST_MAX_ASYNC_DURATION, req)
+        timeout(SARGON_OS_TEST_MAX_ASYNC_DURATION, req)
             .await
             .unwrap()
-            .unwrap();
+            .unwrap()
+    }
+
+    /// Boots with a profile holding the accounts Alice, Bob and Carol and a
+    /// persona, where Carol and the persona have a provisional shield.
+    async fn boot_with_provisional_shields(
+        responses: Vec<MockNetworkingDriverResponse>,
+    ) -> SUT {
+        let sut = boot_with_responses(responses).await;
         sut.import_wallet(
             &Profile::with(
                 Header::sample(),
@@ -379,8 +810,12 @@ mod tests {
                 AppPreferences::sample(),
                 ProfileNetworks::just(ProfileNetwork::new(
                     NetworkID::Mainnet,
-                    all_initial_accounts.clone(),
-                    vec![persona_securified_on_ledger.clone()],
+                    vec![
+                        Account::sample_mainnet_alice(),
+                        Account::sample_mainnet_bob(),
+                        Account::sample_mainnet_carol(),
+                    ],
+                    vec![Persona::sample_mainnet()],
                     AuthorizedDapps::new(),
                     ResourcePreferences::new(),
                     MFAFactorInstances::new(),
@@ -405,73 +840,101 @@ mod tests {
             structure_source_ids_sample.id(),
             IndexSet::from_iter([
                 AddressOfAccountOrPersona::from(
-                    account_securified_on_ledger.address,
+                    Account::sample_mainnet_carol().address,
                 ),
                 AddressOfAccountOrPersona::from(
-                    persona_securified_on_ledger.address,
+                    Persona::sample_mainnet().address,
                 ),
             ]),
         )
         .await
         .unwrap();
 
-        let report = sut.wip_sync_entities_state_on_ledger().await.unwrap();
+        sut
+    }
 
-        assert!(report
-            .actions_performed
-            .contains(&EntitySyncActionPerformed::SomeEntitiesTombstoned));
-        assert!(report
-            .actions_performed
-            .contains(&EntitySyncActionPerformed::SomeEntitiesSecurified));
+    fn mock_responses_deleting_alice_securifying_carol_and_persona(
+    ) -> Vec<MockNetworkingDriverResponse> {
+        let alice = Account::sample_mainnet_alice().address;
+        let bob = Account::sample_mainnet_bob().address;
+        let carol = Account::sample_mainnet_carol().address;
+        let persona = Persona::sample_mainnet().address;
 
-        let mutated_profile = sut.profile().unwrap();
-        let network = mutated_profile.current_network().unwrap();
-        assert!(network
-            .accounts
-            .iter()
-            .find(|a| a.address == account_deleted_on_ledger.address())
-            .unwrap()
-            .is_tombstoned());
-        assert!(sut
-            .account_by_address(account_securified_on_ledger.address())
-            .unwrap()
-            .is_securified());
-        assert!(sut
-            .persona_by_address(persona_securified_on_ledger.address())
-            .unwrap()
-            .is_securified());
+        let mut responses = mock_location_responses(vec![
+            (alice.into(), alice.into()),
+            (
+                carol.into(),
+                AccessControllerAddress::sample_mainnet().into(),
+            ),
+            (
+                persona.into(),
+                AccessControllerAddress::sample_mainnet_other().into(),
+            ),
+        ]);
+        responses.extend(mock_access_controllers_responses(vec![
+            (
+                AccessControllerAddress::sample_mainnet(),
+                AccessControllerFieldStateValue::sample(),
+            ),
+            (
+                AccessControllerAddress::sample_mainnet_other(),
+                AccessControllerFieldStateValue::sample(),
+            ),
+        ]));
+        responses.push(mock_gateway_status_response());
+        responses.push(mock_deposit_rules_response(vec![
+            (bob, AccountDefaultDepositRule::Accept),
+            (carol, AccountDefaultDepositRule::Accept),
+        ]));
+        for _ in [bob, carol] {
+            responses.extend(mock_deposit_lists_responses(vec![], vec![]));
+        }
+        responses
+    }
+
+    fn expected_changes_deleting_alice_securifying_carol_and_persona(
+    ) -> Vec<EntitySyncChange> {
+        vec![
+            EntitySyncChange::AccountDeleted {
+                account_address: Account::sample_mainnet_alice().address,
+            },
+            EntitySyncChange::EntitySecurified {
+                entity_address: Account::sample_mainnet_carol().address.into(),
+                access_controller_address:
+                    AccessControllerAddress::sample_mainnet(),
+            },
+            EntitySyncChange::EntitySecurified {
+                entity_address: Persona::sample_mainnet().address.into(),
+                access_controller_address:
+                    AccessControllerAddress::sample_mainnet_other(),
+            },
+        ]
     }
 
+    /// Responses of the badge owners lookup, where each entity's badge is
+    /// owned by the given address. The lookup of identities is only mocked if
+    /// any persona is given, since no request is made for no personas.
     fn mock_location_responses(
-        sync_actions: Vec<EntitySyncAction>,
+        badge_owners: Vec<(AddressOfAccountOrPersona, Address)>,
     ) -> Vec<MockNetworkingDriverResponse> {
-        let location_responses = |input: Vec<EntitySyncAction>| -> Vec<
+        let location_responses = |is_account: bool| -> Vec<
             StateNonFungibleLocationResponseItem,
         > {
-            input
-                .into_iter()
-                .map(|action| {
-                    let (ancestor, local_id) = match action {
-                        EntitySyncAction::ToTombstone(account_address) => (
-                            Address::from(account_address),
-                            NonFungibleLocalId::from(account_address),
-                        ),
-                        EntitySyncAction::ToSecurify(
-                            entity_address,
-                            access_controller_address,
-                        ) => (
-                            Address::from(access_controller_address),
-                            NonFungibleLocalId::from(entity_address),
-                        ),
-                    };
-
+            badge_owners
+                .iter()
+                .filter(|(entity_address, _)| {
+                    entity_address.is_account() == is_account
+                })
+                .map(|(entity_address, ancestor)| {
                     StateNonFungibleLocationResponseItem {
-                        non_fungible_id: local_id,
+                        non_fungible_id: NonFungibleLocalId::from(
+                            *entity_address,
+                        ),
                         is_burned: false,
                         last_updated_at_state_version: 0,
                         owning_vault_address: VaultAddress::sample_mainnet(),
-                        owning_vault_parent_ancestor_address: Some(ancestor),
-                        owning_vault_global_ancestor_address: Some(ancestor),
+                        owning_vault_parent_ancestor_address: Some(*ancestor),
+                        owning_vault_global_ancestor_address: Some(*ancestor),
                     }
                 })
                 .collect_vec()
@@ -485,13 +948,7 @@ mod tests {
                     NetworkID::Mainnet,
                 )
                 .unwrap(),
-                non_fungible_ids: location_responses(
-                    sync_actions
-                        .clone()
-                        .into_iter()
-                        .filter(|action| action.entity_address().is_account())
-                        .collect_vec(),
-                ),
+                non_fungible_ids: location_responses(true),
             },
         );
 
@@ -503,16 +960,115 @@ mod tests {
                     NetworkID::Mainnet,
                 )
                 .unwrap(),
-                non_fungible_ids: location_responses(
-                    sync_actions
-                        .clone()
-                        .into_iter()
-                        .filter(|action| action.entity_address().is_identity())
-                        .collect_vec(),
-                ),
+                non_fungible_ids: location_responses(false),
             },
         );
 
-        vec![response_for_accounts, response_for_identities]
+        if badge_owners.iter().any(|(a, _)| a.is_identity()) {
+            vec![response_for_accounts, response_for_identities]
+        } else {
+            vec![response_for_accounts]
+        }
+    }
+
+    fn mock_gateway_status_response() -> MockNetworkingDriverResponse {
+        MockNetworkingDriverResponse::new_success(GatewayStatusResponse {
+            ledger_state: LedgerState::sample(),
+        })
+    }
+
+    fn mock_component_details_response(
+        address: impl Into<Address>,
+        state: &impl Serialize,
+    ) -> StateEntityDetailsResponseItem {
+        StateEntityDetailsResponseItem::new(
+            address.into(),
+            None,
+            None,
+            EntityMetadataCollection::empty(),
+            StateEntityDetailsResponseItemDetails::Component(
+                StateEntityDetailsResponseComponentDetails {
+                    role_assignments: None,
+                    state: Some(serde_json::to_string(state).unwrap()),
+                },
+            ),
+        )
+    }
+
+    /// Responses of the access controllers details lookup.
+    fn mock_access_controllers_responses(
+        states: Vec<(AccessControllerAddress, AccessControllerFieldStateValue)>,
+    ) -> Vec<MockNetworkingDriverResponse> {
+        vec![
+            mock_gateway_status_response(),
+            MockNetworkingDriverResponse::new_success(
+                StateEntityDetailsResponse::new(
+                    LedgerState::sample(),
+                    states.iter().map(|(address, state)| {
+                        mock_component_details_response(*address, state)
+                    }),
+                ),
+            ),
+        ]
+    }
+
+    /// Responses of the third-party deposits lookup of a single account.
+    fn mock_third_party_deposits_responses(
+        account_address: AccountAddress,
+        default_deposit_rule: AccountDefaultDepositRule,
+        resource_preferences: Vec<AccountResourcePreference>,
+        authorized_depositors: Vec<AccountAuthorizedDepositor>,
+    ) -> Vec<MockNetworkingDriverResponse> {
+        let mut responses = vec![mock_deposit_rules_response(vec![(
+            account_address,
+            default_deposit_rule,
+        )])];
+        responses.extend(mock_deposit_lists_responses(
+            resource_preferences,
+            authorized_depositors,
+        ));
+        responses
+    }
+
+    /// Response of the batched lookup of the default deposit rules of
+    /// accounts.
+    fn mock_deposit_rules_response(
+        rules: Vec<(AccountAddress, AccountDefaultDepositRule)>,
+    ) -> MockNetworkingDriverResponse {
+        MockNetworkingDriverResponse::new_success(
+            StateEntityDetailsResponse::new(
+                LedgerState::sample(),
+                rules.into_iter().map(|(account_address, rule)| {
+                    mock_component_details_response(
+                        account_address,
+                        &AccountFieldStateValue {
+                            default_deposit_rule: rule,
+                        },
+                    )
+                }),
+            ),
+        )
+    }
+
+    /// Responses of the lookup of the resource preferences and authorized
+    /// depositors of a single account.
+    fn mock_deposit_lists_responses(
+        resource_preferences: Vec<AccountResourcePreference>,
+        authorized_depositors: Vec<AccountAuthorizedDepositor>,
+    ) -> Vec<MockNetworkingDriverResponse> {
+        vec![
+            MockNetworkingDriverResponse::new_success(PageResponse::new(
+                LedgerState::sample(),
+                resource_preferences.len() as u64,
+                None,
+                resource_preferences,
+            )),
+            MockNetworkingDriverResponse::new_success(PageResponse::new(
+                LedgerState::sample(),
+                authorized_depositors.len() as u64,
+                None,
+                authorized_depositors,
+            )),
+        ]
     }
 }
@@ -1,14 +1,31 @@
 use crate::prelude::*;
 
-/// The report that gathers the different actions performed on profile after sync completes.
+/// The report that gathers the differences found between the entities in
+/// profile and their state on ledger, and the different actions performed on
+/// profile after sync completes.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct EntitySyncOutcome {
+    /// Every difference found between profile and ledger, in the order of
+    /// the entities in profile.
+    pub changes: Vec<EntitySyncChange>,
+
+    /// The kinds of actions performed on profile, always empty if the sync
+    /// ran in `EntitySyncMode::Preview`.
     pub actions_performed: IndexSet<EntitySyncActionPerformed>,
+
+    /// The entities which could not be fully synced, e.g. since a request
+    /// to the Gateway failed. All other differences are still found and
+    /// - in `EntitySyncMode::Apply` - applied.
+    pub failures: Vec<EntitySyncFailure>,
 }
 
 impl HasSampleValues for EntitySyncOutcome {
     fn sample() -> Self {
-        Self::new(IndexSet::just(EntitySyncActionPerformed::sample()))
+        Self::new(
+            vec![EntitySyncChange::sample()],
+            IndexSet::just(EntitySyncActionPerformed::sample()),
+            vec![EntitySyncFailure::sample()],
+        )
     }
 
     fn sample_other() -> Self {
@@ -17,22 +34,147 @@ impl HasSampleValues for EntitySyncOutcome {
 }
 
 impl EntitySyncOutcome {
-    pub fn new(actions: IndexSet<EntitySyncActionPerformed>) -> Self {
+    pub fn new(
+        changes: impl IntoIterator<Item = EntitySyncChange>,
+        actions: IndexSet<EntitySyncActionPerformed>,
+        failures: impl IntoIterator<Item = EntitySyncFailure>,
+    ) -> Self {
         Self {
+            changes: changes.into_iter().collect(),
             actions_performed: actions,
+            failures: failures.into_iter().collect(),
         }
     }
 
     pub fn no_action() -> Self {
-        Self::new(IndexSet::new())
+        Self::new([], IndexSet::new(), [])
     }
 }
 
-/// An action that implies a synchronisation needs to be applied in an entity in the local profile.
+/// Whether the differences found when syncing entities with their state on
+/// ledger should be written to profile or only be reported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, std::hash::Hash)]
+pub enum EntitySyncMode {
+    /// Updates profile with the state on ledger where possible.
+    Apply,
+
+    /// Only reports the differences, leaving profile untouched.
+    Preview,
+}
+
+/// A difference between an entity in profile and its state on ledger.
 #[derive(Clone, Debug, PartialEq, Eq, std::hash::Hash)]
-pub(crate) enum EntitySyncAction {
-    ToTombstone(AccountAddress),
-    ToSecurify(AddressOfAccountOrPersona, AccessControllerAddress),
+pub enum EntitySyncChange {
+    /// The account has been deleted on ledger, applied by tombstoning it.
+    AccountDeleted { account_address: AccountAddress },
+
+    /// The provisional shield of the entity has been applied on ledger,
+    /// applied by marking the entity as securified.
+    EntitySecurified {
+        entity_address: AddressOfAccountOrPersona,
+        access_controller_address: AccessControllerAddress,
+    },
+
+    /// The entity is controlled on ledger by an access controller this
+    /// wallet did not set up - e.g. the shield was applied or changed from
+    /// another wallet - so the factor instances securing it are unknown.
+    ///
+    /// Never applied, the user needs to be informed.
+    ShieldChangedElsewhere {
+        entity_address: AddressOfAccountOrPersona,
+        access_controller_address: AccessControllerAddress,
+    },
+
+    /// A recovery using the recovery role has been initiated on the access
+    /// controller of the entity, which can be confirmed without the
+    /// confirmation role after `allow_timed_recovery_after`.
+    ///
+    /// Never applied, the user needs to be informed.
+    TimedRecoveryInProgress {
+        entity_address: AddressOfAccountOrPersona,
+        access_controller_address: AccessControllerAddress,
+        allow_timed_recovery_after: Instant,
+    },
+
+    /// The on ledger settings of the account differ from the ones in
+    /// profile, applied by replacing the settings in profile with
+    /// `on_ledger_settings`.
+    OnLedgerSettingsChanged {
+        account_address: AccountAddress,
+        on_ledger_settings: OnLedgerSettings,
+    },
+}
+
+impl EntitySyncChange {
+    /// The address of the entity this change concerns.
+    pub fn entity_address(&self) -> AddressOfAccountOrPersona {
+        match self {
+            Self::AccountDeleted { account_address }
+            | Self::OnLedgerSettingsChanged {
+                account_address, ..
+            } => AddressOfAccountOrPersona::from(*account_address),
+            Self::EntitySecurified { entity_address, .. }
+            | Self::ShieldChangedElsewhere { entity_address, .. }
+            | Self::TimedRecoveryInProgress { entity_address, .. } => {
+                *entity_address
+            }
+        }
+    }
+}
+
+impl HasSampleValues for EntitySyncChange {
+    fn sample() -> Self {
+        Self::AccountDeleted {
+            account_address: AccountAddress::sample(),
+        }
+    }
+
+    fn sample_other() -> Self {
+        Self::TimedRecoveryInProgress {
+            entity_address: AddressOfAccountOrPersona::sample_other(),
+            access_controller_address: AccessControllerAddress::sample(),
+            allow_timed_recovery_after: Instant::sample(),
+        }
+    }
+}
+
+/// An entity which could not be fully synced with its state on ledger.
+#[derive(Clone, Debug, PartialEq, Eq, std::hash::Hash)]
+pub struct EntitySyncFailure {
+    pub entity_address: AddressOfAccountOrPersona,
+
+    /// Description of the error which made the sync of the entity fail.
+    pub error_message: String,
+}
+
+impl EntitySyncFailure {
+    pub fn new(
+        entity_address: impl Into<AddressOfAccountOrPersona>,
+        error: CommonError,
+    ) -> Self {
+        Self {
+            entity_address: entity_address.into(),
+            error_message: error.to_string(),
+        }
+    }
+}
+
+impl HasSampleValues for EntitySyncFailure {
+    fn sample() -> Self {
+        Self::new(
+            AccountAddress::sample(),
+            CommonError::NetworkResponseBadCode { code: 500 },
+        )
+    }
+
+    fn sample_other() -> Self {
+        Self::new(
+            AddressOfAccountOrPersona::sample_other(),
+            CommonError::GWMissingResponseItem {
+                item: "StateEntityDetailsResponseItem".to_owned(),
+            },
+        )
+    }
 }
 
 /// The kinds of sync actions performed on entities in profile.
@@ -40,6 +182,7 @@ pub(crate) enum EntitySyncAction {
 pub enum EntitySyncActionPerformed {
     SomeEntitiesTombstoned,
     SomeEntitiesSecurified,
+    SomeAccountsOnLedgerSettingsUpdated,
 }
 
 impl HasSampleValues for EntitySyncActionPerformed {
@@ -72,7 +215,76 @@ mod entity_sync_outcome_tests {
 
     #[test]
     fn test_no_action() {
-        assert_eq!(EntitySyncOutcome::no_action().actions_performed.len(), 0)
+        let sut = EntitySyncOutcome::no_action();
+        assert_eq!(sut.actions_performed.len(), 0);
+        assert_eq!(sut.changes.len(), 0);
+        assert_eq!(sut.failures.len(), 0);
+    }
+}
+
+#[cfg(test)]
+mod entity_sync_change_tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = EntitySyncChange;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn entity_address() {
+        assert_eq!(
+            SUT::sample().entity_address(),
+            AddressOfAccountOrPersona::from(AccountAddress::sample())
+        );
+        assert_eq!(
+            SUT::sample_other().entity_address(),
+            AddressOfAccountOrPersona::sample_other()
+        );
+        assert_eq!(
+            SUT::OnLedgerSettingsChanged {
+                account_address: AccountAddress::sample_other(),
+                on_ledger_settings: OnLedgerSettings::sample(),
+            }
+            .entity_address(),
+            AddressOfAccountOrPersona::from(AccountAddress::sample_other())
+        );
+    }
+}
+
+#[cfg(test)]
+mod entity_sync_failure_tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = EntitySyncFailure;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn error_message() {
+        assert_eq!(
+            SUT::sample().error_message,
+            CommonError::NetworkResponseBadCode { code: 500 }.to_string()
+        );
     }
 }
 
@@ -1,3 +1,5 @@
 mod entity_sync_outcome;
+mod portfolio_valuation;
 
 pub use entity_sync_outcome::*;
+pub use portfolio_valuation::*;
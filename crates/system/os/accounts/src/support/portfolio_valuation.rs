@@ -0,0 +1,320 @@
+use crate::prelude::*;
+
+/// The worth of a fungible held by an account.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FungibleValuation {
+    pub holding: FungibleHolding,
+
+    /// `None` if the holding could not be priced, i.e. if the price of the
+    /// resource - or of any resource an LSU or pool unit can be redeemed
+    /// for - is unknown.
+    pub worth: Option<Decimal192>,
+}
+
+impl FungibleValuation {
+    /// Values the resources `holding` is made up of, or can be redeemed for,
+    /// using `prices`.
+    pub fn new(
+        holding: FungibleHolding,
+        prices: &HashMap<ResourceAddress, Decimal192>,
+    ) -> Self {
+        let worth = holding.underlying.as_ref().and_then(|underlying| {
+            underlying
+                .iter()
+                .try_fold(Decimal192::zero(), |acc, resource| {
+                    prices
+                        .get(&resource.resource_address)
+                        .map(|price| acc + resource.amount * *price)
+                })
+        });
+        Self { holding, worth }
+    }
+}
+
+/// The worth of a non fungible held by an account.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NonFungibleValuation {
+    pub global_id: NonFungibleGlobalId,
+
+    /// `None` if the non fungible could not be priced.
+    pub worth: Option<Decimal192>,
+}
+
+impl NonFungibleValuation {
+    /// Values stake claims at the price of their claimable XRD, using
+    /// `fungible_prices`, and any other non fungible using
+    /// `non_fungible_prices`.
+    pub fn new(
+        holding: NonFungibleHolding,
+        fungible_prices: &HashMap<ResourceAddress, Decimal192>,
+        non_fungible_prices: &HashMap<NonFungibleGlobalId, Decimal192>,
+    ) -> Self {
+        let worth = match &holding.claimable {
+            Some(claimable) => fungible_prices
+                .get(&claimable.resource_address)
+                .map(|price| claimable.amount * *price),
+            None => non_fungible_prices.get(&holding.global_id).cloned(),
+        };
+        Self {
+            global_id: holding.global_id,
+            worth,
+        }
+    }
+}
+
+/// The worth of all assets held by an account.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AccountValuation {
+    pub account_address: AccountAddress,
+
+    /// The sum of the worth of all assets which could be priced.
+    pub worth: Decimal192,
+
+    pub fungibles: Vec<FungibleValuation>,
+    pub non_fungibles: Vec<NonFungibleValuation>,
+}
+
+impl AccountValuation {
+    pub fn new(
+        holdings: AccountHoldings,
+        fungible_prices: &HashMap<ResourceAddress, Decimal192>,
+        non_fungible_prices: &HashMap<NonFungibleGlobalId, Decimal192>,
+    ) -> Self {
+        let fungibles = holdings
+            .fungibles
+            .into_iter()
+            .map(|holding| FungibleValuation::new(holding, fungible_prices))
+            .collect_vec();
+        let non_fungibles = holdings
+            .non_fungibles
+            .into_iter()
+            .map(|holding| {
+                NonFungibleValuation::new(
+                    holding,
+                    fungible_prices,
+                    non_fungible_prices,
+                )
+            })
+            .collect_vec();
+        let worth = fungibles
+            .iter()
+            .filter_map(|fungible| fungible.worth)
+            .chain(non_fungibles.iter().filter_map(|nft| nft.worth))
+            .fold(Decimal192::zero(), |acc, worth| acc + worth);
+        Self {
+            account_address: holdings.account_address,
+            worth,
+            fungibles,
+            non_fungibles,
+        }
+    }
+}
+
+/// The worth of the assets of many accounts, in `currency`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PortfolioValuation {
+    pub currency: FiatCurrency,
+
+    /// The sum of the worth of all accounts.
+    pub worth: Decimal192,
+
+    pub accounts: Vec<AccountValuation>,
+
+    /// The resources held by any of the accounts which could not be priced,
+    /// and thus are not part of `worth`. For non fungibles this is the
+    /// resource of the collection if any non fungible in it could not be
+    /// priced.
+    pub unpriced_resources: Vec<ResourceAddress>,
+}
+
+impl PortfolioValuation {
+    pub fn new(
+        currency: FiatCurrency,
+        holdings: impl IntoIterator<Item = AccountHoldings>,
+        fungible_prices: &HashMap<ResourceAddress, Decimal192>,
+        non_fungible_prices: &HashMap<NonFungibleGlobalId, Decimal192>,
+    ) -> Self {
+        let accounts = holdings
+            .into_iter()
+            .map(|holdings| {
+                AccountValuation::new(
+                    holdings,
+                    fungible_prices,
+                    non_fungible_prices,
+                )
+            })
+            .collect_vec();
+        let worth = accounts
+            .iter()
+            .fold(Decimal192::zero(), |acc, account| acc + account.worth);
+        let unpriced_resources = accounts
+            .iter()
+            .flat_map(|account| {
+                account
+                    .fungibles
+                    .iter()
+                    .filter(|fungible| fungible.worth.is_none())
+                    .map(|fungible| fungible.holding.resource_address)
+                    .chain(
+                        account
+                            .non_fungibles
+                            .iter()
+                            .filter(|nft| nft.worth.is_none())
+                            .map(|nft| nft.global_id.resource_address),
+                    )
+            })
+            .unique()
+            .collect_vec();
+        Self {
+            currency,
+            worth,
+            accounts,
+            unpriced_resources,
+        }
+    }
+}
+
+impl HasSampleValues for PortfolioValuation {
+    fn sample() -> Self {
+        Self::new(
+            FiatCurrency::USD,
+            [AccountHoldings::sample(), AccountHoldings::sample_other()],
+            &HashMap::from([(
+                ResourceAddress::sample_stokenet_xrd(),
+                Decimal192::from(2),
+            )]),
+            &HashMap::new(),
+        )
+    }
+
+    fn sample_other() -> Self {
+        Self::new(FiatCurrency::SEK, [], &HashMap::new(), &HashMap::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = PortfolioValuation;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn empty() {
+        let sut = SUT::sample_other();
+        assert_eq!(sut.worth, Decimal192::zero());
+        assert!(sut.accounts.is_empty());
+        assert!(sut.unpriced_resources.is_empty());
+    }
+
+    #[test]
+    fn pool_unit_with_unpriced_underlying_is_unpriced() {
+        // `AccountHoldings::sample` holds 100 XRD, a pool unit redeemable
+        // for 20 XRD and 50 GUM, and an NFT - but only XRD is priced.
+        let sut = SUT::sample();
+
+        let account = &sut.accounts[0];
+        assert_eq!(account.worth, Decimal192::from(200));
+        assert_eq!(account.fungibles[0].worth, Some(Decimal192::from(200)));
+        assert_eq!(account.fungibles[1].worth, None);
+        assert_eq!(account.non_fungibles[0].worth, None);
+
+        assert_eq!(
+            sut.unpriced_resources,
+            vec![
+                ResourceAddress::sample_stokenet_candy(),
+                ResourceAddress::sample_stokenet_nft_gc_membership(),
+            ]
+        );
+    }
+
+    #[test]
+    fn worth_sums_all_priced_assets_of_all_accounts() {
+        let holdings = AccountHoldings::sample();
+        let nft = holdings.non_fungibles[0].global_id.clone();
+        let sut = SUT::new(
+            FiatCurrency::USD,
+            [holdings, AccountHoldings::sample_other()],
+            &HashMap::from([
+                (ResourceAddress::sample_stokenet_xrd(), Decimal192::from(2)),
+                (
+                    ResourceAddress::sample_stokenet_gum(),
+                    Decimal192::from_str("0.1").unwrap(),
+                ),
+            ]),
+            &HashMap::from([(nft, Decimal192::from(42))]),
+        );
+
+        // 100 XRD * 2 + (20 XRD * 2 + 50 GUM * 0.1) + 42
+        assert_eq!(sut.accounts[0].worth, Decimal192::from(287));
+        // 100 XRD * 2
+        assert_eq!(sut.accounts[1].worth, Decimal192::from(200));
+        assert_eq!(sut.worth, Decimal192::from(487));
+        assert!(sut.unpriced_resources.is_empty());
+    }
+
+    #[test]
+    fn stake_claim_is_worth_its_claimable_xrd() {
+        let stake_claim = NonFungibleHolding::sample_other();
+        let xrd_prices = HashMap::from([(
+            ResourceAddress::sample_stokenet_xrd(),
+            Decimal192::from(2),
+        )]);
+        // Never priced as a non fungible.
+        let non_fungible_prices = HashMap::from([(
+            stake_claim.global_id.clone(),
+            Decimal192::from(42),
+        )]);
+
+        let sut = NonFungibleValuation::new(
+            stake_claim.clone(),
+            &xrd_prices,
+            &non_fungible_prices,
+        );
+        // 150 XRD * 2
+        assert_eq!(sut.worth, Some(Decimal192::from(300)));
+
+        let sut = NonFungibleValuation::new(
+            stake_claim,
+            &HashMap::new(),
+            &non_fungible_prices,
+        );
+        assert_eq!(sut.worth, None);
+    }
+
+    #[test]
+    fn holding_with_unknown_redemption_value_is_unpriced() {
+        let holding = FungibleHolding::new(
+            ResourceAddress::sample_stokenet_candy(),
+            Decimal192::from(10),
+            Some(&NativeResourceDetails::OneResourcePoolUnit(
+                NativeResourcePoolUnitValue::new(
+                    PoolAddress::sample_stokenet_single_pool(),
+                    [NativeResourceRedemptionValueItem::new(
+                        ResourceAddress::sample_stokenet_xrd(),
+                        None,
+                    )],
+                ),
+            )),
+        );
+        let sut = FungibleValuation::new(
+            holding,
+            &HashMap::from([(
+                ResourceAddress::sample_stokenet_xrd(),
+                Decimal192::from(2),
+            )]),
+        );
+        assert_eq!(sut.worth, None);
+    }
+}
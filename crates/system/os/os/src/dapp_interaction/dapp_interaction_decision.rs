@@ -0,0 +1,1003 @@
+use crate::prelude::*;
+
+/// What the user decided for the steps of a `DappInteractionPlan::Request`,
+/// used to build the response to the dApp and to update the `AuthorizedDapp`.
+///
+/// Decisions left as `None` for ongoing items are filled in from what the
+/// user has already shared with the dApp, which is only possible if the plan
+/// did not contain a step for said item.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DappInteractionDecision {
+    /// The Persona the user chose to log in with, or whose data is shared in
+    /// an unauthorized request. Can be left as `None` for `usePersona`
+    /// requests.
+    pub persona: Option<IdentityAddress>,
+
+    /// Signature of the login challenge, required if the dApp sent one.
+    pub auth_proof: Option<WalletToDappInteractionAuthProof>,
+
+    pub ongoing_accounts: Option<DappInteractionAccountsDecision>,
+    pub ongoing_persona_data: Option<DappInteractionPersonaDataDecision>,
+    pub one_time_accounts: Option<DappInteractionAccountsDecision>,
+    pub one_time_persona_data: Option<DappInteractionPersonaDataDecision>,
+
+    /// Signatures of the proof of ownership challenge, required if the dApp
+    /// requested proof of ownership.
+    pub proof_of_ownership:
+        Option<WalletToDappInteractionProofOfOwnershipRequestResponseItem>,
+}
+
+/// The accounts the user chose to share with a dApp.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DappInteractionAccountsDecision {
+    pub accounts: Vec<AccountAddress>,
+
+    /// Signatures of the accounts request challenge, required if the dApp
+    /// sent one.
+    pub proofs: Option<Vec<WalletToDappInteractionAccountProof>>,
+}
+
+impl DappInteractionAccountsDecision {
+    pub fn new(
+        accounts: impl IntoIterator<Item = AccountAddress>,
+        proofs: impl Into<Option<Vec<WalletToDappInteractionAccountProof>>>,
+    ) -> Self {
+        Self {
+            accounts: accounts.into_iter().collect(),
+            proofs: proofs.into(),
+        }
+    }
+}
+
+/// The Persona data entries the user chose to share with a dApp.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DappInteractionPersonaDataDecision {
+    pub name: Option<PersonaDataEntryID>,
+    pub email_addresses: Vec<PersonaDataEntryID>,
+    pub phone_numbers: Vec<PersonaDataEntryID>,
+    pub postal_addresses: Vec<PersonaDataEntryID>,
+    pub dates_of_birth: Vec<PersonaDataEntryID>,
+    pub companies: Vec<PersonaDataEntryID>,
+}
+
+impl DappInteractionPersonaDataDecision {
+    pub fn new(
+        name: impl Into<Option<PersonaDataEntryID>>,
+        email_addresses: impl IntoIterator<Item = PersonaDataEntryID>,
+        phone_numbers: impl IntoIterator<Item = PersonaDataEntryID>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            email_addresses: email_addresses.into_iter().collect(),
+            phone_numbers: phone_numbers.into_iter().collect(),
+            postal_addresses: Vec::new(),
+            dates_of_birth: Vec::new(),
+            companies: Vec::new(),
+        }
+    }
+
+    /// Returns `self` with the chosen postal addresses, dates of birth and
+    /// companies replaced by the ones passed.
+    pub fn with_extended_entries(
+        self,
+        postal_addresses: impl IntoIterator<Item = PersonaDataEntryID>,
+        dates_of_birth: impl IntoIterator<Item = PersonaDataEntryID>,
+        companies: impl IntoIterator<Item = PersonaDataEntryID>,
+    ) -> Self {
+        Self {
+            postal_addresses: postal_addresses.into_iter().collect(),
+            dates_of_birth: dates_of_birth.into_iter().collect(),
+            companies: companies.into_iter().collect(),
+            ..self
+        }
+    }
+}
+
+impl From<&SharedPersonaData> for DappInteractionPersonaDataDecision {
+    fn from(value: &SharedPersonaData) -> Self {
+        let ids = |shared: &Option<
+            SharedToDappWithPersonaIDsOfPersonaDataEntries,
+        >| {
+            shared
+                .as_ref()
+                .map(|s| s.ids.iter().collect_vec())
+                .unwrap_or_default()
+        };
+        Self::new(
+            value.name,
+            ids(&value.email_addresses),
+            ids(&value.phone_numbers),
+        )
+        .with_extended_entries(
+            ids(&value.postal_addresses),
+            ids(&value.dates_of_birth),
+            ids(&value.companies),
+        )
+    }
+}
+
+fn not_fulfilling(reason: impl AsRef<str>) -> CommonError {
+    CommonError::DappInteractionDecisionDoesNotFulfillRequest {
+        reason: reason.as_ref().to_owned(),
+    }
+}
+
+impl DappInteractionDecision {
+    /// Validates this decision against `interaction` and `network` - the
+    /// current network of the active Profile - and builds the response to
+    /// send to the dApp, and for authorized requests the updated
+    /// `AuthorizedDapp` to persist.
+    pub(crate) fn resolve(
+        &self,
+        interaction: &DappToWalletInteraction,
+        network: &ProfileNetwork,
+        now: Timestamp,
+    ) -> Result<(WalletToDappInteractionResponse, Option<AuthorizedDapp>)> {
+        if interaction.metadata.network_id != network.id {
+            return Err(CommonError::NetworkDiscrepancy {
+                expected: network.id.to_string(),
+                actual: interaction.metadata.network_id.to_string(),
+            });
+        }
+
+        let (items, authorized_dapp) = match &interaction.items {
+            DappToWalletInteractionItems::UnauthorizedRequest(items) => (
+                WalletToDappInteractionResponseItems::UnauthorizedRequest(
+                    self.unauthorized_response_items(items, network)?,
+                ),
+                None,
+            ),
+            DappToWalletInteractionItems::AuthorizedRequest(items) => {
+                let (response_items, authorized_dapp) = self
+                    .authorized_response_items(
+                        items,
+                        network,
+                        &interaction.metadata.dapp_definition_address,
+                        now,
+                    )?;
+                (
+                    WalletToDappInteractionResponseItems::AuthorizedRequest(
+                        response_items,
+                    ),
+                    Some(authorized_dapp),
+                )
+            }
+            _ => return Err(CommonError::DappInteractionIsNotARequest),
+        };
+
+        let response = WalletToDappInteractionResponse::Success(
+            WalletToDappInteractionSuccessResponse::new(
+                interaction.interaction_id.clone(),
+                items,
+            ),
+        );
+        Ok((response, authorized_dapp))
+    }
+
+    fn unauthorized_response_items(
+        &self,
+        items: &DappToWalletInteractionUnauthorizedRequestItems,
+        network: &ProfileNetwork,
+    ) -> Result<WalletToDappInteractionUnauthorizedRequestResponseItems> {
+        let one_time_accounts = items
+            .one_time_accounts
+            .as_ref()
+            .map(|request| {
+                accounts_response_item(
+                    request,
+                    self.one_time_accounts.as_ref(),
+                    network,
+                )
+            })
+            .transpose()?;
+
+        let one_time_persona_data = items
+            .one_time_persona_data
+            .as_ref()
+            .map(|request| {
+                persona_data_response_item(
+                    request,
+                    self.one_time_persona_data.as_ref(),
+                    &non_hidden_persona(self.persona, network)?,
+                )
+            })
+            .transpose()?;
+
+        Ok(
+            WalletToDappInteractionUnauthorizedRequestResponseItems::new(
+                one_time_accounts,
+                one_time_persona_data,
+            ),
+        )
+    }
+
+    fn authorized_response_items(
+        &self,
+        items: &DappToWalletInteractionAuthorizedRequestItems,
+        network: &ProfileNetwork,
+        dapp_definition_address: &DappDefinitionAddress,
+        now: Timestamp,
+    ) -> Result<(
+        WalletToDappInteractionAuthorizedRequestResponseItems,
+        AuthorizedDapp,
+    )> {
+        let persona = match &items.auth {
+            DappToWalletInteractionAuthRequestItem::UsePersona(use_persona) => {
+                if self
+                    .persona
+                    .is_some_and(|p| p != use_persona.identity_address)
+                {
+                    return Err(not_fulfilling(
+                        "Chosen Persona differs from the requested one",
+                    ));
+                }
+                non_hidden_persona(Some(use_persona.identity_address), network)?
+            }
+            _ => non_hidden_persona(self.persona, network)?,
+        };
+
+        let interaction_persona = DappWalletInteractionPersona::new(
+            persona.address,
+            persona.display_name.value(),
+        );
+        let auth = match &items.auth {
+            DappToWalletInteractionAuthRequestItem::UsePersona(_) => {
+                WalletToDappInteractionAuthRequestResponseItem::UsePersona(
+                    WalletToDappInteractionAuthUsePersonaRequestResponseItem::new(
+                        interaction_persona,
+                    ),
+                )
+            }
+            DappToWalletInteractionAuthRequestItem::LoginWithoutChallenge => {
+                WalletToDappInteractionAuthRequestResponseItem::LoginWithoutChallenge(
+                    WalletToDappInteractionAuthLoginWithoutChallengeRequestResponseItem::new(
+                        interaction_persona,
+                    ),
+                )
+            }
+            DappToWalletInteractionAuthRequestItem::LoginWithChallenge(
+                login,
+            ) => {
+                let proof = self.auth_proof.clone().ok_or(not_fulfilling(
+                    "Missing proof of Persona ownership",
+                ))?;
+                WalletToDappInteractionAuthRequestResponseItem::LoginWithChallenge(
+                    WalletToDappInteractionAuthLoginWithChallengeRequestResponseItem::new(
+                        interaction_persona,
+                        login.challenge.clone(),
+                        proof,
+                    ),
+                )
+            }
+        };
+
+        let mut authorized_dapp = network
+            .authorized_dapps
+            .get_id(dapp_definition_address)
+            .cloned()
+            .unwrap_or(AuthorizedDapp::new(
+                network.id,
+                *dapp_definition_address,
+                None,
+                ReferencesToAuthorizedPersonas::new(),
+                AuthorizedDappPreferences::default(),
+            ));
+        let mut authorized_persona = authorized_dapp
+            .references_to_authorized_personas
+            .get_id(persona.address)
+            .cloned()
+            .unwrap_or(AuthorizedPersonaSimple::new(
+                persona.address,
+                now,
+                None,
+                SharedPersonaData::default(),
+            ));
+        authorized_persona.last_login = now;
+
+        let reset = items.reset.clone().unwrap_or(
+            DappToWalletInteractionResetRequestItem::new(false, false),
+        );
+        if reset.accounts {
+            authorized_persona.shared_accounts = None;
+        }
+        if reset.persona_data {
+            authorized_persona.shared_persona_data =
+                SharedPersonaData::default();
+        }
+
+        let ongoing_accounts = items
+            .ongoing_accounts
+            .as_ref()
+            .map(|request| {
+                let already_shared =
+                    authorized_persona.shared_accounts.as_ref().map(|s| {
+                        DappInteractionAccountsDecision::new(
+                            s.ids.clone(),
+                            None,
+                        )
+                    });
+                let decision = self.ongoing_accounts.clone().or(already_shared);
+                let item = accounts_response_item(
+                    request,
+                    decision.as_ref(),
+                    network,
+                )?;
+                authorized_persona.shared_accounts =
+                    Some(SharedToDappWithPersonaAccountAddresses::new(
+                        request.number_of_accounts,
+                        item.accounts.iter().map(|a| a.address),
+                    ));
+                Ok::<_, CommonError>(item)
+            })
+            .transpose()?;
+
+        let ongoing_persona_data = items
+            .ongoing_persona_data
+            .as_ref()
+            .map(|request| {
+                let already_shared = DappInteractionPersonaDataDecision::from(
+                    &authorized_persona.shared_persona_data,
+                );
+                let decision =
+                    self.ongoing_persona_data.clone().unwrap_or(already_shared);
+                let item = persona_data_response_item(
+                    request,
+                    Some(&decision),
+                    &persona,
+                )?;
+                authorized_persona.shared_persona_data =
+                    shared_persona_data(request, &decision);
+                Ok::<_, CommonError>(item)
+            })
+            .transpose()?;
+
+        let one_time = self.unauthorized_response_items(
+            &DappToWalletInteractionUnauthorizedRequestItems::new(
+                items.one_time_accounts.clone(),
+                items.one_time_persona_data.clone(),
+            ),
+            network,
+        )?;
+
+        let proof_of_ownership = if items.proof_of_ownership.is_some() {
+            Some(
+                self.proof_of_ownership
+                    .clone()
+                    .ok_or(not_fulfilling("Missing proof of ownership"))?,
+            )
+        } else {
+            None
+        };
+
+        authorized_dapp
+            .references_to_authorized_personas
+            .insert(authorized_persona);
+
+        let response_items =
+            WalletToDappInteractionAuthorizedRequestResponseItems::new(
+                auth,
+                ongoing_accounts,
+                ongoing_persona_data,
+                one_time.one_time_accounts,
+                one_time.one_time_persona_data,
+                proof_of_ownership,
+            );
+        Ok((response_items, authorized_dapp))
+    }
+}
+
+fn non_hidden_persona(
+    address: Option<IdentityAddress>,
+    network: &ProfileNetwork,
+) -> Result<Persona> {
+    let address = address.ok_or(not_fulfilling("No Persona was chosen"))?;
+    network
+        .personas_non_hidden()
+        .get_id(address)
+        .cloned()
+        .ok_or(CommonError::UnknownPersona)
+}
+
+fn accounts_response_item(
+    request: &DappToWalletInteractionAccountsRequestItem,
+    decision: Option<&DappInteractionAccountsDecision>,
+    network: &ProfileNetwork,
+) -> Result<WalletToDappInteractionAccountsRequestResponseItem> {
+    let decision = decision.ok_or(not_fulfilling("No accounts were chosen"))?;
+    if !request
+        .number_of_accounts
+        .is_fulfilled_by_quantity(decision.accounts.len())
+    {
+        return Err(not_fulfilling(format!(
+            "Requested {} accounts, but got {}",
+            request.number_of_accounts,
+            decision.accounts.len()
+        )));
+    }
+    if request.challenge.is_some() && decision.proofs.is_none() {
+        return Err(not_fulfilling("Missing proof of account ownership"));
+    }
+
+    let non_hidden_accounts = network.accounts_non_hidden();
+    let accounts = decision
+        .accounts
+        .iter()
+        .map(|address| {
+            non_hidden_accounts
+                .get_id(address)
+                .map(|account| {
+                    WalletInteractionWalletAccount::new(
+                        account.address,
+                        account.display_name.clone(),
+                        account.appearance_id,
+                    )
+                })
+                .ok_or(CommonError::UnknownAccount)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(WalletToDappInteractionAccountsRequestResponseItem::new(
+        accounts,
+        request.challenge.clone(),
+        decision.proofs.clone(),
+    ))
+}
+
+fn persona_data_response_item(
+    request: &DappToWalletInteractionPersonaDataRequestItem,
+    decision: Option<&DappInteractionPersonaDataDecision>,
+    persona: &Persona,
+) -> Result<WalletToDappInteractionPersonaDataRequestResponseItem> {
+    let decision =
+        decision.ok_or(not_fulfilling("No Persona data was chosen"))?;
+    let full = &persona.persona_data;
+
+    let name = if request.is_requesting_name == Some(true) {
+        let name = full
+            .name
+            .as_ref()
+            .filter(|name| decision.name == Some(name.id))
+            .ok_or(not_fulfilling("Missing name"))?;
+        Some(name.value.clone())
+    } else {
+        None
+    };
+
+    let email_addresses = request
+        .number_of_requested_email_addresses
+        .map(|requested| {
+            pick_entries(
+                "email addresses",
+                requested,
+                &decision.email_addresses,
+                &full.email_addresses,
+            )
+        })
+        .transpose()?;
+
+    let phone_numbers = request
+        .number_of_requested_phone_numbers
+        .map(|requested| {
+            pick_entries(
+                "phone numbers",
+                requested,
+                &decision.phone_numbers,
+                &full.phone_numbers,
+            )
+        })
+        .transpose()?;
+
+    let postal_addresses = request
+        .number_of_requested_postal_addresses
+        .map(|requested| {
+            pick_entries(
+                "postal addresses",
+                requested,
+                &decision.postal_addresses,
+                &full.postal_addresses,
+            )
+        })
+        .transpose()?;
+
+    let dates_of_birth = request
+        .number_of_requested_dates_of_birth
+        .map(|requested| {
+            pick_entries(
+                "dates of birth",
+                requested,
+                &decision.dates_of_birth,
+                &full.dates_of_birth,
+            )
+        })
+        .transpose()?;
+
+    let companies = request
+        .number_of_requested_companies
+        .map(|requested| {
+            pick_entries(
+                "companies",
+                requested,
+                &decision.companies,
+                &full.companies,
+            )
+        })
+        .transpose()?;
+
+    Ok(WalletToDappInteractionPersonaDataRequestResponseItem::new(
+        name,
+        email_addresses,
+        phone_numbers,
+    )
+    .with_extended_entries(postal_addresses, dates_of_birth, companies))
+}
+
+fn pick_entries<E, V>(
+    kind: &str,
+    requested: RequestedQuantity,
+    ids: &[PersonaDataEntryID],
+    full: &IdentifiedVecOf<E>,
+) -> Result<Vec<V>>
+where
+    E: std::fmt::Debug
+        + PartialEq
+        + Eq
+        + Clone
+        + Identifiable<ID = PersonaDataEntryID>
+        + std::ops::Deref<Target = V>,
+    V: Clone,
+{
+    if !requested.is_fulfilled_by_quantity(ids.len()) {
+        return Err(not_fulfilling(format!(
+            "Requested {} {}, but got {}",
+            requested,
+            kind,
+            ids.len()
+        )));
+    }
+    ids.iter()
+        .map(|id| {
+            full.get_id(id).map(|entry| (**entry).clone()).ok_or(
+                CommonError::AuthorizedDappReferencesFieldIDThatDoesNotExist,
+            )
+        })
+        .collect()
+}
+
+/// The `SharedPersonaData` to remember for an ongoing request, `decision`
+/// must already have been validated against `request`.
+fn shared_persona_data(
+    request: &DappToWalletInteractionPersonaDataRequestItem,
+    decision: &DappInteractionPersonaDataDecision,
+) -> SharedPersonaData {
+    let shared = |requested: Option<RequestedQuantity>,
+                  ids: &Vec<PersonaDataEntryID>| {
+        requested.map(|requested| {
+            SharedToDappWithPersonaIDsOfPersonaDataEntries::new(
+                requested,
+                ids.clone(),
+            )
+        })
+    };
+    SharedPersonaData::new(
+        decision
+            .name
+            .filter(|_| request.is_requesting_name == Some(true)),
+        shared(
+            request.number_of_requested_email_addresses,
+            &decision.email_addresses,
+        ),
+        shared(
+            request.number_of_requested_phone_numbers,
+            &decision.phone_numbers,
+        ),
+    )
+    .with_extended_entries(
+        shared(
+            request.number_of_requested_postal_addresses,
+            &decision.postal_addresses,
+        ),
+        shared(
+            request.number_of_requested_dates_of_birth,
+            &decision.dates_of_birth,
+        ),
+        shared(request.number_of_requested_companies, &decision.companies),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = DappInteractionDecision;
+
+    fn network() -> ProfileNetwork {
+        ProfileNetwork::sample_mainnet()
+    }
+
+    fn now() -> Timestamp {
+        Timestamp::parse("2025-01-01T12:00:00Z").unwrap()
+    }
+
+    fn interaction(
+        items: DappToWalletInteractionItems,
+    ) -> DappToWalletInteraction {
+        DappToWalletInteraction::new(
+            WalletInteractionId::sample(),
+            items,
+            DappToWalletInteractionMetadata::new(
+                WalletInteractionVersion::current(),
+                NetworkID::Mainnet,
+                "https://dashboard.radixdlt.com",
+                AuthorizedDapp::sample_mainnet_dashboard()
+                    .dapp_definition_address,
+            ),
+        )
+    }
+
+    fn login_items(
+        accounts: RequestedQuantity,
+    ) -> DappToWalletInteractionItems {
+        DappToWalletInteractionItems::AuthorizedRequest(
+            DappToWalletInteractionAuthorizedRequestItems::new(
+                DappToWalletInteractionAuthRequestItem::LoginWithoutChallenge,
+                None,
+                DappToWalletInteractionAccountsRequestItem::new(accounts, None),
+                DappToWalletInteractionPersonaDataRequestItem::new(
+                    true, None, None,
+                ),
+                None,
+                None,
+                None,
+            ),
+        )
+    }
+
+    fn success_items(
+        response: WalletToDappInteractionResponse,
+    ) -> WalletToDappInteractionResponseItems {
+        match response {
+            WalletToDappInteractionResponse::Success(success) => success.items,
+            _ => panic!("Expected success"),
+        }
+    }
+
+    #[test]
+    fn login_with_new_persona_updates_authorized_dapp() {
+        let persona = Persona::sample_mainnet_ripley();
+        let account = Account::sample_mainnet();
+        let mut network = network();
+        network.personas.insert(persona.clone());
+
+        let sut = SUT {
+            persona: Some(persona.address),
+            ongoing_accounts: Some(DappInteractionAccountsDecision::new(
+                [account.address],
+                None,
+            )),
+            ongoing_persona_data: Some(
+                DappInteractionPersonaDataDecision::new(
+                    persona.persona_data.name.clone().map(|n| n.id),
+                    [],
+                    [],
+                ),
+            ),
+            ..Default::default()
+        };
+
+        let (response, authorized_dapp) = sut
+            .resolve(
+                &interaction(login_items(RequestedQuantity::exactly(1))),
+                &network,
+                now(),
+            )
+            .unwrap();
+
+        let WalletToDappInteractionResponseItems::AuthorizedRequest(items) =
+            success_items(response)
+        else {
+            panic!("Expected authorized request")
+        };
+        assert_eq!(
+            items.ongoing_accounts.unwrap().accounts,
+            vec![WalletInteractionWalletAccount::new(
+                account.address,
+                account.display_name,
+                account.appearance_id
+            )]
+        );
+        assert_eq!(
+            items.ongoing_persona_data.unwrap().name,
+            persona.persona_data.name.clone().map(|n| n.value)
+        );
+
+        let authorized_persona = authorized_dapp
+            .unwrap()
+            .references_to_authorized_personas
+            .get_id(persona.address)
+            .cloned()
+            .unwrap();
+        assert_eq!(authorized_persona.last_login, now());
+        assert_eq!(
+            authorized_persona.shared_accounts,
+            Some(SharedToDappWithPersonaAccountAddresses::new(
+                RequestedQuantity::exactly(1),
+                [account.address]
+            ))
+        );
+        assert_eq!(
+            authorized_persona.shared_persona_data.name,
+            persona.persona_data.name.map(|n| n.id)
+        );
+    }
+
+    #[test]
+    fn use_persona_falls_back_to_already_shared() {
+        let authorized = AuthorizedPersonaSimple::sample_mainnet();
+        let items = DappToWalletInteractionItems::AuthorizedRequest(
+            DappToWalletInteractionAuthorizedRequestItems::new(
+                DappToWalletInteractionAuthRequestItem::UsePersona(
+                    DappToWalletInteractionAuthUsePersonaRequestItem::new(
+                        authorized.identity_address,
+                    ),
+                ),
+                None,
+                DappToWalletInteractionAccountsRequestItem::new(
+                    RequestedQuantity::exactly(2),
+                    None,
+                ),
+                None,
+                None,
+                None,
+                None,
+            ),
+        );
+
+        let (response, _) = SUT::default()
+            .resolve(&interaction(items), &network(), now())
+            .unwrap();
+
+        let WalletToDappInteractionResponseItems::AuthorizedRequest(items) =
+            success_items(response)
+        else {
+            panic!("Expected authorized request")
+        };
+        assert_eq!(
+            items
+                .ongoing_accounts
+                .unwrap()
+                .accounts
+                .into_iter()
+                .map(|a| a.address)
+                .collect_vec(),
+            authorized.shared_accounts.unwrap().ids.items()
+        );
+    }
+
+    #[test]
+    fn too_few_accounts_does_not_fulfill() {
+        let sut = SUT {
+            persona: Some(Persona::sample_mainnet().address),
+            ongoing_accounts: Some(DappInteractionAccountsDecision::new(
+                [Account::sample_mainnet().address],
+                None,
+            )),
+            ..Default::default()
+        };
+        let res = sut.resolve(
+            &interaction(login_items(RequestedQuantity::at_least(2))),
+            &network(),
+            now(),
+        );
+        assert!(matches!(
+            res,
+            Err(
+                CommonError::DappInteractionDecisionDoesNotFulfillRequest { .. }
+            )
+        ));
+    }
+
+    #[test]
+    fn unknown_account_is_err() {
+        let sut = SUT {
+            persona: Some(Persona::sample_mainnet().address),
+            ongoing_accounts: Some(DappInteractionAccountsDecision::new(
+                [Account::sample_stokenet().address],
+                None,
+            )),
+            ..Default::default()
+        };
+        let res = sut.resolve(
+            &interaction(login_items(RequestedQuantity::exactly(1))),
+            &network(),
+            now(),
+        );
+        assert_eq!(res, Err(CommonError::UnknownAccount));
+    }
+
+    #[test]
+    fn login_without_persona_does_not_fulfill() {
+        let res = SUT::default().resolve(
+            &interaction(login_items(RequestedQuantity::exactly(1))),
+            &network(),
+            now(),
+        );
+        assert!(matches!(
+            res,
+            Err(
+                CommonError::DappInteractionDecisionDoesNotFulfillRequest { .. }
+            )
+        ));
+    }
+
+    #[test]
+    fn unauthorized_request_does_not_update_authorized_dapp() {
+        let persona = Persona::sample_mainnet();
+        let sut = SUT {
+            persona: Some(persona.address),
+            one_time_persona_data: Some(
+                DappInteractionPersonaDataDecision::new(
+                    persona.persona_data.name.clone().map(|n| n.id),
+                    [],
+                    [],
+                ),
+            ),
+            ..Default::default()
+        };
+        let items = DappToWalletInteractionItems::UnauthorizedRequest(
+            DappToWalletInteractionUnauthorizedRequestItems::new(
+                None,
+                DappToWalletInteractionPersonaDataRequestItem::new(
+                    true, None, None,
+                ),
+            ),
+        );
+        let (_, authorized_dapp) =
+            sut.resolve(&interaction(items), &network(), now()).unwrap();
+        assert_eq!(authorized_dapp, None);
+    }
+
+    #[test]
+    fn login_sharing_extended_persona_data() {
+        let mut persona = Persona::sample_mainnet_ripley();
+        let postal_address = PersonaDataIdentifiedPostalAddress::sample();
+        let date_of_birth = PersonaDataIdentifiedDateOfBirth::sample();
+        persona.persona_data = persona.persona_data.with_extended_entries(
+            CollectionOfPostalAddresses::new(postal_address.clone()),
+            CollectionOfDatesOfBirth::new(date_of_birth.clone()),
+            CollectionOfCompanies::default(),
+        );
+        let mut network = network();
+        network.personas.insert(persona.clone());
+
+        let sut = SUT {
+            persona: Some(persona.address),
+            ongoing_accounts: Some(DappInteractionAccountsDecision::new(
+                [Account::sample_mainnet().address],
+                None,
+            )),
+            ongoing_persona_data: Some(
+                DappInteractionPersonaDataDecision::new(None, [], [])
+                    .with_extended_entries(
+                        [postal_address.id],
+                        [date_of_birth.id],
+                        [],
+                    ),
+            ),
+            ..Default::default()
+        };
+        let items = DappToWalletInteractionItems::AuthorizedRequest(
+            DappToWalletInteractionAuthorizedRequestItems::new(
+                DappToWalletInteractionAuthRequestItem::LoginWithoutChallenge,
+                None,
+                DappToWalletInteractionAccountsRequestItem::new(
+                    RequestedQuantity::exactly(1),
+                    None,
+                ),
+                DappToWalletInteractionPersonaDataRequestItem::new(
+                    None, None, None,
+                )
+                .with_extended_entries(
+                    RequestedQuantity::exactly(1),
+                    RequestedQuantity::exactly(1),
+                    None,
+                ),
+                None,
+                None,
+                None,
+            ),
+        );
+
+        let (response, authorized_dapp) =
+            sut.resolve(&interaction(items), &network, now()).unwrap();
+
+        let WalletToDappInteractionResponseItems::AuthorizedRequest(items) =
+            success_items(response)
+        else {
+            panic!("Expected authorized request")
+        };
+        let persona_data = items.ongoing_persona_data.unwrap();
+        assert_eq!(
+            persona_data.postal_addresses,
+            Some(vec![postal_address.value.clone()])
+        );
+        assert_eq!(
+            persona_data.dates_of_birth,
+            Some(vec![date_of_birth.value])
+        );
+        assert_eq!(persona_data.companies, None);
+
+        let shared_persona_data = authorized_dapp
+            .unwrap()
+            .references_to_authorized_personas
+            .get_id(persona.address)
+            .cloned()
+            .unwrap()
+            .shared_persona_data;
+        assert_eq!(
+            shared_persona_data.postal_addresses,
+            Some(SharedToDappWithPersonaIDsOfPersonaDataEntries::just(
+                postal_address.id
+            ))
+        );
+        assert_eq!(
+            shared_persona_data.dates_of_birth,
+            Some(SharedToDappWithPersonaIDsOfPersonaDataEntries::just(
+                date_of_birth.id
+            ))
+        );
+        assert_eq!(shared_persona_data.companies, None);
+    }
+
+    #[test]
+    fn too_few_extended_persona_data_entries_does_not_fulfill() {
+        let persona = Persona::sample_mainnet();
+        let sut = SUT {
+            persona: Some(persona.address),
+            one_time_persona_data: Some(
+                DappInteractionPersonaDataDecision::new(None, [], []),
+            ),
+            ..Default::default()
+        };
+        let items = DappToWalletInteractionItems::UnauthorizedRequest(
+            DappToWalletInteractionUnauthorizedRequestItems::new(
+                None,
+                DappToWalletInteractionPersonaDataRequestItem::new(
+                    None, None, None,
+                )
+                .with_extended_entries(
+                    None,
+                    None,
+                    RequestedQuantity::at_least(1),
+                ),
+            ),
+        );
+        let res = sut.resolve(&interaction(items), &network(), now());
+        assert!(matches!(
+            res,
+            Err(
+                CommonError::DappInteractionDecisionDoesNotFulfillRequest { .. }
+            )
+        ));
+    }
+
+    #[test]
+    fn transaction_is_not_a_request() {
+        let res = SUT::default().resolve(
+            &interaction(DappToWalletInteractionItems::sample_other()),
+            &network(),
+            now(),
+        );
+        assert_eq!(res, Err(CommonError::DappInteractionIsNotARequest));
+    }
+
+    #[test]
+    fn wrong_network_is_err() {
+        let res = SUT::default().resolve(
+            &interaction(DappToWalletInteractionItems::sample()),
+            &ProfileNetwork::sample_stokenet(),
+            now(),
+        );
+        assert!(matches!(res, Err(CommonError::NetworkDiscrepancy { .. })));
+    }
+}
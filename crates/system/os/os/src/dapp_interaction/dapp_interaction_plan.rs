@@ -0,0 +1,713 @@
+use crate::prelude::*;
+
+/// The result of validating a `DappToWalletInteractionUnvalidated` against the
+/// active Profile, telling the host what it must ask the user, if anything,
+/// before it can respond to the dApp.
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum DappInteractionPlan {
+    /// The interaction cannot be fulfilled, the host should send `response`
+    /// to the dApp without involving the user.
+    Reject {
+        response: WalletToDappInteractionResponse,
+    },
+
+    /// A Transaction, batch of Transactions or Pre-Authorization, which is
+    /// handled by the transaction review flows.
+    Review {
+        interaction: DappToWalletInteraction,
+    },
+
+    /// An authorized or unauthorized request, the user must complete all
+    /// `steps` after which the host builds a `DappInteractionDecision` and
+    /// responds using `SargonOS::respond_to_dapp_interaction`.
+    ///
+    /// `steps` can be empty, e.g. for a `usePersona` request where the user
+    /// has already shared everything requested with the dApp.
+    Request {
+        interaction: DappToWalletInteraction,
+        steps: Vec<DappInteractionStep>,
+    },
+}
+
+/// A single decision the user must make to fulfill a dApp request.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DappInteractionStep {
+    /// The user must choose which Persona to log in with. `remembered_persona`
+    /// is the Persona most recently used with this dApp, if any, which hosts
+    /// should preselect. If `challenge` is present the host must also sign it
+    /// with the chosen Persona.
+    Login {
+        remembered_persona: Option<IdentityAddress>,
+        challenge: Option<DappToWalletInteractionAuthChallengeNonce>,
+    },
+
+    /// The user must choose accounts to share, which are remembered for
+    /// future requests if `is_ongoing`.
+    ChooseAccounts {
+        request: DappToWalletInteractionAccountsRequestItem,
+        is_ongoing: bool,
+    },
+
+    /// The user must choose Persona data entries to share, which are
+    /// remembered for future requests if `is_ongoing`.
+    ChoosePersonaData {
+        request: DappToWalletInteractionPersonaDataRequestItem,
+        is_ongoing: bool,
+    },
+
+    /// The user must sign the challenge to prove ownership of the requested
+    /// accounts and/or Persona.
+    ProveOwnership {
+        request: DappToWalletInteractionProofOfOwnershipRequestItem,
+    },
+}
+
+impl DappInteractionPlan {
+    /// Rejects the interaction identified by `interaction_id` with `error`.
+    pub fn reject(
+        interaction_id: WalletInteractionId,
+        error: DappWalletInteractionErrorType,
+    ) -> Self {
+        Self::Reject {
+            response: WalletToDappInteractionResponse::Failure(
+                WalletToDappInteractionFailureResponse::new(
+                    interaction_id,
+                    error,
+                    None,
+                ),
+            ),
+        }
+    }
+
+    /// Validates `interaction` against `network` - the current network of the
+    /// active Profile - and computes which steps the user must complete.
+    pub fn new(
+        interaction: DappToWalletInteractionUnvalidated,
+        network: &ProfileNetwork,
+    ) -> Self {
+        let interaction_id = interaction.interaction_id.clone();
+        let reject = |error: DappWalletInteractionErrorType| {
+            Self::reject(interaction_id.clone(), error)
+        };
+
+        let metadata = interaction.metadata;
+        if metadata.version.0 > WalletInteractionVersion::current().0 {
+            return reject(DappWalletInteractionErrorType::IncompatibleVersion);
+        }
+        if metadata.network_id != network.id {
+            return reject(DappWalletInteractionErrorType::WrongNetwork);
+        }
+        let Ok(dapp_definition_address) = metadata
+            .dapp_definition_address
+            .parse::<DappDefinitionAddress>()
+        else {
+            return reject(
+                DappWalletInteractionErrorType::UnknownDappDefinitionAddress,
+            );
+        };
+        if dapp_definition_address.network_id() != network.id {
+            return reject(
+                DappWalletInteractionErrorType::UnknownDappDefinitionAddress,
+            );
+        }
+
+        let interaction = DappToWalletInteraction::new(
+            interaction.interaction_id,
+            interaction.items,
+            DappToWalletInteractionMetadata::new(
+                metadata.version,
+                metadata.network_id,
+                metadata.origin,
+                dapp_definition_address,
+            ),
+        );
+
+        let steps = match &interaction.items {
+            DappToWalletInteractionItems::Transaction(_)
+            | DappToWalletInteractionItems::BatchOfTransactions(_)
+            | DappToWalletInteractionItems::PreAuthorization(_) => {
+                return Self::Review { interaction };
+            }
+            DappToWalletInteractionItems::UnauthorizedRequest(items) => {
+                steps_for_unauthorized_request(items)
+            }
+            DappToWalletInteractionItems::AuthorizedRequest(items) => {
+                steps_for_authorized_request(
+                    items,
+                    network,
+                    &dapp_definition_address,
+                )
+            }
+        };
+
+        match steps {
+            Ok(steps) => Self::Request { interaction, steps },
+            Err(error) => reject(error),
+        }
+    }
+}
+
+fn validate_accounts_request(
+    request: &Option<DappToWalletInteractionAccountsRequestItem>,
+) -> Result<(), DappWalletInteractionErrorType> {
+    if request
+        .as_ref()
+        .is_some_and(|r| !r.number_of_accounts.is_valid())
+    {
+        return Err(DappWalletInteractionErrorType::InvalidRequest);
+    }
+    Ok(())
+}
+
+fn validate_persona_data_request(
+    request: &Option<DappToWalletInteractionPersonaDataRequestItem>,
+) -> Result<(), DappWalletInteractionErrorType> {
+    let Some(request) = request else {
+        return Ok(());
+    };
+    let is_invalid = [
+        request.number_of_requested_email_addresses,
+        request.number_of_requested_phone_numbers,
+        request.number_of_requested_postal_addresses,
+        request.number_of_requested_dates_of_birth,
+        request.number_of_requested_companies,
+    ]
+    .into_iter()
+    .flatten()
+    .any(|q| !q.is_valid());
+    if is_invalid {
+        return Err(DappWalletInteractionErrorType::InvalidRequest);
+    }
+    Ok(())
+}
+
+fn one_time_steps(
+    accounts: &Option<DappToWalletInteractionAccountsRequestItem>,
+    persona_data: &Option<DappToWalletInteractionPersonaDataRequestItem>,
+) -> Vec<DappInteractionStep> {
+    let mut steps = Vec::new();
+    if let Some(request) = accounts {
+        steps.push(DappInteractionStep::ChooseAccounts {
+            request: request.clone(),
+            is_ongoing: false,
+        });
+    }
+    if let Some(request) = persona_data {
+        steps.push(DappInteractionStep::ChoosePersonaData {
+            request: request.clone(),
+            is_ongoing: false,
+        });
+    }
+    steps
+}
+
+fn steps_for_unauthorized_request(
+    items: &DappToWalletInteractionUnauthorizedRequestItems,
+) -> Result<Vec<DappInteractionStep>, DappWalletInteractionErrorType> {
+    validate_accounts_request(&items.one_time_accounts)?;
+    validate_persona_data_request(&items.one_time_persona_data)?;
+    Ok(one_time_steps(
+        &items.one_time_accounts,
+        &items.one_time_persona_data,
+    ))
+}
+
+fn steps_for_authorized_request(
+    items: &DappToWalletInteractionAuthorizedRequestItems,
+    network: &ProfileNetwork,
+    dapp_definition_address: &DappDefinitionAddress,
+) -> Result<Vec<DappInteractionStep>, DappWalletInteractionErrorType> {
+    validate_accounts_request(&items.ongoing_accounts)?;
+    validate_accounts_request(&items.one_time_accounts)?;
+    validate_persona_data_request(&items.ongoing_persona_data)?;
+    validate_persona_data_request(&items.one_time_persona_data)?;
+
+    let personas = network.personas_non_hidden();
+    let authorized_dapp =
+        network.authorized_dapps.get_id(dapp_definition_address);
+    let authorized_persona = |address: &IdentityAddress| {
+        authorized_dapp.and_then(|dapp| {
+            dapp.references_to_authorized_personas.get_id(address)
+        })
+    };
+
+    let mut steps = Vec::new();
+
+    // When the dApp asks to use a specific Persona, it must exist and must
+    // previously have been used to log in to the dApp.
+    let remembered = match &items.auth {
+        DappToWalletInteractionAuthRequestItem::UsePersona(use_persona) => {
+            let address = use_persona.identity_address;
+            let Some(authorized_persona) = authorized_persona(&address)
+                .filter(|_| personas.contains_id(address))
+            else {
+                return Err(DappWalletInteractionErrorType::InvalidPersona);
+            };
+            Some(authorized_persona)
+        }
+        DappToWalletInteractionAuthRequestItem::LoginWithoutChallenge => {
+            steps.push(DappInteractionStep::Login {
+                remembered_persona: last_logged_in(authorized_dapp, &personas),
+                challenge: None,
+            });
+            None
+        }
+        DappToWalletInteractionAuthRequestItem::LoginWithChallenge(login) => {
+            steps.push(DappInteractionStep::Login {
+                remembered_persona: last_logged_in(authorized_dapp, &personas),
+                challenge: Some(login.challenge.clone()),
+            });
+            None
+        }
+    };
+
+    let reset = items
+        .reset
+        .clone()
+        .unwrap_or(DappToWalletInteractionResetRequestItem::new(false, false));
+
+    if let Some(request) = &items.ongoing_accounts {
+        let already_shared = !reset.accounts
+            && request.challenge.is_none()
+            && remembered.is_some_and(|p| {
+                has_shared_accounts_fulfilling(p, request, network)
+            });
+        if !already_shared {
+            steps.push(DappInteractionStep::ChooseAccounts {
+                request: request.clone(),
+                is_ongoing: true,
+            });
+        }
+    }
+
+    if let Some(request) = &items.ongoing_persona_data {
+        let already_shared = !reset.persona_data
+            && remembered.is_some_and(|p| {
+                has_shared_persona_data_fulfilling(p, request, &personas)
+            });
+        if !already_shared {
+            steps.push(DappInteractionStep::ChoosePersonaData {
+                request: request.clone(),
+                is_ongoing: true,
+            });
+        }
+    }
+
+    steps.extend(one_time_steps(
+        &items.one_time_accounts,
+        &items.one_time_persona_data,
+    ));
+
+    if let Some(request) = &items.proof_of_ownership {
+        steps.push(DappInteractionStep::ProveOwnership {
+            request: request.clone(),
+        });
+    }
+
+    Ok(steps)
+}
+
+/// The Persona which most recently logged in to `authorized_dapp`, if it
+/// still exists and is not hidden.
+fn last_logged_in(
+    authorized_dapp: Option<&AuthorizedDapp>,
+    personas: &Personas,
+) -> Option<IdentityAddress> {
+    authorized_dapp?
+        .references_to_authorized_personas
+        .iter()
+        .filter(|p| personas.contains_id(p.identity_address))
+        .max_by_key(|p| p.last_login)
+        .map(|p| p.identity_address)
+}
+
+fn has_shared_accounts_fulfilling(
+    authorized_persona: &AuthorizedPersonaSimple,
+    request: &DappToWalletInteractionAccountsRequestItem,
+    network: &ProfileNetwork,
+) -> bool {
+    let Some(shared) = &authorized_persona.shared_accounts else {
+        return false;
+    };
+    let accounts = network.accounts_non_hidden();
+    request
+        .number_of_accounts
+        .is_fulfilled_by_quantity(shared.ids.len())
+        && shared.ids.iter().all(|a| accounts.contains_id(a))
+}
+
+fn has_shared_persona_data_fulfilling(
+    authorized_persona: &AuthorizedPersonaSimple,
+    request: &DappToWalletInteractionPersonaDataRequestItem,
+    personas: &Personas,
+) -> bool {
+    let Some(persona) = personas.get_id(authorized_persona.identity_address)
+    else {
+        return false;
+    };
+    let full = &persona.persona_data;
+    let shared = &authorized_persona.shared_persona_data;
+
+    let name_fulfilled = request.is_requesting_name != Some(true)
+        || shared.name.is_some_and(|id| {
+            full.name.as_ref().is_some_and(|name| name.id == id)
+        });
+
+    let is_fulfilled =
+        |requested: Option<RequestedQuantity>,
+         shared: &Option<SharedToDappWithPersonaIDsOfPersonaDataEntries>,
+         existing: Vec<PersonaDataEntryID>| {
+            let Some(requested) = requested else {
+                return true;
+            };
+            shared.as_ref().is_some_and(|s| {
+                requested.is_fulfilled_by_quantity(s.ids.len())
+                    && s.ids.iter().all(|id| existing.contains(&id))
+            })
+        };
+
+    name_fulfilled
+        && is_fulfilled(
+            request.number_of_requested_email_addresses,
+            &shared.email_addresses,
+            full.email_addresses.ids().into_iter().cloned().collect(),
+        )
+        && is_fulfilled(
+            request.number_of_requested_phone_numbers,
+            &shared.phone_numbers,
+            full.phone_numbers.ids().into_iter().cloned().collect(),
+        )
+        && is_fulfilled(
+            request.number_of_requested_postal_addresses,
+            &shared.postal_addresses,
+            full.postal_addresses.ids().into_iter().cloned().collect(),
+        )
+        && is_fulfilled(
+            request.number_of_requested_dates_of_birth,
+            &shared.dates_of_birth,
+            full.dates_of_birth.ids().into_iter().cloned().collect(),
+        )
+        && is_fulfilled(
+            request.number_of_requested_companies,
+            &shared.companies,
+            full.companies.ids().into_iter().cloned().collect(),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = DappInteractionPlan;
+
+    fn network() -> ProfileNetwork {
+        ProfileNetwork::sample_mainnet()
+    }
+
+    fn dapp() -> AuthorizedDapp {
+        AuthorizedDapp::sample_mainnet_dashboard()
+    }
+
+    fn interaction(
+        items: DappToWalletInteractionItems,
+    ) -> DappToWalletInteractionUnvalidated {
+        DappToWalletInteractionUnvalidated::new(
+            WalletInteractionId::sample(),
+            items,
+            DappToWalletInteractionMetadataUnvalidated::new(
+                WalletInteractionVersion::current(),
+                NetworkID::Mainnet,
+                "https://dashboard.radixdlt.com",
+                dapp().dapp_definition_address.to_string(),
+            ),
+        )
+    }
+
+    fn authorized(
+        auth: DappToWalletInteractionAuthRequestItem,
+        reset: impl Into<Option<DappToWalletInteractionResetRequestItem>>,
+    ) -> DappToWalletInteractionItems {
+        DappToWalletInteractionItems::AuthorizedRequest(
+            DappToWalletInteractionAuthorizedRequestItems::new(
+                auth,
+                reset,
+                DappToWalletInteractionAccountsRequestItem::new(
+                    RequestedQuantity::exactly(2),
+                    None,
+                ),
+                DappToWalletInteractionPersonaDataRequestItem::new(
+                    true,
+                    RequestedQuantity::at_least(1),
+                    None,
+                ),
+                None,
+                None,
+                None,
+            ),
+        )
+    }
+
+    fn use_persona(
+        address: IdentityAddress,
+    ) -> DappToWalletInteractionAuthRequestItem {
+        DappToWalletInteractionAuthRequestItem::UsePersona(
+            DappToWalletInteractionAuthUsePersonaRequestItem::new(address),
+        )
+    }
+
+    fn rejected_with(error: DappWalletInteractionErrorType) -> SUT {
+        SUT::Reject {
+            response: WalletToDappInteractionResponse::Failure(
+                WalletToDappInteractionFailureResponse::new(
+                    WalletInteractionId::sample(),
+                    error,
+                    None,
+                ),
+            ),
+        }
+    }
+
+    fn steps(sut: SUT) -> Vec<DappInteractionStep> {
+        match sut {
+            SUT::Request { steps, .. } => steps,
+            _ => panic!("Expected request, got {:?}", sut),
+        }
+    }
+
+    #[test]
+    fn wrong_network_is_rejected() {
+        let mut unvalidated =
+            interaction(DappToWalletInteractionItems::sample());
+        unvalidated.metadata.network_id = NetworkID::Stokenet;
+        assert_eq!(
+            SUT::new(unvalidated, &network()),
+            rejected_with(DappWalletInteractionErrorType::WrongNetwork)
+        );
+    }
+
+    #[test]
+    fn incompatible_version_is_rejected() {
+        let mut unvalidated =
+            interaction(DappToWalletInteractionItems::sample());
+        unvalidated.metadata.version =
+            (WalletInteractionVersion::current().0 + 1).into();
+        assert_eq!(
+            SUT::new(unvalidated, &network()),
+            rejected_with(DappWalletInteractionErrorType::IncompatibleVersion)
+        );
+    }
+
+    #[test]
+    fn invalid_dapp_definition_address_is_rejected() {
+        let mut unvalidated =
+            interaction(DappToWalletInteractionItems::sample());
+        unvalidated.metadata.dapp_definition_address = "invalid".to_owned();
+        assert_eq!(
+            SUT::new(unvalidated, &network()),
+            rejected_with(
+                DappWalletInteractionErrorType::UnknownDappDefinitionAddress
+            )
+        );
+    }
+
+    #[test]
+    fn transaction_is_reviewed() {
+        let sut = SUT::new(
+            interaction(DappToWalletInteractionItems::sample_other()),
+            &network(),
+        );
+        assert!(matches!(sut, SUT::Review { .. }));
+    }
+
+    #[test]
+    fn unauthorized_request_has_one_time_steps() {
+        let items = DappToWalletInteractionUnauthorizedRequestItems::sample();
+        let sut = SUT::new(
+            interaction(DappToWalletInteractionItems::UnauthorizedRequest(
+                items.clone(),
+            )),
+            &network(),
+        );
+        assert_eq!(
+            steps(sut),
+            vec![
+                DappInteractionStep::ChooseAccounts {
+                    request: items.one_time_accounts.unwrap(),
+                    is_ongoing: false,
+                },
+                DappInteractionStep::ChoosePersonaData {
+                    request: items.one_time_persona_data.unwrap(),
+                    is_ongoing: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn use_persona_already_shared_everything_has_no_steps() {
+        let persona = AuthorizedPersonaSimple::sample_mainnet();
+        let sut = SUT::new(
+            interaction(authorized(
+                use_persona(persona.identity_address),
+                None,
+            )),
+            &network(),
+        );
+        assert_eq!(steps(sut), Vec::new());
+    }
+
+    #[test]
+    fn use_persona_with_reset_asks_again() {
+        let persona = AuthorizedPersonaSimple::sample_mainnet();
+        let sut = SUT::new(
+            interaction(authorized(
+                use_persona(persona.identity_address),
+                DappToWalletInteractionResetRequestItem::new(true, true),
+            )),
+            &network(),
+        );
+        assert_eq!(
+            steps(sut)
+                .into_iter()
+                .map(|s| matches!(
+                    s,
+                    DappInteractionStep::ChooseAccounts {
+                        is_ongoing: true,
+                        ..
+                    } | DappInteractionStep::ChoosePersonaData {
+                        is_ongoing: true,
+                        ..
+                    }
+                ))
+                .collect_vec(),
+            vec![true, true]
+        );
+    }
+
+    #[test]
+    fn use_unknown_persona_is_rejected() {
+        let sut = SUT::new(
+            interaction(authorized(
+                use_persona(IdentityAddress::sample_stokenet()),
+                None,
+            )),
+            &network(),
+        );
+        assert_eq!(
+            sut,
+            rejected_with(DappWalletInteractionErrorType::InvalidPersona)
+        );
+    }
+
+    #[test]
+    fn login_remembers_last_logged_in_persona() {
+        let sut = SUT::new(
+            interaction(authorized(
+                DappToWalletInteractionAuthRequestItem::LoginWithoutChallenge,
+                None,
+            )),
+            &network(),
+        );
+        let expected = dapp()
+            .references_to_authorized_personas
+            .iter()
+            .max_by_key(|p| p.last_login)
+            .map(|p| p.identity_address);
+        assert_eq!(
+            steps(sut).first().cloned(),
+            Some(DappInteractionStep::Login {
+                remembered_persona: expected,
+                challenge: None,
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_requested_quantity_is_rejected() {
+        let sut = SUT::new(
+            interaction(DappToWalletInteractionItems::UnauthorizedRequest(
+                DappToWalletInteractionUnauthorizedRequestItems::new(
+                    DappToWalletInteractionAccountsRequestItem::new(
+                        RequestedQuantity {
+                            quantifier: RequestedNumberQuantifier::Exactly,
+                            quantity: 0,
+                        },
+                        None,
+                    ),
+                    None,
+                ),
+            )),
+            &network(),
+        );
+        assert_eq!(
+            sut,
+            rejected_with(DappWalletInteractionErrorType::InvalidRequest)
+        );
+    }
+
+    #[test]
+    fn invalid_requested_quantity_of_postal_addresses_is_rejected() {
+        let sut = SUT::new(
+            interaction(DappToWalletInteractionItems::UnauthorizedRequest(
+                DappToWalletInteractionUnauthorizedRequestItems::new(
+                    None,
+                    DappToWalletInteractionPersonaDataRequestItem::new(
+                        None, None, None,
+                    )
+                    .with_extended_entries(
+                        RequestedQuantity {
+                            quantifier: RequestedNumberQuantifier::Exactly,
+                            quantity: 0,
+                        },
+                        None,
+                        None,
+                    ),
+                ),
+            )),
+            &network(),
+        );
+        assert_eq!(
+            sut,
+            rejected_with(DappWalletInteractionErrorType::InvalidRequest)
+        );
+    }
+
+    #[test]
+    fn use_persona_requesting_unshared_company_asks_again() {
+        let persona = AuthorizedPersonaSimple::sample_mainnet();
+        let request = DappToWalletInteractionPersonaDataRequestItem::new(
+            true,
+            RequestedQuantity::at_least(1),
+            None,
+        )
+        .with_extended_entries(
+            None,
+            None,
+            RequestedQuantity::exactly(1),
+        );
+        let items = DappToWalletInteractionItems::AuthorizedRequest(
+            DappToWalletInteractionAuthorizedRequestItems::new(
+                use_persona(persona.identity_address),
+                None,
+                DappToWalletInteractionAccountsRequestItem::new(
+                    RequestedQuantity::exactly(2),
+                    None,
+                ),
+                request.clone(),
+                None,
+                None,
+                None,
+            ),
+        );
+        let sut = SUT::new(interaction(items), &network());
+        assert_eq!(
+            steps(sut),
+            vec![DappInteractionStep::ChoosePersonaData {
+                request,
+                is_ongoing: true,
+            }]
+        );
+    }
+}
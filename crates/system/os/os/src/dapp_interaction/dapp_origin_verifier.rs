@@ -0,0 +1,657 @@
+use crate::prelude::*;
+use std::sync::RwLock;
+
+/// For how long a `DappOriginVerdict` is reused before the well-known file
+/// and the on-ledger metadata of the dApp definition are fetched again.
+pub const DAPP_ORIGIN_VERDICT_TTL_SECONDS: i64 = 5 * 60;
+
+/// The maximum number of `DappOriginVerdict`s cached, when full the oldest
+/// verdict is evicted to make room for a new one.
+pub const DAPP_ORIGIN_VERDICT_CACHE_CAPACITY: usize = 64;
+
+/// The `account_type` metadata value of a dApp definition account.
+const ACCOUNT_TYPE_DAPP_DEFINITION: &str = "dapp definition";
+
+/// The outcome of verifying that a `DappOrigin` and a `DappDefinitionAddress`
+/// claim each other, i.e. that the `.well-known/radix.json` file hosted on the
+/// origin lists the dApp definition, and that the dApp definition account
+/// on-ledger claims the origin in its `claimed_websites` metadata.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DappOriginVerdict {
+    /// The origin and the dApp definition claim each other.
+    Verified {
+        /// The entities claimed by the dApp definition, read from its
+        /// `claimed_entities` metadata.
+        claimed_entities: Vec<Address>,
+    },
+
+    /// The origin is not a valid URL.
+    InvalidOrigin,
+
+    /// No `.well-known/radix.json` file is hosted on the origin.
+    WellKnownFileMissing,
+
+    /// The `.well-known/radix.json` file hosted on the origin could not be
+    /// parsed.
+    WellKnownFileInvalid,
+
+    /// The `.well-known/radix.json` file hosted on the origin does not list
+    /// the dApp definition address.
+    DappDefinitionNotInWellKnownFile,
+
+    /// The dApp definition address is not an account with `account_type`
+    /// metadata set to `dapp definition`.
+    NotADappDefinition,
+
+    /// The `claimed_websites` metadata of the dApp definition does not
+    /// contain the origin.
+    WebsiteNotClaimed,
+}
+
+impl DappOriginVerdict {
+    pub fn is_verified(&self) -> bool {
+        matches!(self, Self::Verified { .. })
+    }
+
+    /// The error type to respond to the dApp with if verification failed,
+    /// `None` if verified.
+    pub fn failure_error_type(&self) -> Option<DappWalletInteractionErrorType> {
+        match self {
+            Self::Verified { .. } => None,
+            Self::InvalidOrigin => {
+                Some(DappWalletInteractionErrorType::InvalidOriginURL)
+            }
+            Self::WellKnownFileMissing => {
+                Some(DappWalletInteractionErrorType::RadixJsonNotFound)
+            }
+            Self::WellKnownFileInvalid => {
+                Some(DappWalletInteractionErrorType::RadixJsonUnknownFileFormat)
+            }
+            Self::DappDefinitionNotInWellKnownFile => Some(
+                DappWalletInteractionErrorType::UnknownDappDefinitionAddress,
+            ),
+            Self::NotADappDefinition => {
+                Some(DappWalletInteractionErrorType::WrongAccountType)
+            }
+            Self::WebsiteNotClaimed => {
+                Some(DappWalletInteractionErrorType::UnknownWebsite)
+            }
+        }
+    }
+}
+
+impl HasSampleValues for DappOriginVerdict {
+    fn sample() -> Self {
+        Self::Verified {
+            claimed_entities: vec![Address::sample()],
+        }
+    }
+
+    fn sample_other() -> Self {
+        Self::WebsiteNotClaimed
+    }
+}
+
+/// Performs the two-way check between a `DappOrigin` and a
+/// `DappDefinitionAddress`, caching the verdicts for
+/// `DAPP_ORIGIN_VERDICT_TTL_SECONDS`, so that requests received over Radix
+/// Connect Mobile and P2P are verified consistently. At most
+/// `DAPP_ORIGIN_VERDICT_CACHE_CAPACITY` verdicts are cached.
+pub struct DappOriginVerifier {
+    networking_driver: Arc<dyn NetworkingDriver>,
+    well_known_client: WellKnownClient,
+    verdicts: RwLock<
+        HashMap<
+            (DappOrigin, DappDefinitionAddress),
+            (DappOriginVerdict, Timestamp),
+        >,
+    >,
+}
+
+impl DappOriginVerifier {
+    pub fn new(networking_driver: Arc<dyn NetworkingDriver>) -> Self {
+        Self {
+            well_known_client: WellKnownClient::new_with_networking_driver(
+                networking_driver.clone(),
+            ),
+            networking_driver,
+            verdicts: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl DappOriginVerifier {
+    /// Verifies that `origin` and `dapp_definition_address` claim each other,
+    /// reading the on-ledger metadata using `gateway`.
+    ///
+    /// Failing to reach the origin or the Gateway results in an `Err` - which
+    /// is not cached - whereas any definitive answer is returned as a
+    /// `DappOriginVerdict` and cached.
+    pub async fn verify(
+        &self,
+        origin: &DappOrigin,
+        dapp_definition_address: DappDefinitionAddress,
+        gateway: Gateway,
+    ) -> Result<DappOriginVerdict> {
+        if gateway.network.id != dapp_definition_address.network_id() {
+            return Err(CommonError::NetworkDiscrepancy {
+                expected: gateway.network.id.to_string(),
+                actual: dapp_definition_address.network_id().to_string(),
+            });
+        }
+
+        let key = (origin.clone(), dapp_definition_address);
+        if let Some(verdict) = self.cached_verdict(&key) {
+            return Ok(verdict);
+        }
+
+        let verdict = self
+            .fetch_verdict(origin, dapp_definition_address, gateway)
+            .await?;
+
+        self.cache_verdict(key, verdict.clone(), Timestamp::now_utc());
+
+        Ok(verdict)
+    }
+
+    /// Caches `verdict` as made at `now`, evicting the expired verdicts, and
+    /// the oldest one if the cache is still full.
+    fn cache_verdict(
+        &self,
+        key: (DappOrigin, DappDefinitionAddress),
+        verdict: DappOriginVerdict,
+        now: Timestamp,
+    ) {
+        let mut verdicts = self
+            .verdicts
+            .write()
+            .expect("Stopped using DappOriginVerifier, lock poisoned");
+        verdicts
+            .retain(|_, (_, verified_at)| !Self::is_expired(*verified_at, now));
+        if verdicts.len() >= DAPP_ORIGIN_VERDICT_CACHE_CAPACITY
+            && !verdicts.contains_key(&key)
+        {
+            let oldest = verdicts
+                .iter()
+                .min_by_key(|(_, (_, verified_at))| *verified_at)
+                .map(|(oldest, _)| oldest.clone());
+            if let Some(oldest) = oldest {
+                verdicts.remove(&oldest);
+            }
+        }
+        verdicts.insert(key, (verdict, now));
+    }
+
+    fn is_expired(verified_at: Timestamp, now: Timestamp) -> bool {
+        now.duration_since(verified_at).whole_seconds()
+            > DAPP_ORIGIN_VERDICT_TTL_SECONDS
+    }
+
+    fn cached_verdict(
+        &self,
+        key: &(DappOrigin, DappDefinitionAddress),
+    ) -> Option<DappOriginVerdict> {
+        let verdicts = self
+            .verdicts
+            .read()
+            .expect("Stopped using DappOriginVerifier, lock poisoned");
+        let (verdict, verified_at) = verdicts.get(key)?;
+        (!Self::is_expired(*verified_at, Timestamp::now_utc()))
+            .then(|| verdict.clone())
+    }
+
+    async fn fetch_verdict(
+        &self,
+        origin: &DappOrigin,
+        dapp_definition_address: DappDefinitionAddress,
+        gateway: Gateway,
+    ) -> Result<DappOriginVerdict> {
+        let Ok(origin_url) = TryInto::<Url>::try_into(origin.clone()) else {
+            return Ok(DappOriginVerdict::InvalidOrigin);
+        };
+
+        let well_known = match self
+            .well_known_client
+            .get_well_known_file(origin_url.clone())
+            .await
+        {
+            Ok(well_known) => well_known,
+            Err(CommonError::NetworkResponseBadCode { code: 404 }) => {
+                return Ok(DappOriginVerdict::WellKnownFileMissing)
+            }
+            Err(CommonError::NetworkResponseJSONDeserialize { .. }) => {
+                return Ok(DappOriginVerdict::WellKnownFileInvalid)
+            }
+            Err(error) => return Err(error),
+        };
+
+        if !well_known
+            .dapp_definitions
+            .iter()
+            .any(|d| d.dapp_definition_address == dapp_definition_address)
+        {
+            return Ok(DappOriginVerdict::DappDefinitionNotInWellKnownFile);
+        }
+
+        let gateway_client = GatewayClient::with_gateway(
+            self.networking_driver.clone(),
+            gateway,
+        );
+        let metadata = match gateway_client
+            .fetch_dapp_metadata(dapp_definition_address)
+            .await
+        {
+            Ok(metadata) => metadata,
+            Err(CommonError::EntityNotFound) => {
+                return Ok(DappOriginVerdict::NotADappDefinition)
+            }
+            Err(error) => return Err(error),
+        };
+
+        if metadata.get_account_type().as_deref()
+            != Some(ACCOUNT_TYPE_DAPP_DEFINITION)
+        {
+            return Ok(DappOriginVerdict::NotADappDefinition);
+        }
+
+        let is_website_claimed = metadata
+            .get_claimed_websites()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|website| Url::parse(&website).ok())
+            .any(|website| website.origin() == origin_url.origin());
+        if !is_website_claimed {
+            return Ok(DappOriginVerdict::WebsiteNotClaimed);
+        }
+
+        let claimed_entities = metadata
+            .get_claimed_entities()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|entity| entity.parse::<Address>().ok())
+            .collect();
+
+        Ok(DappOriginVerdict::Verified { claimed_entities })
+    }
+}
+
+/// A networking driver responding to the requests made when verifying
+/// `origin` and `dapp_definition_address` as if they claim each other.
+#[cfg(test)]
+pub(crate) fn networking_driver_verifying(
+    origin: &str,
+    dapp_definition_address: DappDefinitionAddress,
+) -> MockNetworkingDriver {
+    let well_known =
+        serde_json::to_vec(&DappWellKnownData::new([DappDefinition::new(
+            dapp_definition_address,
+        )]))
+        .unwrap();
+    let item =
+        |key: MetadataKey, typed: MetadataTypedValue| EntityMetadataItem {
+            key: key.to_string(),
+            value: EntityMetadataItemValue { typed },
+        };
+    let metadata = serde_json::to_vec(&StateEntityDetailsResponse {
+        ledger_state: None,
+        items: vec![StateEntityDetailsResponseItem {
+            address: dapp_definition_address.into(),
+            fungible_resources: None,
+            non_fungible_resources: None,
+            metadata: EntityMetadataCollection::new(vec![
+                item(
+                    MetadataKey::AccountType,
+                    MetadataTypedValue::MetadataStringValue {
+                        value: ACCOUNT_TYPE_DAPP_DEFINITION.to_owned(),
+                    },
+                ),
+                item(
+                    MetadataKey::ClaimedWebsites,
+                    MetadataTypedValue::MetadataOriginArrayValue {
+                        values: vec![origin.to_owned()],
+                    },
+                ),
+            ]),
+            details: None,
+        }],
+    })
+    .unwrap();
+    MockNetworkingDriver::with_lazy_responses(move |request, _| {
+        if request.url.path().ends_with("radix.json") {
+            NetworkResponse::new(200, well_known.clone())
+        } else {
+            NetworkResponse::new(200, metadata.clone())
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = DappOriginVerifier;
+
+    const ORIGIN: &str = "https://dashboard.radixdlt.com";
+
+    fn dapp_definition() -> DappDefinitionAddress {
+        AccountAddress::sample_mainnet()
+    }
+
+    fn well_known(address: DappDefinitionAddress) -> DappWellKnownData {
+        DappWellKnownData::new([DappDefinition::new(address)])
+    }
+
+    fn metadata(
+        account_type: &str,
+        claimed_websites: Vec<String>,
+    ) -> StateEntityDetailsResponse {
+        let item =
+            |key: MetadataKey, typed: MetadataTypedValue| EntityMetadataItem {
+                key: key.to_string(),
+                value: EntityMetadataItemValue { typed },
+            };
+        StateEntityDetailsResponse {
+            ledger_state: None,
+            items: vec![StateEntityDetailsResponseItem {
+                address: dapp_definition().into(),
+                fungible_resources: None,
+                non_fungible_resources: None,
+                metadata: EntityMetadataCollection::new(vec![
+                    item(
+                        MetadataKey::AccountType,
+                        MetadataTypedValue::MetadataStringValue {
+                            value: account_type.to_owned(),
+                        },
+                    ),
+                    item(
+                        MetadataKey::ClaimedWebsites,
+                        MetadataTypedValue::MetadataOriginArrayValue {
+                            values: claimed_websites,
+                        },
+                    ),
+                    item(
+                        MetadataKey::ClaimedEntities,
+                        MetadataTypedValue::MetadataGlobalAddressArrayValue {
+                            values: vec![
+                                ComponentAddress::sample_mainnet().to_string()
+                            ],
+                        },
+                    ),
+                ]),
+                details: None,
+            }],
+        }
+    }
+
+    fn responding(
+        well_known: NetworkResponse,
+        metadata: StateEntityDetailsResponse,
+    ) -> SUT {
+        let metadata = serde_json::to_vec(&metadata).unwrap();
+        SUT::new(Arc::new(MockNetworkingDriver::with_lazy_responses(
+            move |request, _| {
+                if request.url.path().ends_with("radix.json") {
+                    well_known.clone()
+                } else {
+                    NetworkResponse::new(200, metadata.clone())
+                }
+            },
+        )))
+    }
+
+    fn ok_json<T: Serialize>(value: T) -> NetworkResponse {
+        NetworkResponse::new(200, serde_json::to_vec(&value).unwrap())
+    }
+
+    async fn verify(sut: &SUT) -> Result<DappOriginVerdict> {
+        sut.verify(
+            &DappOrigin::new(ORIGIN),
+            dapp_definition(),
+            Gateway::mainnet(),
+        )
+        .await
+    }
+
+    #[actix_rt::test]
+    async fn verified() {
+        let sut = responding(
+            ok_json(well_known(dapp_definition())),
+            metadata("dapp definition", vec![format!("{}/", ORIGIN)]),
+        );
+        assert_eq!(
+            verify(&sut).await.unwrap(),
+            DappOriginVerdict::Verified {
+                claimed_entities: vec![
+                    ComponentAddress::sample_mainnet().into()
+                ]
+            }
+        );
+    }
+
+    #[actix_rt::test]
+    async fn invalid_origin() {
+        let sut =
+            SUT::new(Arc::new(MockNetworkingDriver::new_always_failing()));
+        let verdict = sut
+            .verify(
+                &DappOrigin::new("not a url"),
+                dapp_definition(),
+                Gateway::mainnet(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(verdict, DappOriginVerdict::InvalidOrigin);
+    }
+
+    #[actix_rt::test]
+    async fn well_known_file_missing() {
+        let sut = responding(
+            NetworkResponse::new(404, vec![]),
+            metadata("dapp definition", vec![ORIGIN.to_owned()]),
+        );
+        assert_eq!(
+            verify(&sut).await.unwrap(),
+            DappOriginVerdict::WellKnownFileMissing
+        );
+    }
+
+    #[actix_rt::test]
+    async fn well_known_file_invalid() {
+        let sut = responding(
+            NetworkResponse::new(200, b"garbage".to_vec()),
+            metadata("dapp definition", vec![ORIGIN.to_owned()]),
+        );
+        assert_eq!(
+            verify(&sut).await.unwrap(),
+            DappOriginVerdict::WellKnownFileInvalid
+        );
+    }
+
+    #[actix_rt::test]
+    async fn dapp_definition_not_in_well_known_file() {
+        let sut = responding(
+            ok_json(well_known(AccountAddress::sample_mainnet_other())),
+            metadata("dapp definition", vec![ORIGIN.to_owned()]),
+        );
+        assert_eq!(
+            verify(&sut).await.unwrap(),
+            DappOriginVerdict::DappDefinitionNotInWellKnownFile
+        );
+    }
+
+    #[actix_rt::test]
+    async fn not_a_dapp_definition() {
+        let sut = responding(
+            ok_json(well_known(dapp_definition())),
+            metadata("account", vec![ORIGIN.to_owned()]),
+        );
+        assert_eq!(
+            verify(&sut).await.unwrap(),
+            DappOriginVerdict::NotADappDefinition
+        );
+    }
+
+    #[actix_rt::test]
+    async fn website_not_claimed() {
+        let sut = responding(
+            ok_json(well_known(dapp_definition())),
+            metadata(
+                "dapp definition",
+                vec!["https://radixdlt.com".to_owned()],
+            ),
+        );
+        assert_eq!(
+            verify(&sut).await.unwrap(),
+            DappOriginVerdict::WebsiteNotClaimed
+        );
+    }
+
+    #[actix_rt::test]
+    async fn network_failure_is_error() {
+        let sut =
+            SUT::new(Arc::new(MockNetworkingDriver::new_always_failing()));
+        assert!(verify(&sut).await.is_err());
+    }
+
+    #[actix_rt::test]
+    async fn wrong_network_is_error() {
+        let sut =
+            SUT::new(Arc::new(MockNetworkingDriver::new_always_failing()));
+        let res = sut
+            .verify(
+                &DappOrigin::new(ORIGIN),
+                dapp_definition(),
+                Gateway::stokenet(),
+            )
+            .await;
+        assert!(matches!(res, Err(CommonError::NetworkDiscrepancy { .. })));
+    }
+
+    #[actix_rt::test]
+    async fn verdict_is_cached() {
+        static REQUESTS: AtomicUsize = AtomicUsize::new(0);
+        let well_known =
+            serde_json::to_vec(&well_known(dapp_definition())).unwrap();
+        let metadata = serde_json::to_vec(&metadata(
+            "dapp definition",
+            vec![ORIGIN.to_owned()],
+        ))
+        .unwrap();
+        let sut = SUT::new(Arc::new(
+            MockNetworkingDriver::with_lazy_responses(move |request, _| {
+                REQUESTS.fetch_add(1, Ordering::SeqCst);
+                if request.url.path().ends_with("radix.json") {
+                    NetworkResponse::new(200, well_known.clone())
+                } else {
+                    NetworkResponse::new(200, metadata.clone())
+                }
+            }),
+        ));
+
+        let first = verify(&sut).await.unwrap();
+        let second = verify(&sut).await.unwrap();
+
+        assert_eq!(first, second);
+        assert!(first.is_verified());
+        assert_eq!(REQUESTS.load(Ordering::SeqCst), 2);
+    }
+
+    fn key(origin: &str) -> (DappOrigin, DappDefinitionAddress) {
+        (DappOrigin::new(origin), dapp_definition())
+    }
+
+    fn cached_keys(sut: &SUT) -> HashSet<(DappOrigin, DappDefinitionAddress)> {
+        sut.verdicts.read().unwrap().keys().cloned().collect()
+    }
+
+    #[test]
+    fn expired_verdicts_are_evicted_on_insert() {
+        let sut =
+            SUT::new(Arc::new(MockNetworkingDriver::new_always_failing()));
+        let now = Timestamp::now_utc();
+        let expired = now.sub(Duration::from_secs(
+            DAPP_ORIGIN_VERDICT_TTL_SECONDS as u64 + 1,
+        ));
+        sut.cache_verdict(
+            key("https://expired.com"),
+            DappOriginVerdict::sample(),
+            expired,
+        );
+        sut.cache_verdict(
+            key("https://recent.com"),
+            DappOriginVerdict::sample(),
+            now.sub(Duration::from_secs(1)),
+        );
+
+        sut.cache_verdict(key(ORIGIN), DappOriginVerdict::sample(), now);
+
+        assert_eq!(
+            cached_keys(&sut),
+            HashSet::from([key("https://recent.com"), key(ORIGIN)])
+        );
+    }
+
+    #[test]
+    fn oldest_verdict_is_evicted_when_full() {
+        let sut =
+            SUT::new(Arc::new(MockNetworkingDriver::new_always_failing()));
+        let now = Timestamp::now_utc();
+        let origins = (0..DAPP_ORIGIN_VERDICT_CACHE_CAPACITY)
+            .map(|n| format!("https://dapp{}.com", n))
+            .collect_vec();
+        for (n, origin) in origins.iter().enumerate() {
+            let age = (DAPP_ORIGIN_VERDICT_CACHE_CAPACITY - n) as u64;
+            sut.cache_verdict(
+                key(origin),
+                DappOriginVerdict::sample(),
+                now.sub(Duration::from_secs(age)),
+            );
+        }
+
+        sut.cache_verdict(key(ORIGIN), DappOriginVerdict::sample(), now);
+
+        let keys = cached_keys(&sut);
+        assert_eq!(keys.len(), DAPP_ORIGIN_VERDICT_CACHE_CAPACITY);
+        assert!(!keys.contains(&key(&origins[0])));
+        assert!(keys.contains(&key(&origins[1])));
+        assert!(keys.contains(&key(ORIGIN)));
+    }
+
+    #[test]
+    fn updating_cached_verdict_when_full_evicts_nothing() {
+        let sut =
+            SUT::new(Arc::new(MockNetworkingDriver::new_always_failing()));
+        let now = Timestamp::now_utc();
+        for n in 0..DAPP_ORIGIN_VERDICT_CACHE_CAPACITY {
+            sut.cache_verdict(
+                key(&format!("https://dapp{}.com", n)),
+                DappOriginVerdict::sample(),
+                now,
+            );
+        }
+
+        sut.cache_verdict(
+            key("https://dapp1.com"),
+            DappOriginVerdict::sample_other(),
+            now,
+        );
+
+        assert_eq!(cached_keys(&sut).len(), DAPP_ORIGIN_VERDICT_CACHE_CAPACITY);
+    }
+
+    #[test]
+    fn failure_error_type() {
+        assert_eq!(DappOriginVerdict::sample().failure_error_type(), None);
+        assert_eq!(
+            DappOriginVerdict::sample_other().failure_error_type(),
+            Some(DappWalletInteractionErrorType::UnknownWebsite)
+        );
+        assert_eq!(
+            DappOriginVerdict::WellKnownFileMissing.failure_error_type(),
+            Some(DappWalletInteractionErrorType::RadixJsonNotFound)
+        );
+    }
+}
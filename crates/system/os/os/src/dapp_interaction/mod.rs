@@ -0,0 +1,9 @@
+mod dapp_interaction_decision;
+mod dapp_interaction_plan;
+mod dapp_origin_verifier;
+mod sargon_os_dapp_interaction;
+
+pub use dapp_interaction_decision::*;
+pub use dapp_interaction_plan::*;
+pub use dapp_origin_verifier::*;
+pub use sargon_os_dapp_interaction::*;
@@ -0,0 +1,463 @@
+use crate::prelude::*;
+
+// ==================
+// Dapp Interaction
+// ==================
+impl SargonOS {
+    /// Validates `interaction` against the current network of the active
+    /// Profile and returns a plan of what the user must decide, if anything,
+    /// before the host can respond to the dApp.
+    ///
+    /// Invalid interactions, e.g. for another network or using an unknown
+    /// Persona, result in `DappInteractionPlan::Reject` containing the failure
+    /// response to send to the dApp. So do interactions whose origin and dApp
+    /// definition do not claim each other, see `verify_dapp_origin`, unless
+    /// developer mode is enabled.
+    ///
+    /// Fails if the origin could not be verified, e.g. due to the origin or
+    /// the Gateway being unreachable.
+    pub async fn prepare_dapp_interaction(
+        &self,
+        interaction: DappToWalletInteractionUnvalidated,
+    ) -> Result<DappInteractionPlan> {
+        let network = self.current_network()?;
+        let plan = DappInteractionPlan::new(interaction, &network);
+
+        let (DappInteractionPlan::Review { interaction }
+        | DappInteractionPlan::Request { interaction, .. }) = &plan
+        else {
+            return Ok(plan);
+        };
+
+        let failure = self
+            .dapp_origin_failure(
+                &interaction.metadata.origin,
+                interaction.metadata.dapp_definition_address,
+            )
+            .await?;
+
+        Ok(match failure {
+            Some(error) => DappInteractionPlan::reject(
+                interaction.interaction_id.clone(),
+                error,
+            ),
+            None => plan,
+        })
+    }
+
+    /// Verifies that `origin` and `dapp_definition_address` claim each other,
+    /// using the `.well-known/radix.json` file hosted on the origin and the
+    /// on-ledger metadata of the dApp definition read from the current
+    /// gateway.
+    ///
+    /// Verdicts are cached for `DAPP_ORIGIN_VERDICT_TTL_SECONDS`, so calling
+    /// this for every request received, no matter the transport, is cheap.
+    pub async fn verify_dapp_origin(
+        &self,
+        origin: DappOrigin,
+        dapp_definition_address: DappDefinitionAddress,
+    ) -> Result<DappOriginVerdict> {
+        let gateway = self.current_gateway()?;
+        self.dapp_origin_verifier
+            .verify(&origin, dapp_definition_address, gateway)
+            .await
+    }
+
+    /// Verifies `origin` using `verify_dapp_origin` and returns the error to
+    /// respond to the dApp with if verification failed, `None` if verified
+    /// or if developer mode is enabled.
+    pub(crate) async fn dapp_origin_failure(
+        &self,
+        origin: &DappOrigin,
+        dapp_definition_address: DappDefinitionAddress,
+    ) -> Result<Option<DappWalletInteractionErrorType>> {
+        let is_developer_mode_enabled = self
+            .profile()?
+            .app_preferences
+            .security
+            .is_developer_mode_enabled
+            .0;
+        if is_developer_mode_enabled {
+            return Ok(None);
+        }
+
+        self.verify_dapp_origin(origin.clone(), dapp_definition_address)
+            .await
+            .map(|verdict| verdict.failure_error_type())
+    }
+
+    /// Builds the response to the dApp from the `decision` of the user and,
+    /// for authorized requests, saves the updated `AuthorizedDapp` - i.e. last
+    /// login and the ongoing accounts and Persona data shared - into Profile.
+    ///
+    /// # Emits Event
+    /// Emits `Event::ProfileSaved` for authorized requests.
+    pub async fn respond_to_dapp_interaction(
+        &self,
+        interaction: DappToWalletInteraction,
+        decision: DappInteractionDecision,
+    ) -> Result<WalletToDappInteractionResponse> {
+        let network = self.current_network()?;
+        let (response, authorized_dapp) =
+            decision.resolve(&interaction, &network, Timestamp::now_utc())?;
+
+        if let Some(authorized_dapp) = authorized_dapp {
+            self.update_profile_with(|profile| {
+                profile.networks.try_update_with(
+                    &authorized_dapp.network_id,
+                    |network| {
+                        network
+                            .authorized_dapps
+                            .insert(authorized_dapp.clone());
+                    },
+                )
+            })
+            .await?;
+        }
+
+        Ok(response)
+    }
+
+    /// Removes the `AuthorizedDapp` identified by `dapp_definition_address`
+    /// from the current network in Profile, and revokes all Radix Connect
+    /// Mobile sessions established with it, so that the dApp needs to be
+    /// verified again before the Wallet responds to it.
+    ///
+    /// # Emits Event
+    /// Emits `Event::ProfileSaved`.
+    pub async fn forget_authorized_dapp(
+        &self,
+        dapp_definition_address: DappDefinitionAddress,
+    ) -> Result<()> {
+        let network_id = self.current_network_id()?;
+        self.update_profile_with(|profile| {
+            profile.networks.try_update_with(&network_id, |network| {
+                network.authorized_dapps.remove_id(&dapp_definition_address);
+            })
+        })
+        .await?;
+
+        self.radix_connect_mobile
+            .revoke_sessions_for_dapp(dapp_definition_address)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = SargonOS;
+
+    const ORIGIN: &str = "https://dashboard.radixdlt.com";
+
+    async fn boot_with_networking_driver(
+        networking_driver: MockNetworkingDriver,
+    ) -> Arc<SUT> {
+        let os =
+            SUT::boot_test_with_networking_driver(Arc::new(networking_driver))
+                .await
+                .unwrap();
+        os.import_wallet(&Profile::sample(), true).await.unwrap();
+        os
+    }
+
+    async fn boot() -> Arc<SUT> {
+        boot_with_networking_driver(networking_driver_verifying(
+            ORIGIN,
+            AuthorizedDapp::sample_mainnet_dashboard().dapp_definition_address,
+        ))
+        .await
+    }
+
+    fn unvalidated(
+        items: DappToWalletInteractionItems,
+    ) -> DappToWalletInteractionUnvalidated {
+        DappToWalletInteractionUnvalidated::new(
+            WalletInteractionId::sample(),
+            items,
+            DappToWalletInteractionMetadataUnvalidated::new(
+                WalletInteractionVersion::current(),
+                NetworkID::Mainnet,
+                ORIGIN,
+                AuthorizedDapp::sample_mainnet_dashboard()
+                    .dapp_definition_address
+                    .to_string(),
+            ),
+        )
+    }
+
+    #[actix_rt::test]
+    async fn login_persists_authorized_dapp() {
+        let os = boot().await;
+        let persona = Persona::sample_mainnet_other();
+        let items = DappToWalletInteractionItems::AuthorizedRequest(
+            DappToWalletInteractionAuthorizedRequestItems::new(
+                DappToWalletInteractionAuthRequestItem::LoginWithoutChallenge,
+                None,
+                DappToWalletInteractionAccountsRequestItem::new(
+                    RequestedQuantity::exactly(1),
+                    None,
+                ),
+                None,
+                None,
+                None,
+                None,
+            ),
+        );
+
+        let DappInteractionPlan::Request { interaction, steps } = os
+            .prepare_dapp_interaction(unvalidated(items))
+            .await
+            .unwrap()
+        else {
+            panic!("Expected request")
+        };
+        assert_eq!(steps.len(), 2);
+
+        let account = Account::sample_mainnet_bob();
+        let decision = DappInteractionDecision {
+            persona: Some(persona.address),
+            ongoing_accounts: Some(DappInteractionAccountsDecision::new(
+                [account.address],
+                None,
+            )),
+            ..Default::default()
+        };
+        let response = os
+            .respond_to_dapp_interaction(interaction, decision)
+            .await
+            .unwrap();
+        assert!(matches!(
+            response,
+            WalletToDappInteractionResponse::Success(_)
+        ));
+
+        let authorized_persona = os
+            .current_network()
+            .unwrap()
+            .authorized_dapps
+            .get_id(
+                AuthorizedDapp::sample_mainnet_dashboard()
+                    .dapp_definition_address,
+            )
+            .unwrap()
+            .references_to_authorized_personas
+            .get_id(persona.address)
+            .cloned()
+            .unwrap();
+        assert_eq!(
+            authorized_persona.shared_accounts,
+            Some(SharedToDappWithPersonaAccountAddresses::new(
+                RequestedQuantity::exactly(1),
+                [account.address]
+            ))
+        );
+    }
+
+    #[actix_rt::test]
+    async fn prepare_wrong_network_is_rejected() {
+        let os = boot().await;
+        let mut interaction =
+            unvalidated(DappToWalletInteractionItems::sample());
+        interaction.metadata.network_id = NetworkID::Stokenet;
+
+        let plan = os.prepare_dapp_interaction(interaction).await.unwrap();
+
+        assert!(matches!(plan, DappInteractionPlan::Reject { .. }));
+    }
+
+    #[actix_rt::test]
+    async fn prepare_unverified_origin_is_rejected() {
+        let os = boot_with_networking_driver(
+            MockNetworkingDriver::with_lazy_responses(|_, _| {
+                NetworkResponse::new(404, vec![])
+            }),
+        )
+        .await;
+
+        let plan = os
+            .prepare_dapp_interaction(unvalidated(
+                DappToWalletInteractionItems::sample(),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            plan,
+            DappInteractionPlan::reject(
+                WalletInteractionId::sample(),
+                DappWalletInteractionErrorType::RadixJsonNotFound
+            )
+        );
+    }
+
+    #[actix_rt::test]
+    async fn prepare_origin_claiming_other_dapp_is_rejected() {
+        let os = boot_with_networking_driver(networking_driver_verifying(
+            ORIGIN,
+            AccountAddress::sample_mainnet_other(),
+        ))
+        .await;
+
+        let plan = os
+            .prepare_dapp_interaction(unvalidated(
+                DappToWalletInteractionItems::sample(),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            plan,
+            DappInteractionPlan::reject(
+                WalletInteractionId::sample(),
+                DappWalletInteractionErrorType::UnknownDappDefinitionAddress
+            )
+        );
+    }
+
+    #[actix_rt::test]
+    async fn prepare_unreachable_origin_is_err() {
+        let os = boot_with_networking_driver(
+            MockNetworkingDriver::new_always_failing(),
+        )
+        .await;
+
+        let res = os
+            .prepare_dapp_interaction(unvalidated(
+                DappToWalletInteractionItems::sample(),
+            ))
+            .await;
+
+        assert!(res.is_err());
+    }
+
+    #[actix_rt::test]
+    async fn prepare_in_developer_mode_skips_origin_verification() {
+        let os = boot_with_networking_driver(
+            MockNetworkingDriver::new_always_failing(),
+        )
+        .await;
+        os.update_profile_with(|profile| {
+            profile.app_preferences.security.is_developer_mode_enabled =
+                IsDeveloperModeEnabled(true);
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        let plan = os
+            .prepare_dapp_interaction(unvalidated(
+                DappToWalletInteractionItems::sample(),
+            ))
+            .await
+            .unwrap();
+
+        assert!(!matches!(plan, DappInteractionPlan::Reject { .. }));
+    }
+
+    #[actix_rt::test]
+    async fn verify_dapp_origin_uses_current_gateway() {
+        let os = SUT::boot_test_with_networking_driver(Arc::new(
+            MockNetworkingDriver::new_always_failing(),
+        ))
+        .await
+        .unwrap();
+        os.change_current_gateway(Gateway::stokenet())
+            .await
+            .unwrap();
+
+        let verdict = os
+            .verify_dapp_origin(
+                DappOrigin::new("not a url"),
+                AccountAddress::sample_stokenet(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(verdict, DappOriginVerdict::InvalidOrigin);
+
+        let res = os
+            .verify_dapp_origin(
+                DappOrigin::new("not a url"),
+                AccountAddress::sample_mainnet(),
+            )
+            .await;
+        assert!(matches!(res, Err(CommonError::NetworkDiscrepancy { .. })));
+    }
+
+    #[actix_rt::test]
+    async fn invalid_decision_does_not_update_profile() {
+        let os = boot().await;
+        let profile = os.profile().unwrap();
+        let DappInteractionPlan::Request { interaction, .. } = os
+            .prepare_dapp_interaction(unvalidated(
+                DappToWalletInteractionItems::AuthorizedRequest(
+                    DappToWalletInteractionAuthorizedRequestItems::sample(),
+                ),
+            ))
+            .await
+            .unwrap()
+        else {
+            panic!("Expected request")
+        };
+
+        let res = os
+            .respond_to_dapp_interaction(
+                interaction,
+                DappInteractionDecision::default(),
+            )
+            .await;
+
+        assert!(res.is_err());
+        assert_eq!(os.profile().unwrap(), profile);
+    }
+
+    #[actix_rt::test]
+    async fn forget_authorized_dapp_removes_it_and_its_sessions() {
+        let os = boot().await;
+        let dapp_definition_address =
+            AuthorizedDapp::sample_mainnet_dashboard().dapp_definition_address;
+        let mut session = Session::sample();
+        session.dapp_definition_address = Some(dapp_definition_address);
+        session.last_used_at = Timestamp::now_utc();
+        os.secure_storage
+            .save_radix_connect_mobile_session(
+                session.id.to_string(),
+                session.serialize_to_bytes().unwrap().into(),
+            )
+            .await
+            .unwrap();
+        os.secure_storage
+            .save_radix_connect_mobile_session_ids(vec![session.id.to_string()])
+            .await
+            .unwrap();
+        assert!(os
+            .current_network()
+            .unwrap()
+            .authorized_dapps
+            .get_id(dapp_definition_address)
+            .is_some());
+        assert_eq!(
+            os.radix_connect_mobile().sessions().await.unwrap().len(),
+            1
+        );
+
+        os.forget_authorized_dapp(dapp_definition_address)
+            .await
+            .unwrap();
+
+        assert!(os
+            .current_network()
+            .unwrap()
+            .authorized_dapps
+            .get_id(dapp_definition_address)
+            .is_none());
+        assert!(os
+            .radix_connect_mobile()
+            .sessions()
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
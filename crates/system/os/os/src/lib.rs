@@ -3,6 +3,7 @@
 #![feature(trait_upcasting)]
 
 mod bios;
+mod dapp_interaction;
 mod entity_creating_with_factor_source_and_derivation_outcome;
 mod mfa;
 mod os_testing_support;
@@ -14,6 +15,7 @@ mod sargon_os_factors;
 mod sargon_os_fungible_pricing;
 mod sargon_os_gateway;
 mod sargon_os_gateway_client;
+mod sargon_os_ledger_hardware_wallet;
 mod sargon_os_nft_pricing;
 mod sargon_os_p2p_transport_profiles;
 mod sargon_os_personas;
@@ -21,10 +23,13 @@ mod sargon_os_profile;
 mod sargon_os_radix_connect_mobile;
 mod sargon_os_relay_service;
 mod sargon_os_token_price_services;
+mod sargon_os_transactions_coordination;
+mod sargon_os_watch_only_accounts;
 mod testing_interactors;
 
 pub mod prelude {
     pub use crate::bios::*;
+    pub use crate::dapp_interaction::*;
     pub use crate::entity_creating_with_factor_source_and_derivation_outcome::*;
     pub use crate::mfa::*;
     pub use crate::os_testing_support::*;
@@ -36,6 +41,7 @@ pub mod prelude {
     pub use crate::sargon_os_fungible_pricing::*;
     pub use crate::sargon_os_gateway::*;
     pub use crate::sargon_os_gateway_client::*;
+    pub use crate::sargon_os_ledger_hardware_wallet::*;
     pub use crate::sargon_os_nft_pricing::*;
     pub use crate::sargon_os_p2p_transport_profiles::*;
     pub use crate::sargon_os_personas::*;
@@ -43,6 +49,8 @@ pub mod prelude {
     pub use crate::sargon_os_radix_connect_mobile::*;
     pub use crate::sargon_os_relay_service::*;
     pub use crate::sargon_os_token_price_services::*;
+    pub use crate::sargon_os_transactions_coordination::*;
+    pub use crate::sargon_os_watch_only_accounts::*;
     pub use crate::testing_interactors::*;
     pub use clients::prelude::ArculusMinFirmwareVersionRequirement;
     pub use clients::prelude::NFCTagArculusInteractonPurpose;
@@ -0,0 +1,103 @@
+use crate::prelude::*;
+
+impl SargonOS {
+    /// Creates a manifest locking the Primary role of the access controller
+    /// of the securified entity at `address`, freezing it in case its
+    /// Primary factors are compromised. Must be signed with the Recovery role.
+    pub fn make_lock_primary_role_manifest(
+        &self,
+        address: AddressOfAccountOrPersona,
+    ) -> Result<TransactionManifest> {
+        self.access_controller_address_of_entity(address)
+            .map(TransactionManifest::lock_primary_role)
+    }
+
+    /// Creates a manifest unlocking the Primary role of the access controller
+    /// of the securified entity at `address`. Must be signed with the Recovery
+    /// role.
+    pub fn make_unlock_primary_role_manifest(
+        &self,
+        address: AddressOfAccountOrPersona,
+    ) -> Result<TransactionManifest> {
+        self.access_controller_address_of_entity(address)
+            .map(TransactionManifest::unlock_primary_role)
+    }
+
+    fn access_controller_address_of_entity(
+        &self,
+        address: AddressOfAccountOrPersona,
+    ) -> Result<AccessControllerAddress> {
+        let profile_snapshot = self.profile()?;
+        let entity = profile_snapshot.entity_by_address(address)?;
+        entity
+            .security_state()
+            .as_securified()
+            .map(|control| control.access_controller_address)
+            .ok_or(CommonError::SecurityStateNotSecurified)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = SargonOS;
+
+    async fn boot() -> Arc<SUT> {
+        let os = SUT::fast_boot().await;
+        let profile = Profile::sample_from(
+            FactorSource::sample_all(),
+            [&Account::sample_at(0), &Account::sample_at(2)],
+            [],
+        );
+        os.import_wallet(&profile, true).await.unwrap();
+        os
+    }
+
+    #[actix_rt::test]
+    async fn lock_and_unlock_primary_role_of_securified_account() {
+        let account = Account::sample_at(2);
+        let ac_address = account
+            .security_state()
+            .as_securified()
+            .unwrap()
+            .access_controller_address;
+        let os = boot().await;
+
+        let lock = os
+            .make_lock_primary_role_manifest(account.address.into())
+            .unwrap();
+        assert_eq!(
+            lock.access_controllers_locking_primary_role(),
+            vec![ac_address]
+        );
+
+        let unlock = os
+            .make_unlock_primary_role_manifest(account.address.into())
+            .unwrap();
+        assert_eq!(
+            unlock.access_controllers_unlocking_primary_role(),
+            vec![ac_address]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn lock_primary_role_of_unsecurified_account_fails() {
+        let account = Account::sample_at(0);
+        let os = boot().await;
+
+        let result = os.make_lock_primary_role_manifest(account.address.into());
+        assert_eq!(result, Err(CommonError::SecurityStateNotSecurified));
+    }
+
+    #[actix_rt::test]
+    async fn lock_primary_role_of_unknown_entity_fails() {
+        let os = boot().await;
+
+        let result = os.make_lock_primary_role_manifest(
+            Account::sample_at(1).address.into(),
+        );
+        assert!(result.is_err());
+    }
+}
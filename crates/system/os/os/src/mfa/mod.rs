@@ -1,6 +1,7 @@
 pub mod apply_security_structure;
 pub mod confirm_timed_recovery;
 pub mod factor_instances_derivation;
+mod lock_primary_role;
 mod sargon_os_access_controller_state;
 pub mod securify_unsecurified_entity;
 mod stop_timed_recovery;
@@ -9,6 +10,7 @@ pub mod update_securified_entity;
 pub use apply_security_structure::*;
 pub use confirm_timed_recovery::*;
 pub(crate) use factor_instances_derivation::*;
+pub use lock_primary_role::*;
 pub(crate) use securify_unsecurified_entity::*;
 pub use stop_timed_recovery::*;
 pub(crate) use update_securified_entity::*;
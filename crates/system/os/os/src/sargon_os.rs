@@ -10,6 +10,8 @@ pub struct SargonOS {
     pub(crate) profile_state_holder: Arc<ProfileStateHolder>,
     pub(crate) clients: Clients,
     pub(crate) radix_connect_mobile: Arc<RadixConnectMobile>,
+    pub(crate) dapp_origin_verifier: Arc<DappOriginVerifier>,
+    pub(crate) transactions_coordination: Arc<TransactionsCoordination>,
     pub(crate) interactors: Interactors,
     pub(crate) host_id: HostId,
     pub(crate) host_info: HostInfo,
@@ -90,6 +92,12 @@ impl SargonOS {
                 networking_driver.clone(),
                 secure_storage.clone(),
             ),
+            dapp_origin_verifier: Arc::new(DappOriginVerifier::new(
+                networking_driver.clone(),
+            )),
+            transactions_coordination: Arc::new(
+                TransactionsCoordination::default(),
+            ),
             interactors,
             host_id,
             host_info,
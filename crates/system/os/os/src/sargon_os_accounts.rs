@@ -436,6 +436,26 @@ impl SargonOS {
         let to_personas =
             || -> Personas { entities.clone().to_personas().unwrap() };
 
+        // Accounts which were watch-only so far are now controlled by the
+        // wallet, so they are no longer watch-only.
+        let no_longer_watch_only = match entity_kind {
+            CAP26EntityKind::Account => {
+                let watch_only_accounts =
+                    self.profile_state_holder.access_profile_with(|p| {
+                        p.networks
+                            .get_id(network_id)
+                            .map(|n| n.watch_only_accounts.clone())
+                            .unwrap_or_default()
+                    })?;
+                to_accounts()
+                    .into_iter()
+                    .map(|account| account.address)
+                    .filter(|address| watch_only_accounts.contains_id(address))
+                    .collect_vec()
+            }
+            CAP26EntityKind::Identity => Vec::new(),
+        };
+
         self.update_profile_with(|p| {
             let networks = &mut p.networks;
             let networks_backup = networks.clone();
@@ -452,6 +472,9 @@ impl SargonOS {
                         match entity_kind {
                             CAP26EntityKind::Account => {
                                 network.accounts.extend(to_accounts());
+                                for address in no_longer_watch_only.iter() {
+                                    network.watch_only_accounts.remove_id(address);
+                                }
                             }
                             CAP26EntityKind::Identity => {
                                 network.personas.extend(to_personas());
@@ -517,6 +540,14 @@ impl SargonOS {
                 .await;
         }
 
+        for address in no_longer_watch_only {
+            self.event_bus
+                .emit(EventNotification::profile_modified(
+                    EventProfileModified::WatchOnlyAccountRemoved { address },
+                ))
+                .await;
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,111 @@
+use crate::prelude::*;
+
+impl SargonOS {
+    /// Returns a `LedgerHardwareWalletInteractor` sending requests over
+    /// `transport`, resolving the Ledger device of a factor source from the
+    /// `LedgerHardwareWalletFactorSource`s in the active Profile.
+    pub fn ledger_hardware_wallet_interactor(
+        &self,
+        transport: Arc<dyn LedgerHardwareWalletTransport>,
+    ) -> LedgerHardwareWalletInteractor {
+        let profile_state_holder = self.profile_state_holder.clone();
+        LedgerHardwareWalletInteractor::new(
+            transport,
+            Arc::new(move |factor_source_id| {
+                profile_state_holder
+                    .profile()?
+                    .factor_source_by_id::<LedgerHardwareWalletFactorSource>(
+                        factor_source_id,
+                    )
+                    .map(LedgerDevice::from)
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = SargonOS;
+
+    /// Asserts that requests are made to the Ledger device of
+    /// `LedgerHardwareWalletFactorSource::sample()`.
+    struct MockLedgerTransport;
+
+    #[async_trait::async_trait]
+    impl LedgerHardwareWalletTransport for MockLedgerTransport {
+        async fn send_ledger_request(
+            &self,
+            encoded_request: BagOfBytes,
+        ) -> Result<BagOfBytes> {
+            let request =
+                encoded_request.deserialize::<LedgerHardwareWalletRequest>()?;
+            let LedgerHardwareWalletRequestKind::DeriveAndDisplayAddress {
+                ledger_device,
+                ..
+            } = request.kind
+            else {
+                panic!("Unexpected request")
+            };
+            assert_eq!(ledger_device, LedgerDevice::sample());
+            let derived_key = LedgerDerivedPublicKey::from(
+                MnemonicWithPassphrase::sample_ledger()
+                    .derive_public_keys([DerivationPath::sample()])[0]
+                    .clone(),
+            );
+            Ok(LedgerHardwareWalletResponse::success(
+                request.interaction_id,
+                LedgerHardwareWalletResponseSuccess::DeriveAndDisplayAddress(
+                    LedgerDerivedAddress::new(
+                        derived_key,
+                        AccountAddress::sample().to_string(),
+                    ),
+                ),
+            )
+            .serialize_to_bytes()?
+            .into())
+        }
+    }
+
+    #[actix_rt::test]
+    async fn ledger_device_is_resolved_from_profile() {
+        let os = SUT::fast_boot().await;
+        os.import_wallet(&Profile::sample(), true).await.unwrap();
+        let sut =
+            os.ledger_hardware_wallet_interactor(Arc::new(MockLedgerTransport));
+
+        let derived_address = sut
+            .derive_and_display_address(
+                LedgerHardwareWalletFactorSource::sample().id,
+                DerivationPath::sample(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            derived_address.address,
+            AccountAddress::sample().to_string()
+        );
+    }
+
+    #[actix_rt::test]
+    async fn unknown_ledger_factor_source_is_err() {
+        let os = SUT::fast_boot().await;
+        let sut =
+            os.ledger_hardware_wallet_interactor(Arc::new(MockLedgerTransport));
+
+        let result = sut
+            .derive_and_display_address(
+                LedgerHardwareWalletFactorSource::sample().id,
+                DerivationPath::sample(),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(CommonError::ProfileDoesNotContainFactorSourceWithID { .. })
+        ));
+    }
+}
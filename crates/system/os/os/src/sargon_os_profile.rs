@@ -61,6 +61,50 @@ impl SargonOS {
         Ok(())
     }
 
+    /// Three-way merges the active Profile, being the local snapshot, with
+    /// the `remote` snapshot of the same Profile, e.g. from a backup, where
+    /// both have diverged from the common `base` snapshot.
+    ///
+    /// Does **not** change the active Profile, once the user has resolved
+    /// the conflicts of the merge, call `apply_profile_merge`.
+    pub fn merge_profile(
+        &self,
+        base: Profile,
+        remote: Profile,
+    ) -> Result<ProfileMerge> {
+        self.profile()?.merge(&base, &remote)
+    }
+
+    /// Resolves the conflicts of `merge` with `resolutions`, one resolution
+    /// per conflict in the same order, and sets the resolved Profile as the
+    /// active one, saving it into secure storage.
+    ///
+    /// Fails with `CommonError::ProfileChangedSinceMerge` if the active
+    /// Profile has changed since `merge_profile`, since applying the merge
+    /// would discard those changes, in which case the Profile must be merged
+    /// again.
+    ///
+    /// # Emits Event
+    /// Emits `Event::ProfileSaved` after having successfully written the JSON
+    /// of the active profile to secure storage.
+    pub async fn apply_profile_merge(
+        &self,
+        merge: ProfileMerge,
+        resolutions: Vec<ProfileMergeConflictResolution>,
+    ) -> Result<Profile> {
+        let local_last_modified = merge.local_last_modified;
+        let profile = merge.resolve(resolutions)?;
+        self.update_profile_with(|active| {
+            if active.header.last_modified != local_last_modified {
+                return Err(CommonError::ProfileChangedSinceMerge);
+            }
+            *active = profile.clone();
+            Ok(())
+        })
+        .await?;
+        Ok(profile)
+    }
+
     /// Checks if current Profile contains any `ProfileNetwork`s.
     pub fn has_any_network(&self) -> Result<bool> {
         self.profile_state_holder
@@ -512,4 +556,118 @@ mod tests {
         // ASSERT
         assert_eq!(os.profile().unwrap(), Profile::sample());
     }
+
+    #[actix_rt::test]
+    async fn merge_profile_without_conflicts_and_apply() {
+        // ARRANGE
+        let os = SUT::fast_boot().await;
+        let base = Profile::sample();
+        os.import_wallet(&base, true).await.unwrap();
+        let mut account = Account::sample_mainnet();
+        account.display_name = DisplayName::new("Remote").unwrap();
+        let mut remote = base.clone();
+        remote.update_account(&account.address, |a| {
+            a.display_name = account.display_name
+        });
+
+        // ACT
+        let merge = os.merge_profile(base, remote).unwrap();
+        assert!(merge.is_conflict_free());
+        os.with_timeout(|x| x.apply_profile_merge(merge, Vec::new()))
+            .await
+            .unwrap();
+
+        // ASSERT
+        assert_eq!(
+            os.profile().unwrap().account_by_address(account.address),
+            Ok(account)
+        );
+    }
+
+    #[actix_rt::test]
+    async fn merge_profile_with_conflict_and_apply_keep_remote() {
+        // ARRANGE
+        let os = SUT::fast_boot().await;
+        let base = Profile::sample();
+        os.import_wallet(&base, true).await.unwrap();
+        let mut local = Account::sample_mainnet();
+        local.display_name = DisplayName::new("Local").unwrap();
+        os.with_timeout(|x| x.update_account(local.clone()))
+            .await
+            .unwrap();
+        let mut remote_account = Account::sample_mainnet();
+        remote_account.display_name = DisplayName::new("Remote").unwrap();
+        let mut remote = base.clone();
+        remote.update_account(&remote_account.address, |a| {
+            a.display_name = remote_account.display_name
+        });
+
+        // ACT
+        let merge = os.merge_profile(base, remote).unwrap();
+        assert_eq!(merge.conflicts.len(), 1);
+        os.with_timeout(|x| {
+            x.apply_profile_merge(
+                merge,
+                vec![ProfileMergeConflictResolution::KeepRemote],
+            )
+        })
+        .await
+        .unwrap();
+
+        // ASSERT
+        assert_eq!(
+            os.profile()
+                .unwrap()
+                .account_by_address(remote_account.address),
+            Ok(remote_account)
+        );
+    }
+
+    #[actix_rt::test]
+    async fn apply_profile_merge_after_local_change_is_err() {
+        // ARRANGE
+        let os = SUT::fast_boot().await;
+        let base = Profile::sample();
+        os.import_wallet(&base, true).await.unwrap();
+        let mut remote_account = Account::sample_mainnet();
+        remote_account.display_name = DisplayName::new("Remote").unwrap();
+        let mut remote = base.clone();
+        remote.update_account(&remote_account.address, |a| {
+            a.display_name = remote_account.display_name
+        });
+        let merge = os.merge_profile(base, remote).unwrap();
+        let mut local = Account::sample_mainnet_other();
+        local.display_name = DisplayName::new("Changed since").unwrap();
+        os.with_timeout(|x| x.update_account(local.clone()))
+            .await
+            .unwrap();
+
+        // ACT
+        let result = os
+            .with_timeout(|x| x.apply_profile_merge(merge, Vec::new()))
+            .await;
+
+        // ASSERT
+        assert_eq!(result, Err(CommonError::ProfileChangedSinceMerge));
+        assert_eq!(
+            os.profile().unwrap().account_by_address(local.address),
+            Ok(local)
+        );
+    }
+
+    #[actix_rt::test]
+    async fn merge_profile_with_other_profile_is_err() {
+        // ARRANGE
+        let os = SUT::fast_boot().await;
+
+        // ACT
+        let result =
+            os.merge_profile(Profile::sample(), Profile::sample_other());
+
+        // ASSERT
+        assert_eq!(
+            result,
+            Err(CommonError::CannotMergeProfilesWithDifferentIDs)
+        );
+    }
 }
@@ -2,6 +2,35 @@ use crate::prelude::*;
 
 struct SecureStorageSessionStorage {
     secure_storage: SecureStorageClient,
+    /// Held during each load-modify-save of the index of saved sessions, so
+    /// that concurrent saving and deleting of sessions do not overwrite each
+    /// other's changes.
+    session_ids_lock: async_std::sync::Mutex<()>,
+}
+
+impl SecureStorageSessionStorage {
+    fn new(secure_storage: SecureStorageClient) -> Self {
+        Self {
+            secure_storage,
+            session_ids_lock: async_std::sync::Mutex::new(()),
+        }
+    }
+
+    /// Adds `session_id` to the index of saved sessions, if not present.
+    async fn index_session_id(&self, session_id: SessionID) -> Result<()> {
+        let _guard = self.session_ids_lock.lock().await;
+        let mut session_ids = self
+            .secure_storage
+            .load_radix_connect_mobile_session_ids()
+            .await?;
+        if session_ids.contains(&session_id.to_string()) {
+            return Ok(());
+        }
+        session_ids.push(session_id.to_string());
+        self.secure_storage
+            .save_radix_connect_mobile_session_ids(session_ids)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -16,17 +45,50 @@ impl RadixConnectMobileSessionStorage for SecureStorageSessionStorage {
                 session_id.to_string(),
                 encoded_session,
             )
-            .await
+            .await?;
+        self.index_session_id(session_id).await
     }
 
+    /// Loads the session, migrating sessions saved before the index of
+    /// sessions existed into it the first time they are loaded.
     async fn load_session(
         &self,
         session_id: SessionID,
     ) -> Result<Option<BagOfBytes>> {
-        self.secure_storage
+        let encoded_session = self
+            .secure_storage
             .load_radix_connect_mobile_session(session_id.to_string())
+            .await?;
+        if encoded_session.is_some() {
+            self.index_session_id(session_id).await?;
+        }
+        Ok(encoded_session)
+    }
+
+    async fn delete_session(&self, session_id: SessionID) -> Result<()> {
+        self.secure_storage
+            .delete_radix_connect_mobile_session(session_id.to_string())
+            .await?;
+
+        let _guard = self.session_ids_lock.lock().await;
+        let mut session_ids = self
+            .secure_storage
+            .load_radix_connect_mobile_session_ids()
+            .await?;
+        session_ids.retain(|id| *id != session_id.to_string());
+        self.secure_storage
+            .save_radix_connect_mobile_session_ids(session_ids)
             .await
     }
+
+    async fn load_session_ids(&self) -> Result<Vec<SessionID>> {
+        self.secure_storage
+            .load_radix_connect_mobile_session_ids()
+            .await?
+            .into_iter()
+            .map(|id| id.parse::<SessionID>())
+            .collect()
+    }
 }
 
 impl SargonOS {
@@ -43,7 +105,7 @@ impl SargonOS {
 
         Arc::new(RadixConnectMobile::new_with_relay_service_url_resolver(
             networking_driver,
-            Arc::new(SecureStorageSessionStorage { secure_storage }),
+            Arc::new(SecureStorageSessionStorage::new(secure_storage)),
             relay_service_url_resolver,
         ))
     }
@@ -55,6 +117,69 @@ impl SargonOS {
     pub fn radix_connect_mobile(&self) -> Arc<RadixConnectMobile> {
         self.radix_connect_mobile.clone()
     }
+
+    /// Handles a Radix Connect Mobile deep link `url`, see
+    /// `RadixConnectMobile::handle_deep_link`, verifying the origin of
+    /// requests establishing a new session using `verify_dapp_origin`.
+    ///
+    /// If the origin fails verification a failure response is sent to the
+    /// dApp and `CommonError::DappOriginNotVerified` is returned, so the host
+    /// never shows the request to the user. Verification is skipped if
+    /// developer mode is enabled.
+    pub async fn handle_radix_connect_mobile_deep_link(
+        &self,
+        url: String,
+    ) -> Result<RadixConnectMobileSessionRequest> {
+        let request = self.radix_connect_mobile.handle_deep_link(url).await?;
+        if !request.origin_requires_validation {
+            return Ok(request);
+        }
+
+        let failure = match request
+            .interaction
+            .metadata
+            .dapp_definition_address
+            .parse::<DappDefinitionAddress>()
+        {
+            Ok(dapp_definition_address) => {
+                self.dapp_origin_failure(
+                    &request.origin,
+                    dapp_definition_address,
+                )
+                .await?
+            }
+            Err(_) => Some(
+                DappWalletInteractionErrorType::UnknownDappDefinitionAddress,
+            ),
+        };
+        let Some(error) = failure else {
+            return Ok(request);
+        };
+
+        let response = WalletToDappInteractionResponse::Failure(
+            WalletToDappInteractionFailureResponse::new(
+                request.interaction.interaction_id.clone(),
+                error,
+                None,
+            ),
+        );
+        if let Err(error) = self
+            .radix_connect_mobile
+            .send_dapp_interaction_response(
+                RadixConnectMobileWalletResponse::new(
+                    request.session_id,
+                    response,
+                ),
+            )
+            .await
+        {
+            warn!("Failed to reject unverified dApp origin: {:?}", error);
+        }
+
+        Err(CommonError::DappOriginNotVerified {
+            origin: request.origin.to_string(),
+        })
+    }
 }
 
 #[cfg(test)]
@@ -65,6 +190,42 @@ mod tests {
     #[allow(clippy::upper_case_acronyms)]
     type SUT = SargonOS;
 
+    const DEEP_LINK_ORIGIN: &str = "https://d1vq8n3dnxcyhd.cloudfront.net";
+
+    fn deep_link_url() -> String {
+        let params =
+            serde_json::from_str::<std::collections::HashMap<String, String>>(
+                prelude::fixture_interaction!("deep_link_request_params"),
+            )
+            .unwrap();
+        let query = [
+            ("sessionId", "session_id"),
+            ("origin", "origin"),
+            ("publicKey", "public_key"),
+            ("request", "request"),
+            ("dAppDefinitionAddress", "dapp_definition_address"),
+            ("signature", "signature"),
+            ("identity", "identity_public_key"),
+        ]
+        .into_iter()
+        .map(|(key, field)| format!("{}={}", key, params[field]))
+        .join("&");
+        format!("radixwallet://?{}", query)
+    }
+
+    async fn boot_on_stokenet(
+        networking_driver: MockNetworkingDriver,
+    ) -> Arc<SUT> {
+        let os =
+            SUT::boot_test_with_networking_driver(Arc::new(networking_driver))
+                .await
+                .unwrap();
+        os.change_current_gateway(Gateway::stokenet())
+            .await
+            .unwrap();
+        os
+    }
+
     #[actix_rt::test]
     async fn radix_connect_mobile_is_eager_and_uses_updated_relay_without_recreation(
     ) {
@@ -83,7 +244,8 @@ mod tests {
         let mobile = os.radix_connect_mobile();
         assert!(Arc::ptr_eq(&mobile, &os.radix_connect_mobile()));
 
-        let session = Session::sample();
+        let mut session = Session::sample();
+        session.last_used_at = Timestamp::now_utc();
         os.secure_storage
             .save_radix_connect_mobile_session(
                 session.id.to_string(),
@@ -116,4 +278,132 @@ mod tests {
         let urls = requested_urls.lock().unwrap().clone();
         assert_eq!(urls, vec![first_relay_url, second_relay_url]);
     }
+
+    #[actix_rt::test]
+    async fn secure_storage_session_storage_tracks_session_ids() {
+        let os = SUT::fast_boot().await;
+        let sut = SecureStorageSessionStorage::new(os.secure_storage.clone());
+        let session = Session::sample();
+        let other = Session::sample_other();
+
+        for session in [&session, &other, &session] {
+            sut.save_session(
+                session.id,
+                session.serialize_to_bytes().unwrap().into(),
+            )
+            .await
+            .unwrap();
+        }
+        assert_eq!(
+            sut.load_session_ids().await.unwrap(),
+            vec![session.id, other.id]
+        );
+
+        sut.delete_session(session.id).await.unwrap();
+
+        assert_eq!(sut.load_session_ids().await.unwrap(), vec![other.id]);
+        assert_eq!(sut.load_session(session.id).await.unwrap(), None);
+    }
+
+    #[actix_rt::test]
+    async fn secure_storage_session_storage_concurrent_saves_index_all() {
+        let os = SUT::fast_boot().await;
+        let sut = Arc::new(SecureStorageSessionStorage::new(
+            os.secure_storage.clone(),
+        ));
+        let sessions = (0..10u8)
+            .map(|i| {
+                let mut session = Session::sample();
+                session.id = SessionID(Uuid::from_bytes([i; 16]));
+                session
+            })
+            .collect_vec();
+
+        let handles = sessions
+            .iter()
+            .cloned()
+            .map(|session| {
+                let sut = sut.clone();
+                actix_rt::spawn(async move {
+                    sut.save_session(
+                        session.id,
+                        session.serialize_to_bytes().unwrap().into(),
+                    )
+                    .await
+                })
+            })
+            .collect_vec();
+        for handle in handles {
+            handle.await.unwrap().unwrap();
+        }
+
+        assert_eq!(sut.load_session_ids().await.unwrap().len(), sessions.len());
+    }
+
+    #[actix_rt::test]
+    async fn handle_deep_link_of_new_session_verifies_origin() {
+        let os = boot_on_stokenet(networking_driver_verifying(
+            DEEP_LINK_ORIGIN,
+            "account_tdx_2_12yf9gd53yfep7a669fv2t3wm7nz9zeezwd04n02a433ker8vza6rhe"
+                .parse()
+                .unwrap(),
+        ))
+        .await;
+
+        let request = os
+            .handle_radix_connect_mobile_deep_link(deep_link_url())
+            .await
+            .unwrap();
+
+        assert!(request.origin_requires_validation);
+        assert_eq!(request.origin, DappOrigin::new(DEEP_LINK_ORIGIN));
+    }
+
+    #[actix_rt::test]
+    async fn handle_deep_link_of_unverified_origin_is_err() {
+        let os = boot_on_stokenet(MockNetworkingDriver::with_lazy_responses(
+            |_, _| NetworkResponse::new(404, vec![]),
+        ))
+        .await;
+
+        let res = os
+            .handle_radix_connect_mobile_deep_link(deep_link_url())
+            .await;
+
+        assert_eq!(
+            res.err(),
+            Some(CommonError::DappOriginNotVerified {
+                origin: DEEP_LINK_ORIGIN.to_owned()
+            })
+        );
+    }
+
+    #[actix_rt::test]
+    async fn secure_storage_session_storage_indexes_legacy_session_on_load() {
+        let os = SUT::fast_boot().await;
+        let sut = SecureStorageSessionStorage::new(os.secure_storage.clone());
+        let session = Session::sample();
+        os.secure_storage
+            .save_radix_connect_mobile_session(
+                session.id.to_string(),
+                session.serialize_to_bytes().unwrap().into(),
+            )
+            .await
+            .unwrap();
+        assert!(sut.load_session_ids().await.unwrap().is_empty());
+
+        assert!(sut.load_session(session.id).await.unwrap().is_some());
+
+        assert_eq!(sut.load_session_ids().await.unwrap(), vec![session.id]);
+    }
+
+    #[actix_rt::test]
+    async fn secure_storage_session_storage_load_unknown_session() {
+        let os = SUT::fast_boot().await;
+        let sut = SecureStorageSessionStorage::new(os.secure_storage.clone());
+
+        assert_eq!(sut.load_session(SessionID::sample()).await.unwrap(), None);
+
+        assert!(sut.load_session_ids().await.unwrap().is_empty());
+    }
 }
@@ -0,0 +1,30 @@
+use crate::prelude::*;
+use async_std::sync::Mutex;
+use std::sync::RwLock;
+
+/// In-memory state of a `SargonOS` coordinating concurrent operations on the
+/// transactions it persists in unsafe storage, so that they do not overwrite
+/// each other's changes.
+#[derive(Default)]
+pub struct TransactionsCoordination {
+    /// Held during each load-modify-save of the pending transactions.
+    pub pending_transactions_lock: Mutex<()>,
+
+    /// The hashes of the pending transactions whose polling has been
+    /// cancelled, checked by ongoing polling between its delays.
+    pub cancelled_pending_transactions: RwLock<HashSet<String>>,
+
+    /// Held during each load-modify-save of the queue of apply shield
+    /// transactions, both when enqueuing and when processing transactions.
+    pub apply_shield_transactions_queue_lock: Mutex<()>,
+
+    /// Held while the queue of apply shield transactions is being processed,
+    /// so that it is processed by at most one task at a time.
+    pub apply_shield_transactions_queue_processing: Mutex<()>,
+}
+
+impl SargonOS {
+    pub fn transactions_coordination(&self) -> Arc<TransactionsCoordination> {
+        self.transactions_coordination.clone()
+    }
+}
@@ -0,0 +1,421 @@
+use crate::prelude::*;
+
+impl SargonOS {
+    /// Returns the watch-only accounts on the current network, empty if no
+    /// watch-only accounts on the network.
+    pub fn watch_only_accounts_on_current_network(
+        &self,
+    ) -> Result<WatchOnlyAccounts> {
+        self.profile_state_holder
+            .watch_only_accounts_on_current_network()
+    }
+}
+
+impl SargonOS {
+    /// Starts watching the account of `address` on the current network.
+    ///
+    /// Throws `WatchOnlyAccountIsControlledByWallet` if the account is
+    /// controlled by this wallet, and `WatchOnlyAccountAlreadyExists` if it
+    /// is already watched.
+    ///
+    /// # Emits Event
+    /// Emits `Event::ProfileModified { change: EventProfileModified::WatchOnlyAccountAdded { address } }`
+    pub async fn add_watch_only_account(
+        &self,
+        address: AccountAddress,
+        display_name: DisplayName,
+        appearance_id: AppearanceID,
+    ) -> Result<()> {
+        self.update_profile_with(|profile| {
+            let network =
+                Self::current_network_of_watch_only_account(profile, address)?;
+
+            if network.accounts.contains_id(address) {
+                return Err(
+                    CommonError::WatchOnlyAccountIsControlledByWallet {
+                        address: address.to_string(),
+                    },
+                );
+            }
+            if network.watch_only_accounts.contains_id(address) {
+                return Err(CommonError::WatchOnlyAccountAlreadyExists {
+                    address: address.to_string(),
+                });
+            }
+
+            let watch_only_account =
+                WatchOnlyAccount::new(address, display_name, appearance_id);
+            profile
+                .networks
+                .update_with(address.network_id(), |network| {
+                    network
+                        .watch_only_accounts
+                        .append(watch_only_account.clone());
+                });
+            Ok(())
+        })
+        .await?;
+
+        self.event_bus
+            .emit(EventNotification::profile_modified(
+                EventProfileModified::WatchOnlyAccountAdded { address },
+            ))
+            .await;
+
+        Ok(())
+    }
+
+    /// Updates the display name and appearance of the watch-only account of
+    /// `address` on the current network. Returns false if no such account
+    /// is watched.
+    ///
+    /// # Emits Event
+    /// Emits `Event::ProfileModified { change: EventProfileModified::WatchOnlyAccountUpdated { address } }`
+    /// if the account was updated.
+    pub async fn update_watch_only_account(
+        &self,
+        address: AccountAddress,
+        display_name: DisplayName,
+        appearance_id: AppearanceID,
+    ) -> Result<bool> {
+        let did_update = self
+            .update_profile_with(|profile| {
+                Self::current_network_of_watch_only_account(profile, address)?;
+
+                let mut did_update = false;
+                profile
+                    .networks
+                    .update_with(address.network_id(), |network| {
+                        did_update = network.watch_only_accounts.update_with(
+                            address,
+                            |a| {
+                                a.display_name = display_name;
+                                a.appearance_id = appearance_id;
+                            },
+                        );
+                    });
+                Ok(did_update)
+            })
+            .await?;
+
+        if did_update {
+            self.event_bus
+                .emit(EventNotification::profile_modified(
+                    EventProfileModified::WatchOnlyAccountUpdated { address },
+                ))
+                .await;
+        }
+
+        Ok(did_update)
+    }
+
+    /// Stops watching the account of `address` on the current network.
+    /// Returns false if no such account is watched.
+    ///
+    /// # Emits Event
+    /// Emits `Event::ProfileModified { change: EventProfileModified::WatchOnlyAccountRemoved { address } }`
+    /// if the account was removed.
+    pub async fn remove_watch_only_account(
+        &self,
+        address: AccountAddress,
+    ) -> Result<bool> {
+        let did_remove = self
+            .update_profile_with(|profile| {
+                Self::current_network_of_watch_only_account(profile, address)?;
+
+                let mut did_remove = false;
+                profile
+                    .networks
+                    .update_with(address.network_id(), |network| {
+                        did_remove = network
+                            .watch_only_accounts
+                            .remove_id(&address)
+                            .is_some();
+                    });
+                Ok(did_remove)
+            })
+            .await?;
+
+        if did_remove {
+            self.event_bus
+                .emit(EventNotification::profile_modified(
+                    EventProfileModified::WatchOnlyAccountRemoved { address },
+                ))
+                .await;
+        }
+
+        Ok(did_remove)
+    }
+
+    /// The current network of `profile`, if `address` is on it.
+    fn current_network_of_watch_only_account(
+        profile: &Profile,
+        address: AccountAddress,
+    ) -> Result<&ProfileNetwork> {
+        let current_network = profile.current_network_id();
+        if address.network_id() != current_network {
+            return Err(CommonError::EntityOnWrongNetwork {
+                entity_kind: "WatchOnlyAccount".to_owned(),
+                wrong_network: address.network_id().to_string(),
+                expected_network: current_network.to_string(),
+            });
+        }
+        profile.current_network()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_rt::time::timeout;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = SargonOS;
+
+    async fn boot(event_bus_driver: Arc<RustEventBusDriver>) -> Arc<SUT> {
+        let drivers = Drivers::with_event_bus(event_bus_driver);
+        let mut clients = Clients::new(Bios::new(drivers));
+        clients.factor_instances_cache =
+            FactorInstancesCacheClient::in_memory();
+        let interactors = Interactors::new_from_clients(&clients);
+        let os = timeout(
+            SARGON_OS_TEST_MAX_ASYNC_DURATION,
+            SUT::boot_with_clients_and_interactor(clients, interactors),
+        )
+        .await
+        .unwrap();
+        os.with_timeout(|x| x.new_wallet()).await.unwrap();
+        os
+    }
+
+    async fn add_watched(os: &SUT) -> WatchOnlyAccount {
+        let watched = WatchOnlyAccount::sample();
+        os.with_timeout(|x| {
+            x.add_watch_only_account(
+                watched.address,
+                watched.display_name,
+                watched.appearance_id,
+            )
+        })
+        .await
+        .unwrap();
+        watched
+    }
+
+    #[actix_rt::test]
+    async fn add_watch_only_account() {
+        let event_bus_driver = RustEventBusDriver::new();
+        let os = boot(event_bus_driver.clone()).await;
+
+        let watched = add_watched(&os).await;
+
+        assert_eq!(
+            os.watch_only_accounts_on_current_network().unwrap(),
+            WatchOnlyAccounts::just(watched.clone())
+        );
+        assert!(os.accounts_on_current_network().unwrap().is_empty());
+        assert!(event_bus_driver.recorded().iter().any(|e| e.event
+            == Event::ProfileModified {
+                change: EventProfileModified::WatchOnlyAccountAdded {
+                    address: watched.address
+                }
+            }));
+    }
+
+    #[actix_rt::test]
+    async fn add_watch_only_account_twice_is_err() {
+        let os = boot(RustEventBusDriver::new()).await;
+        let watched = add_watched(&os).await;
+
+        let result = os
+            .with_timeout(|x| {
+                x.add_watch_only_account(
+                    watched.address,
+                    DisplayName::sample_other(),
+                    AppearanceID::sample_other(),
+                )
+            })
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommonError::WatchOnlyAccountAlreadyExists {
+                address: watched.address.to_string()
+            })
+        );
+    }
+
+    #[actix_rt::test]
+    async fn add_watch_only_account_of_controlled_account_is_err() {
+        let os = boot(RustEventBusDriver::new()).await;
+        let account = os
+            .with_timeout(|x| {
+                x.create_and_save_new_mainnet_account_with_bdfs(
+                    DisplayName::sample(),
+                )
+            })
+            .await
+            .unwrap();
+
+        let result = os
+            .with_timeout(|x| {
+                x.add_watch_only_account(
+                    account.address,
+                    DisplayName::sample_other(),
+                    AppearanceID::sample_other(),
+                )
+            })
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommonError::WatchOnlyAccountIsControlledByWallet {
+                address: account.address.to_string()
+            })
+        );
+    }
+
+    #[actix_rt::test]
+    async fn add_watch_only_account_on_other_network_is_err() {
+        let os = boot(RustEventBusDriver::new()).await;
+
+        let result = os
+            .with_timeout(|x| {
+                x.add_watch_only_account(
+                    AccountAddress::sample_stokenet(),
+                    DisplayName::sample(),
+                    AppearanceID::sample(),
+                )
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(CommonError::EntityOnWrongNetwork { .. })
+        ));
+    }
+
+    #[actix_rt::test]
+    async fn update_watch_only_account() {
+        let event_bus_driver = RustEventBusDriver::new();
+        let os = boot(event_bus_driver.clone()).await;
+        let watched = add_watched(&os).await;
+
+        let did_update = os
+            .with_timeout(|x| {
+                x.update_watch_only_account(
+                    watched.address,
+                    DisplayName::sample_other(),
+                    AppearanceID::sample_other(),
+                )
+            })
+            .await
+            .unwrap();
+
+        assert!(did_update);
+        assert_eq!(
+            os.watch_only_accounts_on_current_network().unwrap(),
+            WatchOnlyAccounts::just(WatchOnlyAccount::new(
+                watched.address,
+                DisplayName::sample_other(),
+                AppearanceID::sample_other(),
+            ))
+        );
+        assert!(event_bus_driver.recorded().iter().any(|e| e.event
+            == Event::ProfileModified {
+                change: EventProfileModified::WatchOnlyAccountUpdated {
+                    address: watched.address
+                }
+            }));
+    }
+
+    #[actix_rt::test]
+    async fn remove_watch_only_account() {
+        let event_bus_driver = RustEventBusDriver::new();
+        let os = boot(event_bus_driver.clone()).await;
+        let watched = add_watched(&os).await;
+
+        let did_remove = os
+            .with_timeout(|x| x.remove_watch_only_account(watched.address))
+            .await
+            .unwrap();
+
+        assert!(did_remove);
+        assert!(os
+            .watch_only_accounts_on_current_network()
+            .unwrap()
+            .is_empty());
+        assert!(event_bus_driver.recorded().iter().any(|e| e.event
+            == Event::ProfileModified {
+                change: EventProfileModified::WatchOnlyAccountRemoved {
+                    address: watched.address
+                }
+            }));
+    }
+
+    #[actix_rt::test]
+    async fn update_or_remove_unwatched_account_returns_false() {
+        let os = boot(RustEventBusDriver::new()).await;
+        let address = AccountAddress::sample_mainnet_other();
+
+        let did_update = os
+            .with_timeout(|x| {
+                x.update_watch_only_account(
+                    address,
+                    DisplayName::sample(),
+                    AppearanceID::sample(),
+                )
+            })
+            .await
+            .unwrap();
+        let did_remove = os
+            .with_timeout(|x| x.remove_watch_only_account(address))
+            .await
+            .unwrap();
+
+        assert!(!did_update);
+        assert!(!did_remove);
+    }
+
+    #[actix_rt::test]
+    async fn adding_watched_account_as_controlled_stops_watching_it() {
+        let event_bus_driver = RustEventBusDriver::new();
+        let os = boot(event_bus_driver.clone()).await;
+        let account = os
+            .with_timeout(|x| {
+                x.create_unsaved_mainnet_account_with_bdfs(
+                    DisplayName::sample(),
+                )
+            })
+            .await
+            .unwrap();
+        os.with_timeout(|x| {
+            x.add_watch_only_account(
+                account.address,
+                DisplayName::sample_other(),
+                AppearanceID::sample_other(),
+            )
+        })
+        .await
+        .unwrap();
+
+        os.with_timeout(|x| x.add_account(account.clone()))
+            .await
+            .unwrap();
+
+        assert!(os
+            .watch_only_accounts_on_current_network()
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            os.accounts_on_current_network().unwrap(),
+            Accounts::just(account.clone())
+        );
+        assert!(event_bus_driver.recorded().iter().any(|e| e.event
+            == Event::ProfileModified {
+                change: EventProfileModified::WatchOnlyAccountRemoved {
+                    address: account.address
+                }
+            }));
+    }
+}
@@ -0,0 +1,79 @@
+use crate::prelude::*;
+
+/// Signs a transaction locking or unlocking the Primary role of the access
+/// controller with `ac_address`, which is authorized by the Recovery role of
+/// the securified entity, while the fee payer - if any - signs with its
+/// Primary role.
+pub async fn sign_access_controller_primary_role_lock_transaction(
+    os: &SargonOS,
+    transaction_intent: TransactionIntent,
+    ac_address: AccessControllerAddress,
+) -> Result<SignedIntent> {
+    let profile = os.profile()?;
+
+    let securified_entity =
+        profile.entity_by_access_controller_address(ac_address)?;
+
+    let collector_builder =
+        DefaultTransactionIntentSignaturesCollectorBuilder::new(
+            os.sign_transactions_interactor(),
+            IndexSet::from_iter(profile.factor_sources.iter()),
+        );
+
+    let mut signatures = collect_signatures_of_role(
+        &collector_builder,
+        &transaction_intent,
+        securified_entity,
+        RoleKind::Recovery,
+    )
+    .await?;
+
+    if let Some((fee_paying_account_address, _)) =
+        transaction_intent.extract_fee_payer_info()
+    {
+        let fee_payer_account =
+            profile.account_by_address(fee_paying_account_address)?;
+        let fee_payer_signatures = collect_signatures_of_role(
+            &collector_builder,
+            &transaction_intent,
+            AccountOrPersona::from(fee_payer_account),
+            RoleKind::Primary,
+        )
+        .await?;
+        signatures.extend(fee_payer_signatures);
+    }
+
+    let intent_signatures = signatures
+        .into_iter()
+        .map(|hd| IntentSignature(hd.signature))
+        .collect_vec();
+
+    SignedIntent::new(
+        transaction_intent,
+        IntentSignatures::new(intent_signatures),
+    )
+}
+
+async fn collect_signatures_of_role(
+    collector_builder: &impl TransactionIntentSignaturesCollectorBuilder,
+    transaction_intent: &TransactionIntent,
+    entity: AccountOrPersona,
+    role_kind: RoleKind,
+) -> Result<IndexSet<HDSignature<TransactionIntentHash>>> {
+    let outcome = collector_builder
+        .build(
+            IdentifiedVecOf::from(vec![SignableWithEntities::with(
+                transaction_intent.clone(),
+                vec![entity],
+            )]),
+            SigningPurpose::SignTX { role_kind },
+        )
+        .collect_signatures()
+        .await?;
+
+    if outcome.successful() {
+        Ok(outcome.all_signatures())
+    } else {
+        Err(CommonError::SigningFailedTooManyFactorSourcesNeglected)
+    }
+}
@@ -1,8 +1,10 @@
+mod access_controller_primary_role_lock_signing;
 mod access_controller_recovery_signing;
 mod access_controller_stop_timed_recovery_signing;
 mod manifest_updating;
 mod transaction_intent_signatures_collector;
 
+pub use access_controller_primary_role_lock_signing::*;
 pub use access_controller_recovery_signing::*;
 pub use access_controller_stop_timed_recovery_signing::*;
 pub(crate) use manifest_updating::*;
@@ -72,6 +72,23 @@ impl OsSigning for SargonOS {
                 )
                 .await;
             }
+            Some(
+                DetailedManifestClass::AccessControllerLockPrimaryRole {
+                    ac_addresses,
+                }
+                | DetailedManifestClass::AccessControllerUnlockPrimaryRole {
+                    ac_addresses,
+                },
+            ) => {
+                // Locking and unlocking the Primary role is authorized by
+                // the Recovery role of the access controller.
+                return sign_access_controller_primary_role_lock_transaction(
+                    self,
+                    transaction_intent,
+                    ac_addresses[0],
+                )
+                .await;
+            }
             Some(DetailedManifestClass::SecurifyEntity { entities }) => {
                 let entity_address = entities.first().unwrap();
                 return sign_entity_securify(
@@ -494,6 +511,83 @@ mod test {
         assert_eq!(outcome, Err(CommonError::HostInteractionAborted));
     }
 
+    #[actix_rt::test]
+    async fn test_sign_lock_primary_role_with_recovery_and_fee_payer_with_primary(
+    ) {
+        let fee_payer = Account::sample_at(0);
+        let mut securified = Account::sample_at(2);
+        let mut control =
+            securified.security_state.as_securified().unwrap().clone();
+        control.security_structure =
+            SecurityStructureOfFactorInstances::sample();
+        let ac_address = control.access_controller_address;
+        securified.security_state = EntitySecurityState::Securified {
+            value: control.clone(),
+        };
+
+        let profile = Profile::sample_from(
+            FactorSource::sample_all(),
+            [&fee_payer, &securified],
+            [],
+        );
+        let sut = boot(Some(profile), None).await;
+
+        let manifest = TransactionManifest::lock_primary_role(ac_address)
+            .modify_add_lock_fee(LockFeeData::new_with_unsecurified_fee_payer(
+                fee_payer.address,
+                Decimal192::one(),
+            ))
+            .unwrap();
+        let transaction_intent = TransactionIntent::new(
+            TransactionHeader::sample(),
+            manifest,
+            Message::None,
+        )
+        .unwrap();
+        let mut execution_summary = ExecutionSummary::sample();
+        execution_summary.detailed_classification =
+            Some(DetailedManifestClass::AccessControllerLockPrimaryRole {
+                ac_addresses: vec![ac_address],
+            });
+
+        let signed = sut
+            .sign_transaction(transaction_intent.clone(), execution_summary)
+            .await
+            .unwrap();
+
+        let recovery_keys = control
+            .security_structure
+            .matrix_of_factors
+            .recovery()
+            .all_factors()
+            .into_iter()
+            .map(|f| {
+                HierarchicalDeterministicFactorInstance::try_from(f.clone())
+                    .unwrap()
+                    .public_key()
+            })
+            .collect::<IndexSet<_>>();
+        let fee_payer_key = fee_payer
+            .security_state
+            .as_unsecured()
+            .unwrap()
+            .transaction_signing
+            .public_key();
+        let signing_keys = signed
+            .intent_signatures
+            .signatures
+            .iter()
+            .map(|s| s.0.public_key())
+            .collect::<IndexSet<_>>();
+
+        assert_eq!(signed.intent, transaction_intent);
+        assert!(signing_keys.contains(&fee_payer_key));
+        assert!(signing_keys.iter().any(|k| recovery_keys.contains(k)));
+        assert!(signing_keys
+            .iter()
+            .all(|k| *k == fee_payer_key || recovery_keys.contains(k)));
+    }
+
     async fn boot(
         profile: Option<Profile>,
         maybe_signing_failure: Option<SigningFailure>,
@@ -30,8 +30,14 @@ pub trait ApplySecurityShieldCommitting: Send + Sync {
     /// We will compile them and we will start the process of signing them. Which will be the job of `SigningManager` - many instances of `SignaturesCollector` using one Role at a time.
     ///
     /// Can work with single transaction of course...
+    ///
+    /// Returns the `TransactionIntentHash`es once the transactions have been
+    /// enqueued. They are then submitted one at a time by
+    /// `process_apply_shield_transactions_queue`, which this starts in the
+    /// background. Should processing fail, the transactions stay queued and
+    /// processing is resumed on next boot.
     async fn sign_and_enqueue_batch_of_transactions_applying_security_shield(
-        &self,
+        self: Arc<Self>,
         network_id: NetworkID,
         manifest_and_payer_tuples: Vec<ManifestWithPayerByAddress>, // TODO: Want IndexSet but not Hash
     ) -> Result<IndexSet<TransactionIntentHash>>;
@@ -40,14 +46,18 @@ pub trait ApplySecurityShieldCommitting: Send + Sync {
 #[async_trait::async_trait]
 impl ApplySecurityShieldCommitting for SargonOS {
     async fn sign_and_enqueue_batch_of_transactions_applying_security_shield(
-        &self,
+        self: Arc<Self>,
         network_id: NetworkID,
         manifest_and_payer_tuples: Vec<ManifestWithPayerByAddress>, // TODO: Want IndexSet but not Hash
     ) -> Result<IndexSet<TransactionIntentHash>> {
-        let committer = ApplyShieldTransactionsCommitterImpl::new(self)?;
-        committer
+        let committer = ApplyShieldTransactionsCommitterImpl::new(&self)?;
+        let transaction_ids = committer
             .commit(network_id, manifest_and_payer_tuples)
-            .await
+            .await?;
+
+        self.process_apply_shield_transactions_queue_in_background();
+
+        Ok(transaction_ids)
     }
 }
 
@@ -16,6 +16,23 @@ pub enum SecurityShieldApplicationWithTransactionIntents {
     ),
 }
 
+impl SecurityShieldApplicationWithTransactionIntents {
+    pub fn address_of_entity_applying_shield(
+        &self,
+    ) -> AddressOfAccountOrPersona {
+        match self {
+            Self::ForUnsecurifiedEntity(unsec) => match unsec {
+                SecurityShieldApplicationForUnsecurifiedEntityWithTransactionIntent::Account(a) => a.application.entity_applying_shield.address(),
+                SecurityShieldApplicationForUnsecurifiedEntityWithTransactionIntent::Persona(p) => p.application.entity_applying_shield.address(),
+            },
+            Self::ForSecurifiedEntity(sec) => match sec {
+                SecurityShieldApplicationForSecurifiedEntityWithTransactionIntents::Account(a) => a.entity.entity.address(),
+                SecurityShieldApplicationForSecurifiedEntityWithTransactionIntents::Persona(p) => p.entity.entity.address(),
+            },
+        }
+    }
+}
+
 impl SecurityShieldApplication {
     pub fn unsecurified(
         application: SecurityShieldApplicationForUnsecurifiedEntity,
@@ -22,7 +22,7 @@ impl ApplyShieldTransactionsCommitterImpl {
         let builder = ApplyShieldTransactionsBuilderImpl::new(os)?;
         Ok(Self {
             builder: Arc::new(builder),
-            signer: Arc::new(ApplyShieldTransactionsSignerImpl::new(os)),
+            signer: Arc::new(ApplyShieldTransactionsSignerImpl::new(os)?),
             enqueuer: Arc::new(ApplyShieldTransactionsEnqueuerImpl::new(os)),
         })
     }
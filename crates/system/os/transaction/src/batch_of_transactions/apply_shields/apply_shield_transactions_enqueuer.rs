@@ -8,26 +8,333 @@ pub trait ApplyShieldTransactionsEnqueuer: Send + Sync {
     ) -> Result<IndexSet<TransactionIntentHash>>;
 }
 
-pub struct ApplyShieldTransactionsEnqueuerImpl {}
+/// Persists signed transactions applying shields in the queue of apply
+/// shield transactions, which is processed - submitting the transactions
+/// one at a time - by `process_apply_shield_transactions_queue`.
+pub struct ApplyShieldTransactionsEnqueuerImpl {
+    unsafe_storage: UnsafeStorageClient,
+    coordination: Arc<TransactionsCoordination>,
+}
 
 impl ApplyShieldTransactionsEnqueuerImpl {
-    pub fn new(_os: &SargonOS) -> Self {
-        warn!("ApplyShieldTransactionsEnqueuerImpl is not implemented yet. We might not need `os` here.");
-        Self {}
+    pub fn new(os: &SargonOS) -> Self {
+        Self {
+            unsafe_storage: os.unsafe_storage.clone(),
+            coordination: os.transactions_coordination(),
+        }
     }
 }
 
 #[async_trait::async_trait]
 impl ApplyShieldTransactionsEnqueuer for ApplyShieldTransactionsEnqueuerImpl {
+    /// Appends the signed transactions to the persisted queue, sorted so that
+    /// no transaction requires the auth of an entity whose shield has been
+    /// applied by a transaction submitted before it.
+    ///
+    /// Transactions of previous batches which have been committed or have
+    /// failed are dropped from the queue. The queue lock is held while doing
+    /// so, such that a concurrent processing of the queue keeps the enqueued
+    /// transactions.
+    ///
+    /// Returns the `TransactionIntentHash`es in submission order.
     async fn enqueue_signed_transactions(
         &self,
         signed_payload: ApplySecurityShieldSignedPayload,
     ) -> Result<IndexSet<TransactionIntentHash>> {
-        warn!("Enqueuing signed transactions is not implemented yet");
-        Ok(signed_payload
+        let transactions = signed_payload
             .notarized_transactions
             .into_iter()
-            .map(|nt| nt.signed_intent().intent().transaction_intent_hash())
-            .collect())
+            .map(|notarized_transaction| {
+                let intent_hash = notarized_transaction
+                    .signed_intent()
+                    .intent()
+                    .transaction_intent_hash();
+                let entity_address = signed_payload
+                    .entity_address_per_transaction
+                    .get(&intent_hash)
+                    .cloned()
+                    .ok_or_else(|| CommonError::Unknown {
+                        error_message: format!(
+                            "No entity applying shield for transaction {}",
+                            intent_hash
+                        ),
+                    })?;
+                let role_kinds = signed_payload
+                    .role_kinds_per_transaction
+                    .get(&intent_hash)
+                    .cloned()
+                    .ok_or_else(|| CommonError::Unknown {
+                        error_message: format!(
+                            "No role kinds signed with for transaction {}",
+                            intent_hash
+                        ),
+                    })?;
+                Ok(ApplyShieldQueuedTransaction::new(
+                    entity_address,
+                    &notarized_transaction,
+                    role_kinds,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let transactions = sorted_in_dependency_order(transactions);
+        let intent_hashes = transactions
+            .iter()
+            .map(|t| t.transaction_intent_hash())
+            .collect::<IndexSet<_>>();
+
+        let _guard = self
+            .coordination
+            .apply_shield_transactions_queue_lock
+            .lock()
+            .await;
+        let mut queue: Vec<ApplyShieldQueuedTransaction> = self
+            .unsafe_storage
+            .load(UnsafeStorageKey::ApplyShieldTransactionsQueue)
+            .await?
+            .unwrap_or_default();
+        queue.retain(|t| !t.status.is_final());
+        queue.extend(transactions);
+        self.unsafe_storage
+            .save(UnsafeStorageKey::ApplyShieldTransactionsQueue, &queue)
+            .await?;
+
+        Ok(intent_hashes)
+    }
+}
+
+/// The entities whose auth is required by the manifest of `transaction`.
+fn entities_requiring_auth(
+    transaction: &ApplyShieldQueuedTransaction,
+) -> IndexSet<AddressOfAccountOrPersona> {
+    let Ok(summary) = transaction
+        .notarized_transaction()
+        .signed_intent()
+        .intent()
+        .manifest
+        .summary()
+    else {
+        return IndexSet::new();
+    };
+    summary
+        .addresses_of_accounts_requiring_auth
+        .into_iter()
+        .map(AddressOfAccountOrPersona::from)
+        .chain(
+            summary
+                .addresses_of_personas_requiring_auth
+                .into_iter()
+                .map(AddressOfAccountOrPersona::from),
+        )
+        .collect()
+}
+
+/// Once a shield has been applied to an entity, the factors previously
+/// controlling it can no longer sign on its behalf. So any other transaction
+/// requiring the auth of an entity - e.g. because the entity pays the fee -
+/// must be submitted before the transaction applying the shield to it.
+fn sorted_in_dependency_order(
+    transactions: Vec<ApplyShieldQueuedTransaction>,
+) -> Vec<ApplyShieldQueuedTransaction> {
+    let auths = transactions
+        .iter()
+        .map(entities_requiring_auth)
+        .collect_vec();
+    let entities = transactions.iter().map(|t| t.entity_address).collect_vec();
+
+    let order = dependency_order(&entities, &auths);
+    let mut transactions = transactions.into_iter().map(Some).collect_vec();
+    order
+        .into_iter()
+        .filter_map(|index| transactions[index].take())
+        .collect()
+}
+
+/// Returns the indices of the transactions applying shields to `entities`,
+/// where `auths[i]` are the entities whose auth transaction `i` requires,
+/// ordered such that each transaction comes before the transactions applying
+/// shields to the entities it requires auth of. The original order is kept
+/// for independent transactions.
+///
+/// Should there be a cycle - which can only happen if the transactions pay
+/// for each other - the remaining transactions keep their original order.
+fn dependency_order(
+    entities: &[AddressOfAccountOrPersona],
+    auths: &[IndexSet<AddressOfAccountOrPersona>],
+) -> Vec<usize> {
+    let must_precede = |before: usize, after: usize| {
+        before != after && auths[before].contains(&entities[after])
+    };
+
+    let mut remaining = (0..entities.len()).collect_vec();
+    let mut order = Vec::with_capacity(entities.len());
+    while !remaining.is_empty() {
+        let next = remaining
+            .iter()
+            .position(|&candidate| {
+                !remaining.iter().any(|&other| must_precede(other, candidate))
+            })
+            .unwrap_or_else(|| {
+                warn!("Cyclic dependency between apply shield transactions, keeping their original order");
+                0
+            });
+        order.push(remaining.remove(next));
+    }
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = ApplyShieldTransactionsEnqueuerImpl;
+
+    fn entities() -> Vec<AddressOfAccountOrPersona> {
+        vec![
+            AccountAddress::sample().into(),
+            AccountAddress::sample_other().into(),
+            IdentityAddress::sample().into(),
+        ]
+    }
+
+    #[test]
+    fn dependency_order_keeps_order_of_independent() {
+        let auths = vec![IndexSet::new(); 3];
+        assert_eq!(dependency_order(&entities(), &auths), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn dependency_order_submits_payer_transactions_first() {
+        let entities = entities();
+        // The shield of the persona is paid by the first account, so it
+        // must be submitted before the shield of that account is applied.
+        let auths = vec![
+            IndexSet::from([entities[0]]),
+            IndexSet::from([entities[1]]),
+            IndexSet::from([entities[2], entities[0]]),
+        ];
+        assert_eq!(dependency_order(&entities, &auths), vec![1, 2, 0]);
+    }
+
+    #[test]
+    fn dependency_order_transitive() {
+        let entities = entities();
+        let auths = vec![
+            IndexSet::from([entities[0]]),
+            IndexSet::from([entities[1], entities[0]]),
+            IndexSet::from([entities[2], entities[1]]),
+        ];
+        assert_eq!(dependency_order(&entities, &auths), vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn dependency_order_cycle_keeps_original_order() {
+        let entities = entities();
+        let auths = vec![
+            IndexSet::from([entities[1]]),
+            IndexSet::from([entities[0]]),
+            IndexSet::new(),
+        ];
+        assert_eq!(dependency_order(&entities, &auths), vec![2, 0, 1]);
+    }
+
+    #[actix_rt::test]
+    async fn enqueue_persists_transactions() {
+        let os = SargonOS::fast_boot().await;
+        let sut = SUT::new(&os);
+        let queued = ApplyShieldQueuedTransaction::sample();
+        let queued_other = ApplyShieldQueuedTransaction::sample_other();
+        let payload = ApplySecurityShieldSignedPayload {
+            notarized_transactions: vec![
+                queued.notarized_transaction(),
+                queued_other.notarized_transaction(),
+            ],
+            entity_address_per_transaction: IndexMap::from([
+                (queued.transaction_intent_hash(), queued.entity_address),
+                (
+                    queued_other.transaction_intent_hash(),
+                    queued_other.entity_address,
+                ),
+            ]),
+            role_kinds_per_transaction: IndexMap::from([
+                (queued.transaction_intent_hash(), queued.role_kinds.clone()),
+                (
+                    queued_other.transaction_intent_hash(),
+                    queued_other.role_kinds.clone(),
+                ),
+            ]),
+        };
+
+        let intent_hashes =
+            sut.enqueue_signed_transactions(payload).await.unwrap();
+
+        assert_eq!(
+            intent_hashes,
+            IndexSet::from([
+                queued.transaction_intent_hash(),
+                queued_other.transaction_intent_hash()
+            ])
+        );
+        assert_eq!(
+            os.apply_shield_transactions_queue().await.unwrap(),
+            vec![queued, queued_other]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn enqueue_drops_final_transactions_of_previous_batches() {
+        let os = SargonOS::fast_boot().await;
+        let sut = SUT::new(&os);
+        let mut committed = ApplyShieldQueuedTransaction::sample_other();
+        committed.status = ApplyShieldQueuedTransactionStatus::Committed;
+        os.unsafe_storage
+            .save(
+                UnsafeStorageKey::ApplyShieldTransactionsQueue,
+                &vec![committed],
+            )
+            .await
+            .unwrap();
+        let queued = ApplyShieldQueuedTransaction::sample();
+
+        sut.enqueue_signed_transactions(ApplySecurityShieldSignedPayload {
+            notarized_transactions: vec![queued.notarized_transaction()],
+            entity_address_per_transaction: IndexMap::from([(
+                queued.transaction_intent_hash(),
+                queued.entity_address,
+            )]),
+            role_kinds_per_transaction: IndexMap::from([(
+                queued.transaction_intent_hash(),
+                queued.role_kinds.clone(),
+            )]),
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(
+            os.apply_shield_transactions_queue().await.unwrap(),
+            vec![queued]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn enqueue_fails_without_entity_address() {
+        let os = SargonOS::fast_boot().await;
+        let sut = SUT::new(&os);
+
+        let result = sut
+            .enqueue_signed_transactions(ApplySecurityShieldSignedPayload {
+                notarized_transactions: vec![NotarizedTransaction::sample()],
+                entity_address_per_transaction: IndexMap::new(),
+                role_kinds_per_transaction: IndexMap::new(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(CommonError::Unknown { .. })));
+        assert!(os
+            .apply_shield_transactions_queue()
+            .await
+            .unwrap()
+            .is_empty());
     }
 }
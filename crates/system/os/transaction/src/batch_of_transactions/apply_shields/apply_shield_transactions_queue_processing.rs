@@ -0,0 +1,745 @@
+use crate::prelude::*;
+
+/// The number of times we try to submit a queued transaction before we give
+/// up processing the queue, leaving the transaction queued.
+const MAX_SUBMISSION_ATTEMPTS: usize = 3;
+
+#[async_trait::async_trait]
+pub trait OsApplyShieldTransactionsQueue {
+    async fn apply_shield_transactions_queue(
+        &self,
+    ) -> Result<Vec<ApplyShieldQueuedTransaction>>;
+
+    async fn process_apply_shield_transactions_queue(
+        &self,
+    ) -> Result<Vec<ApplyShieldQueuedTransaction>>;
+
+    fn process_apply_shield_transactions_queue_in_background(self: Arc<Self>);
+}
+
+#[async_trait::async_trait]
+impl OsApplyShieldTransactionsQueue for SargonOS {
+    /// The signed transactions applying shields which have been enqueued by
+    /// `sign_and_enqueue_batch_of_transactions_applying_security_shield`,
+    /// persisted in unsafe storage, in submission order.
+    async fn apply_shield_transactions_queue(
+        &self,
+    ) -> Result<Vec<ApplyShieldQueuedTransaction>> {
+        self.unsafe_storage
+            .load(UnsafeStorageKey::ApplyShieldTransactionsQueue)
+            .await
+            .map(Option::unwrap_or_default)
+    }
+
+    /// Submits the queued transactions one at a time, waiting for each one to
+    /// be committed before submitting the next one. Once a transaction has
+    /// been committed, the provisional security structure of its entity is
+    /// marked as applied.
+    ///
+    /// Temporarily rejected transactions are submitted again, and
+    /// transactions which could not be committed before the end of their
+    /// epoch window are re-signed and re-notarized with a new epoch window.
+    /// If a transaction fails, all transactions after it are marked as
+    /// failed too, since they might depend on it.
+    ///
+    /// The progress is persisted after each step, so if we fail to submit a
+    /// transaction or time out waiting for its commit, processing can be
+    /// resumed - typically after an app restart - by calling this again.
+    ///
+    /// The queue is processed by at most one task at a time, calling this
+    /// while it is already being processed returns the persisted queue
+    /// without processing it, since the ongoing processing also processes
+    /// the transactions enqueued meanwhile.
+    ///
+    /// Returns the queue once processed.
+    async fn process_apply_shield_transactions_queue(
+        &self,
+    ) -> Result<Vec<ApplyShieldQueuedTransaction>> {
+        let coordination = self.transactions_coordination();
+        let Some(processing) = coordination
+            .apply_shield_transactions_queue_processing
+            .try_lock()
+        else {
+            return self.apply_shield_transactions_queue().await;
+        };
+
+        loop {
+            let queue = {
+                let _guard = coordination
+                    .apply_shield_transactions_queue_lock
+                    .lock()
+                    .await;
+                let queue = self.apply_shield_transactions_queue().await?;
+                if queue
+                    .iter()
+                    .all(|transaction| transaction.status.is_final())
+                {
+                    // Stop processing while holding the queue lock, so that
+                    // transactions enqueued after we release it get processed
+                    // by the processing started by their enqueuer.
+                    drop(processing);
+                    return Ok(queue);
+                }
+                queue
+            };
+
+            let signer = ApplyShieldTransactionsSignerImpl::new(self)?;
+            process_queue(self, &signer, queue).await?;
+        }
+    }
+
+    /// Processes the queue on a task of its own, without waiting for the
+    /// transactions to be committed, e.g. after booting or enqueuing
+    /// transactions. Errors are logged, since processing is resumed by the
+    /// next call.
+    fn process_apply_shield_transactions_queue_in_background(self: Arc<Self>) {
+        async_std::task::spawn(async move {
+            if let Err(error) =
+                self.process_apply_shield_transactions_queue().await
+            {
+                warn!(
+                    "Failed to process apply shield transactions queue: {:?}",
+                    error
+                );
+            }
+        });
+    }
+}
+
+/// Processes the transactions of `queue` in order, see
+/// `process_apply_shield_transactions_queue`, re-signing expired
+/// transactions with `signer`.
+async fn process_queue(
+    os: &SargonOS,
+    signer: &dyn ApplyShieldTransactionsSigner,
+    mut queue: Vec<ApplyShieldQueuedTransaction>,
+) -> Result<Vec<ApplyShieldQueuedTransaction>> {
+    let mut known_intent_hashes = queue
+        .iter()
+        .map(|transaction| transaction.transaction_intent_hash())
+        .collect::<HashSet<_>>();
+    for index in 0..queue.len() {
+        process_queued_transaction(
+            os,
+            signer,
+            &mut queue,
+            &mut known_intent_hashes,
+            index,
+        )
+        .await?;
+
+        if let ApplyShieldQueuedTransactionStatus::Failed { .. } =
+            queue[index].status
+        {
+            let reason = format!(
+                "Preceding transaction {} failed",
+                queue[index].transaction_intent_hash()
+            );
+            queue.iter_mut().skip(index + 1).for_each(|transaction| {
+                if !transaction.status.is_final() {
+                    transaction.status =
+                        ApplyShieldQueuedTransactionStatus::Failed {
+                            reason: reason.clone(),
+                        };
+                }
+            });
+            save_queue(os, &queue, &mut known_intent_hashes).await?;
+            break;
+        }
+    }
+
+    Ok(queue)
+}
+
+/// Saves the `queue` being processed, followed by the transactions which
+/// have been enqueued since we started processing it, i.e. the persisted
+/// transactions whose intent hash is not in `known_intent_hashes`, which is
+/// updated with the intent hashes of `queue` - e.g. after re-notarization.
+async fn save_queue(
+    os: &SargonOS,
+    queue: &[ApplyShieldQueuedTransaction],
+    known_intent_hashes: &mut HashSet<TransactionIntentHash>,
+) -> Result<()> {
+    let coordination = os.transactions_coordination();
+    let _guard = coordination
+        .apply_shield_transactions_queue_lock
+        .lock()
+        .await;
+    let enqueued_meanwhile = os
+        .apply_shield_transactions_queue()
+        .await?
+        .into_iter()
+        .filter(|transaction| {
+            !known_intent_hashes
+                .contains(&transaction.transaction_intent_hash())
+        })
+        .collect_vec();
+    known_intent_hashes.extend(
+        queue
+            .iter()
+            .map(|transaction| transaction.transaction_intent_hash()),
+    );
+    let queue = queue
+        .iter()
+        .cloned()
+        .chain(enqueued_meanwhile)
+        .collect_vec();
+    os.unsafe_storage
+        .save(UnsafeStorageKey::ApplyShieldTransactionsQueue, &queue)
+        .await
+}
+
+/// Processes the transaction at `index` of `queue` until it has been
+/// committed or has failed, persisting `queue` after each status change.
+async fn process_queued_transaction(
+    os: &SargonOS,
+    signer: &dyn ApplyShieldTransactionsSigner,
+    queue: &mut [ApplyShieldQueuedTransaction],
+    known_intent_hashes: &mut HashSet<TransactionIntentHash>,
+    index: usize,
+) -> Result<()> {
+    let mut submission_attempts = 0;
+    loop {
+        let transaction = &mut queue[index];
+        let notarized_transaction = transaction.notarized_transaction();
+
+        match transaction.status.clone() {
+            ApplyShieldQueuedTransactionStatus::Committed
+            | ApplyShieldQueuedTransactionStatus::Failed { .. } => {
+                return Ok(())
+            }
+            ApplyShieldQueuedTransactionStatus::Queued => {
+                submission_attempts += 1;
+                match os.submit_transaction(notarized_transaction).await {
+                    Ok(_) => {
+                        transaction.status =
+                            ApplyShieldQueuedTransactionStatus::Submitted;
+                    }
+                    Err(error)
+                        if submission_attempts < MAX_SUBMISSION_ATTEMPTS =>
+                    {
+                        warn!(
+                            "Failed to submit apply shield transaction, retrying: {:?}",
+                            error
+                        );
+                        continue;
+                    }
+                    Err(error) => return Err(error),
+                }
+            }
+            ApplyShieldQueuedTransactionStatus::Submitted => {
+                let outcome = os
+                    .poll_pending_transaction(PendingTransaction::from(
+                        &notarized_transaction,
+                    ))
+                    .await?;
+                let PendingTransactionOutcome::Transaction { status, .. } =
+                    outcome
+                else {
+                    return Err(CommonError::Unknown {
+                        error_message: "Expected the outcome of a transaction"
+                            .to_owned(),
+                    });
+                };
+
+                match status {
+                    TransactionStatus::Success => {
+                        mark_shield_as_applied(os, transaction.entity_address)
+                            .await?;
+                        transaction.status =
+                            ApplyShieldQueuedTransactionStatus::Committed;
+                    }
+                    TransactionStatus::TemporarilyRejected { .. } => {
+                        transaction.status =
+                            ApplyShieldQueuedTransactionStatus::Queued;
+                    }
+                    TransactionStatus::PermanentlyRejected { reason } => {
+                        if is_expired(os, &notarized_transaction).await? {
+                            let re_notarized =
+                                re_notarize(os, signer, transaction).await?;
+                            transaction.replace_transaction(&re_notarized);
+                            submission_attempts = 0;
+                        } else {
+                            transaction.status =
+                                ApplyShieldQueuedTransactionStatus::Failed {
+                                    reason: format!(
+                                        "Permanently rejected: {:?}",
+                                        reason
+                                    ),
+                                };
+                        }
+                    }
+                    TransactionStatus::Failed { reason } => {
+                        transaction.status =
+                            ApplyShieldQueuedTransactionStatus::Failed {
+                                reason: format!("Failed: {:?}", reason),
+                            };
+                    }
+                }
+            }
+        }
+
+        save_queue(os, queue, known_intent_hashes).await?;
+    }
+}
+
+/// Whether the network has reached the end of the epoch window of
+/// `notarized_transaction`, after which it can never be committed.
+async fn is_expired(
+    os: &SargonOS,
+    notarized_transaction: &NotarizedTransaction,
+) -> Result<bool> {
+    let current_epoch = os.gateway_client()?.current_epoch().await?;
+    Ok(current_epoch
+        >= notarized_transaction
+            .signed_intent()
+            .intent()
+            .header
+            .end_epoch_exclusive)
+}
+
+/// Builds a new transaction with the manifest of the expired `transaction`,
+/// with a new epoch window, nonce and ephemeral notary key, which `signer`
+/// signs with the roles the expired transaction was signed with, and which
+/// we then notarize.
+async fn re_notarize(
+    os: &SargonOS,
+    signer: &dyn ApplyShieldTransactionsSigner,
+    transaction: &ApplyShieldQueuedTransaction,
+) -> Result<NotarizedTransaction> {
+    let expired_intent = transaction
+        .notarized_transaction()
+        .signed_intent()
+        .intent()
+        .clone();
+    let start_epoch_inclusive = os.gateway_client()?.current_epoch().await?;
+    let notary_private_key =
+        Ed25519PrivateKey::from_exactly32_bytes(Exactly32Bytes::generate());
+    let header = TransactionHeader::new(
+        expired_intent.header.network_id,
+        start_epoch_inclusive,
+        Epoch::max_window_from_start(start_epoch_inclusive),
+        Nonce::random(),
+        notary_private_key.public_key(),
+        NotaryIsSignatory(false),
+        expired_intent.header.tip_percentage,
+    );
+    let intent = TransactionIntent::new(
+        header,
+        expired_intent.manifest,
+        expired_intent.message,
+    )?;
+
+    let signed_intent = signer
+        .sign_transaction_intent_with_roles(
+            intent,
+            transaction.entity_address,
+            transaction.role_kinds.clone(),
+        )
+        .await?;
+
+    let notary_signature =
+        notary_private_key.notarize_hash(&signed_intent.hash());
+    NotarizedTransaction::new(signed_intent, notary_signature)
+}
+
+/// Marks the provisional security structure of the entity as applied, now
+/// that the transaction applying it has been committed.
+async fn mark_shield_as_applied(
+    os: &SargonOS,
+    entity_address: AddressOfAccountOrPersona,
+) -> Result<()> {
+    let entity = os.profile()?.entity_by_address(entity_address)?;
+    if entity.is_securified() {
+        os.update_profile_with(|profile| {
+            profile
+                .commit_provisional_security_state(entity_address)
+                .map(|_| ())
+        })
+        .await
+    } else {
+        os.mark_entities_as_securified(IndexSet::from([entity_address]))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = SargonOS;
+    #[allow(clippy::upper_case_acronyms)]
+    type TSR = TransactionStatusResponse;
+
+    async fn boot(driver: MockNetworkingDriver) -> Arc<SUT> {
+        let req = SUT::boot_test_with_networking_driver(Arc::new(driver));
+        actix_rt::time::timeout(SARGON_OS_TEST_MAX_ASYNC_DURATION, req)
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
+    async fn enqueue(os: &SUT, queue: Vec<ApplyShieldQueuedTransaction>) {
+        os.unsafe_storage
+            .save(UnsafeStorageKey::ApplyShieldTransactionsQueue, &queue)
+            .await
+            .unwrap();
+    }
+
+    fn submitted() -> MockNetworkingDriverResponse {
+        MockNetworkingDriverResponse::new_success(TransactionSubmitResponse {
+            duplicate: false,
+        })
+    }
+
+    fn current_epoch(epoch: u64) -> MockNetworkingDriverResponse {
+        MockNetworkingDriverResponse::new_success(
+            TransactionConstructionResponse::new(LedgerState::new(
+                NetworkID::Mainnet.logical_name(),
+                1,
+                "2021-01-01T00:00:00Z",
+                epoch,
+                1,
+            )),
+        )
+    }
+
+    /// Records the entity and roles it is asked to sign with, signing with
+    /// sample signatures.
+    #[derive(Default)]
+    struct RecordingSigner {
+        signed_with:
+            std::sync::Mutex<Vec<(AddressOfAccountOrPersona, Vec<RoleKind>)>>,
+    }
+
+    #[async_trait::async_trait]
+    impl ApplyShieldTransactionsSigner for RecordingSigner {
+        async fn sign_transaction_intents(
+            &self,
+            _payload_to_sign: ApplySecurityShieldPayloadToSign,
+        ) -> Result<ApplySecurityShieldSignedPayload> {
+            unreachable!("Only used to re-sign expired transactions")
+        }
+
+        async fn sign_transaction_intent_with_roles(
+            &self,
+            transaction_intent: TransactionIntent,
+            entity_address: AddressOfAccountOrPersona,
+            role_kinds: Vec<RoleKind>,
+        ) -> Result<SignedIntent> {
+            self.signed_with
+                .lock()
+                .unwrap()
+                .push((entity_address, role_kinds));
+            Ok(SignedIntent {
+                intent: transaction_intent,
+                intent_signatures: IntentSignatures::sample(),
+            })
+        }
+    }
+
+    #[actix_rt::test]
+    async fn empty_queue() {
+        let os = boot(MockNetworkingDriver::new_always_failing()).await;
+        assert!(os
+            .process_apply_shield_transactions_queue()
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn process_while_processing_is_a_no_op() {
+        let os = boot(MockNetworkingDriver::new_always_failing()).await;
+        let queued = ApplyShieldQueuedTransaction::sample();
+        enqueue(&os, vec![queued.clone()]).await;
+        let coordination = os.transactions_coordination();
+        let _processing = coordination
+            .apply_shield_transactions_queue_processing
+            .lock()
+            .await;
+
+        // Would fail to submit if it processed the queue
+        let queue = os.process_apply_shield_transactions_queue().await.unwrap();
+
+        assert_eq!(queue, vec![queued]);
+    }
+
+    #[actix_rt::test]
+    async fn save_queue_keeps_transactions_enqueued_meanwhile() {
+        let os = boot(MockNetworkingDriver::new_always_failing()).await;
+        let mut processed = ApplyShieldQueuedTransaction::sample();
+        let enqueued_meanwhile = ApplyShieldQueuedTransaction::sample_other();
+        let mut known_intent_hashes =
+            HashSet::from([processed.transaction_intent_hash()]);
+        enqueue(&os, vec![processed.clone(), enqueued_meanwhile.clone()]).await;
+        processed.status = ApplyShieldQueuedTransactionStatus::Submitted;
+
+        save_queue(&os, &[processed.clone()], &mut known_intent_hashes)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            os.apply_shield_transactions_queue().await.unwrap(),
+            vec![processed, enqueued_meanwhile]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn save_queue_does_not_keep_replaced_transactions() {
+        let os = boot(MockNetworkingDriver::new_always_failing()).await;
+        let expired = ApplyShieldQueuedTransaction::sample();
+        let mut known_intent_hashes =
+            HashSet::from([expired.transaction_intent_hash()]);
+        enqueue(&os, vec![expired.clone()]).await;
+        let mut re_notarized = expired.clone();
+        re_notarized.replace_transaction(&NotarizedTransaction::sample_other());
+
+        save_queue(&os, &[re_notarized.clone()], &mut known_intent_hashes)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            os.apply_shield_transactions_queue().await.unwrap(),
+            vec![re_notarized]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn failed_transaction_fails_the_following_ones() {
+        let os = boot(MockNetworkingDriver::new_with_responses(vec![
+            submitted(),
+            MockNetworkingDriverResponse::new_success(
+                TSR::sample_committed_failure(None),
+            ),
+        ]))
+        .await;
+        let first = ApplyShieldQueuedTransaction::sample();
+        let second = ApplyShieldQueuedTransaction::sample_other();
+        enqueue(&os, vec![first.clone(), second.clone()]).await;
+
+        let queue = os.process_apply_shield_transactions_queue().await.unwrap();
+
+        assert_eq!(
+            queue.iter().map(|t| t.status.clone()).collect_vec(),
+            vec![
+                ApplyShieldQueuedTransactionStatus::Failed {
+                    reason: "Failed: Unknown".to_owned()
+                },
+                ApplyShieldQueuedTransactionStatus::Failed {
+                    reason: format!(
+                        "Preceding transaction {} failed",
+                        first.transaction_intent_hash()
+                    )
+                },
+            ]
+        );
+        assert_eq!(os.apply_shield_transactions_queue().await.unwrap(), queue);
+        // The transaction is no longer tracked as pending
+        assert!(os.pending_transactions().await.unwrap().is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn failing_submission_keeps_transaction_queued() {
+        let os = boot(MockNetworkingDriver::new_always_failing()).await;
+        let queued = ApplyShieldQueuedTransaction::sample();
+        enqueue(&os, vec![queued.clone()]).await;
+
+        let result = os.process_apply_shield_transactions_queue().await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            os.apply_shield_transactions_queue().await.unwrap(),
+            vec![queued]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn resume_polls_submitted_transaction_without_submitting_again() {
+        let os = boot(MockNetworkingDriver::with_responses(vec![
+            TSR::sample_committed_failure(None),
+        ]))
+        .await;
+        let mut submitted = ApplyShieldQueuedTransaction::sample();
+        submitted.status = ApplyShieldQueuedTransactionStatus::Submitted;
+        enqueue(&os, vec![submitted]).await;
+
+        let queue = os.process_apply_shield_transactions_queue().await.unwrap();
+
+        assert_eq!(
+            queue[0].status,
+            ApplyShieldQueuedTransactionStatus::Failed {
+                reason: "Failed: Unknown".to_owned()
+            }
+        );
+    }
+
+    #[actix_rt::test]
+    async fn committed_transaction_of_unknown_entity_stays_submitted() {
+        let os = boot(MockNetworkingDriver::new_with_responses(vec![
+            submitted(),
+            MockNetworkingDriverResponse::new_success(
+                TSR::sample_committed_success(),
+            ),
+        ]))
+        .await;
+        let queued = ApplyShieldQueuedTransaction::sample();
+        enqueue(&os, vec![queued]).await;
+
+        let result = os.process_apply_shield_transactions_queue().await;
+
+        // We could not mark the shield as applied, so we will poll its status
+        // again, and retry marking it, when processing is resumed.
+        assert_eq!(result, Err(CommonError::UnknownAccount));
+        assert_eq!(
+            os.apply_shield_transactions_queue().await.unwrap()[0].status,
+            ApplyShieldQueuedTransactionStatus::Submitted
+        );
+    }
+
+    #[actix_rt::test]
+    async fn committed_transactions_are_skipped() {
+        let os = boot(MockNetworkingDriver::new_always_failing()).await;
+        let mut committed = ApplyShieldQueuedTransaction::sample();
+        committed.status = ApplyShieldQueuedTransactionStatus::Committed;
+        enqueue(&os, vec![committed.clone()]).await;
+
+        let queue = os.process_apply_shield_transactions_queue().await.unwrap();
+
+        assert_eq!(queue, vec![committed]);
+    }
+
+    #[actix_rt::test]
+    async fn committed_transaction_marks_shield_as_applied() {
+        let os = boot(MockNetworkingDriver::new_with_responses(vec![
+            submitted(),
+            MockNetworkingDriverResponse::new_success(
+                TSR::sample_committed_success(),
+            ),
+        ]))
+        .await;
+        let mut account = Account::sample_at(2);
+        let security_structure = account
+            .security_state()
+            .as_securified()
+            .unwrap()
+            .security_structure
+            .clone();
+        account.set_provisional(
+            ProvisionalSecurifiedConfig::FactorInstancesDerived {
+                value: security_structure,
+            },
+        );
+        os.add_account(account.clone()).await.unwrap();
+        let queued = ApplyShieldQueuedTransaction::new(
+            account.address,
+            &NotarizedTransaction::sample(),
+            [RoleKind::Primary, RoleKind::Confirmation],
+        );
+        enqueue(&os, vec![queued]).await;
+
+        let queue = os.process_apply_shield_transactions_queue().await.unwrap();
+
+        assert_eq!(
+            queue[0].status,
+            ApplyShieldQueuedTransactionStatus::Committed
+        );
+        assert_eq!(
+            os.account_by_address(account.address)
+                .unwrap()
+                .get_provisional(),
+            None
+        );
+    }
+
+    #[actix_rt::test]
+    async fn expired_transaction_is_re_notarized_with_original_roles() {
+        let mut expired = ApplyShieldQueuedTransaction::sample_other();
+        expired.status = ApplyShieldQueuedTransactionStatus::Submitted;
+        let expired_intent = expired
+            .notarized_transaction()
+            .signed_intent()
+            .intent()
+            .clone();
+        let end_epoch = u64::from(expired_intent.header.end_epoch_exclusive);
+        let os = boot(MockNetworkingDriver::new_with_responses(vec![
+            MockNetworkingDriverResponse::new_success(
+                TSR::sample_permanently_rejected(None),
+            ),
+            // The epoch window has ended...
+            current_epoch(end_epoch),
+            // ... so we start a new one.
+            current_epoch(end_epoch),
+            submitted(),
+            MockNetworkingDriverResponse::new_success(
+                TSR::sample_committed_failure(None),
+            ),
+        ]))
+        .await;
+        enqueue(&os, vec![expired.clone()]).await;
+        let signer = RecordingSigner::default();
+
+        let queue = process_queue(
+            &os,
+            &signer,
+            os.apply_shield_transactions_queue().await.unwrap(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            *signer.signed_with.lock().unwrap(),
+            vec![(expired.entity_address, expired.role_kinds.clone())]
+        );
+        let re_notarized_intent = queue[0]
+            .notarized_transaction()
+            .signed_intent()
+            .intent()
+            .clone();
+        assert_ne!(
+            queue[0].transaction_intent_hash(),
+            expired.transaction_intent_hash()
+        );
+        assert_eq!(
+            re_notarized_intent.header.start_epoch_inclusive,
+            Epoch::from(end_epoch)
+        );
+        assert_eq!(re_notarized_intent.manifest, expired_intent.manifest);
+        assert_eq!(
+            queue[0].status,
+            ApplyShieldQueuedTransactionStatus::Failed {
+                reason: "Failed: Unknown".to_owned()
+            }
+        );
+    }
+
+    #[actix_rt::test]
+    async fn rejected_transaction_within_epoch_window_fails() {
+        let mut submitted = ApplyShieldQueuedTransaction::sample_other();
+        submitted.status = ApplyShieldQueuedTransactionStatus::Submitted;
+        let os = boot(MockNetworkingDriver::new_with_responses(vec![
+            MockNetworkingDriverResponse::new_success(
+                TSR::sample_permanently_rejected(None),
+            ),
+            current_epoch(0),
+        ]))
+        .await;
+        enqueue(&os, vec![submitted]).await;
+        let signer = RecordingSigner::default();
+
+        let queue = process_queue(
+            &os,
+            &signer,
+            os.apply_shield_transactions_queue().await.unwrap(),
+        )
+        .await
+        .unwrap();
+
+        assert!(signer.signed_with.lock().unwrap().is_empty());
+        assert!(matches!(
+            queue[0].status,
+            ApplyShieldQueuedTransactionStatus::Failed { .. }
+        ));
+    }
+}
@@ -6,16 +6,61 @@ pub trait ApplyShieldTransactionsSigner: Send + Sync {
         &self,
         payload_to_sign: ApplySecurityShieldPayloadToSign,
     ) -> Result<ApplySecurityShieldSignedPayload>;
+
+    /// Signs `transaction_intent` - applying a shield to the entity with
+    /// `entity_address` - with the `role_kinds` of that entity, and with the
+    /// Primary role of the fee payer, if it is another account.
+    ///
+    /// Used to sign a transaction with the roles it was originally signed
+    /// with, when it has to be re-notarized since its epoch window expired.
+    async fn sign_transaction_intent_with_roles(
+        &self,
+        transaction_intent: TransactionIntent,
+        entity_address: AddressOfAccountOrPersona,
+        role_kinds: Vec<RoleKind>,
+    ) -> Result<SignedIntent>;
 }
 
-pub struct ApplyShieldTransactionsSignerImpl {}
+pub struct ApplyShieldTransactionsSignerImpl {
+    profile: Profile,
+    interactor: Arc<dyn SignInteractor<TransactionIntent>>,
+}
 
 impl ApplyShieldTransactionsSignerImpl {
-    pub fn new(_os: &SargonOS) -> Self {
+    pub fn new(os: &SargonOS) -> Result<Self> {
         warn!(
-            "ApplyShieldTransactionsSignerImpl is not implemented yet. Actually might only need the `sign_transactions_interactor` here"
+            "ApplyShieldTransactionsSignerImpl::sign_transaction_intents is not implemented yet"
         );
-        Self {}
+        Ok(Self {
+            profile: os.profile()?,
+            interactor: os.sign_transactions_interactor(),
+        })
+    }
+
+    async fn collect_signatures_of_role(
+        &self,
+        transaction_intent: &TransactionIntent,
+        entity: AccountOrPersona,
+        role_kind: RoleKind,
+    ) -> Result<IndexSet<HDSignature<TransactionIntentHash>>> {
+        let outcome = SignaturesCollector::with(
+            SigningFinishEarlyStrategy::default(),
+            self.profile.factor_sources.iter().collect(),
+            IdentifiedVecOf::from(vec![SignableWithEntities::with(
+                transaction_intent.clone(),
+                vec![entity],
+            )]),
+            self.interactor.clone(),
+            SigningPurpose::sign_transaction(role_kind),
+        )
+        .collect_signatures()
+        .await?;
+
+        if outcome.successful() {
+            Ok(outcome.all_signatures())
+        } else {
+            Err(CommonError::SigningFailedTooManyFactorSourcesNeglected)
+        }
     }
 }
 
@@ -30,6 +75,57 @@ impl ApplyShieldTransactionsSigner for ApplyShieldTransactionsSignerImpl {
         #[cfg(test)]
         self.fake_sign(_payload_to_sign)
     }
+
+    async fn sign_transaction_intent_with_roles(
+        &self,
+        transaction_intent: TransactionIntent,
+        entity_address: AddressOfAccountOrPersona,
+        role_kinds: Vec<RoleKind>,
+    ) -> Result<SignedIntent> {
+        let entity = self.profile.entity_by_address(entity_address)?;
+
+        let mut signatures = IndexSet::new();
+        for role_kind in role_kinds {
+            signatures.extend(
+                self.collect_signatures_of_role(
+                    &transaction_intent,
+                    entity.clone(),
+                    role_kind,
+                )
+                .await?,
+            );
+        }
+
+        if let Some((fee_paying_account_address, _)) =
+            transaction_intent.extract_fee_payer_info()
+        {
+            if AddressOfAccountOrPersona::from(fee_paying_account_address)
+                != entity_address
+            {
+                let fee_payer_account = self
+                    .profile
+                    .account_by_address(fee_paying_account_address)?;
+                signatures.extend(
+                    self.collect_signatures_of_role(
+                        &transaction_intent,
+                        AccountOrPersona::from(fee_payer_account),
+                        RoleKind::Primary,
+                    )
+                    .await?,
+                );
+            }
+        }
+
+        let intent_signatures = signatures
+            .into_iter()
+            .map(|hd| IntentSignature(hd.signature))
+            .collect_vec();
+
+        SignedIntent::new(
+            transaction_intent,
+            IntentSignatures::new(intent_signatures),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -46,6 +142,19 @@ impl SecurityShieldApplicationWithTransactionIntents {
         }
     }
     }
+
+    /// The roles of the entity applying the shield which sign the
+    /// `first_transaction_intent`.
+    fn first_transaction_role_kinds(&self) -> Vec<RoleKind> {
+        match self {
+            SecurityShieldApplicationWithTransactionIntents::ForSecurifiedEntity(_) => {
+                vec![RoleKind::Primary, RoleKind::Confirmation]
+            }
+            SecurityShieldApplicationWithTransactionIntents::ForUnsecurifiedEntity(_) => {
+                vec![RoleKind::Primary]
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -55,23 +164,36 @@ impl ApplyShieldTransactionsSignerImpl {
         payload_to_sign: ApplySecurityShieldPayloadToSign,
     ) -> Result<ApplySecurityShieldSignedPayload> {
         error!("Signing transaction intents is not implemented yet");
+        let mut entity_address_per_transaction = IndexMap::new();
+        let mut role_kinds_per_transaction = IndexMap::new();
+        let notarized_transactions = payload_to_sign
+            .applications_with_intents
+            .into_iter()
+            .map(|i| {
+                let intent = i.first_transaction_intent();
+                entity_address_per_transaction.insert(
+                    intent.transaction_intent_hash(),
+                    i.address_of_entity_applying_shield(),
+                );
+                role_kinds_per_transaction.insert(
+                    intent.transaction_intent_hash(),
+                    i.first_transaction_role_kinds(),
+                );
+
+                NotarizedTransaction::new(
+                    SignedIntent {
+                        intent,
+                        intent_signatures: IntentSignatures::sample(),
+                    },
+                    NotarySignature::sample(),
+                )
+                .unwrap()
+            })
+            .collect_vec();
         Ok(ApplySecurityShieldSignedPayload {
-            notarized_transactions: payload_to_sign
-                .applications_with_intents
-                .into_iter()
-                .map(|i| {
-                    let intent = i.first_transaction_intent();
-
-                    NotarizedTransaction::new(
-                        SignedIntent {
-                            intent,
-                            intent_signatures: IntentSignatures::sample(),
-                        },
-                        NotarySignature::sample(),
-                    )
-                    .unwrap()
-                })
-                .collect_vec(),
+            notarized_transactions,
+            entity_address_per_transaction,
+            role_kinds_per_transaction,
         })
     }
 }
@@ -3,6 +3,7 @@ mod apply_security_shield_payload_to_sign;
 mod apply_shield_transactions_builder;
 mod apply_shield_transactions_committer;
 mod apply_shield_transactions_enqueuer;
+mod apply_shield_transactions_queue_processing;
 mod apply_shield_transactions_signer;
 mod models;
 
@@ -11,5 +12,6 @@ pub use apply_security_shield_payload_to_sign::*;
 pub use apply_shield_transactions_builder::*;
 pub use apply_shield_transactions_committer::*;
 pub use apply_shield_transactions_enqueuer::*;
+pub use apply_shield_transactions_queue_processing::*;
 pub use apply_shield_transactions_signer::*;
 pub use models::*;
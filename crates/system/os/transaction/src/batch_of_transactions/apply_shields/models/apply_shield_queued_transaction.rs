@@ -0,0 +1,169 @@
+use crate::prelude::*;
+
+/// A signed transaction applying a shield to an entity, waiting in the
+/// persisted queue of apply shield transactions to be submitted, or to have
+/// its commit confirmed.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyShieldQueuedTransaction {
+    /// The entity this transaction applies a shield to.
+    pub entity_address: AddressOfAccountOrPersona,
+
+    /// The compiled notarized transaction, we store it compiled since that
+    /// is how it is submitted to the network.
+    pub compiled_notarized_intent: CompiledNotarizedIntent,
+
+    /// The roles of the entity the transaction was signed with, so that we
+    /// can sign it with the same roles if it has to be re-notarized.
+    pub role_kinds: Vec<RoleKind>,
+
+    pub status: ApplyShieldQueuedTransactionStatus,
+}
+
+/// The progress of an `ApplyShieldQueuedTransaction`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ApplyShieldQueuedTransactionStatus {
+    /// Not yet submitted, or needs to be submitted again.
+    Queued,
+
+    /// Submitted to the network, but not yet committed.
+    Submitted,
+
+    /// Committed successfully and the provisional security structure of the
+    /// entity has been marked as applied.
+    Committed,
+
+    /// The transaction failed or was rejected, or a transaction preceding it
+    /// in the queue did.
+    #[serde(rename_all = "camelCase")]
+    Failed { reason: String },
+}
+
+impl ApplyShieldQueuedTransactionStatus {
+    /// Whether no further processing of the transaction will happen.
+    pub fn is_final(&self) -> bool {
+        matches!(self, Self::Committed | Self::Failed { .. })
+    }
+}
+
+impl ApplyShieldQueuedTransaction {
+    pub fn new(
+        entity_address: impl Into<AddressOfAccountOrPersona>,
+        notarized_transaction: &NotarizedTransaction,
+        role_kinds: impl IntoIterator<Item = RoleKind>,
+    ) -> Self {
+        Self {
+            entity_address: entity_address.into(),
+            compiled_notarized_intent: notarized_transaction.compile(),
+            role_kinds: role_kinds.into_iter().collect(),
+            status: ApplyShieldQueuedTransactionStatus::Queued,
+        }
+    }
+
+    pub fn notarized_transaction(&self) -> NotarizedTransaction {
+        NotarizedTransaction::decompile(&self.compiled_notarized_intent)
+    }
+
+    pub fn transaction_intent_hash(&self) -> TransactionIntentHash {
+        self.notarized_transaction()
+            .signed_intent()
+            .intent()
+            .transaction_intent_hash()
+    }
+
+    /// Replaces the transaction with `notarized_transaction`, e.g. after it
+    /// has been re-notarized with a new epoch window, queueing it again.
+    pub fn replace_transaction(
+        &mut self,
+        notarized_transaction: &NotarizedTransaction,
+    ) {
+        self.compiled_notarized_intent = notarized_transaction.compile();
+        self.status = ApplyShieldQueuedTransactionStatus::Queued;
+    }
+}
+
+impl HasSampleValues for ApplyShieldQueuedTransaction {
+    fn sample() -> Self {
+        Self::new(
+            AccountAddress::sample(),
+            &NotarizedTransaction::sample(),
+            [RoleKind::Primary],
+        )
+    }
+
+    fn sample_other() -> Self {
+        Self::new(
+            IdentityAddress::sample(),
+            &NotarizedTransaction::sample_other(),
+            [RoleKind::Primary, RoleKind::Confirmation],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = ApplyShieldQueuedTransaction;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn transaction_roundtrip() {
+        let sut = SUT::sample();
+        assert_eq!(sut.notarized_transaction(), NotarizedTransaction::sample());
+        assert_eq!(
+            sut.transaction_intent_hash(),
+            NotarizedTransaction::sample()
+                .signed_intent()
+                .intent()
+                .transaction_intent_hash()
+        );
+    }
+
+    #[test]
+    fn replace_transaction_queues_again() {
+        let mut sut = SUT::sample();
+        sut.status = ApplyShieldQueuedTransactionStatus::Submitted;
+
+        sut.replace_transaction(&NotarizedTransaction::sample_other());
+
+        assert_eq!(sut.status, ApplyShieldQueuedTransactionStatus::Queued);
+        assert_eq!(
+            sut.notarized_transaction(),
+            NotarizedTransaction::sample_other()
+        );
+    }
+
+    #[test]
+    fn is_final() {
+        assert!(!ApplyShieldQueuedTransactionStatus::Queued.is_final());
+        assert!(!ApplyShieldQueuedTransactionStatus::Submitted.is_final());
+        assert!(ApplyShieldQueuedTransactionStatus::Committed.is_final());
+        assert!(ApplyShieldQueuedTransactionStatus::Failed {
+            reason: "rejected".to_owned()
+        }
+        .is_final());
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let mut sut = SUT::sample();
+        sut.status = ApplyShieldQueuedTransactionStatus::Failed {
+            reason: "rejected".to_owned(),
+        };
+        let json = serde_json::to_string(&sut).unwrap();
+        assert_eq!(serde_json::from_str::<SUT>(&json).unwrap(), sut);
+    }
+}
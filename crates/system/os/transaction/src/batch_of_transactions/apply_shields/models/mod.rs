@@ -1,10 +1,12 @@
 mod application;
+mod apply_shield_queued_transaction;
 mod manifest_with_payer_by_address;
 mod payload_to_sign;
 mod signed_payload;
 mod xrd_balance_of_entity;
 
 pub use application::*;
+pub use apply_shield_queued_transaction::*;
 pub use manifest_with_payer_by_address::*;
 pub use payload_to_sign::*;
 pub use signed_payload::*;
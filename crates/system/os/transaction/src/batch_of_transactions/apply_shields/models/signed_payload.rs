@@ -6,4 +6,16 @@ pub struct ApplySecurityShieldSignedPayload {
     /// and we select "the best" (quick confirm if possible) depending on the outcome of the
     /// signing process
     pub notarized_transactions: Vec<NotarizedTransaction>,
+
+    /// The address of the entity each of the `notarized_transactions` applies
+    /// a shield to, so that we can mark its provisional security structure
+    /// as applied once the transaction has been committed.
+    pub entity_address_per_transaction:
+        IndexMap<TransactionIntentHash, AddressOfAccountOrPersona>,
+
+    /// The roles of the entity applying the shield each of the
+    /// `notarized_transactions` was signed with, so that it can be signed
+    /// with the same roles again if it has to be re-notarized.
+    pub role_kinds_per_transaction:
+        IndexMap<TransactionIntentHash, Vec<RoleKind>>,
 }
@@ -21,6 +21,7 @@ pub mod prelude {
 
     pub(crate) use enum_as_inner::EnumAsInner;
     pub(crate) use radix_engine_interface::prelude::MetadataValue as ScryptoMetadataValue;
+    pub(crate) use serde::{Deserialize, Serialize};
 }
 
 pub use prelude::*;
@@ -16,19 +16,45 @@ pub trait OSPollPreAuthorizationStatus {
 #[async_trait::async_trait]
 impl OSPollPreAuthorizationStatus for SargonOS {
     /// Polls the status of a `SubintentHash` until it is either `Success` or `Expired`.
+    ///
+    /// The Pre-Authorization is tracked as a `PendingTransaction` while
+    /// polling, so that polling can be resumed after an app restart.
     async fn poll_pre_authorization_status(
         &self,
         intent_hash: SubintentHash,
         expiration_timestamp: Instant,
     ) -> Result<PreAuthorizationStatus> {
+        let pending_transaction = PendingTransaction::pre_authorization(
+            intent_hash.clone(),
+            Timestamp::from(expiration_timestamp),
+        );
+        if let Err(error) =
+            self.track_pending_transaction(pending_transaction).await
+        {
+            error!(
+                "Failed to track Pre-Authorization {}: {:?}",
+                intent_hash, error
+            );
+        }
+
         let seconds_until_expiration =
             self.seconds_until_expiration(expiration_timestamp);
-        self.poll_pre_authorization_status_with_delays(
-            intent_hash,
-            seconds_until_expiration,
+        let (status, _) = self
+            .poll_pre_authorization_status_with_delays(
+                intent_hash.clone(),
+                seconds_until_expiration,
+            )
+            .await?;
+
+        self.resolve_pending_transaction(
+            PendingTransactionOutcome::PreAuthorization {
+                subintent_hash: intent_hash,
+                status: status.clone(),
+            },
         )
-        .await
-        .map(|(status, _)| status)
+        .await?;
+
+        Ok(status)
     }
 }
 
@@ -321,6 +347,56 @@ mod poll_pre_authorization_status_with_delays {
     }
 }
 
+#[cfg(test)]
+mod poll_pre_authorization_status_tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = SargonOS;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SSR = SubintentStatusResponse;
+
+    #[actix_rt::test]
+    async fn is_tracked_while_polling_and_resolved_after() {
+        let mock_driver = MockNetworkingDriver::with_responses(vec![
+            SSR::sample_unknown(),
+            SSR::sample_committed_success(),
+        ]);
+        let req = SUT::boot_test_with_networking_driver(Arc::new(mock_driver));
+        let os =
+            actix_rt::time::timeout(SARGON_OS_TEST_MAX_ASYNC_DURATION, req)
+                .await
+                .unwrap()
+                .unwrap();
+        let expiration_timestamp =
+            Instant::from(Timestamp::now_utc().add(Duration::from_secs(60)));
+
+        let os_clone = os.clone();
+        let handle = actix_rt::spawn(async move {
+            os_clone
+                .poll_pre_authorization_status(
+                    SubintentHash::sample(),
+                    expiration_timestamp,
+                )
+                .await
+        });
+        async_std::task::sleep(Duration::from_millis(1)).await;
+        assert_eq!(
+            os.pending_transactions().await.unwrap(),
+            vec![PendingTransaction::pre_authorization(
+                SubintentHash::sample(),
+                Timestamp::from(expiration_timestamp),
+            )]
+        );
+
+        let status = handle.await.unwrap().unwrap();
+
+        assert!(matches!(status, PreAuthorizationStatus::Success { .. }));
+        assert!(os.pending_transactions().await.unwrap().is_empty());
+    }
+}
+
 #[cfg(test)]
 mod seconds_until_expiration_tests {
     use super::*;
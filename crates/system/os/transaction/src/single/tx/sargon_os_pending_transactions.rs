@@ -0,0 +1,603 @@
+use crate::prelude::*;
+
+#[async_trait::async_trait]
+pub trait OsPendingTransactions {
+    async fn pending_transactions(&self) -> Result<Vec<PendingTransaction>>;
+
+    async fn track_pending_transaction(
+        &self,
+        pending_transaction: PendingTransaction,
+    ) -> Result<()>;
+
+    async fn cancel_pending_transaction(
+        &self,
+        pending_transaction: PendingTransaction,
+    ) -> Result<()>;
+
+    async fn poll_pending_transaction(
+        &self,
+        pending_transaction: PendingTransaction,
+    ) -> Result<PendingTransactionOutcome>;
+
+    async fn resume_polling_pending_transactions(
+        &self,
+    ) -> Result<Vec<PendingTransactionOutcome>>;
+
+    async fn resolve_pending_transaction(
+        &self,
+        outcome: PendingTransactionOutcome,
+    ) -> Result<()>;
+}
+
+// ==================
+// Pending Transactions (Public)
+// ==================
+#[async_trait::async_trait]
+impl OsPendingTransactions for SargonOS {
+    /// The transactions and Pre-Authorizations which have been submitted but
+    /// whose outcome is not yet known, persisted in unsafe storage.
+    async fn pending_transactions(&self) -> Result<Vec<PendingTransaction>> {
+        self.unsafe_storage
+            .load(UnsafeStorageKey::PendingTransactions)
+            .await
+            .map(Option::unwrap_or_default)
+    }
+
+    /// Starts tracking `pending_transaction`, persisting it so that polling of
+    /// its status can be resumed after an app restart. Does nothing if it is
+    /// already tracked.
+    async fn track_pending_transaction(
+        &self,
+        pending_transaction: PendingTransaction,
+    ) -> Result<()> {
+        self.transactions_coordination()
+            .cancelled_pending_transactions
+            .write()
+            .expect("Stop polling cancelled transactions")
+            .remove(&pending_transaction.hash());
+        update_pending_transactions(self, |pending_transactions| {
+            if !pending_transactions.contains(&pending_transaction) {
+                pending_transactions.push(pending_transaction);
+            }
+        })
+        .await
+    }
+
+    /// Stops tracking `pending_transaction`, any ongoing polling of its status
+    /// is cancelled within a second.
+    async fn cancel_pending_transaction(
+        &self,
+        pending_transaction: PendingTransaction,
+    ) -> Result<()> {
+        let hash = pending_transaction.hash();
+        self.transactions_coordination()
+            .cancelled_pending_transactions
+            .write()
+            .expect("Stop polling cancelled transactions")
+            .insert(hash.clone());
+        update_pending_transactions(self, |pending_transactions| {
+            pending_transactions.retain(|p| p.hash() != hash)
+        })
+        .await
+    }
+
+    /// Tracks and polls the status of `pending_transaction` until its outcome
+    /// is known, after which it is no longer tracked and an event is emitted.
+    ///
+    /// Polling stops with an error if `pending_transaction` gets cancelled,
+    /// or if we have been polling for too long, in which case it is still
+    /// tracked and polling can be resumed later.
+    async fn poll_pending_transaction(
+        &self,
+        pending_transaction: PendingTransaction,
+    ) -> Result<PendingTransactionOutcome> {
+        poll_pending_transaction_with_backoff(
+            self,
+            pending_transaction,
+            PollingBackoff::default(),
+        )
+        .await
+    }
+
+    /// Polls all tracked pending transactions, typically called after an app
+    /// restart, returns the outcomes of the ones which could be determined.
+    async fn resume_polling_pending_transactions(
+        &self,
+    ) -> Result<Vec<PendingTransactionOutcome>> {
+        let mut outcomes = Vec::new();
+        for pending_transaction in self.pending_transactions().await? {
+            match self.poll_pending_transaction(pending_transaction).await {
+                Ok(outcome) => outcomes.push(outcome),
+                Err(error) => {
+                    warn!(
+                        "Failed to resume polling of pending transaction: {:?}",
+                        error
+                    )
+                }
+            }
+        }
+        Ok(outcomes)
+    }
+
+    /// Stops tracking the pending transaction of `outcome` and emits the
+    /// corresponding event.
+    async fn resolve_pending_transaction(
+        &self,
+        outcome: PendingTransactionOutcome,
+    ) -> Result<()> {
+        let hash = outcome.hash();
+        update_pending_transactions(self, |pending_transactions| {
+            pending_transactions.retain(|p| p.hash() != hash)
+        })
+        .await?;
+
+        self.event_bus
+            .emit(EventNotification::new(outcome.event()))
+            .await;
+
+        Ok(())
+    }
+}
+
+/// Loads the pending transactions, applies `update` to them and saves them,
+/// while holding the pending transactions lock of `os`, so that concurrent
+/// tracking, cancelling and resolving of pending transactions do not
+/// overwrite each other's changes.
+async fn update_pending_transactions(
+    os: &SargonOS,
+    update: impl FnOnce(&mut Vec<PendingTransaction>) + Send,
+) -> Result<()> {
+    let coordination = os.transactions_coordination();
+    let _guard = coordination.pending_transactions_lock.lock().await;
+    let mut pending_transactions = os.pending_transactions().await?;
+    update(&mut pending_transactions);
+    os.unsafe_storage
+        .save(UnsafeStorageKey::PendingTransactions, &pending_transactions)
+        .await
+}
+
+fn is_cancelled(
+    os: &SargonOS,
+    pending_transaction: &PendingTransaction,
+) -> bool {
+    os.transactions_coordination()
+        .cancelled_pending_transactions
+        .read()
+        .expect("Stop polling cancelled transactions")
+        .contains(&pending_transaction.hash())
+}
+
+/// Tracks and polls the status of `pending_transaction` with `backoff`
+/// between polls, see `poll_pending_transaction`.
+///
+/// The delays between polls are waited a second at a time, so that a
+/// cancellation is noticed promptly, and not only before the next poll.
+/// Failures to fetch the status, e.g. because the Gateway is unreachable,
+/// are retried within the same `backoff`.
+async fn poll_pending_transaction_with_backoff(
+    os: &SargonOS,
+    pending_transaction: PendingTransaction,
+    mut backoff: PollingBackoff,
+) -> Result<PendingTransactionOutcome> {
+    os.track_pending_transaction(pending_transaction.clone())
+        .await?;
+
+    let gateway_client = os.gateway_client()?;
+    let cancelled = || CommonError::TransactionPollingCancelled {
+        hash: pending_transaction.hash(),
+    };
+
+    loop {
+        if is_cancelled(os, &pending_transaction) {
+            return Err(cancelled());
+        }
+
+        match fetch_pending_transaction_outcome(
+            &gateway_client,
+            &pending_transaction,
+        )
+        .await
+        {
+            Ok(Some(outcome)) => {
+                os.resolve_pending_transaction(outcome.clone()).await?;
+                return Ok(outcome);
+            }
+            Ok(None) => {}
+            Err(error) => {
+                warn!(
+                    "Failed to fetch status of pending transaction, retrying: {:?}",
+                    error
+                );
+            }
+        }
+
+        let Some(delay) = backoff.next_delay() else {
+            return Err(CommonError::TransactionPollingTimedOut {
+                hash: pending_transaction.hash(),
+            });
+        };
+        for _ in 0..delay {
+            PollingBackoff::sleep(1).await;
+            if is_cancelled(os, &pending_transaction) {
+                return Err(cancelled());
+            }
+        }
+    }
+}
+
+/// Fetches the status of `pending_transaction` once, returning `None` if its
+/// outcome is not yet known.
+async fn fetch_pending_transaction_outcome(
+    gateway_client: &GatewayClient,
+    pending_transaction: &PendingTransaction,
+) -> Result<Option<PendingTransactionOutcome>> {
+    match pending_transaction {
+        PendingTransaction::Transaction {
+            intent_hash,
+            end_epoch_exclusive,
+        } => Ok(fetch_final_transaction_status(
+            gateway_client,
+            intent_hash,
+            Some(*end_epoch_exclusive),
+        )
+        .await
+        .map(|status| PendingTransactionOutcome::Transaction {
+            intent_hash: intent_hash.clone(),
+            status,
+        })),
+        PendingTransaction::PreAuthorization {
+            subintent_hash,
+            expiration_timestamp,
+        } => {
+            let response = gateway_client
+                .subintent_status(SubintentStatusRequest::new(
+                    subintent_hash.to_string(),
+                ))
+                .await?;
+            let status = if response.subintent_status
+                == SubintentStatus::CommittedSuccess
+            {
+                let hash = response
+                    .finalized_at_transaction_intent_hash
+                    .ok_or(CommonError::Unknown {
+                        error_message:
+                            "Failed mapping sub-intent transaction hash"
+                                .to_string(),
+                    })?;
+                Some(PreAuthorizationStatus::Success {
+                    intent_hash: TransactionIntentHash::from_bech32(&hash)?,
+                })
+            } else if Timestamp::now_utc() > *expiration_timestamp {
+                // A Pre-Authorization can no longer be committed once expired
+                Some(PreAuthorizationStatus::Expired)
+            } else {
+                None
+            };
+            Ok(status.map(|status| {
+                PendingTransactionOutcome::PreAuthorization {
+                    subintent_hash: subintent_hash.clone(),
+                    status,
+                }
+            }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ops::Add;
+    use std::time::Duration;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = SargonOS;
+    #[allow(clippy::upper_case_acronyms)]
+    type TSR = TransactionStatusResponse;
+    #[allow(clippy::upper_case_acronyms)]
+    type SSR = SubintentStatusResponse;
+
+    async fn boot(driver: MockNetworkingDriver) -> Arc<SUT> {
+        let req = SUT::boot_test_with_networking_driver(Arc::new(driver));
+        actix_rt::time::timeout(SARGON_OS_TEST_MAX_ASYNC_DURATION, req)
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
+    fn pending_transaction() -> PendingTransaction {
+        PendingTransaction::transaction(
+            TransactionIntentHash::sample(),
+            Epoch::from(LedgerState::sample_stokenet().epoch + 10),
+        )
+    }
+
+    #[actix_rt::test]
+    async fn track_and_cancel() {
+        let os = boot(MockNetworkingDriver::new_always_failing()).await;
+        assert!(os.pending_transactions().await.unwrap().is_empty());
+
+        os.track_pending_transaction(PendingTransaction::sample())
+            .await
+            .unwrap();
+        os.track_pending_transaction(PendingTransaction::sample_other())
+            .await
+            .unwrap();
+        // tracking twice is a no-op
+        os.track_pending_transaction(PendingTransaction::sample())
+            .await
+            .unwrap();
+        assert_eq!(
+            os.pending_transactions().await.unwrap(),
+            vec![
+                PendingTransaction::sample(),
+                PendingTransaction::sample_other()
+            ]
+        );
+
+        os.cancel_pending_transaction(PendingTransaction::sample())
+            .await
+            .unwrap();
+        assert_eq!(
+            os.pending_transactions().await.unwrap(),
+            vec![PendingTransaction::sample_other()]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn poll_pending_transaction_committed() {
+        let os = boot(MockNetworkingDriver::with_responses(vec![
+            TSR::sample_pending(),
+            TSR::sample_committed_success(),
+        ]))
+        .await;
+
+        let outcome = os
+            .poll_pending_transaction(pending_transaction())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            outcome,
+            PendingTransactionOutcome::Transaction {
+                intent_hash: TransactionIntentHash::sample(),
+                status: TransactionStatus::Success,
+            }
+        );
+        assert_eq!(
+            outcome.event(),
+            Event::TransactionCommitted {
+                intent_hash: TransactionIntentHash::sample()
+            }
+        );
+        assert!(os.pending_transactions().await.unwrap().is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn poll_pending_transaction_past_end_epoch() {
+        let os = boot(MockNetworkingDriver::with_responses(vec![
+            TSR::sample_pending(),
+        ]))
+        .await;
+
+        let outcome = os
+            .poll_pending_transaction(PendingTransaction::sample())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            outcome,
+            PendingTransactionOutcome::Transaction {
+                intent_hash: TransactionIntentHash::sample(),
+                status: TransactionStatus::PermanentlyRejected {
+                    reason: TransactionStatusReason::Unknown
+                },
+            }
+        );
+        assert!(os.pending_transactions().await.unwrap().is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn poll_pending_transaction_timed_out_is_still_tracked() {
+        let os = boot(MockNetworkingDriver::new_always_failing()).await;
+
+        let result = os.poll_pending_transaction(pending_transaction()).await;
+
+        assert_eq!(
+            result,
+            Err(CommonError::TransactionPollingTimedOut {
+                hash: TransactionIntentHash::sample().to_string()
+            })
+        );
+        assert_eq!(
+            os.pending_transactions().await.unwrap(),
+            vec![pending_transaction()]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn poll_pending_transaction_cancelled() {
+        let os = boot(MockNetworkingDriver::new_always_failing()).await;
+
+        let os_clone = os.clone();
+        actix_rt::spawn(async move {
+            async_std::task::sleep(Duration::from_millis(5)).await;
+            os_clone
+                .cancel_pending_transaction(pending_transaction())
+                .await
+                .unwrap();
+        });
+        let result = os.poll_pending_transaction(pending_transaction()).await;
+
+        assert_eq!(
+            result,
+            Err(CommonError::TransactionPollingCancelled {
+                hash: TransactionIntentHash::sample().to_string()
+            })
+        );
+        assert!(os.pending_transactions().await.unwrap().is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn poll_pending_transaction_cancelled_while_waiting() {
+        let os = boot(MockNetworkingDriver::new_always_failing()).await;
+
+        let os_clone = os.clone();
+        actix_rt::spawn(async move {
+            async_std::task::sleep(Duration::from_millis(5)).await;
+            os_clone
+                .cancel_pending_transaction(pending_transaction())
+                .await
+                .unwrap();
+        });
+        // A single delay of 1000 "seconds" (milliseconds in tests), the
+        // cancellation must be noticed long before it has been waited.
+        let req = poll_pending_transaction_with_backoff(
+            &os,
+            pending_transaction(),
+            PollingBackoff::new(1000, 1000, 1000),
+        );
+        let result = actix_rt::time::timeout(Duration::from_millis(500), req)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result,
+            Err(CommonError::TransactionPollingCancelled {
+                hash: TransactionIntentHash::sample().to_string()
+            })
+        );
+    }
+
+    #[actix_rt::test]
+    async fn concurrent_tracking_keeps_all() {
+        let os = boot(MockNetworkingDriver::new_always_failing()).await;
+
+        let pending = (0..10)
+            .map(|i| {
+                PendingTransaction::transaction(
+                    TransactionIntentHash::sample(),
+                    Epoch::from(i),
+                )
+            })
+            .collect_vec();
+        let handles = pending
+            .iter()
+            .cloned()
+            .map(|p| {
+                let os = os.clone();
+                actix_rt::spawn(
+                    async move { os.track_pending_transaction(p).await },
+                )
+            })
+            .collect_vec();
+        for handle in handles {
+            handle.await.unwrap().unwrap();
+        }
+
+        assert_eq!(
+            os.pending_transactions().await.unwrap().len(),
+            pending.len()
+        );
+    }
+
+    #[actix_rt::test]
+    async fn poll_pending_pre_authorization_committed() {
+        let os = boot(MockNetworkingDriver::with_responses(vec![
+            SSR::sample_unknown(),
+            SSR::sample_committed_success(),
+        ]))
+        .await;
+        let pending = PendingTransaction::pre_authorization(
+            SubintentHash::sample(),
+            Timestamp::now_utc().add(Duration::from_secs(60)),
+        );
+
+        let outcome = os.poll_pending_transaction(pending).await.unwrap();
+
+        assert!(matches!(
+            outcome,
+            PendingTransactionOutcome::PreAuthorization {
+                status: PreAuthorizationStatus::Success { .. },
+                ..
+            }
+        ));
+        assert!(os.pending_transactions().await.unwrap().is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn poll_pending_pre_authorization_retries_transient_failure() {
+        let os = boot(MockNetworkingDriver::new_with_responses(vec![
+            MockNetworkingDriverResponse::new_failing(),
+            MockNetworkingDriverResponse::new_success(
+                SSR::sample_committed_success(),
+            ),
+        ]))
+        .await;
+        let pending = PendingTransaction::pre_authorization(
+            SubintentHash::sample(),
+            Timestamp::now_utc().add(Duration::from_secs(60)),
+        );
+
+        let outcome = os.poll_pending_transaction(pending).await.unwrap();
+
+        assert!(matches!(
+            outcome,
+            PendingTransactionOutcome::PreAuthorization {
+                status: PreAuthorizationStatus::Success { .. },
+                ..
+            }
+        ));
+        assert!(os.pending_transactions().await.unwrap().is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn poll_pending_pre_authorization_expired() {
+        let os = boot(MockNetworkingDriver::with_responses(vec![
+            SSR::sample_unknown(),
+        ]))
+        .await;
+
+        let outcome = os
+            .poll_pending_transaction(PendingTransaction::sample_other())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            outcome,
+            PendingTransactionOutcome::PreAuthorization {
+                subintent_hash: SubintentHash::sample(),
+                status: PreAuthorizationStatus::Expired,
+            }
+        );
+        assert_eq!(
+            outcome.event(),
+            Event::PreAuthorizationExpired {
+                subintent_hash: SubintentHash::sample()
+            }
+        );
+    }
+
+    #[actix_rt::test]
+    async fn resume_polling_pending_transactions() {
+        let os = boot(MockNetworkingDriver::with_responses(vec![
+            TSR::sample_committed_failure(None),
+        ]))
+        .await;
+        os.track_pending_transaction(pending_transaction())
+            .await
+            .unwrap();
+
+        let outcomes = os.resume_polling_pending_transactions().await.unwrap();
+
+        assert_eq!(
+            outcomes,
+            vec![PendingTransactionOutcome::Transaction {
+                intent_hash: TransactionIntentHash::sample(),
+                status: TransactionStatus::Failed {
+                    reason: TransactionStatusReason::Unknown
+                },
+            }]
+        );
+        assert!(os.pending_transactions().await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,221 @@
+use crate::prelude::*;
+
+#[async_trait::async_trait]
+pub trait OsPoolManifests {
+    async fn create_pool_contribution_manifest(
+        &self,
+        account_address: AccountAddress,
+        pool_address: PoolAddress,
+        contributions: HashMap<ResourceAddress, Decimal192>,
+    ) -> Result<TransactionManifest>;
+
+    async fn create_pool_redemption_manifest(
+        &self,
+        account_address: AccountAddress,
+        pool_address: PoolAddress,
+        pool_units: Decimal192,
+    ) -> Result<TransactionManifest>;
+}
+
+#[async_trait::async_trait]
+impl OsPoolManifests for SargonOS {
+    /// Creates a manifest contributing `contributions` from the account of
+    /// `account_address` to the pool of `pool_address`, asserting that at
+    /// least the `default_deposit_guarantee` of the pool units expected at
+    /// the current ratio of the pool, as fetched from the Gateway, is
+    /// received.
+    async fn create_pool_contribution_manifest(
+        &self,
+        account_address: AccountAddress,
+        pool_address: PoolAddress,
+        contributions: HashMap<ResourceAddress, Decimal192>,
+    ) -> Result<TransactionManifest> {
+        let pool_state = self
+            .gateway_client_with(account_address.network_id())
+            .fetch_pool_state(pool_address)
+            .await?;
+        let transaction_preferences =
+            self.profile()?.app_preferences.transaction;
+
+        TransactionManifest::contribution_to_pool(
+            account_address,
+            &pool_state,
+            contributions,
+            &transaction_preferences,
+        )
+    }
+
+    /// Creates a manifest redeeming `pool_units` of the pool of
+    /// `pool_address` from the account of `account_address`, asserting that
+    /// at least the `default_deposit_guarantee` of the resources expected
+    /// at the current state of the pool, as fetched from the Gateway, is
+    /// received.
+    async fn create_pool_redemption_manifest(
+        &self,
+        account_address: AccountAddress,
+        pool_address: PoolAddress,
+        pool_units: Decimal192,
+    ) -> Result<TransactionManifest> {
+        let pool_state = self
+            .gateway_client_with(account_address.network_id())
+            .fetch_pool_state(pool_address)
+            .await?;
+        let transaction_preferences =
+            self.profile()?.app_preferences.transaction;
+
+        Ok(TransactionManifest::redemption_from_pool(
+            account_address,
+            &pool_state,
+            pool_units,
+            &transaction_preferences,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = SargonOS;
+
+    async fn boot(responses: Vec<MockNetworkingDriverResponse>) -> Arc<SUT> {
+        let mock_driver = MockNetworkingDriver::new_with_responses(responses);
+        let req = SUT::boot_test_with_networking_driver(Arc::new(mock_driver));
+
+        actix_rt::time::timeout(SARGON_OS_TEST_MAX_ASYNC_DURATION, req)
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
+    fn pool_responses(
+        pool_state: &LiquidityPoolState,
+    ) -> Vec<MockNetworkingDriverResponse> {
+        let state = serde_json::to_string(&PoolFieldStateValue {
+            pool_unit_resource_address: pool_state.pool_unit_resource_address,
+        })
+        .unwrap();
+        let pool_item = StateEntityDetailsResponseItem::new(
+            pool_state.pool_address.into(),
+            FungibleResourcesCollection::new(
+                None,
+                None,
+                pool_state
+                    .reserves
+                    .iter()
+                    .map(|reserve| {
+                        FungibleResourcesCollectionItem::global(
+                            reserve.resource_address,
+                            reserve.amount,
+                        )
+                    })
+                    .collect_vec(),
+            ),
+            None,
+            EntityMetadataCollection::empty(),
+            StateEntityDetailsResponseItemDetails::Component(
+                StateEntityDetailsResponseComponentDetails {
+                    role_assignments: None,
+                    state: Some(state),
+                },
+            ),
+        );
+        let pool_unit_item = StateEntityDetailsResponseItem::new(
+            pool_state.pool_unit_resource_address.into(),
+            None,
+            None,
+            EntityMetadataCollection::empty(),
+            StateEntityDetailsResponseItemDetails::FungibleResource(
+                StateEntityDetailsResponseFungibleResourceDetails::with_total_supply(
+                    ComponentEntityRoleAssignments::sample(),
+                    pool_state.pool_unit_total_supply,
+                ),
+            ),
+        );
+        [pool_item, pool_unit_item]
+            .into_iter()
+            .map(|item| {
+                MockNetworkingDriverResponse::new_success(
+                    StateEntityDetailsResponse::new(
+                        LedgerState::sample(),
+                        vec![item],
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    #[actix_rt::test]
+    async fn create_pool_contribution_manifest() {
+        let pool_state = LiquidityPoolState::sample();
+        let os = boot(pool_responses(&pool_state)).await;
+        let account_address = AccountAddress::sample_stokenet();
+        let contributions = pool_state
+            .resource_addresses()
+            .into_iter()
+            .zip([Decimal192::from(200), Decimal192::from(100)])
+            .collect::<HashMap<_, _>>();
+
+        let manifest = os
+            .create_pool_contribution_manifest(
+                account_address,
+                pool_state.pool_address,
+                contributions.clone(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            manifest,
+            TransactionManifest::contribution_to_pool(
+                account_address,
+                &pool_state,
+                contributions,
+                &TransactionPreferences::default(),
+            )
+            .unwrap()
+        );
+    }
+
+    #[actix_rt::test]
+    async fn create_pool_redemption_manifest() {
+        let pool_state = LiquidityPoolState::sample();
+        let os = boot(pool_responses(&pool_state)).await;
+        let account_address = AccountAddress::sample_stokenet();
+
+        let manifest = os
+            .create_pool_redemption_manifest(
+                account_address,
+                pool_state.pool_address,
+                Decimal192::from(10),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            manifest,
+            TransactionManifest::redemption_from_pool(
+                account_address,
+                &pool_state,
+                Decimal192::from(10),
+                &TransactionPreferences::default(),
+            )
+        );
+    }
+
+    #[actix_rt::test]
+    async fn create_pool_redemption_manifest_failure() {
+        let os = boot(vec![MockNetworkingDriverResponse::new_failing()]).await;
+
+        let result = os
+            .create_pool_redemption_manifest(
+                AccountAddress::sample_stokenet(),
+                LiquidityPoolState::sample().pool_address,
+                Decimal192::from(10),
+            )
+            .await;
+
+        assert!(result.is_err());
+    }
+}
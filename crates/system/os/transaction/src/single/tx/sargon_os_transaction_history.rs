@@ -0,0 +1,94 @@
+use crate::prelude::*;
+
+#[async_trait::async_trait]
+pub trait OsTransactionHistory {
+    async fn fetch_transaction_history(
+        &self,
+        account_address: AccountAddress,
+        filters: TransactionHistoryFilters,
+        cursor: Option<String>,
+    ) -> Result<TransactionHistoryPage>;
+}
+
+// ==================
+// Fetch Transaction History (Public)
+// ==================
+#[async_trait::async_trait]
+impl OsTransactionHistory for SargonOS {
+    /// Fetches a page of the transaction history of the account of
+    /// `account_address` from the Gateway of the network of the account,
+    /// newest first, matching `filters`.
+    ///
+    /// Pass the `next_cursor` of the previous page as `cursor` to fetch the
+    /// next page.
+    async fn fetch_transaction_history(
+        &self,
+        account_address: AccountAddress,
+        filters: TransactionHistoryFilters,
+        cursor: Option<String>,
+    ) -> Result<TransactionHistoryPage> {
+        self.gateway_client_with(account_address.network_id())
+            .fetch_transaction_history(account_address, filters, cursor)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = SargonOS;
+
+    async fn boot(responses: Vec<MockNetworkingDriverResponse>) -> Arc<SUT> {
+        let mock_driver = MockNetworkingDriver::new_with_responses(responses);
+        let req = SUT::boot_test_with_networking_driver(Arc::new(mock_driver));
+
+        actix_rt::time::timeout(SARGON_OS_TEST_MAX_ASYNC_DURATION, req)
+            .await
+            .unwrap()
+            .unwrap()
+    }
+
+    #[actix_rt::test]
+    async fn fetch_transaction_history() {
+        let response = StreamTransactionsResponse::new(
+            LedgerState::sample(),
+            None,
+            "next".to_owned(),
+            Vec::new(),
+        );
+        let os =
+            boot(vec![MockNetworkingDriverResponse::new_success(response)])
+                .await;
+
+        let page = os
+            .fetch_transaction_history(
+                AccountAddress::sample_mainnet(),
+                TransactionHistoryFilters::default(),
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            page,
+            TransactionHistoryPage::new(Vec::new(), "next".to_owned())
+        );
+    }
+
+    #[actix_rt::test]
+    async fn fetch_transaction_history_failure() {
+        let os = boot(vec![MockNetworkingDriverResponse::new_failing()]).await;
+
+        let result = os
+            .fetch_transaction_history(
+                AccountAddress::sample_mainnet(),
+                TransactionHistoryFilters::default(),
+                None,
+            )
+            .await;
+
+        assert!(result.is_err());
+    }
+}
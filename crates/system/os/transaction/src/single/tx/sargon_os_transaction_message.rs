@@ -0,0 +1,338 @@
+use crate::prelude::*;
+
+#[async_trait::async_trait]
+pub trait OsTransactionMessageDecryption {
+    async fn decrypt_transaction_message(
+        &self,
+        message: EncryptedMessage,
+        account_address: AccountAddress,
+    ) -> Result<PlaintextMessage>;
+}
+
+// ==================
+// Decrypt Transaction Message (Public)
+// ==================
+#[async_trait::async_trait]
+impl OsTransactionMessageDecryption for SargonOS {
+    /// Decrypts an encrypted transaction message received by the account
+    /// with `account_address`, using the private key of the factor instance
+    /// of the account which the message was encrypted for.
+    ///
+    /// Fails if the account is unknown, if the message was not encrypted for
+    /// any of the keys of the account or if the mnemonic of the device factor
+    /// source controlling the key cannot be loaded from secure storage.
+    async fn decrypt_transaction_message(
+        &self,
+        message: EncryptedMessage,
+        account_address: AccountAddress,
+    ) -> Result<PlaintextMessage> {
+        let account = self.account_by_address(account_address)?;
+
+        let factor_instance = account
+            .unique_tx_signing_factor_instances()
+            .into_iter()
+            .filter_map(|f| {
+                HierarchicalDeterministicFactorInstance::try_from(f).ok()
+            })
+            .find(|f| message.can_be_decrypted_by(&f.public_key()))
+            .ok_or(CommonError::EncryptedMessageNotForAccount {
+                address: account_address.to_string(),
+            })?;
+
+        let private_factor_source = self
+            .load_private_device_factor_source_by_id(
+                factor_instance.factor_source_id,
+            )
+            .await?;
+
+        let private_key = private_factor_source
+            .mnemonic_with_passphrase
+            .derive_private_key(&factor_instance.derivation_path());
+
+        message.decrypt(&private_key)
+    }
+}
+
+#[async_trait::async_trait]
+pub trait OsTransactionMessageEncryption {
+    async fn set_encrypted_transaction_message(
+        &self,
+        intent: TransactionIntent,
+        message: PlaintextMessage,
+        recipients: Vec<AccountAddress>,
+    ) -> Result<TransactionIntent>;
+}
+
+// ==================
+// Encrypt Transaction Message (Public)
+// ==================
+#[async_trait::async_trait]
+impl OsTransactionMessageEncryption for SargonOS {
+    /// Encrypts `message` for the accounts of `recipients`, e.g. the accounts
+    /// receiving a transfer, and sets it as the message of `intent`.
+    ///
+    /// The public keys of accounts in the Profile are read from their
+    /// security state, those of other accounts are fetched from the
+    /// `owner_keys` metadata of the accounts on ledger.
+    ///
+    /// Fails if no public key is known for any of the recipients, which is
+    /// the case for accounts not in the Profile whose `owner_keys` only hold
+    /// key hashes - as they do unless explicitly set.
+    async fn set_encrypted_transaction_message(
+        &self,
+        intent: TransactionIntent,
+        message: PlaintextMessage,
+        recipients: Vec<AccountAddress>,
+    ) -> Result<TransactionIntent> {
+        let public_keys =
+            self.public_keys_of_message_recipients(recipients).await?;
+        let encrypted = EncryptedMessage::encrypting(message, public_keys)?;
+        TransactionIntent::new(
+            intent.header,
+            intent.manifest,
+            Message::Encrypted { encrypted },
+        )
+    }
+}
+
+impl SargonOS {
+    async fn public_keys_of_message_recipients(
+        &self,
+        recipients: Vec<AccountAddress>,
+    ) -> Result<Vec<PublicKey>> {
+        let mut public_keys_per_recipient = recipients
+            .iter()
+            .map(|recipient| {
+                let public_keys = self
+                    .account_by_address(*recipient)
+                    .map(|account| {
+                        account
+                            .unique_tx_signing_factor_instances()
+                            .into_iter()
+                            .filter_map(|f| {
+                                HierarchicalDeterministicFactorInstance::try_from(f)
+                                    .ok()
+                            })
+                            .map(|f| f.public_key())
+                            .collect_vec()
+                    })
+                    .unwrap_or_default();
+                (*recipient, public_keys)
+            })
+            .collect::<IndexMap<_, _>>();
+
+        let unknown_recipients = public_keys_per_recipient
+            .iter()
+            .filter(|(_, public_keys)| public_keys.is_empty())
+            .map(|(recipient, _)| *recipient)
+            .collect_vec();
+        if !unknown_recipients.is_empty() {
+            let owner_public_keys = self
+                .gateway_client()?
+                .fetch_accounts_owner_public_keys(unknown_recipients)
+                .await?;
+            public_keys_per_recipient.extend(owner_public_keys);
+        }
+
+        let mut public_keys = Vec::new();
+        for (recipient, recipient_public_keys) in public_keys_per_recipient {
+            if recipient_public_keys.is_empty() {
+                return Err(
+                    CommonError::EncryptedMessageRecipientPublicKeysUnknown {
+                        address: recipient.to_string(),
+                    },
+                );
+            }
+            public_keys.extend(recipient_public_keys);
+        }
+        Ok(public_keys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = SargonOS;
+
+    fn public_key_of(account: &Account) -> PublicKey {
+        account
+            .security_state
+            .as_unsecured()
+            .unwrap()
+            .transaction_signing
+            .public_key()
+    }
+
+    #[actix_rt::test]
+    async fn decrypt_message_for_account() {
+        let os = SUT::fast_boot().await;
+        let account = os
+            .create_and_save_new_unnamed_mainnet_account_with_bdfs()
+            .await
+            .unwrap();
+
+        let message = EncryptedMessage::encrypting(
+            PlaintextMessage::sample(),
+            [public_key_of(&account), PublicKey::sample_secp256k1()],
+        )
+        .unwrap();
+
+        let decrypted = os
+            .decrypt_transaction_message(message, account.address)
+            .await
+            .unwrap();
+
+        assert_eq!(decrypted, PlaintextMessage::sample());
+    }
+
+    #[actix_rt::test]
+    async fn decrypt_message_not_for_account_fails() {
+        let os = SUT::fast_boot().await;
+        let account = os
+            .create_and_save_new_unnamed_mainnet_account_with_bdfs()
+            .await
+            .unwrap();
+
+        let message = EncryptedMessage::encrypting(
+            PlaintextMessage::sample(),
+            [PublicKey::sample_ed25519_bob()],
+        )
+        .unwrap();
+
+        let result = os
+            .decrypt_transaction_message(message, account.address)
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommonError::EncryptedMessageNotForAccount {
+                address: account.address.to_string()
+            })
+        );
+    }
+
+    #[actix_rt::test]
+    async fn decrypt_message_unknown_account_fails() {
+        let os = SUT::fast_boot().await;
+
+        let result = os
+            .decrypt_transaction_message(
+                EncryptedMessage::sample(),
+                AccountAddress::sample_other(),
+            )
+            .await;
+
+        assert!(result.is_err());
+    }
+
+    fn owner_public_keys_response(
+        account_address: AccountAddress,
+        public_key: PublicKey,
+    ) -> MockNetworkingDriverResponse {
+        MockNetworkingDriverResponse::new_success(serde_json::json!({
+            "ledger_state": LedgerState::sample(),
+            "items": [
+                {
+                    "address": account_address,
+                    "metadata": {
+                        "items": [
+                            {
+                                "key": "owner_keys",
+                                "value": {
+                                    "typed": {
+                                        "type": "PublicKeyArray",
+                                        "values": [
+                                            {
+                                                "key_type": "EddsaEd25519",
+                                                "key_hex": public_key.to_hex()
+                                            }
+                                        ]
+                                    }
+                                }
+                            }
+                        ]
+                    }
+                }
+            ]
+        }))
+    }
+
+    #[actix_rt::test]
+    async fn encrypt_message_for_own_and_other_account() {
+        let other_private_key =
+            PrivateKey::from(Ed25519PrivateKey::sample_alice());
+        let other_account = AccountAddress::sample_mainnet_other();
+        let os = SUT::boot_test_with_networking_driver(Arc::new(
+            MockNetworkingDriver::new_with_responses(vec![
+                owner_public_keys_response(
+                    other_account,
+                    other_private_key.public_key(),
+                ),
+            ]),
+        ))
+        .await
+        .unwrap();
+        let account = os
+            .create_and_save_new_unnamed_mainnet_account_with_bdfs()
+            .await
+            .unwrap();
+
+        let intent = os
+            .set_encrypted_transaction_message(
+                TransactionIntent::sample(),
+                PlaintextMessage::sample(),
+                vec![account.address, other_account],
+            )
+            .await
+            .unwrap();
+
+        let Message::Encrypted { encrypted } = intent.message.clone() else {
+            panic!("Expected an encrypted message");
+        };
+        assert_eq!(intent.manifest, TransactionIntent::sample().manifest);
+        assert_eq!(
+            os.decrypt_transaction_message(encrypted.clone(), account.address)
+                .await
+                .unwrap(),
+            PlaintextMessage::sample()
+        );
+        assert_eq!(
+            encrypted.decrypt(&other_private_key).unwrap(),
+            PlaintextMessage::sample()
+        );
+    }
+
+    #[actix_rt::test]
+    async fn encrypt_message_for_account_without_public_keys_fails() {
+        let other_account = AccountAddress::sample_mainnet_other();
+        let os = SUT::boot_test_with_networking_driver(Arc::new(
+            MockNetworkingDriver::new_with_responses(vec![
+                MockNetworkingDriverResponse::new_success(
+                    StateEntityDetailsResponse::new(
+                        LedgerState::sample(),
+                        vec![],
+                    ),
+                ),
+            ]),
+        ))
+        .await
+        .unwrap();
+
+        let result = os
+            .set_encrypted_transaction_message(
+                TransactionIntent::sample(),
+                PlaintextMessage::sample(),
+                vec![other_account],
+            )
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommonError::EncryptedMessageRecipientPublicKeysUnknown {
+                address: other_account.to_string()
+            })
+        );
+    }
+}
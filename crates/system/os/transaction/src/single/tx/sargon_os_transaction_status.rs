@@ -1,5 +1,4 @@
 use crate::prelude::*;
-use std::time::Duration;
 
 #[async_trait::async_trait]
 pub trait OsTXStatusPolling {
@@ -11,6 +10,7 @@ pub trait OsTXStatusPolling {
     async fn poll_transaction_status_with_delays(
         &self,
         intent_hash: TransactionIntentHash,
+        end_epoch_exclusive: Option<Epoch>,
     ) -> Result<(TransactionStatus, Vec<u64>)>;
 }
 
@@ -20,13 +20,45 @@ pub trait OsTXStatusPolling {
 #[async_trait::async_trait]
 impl OsTXStatusPolling for SargonOS {
     /// Polls the state of a Transaction until we can determine its `TransactionStatus`.
+    ///
+    /// If the transaction is tracked as pending, polling stops once its
+    /// `end_epoch_exclusive` has been reached, and it is no longer tracked
+    /// once its status has been determined.
     async fn poll_transaction_status(
         &self,
         intent_hash: TransactionIntentHash,
     ) -> Result<TransactionStatus> {
-        self.poll_transaction_status_with_delays(intent_hash)
-            .await
-            .map(|(status, _)| status)
+        let end_epoch_exclusive =
+            self.pending_transactions().await?.into_iter().find_map(
+                |pending| match pending {
+                    PendingTransaction::Transaction {
+                        intent_hash: pending_intent_hash,
+                        end_epoch_exclusive,
+                    } if pending_intent_hash == intent_hash => {
+                        Some(end_epoch_exclusive)
+                    }
+                    _ => None,
+                },
+            );
+
+        let (status, _) = self
+            .poll_transaction_status_with_delays(
+                intent_hash.clone(),
+                end_epoch_exclusive,
+            )
+            .await?;
+
+        if end_epoch_exclusive.is_some() {
+            self.resolve_pending_transaction(
+                PendingTransactionOutcome::Transaction {
+                    intent_hash,
+                    status: status.clone(),
+                },
+            )
+            .await?;
+        }
+
+        Ok(status)
     }
 
     // ==================
@@ -36,71 +68,95 @@ impl OsTXStatusPolling for SargonOS {
     ///
     /// This is the internal implementation of `poll_transaction_status`, which is the public API.
     /// It returns the `TransactionStatus`, but also the list of delays between each poll.
+    ///
+    /// The delays grow exponentially, and we give up with an error once the
+    /// accumulated delay exceeds `POLLING_MAX_TOTAL_DELAY_IN_SECONDS`.
     async fn poll_transaction_status_with_delays(
         &self,
         intent_hash: TransactionIntentHash,
+        end_epoch_exclusive: Option<Epoch>,
     ) -> Result<(TransactionStatus, Vec<u64>)> {
         let gateway_client = self.gateway_client()?;
-        let mut delays: Vec<u64> = vec![];
-        let mut delay_duration = POLLING_DELAY_INCREMENT_IN_SECONDS;
+        let mut backoff = PollingBackoff::default();
 
         loop {
-            // Increase delay by 1 second on subsequent calls
-            delay_duration += POLLING_DELAY_INCREMENT_IN_SECONDS;
-
-            #[cfg(test)]
-            let sleep_duration = Duration::from_millis(delay_duration); // make it faster for tests
-            #[cfg(not(test))]
-            let sleep_duration = Duration::from_secs(delay_duration);
-
-            let response = match gateway_client
-                .get_transaction_status(intent_hash.clone())
-                .await
-            {
-                Ok(response) => response,
-                Err(_) => {
-                    delays.push(delay_duration);
-                    async_std::task::sleep(sleep_duration).await;
-                    continue;
-                }
-            };
-
-            match response
-                .known_payloads
-                .first()
-                .and_then(|payload| payload.payload_status.clone())
+            if let Some(status) = fetch_final_transaction_status(
+                &gateway_client,
+                &intent_hash,
+                end_epoch_exclusive,
+            )
+            .await
             {
-                Some(status) => {
-                    match status {
-                        TransactionStatusResponsePayloadStatus::Unknown |
-                        TransactionStatusResponsePayloadStatus::Pending |
-                        TransactionStatusResponsePayloadStatus::CommitPendingOutcomeUnknown => {
-                            delays.push(delay_duration);
-                            async_std::task::sleep(sleep_duration).await;
-                        }
-                        TransactionStatusResponsePayloadStatus::CommittedSuccess => {
-                            return Ok((TransactionStatus::Success, delays));
-                        }
-                        TransactionStatusResponsePayloadStatus::CommittedFailure => {
-                            return Ok((TransactionStatus::Failed { reason: TransactionStatusReason::from_raw_error(response.error_message) }, delays));
-                        }
-                        TransactionStatusResponsePayloadStatus::PermanentlyRejected => {
-                            return Ok((TransactionStatus::PermanentlyRejected { reason: TransactionStatusReason::from_raw_error(response.error_message) }, delays));
-                        }
-                        TransactionStatusResponsePayloadStatus::TemporarilyRejected => {
-                            return Ok((TransactionStatus::TemporarilyRejected { current_epoch: Epoch::from(response.ledger_state.epoch) }, delays));
-                        }
-                    }
-                }
-                None => {
-                    delays.push(delay_duration);
-                    async_std::task::sleep(sleep_duration).await;
-                }
+                return Ok((status, backoff.delays()));
+            }
+
+            if !backoff.wait().await {
+                return Err(CommonError::TransactionPollingTimedOut {
+                    hash: intent_hash.to_string(),
+                });
             }
         }
     }
 }
 
+/// Fetches the status of the transaction once, returning `None` if it is not
+/// yet known or final, or if we failed to fetch it.
+///
+/// A transaction which has not been committed once the network has reached
+/// its `end_epoch_exclusive` never will be, so we consider it permanently
+/// rejected.
+pub(crate) async fn fetch_final_transaction_status(
+    gateway_client: &GatewayClient,
+    intent_hash: &TransactionIntentHash,
+    end_epoch_exclusive: Option<Epoch>,
+) -> Option<TransactionStatus> {
+    let response = gateway_client
+        .get_transaction_status(intent_hash.clone())
+        .await
+        .ok()?;
+
+    let status = response
+        .known_payloads
+        .first()
+        .and_then(|payload| payload.payload_status.clone())
+        .and_then(|status| match status {
+            TransactionStatusResponsePayloadStatus::Unknown
+            | TransactionStatusResponsePayloadStatus::Pending
+            | TransactionStatusResponsePayloadStatus::CommitPendingOutcomeUnknown => None,
+            TransactionStatusResponsePayloadStatus::CommittedSuccess => {
+                Some(TransactionStatus::Success)
+            }
+            TransactionStatusResponsePayloadStatus::CommittedFailure => {
+                Some(TransactionStatus::Failed {
+                    reason: TransactionStatusReason::from_raw_error(
+                        response.error_message.clone(),
+                    ),
+                })
+            }
+            TransactionStatusResponsePayloadStatus::PermanentlyRejected => {
+                Some(TransactionStatus::PermanentlyRejected {
+                    reason: TransactionStatusReason::from_raw_error(
+                        response.error_message.clone(),
+                    ),
+                })
+            }
+            TransactionStatusResponsePayloadStatus::TemporarilyRejected => {
+                Some(TransactionStatus::TemporarilyRejected {
+                    current_epoch: Epoch::from(response.ledger_state.epoch),
+                })
+            }
+        });
+
+    status.or_else(|| {
+        let current_epoch = Epoch::from(response.ledger_state.epoch);
+        end_epoch_exclusive
+            .filter(|end_epoch| current_epoch >= *end_epoch)
+            .map(|_| TransactionStatus::PermanentlyRejected {
+                reason: TransactionStatusReason::Unknown,
+            })
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -140,8 +196,8 @@ mod tests {
                 reason: TransactionStatusReason::Unknown
             }
         );
-        // and there should have been a delay of 2s after first call, and 3s after the second call
-        assert_eq!(result.1, vec![2, 3]);
+        // and there should have been a delay of 2s after first call, and 4s after the second call
+        assert_eq!(result.1, vec![2, 4]);
     }
 
     #[actix_rt::test]
@@ -209,9 +265,10 @@ mod tests {
                 .unwrap()
                 .unwrap();
 
-        let result =
-            os.poll_transaction_status_with_delays(
+        let result = os
+            .poll_transaction_status_with_delays(
                 TransactionIntentHash::sample(),
+                None,
             )
             .await
             .unwrap();
@@ -222,11 +279,95 @@ mod tests {
         assert_eq!(result.1, vec![2]);
     }
 
+    #[actix_rt::test]
+    async fn poll_status_pending_past_end_epoch_is_permanently_rejected() {
+        // This test will simulate the case where the transaction is still pending
+        // although the network has reached the end epoch of the transaction.
+        let current_epoch = Epoch::from(LedgerState::sample_stokenet().epoch);
+        let result = simulate_poll_status_until(
+            vec![TSR::sample_pending()],
+            Some(current_epoch),
+        )
+        .await
+        .unwrap();
+
+        // Result should be `PermanentlyRejected`
+        assert_eq!(
+            result.0,
+            TransactionStatus::PermanentlyRejected {
+                reason: TransactionStatusReason::Unknown
+            }
+        );
+        // and there shouldn't be any delays
+        assert!(result.1.is_empty());
+    }
+
+    #[actix_rt::test]
+    async fn poll_status_gives_up_after_max_total_delay() {
+        // This test will simulate the case where gateway keeps failing,
+        // which we should give up on eventually.
+        let mock_driver = MockNetworkingDriver::new_always_failing();
+
+        let req = SUT::boot_test_with_networking_driver(Arc::new(mock_driver));
+
+        let os =
+            actix_rt::time::timeout(SARGON_OS_TEST_MAX_ASYNC_DURATION, req)
+                .await
+                .unwrap()
+                .unwrap();
+
+        let result = os
+            .poll_transaction_status_with_delays(
+                TransactionIntentHash::sample(),
+                None,
+            )
+            .await;
+
+        assert_eq!(
+            result,
+            Err(CommonError::TransactionPollingTimedOut {
+                hash: TransactionIntentHash::sample().to_string()
+            })
+        );
+    }
+
+    #[actix_rt::test]
+    async fn poll_status_of_pending_transaction_stops_tracking_it() {
+        let pending = PendingTransaction::sample();
+        let mock_driver = MockNetworkingDriver::with_responses(vec![
+            TSR::sample_committed_success(),
+        ]);
+
+        let req = SUT::boot_test_with_networking_driver(Arc::new(mock_driver));
+
+        let os =
+            actix_rt::time::timeout(SARGON_OS_TEST_MAX_ASYNC_DURATION, req)
+                .await
+                .unwrap()
+                .unwrap();
+        os.track_pending_transaction(pending.clone()).await.unwrap();
+
+        let status = os
+            .poll_transaction_status(TransactionIntentHash::sample())
+            .await
+            .unwrap();
+
+        assert_eq!(status, TransactionStatus::Success);
+        assert!(os.pending_transactions().await.unwrap().is_empty());
+    }
+
     // Creates a `MockNetworkingDriver` that returns the given list of responses sequentially,
     // and then call `poll_transaction_status` to get the result.
     async fn simulate_poll_status(
         responses: Vec<TransactionStatusResponse>,
     ) -> (TransactionStatus, Vec<u64>) {
+        simulate_poll_status_until(responses, None).await.unwrap()
+    }
+
+    async fn simulate_poll_status_until(
+        responses: Vec<TransactionStatusResponse>,
+        end_epoch_exclusive: Option<Epoch>,
+    ) -> Result<(TransactionStatus, Vec<u64>)> {
         let mock_driver = MockNetworkingDriver::with_responses(responses);
 
         let req = SUT::boot_test_with_networking_driver(Arc::new(mock_driver));
@@ -237,8 +378,10 @@ mod tests {
                 .unwrap()
                 .unwrap();
 
-        os.poll_transaction_status_with_delays(TransactionIntentHash::sample())
-            .await
-            .unwrap()
+        os.poll_transaction_status_with_delays(
+            TransactionIntentHash::sample(),
+            end_epoch_exclusive,
+        )
+        .await
     }
 }
@@ -13,16 +13,33 @@ pub trait OsTxSubmitting {
 // ==================
 #[async_trait::async_trait]
 impl OsTxSubmitting for SargonOS {
-    /// Submits a notarized transaction payload to the network.
+    /// Submits a notarized transaction payload to the network, and tracks it
+    /// as a `PendingTransaction` until its status has been polled.
+    ///
+    /// Failing to track the transaction is only logged, since it has been
+    /// submitted nonetheless.
     async fn submit_transaction(
         &self,
         notarized_transaction: NotarizedTransaction,
     ) -> Result<TransactionIntentHash> {
         let gateway_client = self.gateway_client()?;
+        let pending_transaction =
+            PendingTransaction::from(&notarized_transaction);
 
-        gateway_client
+        let intent_hash = gateway_client
             .submit_notarized_transaction(notarized_transaction)
-            .await
+            .await?;
+
+        if let Err(error) =
+            self.track_pending_transaction(pending_transaction).await
+        {
+            error!(
+                "Failed to track submitted transaction {}: {:?}",
+                intent_hash, error
+            );
+        }
+
+        Ok(intent_hash)
     }
 }
 
@@ -69,6 +86,42 @@ mod tests {
             .transaction_intent_hash();
 
         assert_eq!(result, expected_result);
+        assert_eq!(
+            os.pending_transactions().await.unwrap(),
+            vec![PendingTransaction::from(&notarized_transaction)]
+        );
+    }
+
+    #[actix_rt::test]
+    async fn submit_transaction_success_even_if_tracking_fails() {
+        let notarized_transaction = NotarizedTransaction::sample();
+        let response = TransactionSubmitResponse { duplicate: false };
+        let body = serde_json::to_vec(&response).unwrap();
+        let mock_driver = MockNetworkingDriver::new(200, body);
+
+        let req = SUT::boot_test_with_networking_driver(Arc::new(mock_driver));
+
+        let os =
+            actix_rt::time::timeout(SARGON_OS_TEST_MAX_ASYNC_DURATION, req)
+                .await
+                .unwrap()
+                .unwrap();
+
+        // Corrupt the stored pending transactions, so that tracking fails
+        os.unsafe_storage
+            .save(UnsafeStorageKey::PendingTransactions, &"corrupt")
+            .await
+            .unwrap();
+
+        let result = os.submit_transaction(notarized_transaction.clone()).await;
+
+        assert_eq!(
+            result,
+            Ok(notarized_transaction
+                .signed_intent()
+                .intent()
+                .transaction_intent_hash())
+        );
     }
 
     #[actix_rt::test]
@@ -90,5 +143,6 @@ mod tests {
             .expect_err("Expected an error");
 
         assert_eq!(result, CommonError::NetworkResponseBadCode { code: 500 });
+        assert!(os.pending_transactions().await.unwrap().is_empty());
     }
 }
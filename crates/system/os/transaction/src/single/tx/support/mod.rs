@@ -1,5 +1,9 @@
+mod pending_transaction;
+mod polling_backoff;
 mod signable_manifest_summary;
 mod transaction_to_review;
 
+pub use pending_transaction::*;
+pub(crate) use polling_backoff::*;
 pub use signable_manifest_summary::*;
 pub use transaction_to_review::*;
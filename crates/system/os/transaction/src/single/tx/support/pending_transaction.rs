@@ -0,0 +1,224 @@
+use crate::prelude::*;
+
+/// A transaction, or a Pre-Authorization, which has been submitted to the
+/// network but whose outcome the wallet has not yet learned. Persisted so
+/// that the wallet can resume polling after an app restart.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PendingTransaction {
+    /// A transaction submitted by the wallet, which can never be committed
+    /// once the network has reached `end_epoch_exclusive`.
+    #[serde(rename_all = "camelCase")]
+    Transaction {
+        intent_hash: TransactionIntentHash,
+        end_epoch_exclusive: Epoch,
+    },
+
+    /// A Pre-Authorization sent to a dApp, which can never be committed after
+    /// `expiration_timestamp`.
+    #[serde(rename_all = "camelCase")]
+    PreAuthorization {
+        subintent_hash: SubintentHash,
+        expiration_timestamp: Timestamp,
+    },
+}
+
+impl PendingTransaction {
+    pub fn transaction(
+        intent_hash: TransactionIntentHash,
+        end_epoch_exclusive: Epoch,
+    ) -> Self {
+        Self::Transaction {
+            intent_hash,
+            end_epoch_exclusive,
+        }
+    }
+
+    pub fn pre_authorization(
+        subintent_hash: SubintentHash,
+        expiration_timestamp: Timestamp,
+    ) -> Self {
+        Self::PreAuthorization {
+            subintent_hash,
+            expiration_timestamp,
+        }
+    }
+
+    /// The Bech32 encoded hash identifying this pending transaction.
+    pub fn hash(&self) -> String {
+        match self {
+            Self::Transaction { intent_hash, .. } => intent_hash.to_string(),
+            Self::PreAuthorization { subintent_hash, .. } => {
+                subintent_hash.to_string()
+            }
+        }
+    }
+}
+
+impl From<&NotarizedTransaction> for PendingTransaction {
+    fn from(value: &NotarizedTransaction) -> Self {
+        let intent = value.signed_intent().intent();
+        Self::transaction(
+            intent.transaction_intent_hash(),
+            intent.header.end_epoch_exclusive,
+        )
+    }
+}
+
+impl HasSampleValues for PendingTransaction {
+    fn sample() -> Self {
+        Self::transaction(TransactionIntentHash::sample(), Epoch::sample())
+    }
+
+    fn sample_other() -> Self {
+        Self::pre_authorization(SubintentHash::sample(), Timestamp::sample())
+    }
+}
+
+/// The final outcome of a `PendingTransaction`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PendingTransactionOutcome {
+    Transaction {
+        intent_hash: TransactionIntentHash,
+        status: TransactionStatus,
+    },
+    PreAuthorization {
+        subintent_hash: SubintentHash,
+        status: PreAuthorizationStatus,
+    },
+}
+
+impl PendingTransactionOutcome {
+    /// The Bech32 encoded hash of the resolved `PendingTransaction`.
+    pub fn hash(&self) -> String {
+        match self {
+            Self::Transaction { intent_hash, .. } => intent_hash.to_string(),
+            Self::PreAuthorization { subintent_hash, .. } => {
+                subintent_hash.to_string()
+            }
+        }
+    }
+
+    /// The event to emit on the event bus for this outcome.
+    pub fn event(&self) -> Event {
+        match self {
+            Self::Transaction {
+                intent_hash,
+                status: TransactionStatus::Success,
+            } => Event::TransactionCommitted {
+                intent_hash: intent_hash.clone(),
+            },
+            Self::Transaction { intent_hash, .. } => Event::TransactionFailed {
+                intent_hash: intent_hash.clone(),
+            },
+            Self::PreAuthorization {
+                status: PreAuthorizationStatus::Success { intent_hash },
+                ..
+            } => Event::TransactionCommitted {
+                intent_hash: intent_hash.clone(),
+            },
+            Self::PreAuthorization {
+                subintent_hash,
+                status: PreAuthorizationStatus::Expired,
+            } => Event::PreAuthorizationExpired {
+                subintent_hash: subintent_hash.clone(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = PendingTransaction;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn hash() {
+        assert_eq!(
+            SUT::sample().hash(),
+            TransactionIntentHash::sample().to_string()
+        );
+        assert_eq!(
+            SUT::sample_other().hash(),
+            SubintentHash::sample().to_string()
+        );
+    }
+
+    #[test]
+    fn json_roundtrip() {
+        let sut = vec![SUT::sample(), SUT::sample_other()];
+        let json = serde_json::to_vec(&sut).unwrap();
+        let decoded = serde_json::from_slice::<Vec<SUT>>(&json).unwrap();
+        assert_eq!(decoded, sut);
+    }
+
+    #[test]
+    fn from_notarized_transaction() {
+        let notarized = NotarizedTransaction::sample();
+        let intent = notarized.signed_intent().intent();
+        assert_eq!(
+            SUT::from(&notarized),
+            SUT::transaction(
+                intent.transaction_intent_hash(),
+                intent.header.end_epoch_exclusive
+            )
+        );
+    }
+
+    #[test]
+    fn outcome_events() {
+        let intent_hash = TransactionIntentHash::sample();
+        let subintent_hash = SubintentHash::sample();
+        assert_eq!(
+            PendingTransactionOutcome::Transaction {
+                intent_hash: intent_hash.clone(),
+                status: TransactionStatus::Success,
+            }
+            .event(),
+            Event::TransactionCommitted {
+                intent_hash: intent_hash.clone()
+            }
+        );
+        assert_eq!(
+            PendingTransactionOutcome::Transaction {
+                intent_hash: intent_hash.clone(),
+                status: TransactionStatus::sample_other(),
+            }
+            .event(),
+            Event::TransactionFailed {
+                intent_hash: intent_hash.clone()
+            }
+        );
+        assert_eq!(
+            PendingTransactionOutcome::PreAuthorization {
+                subintent_hash: subintent_hash.clone(),
+                status: PreAuthorizationStatus::Success {
+                    intent_hash: intent_hash.clone()
+                },
+            }
+            .event(),
+            Event::TransactionCommitted { intent_hash }
+        );
+        assert_eq!(
+            PendingTransactionOutcome::PreAuthorization {
+                subintent_hash: subintent_hash.clone(),
+                status: PreAuthorizationStatus::Expired,
+            }
+            .event(),
+            Event::PreAuthorizationExpired { subintent_hash }
+        );
+    }
+}
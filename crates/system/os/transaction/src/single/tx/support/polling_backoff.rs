@@ -0,0 +1,116 @@
+use crate::prelude::*;
+use std::time::Duration;
+
+/// Bounded exponential backoff used between polling requests of the status
+/// of transactions. The delay is doubled after each poll, up to `max_delay`,
+/// and we give up once the accumulated delay has reached `max_total_delay`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct PollingBackoff {
+    next_delay: u64,
+    max_delay: u64,
+    max_total_delay: u64,
+    delays: Vec<u64>,
+}
+
+impl Default for PollingBackoff {
+    fn default() -> Self {
+        Self::new(
+            POLLING_INITIAL_BACKOFF_DELAY_IN_SECONDS,
+            POLLING_MAX_DELAY_IN_SECONDS,
+            POLLING_MAX_TOTAL_DELAY_IN_SECONDS,
+        )
+    }
+}
+
+impl PollingBackoff {
+    pub(crate) fn new(
+        initial_delay: u64,
+        max_delay: u64,
+        max_total_delay: u64,
+    ) -> Self {
+        Self {
+            next_delay: initial_delay,
+            max_delay,
+            max_total_delay,
+            delays: Vec::new(),
+        }
+    }
+
+    /// The delays (in seconds) waited so far.
+    pub(crate) fn delays(&self) -> Vec<u64> {
+        self.delays.clone()
+    }
+
+    fn accumulated_delay(&self) -> u64 {
+        self.delays.iter().sum()
+    }
+
+    /// Returns the next delay, or `None` if we have waited long enough and
+    /// should give up.
+    pub(crate) fn next_delay(&mut self) -> Option<u64> {
+        let accumulated_delay = self.accumulated_delay();
+        if accumulated_delay >= self.max_total_delay {
+            return None;
+        }
+        let delay = self
+            .next_delay
+            .min(self.max_delay)
+            .min(self.max_total_delay - accumulated_delay);
+        self.next_delay = self.next_delay.saturating_mul(2);
+        self.delays.push(delay);
+        Some(delay)
+    }
+
+    /// Sleeps for the next delay, returns `false` if we should give up
+    /// polling instead.
+    pub(crate) async fn wait(&mut self) -> bool {
+        let Some(delay) = self.next_delay() else {
+            return false;
+        };
+        Self::sleep(delay).await;
+        true
+    }
+
+    /// Sleeps for `seconds`.
+    pub(crate) async fn sleep(seconds: u64) {
+        #[cfg(test)]
+        let sleep_duration = Duration::from_millis(seconds); // make it faster for tests
+        #[cfg(not(test))]
+        let sleep_duration = Duration::from_secs(seconds);
+
+        async_std::task::sleep(sleep_duration).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = PollingBackoff;
+
+    #[test]
+    fn delays_are_doubled_up_to_max_delay() {
+        let mut sut = SUT::new(2, 10, 100);
+        let delays = (0..6).filter_map(|_| sut.next_delay()).collect_vec();
+        assert_eq!(delays, vec![2, 4, 8, 10, 10, 10]);
+        assert_eq!(sut.delays(), delays);
+    }
+
+    #[test]
+    fn gives_up_after_max_total_delay() {
+        let mut sut = SUT::new(2, 10, 15);
+        let delays = (0..6).filter_map(|_| sut.next_delay()).collect_vec();
+        // the last delay is cut short to not exceed the max total delay
+        assert_eq!(delays, vec![2, 4, 8, 1]);
+    }
+
+    #[actix_rt::test]
+    async fn wait_returns_false_when_giving_up() {
+        let mut sut = SUT::new(1, 1, 2);
+        assert!(sut.wait().await);
+        assert!(sut.wait().await);
+        assert!(!sut.wait().await);
+        assert_eq!(sut.delays(), vec![1, 1]);
+    }
+}
@@ -71,6 +71,14 @@ impl ProfileStateHolder {
         })
     }
 
+    pub fn watch_only_accounts_on_current_network(
+        &self,
+    ) -> Result<WatchOnlyAccounts> {
+        self.try_access_profile_with(|p| {
+            p.current_network().map(|n| n.watch_only_accounts.clone())
+        })
+    }
+
     pub fn address_book_entry_by_address(
         &self,
         address: Address,
@@ -1,9 +1,15 @@
 mod account_locker;
 mod assets_transfers;
+mod payment_request;
 mod stake_claim;
+mod stake_to_validator;
 mod transaction_guarantee;
+mod unstake_from_validator;
 
 pub use account_locker::*;
 pub use assets_transfers::*;
+pub use payment_request::*;
 pub use stake_claim::*;
+pub use stake_to_validator::*;
 pub use transaction_guarantee::*;
+pub use unstake_from_validator::*;
@@ -0,0 +1,7 @@
+mod payment_request;
+mod payment_request_asset;
+mod payment_request_recipient;
+
+pub use payment_request::*;
+pub use payment_request_asset::*;
+pub use payment_request_recipient::*;
@@ -0,0 +1,583 @@
+use crate::prelude::*;
+
+const PAYMENT_REQUEST_SCHEME: &str = "radix";
+const PAYMENT_REQUEST_PARAM_RESOURCE: &str = "resource";
+const PAYMENT_REQUEST_PARAM_AMOUNT: &str = "amount";
+const PAYMENT_REQUEST_PARAM_NON_FUNGIBLE_LOCAL_ID: &str = "nft";
+const PAYMENT_REQUEST_PARAM_MESSAGE: &str = "message";
+const PAYMENT_REQUEST_PARAM_EXPIRY: &str = "expiry";
+
+/// A request to pay an asset to a recipient, typically shared by a merchant
+/// as a "scan to pay" QR code, encoded as a `radix:` URI:
+///
+/// ```text
+/// radix:<recipient>?resource=<address>&amount=<decimal>&message=<text>&expiry=<unix seconds>
+/// radix:<recipient>?resource=<address>&nft=<local id>&nft=<local id>
+/// ```
+///
+/// Where `<recipient>` is an account address or an RNS domain. Exactly one
+/// of `amount` or (one or many) `nft` must be present, `message` and
+/// `expiry` are optional. Unknown query parameters are ignored, allowing the
+/// format to be extended in a backwards compatible way.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PaymentRequest {
+    pub recipient: PaymentRequestRecipient,
+    pub asset: PaymentRequestAsset,
+
+    /// A message from the requester, e.g. an order reference, which the
+    /// wallet should attach to the transaction.
+    pub message: Option<String>,
+
+    /// After this point in time the request should no longer be paid.
+    pub expiry: Option<Instant>,
+}
+
+impl PaymentRequest {
+    pub fn new(
+        recipient: impl Into<PaymentRequestRecipient>,
+        asset: PaymentRequestAsset,
+        message: impl Into<Option<String>>,
+        expiry: impl Into<Option<Instant>>,
+    ) -> Self {
+        Self {
+            recipient: recipient.into(),
+            asset,
+            message: message.into(),
+            expiry: expiry.into(),
+        }
+    }
+}
+
+impl PaymentRequest {
+    /// Parses a `radix:` payment request URI, validating that all addresses
+    /// in it are on `network_id`.
+    pub fn parse(uri: impl AsRef<str>, network_id: NetworkID) -> Result<Self> {
+        let uri = uri.as_ref().trim();
+        let invalid = |reason: &str| CommonError::InvalidPaymentRequest {
+            reason: reason.to_owned(),
+        };
+
+        let url = parse_url(uri)?;
+        if url.scheme() != PAYMENT_REQUEST_SCHEME {
+            return Err(invalid("Expected 'radix' scheme"));
+        }
+
+        let recipient = PaymentRequestRecipient::parse(url.path(), network_id)?;
+
+        let mut params = HashMap::<String, Vec<String>>::new();
+        for (key, value) in url.query_pairs().into_owned() {
+            params.entry(key).or_default().push(value);
+        }
+        let single = |key: &str| -> Result<Option<String>> {
+            match params.get(key).map(|values| values.as_slice()) {
+                None => Ok(None),
+                Some([value]) => Ok(Some(value.clone())),
+                Some(_) => Err(CommonError::InvalidPaymentRequest {
+                    reason: format!("Duplicate parameter '{}'", key),
+                }),
+            }
+        };
+
+        let resource_address = single(PAYMENT_REQUEST_PARAM_RESOURCE)?
+            .ok_or_else(|| invalid("Missing parameter 'resource'"))
+            .and_then(|s| ResourceAddress::from_str(&s))?;
+        if resource_address.network_id() != network_id {
+            return Err(CommonError::NetworkDiscrepancy {
+                expected: network_id.to_string(),
+                actual: resource_address.network_id().to_string(),
+            });
+        }
+
+        let amount = single(PAYMENT_REQUEST_PARAM_AMOUNT)?;
+        let local_ids = params
+            .get(PAYMENT_REQUEST_PARAM_NON_FUNGIBLE_LOCAL_ID)
+            .cloned()
+            .unwrap_or_default();
+        let asset = match (amount, local_ids.is_empty()) {
+            (Some(amount), true) => PaymentRequestAsset::fungible(
+                resource_address,
+                Decimal192::from_str(&amount)?,
+            ),
+            (None, false) => PaymentRequestAsset::non_fungible(
+                resource_address,
+                local_ids
+                    .iter()
+                    .map(|s| NonFungibleLocalId::from_str(s))
+                    .collect::<Result<Vec<_>>>()?,
+            ),
+            (Some(_), false) => {
+                Err(invalid("Both 'amount' and 'nft' parameters present"))
+            }
+            (None, true) => Err(invalid("Missing parameter 'amount' or 'nft'")),
+        }?;
+
+        let message = single(PAYMENT_REQUEST_PARAM_MESSAGE)?
+            .filter(|message| !message.is_empty());
+
+        let expiry = single(PAYMENT_REQUEST_PARAM_EXPIRY)?
+            .map(|s| {
+                s.parse::<i64>().map(Instant::from).map_err(|_| {
+                    CommonError::InvalidPaymentRequest {
+                        reason: format!("Invalid expiry '{}'", s),
+                    }
+                })
+            })
+            .transpose()?;
+
+        Ok(Self::new(recipient, asset, message, expiry))
+    }
+
+    /// Encodes this request as a `radix:` URI, e.g. to be shown as a QR code.
+    pub fn to_uri(&self) -> Result<String> {
+        let mut url = parse_url(format!(
+            "{}:{}",
+            PAYMENT_REQUEST_SCHEME, self.recipient
+        ))?;
+
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair(
+                PAYMENT_REQUEST_PARAM_RESOURCE,
+                &self.asset.resource_address().to_string(),
+            );
+            match &self.asset {
+                PaymentRequestAsset::Fungible { amount, .. } => {
+                    query.append_pair(
+                        PAYMENT_REQUEST_PARAM_AMOUNT,
+                        &amount.to_string(),
+                    );
+                }
+                PaymentRequestAsset::NonFungible { local_ids, .. } => {
+                    for local_id in local_ids {
+                        query.append_pair(
+                            PAYMENT_REQUEST_PARAM_NON_FUNGIBLE_LOCAL_ID,
+                            &local_id.to_string(),
+                        );
+                    }
+                }
+            }
+            if let Some(message) = &self.message {
+                query.append_pair(PAYMENT_REQUEST_PARAM_MESSAGE, message);
+            }
+            if let Some(expiry) = self.expiry {
+                query.append_pair(
+                    PAYMENT_REQUEST_PARAM_EXPIRY,
+                    &expiry.seconds_since_unix_epoch.to_string(),
+                );
+            }
+        }
+
+        Ok(url.to_string())
+    }
+
+    /// Whether the request has expired at `now`, requests without expiry
+    /// never expire.
+    pub fn is_expired(&self, now: Instant) -> bool {
+        self.expiry.is_some_and(|expiry| now >= expiry)
+    }
+
+    /// Converts this request into transfers, ready to be passed to
+    /// `TransactionManifest::per_recipient_transfers`, sent from
+    /// `address_of_sender`. Fails if the request has expired at `now`.
+    ///
+    /// If the recipient is an RNS domain, `resolved_rns_receiver` must be the
+    /// receiver the domain was resolved to, using `RadixNameService`.
+    pub fn per_recipient_transfers(
+        &self,
+        address_of_sender: AccountAddress,
+        resolved_rns_receiver: impl Into<Option<RnsDomainConfiguredReceiver>>,
+        now: Instant,
+    ) -> Result<PerRecipientAssetTransfers> {
+        if let Some(expiry) = self.expiry.filter(|_| self.is_expired(now)) {
+            return Err(CommonError::PaymentRequestExpired {
+                expiry: expiry.seconds_since_unix_epoch,
+            });
+        }
+
+        let network_id = self.asset.resource_address().network_id();
+        if address_of_sender.network_id() != network_id {
+            return Err(CommonError::NetworkDiscrepancy {
+                expected: network_id.to_string(),
+                actual: address_of_sender.network_id().to_string(),
+            });
+        }
+
+        let recipient = match &self.recipient {
+            PaymentRequestRecipient::Account { value } => {
+                TransferRecipient::AddressOfExternalAccount { value: *value }
+            }
+            PaymentRequestRecipient::RnsDomain { value } => {
+                let receiver =
+                    resolved_rns_receiver.into().ok_or_else(|| {
+                        CommonError::InvalidPaymentRequest {
+                            reason: format!(
+                                "RNS domain '{}' has not been resolved",
+                                value.0
+                            ),
+                        }
+                    })?;
+                if &receiver.domain.domain != value {
+                    return Err(CommonError::PaymentRequestRnsDomainMismatch {
+                        expected: value.0.clone(),
+                        found: receiver.domain.domain.0,
+                    });
+                }
+                if receiver.receiver.network_id() != network_id {
+                    return Err(CommonError::NetworkDiscrepancy {
+                        expected: network_id.to_string(),
+                        actual: receiver.receiver.network_id().to_string(),
+                    });
+                }
+                TransferRecipient::RnsDomain { value: receiver }
+            }
+        };
+
+        let transfer = match &self.asset {
+            PaymentRequestAsset::Fungible {
+                resource_address,
+                amount,
+            } => PerRecipientAssetTransfer::new(
+                recipient,
+                [PerRecipientFungibleTransfer::new(
+                    *resource_address,
+                    *amount,
+                    true,
+                    None,
+                )],
+                [],
+            ),
+            PaymentRequestAsset::NonFungible {
+                resource_address,
+                local_ids,
+            } => PerRecipientAssetTransfer::new(
+                recipient,
+                [],
+                [PerRecipientNonFungibleTransfer::new(
+                    *resource_address,
+                    true,
+                    local_ids.clone(),
+                )],
+            ),
+        };
+
+        Ok(PerRecipientAssetTransfers::new(
+            address_of_sender,
+            [transfer],
+        ))
+    }
+}
+
+impl HasSampleValues for PaymentRequest {
+    fn sample() -> Self {
+        Self::new(
+            PaymentRequestRecipient::sample(),
+            PaymentRequestAsset::sample(),
+            "Order #1337".to_owned(),
+            Instant::sample(),
+        )
+    }
+
+    fn sample_other() -> Self {
+        Self::new(
+            PaymentRequestRecipient::sample_other(),
+            PaymentRequestAsset::sample_other(),
+            None,
+            None,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = PaymentRequest;
+
+    /// A point in time before the expiry of `SUT::sample()`.
+    fn before_expiry() -> Instant {
+        Instant::from(Instant::sample().seconds_since_unix_epoch - 1)
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn to_uri() {
+        assert_eq!(
+            SUT::sample().to_uri().unwrap(),
+            "radix:account_rdx128y6j78mt0aqv6372evz28hrxp8mn06ccddkr7xppc88hyvynvjdwr?resource=resource_rdx1tknxxxxxxxxxradxrdxxxxxxxxx009923554798xxxxxxxxxradxrd&amount=237.13372718281828&message=Order+%231337&expiry=1694448356"
+        );
+        assert_eq!(
+            SUT::sample_other().to_uri().unwrap(),
+            "radix:sample1.xrd?resource=resource_rdx1nfyg2f68jw7hfdlg5hzvd8ylsa7e0kjl68t5t62v3ttamtejc9wlxa&nft=%231%23&nft=%232%23"
+        );
+    }
+
+    #[test]
+    fn uri_roundtrip() {
+        let roundtrip = |sut: SUT| {
+            assert_eq!(
+                SUT::parse(sut.to_uri().unwrap(), NetworkID::Mainnet).unwrap(),
+                sut
+            )
+        };
+        roundtrip(SUT::sample());
+        roundtrip(SUT::sample_other());
+    }
+
+    #[test]
+    fn parse_ignores_unknown_parameters() {
+        let uri =
+            format!("{}&label=Coffee", SUT::sample_other().to_uri().unwrap());
+        assert_eq!(
+            SUT::parse(uri, NetworkID::Mainnet).unwrap(),
+            SUT::sample_other()
+        );
+    }
+
+    #[test]
+    fn parse_wrong_scheme() {
+        assert!(matches!(
+            SUT::parse(
+                SUT::sample()
+                    .to_uri()
+                    .unwrap()
+                    .replace("radix:", "bitcoin:"),
+                NetworkID::Mainnet
+            ),
+            Err(CommonError::InvalidPaymentRequest { .. })
+        ));
+    }
+
+    #[test]
+    fn parse_not_a_uri() {
+        assert!(matches!(
+            SUT::parse(
+                AccountAddress::sample_mainnet().to_string(),
+                NetworkID::Mainnet
+            ),
+            Err(CommonError::InvalidURL { .. })
+        ));
+    }
+
+    #[test]
+    fn parse_recipient_on_wrong_network() {
+        assert_eq!(
+            SUT::parse(SUT::sample().to_uri().unwrap(), NetworkID::Stokenet),
+            Err(CommonError::NetworkDiscrepancy {
+                expected: NetworkID::Stokenet.to_string(),
+                actual: NetworkID::Mainnet.to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_resource_on_wrong_network() {
+        let uri = format!(
+            "radix:sample1.xrd?resource={}&amount=1",
+            ResourceAddress::sample_stokenet_xrd()
+        );
+        assert_eq!(
+            SUT::parse(uri, NetworkID::Mainnet),
+            Err(CommonError::NetworkDiscrepancy {
+                expected: NetworkID::Mainnet.to_string(),
+                actual: NetworkID::Stokenet.to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_missing_resource() {
+        assert!(matches!(
+            SUT::parse("radix:sample1.xrd?amount=1", NetworkID::Mainnet),
+            Err(CommonError::InvalidPaymentRequest { .. })
+        ));
+    }
+
+    #[test]
+    fn parse_missing_amount_and_nft() {
+        let uri = format!(
+            "radix:sample1.xrd?resource={}",
+            ResourceAddress::sample_mainnet_xrd()
+        );
+        assert!(matches!(
+            SUT::parse(uri, NetworkID::Mainnet),
+            Err(CommonError::InvalidPaymentRequest { .. })
+        ));
+    }
+
+    #[test]
+    fn parse_both_amount_and_nft() {
+        let uri = format!("{}&amount=1", SUT::sample_other().to_uri().unwrap());
+        assert!(matches!(
+            SUT::parse(uri, NetworkID::Mainnet),
+            Err(CommonError::InvalidPaymentRequest { .. })
+        ));
+    }
+
+    #[test]
+    fn parse_duplicate_amount() {
+        let uri = format!("{}&amount=2", SUT::sample().to_uri().unwrap());
+        assert!(matches!(
+            SUT::parse(uri, NetworkID::Mainnet),
+            Err(CommonError::InvalidPaymentRequest { .. })
+        ));
+    }
+
+    #[test]
+    fn parse_invalid_amount() {
+        let uri = format!(
+            "radix:sample1.xrd?resource={}&amount=lots",
+            ResourceAddress::sample_mainnet_xrd()
+        );
+        assert_eq!(
+            SUT::parse(uri, NetworkID::Mainnet),
+            Err(CommonError::DecimalError)
+        );
+    }
+
+    #[test]
+    fn parse_invalid_expiry() {
+        let uri = format!(
+            "{}&expiry=tomorrow",
+            SUT::sample_other().to_uri().unwrap()
+        );
+        assert!(matches!(
+            SUT::parse(uri, NetworkID::Mainnet),
+            Err(CommonError::InvalidPaymentRequest { .. })
+        ));
+    }
+
+    #[test]
+    fn is_expired() {
+        let sut = SUT::sample();
+        let expiry = Instant::sample().seconds_since_unix_epoch;
+        assert!(!sut.is_expired(Instant::from(expiry - 1)));
+        assert!(sut.is_expired(Instant::from(expiry)));
+        assert!(!SUT::sample_other().is_expired(Instant::from(i64::MAX)));
+    }
+
+    #[test]
+    fn per_recipient_transfers_to_account() {
+        let sender = AccountAddress::sample_mainnet_other();
+        assert_eq!(
+            SUT::sample()
+                .per_recipient_transfers(sender, None, before_expiry())
+                .unwrap(),
+            PerRecipientAssetTransfers::new(
+                sender,
+                [PerRecipientAssetTransfer::new(
+                    TransferRecipient::AddressOfExternalAccount {
+                        value: AccountAddress::sample_mainnet()
+                    },
+                    [PerRecipientFungibleTransfer::new(
+                        ResourceAddress::sample_mainnet_xrd(),
+                        Decimal192::from_str("237.13372718281828").unwrap(),
+                        true,
+                        None,
+                    )],
+                    [],
+                )]
+            )
+        );
+    }
+
+    #[test]
+    fn per_recipient_transfers_to_rns_domain() {
+        let sender = AccountAddress::sample_mainnet_other();
+        let receiver = RnsDomainConfiguredReceiver::sample();
+        assert_eq!(receiver.domain.domain, RnsDomain::sample());
+        assert_eq!(
+            SUT::sample_other()
+                .per_recipient_transfers(
+                    sender,
+                    receiver.clone(),
+                    before_expiry()
+                )
+                .unwrap(),
+            PerRecipientAssetTransfers::new(
+                sender,
+                [PerRecipientAssetTransfer::new(
+                    TransferRecipient::RnsDomain { value: receiver },
+                    [],
+                    [PerRecipientNonFungibleTransfer::new(
+                        ResourceAddress::sample_mainnet_nft_gc_membership(),
+                        true,
+                        [
+                            NonFungibleLocalId::integer(1),
+                            NonFungibleLocalId::integer(2)
+                        ],
+                    )],
+                )]
+            )
+        );
+    }
+
+    #[test]
+    fn per_recipient_transfers_unresolved_rns_domain() {
+        assert!(matches!(
+            SUT::sample_other().per_recipient_transfers(
+                AccountAddress::sample_mainnet_other(),
+                None,
+                before_expiry()
+            ),
+            Err(CommonError::InvalidPaymentRequest { .. })
+        ));
+    }
+
+    #[test]
+    fn per_recipient_transfers_rns_domain_mismatch() {
+        assert_eq!(
+            SUT::sample_other().per_recipient_transfers(
+                AccountAddress::sample_mainnet_other(),
+                RnsDomainConfiguredReceiver::sample_other(),
+                before_expiry()
+            ),
+            Err(CommonError::PaymentRequestRnsDomainMismatch {
+                expected: RnsDomain::sample().0,
+                found: RnsDomainConfiguredReceiver::sample_other()
+                    .domain
+                    .domain
+                    .0,
+            })
+        );
+    }
+
+    #[test]
+    fn per_recipient_transfers_expired() {
+        assert_eq!(
+            SUT::sample().per_recipient_transfers(
+                AccountAddress::sample_mainnet_other(),
+                None,
+                Instant::sample()
+            ),
+            Err(CommonError::PaymentRequestExpired {
+                expiry: Instant::sample().seconds_since_unix_epoch
+            })
+        );
+    }
+
+    #[test]
+    fn per_recipient_transfers_sender_on_wrong_network() {
+        assert_eq!(
+            SUT::sample().per_recipient_transfers(
+                AccountAddress::sample_stokenet(),
+                None,
+                before_expiry()
+            ),
+            Err(CommonError::NetworkDiscrepancy {
+                expected: NetworkID::Mainnet.to_string(),
+                actual: NetworkID::Stokenet.to_string(),
+            })
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use crate::prelude::*;
+
+/// The asset requested by a `PaymentRequest`, either an amount of a fungible
+/// resource or a set of non fungibles of a non fungible resource.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PaymentRequestAsset {
+    Fungible {
+        resource_address: ResourceAddress,
+        amount: Decimal192,
+    },
+    NonFungible {
+        resource_address: ResourceAddress,
+        local_ids: Vec<NonFungibleLocalId>,
+    },
+}
+
+impl PaymentRequestAsset {
+    /// Creates a fungible asset, fails if `resource_address` is not fungible
+    /// or if `amount` is not positive.
+    pub fn fungible(
+        resource_address: ResourceAddress,
+        amount: impl Into<Decimal192>,
+    ) -> Result<Self> {
+        let amount = amount.into();
+        if !resource_address.is_fungible() {
+            return Err(CommonError::InvalidPaymentRequest {
+                reason: format!(
+                    "An amount was requested of non fungible resource {}",
+                    resource_address
+                ),
+            });
+        }
+        if !amount.is_positive() {
+            return Err(CommonError::InvalidPaymentRequest {
+                reason: format!("Amount must be positive, found: {}", amount),
+            });
+        }
+        Ok(Self::Fungible {
+            resource_address,
+            amount,
+        })
+    }
+
+    /// Creates a non fungible asset, fails if `resource_address` is not non
+    /// fungible or if `local_ids` is empty. Duplicate ids are removed.
+    pub fn non_fungible(
+        resource_address: ResourceAddress,
+        local_ids: impl IntoIterator<Item = NonFungibleLocalId>,
+    ) -> Result<Self> {
+        let local_ids = local_ids.into_iter().unique().collect_vec();
+        if !resource_address.is_non_fungible() {
+            return Err(CommonError::InvalidPaymentRequest {
+                reason: format!(
+                    "Non fungible ids were requested of fungible resource {}",
+                    resource_address
+                ),
+            });
+        }
+        if local_ids.is_empty() {
+            return Err(CommonError::InvalidPaymentRequest {
+                reason: "No non fungible ids requested".to_owned(),
+            });
+        }
+        Ok(Self::NonFungible {
+            resource_address,
+            local_ids,
+        })
+    }
+
+    pub fn resource_address(&self) -> ResourceAddress {
+        match self {
+            Self::Fungible {
+                resource_address, ..
+            }
+            | Self::NonFungible {
+                resource_address, ..
+            } => *resource_address,
+        }
+    }
+}
+
+impl HasSampleValues for PaymentRequestAsset {
+    fn sample() -> Self {
+        Self::fungible(
+            ResourceAddress::sample_mainnet_xrd(),
+            Decimal192::from_str("237.13372718281828").unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn sample_other() -> Self {
+        Self::non_fungible(
+            ResourceAddress::sample_mainnet_nft_gc_membership(),
+            [
+                NonFungibleLocalId::integer(1),
+                NonFungibleLocalId::integer(2),
+            ],
+        )
+        .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = PaymentRequestAsset;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn resource_address() {
+        assert_eq!(
+            SUT::sample().resource_address(),
+            ResourceAddress::sample_mainnet_xrd()
+        );
+        assert_eq!(
+            SUT::sample_other().resource_address(),
+            ResourceAddress::sample_mainnet_nft_gc_membership()
+        );
+    }
+
+    #[test]
+    fn fungible_of_non_fungible_resource_is_err() {
+        assert!(matches!(
+            SUT::fungible(
+                ResourceAddress::sample_mainnet_nft_gc_membership(),
+                1
+            ),
+            Err(CommonError::InvalidPaymentRequest { .. })
+        ));
+    }
+
+    #[test]
+    fn fungible_zero_amount_is_err() {
+        assert!(matches!(
+            SUT::fungible(ResourceAddress::sample_mainnet_xrd(), 0),
+            Err(CommonError::InvalidPaymentRequest { .. })
+        ));
+    }
+
+    #[test]
+    fn non_fungible_of_fungible_resource_is_err() {
+        assert!(matches!(
+            SUT::non_fungible(
+                ResourceAddress::sample_mainnet_xrd(),
+                [NonFungibleLocalId::integer(1)]
+            ),
+            Err(CommonError::InvalidPaymentRequest { .. })
+        ));
+    }
+
+    #[test]
+    fn non_fungible_without_ids_is_err() {
+        assert!(matches!(
+            SUT::non_fungible(
+                ResourceAddress::sample_mainnet_nft_gc_membership(),
+                []
+            ),
+            Err(CommonError::InvalidPaymentRequest { .. })
+        ));
+    }
+
+    #[test]
+    fn non_fungible_duplicate_ids_are_removed() {
+        let sut = SUT::non_fungible(
+            ResourceAddress::sample_mainnet_nft_gc_membership(),
+            [
+                NonFungibleLocalId::integer(1),
+                NonFungibleLocalId::integer(2),
+                NonFungibleLocalId::integer(1),
+            ],
+        )
+        .unwrap();
+        assert_eq!(sut, SUT::sample_other());
+    }
+}
@@ -0,0 +1,147 @@
+use crate::prelude::*;
+
+/// The recipient of a `PaymentRequest`, either an account address or an RNS
+/// domain which must be resolved to its configured receiver account before
+/// the payment can be made.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PaymentRequestRecipient {
+    Account { value: AccountAddress },
+    RnsDomain { value: RnsDomain },
+}
+
+impl From<AccountAddress> for PaymentRequestRecipient {
+    fn from(value: AccountAddress) -> Self {
+        Self::Account { value }
+    }
+}
+
+impl From<RnsDomain> for PaymentRequestRecipient {
+    fn from(value: RnsDomain) -> Self {
+        Self::RnsDomain { value }
+    }
+}
+
+impl PaymentRequestRecipient {
+    /// Parses the recipient part of a payment request URI, which is either
+    /// a Bech32 encoded account address or a valid RNS domain, e.g.
+    /// "alice.xrd".
+    ///
+    /// Account addresses must be on `network_id`.
+    pub fn parse(s: impl AsRef<str>, network_id: NetworkID) -> Result<Self> {
+        let s = s.as_ref();
+        if let Ok(account_address) = AccountAddress::from_str(s) {
+            if account_address.network_id() != network_id {
+                return Err(CommonError::NetworkDiscrepancy {
+                    expected: network_id.to_string(),
+                    actual: account_address.network_id().to_string(),
+                });
+            }
+            return Ok(Self::from(account_address));
+        }
+
+        RnsDomain::new(s.to_owned())
+            .validated()
+            .map(Self::from)
+            .map_err(|_| CommonError::InvalidPaymentRequest {
+                reason: format!(
+                    "Recipient is neither an account address nor an RNS domain: '{}'",
+                    s
+                ),
+            })
+    }
+}
+
+impl std::fmt::Display for PaymentRequestRecipient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Account { value } => write!(f, "{}", value),
+            Self::RnsDomain { value } => write!(f, "{}", value.0),
+        }
+    }
+}
+
+impl HasSampleValues for PaymentRequestRecipient {
+    fn sample() -> Self {
+        Self::from(AccountAddress::sample_mainnet())
+    }
+
+    fn sample_other() -> Self {
+        Self::from(RnsDomain::sample())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = PaymentRequestRecipient;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn parse_account_address() {
+        assert_eq!(
+            SUT::parse(
+                AccountAddress::sample_mainnet().to_string(),
+                NetworkID::Mainnet
+            )
+            .unwrap(),
+            SUT::sample()
+        );
+    }
+
+    #[test]
+    fn parse_rns_domain() {
+        assert_eq!(
+            SUT::parse("sample1.xrd", NetworkID::Mainnet).unwrap(),
+            SUT::sample_other()
+        );
+    }
+
+    #[test]
+    fn parse_account_address_on_wrong_network() {
+        assert_eq!(
+            SUT::parse(
+                AccountAddress::sample_stokenet().to_string(),
+                NetworkID::Mainnet
+            ),
+            Err(CommonError::NetworkDiscrepancy {
+                expected: NetworkID::Mainnet.to_string(),
+                actual: NetworkID::Stokenet.to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(matches!(
+            SUT::parse(
+                ResourceAddress::sample_mainnet_xrd().to_string(),
+                NetworkID::Mainnet
+            ),
+            Err(CommonError::InvalidPaymentRequest { .. })
+        ));
+    }
+
+    #[test]
+    fn to_string_roundtrip() {
+        let roundtrip = |sut: SUT| {
+            assert_eq!(
+                SUT::parse(sut.to_string(), NetworkID::Mainnet).unwrap(),
+                sut
+            )
+        };
+        roundtrip(SUT::sample());
+        roundtrip(SUT::sample_other());
+    }
+}
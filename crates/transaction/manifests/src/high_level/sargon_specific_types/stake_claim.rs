@@ -26,6 +26,27 @@ impl StakeClaim {
             amount: amount.into(),
         }
     }
+
+    /// The stake claims of the account of `overview` which can be claimed
+    /// now, one per validator, ready to be used to build a claim manifest.
+    pub fn claimable_in(overview: &AccountStakingOverview) -> Vec<Self> {
+        overview
+            .validators
+            .iter()
+            .filter_map(|validator| {
+                let stake_claims = validator.stake_claims.as_ref()?;
+                if stake_claims.claimable.is_empty() {
+                    return None;
+                }
+                Some(Self::new(
+                    validator.validator_address,
+                    stake_claims.resource_address,
+                    stake_claims.claimable.iter().map(|claim| claim.id.clone()),
+                    stake_claims.claimable_xrd(),
+                ))
+            })
+            .collect()
+    }
 }
 
 impl HasSampleValues for StakeClaim {
@@ -68,4 +89,25 @@ mod tests {
     fn inequality() {
         assert_ne!(SUT::sample(), SUT::sample_other());
     }
+
+    #[test]
+    fn claimable_in() {
+        let overview = AccountStakingOverview::sample();
+        let stake_claims = overview.validators[1].stake_claims.clone().unwrap();
+        assert_eq!(
+            SUT::claimable_in(&overview),
+            vec![SUT::new(
+                ValidatorAddress::sample_stokenet_other(),
+                stake_claims.resource_address,
+                [StakeClaimNonFungible::sample().id],
+                100,
+            )]
+        );
+    }
+
+    #[test]
+    fn claimable_in_nothing_claimable() {
+        assert!(SUT::claimable_in(&AccountStakingOverview::sample_other())
+            .is_empty());
+    }
 }
@@ -0,0 +1,129 @@
+use crate::prelude::*;
+
+/// An amount of XRD to stake to a validator, the received Liquid Stake Units
+/// (LSU) are deposited back into the staking account.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StakeToValidator {
+    pub validator_address: ValidatorAddress,
+
+    /// The address of the Liquid Stake Unit resource of the validator.
+    pub liquid_stake_unit_address: ResourceAddress,
+
+    /// The amount of XRD to stake.
+    pub xrd_amount: Decimal192,
+}
+
+impl StakeToValidator {
+    pub fn new(
+        validator_address: ValidatorAddress,
+        liquid_stake_unit_address: ResourceAddress,
+        xrd_amount: impl Into<Decimal192>,
+    ) -> Self {
+        Self {
+            validator_address,
+            liquid_stake_unit_address,
+            xrd_amount: xrd_amount.into(),
+        }
+    }
+}
+
+impl HasSampleValues for StakeToValidator {
+    fn sample() -> Self {
+        Self::new(
+            "validator_tdx_2_1sdtnujyn3720ymg8lakydkvc5tw4q3zecdj95akdwt9de362mvtd94"
+                .parse()
+                .expect("Valid sample"),
+            "resource_tdx_2_1t45l9ku3r5mwxazht2qutmhhk3660hqqvxkkyl8rxs20n9k2zv0w7t"
+                .parse()
+                .expect("Valid sample"),
+            1000,
+        )
+    }
+
+    fn sample_other() -> Self {
+        Self::new(
+            "validator_tdx_2_1sdlkptcwjpajqawnuya8r2mgl3eqt89hw27ww6du8kxmx3thmyu8l4"
+                .parse()
+                .expect("Valid sample"),
+            "resource_tdx_2_1t5hpjckz9tm63gqvxsl60ejhzvnlguly77tltvywnj06s2x9wjdxjn"
+                .parse()
+                .expect("Valid sample"),
+            500,
+        )
+    }
+}
+
+/// The XRD `account_address` stakes to one or many validators.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AccountStakes {
+    pub account_address: AccountAddress,
+    pub stakes: Vec<StakeToValidator>,
+}
+
+impl AccountStakes {
+    pub fn new(
+        account_address: AccountAddress,
+        stakes: impl IntoIterator<Item = StakeToValidator>,
+    ) -> Self {
+        Self {
+            account_address,
+            stakes: stakes.into_iter().collect_vec(),
+        }
+    }
+
+    /// The total amount of XRD withdrawn from the account.
+    pub fn total_xrd_amount(&self) -> Decimal192 {
+        self.stakes
+            .iter()
+            .fold(Decimal192::zero(), |acc, stake| acc + stake.xrd_amount)
+    }
+}
+
+impl HasSampleValues for AccountStakes {
+    fn sample() -> Self {
+        Self::new(
+            AccountAddress::sample_stokenet(),
+            [StakeToValidator::sample(), StakeToValidator::sample_other()],
+        )
+    }
+
+    fn sample_other() -> Self {
+        Self::new(
+            AccountAddress::sample_stokenet_other(),
+            [StakeToValidator::sample_other()],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = AccountStakes;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+        assert_eq!(StakeToValidator::sample(), StakeToValidator::sample());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+        assert_ne!(
+            StakeToValidator::sample(),
+            StakeToValidator::sample_other()
+        );
+    }
+
+    #[test]
+    fn total_xrd_amount() {
+        assert_eq!(SUT::sample().total_xrd_amount(), Decimal192::from(1500));
+        assert_eq!(
+            SUT::new(AccountAddress::sample_stokenet(), []).total_xrd_amount(),
+            Decimal192::zero()
+        );
+    }
+}
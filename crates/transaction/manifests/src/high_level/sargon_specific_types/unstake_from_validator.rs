@@ -0,0 +1,132 @@
+use crate::prelude::*;
+
+/// An amount of Liquid Stake Units (LSU) to unstake from a validator, the
+/// received claim NFT is deposited back into the unstaking account and can
+/// be claimed once the unstaking delay has passed, see `StakeClaim`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UnstakeFromValidator {
+    pub validator_address: ValidatorAddress,
+
+    /// The address of the Liquid Stake Unit resource of the validator.
+    pub liquid_stake_unit_address: ResourceAddress,
+
+    /// The address of the claim NFT resource of the validator.
+    pub claim_nft_address: NonFungibleResourceAddress,
+
+    /// The amount of LSU to unstake.
+    pub liquid_stake_unit_amount: Decimal192,
+}
+
+impl UnstakeFromValidator {
+    pub fn new(
+        validator_address: ValidatorAddress,
+        liquid_stake_unit_address: ResourceAddress,
+        claim_nft_address: NonFungibleResourceAddress,
+        liquid_stake_unit_amount: impl Into<Decimal192>,
+    ) -> Self {
+        Self {
+            validator_address,
+            liquid_stake_unit_address,
+            claim_nft_address,
+            liquid_stake_unit_amount: liquid_stake_unit_amount.into(),
+        }
+    }
+}
+
+impl HasSampleValues for UnstakeFromValidator {
+    fn sample() -> Self {
+        Self::new(
+            "validator_tdx_2_1sdlkptcwjpajqawnuya8r2mgl3eqt89hw27ww6du8kxmx3thmyu8l4"
+                .parse()
+                .expect("Valid sample"),
+            "resource_tdx_2_1t5hpjckz9tm63gqvxsl60ejhzvnlguly77tltvywnj06s2x9wjdxjn"
+                .parse()
+                .expect("Valid sample"),
+            "resource_tdx_2_1ngw8z6ut9mw54am4rr65kwcuz24q3n7waxtzyfvug5g4yuc00jydqj"
+                .parse()
+                .expect("Valid sample"),
+            500,
+        )
+    }
+
+    fn sample_other() -> Self {
+        Self::new(
+            "validator_tdx_2_1sdtnujyn3720ymg8lakydkvc5tw4q3zecdj95akdwt9de362mvtd94"
+                .parse()
+                .expect("Valid sample"),
+            "resource_tdx_2_1t45l9ku3r5mwxazht2qutmhhk3660hqqvxkkyl8rxs20n9k2zv0w7t"
+                .parse()
+                .expect("Valid sample"),
+            "resource_tdx_2_1ng3g2nj5pfpmdphgz0nrh8z0gtqcxx5z5dn48t85ar0z0zjhefufaw"
+                .parse()
+                .expect("Valid sample"),
+            250,
+        )
+    }
+}
+
+/// The Liquid Stake Units `account_address` unstakes from one or many
+/// validators.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AccountUnstakes {
+    pub account_address: AccountAddress,
+    pub unstakes: Vec<UnstakeFromValidator>,
+}
+
+impl AccountUnstakes {
+    pub fn new(
+        account_address: AccountAddress,
+        unstakes: impl IntoIterator<Item = UnstakeFromValidator>,
+    ) -> Self {
+        Self {
+            account_address,
+            unstakes: unstakes.into_iter().collect_vec(),
+        }
+    }
+}
+
+impl HasSampleValues for AccountUnstakes {
+    fn sample() -> Self {
+        Self::new(
+            AccountAddress::sample_stokenet(),
+            [
+                UnstakeFromValidator::sample(),
+                UnstakeFromValidator::sample_other(),
+            ],
+        )
+    }
+
+    fn sample_other() -> Self {
+        Self::new(
+            AccountAddress::sample_stokenet_other(),
+            [UnstakeFromValidator::sample_other()],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = AccountUnstakes;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+        assert_eq!(
+            UnstakeFromValidator::sample(),
+            UnstakeFromValidator::sample()
+        );
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+        assert_ne!(
+            UnstakeFromValidator::sample(),
+            UnstakeFromValidator::sample_other()
+        );
+    }
+}
@@ -4,6 +4,8 @@ mod delete_account;
 mod high_level;
 mod manifest_account_locker;
 mod manifest_assets_transfers;
+mod manifest_pools;
+mod manifest_staking;
 mod manifests;
 mod manifests_create_tokens;
 mod manifests_security_shield;
@@ -18,6 +20,8 @@ pub mod prelude {
     pub use crate::high_level::*;
     pub use crate::manifest_account_locker::*;
     pub use crate::manifest_assets_transfers::*;
+    pub use crate::manifest_pools::*;
+    pub use crate::manifest_staking::*;
     pub use crate::manifests::*;
     pub use crate::manifests_create_tokens::*;
     pub use crate::manifests_security_shield::*;
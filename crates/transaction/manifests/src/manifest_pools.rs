@@ -0,0 +1,387 @@
+use radix_engine_interface::blueprints::pool::{
+    MultiResourcePoolContributeManifestInput as ScryptoMultiResourcePoolContributeManifestInput,
+    MultiResourcePoolRedeemManifestInput as ScryptoMultiResourcePoolRedeemManifestInput,
+    OneResourcePoolContributeManifestInput as ScryptoOneResourcePoolContributeManifestInput,
+    OneResourcePoolRedeemManifestInput as ScryptoOneResourcePoolRedeemManifestInput,
+    TwoResourcePoolContributeManifestInput as ScryptoTwoResourcePoolContributeManifestInput,
+    TwoResourcePoolRedeemManifestInput as ScryptoTwoResourcePoolRedeemManifestInput,
+    MULTI_RESOURCE_POOL_CONTRIBUTE_IDENT as SCRYPTO_MULTI_RESOURCE_POOL_CONTRIBUTE_IDENT,
+    MULTI_RESOURCE_POOL_REDEEM_IDENT as SCRYPTO_MULTI_RESOURCE_POOL_REDEEM_IDENT,
+    ONE_RESOURCE_POOL_CONTRIBUTE_IDENT as SCRYPTO_ONE_RESOURCE_POOL_CONTRIBUTE_IDENT,
+    ONE_RESOURCE_POOL_REDEEM_IDENT as SCRYPTO_ONE_RESOURCE_POOL_REDEEM_IDENT,
+    TWO_RESOURCE_POOL_CONTRIBUTE_IDENT as SCRYPTO_TWO_RESOURCE_POOL_CONTRIBUTE_IDENT,
+    TWO_RESOURCE_POOL_REDEEM_IDENT as SCRYPTO_TWO_RESOURCE_POOL_REDEEM_IDENT,
+};
+
+use crate::prelude::*;
+use bucket_factory::BucketFactory;
+
+pub trait TransactionManifestPools: Sized {
+    /// Contributes `contributions` from the account of `account_address` to
+    /// the pool of `pool_state`, one amount for each resource of the pool.
+    /// The received pool units - and any change - are deposited back into
+    /// the account.
+    ///
+    /// If the pool is not empty, the expected amount of pool units is
+    /// computed from the current ratio of the pool, and a guarantee of
+    /// `default_deposit_guarantee` of that amount is asserted.
+    fn contribution_to_pool(
+        account_address: AccountAddress,
+        pool_state: &LiquidityPoolState,
+        contributions: HashMap<ResourceAddress, Decimal192>,
+        transaction_preferences: &TransactionPreferences,
+    ) -> Result<Self>;
+
+    /// Redeems `pool_units` of the pool of `pool_state` from the account of
+    /// `account_address`, the received resources are deposited back into
+    /// the account.
+    ///
+    /// A guarantee of `default_deposit_guarantee` of the expected amount of
+    /// each resource is asserted.
+    fn redemption_from_pool(
+        account_address: AccountAddress,
+        pool_state: &LiquidityPoolState,
+        pool_units: Decimal192,
+        transaction_preferences: &TransactionPreferences,
+    ) -> Self;
+}
+
+impl TransactionManifestPools for TransactionManifest {
+    fn contribution_to_pool(
+        account_address: AccountAddress,
+        pool_state: &LiquidityPoolState,
+        contributions: HashMap<ResourceAddress, Decimal192>,
+        transaction_preferences: &TransactionPreferences,
+    ) -> Result<Self> {
+        let pool_address = pool_state.pool_address;
+        let pool_resources = pool_state.resource_addresses();
+
+        if let Some(resource_address) = contributions
+            .keys()
+            .find(|resource| !pool_resources.contains(resource))
+        {
+            return Err(CommonError::ResourceNotInPool {
+                resource_address: resource_address.to_string(),
+                pool_address: pool_address.to_string(),
+            });
+        }
+
+        let mut builder = ScryptoTransactionManifestBuilder::new();
+        let bucket_factory = BucketFactory::default();
+        let mut buckets = Vec::<ScryptoManifestBucket>::new();
+
+        // Contributed in the order of the resources of the pool
+        for resource_address in pool_resources.iter() {
+            let amount = contributions.get(resource_address).ok_or(
+                CommonError::MissingPoolContribution {
+                    resource_address: resource_address.to_string(),
+                    pool_address: pool_address.to_string(),
+                },
+            )?;
+
+            builder = builder.withdraw_from_account(
+                &account_address,
+                resource_address,
+                *amount,
+            );
+
+            let bucket = &bucket_factory.next();
+            builder = builder.take_all_from_worktop(resource_address, bucket);
+            buckets.push(builder.bucket(bucket));
+        }
+
+        builder = match pool_address.pool_address_kind() {
+            PoolKind::OneResource => {
+                let [bucket] = <[_; 1]>::try_from(buckets)
+                    .map_err(|_| invalid_pool_state(pool_address))?;
+                builder.call_method(
+                    &pool_address,
+                    SCRYPTO_ONE_RESOURCE_POOL_CONTRIBUTE_IDENT,
+                    ScryptoOneResourcePoolContributeManifestInput { bucket },
+                )
+            }
+            PoolKind::TwoResources => {
+                let [first, second] = <[_; 2]>::try_from(buckets)
+                    .map_err(|_| invalid_pool_state(pool_address))?;
+                builder.call_method(
+                    &pool_address,
+                    SCRYPTO_TWO_RESOURCE_POOL_CONTRIBUTE_IDENT,
+                    ScryptoTwoResourcePoolContributeManifestInput {
+                        buckets: (first, second),
+                    },
+                )
+            }
+            PoolKind::MultiResources => builder.call_method(
+                &pool_address,
+                SCRYPTO_MULTI_RESOURCE_POOL_CONTRIBUTE_IDENT,
+                ScryptoMultiResourcePoolContributeManifestInput { buckets },
+            ),
+        };
+
+        if let Some(pool_units) =
+            pool_state.pool_units_for_contribution(&contributions)?
+        {
+            builder = builder.assert_worktop_contains(
+                &pool_state.pool_unit_resource_address,
+                pool_units * transaction_preferences.default_deposit_guarantee,
+            );
+        }
+
+        builder =
+            builder.try_deposit_entire_worktop_or_abort(&account_address, None);
+
+        Ok(TransactionManifest::sargon_built(
+            builder,
+            account_address.network_id(),
+        ))
+    }
+
+    fn redemption_from_pool(
+        account_address: AccountAddress,
+        pool_state: &LiquidityPoolState,
+        pool_units: Decimal192,
+        transaction_preferences: &TransactionPreferences,
+    ) -> Self {
+        let pool_address = pool_state.pool_address;
+        let pool_unit_resource_address = &pool_state.pool_unit_resource_address;
+
+        let mut builder = ScryptoTransactionManifestBuilder::new();
+        let bucket_factory = BucketFactory::default();
+
+        builder = builder.withdraw_from_account(
+            &account_address,
+            pool_unit_resource_address,
+            pool_units,
+        );
+
+        let bucket = &bucket_factory.next();
+        builder = builder.take_from_worktop(
+            pool_unit_resource_address,
+            pool_units,
+            bucket,
+        );
+        let bucket = builder.bucket(bucket);
+
+        builder = match pool_address.pool_address_kind() {
+            PoolKind::OneResource => builder.call_method(
+                &pool_address,
+                SCRYPTO_ONE_RESOURCE_POOL_REDEEM_IDENT,
+                ScryptoOneResourcePoolRedeemManifestInput { bucket },
+            ),
+            PoolKind::TwoResources => builder.call_method(
+                &pool_address,
+                SCRYPTO_TWO_RESOURCE_POOL_REDEEM_IDENT,
+                ScryptoTwoResourcePoolRedeemManifestInput { bucket },
+            ),
+            PoolKind::MultiResources => builder.call_method(
+                &pool_address,
+                SCRYPTO_MULTI_RESOURCE_POOL_REDEEM_IDENT,
+                ScryptoMultiResourcePoolRedeemManifestInput { bucket },
+            ),
+        };
+
+        for redeemed in pool_state
+            .redemption_amounts(pool_units)
+            .into_iter()
+            .filter(|redeemed| redeemed.amount.is_positive())
+        {
+            builder = builder.assert_worktop_contains(
+                &redeemed.resource_address,
+                redeemed.amount
+                    * transaction_preferences.default_deposit_guarantee,
+            );
+        }
+
+        builder =
+            builder.try_deposit_entire_worktop_or_abort(&account_address, None);
+
+        TransactionManifest::sargon_built(builder, account_address.network_id())
+    }
+}
+
+fn invalid_pool_state(pool_address: PoolAddress) -> CommonError {
+    CommonError::InvalidPoolStateFromGateway {
+        pool_address: pool_address.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prelude::fixture_rtm;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = TransactionManifest;
+
+    fn account() -> AccountAddress {
+        "account_tdx_2_12xy3a3eme090gwzku277q0awutftu76nd4wddjvhtzelf84glmghae"
+            .parse()
+            .unwrap()
+    }
+
+    fn contributions(
+        pool_state: &LiquidityPoolState,
+        amounts: impl IntoIterator<Item = i32>,
+    ) -> HashMap<ResourceAddress, Decimal192> {
+        pool_state
+            .resource_addresses()
+            .into_iter()
+            .zip(amounts.into_iter().map(Decimal192::from))
+            .collect()
+    }
+
+    fn single_pool_state() -> LiquidityPoolState {
+        LiquidityPoolState::new(
+            PoolAddress::sample_stokenet_single_pool(),
+            "resource_tdx_2_1thgnc84xkcjhs46pfvm9s8zn8t9kxwryvyr9x3947xpt6jxty7qn25".parse().unwrap(),
+            1000,
+            [PoolResourceAmount::new(
+                ResourceAddress::sample_stokenet_xrd(),
+                2000,
+            )],
+        )
+    }
+
+    fn multi_pool_state() -> LiquidityPoolState {
+        LiquidityPoolState::new(
+            PoolAddress::sample_stokenet_multi_pool(),
+            "resource_tdx_2_1t4u9jzqe69k3ghuhe4ulrmsthqyk2qw8wh6l4hjkt9r8m0736xx4qd".parse().unwrap(),
+            100,
+            [
+                PoolResourceAmount::new(
+                    ResourceAddress::sample_stokenet_xrd(),
+                    1000,
+                ),
+                PoolResourceAmount::new(
+                    "resource_tdx_2_1t5gweua2ggcgw6u0g6xvf7l5t40mvwww8a856q0tzcjdnut7jdh84d".parse().unwrap(),
+                    500,
+                ),
+                PoolResourceAmount::new(
+                    "resource_tdx_2_1tkx5re7dyhr8updty5spde2nsr0kpnhq953m09xg3u6hf3u6j4fsqf".parse().unwrap(),
+                    200,
+                ),
+            ],
+        )
+    }
+
+    #[test]
+    fn contribute_to_single_pool() {
+        let pool_state = single_pool_state();
+        let manifest = SUT::contribution_to_pool(
+            account(),
+            &pool_state,
+            contributions(&pool_state, [100]),
+            &TransactionPreferences::default(),
+        )
+        .unwrap();
+
+        manifest_eq(manifest, fixture_rtm!("contribute_to_single_pool"))
+    }
+
+    #[test]
+    fn contribute_to_two_resource_pool() {
+        let pool_state = LiquidityPoolState::sample();
+        let manifest = SUT::contribution_to_pool(
+            account(),
+            &pool_state,
+            contributions(&pool_state, [200, 100]),
+            &TransactionPreferences::default(),
+        )
+        .unwrap();
+
+        manifest_eq(manifest, fixture_rtm!("contribute_to_two_resource_pool"))
+    }
+
+    #[test]
+    fn contribute_to_multi_resource_pool() {
+        let pool_state = multi_pool_state();
+        let manifest = SUT::contribution_to_pool(
+            account(),
+            &pool_state,
+            contributions(&pool_state, [100, 50, 20]),
+            &TransactionPreferences::default(),
+        )
+        .unwrap();
+
+        manifest_eq(manifest, fixture_rtm!("contribute_to_multi_resource_pool"))
+    }
+
+    #[test]
+    fn contribute_to_empty_pool_has_no_guarantee() {
+        let pool_state = LiquidityPoolState::sample_other();
+        let manifest = SUT::contribution_to_pool(
+            account(),
+            &pool_state,
+            contributions(&pool_state, [200, 100]),
+            &TransactionPreferences::default(),
+        )
+        .unwrap();
+
+        assert!(!manifest.manifest_string().contains("ASSERT_WORKTOP"));
+    }
+
+    #[test]
+    fn contribute_resource_not_in_pool() {
+        let pool_state = LiquidityPoolState::sample();
+        let mut contributions = contributions(&pool_state, [200, 100]);
+        let resource_address = ResourceAddress::sample_stokenet_candy();
+        contributions.insert(resource_address, Decimal192::one());
+
+        assert_eq!(
+            SUT::contribution_to_pool(
+                account(),
+                &pool_state,
+                contributions,
+                &TransactionPreferences::default(),
+            ),
+            Err(CommonError::ResourceNotInPool {
+                resource_address: resource_address.to_string(),
+                pool_address: pool_state.pool_address.to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn contribute_missing_resource_of_pool() {
+        let pool_state = LiquidityPoolState::sample();
+
+        assert_eq!(
+            SUT::contribution_to_pool(
+                account(),
+                &pool_state,
+                contributions(&pool_state, [200]),
+                &TransactionPreferences::default(),
+            ),
+            Err(CommonError::MissingPoolContribution {
+                resource_address: pool_state.reserves[1]
+                    .resource_address
+                    .to_string(),
+                pool_address: pool_state.pool_address.to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn redeem_from_two_resource_pool() {
+        let manifest = SUT::redemption_from_pool(
+            account(),
+            &LiquidityPoolState::sample(),
+            Decimal192::from(10),
+            &TransactionPreferences::default(),
+        );
+
+        manifest_eq(manifest, fixture_rtm!("redeem_from_two_resource_pool"))
+    }
+
+    #[test]
+    fn redeem_from_multi_resource_pool() {
+        let manifest = SUT::redemption_from_pool(
+            account(),
+            &multi_pool_state(),
+            Decimal192::from(10),
+            &TransactionPreferences::new(Decimal192::one()),
+        );
+
+        manifest_eq(manifest, fixture_rtm!("redeem_from_multi_resource_pool"))
+    }
+}
@@ -0,0 +1,258 @@
+use crate::prelude::*;
+use bucket_factory::BucketFactory;
+
+pub trait TransactionManifestStaking: Sized {
+    /// Stakes XRD from one or many accounts to one or many validators, the
+    /// received Liquid Stake Units are deposited into the staking account.
+    ///
+    /// All addresses are mapped to the network of the first account.
+    fn stakes_to_validators(account_stakes: Vec<AccountStakes>) -> Self;
+
+    /// Unstakes Liquid Stake Units from one or many accounts from one or many
+    /// validators, the received claim NFTs are deposited into the unstaking
+    /// account.
+    ///
+    /// All addresses are mapped to the network of the first account.
+    fn unstakes_from_validators(account_unstakes: Vec<AccountUnstakes>)
+        -> Self;
+}
+
+impl TransactionManifestStaking for TransactionManifest {
+    fn stakes_to_validators(account_stakes: Vec<AccountStakes>) -> Self {
+        let network_id = network_of_first(
+            account_stakes.iter().map(|a| a.account_address),
+            account_stakes
+                .iter()
+                .flat_map(|a| a.stakes.iter().map(|s| s.validator_address)),
+        );
+        let xrd_address = &ResourceAddress::xrd_on_network(network_id);
+
+        let mut builder = ScryptoTransactionManifestBuilder::new();
+        let bucket_factory = BucketFactory::default();
+
+        for account_stake in account_stakes.iter() {
+            let account_address = &account_stake.account_address;
+
+            // Withdraw all the XRD to stake at once
+            builder = builder.withdraw_from_account(
+                account_address,
+                xrd_address,
+                account_stake.total_xrd_amount(),
+            );
+
+            for stake in account_stake.stakes.iter() {
+                let xrd_bucket = &bucket_factory.next();
+                builder = builder.take_from_worktop(
+                    xrd_address,
+                    stake.xrd_amount,
+                    xrd_bucket,
+                );
+
+                builder = builder
+                    .stake_validator(&stake.validator_address, xrd_bucket);
+
+                // Deposit the received LSUs
+                let lsu_bucket = &bucket_factory.next();
+                builder = builder.take_all_from_worktop(
+                    &stake.liquid_stake_unit_address,
+                    lsu_bucket,
+                );
+                builder = builder.deposit(account_address, lsu_bucket);
+            }
+        }
+
+        TransactionManifest::sargon_built(builder, network_id)
+    }
+
+    fn unstakes_from_validators(
+        account_unstakes: Vec<AccountUnstakes>,
+    ) -> Self {
+        let network_id = network_of_first(
+            account_unstakes.iter().map(|a| a.account_address),
+            account_unstakes
+                .iter()
+                .flat_map(|a| a.unstakes.iter().map(|u| u.validator_address)),
+        );
+
+        let mut builder = ScryptoTransactionManifestBuilder::new();
+        let bucket_factory = BucketFactory::default();
+
+        for account_unstake in account_unstakes.iter() {
+            let account_address = &account_unstake.account_address;
+
+            for unstake in account_unstake.unstakes.iter() {
+                let lsu_address = &unstake.liquid_stake_unit_address;
+
+                builder = builder.withdraw_from_account(
+                    account_address,
+                    lsu_address,
+                    unstake.liquid_stake_unit_amount,
+                );
+
+                let lsu_bucket = &bucket_factory.next();
+                builder =
+                    builder.take_all_from_worktop(lsu_address, lsu_bucket);
+
+                builder = builder
+                    .unstake_validator(&unstake.validator_address, lsu_bucket);
+
+                // Deposit the received claim NFT
+                let claim_bucket = &bucket_factory.next();
+                builder = builder.take_all_from_worktop(
+                    &unstake.claim_nft_address,
+                    claim_bucket,
+                );
+                builder = builder.deposit(account_address, claim_bucket);
+            }
+        }
+
+        TransactionManifest::sargon_built(builder, network_id)
+    }
+}
+
+/// The network of the first account, warns if any account or validator is on
+/// another network, since those addresses will be mapped to that network.
+fn network_of_first(
+    account_addresses: impl IntoIterator<Item = AccountAddress>,
+    validator_addresses: impl IntoIterator<Item = ValidatorAddress>,
+) -> NetworkID {
+    let mut network_ids = account_addresses
+        .into_iter()
+        .map(|a| a.network_id())
+        .chain(validator_addresses.into_iter().map(|v| v.network_id()));
+
+    let Some(network_id) = network_ids.next() else {
+        return NetworkID::default();
+    };
+    if network_ids.any(|n| n != network_id) {
+        warn!("Not all AccountAddresses and ValidatorAddresses are on the same network, they will automatically be switched to the network of the first account: {network_id}")
+    }
+    network_id
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prelude::fixture_rtm;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = TransactionManifest;
+
+    fn account() -> AccountAddress {
+        "account_tdx_2_129uv9r46an4hwng8wc97qwpraspvnrc7v2farne4lr6ff7yaevaz2a"
+            .parse()
+            .unwrap()
+    }
+
+    fn validator_0() -> ValidatorAddress {
+        "validator_tdx_2_1sdtnujyn3720ymg8lakydkvc5tw4q3zecdj95akdwt9de362mvtd94"
+            .parse()
+            .unwrap()
+    }
+
+    fn validator_1() -> ValidatorAddress {
+        "validator_tdx_2_1sdlkptcwjpajqawnuya8r2mgl3eqt89hw27ww6du8kxmx3thmyu8l4"
+            .parse()
+            .unwrap()
+    }
+
+    fn validator_2() -> ValidatorAddress {
+        "validator_tdx_2_1svr6rmtd9ts5zx8d3euwmmp6mmjdtcj2q7zlmd8xjrn4qx7q5snkas"
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn empty() {
+        manifest_eq(SUT::stakes_to_validators(vec![]), "");
+        manifest_eq(SUT::unstakes_from_validators(vec![]), "");
+    }
+
+    #[test]
+    fn stake_to_three_validators() {
+        let manifest = SUT::stakes_to_validators(vec![AccountStakes::new(
+            account(),
+            [
+                StakeToValidator::new(
+                    validator_0(),
+                    "resource_tdx_2_1t45l9ku3r5mwxazht2qutmhhk3660hqqvxkkyl8rxs20n9k2zv0w7t".parse().unwrap(),
+                    1000,
+                ),
+                StakeToValidator::new(
+                    validator_1(),
+                    "resource_tdx_2_1t5hpjckz9tm63gqvxsl60ejhzvnlguly77tltvywnj06s2x9wjdxjn".parse().unwrap(),
+                    1000,
+                ),
+                StakeToValidator::new(
+                    validator_2(),
+                    "resource_tdx_2_1t48zl3qmcv3pf24r0765q4zc6rrk83cfjv6wza2xksej80pcfd7p5g".parse().unwrap(),
+                    1000,
+                ),
+            ],
+        )]);
+
+        manifest_eq(manifest, fixture_rtm!("stake_to_three_validators"))
+    }
+
+    #[test]
+    fn stake_from_two_accounts_to_two_validators() {
+        let manifest = SUT::stakes_to_validators(vec![
+            AccountStakes::sample(),
+            AccountStakes::sample_other(),
+        ]);
+
+        manifest_eq(
+            manifest,
+            fixture_rtm!("stake_from_two_accounts_to_two_validators"),
+        )
+    }
+
+    #[test]
+    fn unstake_partially_from_one_validator() {
+        let manifest =
+            SUT::unstakes_from_validators(vec![AccountUnstakes::new(
+                account(),
+                [UnstakeFromValidator::sample()],
+            )]);
+
+        manifest_eq(
+            manifest,
+            fixture_rtm!("unstake_partially_from_one_validator"),
+        )
+    }
+
+    #[test]
+    fn unstake_from_two_accounts_and_two_validators() {
+        let manifest = SUT::unstakes_from_validators(vec![
+            AccountUnstakes::sample(),
+            AccountUnstakes::sample_other(),
+        ]);
+
+        manifest_eq(
+            manifest,
+            fixture_rtm!("unstake_from_two_accounts_and_two_validators"),
+        )
+    }
+
+    #[test]
+    fn validators_are_switched_to_network_of_first_account() {
+        let validator_address = ValidatorAddress::sample_mainnet();
+        let manifest = SUT::stakes_to_validators(vec![AccountStakes::new(
+            account(),
+            [StakeToValidator::new(
+                validator_address,
+                "resource_tdx_2_1t45l9ku3r5mwxazht2qutmhhk3660hqqvxkkyl8rxs20n9k2zv0w7t".parse().unwrap(),
+                1,
+            )],
+        )]);
+
+        assert!(!manifest
+            .manifest_string()
+            .contains(&validator_address.address()));
+        assert!(manifest.manifest_string().contains(
+            &validator_address
+                .map_to_network(NetworkID::Stokenet)
+                .address()
+        ));
+    }
+}
@@ -0,0 +1,82 @@
+use crate::prelude::*;
+
+use radix_engine_interface::blueprints::access_controller::{
+    AccessControllerLockPrimaryRoleManifestInput as ScryptoAccessControllerLockPrimaryRoleManifestInput,
+    AccessControllerUnlockPrimaryRoleManifestInput as ScryptoAccessControllerUnlockPrimaryRoleManifestInput,
+    ACCESS_CONTROLLER_LOCK_PRIMARY_ROLE_IDENT as SCRYPTO_ACCESS_CONTROLLER_LOCK_PRIMARY_ROLE_IDENT,
+    ACCESS_CONTROLLER_UNLOCK_PRIMARY_ROLE_IDENT as SCRYPTO_ACCESS_CONTROLLER_UNLOCK_PRIMARY_ROLE_IDENT,
+};
+
+pub trait TransactionManifestLockPrimaryRole {
+    /// Locks the Primary role of the access controller at `ac_address`, i.e.
+    /// an emergency freeze of the entity, which must be signed with the
+    /// Recovery role.
+    fn lock_primary_role(ac_address: AccessControllerAddress) -> Self;
+
+    /// Unlocks a previously locked Primary role of the access controller at
+    /// `ac_address`, must be signed with the Recovery role.
+    fn unlock_primary_role(ac_address: AccessControllerAddress) -> Self;
+}
+
+impl TransactionManifestLockPrimaryRole for TransactionManifest {
+    fn lock_primary_role(ac_address: AccessControllerAddress) -> Self {
+        let mut builder = ScryptoTransactionManifestBuilder::new();
+        builder = builder.call_method(
+            ac_address.scrypto(),
+            SCRYPTO_ACCESS_CONTROLLER_LOCK_PRIMARY_ROLE_IDENT,
+            ScryptoAccessControllerLockPrimaryRoleManifestInput {},
+        );
+
+        TransactionManifest::sargon_built(builder, ac_address.network_id())
+    }
+
+    fn unlock_primary_role(ac_address: AccessControllerAddress) -> Self {
+        let mut builder = ScryptoTransactionManifestBuilder::new();
+        builder = builder.call_method(
+            ac_address.scrypto(),
+            SCRYPTO_ACCESS_CONTROLLER_UNLOCK_PRIMARY_ROLE_IDENT,
+            ScryptoAccessControllerUnlockPrimaryRoleManifestInput {},
+        );
+
+        TransactionManifest::sargon_built(builder, ac_address.network_id())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prelude::fixture_rtm;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = TransactionManifest;
+
+    fn ac_address() -> AccessControllerAddress {
+        AccessControllerAddress::try_from_bech32(
+            "accesscontroller_rdx1c0duj4lq0dc3cpl8qd420fpn5eckh8ljeysvjm894lyl5ja5yq6y5a",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn lock_primary_role() {
+        let manifest = SUT::lock_primary_role(ac_address());
+        manifest_eq(manifest, fixture_rtm!("lock_primary_role"));
+    }
+
+    #[test]
+    fn unlock_primary_role() {
+        let manifest = SUT::unlock_primary_role(ac_address());
+        manifest_eq(manifest, fixture_rtm!("unlock_primary_role"));
+    }
+
+    #[test]
+    fn lock_primary_role_with_lock_fee_against_access_controller() {
+        let manifest = SUT::modify_manifest_add_lock_fee_against_xrd_vault_of_access_controller(
+            SUT::lock_primary_role(ac_address()),
+            Decimal192::one(),
+            ac_address(),
+        );
+        let expected = fixture_rtm!("lock_primary_role_with_xrd_lock");
+        manifest_eq(manifest, expected);
+    }
+}
@@ -2,6 +2,7 @@ mod access_controller_factors_and_time_input;
 mod cancel_recovery_proposal;
 mod confirm_timed_recovery;
 mod lock_fee_against_xrd_vault_of_access_controller;
+mod lock_primary_role;
 mod manifests_securify_shield_securified_entity;
 mod manifests_securify_shield_unsecurified_entity;
 mod roles_exercisable_in_transaction_manifest_combination;
@@ -18,6 +19,7 @@ pub use cancel_recovery_proposal::*;
 pub use confirm_timed_recovery::*;
 pub use stop_timed_recovery::*;
 pub use lock_fee_against_xrd_vault_of_access_controller::*;
+pub use lock_primary_role::*;
 pub use manifests_securify_shield_securified_entity::*;
 pub use manifests_securify_shield_unsecurified_entity::*;
 pub use set_rola_key::*;
@@ -116,6 +116,19 @@ impl LockFeeData {
         }
     }
 
+    /// Locks the `total_fee` of `transaction_fee` against `account`.
+    pub fn new_with_transaction_fee(
+        account: Account,
+        transaction_fee: &TransactionFee,
+        fee_payer_xrd_balance: Decimal192,
+    ) -> Self {
+        Self::new_with_account(
+            account,
+            transaction_fee.total_fee(),
+            fee_payer_xrd_balance,
+        )
+    }
+
     pub fn new_with_unsecurified_fee_payer(
         fee_payer_address: AccountAddress,
         fee: Decimal192,
@@ -322,6 +335,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_lock_fee_data_with_transaction_fee() {
+        let transaction_fee = TransactionFee::sample();
+        let sut = LockFeeData::new_with_transaction_fee(
+            Account::sample_mainnet(),
+            &transaction_fee,
+            Decimal192::from(10),
+        );
+        assert_eq!(sut.fee(), transaction_fee.total_fee());
+    }
+
     #[test]
     fn test_modify_add_lock_fee_with_fee_amount_and_access_controller() {
         let manifest = TransactionManifest::sample_mainnet_without_lock_fee();
@@ -33,10 +33,30 @@ pub trait DynamicallyAnalyzableManifest: StaticallyAnalyzableManifest {
                 }
             })?;
 
-        Ok(ExecutionSummary::from((
-            ret_dynamic_analysis,
-            self.network_id(),
-        )))
+        let mut summary =
+            ExecutionSummary::from((ret_dynamic_analysis, self.network_id()));
+        summary.classify_primary_role_lock_if_present(
+            self.access_controllers_locking_primary_role(),
+            self.access_controllers_unlocking_primary_role(),
+        );
+
+        Ok(summary)
+    }
+
+    /// The access controllers whose Primary role is locked by this manifest,
+    /// which RET does not classify.
+    fn access_controllers_locking_primary_role(
+        &self,
+    ) -> Vec<AccessControllerAddress> {
+        Vec::new()
+    }
+
+    /// The access controllers whose Primary role is unlocked by this manifest,
+    /// which RET does not classify.
+    fn access_controllers_unlocking_primary_role(
+        &self,
+    ) -> Vec<AccessControllerAddress> {
+        Vec::new()
     }
 }
 
@@ -47,6 +67,18 @@ impl DynamicallyAnalyzableManifest for TransactionManifest {
     ) -> Result<RetDynamicAnalysis, RetManifestAnalysisError> {
         RET_dynamically_analyze(&self.scrypto_manifest(), receipt)
     }
+
+    fn access_controllers_locking_primary_role(
+        &self,
+    ) -> Vec<AccessControllerAddress> {
+        TransactionManifest::access_controllers_locking_primary_role(self)
+    }
+
+    fn access_controllers_unlocking_primary_role(
+        &self,
+    ) -> Vec<AccessControllerAddress> {
+        TransactionManifest::access_controllers_unlocking_primary_role(self)
+    }
 }
 
 impl DynamicallyAnalyzableManifest for TransactionManifestV2 {
@@ -33,7 +33,7 @@ pub mod prelude {
     pub(crate) use radix_common::{
         crypto::Secp256k1PrivateKey as ScryptoSecp256k1PrivateKey,
         math::Decimal as ScryptoDecimal192,
-        prelude::UpperBound as ScryptoUpperBound,
+        prelude::{dec, UpperBound as ScryptoUpperBound},
     };
 
     pub(crate) use radix_engine::{
@@ -161,9 +161,14 @@ pub mod prelude {
             MockBlobProvider as ScryptoMockBlobProvider,
         },
         model::{
+            AesGcmPayload as ScryptoAesGcmPayload,
+            AesWrapped128BitKey as ScryptoAesWrapped128BitKey,
             BlobV1 as ScryptoBlob, BlobsV1 as ScryptoBlobs,
             ChildSubintentSpecifier as ScryptoChildSubintentSpecifier,
             ChildSubintentSpecifiersV2 as ScryptoChildSubintentSpecifiers,
+            CurveType as ScryptoCurveType,
+            DecryptorsByCurve as ScryptoDecryptorsByCurve,
+            EncryptedMessageV1 as ScryptoEncryptedMessage,
             InstructionV1 as ScryptoInstruction,
             InstructionV2 as ScryptoInstructionV2,
             InstructionsV1 as ScryptoInstructions,
@@ -183,6 +188,7 @@ pub mod prelude {
             NotarySignatureV1 as ScryptoNotarySignature,
             PartialTransactionV2 as ScryptoPartialTransaction,
             PlaintextMessageV1 as ScryptoPlaintextMessage,
+            PublicKeyFingerprint as ScryptoPublicKeyFingerprint,
             SignedIntentV1 as ScryptoSignedIntent,
             SignedPartialTransactionV2 as ScryptoSignedPartialTransaction,
             SignedTransactionIntentHash as ScryptoSignedTransactionIntentHash,
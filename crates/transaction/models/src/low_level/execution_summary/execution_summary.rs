@@ -196,6 +196,37 @@ impl ExecutionSummary {
                 });
         }
     }
+
+    /// Classifies the summary as locking or unlocking of the Primary role of
+    /// access controllers, given the access controllers found in the manifest
+    /// calling `lock_primary_role` or `unlock_primary_role`. RET does not
+    /// recognize these calls so we only classify if it yielded no or a
+    /// `General` classification.
+    pub fn classify_primary_role_lock_if_present(
+        &mut self,
+        locking: Vec<AccessControllerAddress>,
+        unlocking: Vec<AccessControllerAddress>,
+    ) {
+        if !matches!(
+            self.detailed_classification,
+            None | Some(DetailedManifestClass::General)
+        ) {
+            return;
+        }
+
+        if !locking.is_empty() {
+            self.detailed_classification =
+                Some(DetailedManifestClass::AccessControllerLockPrimaryRole {
+                    ac_addresses: locking,
+                });
+        } else if !unlocking.is_empty() {
+            self.detailed_classification = Some(
+                DetailedManifestClass::AccessControllerUnlockPrimaryRole {
+                    ac_addresses: unlocking,
+                },
+            );
+        }
+    }
 }
 
 fn addresses_of_accounts_from_ret(
@@ -390,3 +421,67 @@ impl HasSampleValues for ExecutionSummary {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = ExecutionSummary;
+
+    #[test]
+    fn classify_primary_role_lock_of_general() {
+        let mut sut = SUT::sample();
+        assert_eq!(
+            sut.detailed_classification,
+            Some(DetailedManifestClass::General)
+        );
+        sut.classify_primary_role_lock_if_present(
+            vec![AccessControllerAddress::sample()],
+            Vec::new(),
+        );
+        assert_eq!(
+            sut.detailed_classification,
+            Some(DetailedManifestClass::AccessControllerLockPrimaryRole {
+                ac_addresses: vec![AccessControllerAddress::sample()]
+            })
+        );
+    }
+
+    #[test]
+    fn classify_primary_role_unlock_of_unclassified() {
+        let mut sut = SUT::sample();
+        sut.detailed_classification = None;
+        sut.classify_primary_role_lock_if_present(
+            Vec::new(),
+            vec![AccessControllerAddress::sample()],
+        );
+        assert_eq!(
+            sut.detailed_classification,
+            Some(DetailedManifestClass::AccessControllerUnlockPrimaryRole {
+                ac_addresses: vec![AccessControllerAddress::sample()]
+            })
+        );
+    }
+
+    #[test]
+    fn classify_primary_role_lock_keeps_specific_classification() {
+        let mut sut = SUT::sample_other();
+        let classification = sut.detailed_classification.clone();
+        sut.classify_primary_role_lock_if_present(
+            vec![AccessControllerAddress::sample()],
+            Vec::new(),
+        );
+        assert_eq!(sut.detailed_classification, classification);
+    }
+
+    #[test]
+    fn classify_primary_role_lock_without_calls_is_noop() {
+        let mut sut = SUT::sample();
+        sut.classify_primary_role_lock_if_present(Vec::new(), Vec::new());
+        assert_eq!(
+            sut.detailed_classification,
+            Some(DetailedManifestClass::General)
+        );
+    }
+}
@@ -6,6 +6,8 @@ mod newly_created_resource;
 mod reserved_instruction;
 mod resource_indicator;
 mod resource_specifier;
+mod transaction_fee;
+mod transaction_fee_costs;
 
 pub use execution_summary::*;
 pub use fee_locks::*;
@@ -15,3 +17,5 @@ pub use newly_created_resource::*;
 pub use reserved_instruction::*;
 pub use resource_indicator::*;
 pub use resource_specifier::*;
+pub use transaction_fee::*;
+pub use transaction_fee_costs::*;
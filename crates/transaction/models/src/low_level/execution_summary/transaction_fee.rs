@@ -0,0 +1,303 @@
+use crate::prelude::*;
+
+/// The "normal" breakdown of a transaction fee, shown by default: the
+/// padded network fee and the royalties, each reduced by what dApps pay
+/// for by locking fees in the manifest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NormalTransactionFeeCustomization {
+    pub network_fee: Decimal192,
+    pub royalty_fee: Decimal192,
+}
+
+impl NormalTransactionFeeCustomization {
+    pub fn new(costs: &TransactionFeeCosts, fee_locks: &FeeLocks) -> Self {
+        let network_fee = costs.network_cost()
+            * (Decimal192::one() + transaction_fee_network_fee_multiplier());
+        let remaining_lock = (fee_locks.lock - network_fee).clamped_to_zero();
+
+        Self {
+            network_fee: (network_fee - fee_locks.lock).clamped_to_zero(),
+            royalty_fee: (costs.royalty_cost - remaining_lock)
+                .clamped_to_zero(),
+        }
+    }
+
+    pub fn total(&self) -> Decimal192 {
+        self.network_fee + self.royalty_fee
+    }
+}
+
+/// The "advanced" breakdown of a transaction fee, in which the padding
+/// is shown separately and a tip to the validator can be added.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AdvancedTransactionFeeCustomization {
+    pub costs: TransactionFeeCosts,
+
+    /// The padding added to the network costs.
+    pub padding_fee: Decimal192,
+
+    /// The tip, as a percentage of the execution and finalization costs.
+    pub tip_percentage: u16,
+
+    /// The fees paid by dApps locking fees in the manifest.
+    pub paid_by_dapps: Decimal192,
+}
+
+impl AdvancedTransactionFeeCustomization {
+    pub fn new(
+        costs: &TransactionFeeCosts,
+        fee_locks: &FeeLocks,
+        tip_percentage: u16,
+    ) -> Self {
+        Self {
+            costs: costs.clone(),
+            padding_fee: costs.network_cost()
+                * transaction_fee_network_fee_multiplier(),
+            tip_percentage,
+            paid_by_dapps: fee_locks.lock,
+        }
+    }
+
+    pub fn tip_amount(&self) -> Decimal192 {
+        (self.costs.total_execution_cost() + self.costs.finalization_cost)
+            * Decimal192::from(self.tip_percentage as u32)
+            / Decimal192::from(100)
+    }
+
+    pub fn total(&self) -> Decimal192 {
+        (self.costs.total_cost() + self.padding_fee + self.tip_amount()
+            - self.paid_by_dapps)
+            .clamped_to_zero()
+    }
+}
+
+/// Which breakdown of the transaction fee is used.
+#[derive(Clone, Debug, PartialEq, Eq, EnumAsInner)]
+pub enum TransactionFeeMode {
+    Normal(NormalTransactionFeeCustomization),
+    Advanced(AdvancedTransactionFeeCustomization),
+}
+
+/// The fee of a transaction, built from the `ExecutionSummary` of its
+/// preview, padded with the costs the preview does not include.
+///
+/// The `total_fee` is the amount to lock using `modify_add_lock_fee`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionFee {
+    pub costs: TransactionFeeCosts,
+    pub fee_locks: FeeLocks,
+    pub mode: TransactionFeeMode,
+}
+
+impl TransactionFee {
+    pub fn new(costs: TransactionFeeCosts, fee_locks: FeeLocks) -> Self {
+        let mode = TransactionFeeMode::Normal(
+            NormalTransactionFeeCustomization::new(&costs, &fee_locks),
+        );
+        Self {
+            costs,
+            fee_locks,
+            mode,
+        }
+    }
+
+    /// Creates the fee, in normal mode, of a transaction previewed with
+    /// `execution_summary`, signed by `signatures_count` signatures.
+    ///
+    /// A guarantee instruction is accounted for each predicted fungible
+    /// deposit.
+    pub fn new_from_execution_summary(
+        execution_summary: &ExecutionSummary,
+        signatures_count: u64,
+        notary_is_signatory: bool,
+        include_lock_fee_instruction: bool,
+    ) -> Self {
+        let guarantees_count = execution_summary
+            .deposits
+            .values()
+            .flatten()
+            .filter(|deposit| {
+                deposit.as_fungible().is_some_and(|(_, indicator)| {
+                    matches!(indicator, FungibleResourceIndicator::Predicted(_))
+                })
+            })
+            .count() as u64;
+
+        Self::new(
+            TransactionFeeCosts::new(
+                &execution_summary.fee_summary,
+                guarantees_count,
+                signatures_count,
+                notary_is_signatory,
+                include_lock_fee_instruction,
+            ),
+            execution_summary.fee_locks.clone(),
+        )
+    }
+
+    /// Switches to the normal breakdown, dropping any tip.
+    pub fn toggle_to_normal(&mut self) {
+        self.mode =
+            TransactionFeeMode::Normal(NormalTransactionFeeCustomization::new(
+                &self.costs,
+                &self.fee_locks,
+            ));
+    }
+
+    /// Switches to the advanced breakdown, with a tip of `tip_percentage`.
+    pub fn toggle_to_advanced(&mut self, tip_percentage: u16) {
+        self.mode = TransactionFeeMode::Advanced(
+            AdvancedTransactionFeeCustomization::new(
+                &self.costs,
+                &self.fee_locks,
+                tip_percentage,
+            ),
+        );
+    }
+
+    /// The amount of XRD to lock for the fee of the transaction.
+    pub fn total_fee(&self) -> Decimal192 {
+        match &self.mode {
+            TransactionFeeMode::Normal(normal) => normal.total(),
+            TransactionFeeMode::Advanced(advanced) => advanced.total(),
+        }
+    }
+}
+
+impl HasSampleValues for TransactionFee {
+    fn sample() -> Self {
+        Self::new(TransactionFeeCosts::sample(), FeeLocks::default())
+    }
+
+    fn sample_other() -> Self {
+        let mut sut = Self::new(
+            TransactionFeeCosts::sample_other(),
+            FeeLocks::sample_other(),
+        );
+        sut.toggle_to_advanced(10);
+        sut
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = TransactionFee;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn normal_total_fee_is_padded() {
+        let sut = SUT::sample();
+        // 0.31224930633 * 1.15
+        assert_eq!(
+            sut.total_fee(),
+            Decimal192::from_str("0.3590867022795").unwrap()
+        );
+        assert_eq!(
+            sut.mode.as_normal().unwrap().royalty_fee,
+            Decimal192::zero()
+        );
+    }
+
+    #[test]
+    fn normal_fee_paid_by_dapp_lock() {
+        let sut = SUT::new(TransactionFeeCosts::sample(), FeeLocks::new(1, 0));
+        let normal = sut.mode.as_normal().unwrap();
+        assert_eq!(normal.network_fee, Decimal192::zero());
+        assert_eq!(normal.royalty_fee, Decimal192::zero());
+        assert_eq!(sut.total_fee(), Decimal192::zero());
+    }
+
+    #[test]
+    fn normal_fee_remaining_lock_pays_royalties() {
+        let sut =
+            SUT::new(TransactionFeeCosts::sample_other(), FeeLocks::new(5, 0));
+        let normal = sut.mode.as_normal().unwrap();
+        assert_eq!(normal.network_fee, Decimal192::zero());
+        assert_eq!(
+            normal.royalty_fee,
+            Decimal192::from(10)
+                - (Decimal192::from(5)
+                    - sut.costs.network_cost()
+                        * Decimal192::from_str("1.15").unwrap())
+        );
+    }
+
+    #[test]
+    fn advanced_total_fee_with_tip() {
+        let mut sut = SUT::sample();
+        sut.toggle_to_advanced(0);
+        // Same total as normal mode without tip or lock by dApps
+        assert_eq!(
+            sut.total_fee(),
+            Decimal192::from_str("0.3590867022795").unwrap()
+        );
+
+        sut.toggle_to_advanced(10);
+        let advanced = sut.mode.as_advanced().unwrap();
+        // (0.20665634592 + 0.02100205) * 10%
+        assert_eq!(
+            advanced.tip_amount(),
+            Decimal192::from_str("0.022765839592").unwrap()
+        );
+        assert_eq!(
+            sut.total_fee(),
+            Decimal192::from_str("0.3818525418715").unwrap()
+        );
+    }
+
+    #[test]
+    fn advanced_subtracts_paid_by_dapps() {
+        let mut sut =
+            SUT::new(TransactionFeeCosts::sample(), FeeLocks::new(1, 0));
+        sut.toggle_to_advanced(0);
+        assert_eq!(sut.mode.as_advanced().unwrap().paid_by_dapps, 1.into());
+        assert_eq!(sut.total_fee(), Decimal192::zero());
+    }
+
+    #[test]
+    fn toggle_to_normal_drops_tip() {
+        let mut sut = SUT::sample();
+        sut.toggle_to_advanced(50);
+        sut.toggle_to_normal();
+        assert_eq!(sut, SUT::sample());
+    }
+
+    #[test]
+    fn from_execution_summary_counts_predicted_fungible_deposits() {
+        let mut summary = ExecutionSummary::sample();
+        summary.fee_summary = FeeSummary::sample();
+        summary.fee_locks = FeeLocks::default();
+        summary.deposits = [(
+            AccountAddress::sample(),
+            vec![
+                ResourceIndicator::fungible(
+                    ResourceAddress::sample(),
+                    FungibleResourceIndicator::new_predicted(1, 0),
+                ),
+                ResourceIndicator::fungible(
+                    ResourceAddress::sample_other(),
+                    FungibleResourceIndicator::new_guaranteed(1),
+                ),
+            ],
+        )]
+        .into_iter()
+        .collect();
+
+        let sut = SUT::new_from_execution_summary(&summary, 1, false, true);
+
+        assert_eq!(sut, SUT::sample());
+    }
+}
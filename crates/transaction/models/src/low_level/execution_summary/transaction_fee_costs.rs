@@ -0,0 +1,227 @@
+use crate::prelude::*;
+
+const NETWORK_FEE_MULTIPLIER: ScryptoDecimal192 = dec!("0.15");
+const LOCK_FEE_INSTRUCTION_COST: ScryptoDecimal192 = dec!("0.08581566997");
+const FUNGIBLE_GUARANTEE_INSTRUCTION_COST: ScryptoDecimal192 =
+    dec!("0.00908532837");
+const NOTARIZING_COST: ScryptoDecimal192 = dec!("0.0081566");
+const NOTARIZING_COST_WHEN_NOTARY_IS_SIGNATORY: ScryptoDecimal192 =
+    dec!("0.0084183");
+const SIGNATURE_COST: ScryptoDecimal192 = dec!("0.01109974758");
+
+/// Multiplier applied to the network costs to pad the fee, since the
+/// costs of the submitted transaction might differ from those of the
+/// preview.
+pub fn transaction_fee_network_fee_multiplier() -> Decimal192 {
+    NETWORK_FEE_MULTIPLIER.into()
+}
+
+/// Execution cost of the `lock_fee` instruction added by the wallet,
+/// which is not part of the previewed manifest.
+pub fn transaction_fee_lock_fee_instruction_cost() -> Decimal192 {
+    LOCK_FEE_INSTRUCTION_COST.into()
+}
+
+/// Execution cost of each fungible guarantee instruction added by the
+/// wallet, which are not part of the previewed manifest.
+pub fn transaction_fee_fungible_guarantee_instruction_cost() -> Decimal192 {
+    FUNGIBLE_GUARANTEE_INSTRUCTION_COST.into()
+}
+
+/// Execution cost of verifying the notary signature.
+pub fn transaction_fee_notarizing_cost() -> Decimal192 {
+    NOTARIZING_COST.into()
+}
+
+/// Execution cost of verifying the notary signature when the notary is
+/// a signatory, i.e. it signs the intent as well.
+pub fn transaction_fee_notarizing_cost_when_notary_is_signatory() -> Decimal192
+{
+    NOTARIZING_COST_WHEN_NOTARY_IS_SIGNATORY.into()
+}
+
+/// Execution cost of verifying each intent signature.
+pub fn transaction_fee_signature_cost() -> Decimal192 {
+    SIGNATURE_COST.into()
+}
+
+/// The costs of a transaction, the network costs of the preview, as found
+/// in the `FeeSummary`, plus the costs of what the preview does not include:
+/// the signatures, the notarization, the `lock_fee` instruction and the
+/// guarantee instructions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransactionFeeCosts {
+    pub execution_cost: Decimal192,
+    pub finalization_cost: Decimal192,
+    pub storage_expansion_cost: Decimal192,
+    pub royalty_cost: Decimal192,
+    pub guarantees_cost: Decimal192,
+    pub lock_fee_cost: Decimal192,
+    pub signatures_cost: Decimal192,
+    pub notarizing_cost: Decimal192,
+}
+
+impl TransactionFeeCosts {
+    /// Creates the costs of a transaction previewed with `fee_summary`, to
+    /// which `guarantees_count` fungible guarantee instructions are added,
+    /// signed by `signatures_count` signatures and notarized by a notary
+    /// which is a signatory if `notary_is_signatory`.
+    ///
+    /// The cost of the `lock_fee` instruction is only included if
+    /// `include_lock_fee_instruction`, since the preview of a manifest to
+    /// which it was already added includes it.
+    pub fn new(
+        fee_summary: &FeeSummary,
+        guarantees_count: u64,
+        signatures_count: u64,
+        notary_is_signatory: bool,
+        include_lock_fee_instruction: bool,
+    ) -> Self {
+        Self {
+            execution_cost: fee_summary.execution_cost,
+            finalization_cost: fee_summary.finalization_cost,
+            storage_expansion_cost: fee_summary.storage_expansion_cost,
+            royalty_cost: fee_summary.royalty_cost,
+            guarantees_cost: Decimal192::from(guarantees_count)
+                * transaction_fee_fungible_guarantee_instruction_cost(),
+            lock_fee_cost: if include_lock_fee_instruction {
+                transaction_fee_lock_fee_instruction_cost()
+            } else {
+                Decimal192::zero()
+            },
+            signatures_cost: Decimal192::from(signatures_count)
+                * transaction_fee_signature_cost(),
+            notarizing_cost: if notary_is_signatory {
+                transaction_fee_notarizing_cost_when_notary_is_signatory()
+            } else {
+                transaction_fee_notarizing_cost()
+            },
+        }
+    }
+
+    /// The execution cost including the costs not part of the preview.
+    pub fn total_execution_cost(&self) -> Decimal192 {
+        self.execution_cost
+            + self.guarantees_cost
+            + self.signatures_cost
+            + self.lock_fee_cost
+            + self.notarizing_cost
+    }
+
+    /// The costs paid to the network, i.e. all costs but royalties.
+    pub fn network_cost(&self) -> Decimal192 {
+        self.total_execution_cost()
+            + self.finalization_cost
+            + self.storage_expansion_cost
+    }
+
+    /// All costs, including royalties.
+    pub fn total_cost(&self) -> Decimal192 {
+        self.network_cost() + self.royalty_cost
+    }
+}
+
+impl HasSampleValues for TransactionFeeCosts {
+    fn sample() -> Self {
+        Self::new(&FeeSummary::sample(), 1, 1, false, true)
+    }
+
+    fn sample_other() -> Self {
+        Self::new(&FeeSummary::sample_other(), 0, 2, true, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = TransactionFeeCosts;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn cost_constants() {
+        assert_eq!(
+            transaction_fee_network_fee_multiplier().to_string(),
+            "0.15"
+        );
+        assert_eq!(
+            transaction_fee_lock_fee_instruction_cost().to_string(),
+            "0.08581566997"
+        );
+        assert_eq!(
+            transaction_fee_fungible_guarantee_instruction_cost().to_string(),
+            "0.00908532837"
+        );
+        assert_eq!(transaction_fee_notarizing_cost().to_string(), "0.0081566");
+        assert_eq!(
+            transaction_fee_notarizing_cost_when_notary_is_signatory()
+                .to_string(),
+            "0.0084183"
+        );
+        assert_eq!(
+            transaction_fee_signature_cost().to_string(),
+            "0.01109974758"
+        );
+    }
+
+    #[test]
+    fn costs_not_part_of_preview() {
+        let sut = SUT::new(&FeeSummary::sample(), 2, 3, false, true);
+        assert_eq!(
+            sut.guarantees_cost,
+            Decimal192::from_str("0.01817065674").unwrap()
+        );
+        assert_eq!(
+            sut.signatures_cost,
+            Decimal192::from_str("0.03329924274").unwrap()
+        );
+        assert_eq!(sut.notarizing_cost, transaction_fee_notarizing_cost());
+        assert_eq!(
+            sut.lock_fee_cost,
+            transaction_fee_lock_fee_instruction_cost()
+        );
+    }
+
+    #[test]
+    fn notary_is_signatory_without_lock_fee() {
+        let sut = SUT::new(&FeeSummary::sample(), 0, 0, true, false);
+        assert_eq!(
+            sut.notarizing_cost,
+            transaction_fee_notarizing_cost_when_notary_is_signatory()
+        );
+        assert_eq!(sut.lock_fee_cost, Decimal192::zero());
+        assert_eq!(sut.guarantees_cost, Decimal192::zero());
+        assert_eq!(sut.signatures_cost, Decimal192::zero());
+    }
+
+    #[test]
+    fn totals() {
+        let sut = SUT::sample();
+        // 0.092499 + 0.00908532837 + 0.01109974758 + 0.08581566997 + 0.0081566
+        assert_eq!(
+            sut.total_execution_cost(),
+            Decimal192::from_str("0.20665634592").unwrap()
+        );
+        // + 0.02100205 + 0.08459091041
+        assert_eq!(
+            sut.network_cost(),
+            Decimal192::from_str("0.31224930633").unwrap()
+        );
+        assert_eq!(sut.total_cost(), sut.network_cost());
+        assert_eq!(
+            SUT::sample_other().total_cost(),
+            SUT::sample_other().network_cost() + Decimal192::from(10)
+        );
+    }
+}
@@ -114,6 +114,18 @@ pub enum DetailedManifestClass {
     AccessControllerConfirmTimedRecovery {
         ac_addresses: Vec<AccessControllerAddress>,
     },
+
+    /// A manifest that locks the Primary role of the access controllers,
+    /// an emergency freeze signed with the Recovery role.
+    AccessControllerLockPrimaryRole {
+        ac_addresses: Vec<AccessControllerAddress>,
+    },
+
+    /// A manifest that unlocks the Primary role of the access controllers,
+    /// signed with the Recovery role.
+    AccessControllerUnlockPrimaryRole {
+        ac_addresses: Vec<AccessControllerAddress>,
+    },
 }
 
 impl DetailedManifestClass {
@@ -154,6 +166,12 @@ impl DetailedManifestClass {
             Self::AccessControllerStopTimedRecovery { .. } => {
                 DetailedManifestClassKind::AccessControllerStopTimedRecovery
             }
+            Self::AccessControllerLockPrimaryRole { .. } => {
+                DetailedManifestClassKind::AccessControllerLockPrimaryRole
+            }
+            Self::AccessControllerUnlockPrimaryRole { .. } => {
+                DetailedManifestClassKind::AccessControllerUnlockPrimaryRole
+            }
         }
     }
 
@@ -576,5 +594,17 @@ mod tests {
             },
             DetailedManifestClassKind::AccessControllerStopTimedRecovery,
         );
+        test(
+            SUT::AccessControllerLockPrimaryRole {
+                ac_addresses: Vec::<_>::sample(),
+            },
+            DetailedManifestClassKind::AccessControllerLockPrimaryRole,
+        );
+        test(
+            SUT::AccessControllerUnlockPrimaryRole {
+                ac_addresses: Vec::<_>::sample(),
+            },
+            DetailedManifestClassKind::AccessControllerUnlockPrimaryRole,
+        );
     }
 }
@@ -43,6 +43,12 @@ pub enum DetailedManifestClassKind {
 
     #[display("AccessControllerStopTimedRecovery")]
     AccessControllerStopTimedRecovery,
+
+    #[display("AccessControllerLockPrimaryRole")]
+    AccessControllerLockPrimaryRole,
+
+    #[display("AccessControllerUnlockPrimaryRole")]
+    AccessControllerUnlockPrimaryRole,
 }
 
 impl DetailedManifestClassKind {
@@ -0,0 +1,174 @@
+use crate::prelude::*;
+
+/// All decryptors of an `EncryptedMessage` whose public keys are on the same
+/// curve as `dh_ephemeral_public_key`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DecryptorsByCurve {
+    /// The public key of the ephemeral key pair used to perform key
+    /// agreement (ECDH) with each recipient on this curve.
+    pub dh_ephemeral_public_key: PublicKey,
+
+    /// The decryptors, one per recipient on this curve.
+    pub decryptors: Vec<MessageDecryptor>,
+}
+
+impl DecryptorsByCurve {
+    pub fn new(
+        dh_ephemeral_public_key: impl Into<PublicKey>,
+        decryptors: impl IntoIterator<Item = MessageDecryptor>,
+    ) -> Self {
+        Self {
+            dh_ephemeral_public_key: dh_ephemeral_public_key.into(),
+            decryptors: decryptors.into_iter().collect(),
+        }
+    }
+
+    pub fn curve(&self) -> SLIP10Curve {
+        self.dh_ephemeral_public_key.curve()
+    }
+
+    /// Returns the decryptor for the recipient with `public_key`, if any.
+    pub fn decryptor_for(
+        &self,
+        public_key: &PublicKey,
+    ) -> Option<&MessageDecryptor> {
+        if public_key.curve() != self.curve() {
+            return None;
+        }
+        let fingerprint = PublicKeyFingerprint::from(*public_key);
+        self.decryptors
+            .iter()
+            .find(|d| d.public_key_fingerprint == fingerprint)
+    }
+}
+
+impl From<DecryptorsByCurve> for ScryptoDecryptorsByCurve {
+    fn from(value: DecryptorsByCurve) -> Self {
+        let decryptors = value
+            .decryptors
+            .into_iter()
+            .map(|d| {
+                (
+                    ScryptoPublicKeyFingerprint::from(d.public_key_fingerprint),
+                    ScryptoAesWrapped128BitKey(*d.aes_wrapped_key.bytes()),
+                )
+            })
+            .collect();
+
+        match value.dh_ephemeral_public_key {
+            PublicKey::Ed25519(key) => Self::Ed25519 {
+                dh_ephemeral_public_key: key.into(),
+                decryptors,
+            },
+            PublicKey::Secp256k1(key) => Self::Secp256k1 {
+                dh_ephemeral_public_key: key.into(),
+                decryptors,
+            },
+        }
+    }
+}
+
+impl TryFrom<ScryptoDecryptorsByCurve> for DecryptorsByCurve {
+    type Error = CommonError;
+
+    fn try_from(value: ScryptoDecryptorsByCurve) -> Result<Self> {
+        let (dh_ephemeral_public_key, decryptors) = match value {
+            ScryptoDecryptorsByCurve::Ed25519 {
+                dh_ephemeral_public_key,
+                decryptors,
+            } => (
+                PublicKey::from(Ed25519PublicKey::try_from(
+                    dh_ephemeral_public_key,
+                )?),
+                decryptors,
+            ),
+            ScryptoDecryptorsByCurve::Secp256k1 {
+                dh_ephemeral_public_key,
+                decryptors,
+            } => (
+                PublicKey::from(Secp256k1PublicKey::try_from(
+                    dh_ephemeral_public_key,
+                )?),
+                decryptors,
+            ),
+        };
+
+        Ok(Self::new(
+            dh_ephemeral_public_key,
+            decryptors.into_iter().map(|(fingerprint, wrapped_key)| {
+                MessageDecryptor::new(
+                    fingerprint,
+                    Exactly24Bytes::from(&wrapped_key.0),
+                )
+            }),
+        ))
+    }
+}
+
+impl HasSampleValues for DecryptorsByCurve {
+    fn sample() -> Self {
+        Self::new(PublicKey::sample_ed25519(), [MessageDecryptor::sample()])
+    }
+
+    fn sample_other() -> Self {
+        Self::new(
+            PublicKey::sample_secp256k1(),
+            [MessageDecryptor::sample_other()],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = DecryptorsByCurve;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn curve() {
+        assert_eq!(SUT::sample().curve(), SLIP10Curve::Curve25519);
+        assert_eq!(SUT::sample_other().curve(), SLIP10Curve::Secp256k1);
+    }
+
+    #[test]
+    fn decryptor_for() {
+        let sut = SUT::new(
+            PublicKey::sample_ed25519_alice(),
+            [MessageDecryptor::new(
+                PublicKey::sample_ed25519_bob(),
+                Exactly24Bytes::sample(),
+            )],
+        );
+        assert_eq!(
+            sut.decryptor_for(&PublicKey::sample_ed25519_bob())
+                .map(|d| d.aes_wrapped_key),
+            Some(Exactly24Bytes::sample())
+        );
+        assert!(sut
+            .decryptor_for(&PublicKey::sample_ed25519_alice())
+            .is_none());
+        assert!(sut
+            .decryptor_for(&PublicKey::sample_secp256k1_bob())
+            .is_none());
+    }
+
+    #[test]
+    fn to_from_scrypto() {
+        let roundtrip =
+            |s: SUT| SUT::try_from(ScryptoDecryptorsByCurve::from(s)).unwrap();
+        assert_eq!(roundtrip(SUT::sample()), SUT::sample());
+        assert_eq!(roundtrip(SUT::sample_other()), SUT::sample_other());
+    }
+}
@@ -0,0 +1,429 @@
+use crate::prelude::*;
+use encryption::{
+    AesGcm256, AesKeyWrap256, EncryptionKey, PbHkdfSha256, VersionedEncryption,
+};
+use radix_common::prelude::{
+    manifest_decode as Scrypto_manifest_decode,
+    manifest_encode as Scrypto_manifest_encode,
+};
+
+/// A transaction message which only the recipients, i.e. the owners of the
+/// public keys it was encrypted for, can read.
+///
+/// The manifest SBOR encoded `PlaintextMessage` is encrypted with AES-GCM-256
+/// using a key derived from a random 128 bit message key. The message key is
+/// in turn AES key wrapped once per recipient, using a key derived from the
+/// Diffie-Hellman shared secret between an ephemeral key (one per curve) and
+/// the public key of the recipient.
+///
+/// # Key derivation
+/// Both keys are derived with HKDF-SHA256 (RFC 5869) into 32 bytes:
+/// * payload key: `ikm` is the 16 byte message key, no salt and `info` is
+///   `"RADIX_MESSAGE_PAYLOAD_KEY"`.
+/// * key encryption key: `ikm` is the 32 byte Diffie-Hellman shared secret -
+///   X25519 of the Ed25519 keys converted to Montgomery form, or the
+///   x-coordinate of the secp256k1 ECDH point - `salt` is the ephemeral
+///   public key bytes followed by the recipient public key bytes (compressed
+///   for secp256k1) and `info` is `"RADIX_MESSAGE_KEY_ENCRYPTION_KEY"`.
+///
+/// The message key is wrapped with AES Key Wrap (RFC 3394) into 24 bytes,
+/// and `encrypted` is the 12 byte nonce followed by the cipher text and the
+/// 16 byte tag.
+///
+/// The vectors in `fixtures/vector/encrypted_transaction_messages.json` were
+/// generated by this implementation, they guard against regressions of this
+/// scheme but do not prove interoperability with other implementations.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EncryptedMessage {
+    /// The AES-GCM-256 sealed box - nonce, cipher text and tag - of the
+    /// manifest SBOR encoded `PlaintextMessage`.
+    pub encrypted: BagOfBytes,
+
+    /// The decryptors of this message grouped by curve, at most one entry
+    /// per curve.
+    pub decryptors_by_curve: Vec<DecryptorsByCurve>,
+}
+
+impl EncryptedMessage {
+    const INFO_PAYLOAD_KEY: &'static [u8] = b"RADIX_MESSAGE_PAYLOAD_KEY";
+    const INFO_KEY_ENCRYPTION_KEY: &'static [u8] =
+        b"RADIX_MESSAGE_KEY_ENCRYPTION_KEY";
+
+    /// Encrypts `plaintext` so that it can be decrypted by the owner of any
+    /// of the `recipients` public keys, typically the owner keys of the
+    /// accounts receiving a transfer.
+    pub fn encrypting(
+        plaintext: PlaintextMessage,
+        recipients: impl IntoIterator<Item = PublicKey>,
+    ) -> Result<Self> {
+        let recipients = recipients.into_iter().collect::<IndexSet<_>>();
+        if recipients.is_empty() {
+            return Err(CommonError::EncryptedMessageWithoutRecipients);
+        }
+
+        let message_key = Exactly16Bytes::generate();
+
+        let ed25519_recipients = recipients
+            .iter()
+            .filter_map(|k| k.as_ed25519().cloned())
+            .collect_vec();
+        let secp256k1_recipients = recipients
+            .iter()
+            .filter_map(|k| k.as_secp256k1().cloned())
+            .collect_vec();
+
+        let mut decryptors_by_curve = Vec::new();
+        if !ed25519_recipients.is_empty() {
+            let ephemeral = Ed25519PrivateKey::generate();
+            let decryptors = ed25519_recipients
+                .into_iter()
+                .map(|recipient| {
+                    let shared_secret = ephemeral
+                        .shared_secret_from_key_agreement(&recipient)?;
+                    Ok(Self::wrap_message_key(
+                        &message_key,
+                        shared_secret,
+                        PublicKey::from(ephemeral.public_key()),
+                        PublicKey::from(recipient),
+                    ))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            decryptors_by_curve.push(DecryptorsByCurve::new(
+                ephemeral.public_key(),
+                decryptors,
+            ));
+        }
+        if !secp256k1_recipients.is_empty() {
+            let ephemeral = Secp256k1PrivateKey::generate();
+            let decryptors =
+                secp256k1_recipients.into_iter().map(|recipient| {
+                    Self::wrap_message_key(
+                        &message_key,
+                        ephemeral.shared_secret_from_key_agreement(&recipient),
+                        PublicKey::from(ephemeral.public_key()),
+                        PublicKey::from(recipient),
+                    )
+                });
+            decryptors_by_curve.push(DecryptorsByCurve::new(
+                ephemeral.public_key(),
+                decryptors,
+            ));
+        }
+
+        let payload =
+            Scrypto_manifest_encode(&ScryptoPlaintextMessage::from(plaintext))
+                .expect("Should always be able to encode a PlaintextMessage");
+        let encrypted = AesGcm256::default()
+            .encrypt(payload, &mut Self::payload_key(&message_key));
+
+        Ok(Self {
+            encrypted: BagOfBytes::from(encrypted),
+            decryptors_by_curve,
+        })
+    }
+
+    /// Returns `true` if this message has a decryptor for `public_key`.
+    pub fn can_be_decrypted_by(&self, public_key: &PublicKey) -> bool {
+        self.decryptors_by_curve
+            .iter()
+            .any(|d| d.decryptor_for(public_key).is_some())
+    }
+
+    /// Decrypts this message using `private_key`, which must be the private
+    /// key of one of the recipients.
+    pub fn decrypt(
+        &self,
+        private_key: &PrivateKey,
+    ) -> Result<PlaintextMessage> {
+        let public_key = private_key.public_key();
+        let (decryptors, decryptor) = self
+            .decryptors_by_curve
+            .iter()
+            .find_map(|d| d.decryptor_for(&public_key).map(|x| (d, x)))
+            .ok_or(CommonError::EncryptedMessageNoDecryptorForPublicKey {
+                public_key: public_key.to_hex(),
+            })?;
+
+        let ephemeral = decryptors.dh_ephemeral_public_key;
+        let shared_secret = match (private_key, ephemeral) {
+            (PrivateKey::Ed25519(private_key), PublicKey::Ed25519(other)) => {
+                private_key.shared_secret_from_key_agreement(&other)?
+            }
+            (
+                PrivateKey::Secp256k1(private_key),
+                PublicKey::Secp256k1(other),
+            ) => private_key.shared_secret_from_key_agreement(&other),
+            _ => unreachable!("Decryptor found only on the same curve."),
+        };
+
+        let message_key = AesKeyWrap256::unwrap(
+            &decryptor.aes_wrapped_key,
+            &Self::key_encryption_key(shared_secret, ephemeral, public_key),
+        )
+        .map_err(|_| CommonError::FailedToDecryptEncryptedMessage)?;
+
+        let payload = AesGcm256::default()
+            .decrypt(
+                self.encrypted.bytes(),
+                &mut Self::payload_key(&message_key),
+            )
+            .map_err(|_| CommonError::FailedToDecryptEncryptedMessage)?;
+
+        Scrypto_manifest_decode::<ScryptoPlaintextMessage>(&payload)
+            .map(PlaintextMessage::from)
+            .map_err(|_| CommonError::FailedToDecryptEncryptedMessage)
+    }
+}
+
+impl EncryptedMessage {
+    fn payload_key(message_key: &Exactly16Bytes) -> EncryptionKey {
+        EncryptionKey::from(PbHkdfSha256::derive_key(
+            message_key,
+            None,
+            Self::INFO_PAYLOAD_KEY,
+        ))
+    }
+
+    /// The key used to wrap the message key for `recipient`, the ephemeral
+    /// and recipient public keys are used as salt to bind the key to them.
+    fn key_encryption_key(
+        shared_secret: Exactly32Bytes,
+        ephemeral: PublicKey,
+        recipient: PublicKey,
+    ) -> EncryptionKey {
+        let salt = [ephemeral.to_bytes(), recipient.to_bytes()].concat();
+        EncryptionKey::from(PbHkdfSha256::derive_key(
+            shared_secret,
+            salt.as_slice(),
+            Self::INFO_KEY_ENCRYPTION_KEY,
+        ))
+    }
+
+    fn wrap_message_key(
+        message_key: &Exactly16Bytes,
+        shared_secret: Exactly32Bytes,
+        ephemeral: PublicKey,
+        recipient: PublicKey,
+    ) -> MessageDecryptor {
+        let key_encryption_key =
+            Self::key_encryption_key(shared_secret, ephemeral, recipient);
+        MessageDecryptor::new(
+            recipient,
+            AesKeyWrap256::wrap(message_key, &key_encryption_key),
+        )
+    }
+}
+
+impl From<EncryptedMessage> for ScryptoEncryptedMessage {
+    fn from(value: EncryptedMessage) -> Self {
+        Self {
+            encrypted: ScryptoAesGcmPayload(value.encrypted.to_vec()),
+            decryptors_by_curve: value
+                .decryptors_by_curve
+                .into_iter()
+                .map(|d| {
+                    let curve = match d.curve() {
+                        SLIP10Curve::Curve25519 => ScryptoCurveType::Ed25519,
+                        SLIP10Curve::Secp256k1 => ScryptoCurveType::Secp256k1,
+                    };
+                    (curve, ScryptoDecryptorsByCurve::from(d))
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<ScryptoEncryptedMessage> for EncryptedMessage {
+    type Error = CommonError;
+
+    fn try_from(value: ScryptoEncryptedMessage) -> Result<Self> {
+        let decryptors_by_curve = value
+            .decryptors_by_curve
+            .into_values()
+            .map(DecryptorsByCurve::try_from)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            encrypted: BagOfBytes::from(value.encrypted.0),
+            decryptors_by_curve,
+        })
+    }
+}
+
+impl HasSampleValues for EncryptedMessage {
+    fn sample() -> Self {
+        Self {
+            encrypted: BagOfBytes::sample_aced(),
+            decryptors_by_curve: vec![DecryptorsByCurve::sample()],
+        }
+    }
+
+    fn sample_other() -> Self {
+        Self {
+            encrypted: BagOfBytes::sample_babe(),
+            decryptors_by_curve: vec![
+                DecryptorsByCurve::sample(),
+                DecryptorsByCurve::sample_other(),
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = EncryptedMessage;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn encrypt_decrypt_ed25519() {
+        let alice = Ed25519PrivateKey::sample_alice();
+        let sut = SUT::encrypting(
+            PlaintextMessage::sample(),
+            [PublicKey::from(alice.public_key())],
+        )
+        .unwrap();
+        assert_eq!(
+            sut.decrypt(&PrivateKey::from(alice)),
+            Ok(PlaintextMessage::sample())
+        );
+    }
+
+    #[test]
+    fn encrypt_decrypt_secp256k1() {
+        let alice = Secp256k1PrivateKey::sample_alice();
+        let sut = SUT::encrypting(
+            PlaintextMessage::sample(),
+            [PublicKey::from(alice.public_key())],
+        )
+        .unwrap();
+        assert_eq!(
+            sut.decrypt(&PrivateKey::from(alice)),
+            Ok(PlaintextMessage::sample())
+        );
+    }
+
+    #[test]
+    fn encrypt_for_many_recipients_on_both_curves() {
+        let recipients: Vec<PrivateKey> = vec![
+            Ed25519PrivateKey::sample_alice().into(),
+            Ed25519PrivateKey::sample_bob().into(),
+            Secp256k1PrivateKey::sample_alice().into(),
+            Secp256k1PrivateKey::sample_bob().into(),
+        ];
+        let sut = SUT::encrypting(
+            PlaintextMessage::sample_other(),
+            recipients.iter().map(|k| k.public_key()),
+        )
+        .unwrap();
+
+        assert_eq!(sut.decryptors_by_curve.len(), 2);
+        for recipient in recipients {
+            assert!(sut.can_be_decrypted_by(&recipient.public_key()));
+            assert_eq!(
+                sut.decrypt(&recipient),
+                Ok(PlaintextMessage::sample_other())
+            );
+        }
+    }
+
+    #[test]
+    fn encrypt_binary_message() {
+        let alice = Ed25519PrivateKey::sample_alice();
+        let sut = SUT::encrypting(
+            PlaintextMessage::sample_binary(),
+            [PublicKey::from(alice.public_key())],
+        )
+        .unwrap();
+        assert_eq!(
+            sut.decrypt(&PrivateKey::from(alice)),
+            Ok(PlaintextMessage::sample_binary())
+        );
+    }
+
+    #[test]
+    fn duplicate_recipients_are_removed() {
+        let sut = SUT::encrypting(
+            PlaintextMessage::sample(),
+            [PublicKey::sample_ed25519(), PublicKey::sample_ed25519()],
+        )
+        .unwrap();
+        assert_eq!(sut.decryptors_by_curve[0].decryptors.len(), 1);
+    }
+
+    #[test]
+    fn encrypt_without_recipients_fails() {
+        assert_eq!(
+            SUT::encrypting(PlaintextMessage::sample(), []),
+            Err(CommonError::EncryptedMessageWithoutRecipients)
+        );
+    }
+
+    #[test]
+    fn decrypt_by_non_recipient_fails() {
+        let sut = SUT::encrypting(
+            PlaintextMessage::sample(),
+            [PublicKey::from(
+                Ed25519PrivateKey::sample_alice().public_key(),
+            )],
+        )
+        .unwrap();
+        let bob = PrivateKey::from(Ed25519PrivateKey::sample_bob());
+        assert!(!sut.can_be_decrypted_by(&bob.public_key()));
+        assert_eq!(
+            sut.decrypt(&bob),
+            Err(CommonError::EncryptedMessageNoDecryptorForPublicKey {
+                public_key: bob.public_key().to_hex()
+            })
+        );
+    }
+
+    #[test]
+    fn decrypt_tampered_fails() {
+        let alice = Ed25519PrivateKey::sample_alice();
+        let mut sut = SUT::encrypting(
+            PlaintextMessage::sample(),
+            [PublicKey::from(alice.public_key())],
+        )
+        .unwrap();
+        let mut bytes = sut.encrypted.to_vec();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xff;
+        sut.encrypted = BagOfBytes::from(bytes);
+        assert_eq!(
+            sut.decrypt(&PrivateKey::from(alice)),
+            Err(CommonError::FailedToDecryptEncryptedMessage)
+        );
+    }
+
+    #[test]
+    fn to_from_scrypto() {
+        let alice = Ed25519PrivateKey::sample_alice();
+        let sut = SUT::encrypting(
+            PlaintextMessage::sample(),
+            [
+                PublicKey::from(alice.public_key()),
+                PublicKey::sample_secp256k1(),
+            ],
+        )
+        .unwrap();
+        let roundtrip =
+            SUT::try_from(ScryptoEncryptedMessage::from(sut.clone())).unwrap();
+        assert_eq!(roundtrip, sut);
+        assert_eq!(
+            roundtrip.decrypt(&PrivateKey::from(alice)),
+            Ok(PlaintextMessage::sample())
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use crate::prelude::*;
+
+/// The message key of an `EncryptedMessage` wrapped for a single recipient,
+/// identified by the fingerprint of their public key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MessageDecryptor {
+    /// Fingerprint of the public key of the recipient.
+    pub public_key_fingerprint: PublicKeyFingerprint,
+
+    /// The 128 bit message key, AES key wrapped (RFC 3394) with a key
+    /// derived from the Diffie-Hellman shared secret between the ephemeral
+    /// key and the public key of the recipient.
+    pub aes_wrapped_key: Exactly24Bytes,
+}
+
+impl MessageDecryptor {
+    pub fn new(
+        public_key_fingerprint: impl Into<PublicKeyFingerprint>,
+        aes_wrapped_key: Exactly24Bytes,
+    ) -> Self {
+        Self {
+            public_key_fingerprint: public_key_fingerprint.into(),
+            aes_wrapped_key,
+        }
+    }
+}
+
+impl HasSampleValues for MessageDecryptor {
+    fn sample() -> Self {
+        Self::new(PublicKeyFingerprint::sample(), Exactly24Bytes::sample())
+    }
+
+    fn sample_other() -> Self {
+        Self::new(
+            PublicKeyFingerprint::sample_other(),
+            Exactly24Bytes::sample_other(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = MessageDecryptor;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+}
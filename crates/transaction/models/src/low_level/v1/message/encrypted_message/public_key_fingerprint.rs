@@ -0,0 +1,82 @@
+use crate::prelude::*;
+
+/// The last 8 bytes of the Blake2b-256 hash of the public key bytes, used to
+/// identify which decryptor of an `EncryptedMessage` belongs to which
+/// recipient, without revealing the public key of the recipient.
+#[derive(
+    Clone, Copy, PartialEq, Eq, Hash, derive_more::Display, derive_more::Debug,
+)]
+#[display("{}", self.bytes)]
+#[debug("{}", self.bytes)]
+pub struct PublicKeyFingerprint {
+    pub bytes: Exactly8Bytes,
+}
+
+impl From<PublicKey> for PublicKeyFingerprint {
+    fn from(value: PublicKey) -> Self {
+        let hash = hash_of(value.to_bytes()).bytes();
+        let last_8 = &hash[hash.len() - 8..];
+        Self {
+            bytes: Exactly8Bytes::try_from(last_8)
+                .expect("Blake2b-256 hash is 32 bytes, last 8 bytes is 8."),
+        }
+    }
+}
+
+impl From<PublicKeyFingerprint> for ScryptoPublicKeyFingerprint {
+    fn from(value: PublicKeyFingerprint) -> Self {
+        Self(*value.bytes.bytes())
+    }
+}
+
+impl From<ScryptoPublicKeyFingerprint> for PublicKeyFingerprint {
+    fn from(value: ScryptoPublicKeyFingerprint) -> Self {
+        Self {
+            bytes: Exactly8Bytes::from(&value.0),
+        }
+    }
+}
+
+impl HasSampleValues for PublicKeyFingerprint {
+    fn sample() -> Self {
+        Self::from(PublicKey::sample())
+    }
+
+    fn sample_other() -> Self {
+        Self::from(PublicKey::sample_other())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = PublicKeyFingerprint;
+
+    #[test]
+    fn equality() {
+        assert_eq!(SUT::sample(), SUT::sample());
+        assert_eq!(SUT::sample_other(), SUT::sample_other());
+    }
+
+    #[test]
+    fn inequality() {
+        assert_ne!(SUT::sample(), SUT::sample_other());
+    }
+
+    #[test]
+    fn is_suffix_of_hash_of_public_key() {
+        let public_key = PublicKey::sample_ed25519_alice();
+        let hash = hash_of(public_key.to_bytes()).to_string();
+        assert!(hash.ends_with(&SUT::from(public_key).to_string()));
+    }
+
+    #[test]
+    fn to_from_scrypto() {
+        let roundtrip =
+            |s: SUT| SUT::from(ScryptoPublicKeyFingerprint::from(s));
+        assert_eq!(roundtrip(SUT::sample()), SUT::sample());
+        assert_eq!(roundtrip(SUT::sample_other()), SUT::sample_other());
+    }
+}
@@ -3,6 +3,7 @@ use crate::prelude::*;
 #[derive(Clone, Debug, PartialEq, EnumAsInner, Eq, Hash)]
 pub enum Message {
     PlainText { plaintext: PlaintextMessage },
+    Encrypted { encrypted: EncryptedMessage },
     None,
 }
 
@@ -12,13 +13,37 @@ impl Message {
             plaintext: PlaintextMessage::new(message),
         }
     }
+
+    /// Encrypts `message` so that it can only be read by the owners of the
+    /// `recipients` public keys, e.g. the accounts receiving a transfer.
+    pub fn encrypted(
+        message: impl AsRef<str>,
+        recipients: impl IntoIterator<Item = PublicKey>,
+    ) -> Result<Self> {
+        EncryptedMessage::encrypting(PlaintextMessage::new(message), recipients)
+            .map(|encrypted| Self::Encrypted { encrypted })
+    }
 }
 
 impl Message {
     pub fn as_plaintext(&self) -> Option<String> {
         match self {
             Message::PlainText { plaintext } => plaintext.as_string(),
-            Message::None => None,
+            Message::Encrypted { .. } | Message::None => None,
+        }
+    }
+
+    /// Decrypts this message using `private_key`, fails if this is not an
+    /// encrypted message or if `private_key` is not one of its recipients.
+    pub fn decrypt(
+        &self,
+        private_key: &PrivateKey,
+    ) -> Result<PlaintextMessage> {
+        match self {
+            Message::Encrypted { encrypted } => encrypted.decrypt(private_key),
+            Message::PlainText { .. } | Message::None => {
+                Err(CommonError::FailedToDecryptEncryptedMessage)
+            }
         }
     }
 }
@@ -29,6 +54,9 @@ impl From<Message> for ScryptoMessage {
             Message::PlainText { plaintext } => {
                 ScryptoMessage::Plaintext(plaintext.into())
             }
+            Message::Encrypted { encrypted } => {
+                ScryptoMessage::Encrypted(encrypted.into())
+            }
             Message::None => ScryptoMessage::None,
         }
     }
@@ -43,9 +71,9 @@ impl TryFrom<ScryptoMessage> for Message {
             ScryptoMessage::Plaintext(p) => Ok(Self::PlainText {
                 plaintext: p.into(),
             }),
-            ScryptoMessage::Encrypted(_) => {
-                Err(CommonError::EncryptedMessagesAreNotYetSupported)
-            }
+            ScryptoMessage::Encrypted(e) => Ok(Self::Encrypted {
+                encrypted: e.try_into()?,
+            }),
         }
     }
 }
@@ -64,8 +92,6 @@ impl HasSampleValues for Message {
 mod tests {
     use super::*;
 
-    use radix_transactions::model::EncryptedMessageV1;
-
     #[allow(clippy::upper_case_acronyms)]
     type SUT = Message;
 
@@ -118,14 +144,44 @@ mod tests {
     }
 
     #[test]
-    fn encrypted_msg_are_not_yet_supported() {
-        let dummy = EncryptedMessageV1 {
-            encrypted: radix_transactions::prelude::AesGcmPayload(vec![]),
-            decryptors_by_curve: [].into(),
+    fn encrypted_to_from_scrypto() {
+        let sut = SUT::Encrypted {
+            encrypted: EncryptedMessage::sample(),
+        };
+        assert_eq!(
+            SUT::try_from(ScryptoMessage::from(sut.clone())).unwrap(),
+            sut
+        );
+    }
+
+    #[test]
+    fn encrypted_as_plaintext_is_none() {
+        let sut = SUT::Encrypted {
+            encrypted: EncryptedMessage::sample(),
         };
+        assert_eq!(sut.as_plaintext(), None);
+    }
+
+    #[test]
+    fn encrypted_decrypt() {
+        let private_key = PrivateKey::from(Ed25519PrivateKey::sample_alice());
+        let sut = SUT::encrypted(
+            "Hello Radix!",
+            [private_key.public_key(), PublicKey::sample_secp256k1()],
+        )
+        .unwrap();
+        assert_eq!(
+            sut.decrypt(&private_key).unwrap().as_string(),
+            Some("Hello Radix!".to_owned())
+        );
+    }
+
+    #[test]
+    fn decrypt_plaintext_fails() {
+        let private_key = PrivateKey::from(Ed25519PrivateKey::sample_alice());
         assert_eq!(
-            TryInto::<SUT>::try_into(ScryptoMessage::Encrypted(dummy)),
-            Err(CommonError::EncryptedMessagesAreNotYetSupported)
+            SUT::sample().decrypt(&private_key),
+            Err(CommonError::FailedToDecryptEncryptedMessage)
         );
     }
 }
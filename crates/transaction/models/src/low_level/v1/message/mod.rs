@@ -1,5 +1,7 @@
+mod encrypted_message;
 mod message;
 mod plaintext_message;
 
+pub use encrypted_message::*;
 pub use message::*;
 pub use plaintext_message::*;
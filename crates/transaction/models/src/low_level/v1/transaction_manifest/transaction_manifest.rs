@@ -5,8 +5,10 @@ use radix_transactions::manifest::CallMethod;
 use radix_engine_interface::blueprints::access_controller::{
     ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_PRIMARY_IDENT as SCRYPTO_ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_PRIMARY_IDENT,
     ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_RECOVERY_IDENT as SCRYPTO_ACCESS_CONTROLLER_INITIATE_RECOVERY_AS_RECOVERY_IDENT,
+    ACCESS_CONTROLLER_LOCK_PRIMARY_ROLE_IDENT as SCRYPTO_ACCESS_CONTROLLER_LOCK_PRIMARY_ROLE_IDENT,
     ACCESS_CONTROLLER_QUICK_CONFIRM_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT as SCRYPTO_ACCESS_CONTROLLER_QUICK_CONFIRM_PRIMARY_ROLE_RECOVERY_PROPOSAL_IDENT,
     ACCESS_CONTROLLER_QUICK_CONFIRM_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT as SCRYPTO_ACCESS_CONTROLLER_QUICK_CONFIRM_RECOVERY_ROLE_RECOVERY_PROPOSAL_IDENT,
+    ACCESS_CONTROLLER_UNLOCK_PRIMARY_ROLE_IDENT as SCRYPTO_ACCESS_CONTROLLER_UNLOCK_PRIMARY_ROLE_IDENT,
 };
 
 use radix_engine_interface::blueprints::account::ACCOUNT_LOCK_FEE_IDENT as SCRYPTO_ACCOUNT_LOCK_FEE_IDENT;
@@ -93,6 +95,45 @@ impl TransactionManifest {
         None
     }
 
+    /// The access controllers whose Primary role is locked by this manifest.
+    pub fn access_controllers_locking_primary_role(
+        &self,
+    ) -> Vec<AccessControllerAddress> {
+        self.access_controllers_calling_method(
+            SCRYPTO_ACCESS_CONTROLLER_LOCK_PRIMARY_ROLE_IDENT,
+        )
+    }
+
+    /// The access controllers whose Primary role is unlocked by this manifest.
+    pub fn access_controllers_unlocking_primary_role(
+        &self,
+    ) -> Vec<AccessControllerAddress> {
+        self.access_controllers_calling_method(
+            SCRYPTO_ACCESS_CONTROLLER_UNLOCK_PRIMARY_ROLE_IDENT,
+        )
+    }
+
+    fn access_controllers_calling_method(
+        &self,
+        ident: &str,
+    ) -> Vec<AccessControllerAddress> {
+        self.instructions()
+            .iter()
+            .filter_map(|inst| match inst {
+                ScryptoInstruction::CallMethod(method)
+                    if method.method_name == ident =>
+                {
+                    AccessControllerAddress::try_from((
+                        method.address,
+                        self.network_id(),
+                    ))
+                    .ok()
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
     fn has_method_for_ident(&self, ident: &str) -> bool {
         self.instructions().iter().any(|inst| match inst {
             ScryptoInstruction::CallMethod(method) => {
@@ -498,6 +539,43 @@ DROP_AUTH_ZONE_PROOFS;
         );
     }
 
+    #[test]
+    fn access_controllers_locking_and_unlocking_primary_role() {
+        let ac_address = AccessControllerAddress::from_str("accesscontroller_rdx1c0duj4lq0dc3cpl8qd420fpn5eckh8ljeysvjm894lyl5ja5yq6y5a").unwrap();
+        let lock = SUT::new(
+            r#"CALL_METHOD
+            Address("accesscontroller_rdx1c0duj4lq0dc3cpl8qd420fpn5eckh8ljeysvjm894lyl5ja5yq6y5a")
+            "lock_primary_role";
+            "#,
+            NetworkID::Mainnet,
+            Blobs::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            lock.access_controllers_locking_primary_role(),
+            vec![ac_address]
+        );
+        assert!(lock.access_controllers_unlocking_primary_role().is_empty());
+
+        let unlock = SUT::new(
+            r#"CALL_METHOD
+            Address("accesscontroller_rdx1c0duj4lq0dc3cpl8qd420fpn5eckh8ljeysvjm894lyl5ja5yq6y5a")
+            "unlock_primary_role";
+            "#,
+            NetworkID::Mainnet,
+            Blobs::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            unlock.access_controllers_unlocking_primary_role(),
+            vec![ac_address]
+        );
+        assert!(unlock.access_controllers_locking_primary_role().is_empty());
+        assert!(SUT::sample()
+            .access_controllers_locking_primary_role()
+            .is_empty());
+    }
+
     #[test]
     fn network_id() {
         assert_eq!(SUT::sample().network_id(), NetworkID::Mainnet);
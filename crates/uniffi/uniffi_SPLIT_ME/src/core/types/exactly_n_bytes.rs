@@ -134,3 +134,15 @@ decl_exactly_n_bytes!(
     /// source. 32 bytes mnemonic when encrypted results in exactly this length.
     60
 );
+
+decl_exactly_n_bytes!(
+    /// 8 bytes, used as fingerprint of a public key, e.g. to identify the
+    /// decryptor of an encrypted transaction message.
+    8
+);
+
+decl_exactly_n_bytes!(
+    /// 24 bytes, used by AES key wrapped (RFC 3394) 128 bit keys, e.g. the
+    /// per decryptor wrapped key of an encrypted transaction message.
+    24
+);
@@ -0,0 +1,78 @@
+use crate::prelude::*;
+use sargon::BIP39Word as InternalBIP39Word;
+use sargon::FactorSourceIDFromHash as InternalFactorSourceIDFromHash;
+use sargon::MnemonicInputAssistant as InternalMnemonicInputAssistant;
+use sargon::MnemonicWithPassphrase as InternalMnemonicWithPassphrase;
+
+/// The words of the word list of `language` starting with `prefix`, used to
+/// autocomplete a word of a mnemonic being typed by the user.
+#[uniffi::export]
+pub fn mnemonic_input_autocomplete(
+    prefix: String,
+    language: BIP39Language,
+) -> Vec<BIP39Word> {
+    InternalMnemonicInputAssistant::new(language.into_internal())
+        .autocomplete(prefix)
+        .into_type()
+}
+
+/// The words of the word list of `language` closest to `word`, closest
+/// first, used to suggest corrections of a mistyped word.
+#[uniffi::export]
+pub fn mnemonic_input_suggestions(
+    word: String,
+    language: BIP39Language,
+) -> Vec<BIP39Word> {
+    InternalMnemonicInputAssistant::new(language.into_internal())
+        .suggestions(word)
+        .into_type()
+}
+
+/// Valid mnemonics found by replacing a single word of `words`, or by
+/// swapping two adjacent words, for `words` failing the checksum.
+#[uniffi::export]
+pub fn mnemonic_input_checksum_repairs(
+    words: Vec<BIP39Word>,
+    language: BIP39Language,
+) -> Result<Vec<Mnemonic>> {
+    let words: Vec<InternalBIP39Word> = words.into_internal();
+    InternalMnemonicInputAssistant::new(language.into_internal())
+        .checksum_repairs(&words)
+        .into_iter_result()
+}
+
+/// The mnemonic, if any, found by replacing a single word of `words`, or by
+/// swapping two adjacent words, which together with `passphrase` is the
+/// mnemonic of the factor source identified by `factor_source_id`.
+///
+/// Async since deriving the id of every candidate mnemonic is too slow to be
+/// done on the calling thread, the search runs on a blocking task instead.
+#[uniffi::export]
+pub async fn mnemonic_input_checksum_repair_for_factor_source(
+    words: Vec<BIP39Word>,
+    language: BIP39Language,
+    passphrase: BIP39Passphrase,
+    factor_source_id: FactorSourceIDFromHash,
+) -> Result<Option<Mnemonic>> {
+    let factor_source_id: InternalFactorSourceIDFromHash =
+        factor_source_id.into_internal();
+    let passphrase = passphrase.into_internal();
+    let words: Vec<InternalBIP39Word> = words.into_internal();
+    let language = language.into_internal();
+    async_std::task::spawn_blocking(move || {
+        InternalMnemonicInputAssistant::new(language).checksum_repair_matching(
+            &words,
+            |mnemonic| {
+                factor_source_id.is_id_of(
+                    &InternalMnemonicWithPassphrase::with_passphrase(
+                        mnemonic.clone(),
+                        passphrase.clone(),
+                    ),
+                )
+            },
+        )
+    })
+    .await
+    .map(|mnemonic| mnemonic.map(Mnemonic::from))
+    .into_result()
+}
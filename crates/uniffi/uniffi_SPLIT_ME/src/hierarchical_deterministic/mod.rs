@@ -3,9 +3,11 @@ mod bip39;
 mod bip44;
 mod cap26;
 mod derivation;
+mod slip39;
 
 pub use bip32::*;
 pub use bip39::*;
 pub use bip44::*;
 pub use cap26::*;
 pub use derivation::*;
+pub use slip39::*;
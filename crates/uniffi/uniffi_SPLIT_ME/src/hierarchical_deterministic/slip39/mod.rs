@@ -0,0 +1,5 @@
+mod slip39_group;
+mod slip39_shares;
+
+pub use slip39_group::*;
+pub use slip39_shares::*;
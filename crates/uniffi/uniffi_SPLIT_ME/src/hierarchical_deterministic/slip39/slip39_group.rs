@@ -0,0 +1,22 @@
+use crate::prelude::*;
+use sargon::SLIP39Group as InternalSLIP39Group;
+
+/// The configuration of one group of SLIP39 shares, `member_threshold` of
+/// its `member_count` shares being required to recover the group secret.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, InternalConversion, uniffi::Record,
+)]
+pub struct SLIP39Group {
+    pub member_threshold: u8,
+    pub member_count: u8,
+}
+
+#[uniffi::export]
+pub fn new_slip39_group_sample() -> SLIP39Group {
+    InternalSLIP39Group::sample().into()
+}
+
+#[uniffi::export]
+pub fn new_slip39_group_sample_other() -> SLIP39Group {
+    InternalSLIP39Group::sample_other().into()
+}
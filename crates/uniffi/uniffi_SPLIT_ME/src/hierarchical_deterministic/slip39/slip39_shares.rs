@@ -0,0 +1,49 @@
+use crate::prelude::*;
+use sargon::MnemonicWithPassphrase as InternalMnemonicWithPassphrase;
+use sargon::Result as InternalResult;
+use sargon::SLIP39Group as InternalSLIP39Group;
+use sargon::SLIP39Share as InternalSLIP39Share;
+
+/// Splits the mnemonic and passphrase into groups of SLIP39 shares, each
+/// share returned as its phrase, `group_threshold` of the groups being
+/// required to recover it.
+#[uniffi::export]
+pub fn mnemonic_with_passphrase_to_slip39_shares(
+    mnemonic_with_passphrase: &MnemonicWithPassphrase,
+    group_threshold: u8,
+    groups: Vec<SLIP39Group>,
+) -> Result<Vec<Vec<String>>> {
+    let groups: Vec<InternalSLIP39Group> = groups.into_internal();
+    mnemonic_with_passphrase
+        .into_internal()
+        .to_slip39_shares(group_threshold, &groups)
+        .map(|groups| {
+            groups
+                .into_iter()
+                .map(|shares| shares.iter().map(|s| s.phrase()).collect())
+                .collect::<Vec<Vec<String>>>()
+        })
+        .into_result()
+}
+
+/// Recovers the mnemonic in `language` and passphrase from the phrases of
+/// SLIP39 shares created by `mnemonic_with_passphrase_to_slip39_shares`.
+#[uniffi::export]
+pub fn new_mnemonic_with_passphrase_from_slip39_shares(
+    shares: Vec<String>,
+    passphrase: BIP39Passphrase,
+    language: BIP39Language,
+) -> Result<MnemonicWithPassphrase> {
+    shares
+        .iter()
+        .map(InternalSLIP39Share::from_phrase)
+        .collect::<InternalResult<Vec<_>>>()
+        .and_then(|shares| {
+            InternalMnemonicWithPassphrase::from_slip39_shares(
+                &shares,
+                passphrase.into_internal(),
+                language.into_internal(),
+            )
+        })
+        .into_result()
+}
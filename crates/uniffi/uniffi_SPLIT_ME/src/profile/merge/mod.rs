@@ -0,0 +1,5 @@
+mod profile_merge;
+mod profile_merge_conflict;
+
+pub use profile_merge::*;
+pub use profile_merge_conflict::*;
@@ -0,0 +1,45 @@
+use crate::prelude::*;
+use sargon::ProfileMerge as InternalProfileMerge;
+
+/// The outcome of a three-way merge of two divergent Profile snapshots, see
+/// `SargonOS::merge_profile`.
+#[derive(Clone, PartialEq, Eq, uniffi::Record)]
+pub struct ProfileMerge {
+    /// The merged Profile, containing all non-conflicting changes of both
+    /// snapshots, and the local values of all `conflicts`.
+    pub merged: Profile,
+
+    /// The `last_modified` of the header of the local snapshot, used to
+    /// detect whether the local Profile has changed since it was merged.
+    pub local_last_modified: Timestamp,
+
+    /// The items changed differently in the local and remote snapshots,
+    /// which the user has to resolve.
+    pub conflicts: Vec<ProfileMergeConflict>,
+}
+
+impl ProfileMerge {
+    pub fn into_internal(&self) -> InternalProfileMerge {
+        self.clone().into()
+    }
+}
+
+impl From<InternalProfileMerge> for ProfileMerge {
+    fn from(value: InternalProfileMerge) -> Self {
+        Self {
+            merged: value.merged.into(),
+            local_last_modified: value.local_last_modified,
+            conflicts: value.conflicts.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<ProfileMerge> for InternalProfileMerge {
+    fn from(value: ProfileMerge) -> Self {
+        Self::new(
+            value.merged.into_internal(),
+            value.local_last_modified,
+            value.conflicts.into_iter().map(Into::into),
+        )
+    }
+}
@@ -0,0 +1,297 @@
+use crate::prelude::*;
+use sargon::ProfileMergeConflict as InternalProfileMergeConflict;
+use sargon::ProfileMergeConflictResolution as InternalProfileMergeConflictResolution;
+type InternalProfileMergeConflictingValues<T> =
+    sargon::ProfileMergeConflictingValues<T>;
+
+/// An item of a Profile which was changed differently in the local and the
+/// remote snapshot, which the user has to resolve by picking either value.
+///
+/// `base` is the value in the common base snapshot, `local` and `remote` the
+/// values in the snapshots which diverged from it, `None` if the item is
+/// absent from, or was removed in, that snapshot.
+#[derive(Clone, PartialEq, Eq, uniffi::Enum)]
+pub enum ProfileMergeConflict {
+    FactorSource {
+        base: Option<FactorSource>,
+        local: Option<FactorSource>,
+        remote: Option<FactorSource>,
+    },
+    AppDisplay {
+        base: Option<AppDisplay>,
+        local: Option<AppDisplay>,
+        remote: Option<AppDisplay>,
+    },
+    Gateways {
+        base: Option<SavedGateways>,
+        local: Option<SavedGateways>,
+        remote: Option<SavedGateways>,
+    },
+    P2PTransportProfiles {
+        base: Option<SavedP2PTransportProfiles>,
+        local: Option<SavedP2PTransportProfiles>,
+        remote: Option<SavedP2PTransportProfiles>,
+    },
+    RelayServices {
+        base: Option<SavedRelayServices>,
+        local: Option<SavedRelayServices>,
+        remote: Option<SavedRelayServices>,
+    },
+    Security {
+        base: Option<Security>,
+        local: Option<Security>,
+        remote: Option<Security>,
+    },
+    TransactionPreferences {
+        base: Option<TransactionPreferences>,
+        local: Option<TransactionPreferences>,
+        remote: Option<TransactionPreferences>,
+    },
+    Account {
+        network_id: NetworkID,
+        base: Option<Account>,
+        local: Option<Account>,
+        remote: Option<Account>,
+    },
+    Persona {
+        network_id: NetworkID,
+        base: Option<Persona>,
+        local: Option<Persona>,
+        remote: Option<Persona>,
+    },
+    AuthorizedDapp {
+        network_id: NetworkID,
+        base: Option<AuthorizedDapp>,
+        local: Option<AuthorizedDapp>,
+        remote: Option<AuthorizedDapp>,
+    },
+    AddressBookEntry {
+        network_id: NetworkID,
+        base: Option<AddressBookEntry>,
+        local: Option<AddressBookEntry>,
+        remote: Option<AddressBookEntry>,
+    },
+    WatchOnlyAccount {
+        network_id: NetworkID,
+        base: Option<WatchOnlyAccount>,
+        local: Option<WatchOnlyAccount>,
+        remote: Option<WatchOnlyAccount>,
+    },
+}
+
+impl ProfileMergeConflict {
+    pub fn into_internal(&self) -> InternalProfileMergeConflict {
+        self.clone().into()
+    }
+}
+
+fn into_internal_values<T, U: Into<T>>(
+    base: Option<U>,
+    local: Option<U>,
+    remote: Option<U>,
+) -> InternalProfileMergeConflictingValues<T> {
+    InternalProfileMergeConflictingValues::new(
+        base.map(Into::into),
+        local.map(Into::into),
+        remote.map(Into::into),
+    )
+}
+
+impl From<InternalProfileMergeConflict> for ProfileMergeConflict {
+    fn from(value: InternalProfileMergeConflict) -> Self {
+        match value {
+            InternalProfileMergeConflict::FactorSource(values) => {
+                Self::FactorSource {
+                    base: values.base.map(Into::into),
+                    local: values.local.map(Into::into),
+                    remote: values.remote.map(Into::into),
+                }
+            }
+            InternalProfileMergeConflict::AppDisplay(values) => {
+                Self::AppDisplay {
+                    base: values.base.map(Into::into),
+                    local: values.local.map(Into::into),
+                    remote: values.remote.map(Into::into),
+                }
+            }
+            InternalProfileMergeConflict::Gateways(values) => Self::Gateways {
+                base: values.base.map(Into::into),
+                local: values.local.map(Into::into),
+                remote: values.remote.map(Into::into),
+            },
+            InternalProfileMergeConflict::P2PTransportProfiles(values) => {
+                Self::P2PTransportProfiles {
+                    base: values.base.map(Into::into),
+                    local: values.local.map(Into::into),
+                    remote: values.remote.map(Into::into),
+                }
+            }
+            InternalProfileMergeConflict::RelayServices(values) => {
+                Self::RelayServices {
+                    base: values.base.map(Into::into),
+                    local: values.local.map(Into::into),
+                    remote: values.remote.map(Into::into),
+                }
+            }
+            InternalProfileMergeConflict::Security(values) => Self::Security {
+                base: values.base.map(Into::into),
+                local: values.local.map(Into::into),
+                remote: values.remote.map(Into::into),
+            },
+            InternalProfileMergeConflict::TransactionPreferences(values) => {
+                Self::TransactionPreferences {
+                    base: values.base.map(Into::into),
+                    local: values.local.map(Into::into),
+                    remote: values.remote.map(Into::into),
+                }
+            }
+            InternalProfileMergeConflict::Account { network_id, values } => {
+                Self::Account {
+                    network_id: network_id.into(),
+                    base: values.base.map(Into::into),
+                    local: values.local.map(Into::into),
+                    remote: values.remote.map(Into::into),
+                }
+            }
+            InternalProfileMergeConflict::Persona { network_id, values } => {
+                Self::Persona {
+                    network_id: network_id.into(),
+                    base: values.base.map(Into::into),
+                    local: values.local.map(Into::into),
+                    remote: values.remote.map(Into::into),
+                }
+            }
+            InternalProfileMergeConflict::AuthorizedDapp {
+                network_id,
+                values,
+            } => Self::AuthorizedDapp {
+                network_id: network_id.into(),
+                base: values.base.map(Into::into),
+                local: values.local.map(Into::into),
+                remote: values.remote.map(Into::into),
+            },
+            InternalProfileMergeConflict::AddressBookEntry {
+                network_id,
+                values,
+            } => Self::AddressBookEntry {
+                network_id: network_id.into(),
+                base: values.base.map(Into::into),
+                local: values.local.map(Into::into),
+                remote: values.remote.map(Into::into),
+            },
+            InternalProfileMergeConflict::WatchOnlyAccount {
+                network_id,
+                values,
+            } => Self::WatchOnlyAccount {
+                network_id: network_id.into(),
+                base: values.base.map(Into::into),
+                local: values.local.map(Into::into),
+                remote: values.remote.map(Into::into),
+            },
+        }
+    }
+}
+
+impl From<ProfileMergeConflict> for InternalProfileMergeConflict {
+    fn from(value: ProfileMergeConflict) -> Self {
+        match value {
+            ProfileMergeConflict::FactorSource {
+                base,
+                local,
+                remote,
+            } => Self::FactorSource(into_internal_values(base, local, remote)),
+            ProfileMergeConflict::AppDisplay {
+                base,
+                local,
+                remote,
+            } => Self::AppDisplay(into_internal_values(base, local, remote)),
+            ProfileMergeConflict::Gateways {
+                base,
+                local,
+                remote,
+            } => Self::Gateways(into_internal_values(base, local, remote)),
+            ProfileMergeConflict::P2PTransportProfiles {
+                base,
+                local,
+                remote,
+            } => Self::P2PTransportProfiles(into_internal_values(
+                base, local, remote,
+            )),
+            ProfileMergeConflict::RelayServices {
+                base,
+                local,
+                remote,
+            } => Self::RelayServices(into_internal_values(base, local, remote)),
+            ProfileMergeConflict::Security {
+                base,
+                local,
+                remote,
+            } => Self::Security(into_internal_values(base, local, remote)),
+            ProfileMergeConflict::TransactionPreferences {
+                base,
+                local,
+                remote,
+            } => Self::TransactionPreferences(into_internal_values(
+                base, local, remote,
+            )),
+            ProfileMergeConflict::Account {
+                network_id,
+                base,
+                local,
+                remote,
+            } => Self::Account {
+                network_id: network_id.into_internal(),
+                values: into_internal_values(base, local, remote),
+            },
+            ProfileMergeConflict::Persona {
+                network_id,
+                base,
+                local,
+                remote,
+            } => Self::Persona {
+                network_id: network_id.into_internal(),
+                values: into_internal_values(base, local, remote),
+            },
+            ProfileMergeConflict::AuthorizedDapp {
+                network_id,
+                base,
+                local,
+                remote,
+            } => Self::AuthorizedDapp {
+                network_id: network_id.into_internal(),
+                values: into_internal_values(base, local, remote),
+            },
+            ProfileMergeConflict::AddressBookEntry {
+                network_id,
+                base,
+                local,
+                remote,
+            } => Self::AddressBookEntry {
+                network_id: network_id.into_internal(),
+                values: into_internal_values(base, local, remote),
+            },
+            ProfileMergeConflict::WatchOnlyAccount {
+                network_id,
+                base,
+                local,
+                remote,
+            } => Self::WatchOnlyAccount {
+                network_id: network_id.into_internal(),
+                values: into_internal_values(base, local, remote),
+            },
+        }
+    }
+}
+
+/// How the user resolved a [`ProfileMergeConflict`].
+#[derive(
+    Clone, Copy, PartialEq, Eq, Hash, InternalConversion, uniffi::Enum,
+)]
+pub enum ProfileMergeConflictResolution {
+    /// Keep the value of the local snapshot, which is what the merged Profile
+    /// contains before conflicts are resolved.
+    KeepLocal,
+
+    /// Replace the value with the one of the remote snapshot.
+    KeepRemote,
+}
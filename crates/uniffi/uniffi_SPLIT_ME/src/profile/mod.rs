@@ -1,10 +1,12 @@
 mod encrypted;
+mod merge;
 mod mfa;
 mod profilesnapshot_version;
 mod supporting_types;
 mod v100;
 
 pub use encrypted::*;
+pub use merge::*;
 pub use mfa::*;
 pub use profilesnapshot_version::*;
 pub use supporting_types::*;
@@ -9,6 +9,76 @@ pub enum FiatCurrency {
 
     /// Swedish krona.
     SEK,
+
+    /// Euro.
+    EUR,
+
+    /// British pounds.
+    GBP,
+
+    /// Japanese yen.
+    JPY,
+
+    /// Swiss francs.
+    CHF,
+
+    /// Norwegian krone.
+    NOK,
+
+    /// Danish krone.
+    DKK,
+
+    /// Polish złoty.
+    PLN,
+
+    /// Czech koruna.
+    CZK,
+
+    /// Hungarian forint.
+    HUF,
+
+    /// Canadian dollars.
+    CAD,
+
+    /// Australian dollars.
+    AUD,
+
+    /// New Zealand dollars.
+    NZD,
+
+    /// Chinese yuan renminbi.
+    CNY,
+
+    /// Hong Kong dollars.
+    HKD,
+
+    /// Singapore dollars.
+    SGD,
+
+    /// South Korean won.
+    KRW,
+
+    /// Indian rupees.
+    INR,
+
+    /// Brazilian reais.
+    BRL,
+
+    /// Mexican pesos.
+    MXN,
+
+    /// South African rand.
+    ZAR,
+
+    /// Turkish lira.
+    TRY,
+
+    /// United Arab Emirates dirhams.
+    AED,
+
+    /// A currency not known by this version of Sargon, with its code as
+    /// found in the Profile.
+    Unknown { code: String },
 }
 
 json_string_convertible!(FiatCurrency);
@@ -22,3 +92,35 @@ pub fn new_fiat_currency_sample() -> FiatCurrency {
 pub fn new_fiat_currency_sample_other() -> FiatCurrency {
     InternalFiatCurrency::sample_other().into()
 }
+
+/// All currencies known by this version of Sargon.
+#[uniffi::export]
+pub fn fiat_currency_all_known() -> Vec<FiatCurrency> {
+    InternalFiatCurrency::all_known().into_type()
+}
+
+/// The uppercase ISO 4217 code of the currency, e.g. `"USD"`.
+#[uniffi::export]
+pub fn fiat_currency_code(currency: &FiatCurrency) -> String {
+    currency.into_internal().code()
+}
+
+/// The number of digits after the decimal separator of the minor unit of the
+/// currency, e.g. `2` for `USD` and `0` for `JPY`.
+#[uniffi::export]
+pub fn fiat_currency_minor_units(currency: &FiatCurrency) -> u8 {
+    currency.into_internal().minor_units()
+}
+
+/// A human readable, locale respecting string of `amount` in `currency`,
+/// rounded to the minor unit of the currency.
+#[uniffi::export]
+pub fn fiat_currency_formatted(
+    currency: &FiatCurrency,
+    amount: &Decimal192,
+    locale: LocaleConfig,
+) -> String {
+    currency
+        .into_internal()
+        .formatted(amount.into_internal(), locale.into())
+}
@@ -0,0 +1,9 @@
+use crate::prelude::*;
+
+declare_collection_of_identified_entry!(
+    /// A collection of [`PersonaDataIdentifiedPostalAddress`]s, which is essentially a tuple of
+    /// `(Uuid, PersonaDataEntryPostalAddress)`, each element is identifiable by its ID. Can be empty, can
+    /// contain elements with the same value, but under different IDs.
+    PostalAddress,   // singular form
+    PostalAddresses, // plural form
+);
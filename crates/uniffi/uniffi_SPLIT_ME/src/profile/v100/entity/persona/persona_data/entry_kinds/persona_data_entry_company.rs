@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use sargon::PersonaDataEntryCompany as InternalPersonaDataEntryCompany;
+
+/// A company a person has chosen to associate with a Persona, and optionally
+/// their title at it, e.g. `"Wayne Enterprises"` and `"CEO"`.
+///
+/// The name of the company is never empty, the title can be left blank.
+#[derive(Clone, PartialEq, Hash, Eq, InternalConversion, uniffi::Record)]
+pub struct PersonaDataEntryCompany {
+    pub name: String,
+    pub title: String,
+}
+
+json_data_convertible!(PersonaDataEntryCompany);
+
+#[uniffi::export]
+pub fn new_persona_data_entry_company_sample() -> PersonaDataEntryCompany {
+    InternalPersonaDataEntryCompany::sample().into()
+}
+
+#[uniffi::export]
+pub fn new_persona_data_entry_company_sample_other() -> PersonaDataEntryCompany
+{
+    InternalPersonaDataEntryCompany::sample_other().into()
+}
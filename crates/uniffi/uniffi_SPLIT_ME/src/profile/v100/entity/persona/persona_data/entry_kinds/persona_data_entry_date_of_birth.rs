@@ -0,0 +1,34 @@
+use crate::prelude::*;
+use sargon::PersonaDataEntryDateOfBirth as InternalPersonaDataEntryDateOfBirth;
+
+/// A persons date of birth they have chosen to associate with a Persona, a
+/// calendar date without time or timezone, e.g. `1939-05-27`.
+#[derive(Clone, PartialEq, Hash, Eq, InternalConversion, uniffi::Record)]
+pub struct PersonaDataEntryDateOfBirth {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+json_string_convertible!(PersonaDataEntryDateOfBirth);
+
+#[uniffi::export]
+pub fn new_persona_data_entry_date_of_birth(
+    year: u16,
+    month: u8,
+    day: u8,
+) -> Result<PersonaDataEntryDateOfBirth> {
+    InternalPersonaDataEntryDateOfBirth::new(year, month, day).into_result()
+}
+
+#[uniffi::export]
+pub fn new_persona_data_entry_date_of_birth_sample(
+) -> PersonaDataEntryDateOfBirth {
+    InternalPersonaDataEntryDateOfBirth::sample().into()
+}
+
+#[uniffi::export]
+pub fn new_persona_data_entry_date_of_birth_sample_other(
+) -> PersonaDataEntryDateOfBirth {
+    InternalPersonaDataEntryDateOfBirth::sample_other().into()
+}
@@ -0,0 +1,85 @@
+use crate::prelude::*;
+use sargon::PersonaDataEntryPostalAddress as InternalPersonaDataEntryPostalAddress;
+use sargon::PersonaDataPostalAddressField as InternalPersonaDataPostalAddressField;
+use sargon::PersonaDataPostalAddressFieldKind as InternalPersonaDataPostalAddressFieldKind;
+use sargon::PersonaDataPostalAddressFieldLayout as InternalPersonaDataPostalAddressFieldLayout;
+
+/// A postal address a person has chosen to associate with a Persona, e.g.
+/// as shipping address for e-commerce dApps.
+///
+/// Which fields an address consists of, and in which order they are written,
+/// depends on the country, see `persona_data_entry_postal_address_field_layout`.
+#[derive(Clone, PartialEq, Hash, Eq, InternalConversion, uniffi::Record)]
+pub struct PersonaDataEntryPostalAddress {
+    /// Uppercase ISO 3166-1 alpha-2 code of the country, e.g. `"SE"`.
+    pub country_code: String,
+    pub fields: Vec<PersonaDataPostalAddressField>,
+}
+
+/// The kind of a field of a [`PersonaDataEntryPostalAddress`].
+#[derive(Clone, PartialEq, Hash, Eq, InternalConversion, uniffi::Enum)]
+pub enum PersonaDataPostalAddressFieldKind {
+    StreetLine1,
+    StreetLine2,
+    City,
+    State,
+    Province,
+    County,
+    Prefecture,
+    PostalCode,
+
+    /// A kind not known by this version of Sargon, as found in the Profile.
+    Unknown {
+        kind: String,
+    },
+}
+
+/// A field of a [`PersonaDataEntryPostalAddress`], e.g. its postal code.
+#[derive(Clone, PartialEq, Hash, Eq, InternalConversion, uniffi::Record)]
+pub struct PersonaDataPostalAddressField {
+    pub kind: PersonaDataPostalAddressFieldKind,
+    pub value: String,
+}
+
+/// A field in the layout of postal addresses of some country.
+#[derive(Clone, PartialEq, Hash, Eq, InternalConversion, uniffi::Record)]
+pub struct PersonaDataPostalAddressFieldLayout {
+    pub kind: PersonaDataPostalAddressFieldKind,
+    pub is_required: bool,
+}
+
+json_data_convertible!(PersonaDataEntryPostalAddress);
+
+#[uniffi::export]
+pub fn new_persona_data_entry_postal_address(
+    country_code: String,
+    fields: Vec<PersonaDataPostalAddressField>,
+) -> Result<PersonaDataEntryPostalAddress> {
+    InternalPersonaDataEntryPostalAddress::new(
+        country_code,
+        fields.into_internal(),
+    )
+    .into_result()
+}
+
+/// The fields - in order - of postal addresses in the country with
+/// `country_code`, for hosts to display a form for them.
+#[uniffi::export]
+pub fn persona_data_entry_postal_address_field_layout(
+    country_code: String,
+) -> Vec<PersonaDataPostalAddressFieldLayout> {
+    InternalPersonaDataEntryPostalAddress::field_layout(country_code)
+        .into_type()
+}
+
+#[uniffi::export]
+pub fn new_persona_data_entry_postal_address_sample(
+) -> PersonaDataEntryPostalAddress {
+    InternalPersonaDataEntryPostalAddress::sample().into()
+}
+
+#[uniffi::export]
+pub fn new_persona_data_entry_postal_address_sample_other(
+) -> PersonaDataEntryPostalAddress {
+    InternalPersonaDataEntryPostalAddress::sample_other().into()
+}
@@ -40,6 +40,20 @@ pub struct PersonaData {
     /// `(Uuid, PersonaDataIdentifiedEmailAddress)`, each element is identifiable by its ID. Can be empty, can
     /// contain elements with the same value, but under different IDs.
     pub email_addresses: CollectionOfEmailAddresses,
+
+    /// A collection of [`PersonaDataIdentifiedPostalAddress`]s, which is essentially a tuple of
+    /// `(Uuid, PersonaDataEntryPostalAddress)`, each element is identifiable by its ID. Can be empty, can
+    /// contain elements with the same value, but under different IDs.
+    pub postal_addresses: CollectionOfPostalAddresses,
+
+    /// A collection of [`PersonaDataIdentifiedDateOfBirth`]s, which is essentially a tuple of
+    /// `(Uuid, PersonaDataEntryDateOfBirth)`, each element is identifiable by its ID. Can be empty.
+    pub dates_of_birth: CollectionOfDatesOfBirth,
+
+    /// A collection of [`PersonaDataIdentifiedCompany`]s, which is essentially a tuple of
+    /// `(Uuid, PersonaDataEntryCompany)`, each element is identifiable by its ID. Can be empty, can
+    /// contain elements with the same value, but under different IDs.
+    pub companies: CollectionOfCompanies,
 }
 
 #[uniffi::export]
@@ -79,3 +79,21 @@ declare_identified_entry!(
     /// [`(PersonaDataEntryEmailAddress, Uuid)`].
     EmailAddress,
 );
+
+declare_identified_entry!(
+    /// An identifiable Persona postal address. Essentially it is a tuple of a
+    /// [`(PersonaDataEntryPostalAddress, Uuid)`].
+    PostalAddress,
+);
+
+declare_identified_entry!(
+    /// An identifiable Persona date of birth. Essentially it is a tuple of a
+    /// [`(PersonaDataEntryDateOfBirth, Uuid)`].
+    DateOfBirth,
+);
+
+declare_identified_entry!(
+    /// An identifiable Persona company. Essentially it is a tuple of a
+    /// [`(PersonaDataEntryCompany, Uuid)`].
+    Company,
+);
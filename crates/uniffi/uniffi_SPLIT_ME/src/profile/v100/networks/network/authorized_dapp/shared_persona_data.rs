@@ -15,6 +15,19 @@ pub struct SharedPersonaData {
     /// IDs of a `PersonaDataEntryPhoneNumber`s the user has shared with some dApp on some network
     /// can be `None`, or can be `Some(<EMPTY>)`.
     pub phone_numbers: Option<SharedToDappWithPersonaIDsOfPersonaDataEntries>,
+
+    /// IDs of a `PersonaDataEntryPostalAddress`es the user has shared with some dApp on some network
+    /// can be `None`, or can be `Some(<EMPTY>)`.
+    pub postal_addresses:
+        Option<SharedToDappWithPersonaIDsOfPersonaDataEntries>,
+
+    /// IDs of a `PersonaDataEntryDateOfBirth`s the user has shared with some dApp on some network
+    /// can be `None`, or can be `Some(<EMPTY>)`.
+    pub dates_of_birth: Option<SharedToDappWithPersonaIDsOfPersonaDataEntries>,
+
+    /// IDs of a `PersonaDataEntryCompany`s the user has shared with some dApp on some network
+    /// can be `None`, or can be `Some(<EMPTY>)`.
+    pub companies: Option<SharedToDappWithPersonaIDsOfPersonaDataEntries>,
 }
 
 #[uniffi::export]
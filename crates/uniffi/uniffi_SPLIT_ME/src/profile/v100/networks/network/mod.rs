@@ -6,6 +6,7 @@ mod network_id;
 mod profile_network;
 mod resource_preferences;
 mod token_price_service;
+mod watch_only_account;
 
 pub use address_book_entry::*;
 pub use authorized_dapp::*;
@@ -15,3 +16,4 @@ pub use network_id::*;
 pub use profile_network::*;
 pub use resource_preferences::*;
 pub use token_price_service::*;
+pub use watch_only_account::*;
@@ -3,6 +3,7 @@ use profile_logic::prelude::ProfileNetworkDetailsForAuthorizedDapp as _;
 use sargon::AddressBook as InternalAddressBook;
 use sargon::ProfileNetwork as InternalProfileNetwork;
 use sargon::TokenPriceServices as InternalTokenPriceServices;
+use sargon::WatchOnlyAccounts as InternalWatchOnlyAccounts;
 
 decl_vec_samples_for!(ProfileNetworks, ProfileNetwork);
 
@@ -14,6 +15,14 @@ impl IntoInternal<Vec<AddressBookEntry>, InternalAddressBook>
     }
 }
 
+impl IntoInternal<Vec<WatchOnlyAccount>, InternalWatchOnlyAccounts>
+    for Vec<WatchOnlyAccount>
+{
+    fn into_internal(self) -> InternalWatchOnlyAccounts {
+        self.into_iter().map(Into::into).collect()
+    }
+}
+
 impl IntoInternal<Vec<TokenPriceService>, InternalTokenPriceServices>
     for Vec<TokenPriceService>
 {
@@ -49,6 +58,10 @@ pub struct ProfileNetwork {
     /// User-managed external addresses and names.
     pub address_book: Vec<AddressBookEntry>,
 
+    /// An ordered set of accounts on this network the user tracks but does
+    /// not control.
+    pub watch_only_accounts: Vec<WatchOnlyAccount>,
+
     /// Ordered token price service endpoints used for failover.
     pub token_price_services: Vec<TokenPriceService>,
 
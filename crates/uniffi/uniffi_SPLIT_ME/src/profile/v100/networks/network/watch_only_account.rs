@@ -0,0 +1,28 @@
+use crate::prelude::*;
+use sargon::WatchOnlyAccount as InternalWatchOnlyAccount;
+
+/// An account the user wants to track - its balances, their worth and its
+/// transaction history - but which is not controlled by this wallet.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct WatchOnlyAccount {
+    /// The address of the watched account.
+    pub address: AccountAddress,
+
+    /// A name of the watched account chosen by the user.
+    pub display_name: DisplayName,
+
+    /// The visual cue the user picked for the watched account.
+    pub appearance_id: AppearanceID,
+}
+
+delegate_debug_into!(WatchOnlyAccount, InternalWatchOnlyAccount);
+
+#[uniffi::export]
+pub fn new_watch_only_account_sample() -> WatchOnlyAccount {
+    InternalWatchOnlyAccount::sample().into()
+}
+
+#[uniffi::export]
+pub fn new_watch_only_account_sample_other() -> WatchOnlyAccount {
+    InternalWatchOnlyAccount::sample_other().into()
+}
@@ -0,0 +1,44 @@
+use crate::prelude::*;
+use sargon::LedgerDevice as InternalLedgerDevice;
+use sargon::LedgerDeviceInfo as InternalLedgerDeviceInfo;
+
+/// The Ledger device a request is addressed to, the Connector Extension
+/// rejects the request if the connected device has another `id`.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct LedgerDevice {
+    /// The label of the device as saved in Profile, displayed by the
+    /// Connector Extension when asking the user to connect it.
+    pub name: Option<String>,
+
+    pub model: LedgerHardwareWalletModel,
+
+    /// The body of the `FactorSourceIDFromHash` of the Ledger.
+    pub id: Exactly32Bytes,
+}
+
+/// The id and model of the Ledger device connected to the Connector
+/// Extension, as returned for a `getDeviceInfo` request.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct LedgerDeviceInfo {
+    /// The body of the `FactorSourceIDFromHash` of the Ledger.
+    pub id: Exactly32Bytes,
+
+    pub model: LedgerHardwareWalletModel,
+}
+
+#[uniffi::export]
+pub fn ledger_device_info_factor_source_id(
+    info: &LedgerDeviceInfo,
+) -> FactorSourceIDFromHash {
+    info.into_internal().factor_source_id().into()
+}
+
+#[uniffi::export]
+pub fn new_ledger_device_sample() -> LedgerDevice {
+    InternalLedgerDevice::sample().into()
+}
+
+#[uniffi::export]
+pub fn new_ledger_device_sample_other() -> LedgerDevice {
+    InternalLedgerDevice::sample_other().into()
+}
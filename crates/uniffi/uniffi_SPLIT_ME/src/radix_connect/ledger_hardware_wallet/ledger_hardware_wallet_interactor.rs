@@ -0,0 +1,119 @@
+use crate::prelude::*;
+use sargon::AuthIntent as InternalAuthIntent;
+use sargon::KeyDerivationInteractor as InternalKeyDerivationInteractor;
+use sargon::LedgerHardwareWalletInteractor as InternalLedgerHardwareWalletInteractor;
+use sargon::SignInteractor as InternalSignInteractor;
+use sargon::Subintent as InternalSubintent;
+use sargon::TransactionIntent as InternalTransactionIntent;
+
+/// Signs and derives keys with Ledger devices by sending requests to the
+/// Connector Extension the device is connected to, over a
+/// `LedgerHardwareWalletTransport`.
+///
+/// Signatures returned by the device are verified against the hash of the
+/// signed payload and the public keys of the requested factor instances.
+#[derive(uniffi::Object)]
+pub struct LedgerHardwareWalletInteractor {
+    pub(crate) wrapped: Arc<InternalLedgerHardwareWalletInteractor>,
+}
+
+impl LedgerHardwareWalletInteractor {
+    pub(crate) fn from_internal(
+        wrapped: InternalLedgerHardwareWalletInteractor,
+    ) -> Self {
+        Self {
+            wrapped: Arc::new(wrapped),
+        }
+    }
+}
+
+#[uniffi::export]
+impl LedgerHardwareWalletInteractor {
+    /// Reads the id and model of the Ledger device currently connected to
+    /// the Connector Extension.
+    pub async fn get_device_info(&self) -> Result<LedgerDeviceInfo> {
+        self.wrapped.get_device_info().await.into_result()
+    }
+
+    /// Derives the key at `derivation_path` on the Ledger device of
+    /// `factor_source_id` and displays the corresponding address on the
+    /// device, so the user can verify it.
+    pub async fn derive_and_display_address(
+        &self,
+        factor_source_id: FactorSourceIDFromHash,
+        derivation_path: DerivationPath,
+    ) -> Result<LedgerDerivedAddress> {
+        self.wrapped
+            .derive_and_display_address(
+                factor_source_id.into_internal(),
+                derivation_path.into_internal(),
+            )
+            .await
+            .into_result()
+    }
+
+    pub async fn derive_keys(
+        &self,
+        request: KeyDerivationRequest,
+    ) -> Result<KeyDerivationResponse> {
+        InternalKeyDerivationInteractor::derive(
+            self.wrapped.as_ref(),
+            request.into_internal(),
+        )
+        .await
+        .into_result()
+    }
+
+    pub async fn sign_transactions(
+        &self,
+        request: SignRequestOfTransactionIntent,
+    ) -> Result<SignResponseOfTransactionIntentHash> {
+        InternalSignInteractor::<InternalTransactionIntent>::sign(
+            self.wrapped.as_ref(),
+            request.into_internal(),
+        )
+        .await
+        .into_result()
+    }
+
+    pub async fn sign_subintents(
+        &self,
+        request: SignRequestOfSubintent,
+    ) -> Result<SignResponseOfSubintentHash> {
+        InternalSignInteractor::<InternalSubintent>::sign(
+            self.wrapped.as_ref(),
+            request.into_internal(),
+        )
+        .await
+        .into_result()
+    }
+
+    pub async fn sign_auth(
+        &self,
+        request: SignRequestOfAuthIntent,
+    ) -> Result<SignResponseOfAuthIntentHash> {
+        InternalSignInteractor::<InternalAuthIntent>::sign(
+            self.wrapped.as_ref(),
+            request.into_internal(),
+        )
+        .await
+        .into_result()
+    }
+}
+
+#[uniffi::export]
+impl SargonOS {
+    /// Returns a `LedgerHardwareWalletInteractor` sending requests over
+    /// `transport`, resolving the Ledger device of a factor source from the
+    /// active Profile.
+    pub fn ledger_hardware_wallet_interactor(
+        &self,
+        transport: Arc<dyn LedgerHardwareWalletTransport>,
+    ) -> Arc<LedgerHardwareWalletInteractor> {
+        Arc::new(LedgerHardwareWalletInteractor::from_internal(
+            self.wrapped.ledger_hardware_wallet_interactor(Arc::new(
+                LedgerHardwareWalletTransportAdapter { wrapped: transport },
+            )),
+        ))
+    }
+}
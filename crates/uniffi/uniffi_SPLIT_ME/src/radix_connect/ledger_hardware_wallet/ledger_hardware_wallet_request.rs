@@ -0,0 +1,81 @@
+use crate::prelude::*;
+use sargon::LedgerHardwareWalletRequest as InternalLedgerHardwareWalletRequest;
+use sargon::LedgerHardwareWalletRequestKind as InternalLedgerHardwareWalletRequestKind;
+use sargon::LedgerSignTransactionMode as InternalLedgerSignTransactionMode;
+
+/// A request sent by the Wallet to a Ledger device, through the Connector
+/// Extension the device is connected to.
+#[derive(Clone, PartialEq, Eq, InternalConversion, uniffi::Record)]
+pub struct LedgerHardwareWalletRequest {
+    /// Identifies the request, the response of the Connector Extension
+    /// contains the same id.
+    pub interaction_id: WalletInteractionId,
+
+    pub kind: LedgerHardwareWalletRequestKind,
+}
+
+json_data_convertible!(LedgerHardwareWalletRequest);
+
+/// How the Ledger device displays a transaction the user is asked to sign.
+#[derive(
+    Clone, Copy, PartialEq, Eq, Hash, InternalConversion, uniffi::Enum,
+)]
+pub enum LedgerSignTransactionMode {
+    /// Every instruction of the manifest is displayed.
+    Verbose,
+
+    /// Only a summary of the transaction is displayed.
+    Summary,
+}
+
+/// The different kinds of requests a Ledger device can handle.
+#[derive(Clone, PartialEq, Eq, InternalConversion, uniffi::Enum)]
+pub enum LedgerHardwareWalletRequestKind {
+    /// Reads the id and model of the connected device, used when adding a
+    /// new Ledger factor source.
+    GetDeviceInfo,
+
+    DerivePublicKeys {
+        keys_parameters: Vec<LedgerKeyParameters>,
+        ledger_device: LedgerDevice,
+    },
+
+    SignTransaction {
+        signers: Vec<LedgerKeyParameters>,
+        ledger_device: LedgerDevice,
+        compiled_transaction_intent: BagOfBytes,
+        mode: LedgerSignTransactionMode,
+        display_hash: bool,
+    },
+
+    SignSubintentHash {
+        signers: Vec<LedgerKeyParameters>,
+        ledger_device: LedgerDevice,
+        subintent_hash: Exactly32Bytes,
+    },
+
+    SignChallenge {
+        signers: Vec<LedgerKeyParameters>,
+        ledger_device: LedgerDevice,
+        challenge: Exactly32Bytes,
+        origin: DappOrigin,
+        dapp_definition_address: DappDefinitionAddress,
+    },
+
+    DeriveAndDisplayAddress {
+        key_parameters: LedgerKeyParameters,
+        ledger_device: LedgerDevice,
+    },
+}
+
+#[uniffi::export]
+pub fn new_ledger_hardware_wallet_request_sample() -> LedgerHardwareWalletRequest
+{
+    InternalLedgerHardwareWalletRequest::sample().into()
+}
+
+#[uniffi::export]
+pub fn new_ledger_hardware_wallet_request_sample_other(
+) -> LedgerHardwareWalletRequest {
+    InternalLedgerHardwareWalletRequest::sample_other().into()
+}
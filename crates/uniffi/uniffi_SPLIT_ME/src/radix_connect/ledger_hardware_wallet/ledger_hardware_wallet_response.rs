@@ -0,0 +1,154 @@
+use crate::prelude::*;
+use sargon::LedgerHardwareWalletDiscriminator as InternalLedgerHardwareWalletDiscriminator;
+use sargon::LedgerHardwareWalletError as InternalLedgerHardwareWalletError;
+use sargon::LedgerHardwareWalletResponse as InternalLedgerHardwareWalletResponse;
+use sargon::LedgerHardwareWalletResponseSuccess as InternalLedgerHardwareWalletResponseSuccess;
+
+/// Identifies the kind of a Ledger request and of its response.
+#[derive(
+    Clone, Copy, PartialEq, Eq, Hash, InternalConversion, uniffi::Enum,
+)]
+pub enum LedgerHardwareWalletDiscriminator {
+    GetDeviceInfo,
+    DerivePublicKeys,
+    SignTransaction,
+    SignSubintentHash,
+    SignChallenge,
+    DeriveAndDisplayAddress,
+}
+
+/// An error reported by the Connector Extension or the Ledger device, e.g.
+/// because the user rejected signing on the device.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct LedgerHardwareWalletError {
+    pub code: u32,
+    pub message: String,
+}
+
+/// The successful result of a Ledger request, one variant per
+/// `LedgerHardwareWalletDiscriminator`.
+#[derive(Clone, PartialEq, Eq, uniffi::Enum)]
+pub enum LedgerHardwareWalletResponseSuccess {
+    GetDeviceInfo(LedgerDeviceInfo),
+    DerivePublicKeys(Vec<LedgerDerivedPublicKey>),
+    SignTransaction(Vec<LedgerSignature>),
+    SignSubintentHash(Vec<LedgerSignature>),
+    SignChallenge(Vec<LedgerSignature>),
+    DeriveAndDisplayAddress(LedgerDerivedAddress),
+}
+
+impl From<InternalLedgerHardwareWalletResponseSuccess>
+    for LedgerHardwareWalletResponseSuccess
+{
+    fn from(value: InternalLedgerHardwareWalletResponseSuccess) -> Self {
+        match value {
+            InternalLedgerHardwareWalletResponseSuccess::GetDeviceInfo(
+                info,
+            ) => Self::GetDeviceInfo(info.into()),
+            InternalLedgerHardwareWalletResponseSuccess::DerivePublicKeys(
+                keys,
+            ) => Self::DerivePublicKeys(keys.into_type()),
+            InternalLedgerHardwareWalletResponseSuccess::SignTransaction(
+                signatures,
+            ) => Self::SignTransaction(signatures.into_type()),
+            InternalLedgerHardwareWalletResponseSuccess::SignSubintentHash(
+                signatures,
+            ) => Self::SignSubintentHash(signatures.into_type()),
+            InternalLedgerHardwareWalletResponseSuccess::SignChallenge(
+                signatures,
+            ) => Self::SignChallenge(signatures.into_type()),
+            InternalLedgerHardwareWalletResponseSuccess::DeriveAndDisplayAddress(
+                address,
+            ) => Self::DeriveAndDisplayAddress(address.into()),
+        }
+    }
+}
+
+impl From<LedgerHardwareWalletResponseSuccess>
+    for InternalLedgerHardwareWalletResponseSuccess
+{
+    fn from(value: LedgerHardwareWalletResponseSuccess) -> Self {
+        match value {
+            LedgerHardwareWalletResponseSuccess::GetDeviceInfo(info) => {
+                Self::GetDeviceInfo(info.into_internal())
+            }
+            LedgerHardwareWalletResponseSuccess::DerivePublicKeys(keys) => {
+                Self::DerivePublicKeys(keys.into_internal())
+            }
+            LedgerHardwareWalletResponseSuccess::SignTransaction(
+                signatures,
+            ) => Self::SignTransaction(signatures.into_internal()),
+            LedgerHardwareWalletResponseSuccess::SignSubintentHash(
+                signatures,
+            ) => Self::SignSubintentHash(signatures.into_internal()),
+            LedgerHardwareWalletResponseSuccess::SignChallenge(signatures) => {
+                Self::SignChallenge(signatures.into_internal())
+            }
+            LedgerHardwareWalletResponseSuccess::DeriveAndDisplayAddress(
+                address,
+            ) => Self::DeriveAndDisplayAddress(address.into_internal()),
+        }
+    }
+}
+
+/// The outcome of a Ledger request, either the successful result or the
+/// error reported by the Connector Extension or the Ledger device.
+#[derive(Clone, PartialEq, Eq, uniffi::Enum)]
+pub enum LedgerHardwareWalletResponseOutcome {
+    Success(LedgerHardwareWalletResponseSuccess),
+    Failure(LedgerHardwareWalletError),
+}
+
+type InternalLedgerHardwareWalletResponseOutcome = std::result::Result<
+    InternalLedgerHardwareWalletResponseSuccess,
+    InternalLedgerHardwareWalletError,
+>;
+
+impl From<InternalLedgerHardwareWalletResponseOutcome>
+    for LedgerHardwareWalletResponseOutcome
+{
+    fn from(value: InternalLedgerHardwareWalletResponseOutcome) -> Self {
+        match value {
+            Ok(success) => Self::Success(success.into()),
+            Err(error) => Self::Failure(error.into()),
+        }
+    }
+}
+
+impl From<LedgerHardwareWalletResponseOutcome>
+    for InternalLedgerHardwareWalletResponseOutcome
+{
+    fn from(value: LedgerHardwareWalletResponseOutcome) -> Self {
+        match value {
+            LedgerHardwareWalletResponseOutcome::Success(success) => {
+                Ok(success.into())
+            }
+            LedgerHardwareWalletResponseOutcome::Failure(error) => {
+                Err(error.into_internal())
+            }
+        }
+    }
+}
+
+/// A response of the Connector Extension to a `LedgerHardwareWalletRequest`
+/// with the same `interaction_id`.
+#[derive(Clone, PartialEq, Eq, InternalConversion, uniffi::Record)]
+pub struct LedgerHardwareWalletResponse {
+    pub interaction_id: WalletInteractionId,
+    pub discriminator: LedgerHardwareWalletDiscriminator,
+    pub outcome: LedgerHardwareWalletResponseOutcome,
+}
+
+json_data_convertible!(LedgerHardwareWalletResponse);
+
+#[uniffi::export]
+pub fn new_ledger_hardware_wallet_response_sample(
+) -> LedgerHardwareWalletResponse {
+    InternalLedgerHardwareWalletResponse::sample().into()
+}
+
+#[uniffi::export]
+pub fn new_ledger_hardware_wallet_response_sample_other(
+) -> LedgerHardwareWalletResponse {
+    InternalLedgerHardwareWalletResponse::sample_other().into()
+}
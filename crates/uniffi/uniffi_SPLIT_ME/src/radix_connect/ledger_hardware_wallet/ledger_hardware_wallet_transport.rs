@@ -0,0 +1,38 @@
+use crate::prelude::*;
+use sargon::BagOfBytes as InternalBagOfBytes;
+use sargon::LedgerHardwareWalletTransport as InternalLedgerHardwareWalletTransport;
+use sargon::Result as InternalResult;
+
+/// A transport of Ledger requests to the Connector Extension the Ledger
+/// device is connected to, typically a WebRTC data channel of a linked
+/// `P2PLink`.
+#[uniffi::export(with_foreign)]
+#[async_trait::async_trait]
+pub trait LedgerHardwareWalletTransport: Send + Sync {
+    /// Sends the JSON encoded `LedgerHardwareWalletRequest` and awaits the
+    /// JSON encoded `LedgerHardwareWalletResponse` with the same interaction
+    /// id.
+    async fn send_ledger_request(
+        &self,
+        encoded_request: BagOfBytes,
+    ) -> Result<BagOfBytes>;
+}
+
+pub struct LedgerHardwareWalletTransportAdapter {
+    pub wrapped: Arc<dyn LedgerHardwareWalletTransport>,
+}
+
+#[async_trait::async_trait]
+impl InternalLedgerHardwareWalletTransport
+    for LedgerHardwareWalletTransportAdapter
+{
+    async fn send_ledger_request(
+        &self,
+        encoded_request: InternalBagOfBytes,
+    ) -> InternalResult<InternalBagOfBytes> {
+        self.wrapped
+            .send_ledger_request(encoded_request.into())
+            .await
+            .into_internal_result()
+    }
+}
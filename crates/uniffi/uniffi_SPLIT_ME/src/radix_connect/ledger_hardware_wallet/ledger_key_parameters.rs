@@ -0,0 +1,55 @@
+use crate::prelude::*;
+use sargon::LedgerDerivedAddress as InternalLedgerDerivedAddress;
+use sargon::LedgerDerivedPublicKey as InternalLedgerDerivedPublicKey;
+use sargon::LedgerKeyParameters as InternalLedgerKeyParameters;
+use sargon::LedgerSignature as InternalLedgerSignature;
+
+/// The curve and derivation path of a key on a Ledger device, with the path
+/// in BIP32 notation, e.g. `"m/44H/1022H/1H/525H/1460H/0H"`.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct LedgerKeyParameters {
+    pub curve: SLIP10Curve,
+    pub derivation_path: String,
+}
+
+/// A public key derived by a Ledger device, with the parameters it was
+/// derived for.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct LedgerDerivedPublicKey {
+    pub curve: SLIP10Curve,
+    pub derivation_path: String,
+    pub public_key: BagOfBytes,
+}
+
+/// A signature produced by a Ledger device, with the public key of the
+/// signer.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct LedgerSignature {
+    pub derived_public_key: LedgerDerivedPublicKey,
+    pub signature: BagOfBytes,
+}
+
+/// An address derived and displayed by a Ledger device, so the user can
+/// verify it on the device.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct LedgerDerivedAddress {
+    pub derived_key: LedgerDerivedPublicKey,
+    pub address: String,
+}
+
+#[uniffi::export]
+pub fn new_ledger_key_parameters_from_derivation_path(
+    derivation_path: DerivationPath,
+) -> LedgerKeyParameters {
+    InternalLedgerKeyParameters::from(&derivation_path.into_internal()).into()
+}
+
+#[uniffi::export]
+pub fn ledger_derived_public_key_public_key(
+    derived_public_key: &LedgerDerivedPublicKey,
+) -> Result<PublicKey> {
+    derived_public_key
+        .into_internal()
+        .public_key()
+        .into_result()
+}
@@ -79,4 +79,42 @@ impl RadixConnectMobile {
             .await
             .into_result()
     }
+
+    /// Returns all established sessions which have not expired, most recently
+    /// used first.
+    #[uniffi::method]
+    pub async fn sessions(
+        &self,
+    ) -> Result<Vec<RadixConnectMobileSessionSummary>> {
+        self.wrapped.sessions().await.into_result()
+    }
+
+    /// Revokes the session identified by `session_id`.
+    #[uniffi::method]
+    pub async fn revoke_session(&self, session_id: SessionID) -> Result<()> {
+        self.wrapped
+            .revoke_session(session_id.into())
+            .await
+            .into_result()
+    }
+
+    /// Revokes all sessions established with the dApp identified by
+    /// `dapp_definition_address`.
+    #[uniffi::method]
+    pub async fn revoke_sessions_for_dapp(
+        &self,
+        dapp_definition_address: DappDefinitionAddress,
+    ) -> Result<()> {
+        self.wrapped
+            .revoke_sessions_for_dapp(dapp_definition_address.into_internal())
+            .await
+            .into_result()
+    }
+
+    /// Changes for how long a session can remain unused before it expires,
+    /// defaults to 30 days.
+    #[uniffi::method]
+    pub fn set_session_idle_timeout_seconds(&self, seconds: i64) {
+        self.wrapped.set_session_idle_timeout_seconds(seconds)
+    }
 }
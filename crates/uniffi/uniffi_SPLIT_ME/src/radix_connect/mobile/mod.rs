@@ -2,10 +2,12 @@ mod client;
 mod session;
 mod session_dapp_request;
 mod session_storage;
+mod session_summary;
 mod session_wallet_response;
 
 pub use client::*;
 pub use session::*;
 pub use session_dapp_request::*;
 pub use session_storage::*;
+pub use session_summary::*;
 pub use session_wallet_response::*;
@@ -20,6 +20,12 @@ pub trait RadixConnectMobileSessionStorage: Send + Sync {
         &self,
         session_id: SessionID,
     ) -> Result<Option<BagOfBytes>>;
+
+    /// Deletes the session identified by the session id from the storage.
+    async fn delete_session(&self, session_id: SessionID) -> Result<()>;
+
+    /// Loads the ids of all sessions saved in the storage.
+    async fn load_session_ids(&self) -> Result<Vec<SessionID>>;
 }
 
 pub struct RadixConnectMobileSessionStorageAdapter {
@@ -50,4 +56,18 @@ impl InternalRadixConnectMobileSessionStorage
             .await
             .into_internal_result()
     }
+
+    async fn delete_session(
+        &self,
+        session_id: InternalSessionID,
+    ) -> InternalResult<()> {
+        self.wrapped
+            .delete_session(session_id.into())
+            .await
+            .into_internal_result()
+    }
+
+    async fn load_session_ids(&self) -> InternalResult<Vec<InternalSessionID>> {
+        self.wrapped.load_session_ids().await.into_internal_result()
+    }
 }
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use sargon::RadixConnectMobileSessionSummary as InternalRadixConnectMobileSessionSummary;
+
+/// A summary of an established Radix Connect Mobile session, suitable to
+/// display to the user.
+#[derive(PartialEq, Clone, InternalConversion, uniffi::Record)]
+pub struct RadixConnectMobileSessionSummary {
+    pub session_id: SessionID,
+    pub origin: DappOrigin,
+    pub dapp_definition_address: Option<DappDefinitionAddress>,
+    pub created_at: Timestamp,
+    pub last_used_at: Timestamp,
+}
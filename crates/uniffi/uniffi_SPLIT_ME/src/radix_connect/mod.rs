@@ -1,6 +1,8 @@
 mod interaction_id;
 mod interaction_version;
+mod ledger_hardware_wallet;
 mod p2p_links;
+mod p2p_messages;
 mod wallet_account;
 mod wallet_interaction;
 mod wallet_persona;
@@ -10,8 +12,10 @@ mod mobile;
 
 pub use interaction_id::*;
 pub use interaction_version::*;
+pub use ledger_hardware_wallet::*;
 pub use mobile::*;
 pub use p2p_links::*;
+pub use p2p_messages::*;
 pub use wallet_account::*;
 pub use wallet_interaction::*;
 pub use wallet_persona::*;
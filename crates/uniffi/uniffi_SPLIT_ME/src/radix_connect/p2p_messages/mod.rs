@@ -0,0 +1,3 @@
+mod p2p_message_codec;
+
+pub use p2p_message_codec::*;
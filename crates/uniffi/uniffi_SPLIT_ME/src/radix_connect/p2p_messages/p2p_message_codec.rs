@@ -0,0 +1,92 @@
+use crate::prelude::*;
+use sargon::P2PIncomingPackageOutcome as InternalP2PIncomingPackageOutcome;
+use sargon::P2PMessageCodec as InternalP2PMessageCodec;
+use sargon::P2POutgoingMessage as InternalP2POutgoingMessage;
+use sargon::P2PReceiveMessageError as InternalP2PReceiveMessageError;
+
+/// Transport agnostic encoding of messages sent over a `P2PLink`, so that the
+/// host only has to move opaque bytes over WebRTC.
+#[derive(uniffi::Object)]
+pub struct P2PMessageCodec {
+    wrapped: InternalP2PMessageCodec,
+}
+
+/// An outgoing message split into encoded packages, to be sent in order.
+#[derive(Clone, PartialEq, Eq, InternalConversion, uniffi::Record)]
+pub struct P2POutgoingMessage {
+    pub message_id: String,
+    pub packages: Vec<BagOfBytes>,
+}
+
+/// The outcome of receiving a single package.
+#[derive(Clone, PartialEq, Eq, InternalConversion, uniffi::Enum)]
+pub enum P2PIncomingPackageOutcome {
+    AwaitingChunks {
+        message_id: String,
+    },
+    MessageReceived {
+        message_id: String,
+        message: BagOfBytes,
+        confirmation: BagOfBytes,
+    },
+    MessageCorrupted {
+        message_id: String,
+        error: BagOfBytes,
+    },
+    MessageConfirmed {
+        message_id: String,
+    },
+    MessageFailed {
+        message_id: String,
+        error: P2PReceiveMessageError,
+    },
+}
+
+/// The reason the receiving side failed to receive a message.
+#[derive(Clone, Copy, PartialEq, Eq, InternalConversion, uniffi::Enum)]
+pub enum P2PReceiveMessageError {
+    MessageHashesMismatch,
+}
+
+#[uniffi::export]
+impl P2PMessageCodec {
+    #[uniffi::constructor]
+    pub fn new(password: RadixConnectPassword) -> Arc<Self> {
+        Arc::new(Self {
+            wrapped: InternalP2PMessageCodec::new(password.into_internal()),
+        })
+    }
+
+    /// Splits `message` into encoded packages to send over the data channel.
+    pub fn split(&self, message: BagOfBytes) -> P2POutgoingMessage {
+        self.wrapped.split(message.to_vec()).into()
+    }
+
+    /// Handles a package received over the data channel.
+    pub fn receive(
+        &self,
+        package: BagOfBytes,
+    ) -> Result<P2PIncomingPackageOutcome> {
+        self.wrapped.receive(package.to_vec()).into_result()
+    }
+
+    /// Encrypts a message to be sent via the Signaling Server.
+    pub fn encrypt_signaling_message(
+        &self,
+        plaintext: BagOfBytes,
+    ) -> BagOfBytes {
+        self.wrapped
+            .encrypt_signaling_message(plaintext.to_vec())
+            .into()
+    }
+
+    /// Decrypts a message received via the Signaling Server.
+    pub fn decrypt_signaling_message(
+        &self,
+        cipher_text: BagOfBytes,
+    ) -> Result<BagOfBytes> {
+        self.wrapped
+            .decrypt_signaling_message(cipher_text.to_vec())
+            .into_result()
+    }
+}
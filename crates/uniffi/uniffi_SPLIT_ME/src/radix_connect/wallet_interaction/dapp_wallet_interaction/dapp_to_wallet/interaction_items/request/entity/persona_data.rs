@@ -6,4 +6,7 @@ pub struct DappToWalletInteractionPersonaDataRequestItem {
     pub is_requesting_name: Option<bool>,
     pub number_of_requested_email_addresses: Option<RequestedQuantity>,
     pub number_of_requested_phone_numbers: Option<RequestedQuantity>,
+    pub number_of_requested_postal_addresses: Option<RequestedQuantity>,
+    pub number_of_requested_dates_of_birth: Option<RequestedQuantity>,
+    pub number_of_requested_companies: Option<RequestedQuantity>,
 }
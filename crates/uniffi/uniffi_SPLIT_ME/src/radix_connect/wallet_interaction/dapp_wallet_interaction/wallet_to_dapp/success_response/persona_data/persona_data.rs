@@ -6,4 +6,7 @@ pub struct WalletToDappInteractionPersonaDataRequestResponseItem {
     pub name: Option<PersonaDataEntryName>,
     pub email_addresses: Option<Vec<PersonaDataEntryEmailAddress>>,
     pub phone_numbers: Option<Vec<PersonaDataEntryPhoneNumber>>,
+    pub postal_addresses: Option<Vec<PersonaDataEntryPostalAddress>>,
+    pub dates_of_birth: Option<Vec<PersonaDataEntryDateOfBirth>>,
+    pub companies: Option<Vec<PersonaDataEntryCompany>>,
 }
@@ -26,6 +26,16 @@ pub enum Event {
 
     /// The Profile was last used on another device, user ought to claim it.
     ProfileUsedOnOtherDevice(DeviceInfo),
+
+    /// A pending transaction was committed successfully, either submitted by
+    /// the wallet or as the finalizing transaction of a Pre-Authorization.
+    TransactionCommitted { intent_hash: TransactionIntentHash },
+
+    /// A pending transaction failed or was rejected by the network.
+    TransactionFailed { intent_hash: TransactionIntentHash },
+
+    /// A pending Pre-Authorization expired before being submitted.
+    PreAuthorizationExpired { subintent_hash: SubintentHash },
 }
 
 #[uniffi::export]
@@ -63,6 +63,24 @@ pub enum EventKind {
 
     /// Security structures have been updated
     SecurityStructuresUpdated,
+
+    /// A pending Pre-Authorization expired before being submitted.
+    PreAuthorizationExpired,
+
+    /// A pending transaction was committed successfully.
+    TransactionCommitted,
+
+    /// A pending transaction failed or was rejected.
+    TransactionFailed,
+
+    /// Profile updated with a new watch-only account.
+    WatchOnlyAccountAdded,
+
+    /// An existing watch-only account has been updated
+    WatchOnlyAccountUpdated,
+
+    /// A watch-only account has been removed from Profile.
+    WatchOnlyAccountRemoved,
 }
 
 #[uniffi::export]
@@ -90,6 +108,11 @@ pub fn event_kind_affects_security_structures(event_kind: EventKind) -> bool {
     event_kind.into_internal().affects_security_structures()
 }
 
+#[uniffi::export]
+pub fn event_kind_affects_watch_only_accounts(event_kind: EventKind) -> bool {
+    event_kind.into_internal().affects_watch_only_accounts()
+}
+
 #[uniffi::export]
 pub fn event_kind_all() -> Vec<EventKind> {
     InternalEventKind::all().into_type()
@@ -45,4 +45,13 @@ pub enum EventProfileModified {
 
     /// Security structures have been updated
     SecurityStructuresUpdated { ids: Vec<SecurityStructureID> },
+
+    /// A new watch-only account with `address` was inserted into the active profile
+    WatchOnlyAccountAdded { address: AccountAddress },
+
+    /// An existing watch-only account has been updated
+    WatchOnlyAccountUpdated { address: AccountAddress },
+
+    /// The watch-only account with `address` was removed from the active profile
+    WatchOnlyAccountRemoved { address: AccountAddress },
 }
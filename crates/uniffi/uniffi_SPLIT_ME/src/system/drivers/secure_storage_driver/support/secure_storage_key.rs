@@ -10,6 +10,7 @@ pub enum SecureStorageKey {
     RadixConnectMobileSession {
         session_id: String,
     },
+    RadixConnectMobileSessionIDs,
     ProfileSnapshot {
         // Note:
         // `profile_id` is only meant to be used by the iOS Host for backward compatibility.
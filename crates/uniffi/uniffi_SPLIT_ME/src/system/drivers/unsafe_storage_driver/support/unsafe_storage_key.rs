@@ -4,6 +4,8 @@ use sargon::UnsafeStorageKey as InternalUnsafeStorageKey;
 #[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Enum)]
 pub enum UnsafeStorageKey {
     FactorSourceUserHasWrittenDown,
+    PendingTransactions,
+    ApplyShieldTransactionsQueue,
 }
 
 impl UnsafeStorageKey {
@@ -13,6 +15,10 @@ impl UnsafeStorageKey {
             match self {
                 UnsafeStorageKey::FactorSourceUserHasWrittenDown =>
                     "factor_source_user_has_written_down".to_owned(),
+                UnsafeStorageKey::PendingTransactions =>
+                    "pending_transactions".to_owned(),
+                UnsafeStorageKey::ApplyShieldTransactionsQueue =>
+                    "apply_shield_transactions_queue".to_owned(),
             }
         )
     }
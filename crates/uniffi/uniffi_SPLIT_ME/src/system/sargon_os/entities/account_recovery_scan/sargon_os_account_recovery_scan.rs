@@ -0,0 +1,29 @@
+use sargon::OsAccountRecoveryScan;
+
+use crate::prelude::*;
+
+// ==================
+// Account Recovery Scan (Public)
+// ==================
+#[uniffi::export]
+impl SargonOS {
+    /// Derives account factor instances of `factor_source` on `network_id`
+    /// and looks them up on ledger, until `gap_limit` consecutive indices
+    /// without any activity have been found, returning the accounts found,
+    /// both unsecurified and securified ones.
+    pub async fn account_recovery_scan(
+        &self,
+        factor_source: FactorSource,
+        network_id: NetworkID,
+        gap_limit: u32,
+    ) -> Result<AccountRecoveryScanOutcome> {
+        self.wrapped
+            .account_recovery_scan(
+                factor_source.into_internal(),
+                network_id.into_internal(),
+                gap_limit,
+            )
+            .await
+            .into_result()
+    }
+}
@@ -0,0 +1,47 @@
+use crate::prelude::*;
+use sargon::AccountRecoveredBehindAccessController as InternalAccountRecoveredBehindAccessController;
+use sargon::AccountRecoveryScanOutcome as InternalAccountRecoveryScanOutcome;
+
+/// An account found during an account recovery scan, which is active on
+/// ledger but has been securified, i.e. its owner badge is held by an
+/// access controller.
+#[derive(Clone, PartialEq, Eq, InternalConversion, uniffi::Record)]
+pub struct AccountRecoveredBehindAccessController {
+    pub account: Account,
+    pub access_controller_address: AccessControllerAddress,
+}
+
+/// The outcome of an account recovery scan of a factor source.
+#[derive(Clone, PartialEq, Eq, InternalConversion, uniffi::Record)]
+pub struct AccountRecoveryScanOutcome {
+    pub factor_source_id: FactorSourceIDFromHash,
+    pub unsecurified_accounts: Vec<Account>,
+    pub securified_accounts: Vec<AccountRecoveredBehindAccessController>,
+}
+
+#[uniffi::export]
+pub fn new_account_recovery_scan_outcome_sample() -> AccountRecoveryScanOutcome
+{
+    InternalAccountRecoveryScanOutcome::sample().into()
+}
+
+#[uniffi::export]
+pub fn new_account_recovery_scan_outcome_sample_other(
+) -> AccountRecoveryScanOutcome {
+    InternalAccountRecoveryScanOutcome::sample_other().into()
+}
+
+#[uniffi::export]
+pub fn new_account_recovered_behind_access_controller_sample(
+) -> AccountRecoveredBehindAccessController {
+    InternalAccountRecoveredBehindAccessController::sample().into()
+}
+
+#[uniffi::export]
+pub fn new_account_recovered_behind_access_controller_sample_other(
+) -> AccountRecoveredBehindAccessController {
+    InternalAccountRecoveredBehindAccessController::sample_other().into()
+}
+
+decl_conversion_tests_for!(AccountRecoveryScanOutcome);
+decl_conversion_tests_for!(AccountRecoveredBehindAccessController);
@@ -0,0 +1,3 @@
+mod account_recovery_scan_outcome;
+
+pub use account_recovery_scan_outcome::*;
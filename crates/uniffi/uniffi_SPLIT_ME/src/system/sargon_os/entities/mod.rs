@@ -1,15 +1,21 @@
 mod access_controllers;
+mod account_recovery_scan;
 mod apply_shield;
 mod delete_account;
+mod portfolio;
 mod sargon_os_accounts;
 mod sargon_os_entity_query;
 mod sargon_os_securified_state_update;
+mod staking;
 mod sync;
 
 pub use access_controllers::*;
+pub use account_recovery_scan::*;
 pub use apply_shield::*;
 pub use delete_account::*;
+pub use portfolio::*;
 pub use sargon_os_accounts::*;
 pub use sargon_os_entity_query::*;
 pub use sargon_os_securified_state_update::*;
+pub use staking::*;
 pub use sync::*;
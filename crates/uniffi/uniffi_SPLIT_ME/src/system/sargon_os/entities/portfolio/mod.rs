@@ -0,0 +1,5 @@
+mod sargon_os_portfolio_valuation;
+mod support;
+
+pub use sargon_os_portfolio_valuation::*;
+pub use support::*;
@@ -0,0 +1,26 @@
+use sargon::OsPortfolioValuation;
+
+use crate::prelude::*;
+
+// ==================
+// Portfolio Valuation (Public)
+// ==================
+#[uniffi::export]
+impl SargonOS {
+    /// Values the assets held by all non-hidden accounts on the current
+    /// network, per account and in total, in the `FiatCurrency` of the
+    /// display settings of the Profile.
+    ///
+    /// Liquid Stake Units and pool units are valued by the resources they
+    /// can be redeemed for. Assets which could not be priced are listed in
+    /// `unpriced_resources`.
+    pub async fn portfolio_valuation(
+        &self,
+        force_fetch: bool,
+    ) -> Result<PortfolioValuation> {
+        self.wrapped
+            .portfolio_valuation(force_fetch)
+            .await
+            .into_result()
+    }
+}
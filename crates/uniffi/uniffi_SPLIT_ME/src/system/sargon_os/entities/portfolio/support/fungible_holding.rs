@@ -0,0 +1,47 @@
+use crate::prelude::*;
+use sargon::FungibleHolding as InternalFungibleHolding;
+use sargon::FungibleHoldingKind as InternalFungibleHoldingKind;
+use sargon::UnderlyingResourceAmount as InternalUnderlyingResourceAmount;
+
+/// An amount of a resource which a fungible holding is made up of, or can be
+/// redeemed for.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct UnderlyingResourceAmount {
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal192,
+}
+
+/// The kind of a fungible resource held by an account.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Enum)]
+pub enum FungibleHoldingKind {
+    /// Any fungible which is not an LSU or pool unit, e.g. XRD.
+    Token,
+
+    /// The Liquid Stake Unit of `validator_address`.
+    LiquidStakeUnit { validator_address: ValidatorAddress },
+
+    /// The pool unit of the native pool at `pool_address`.
+    PoolUnit { pool_address: PoolAddress },
+}
+
+/// An amount of a fungible resource held by an account.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct FungibleHolding {
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal192,
+    pub kind: FungibleHoldingKind,
+
+    /// What the held `amount` is worth in terms of other resources, `None`
+    /// if the redemption value of any of those resources is unknown.
+    pub underlying: Option<Vec<UnderlyingResourceAmount>>,
+}
+
+#[uniffi::export]
+pub fn new_fungible_holding_sample() -> FungibleHolding {
+    InternalFungibleHolding::sample().into()
+}
+
+#[uniffi::export]
+pub fn new_fungible_holding_sample_other() -> FungibleHolding {
+    InternalFungibleHolding::sample_other().into()
+}
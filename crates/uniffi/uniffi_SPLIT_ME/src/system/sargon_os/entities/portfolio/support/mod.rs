@@ -0,0 +1,5 @@
+mod fungible_holding;
+mod portfolio_valuation;
+
+pub use fungible_holding::*;
+pub use portfolio_valuation::*;
@@ -0,0 +1,50 @@
+use crate::prelude::*;
+use sargon::AccountValuation as InternalAccountValuation;
+use sargon::FungibleValuation as InternalFungibleValuation;
+use sargon::NonFungibleValuation as InternalNonFungibleValuation;
+use sargon::PortfolioValuation as InternalPortfolioValuation;
+
+/// The worth of a fungible held by an account, `None` if it could not be
+/// priced.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct FungibleValuation {
+    pub holding: FungibleHolding,
+    pub worth: Option<Decimal192>,
+}
+
+/// The worth of a non fungible held by an account, `None` if it could not be
+/// priced.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct NonFungibleValuation {
+    pub global_id: NonFungibleGlobalId,
+    pub worth: Option<Decimal192>,
+}
+
+/// The worth of all assets held by an account.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct AccountValuation {
+    pub account_address: AccountAddress,
+    pub worth: Decimal192,
+    pub fungibles: Vec<FungibleValuation>,
+    pub non_fungibles: Vec<NonFungibleValuation>,
+}
+
+/// The worth of the assets of many accounts, in `currency`, and the
+/// resources which could not be priced.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct PortfolioValuation {
+    pub currency: FiatCurrency,
+    pub worth: Decimal192,
+    pub accounts: Vec<AccountValuation>,
+    pub unpriced_resources: Vec<ResourceAddress>,
+}
+
+#[uniffi::export]
+pub fn new_portfolio_valuation_sample() -> PortfolioValuation {
+    InternalPortfolioValuation::sample().into()
+}
+
+#[uniffi::export]
+pub fn new_portfolio_valuation_sample_other() -> PortfolioValuation {
+    InternalPortfolioValuation::sample_other().into()
+}
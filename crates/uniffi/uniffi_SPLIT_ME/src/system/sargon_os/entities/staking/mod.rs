@@ -0,0 +1,5 @@
+mod sargon_os_staking_overview;
+mod support;
+
+pub use sargon_os_staking_overview::*;
+pub use support::*;
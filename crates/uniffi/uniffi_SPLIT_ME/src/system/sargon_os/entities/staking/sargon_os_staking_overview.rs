@@ -0,0 +1,22 @@
+use sargon::OsStakingOverview;
+
+use crate::prelude::*;
+
+// ==================
+// Staking Overview (Public)
+// ==================
+#[uniffi::export]
+impl SargonOS {
+    /// Fetches the staking overview of each account, in the same order as
+    /// `account_addresses`: the XRD staked per validator, and the pending
+    /// and claimable unstakes.
+    pub async fn staking_overview_of_accounts(
+        &self,
+        account_addresses: Vec<AccountAddress>,
+    ) -> Result<Vec<AccountStakingOverview>> {
+        self.wrapped
+            .staking_overview_of_accounts(account_addresses.into_internal())
+            .await
+            .into_result()
+    }
+}
@@ -0,0 +1,123 @@
+use crate::prelude::*;
+use sargon::AccountStakingOverview as InternalAccountStakingOverview;
+use sargon::StakeClaim as InternalStakeClaim;
+use sargon::StakeClaimNonFungible as InternalStakeClaimNonFungible;
+use sargon::ValidatorLiquidStake as InternalValidatorLiquidStake;
+use sargon::ValidatorStakeClaims as InternalValidatorStakeClaims;
+use sargon::ValidatorStakingOverview as InternalValidatorStakingOverview;
+
+/// A claim NFT of a validator, received when unstaking, which can be
+/// redeemed for `claim_amount` XRD once `claim_epoch` has been reached.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct StakeClaimNonFungible {
+    pub id: NonFungibleLocalId,
+    pub claim_amount: Decimal192,
+    pub claim_epoch: Epoch,
+}
+
+/// The Liquid Stake Units (LSU) of a validator held by an account, and the
+/// amount of XRD they can be redeemed for.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct ValidatorLiquidStake {
+    pub resource_address: ResourceAddress,
+    pub stake_units: Decimal192,
+    pub staked_xrd: Decimal192,
+}
+
+/// The claim NFTs of a validator held by an account, split by whether they
+/// can be claimed at the current epoch.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct ValidatorStakeClaims {
+    pub resource_address: NonFungibleResourceAddress,
+    pub pending: Vec<StakeClaimNonFungible>,
+    pub claimable: Vec<StakeClaimNonFungible>,
+}
+
+/// The stake of an account with a single validator.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct ValidatorStakingOverview {
+    pub validator_address: ValidatorAddress,
+    pub liquid_stake: Option<ValidatorLiquidStake>,
+    pub stake_claims: Option<ValidatorStakeClaims>,
+}
+
+/// The staking overview of an account, with its stake per validator.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct AccountStakingOverview {
+    pub account_address: AccountAddress,
+    pub current_epoch: Epoch,
+    pub validators: Vec<ValidatorStakingOverview>,
+}
+
+#[uniffi::export]
+pub fn new_account_staking_overview_sample() -> AccountStakingOverview {
+    InternalAccountStakingOverview::sample().into()
+}
+
+#[uniffi::export]
+pub fn new_account_staking_overview_sample_other() -> AccountStakingOverview {
+    InternalAccountStakingOverview::sample_other().into()
+}
+
+/// The stake claims of the account of `overview` which can be claimed now,
+/// one per validator, ready to be used to build a claim manifest.
+#[uniffi::export]
+pub fn account_staking_overview_claimable_stake_claims(
+    overview: &AccountStakingOverview,
+) -> Vec<StakeClaim> {
+    InternalStakeClaim::claimable_in(&overview.into_internal()).into_type()
+}
+
+#[uniffi::export]
+pub fn account_staking_overview_total_staked_xrd(
+    overview: &AccountStakingOverview,
+) -> Decimal192 {
+    overview.into_internal().total_staked_xrd().into()
+}
+
+#[uniffi::export]
+pub fn account_staking_overview_total_claimable_xrd(
+    overview: &AccountStakingOverview,
+) -> Decimal192 {
+    overview.into_internal().total_claimable_xrd().into()
+}
+
+decl_conversion_tests_for!(AccountStakingOverview);
+
+#[cfg(test)]
+mod uniffi_tests {
+    use super::*;
+
+    #[allow(clippy::upper_case_acronyms)]
+    type SUT = AccountStakingOverview;
+
+    #[test]
+    fn claimable_stake_claims() {
+        let sut = new_account_staking_overview_sample();
+        let expected: Vec<StakeClaim> = InternalStakeClaim::claimable_in(
+            &InternalAccountStakingOverview::sample(),
+        )
+        .into_type();
+        assert_eq!(
+            account_staking_overview_claimable_stake_claims(&sut),
+            expected
+        );
+        assert!(account_staking_overview_claimable_stake_claims(
+            &new_account_staking_overview_sample_other()
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn totals() {
+        let sut: SUT = InternalAccountStakingOverview::sample().into();
+        assert_eq!(
+            account_staking_overview_total_staked_xrd(&sut),
+            new_decimal_from_i32(1050)
+        );
+        assert_eq!(
+            account_staking_overview_total_claimable_xrd(&sut),
+            new_decimal_from_i32(100)
+        );
+    }
+}
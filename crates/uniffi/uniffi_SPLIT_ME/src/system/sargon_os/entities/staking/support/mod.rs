@@ -0,0 +1,3 @@
+mod account_staking_overview;
+
+pub use account_staking_overview::*;
@@ -7,20 +7,30 @@ use crate::prelude::*;
 // ==================
 #[uniffi::export]
 impl SargonOS {
-    /// Syncs entities in profile with on ledger state.
-    /// Returns a summary report of the actions performed.
+    /// Syncs entities in profile on the current network with their state on
+    /// ledger. Returns a summary report of the differences found and - in
+    /// `EntitySyncMode::Apply` - of the actions performed on profile.
     ///
     /// Checks performed on entities are:
     /// 1. Checks if active accounts on profile are deleted on ledger.
     ///    Action => to mark them as tombstoned
     /// 2. Checks if entities with provisional shield are securified on ledger.
     ///    Action => to mark them as securified
-    /// 3. more checks will be developed...
-    async fn wip_sync_entities_state_on_ledger(
+    /// 3. Checks if entities are controlled on ledger by an access controller
+    ///    unknown to profile, i.e. the shield was changed from another wallet.
+    ///    Reported only
+    /// 4. Checks if a timed recovery is in progress on the access controller
+    ///    of securified entities.
+    ///    Reported only
+    /// 5. Checks if the third-party deposit settings of non deleted accounts
+    ///    differ on ledger.
+    ///    Action => to replace the on ledger settings in profile
+    pub async fn sync_entities_state_on_ledger(
         &self,
+        mode: EntitySyncMode,
     ) -> Result<EntitySyncOutcome> {
         self.wrapped
-            .wip_sync_entities_state_on_ledger()
+            .sync_entities_state_on_ledger(mode.into_internal())
             .await
             .into_result()
     }
@@ -6,4 +6,5 @@ use sargon::EntitySyncActionPerformed as InternalEntitySyncActionPerformed;
 pub enum EntitySyncActionPerformed {
     SomeEntitiesTombstoned,
     SomeEntitiesSecurified,
+    SomeAccountsOnLedgerSettingsUpdated,
 }
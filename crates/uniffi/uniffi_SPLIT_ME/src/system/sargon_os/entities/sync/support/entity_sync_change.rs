@@ -0,0 +1,45 @@
+use crate::prelude::*;
+use sargon::EntitySyncChange as InternalEntitySyncChange;
+
+/// A difference between an entity in profile and its state on ledger.
+#[derive(Clone, PartialEq, Eq, InternalConversion, uniffi::Enum)]
+pub enum EntitySyncChange {
+    /// The account has been deleted on ledger, applied by tombstoning it.
+    AccountDeleted { account_address: AccountAddress },
+
+    /// The provisional shield of the entity has been applied on ledger,
+    /// applied by marking the entity as securified.
+    EntitySecurified {
+        entity_address: AddressOfAccountOrPersona,
+        access_controller_address: AccessControllerAddress,
+    },
+
+    /// The entity is controlled on ledger by an access controller this
+    /// wallet did not set up - e.g. the shield was applied or changed from
+    /// another wallet - so the factor instances securing it are unknown.
+    ///
+    /// Never applied, the user needs to be informed.
+    ShieldChangedElsewhere {
+        entity_address: AddressOfAccountOrPersona,
+        access_controller_address: AccessControllerAddress,
+    },
+
+    /// A recovery using the recovery role has been initiated on the access
+    /// controller of the entity, which can be confirmed without the
+    /// confirmation role after `allow_timed_recovery_after`.
+    ///
+    /// Never applied, the user needs to be informed.
+    TimedRecoveryInProgress {
+        entity_address: AddressOfAccountOrPersona,
+        access_controller_address: AccessControllerAddress,
+        allow_timed_recovery_after: Instant,
+    },
+
+    /// The on ledger settings of the account differ from the ones in
+    /// profile, applied by replacing the settings in profile with
+    /// `on_ledger_settings`.
+    OnLedgerSettingsChanged {
+        account_address: AccountAddress,
+        on_ledger_settings: OnLedgerSettings,
+    },
+}
@@ -0,0 +1,11 @@
+use crate::prelude::*;
+use sargon::EntitySyncFailure as InternalEntitySyncFailure;
+
+/// An entity which could not be fully synced with its state on ledger.
+#[derive(Clone, PartialEq, Eq, InternalConversion, uniffi::Record)]
+pub struct EntitySyncFailure {
+    pub entity_address: AddressOfAccountOrPersona,
+
+    /// Description of the error which made the sync of the entity fail.
+    pub error_message: String,
+}
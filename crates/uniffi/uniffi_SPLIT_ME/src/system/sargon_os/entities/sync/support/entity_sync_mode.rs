@@ -0,0 +1,15 @@
+use crate::prelude::*;
+use sargon::EntitySyncMode as InternalEntitySyncMode;
+
+/// Whether the differences found when syncing entities with their state on
+/// ledger should be written to profile or only be reported.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, InternalConversion, uniffi::Enum,
+)]
+pub enum EntitySyncMode {
+    /// Updates profile with the state on ledger where possible.
+    Apply,
+
+    /// Only reports the differences, leaving profile untouched.
+    Preview,
+}
@@ -1,8 +1,21 @@
 use crate::prelude::*;
 use sargon::EntitySyncOutcome as InternalEntitySyncOutcome;
 
-/// The report that gathers the different actions performed on profile after sync completes.
-#[derive(Clone, Debug, InternalConversion, uniffi::Record)]
+/// The report that gathers the differences found between the entities in
+/// profile and their state on ledger, and the different actions performed on
+/// profile after sync completes.
+#[derive(Clone, InternalConversion, uniffi::Record)]
 pub struct EntitySyncOutcome {
+    /// Every difference found between profile and ledger, in the order of
+    /// the entities in profile.
+    pub changes: Vec<EntitySyncChange>,
+
+    /// The kinds of actions performed on profile, always empty if the sync
+    /// ran in `EntitySyncMode::Preview`.
     pub actions_performed: Vec<EntitySyncActionPerformed>,
+
+    /// The entities which could not be fully synced, e.g. since a request
+    /// to the Gateway failed. All other differences are still found and
+    /// - in `EntitySyncMode::Apply` - applied.
+    pub failures: Vec<EntitySyncFailure>,
 }
@@ -1,5 +1,11 @@
 mod entity_sync_action_performed;
+mod entity_sync_change;
+mod entity_sync_failure;
+mod entity_sync_mode;
 mod entity_sync_outcome;
 
 pub use entity_sync_action_performed::*;
+pub use entity_sync_change::*;
+pub use entity_sync_failure::*;
+pub use entity_sync_mode::*;
 pub use entity_sync_outcome::*;
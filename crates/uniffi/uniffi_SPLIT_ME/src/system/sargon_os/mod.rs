@@ -6,7 +6,9 @@ mod profile_state_holder;
 mod sargon_os;
 mod sargon_os_address_book;
 mod sargon_os_apply_security_shield_interaction;
+mod sargon_os_apply_shield_transactions_queue;
 mod sargon_os_confirm_timed_recovery_interaction;
+mod sargon_os_dapp_interaction;
 mod sargon_os_derive_public_keys;
 mod sargon_os_entities_linked_to_factor_source;
 mod sargon_os_entities_linked_to_security_structure;
@@ -14,6 +16,7 @@ mod sargon_os_factor_source_integrity;
 mod sargon_os_factors;
 mod sargon_os_fungible_fiat_values;
 mod sargon_os_gateway;
+mod sargon_os_lock_primary_role_interaction;
 mod sargon_os_nft_fiat_values;
 mod sargon_os_p2p_transport_profiles;
 mod sargon_os_personas;
@@ -25,6 +28,7 @@ mod sargon_os_security_structures;
 mod sargon_os_signing;
 mod sargon_os_stop_timed_recovery_interaction;
 mod sargon_os_token_price_services;
+mod sargon_os_watch_only_accounts;
 mod transactions;
 
 pub use add_factor_source::*;
@@ -35,7 +39,9 @@ pub use profile_state_holder::*;
 pub use sargon_os::*;
 pub use sargon_os_address_book::*;
 pub use sargon_os_apply_security_shield_interaction::*;
+pub use sargon_os_apply_shield_transactions_queue::*;
 pub use sargon_os_confirm_timed_recovery_interaction::*;
+pub use sargon_os_dapp_interaction::*;
 pub use sargon_os_derive_public_keys::*;
 pub use sargon_os_entities_linked_to_factor_source::*;
 pub use sargon_os_entities_linked_to_security_structure::*;
@@ -43,6 +49,7 @@ pub use sargon_os_factor_source_integrity::*;
 pub use sargon_os_factors::*;
 pub use sargon_os_fungible_fiat_values::*;
 pub use sargon_os_gateway::*;
+pub use sargon_os_lock_primary_role_interaction::*;
 pub use sargon_os_nft_fiat_values::*;
 pub use sargon_os_p2p_transport_profiles::*;
 pub use sargon_os_personas::*;
@@ -54,4 +61,5 @@ pub use sargon_os_security_structures::*;
 pub use sargon_os_signing::*;
 pub use sargon_os_stop_timed_recovery_interaction::*;
 pub use sargon_os_token_price_services::*;
+pub use sargon_os_watch_only_accounts::*;
 pub use transactions::*;
@@ -1,5 +1,6 @@
 use crate::prelude::*;
 use sargon::Interactors;
+use sargon::OsApplyShieldTransactionsQueue;
 use sargon::SargonOS as InternalSargonOS;
 
 /// The Sargon "Operating System" is the root "manager" of the Sargon library
@@ -34,6 +35,12 @@ impl SargonOS {
         )
         .await;
 
+        // Resume submitting transactions applying shields which were
+        // enqueued before the app was restarted.
+        internal_sargon_os
+            .clone()
+            .process_apply_shield_transactions_queue_in_background();
+
         Result::Ok(Arc::new(SargonOS {
             wrapped: internal_sargon_os,
         }))
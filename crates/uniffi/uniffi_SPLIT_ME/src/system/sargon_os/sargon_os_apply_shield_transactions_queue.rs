@@ -0,0 +1,69 @@
+use sargon::ApplyShieldQueuedTransaction as InternalApplyShieldQueuedTransaction;
+use sargon::ApplyShieldQueuedTransactionStatus as InternalApplyShieldQueuedTransactionStatus;
+use sargon::OsApplyShieldTransactionsQueue;
+
+use crate::prelude::*;
+
+/// A signed transaction applying a shield to an entity, waiting in the
+/// persisted queue of apply shield transactions to be submitted, or to have
+/// its commit confirmed.
+#[derive(Clone, PartialEq, Eq, InternalConversion, uniffi::Record)]
+pub struct ApplyShieldQueuedTransaction {
+    /// The entity this transaction applies a shield to.
+    pub entity_address: AddressOfAccountOrPersona,
+
+    pub compiled_notarized_intent: CompiledNotarizedIntent,
+
+    /// The roles of the entity the transaction was signed with.
+    pub role_kinds: Vec<RoleKind>,
+
+    pub status: ApplyShieldQueuedTransactionStatus,
+}
+
+/// The progress of an `ApplyShieldQueuedTransaction`.
+#[derive(Clone, PartialEq, Eq, InternalConversion, uniffi::Enum)]
+pub enum ApplyShieldQueuedTransactionStatus {
+    /// Not yet submitted, or needs to be submitted again.
+    Queued,
+
+    /// Submitted to the network, but not yet committed.
+    Submitted,
+
+    /// Committed successfully and the provisional security structure of the
+    /// entity has been marked as applied.
+    Committed,
+
+    /// The transaction failed or was rejected, or a transaction preceding it
+    /// in the queue did.
+    Failed { reason: String },
+}
+
+#[uniffi::export]
+impl SargonOS {
+    /// The signed transactions applying shields which have been enqueued,
+    /// in submission order.
+    pub async fn apply_shield_transactions_queue(
+        &self,
+    ) -> Result<Vec<ApplyShieldQueuedTransaction>> {
+        self.wrapped
+            .apply_shield_transactions_queue()
+            .await
+            .into_iter_result()
+    }
+
+    /// Submits the queued transactions applying shields one at a time,
+    /// waiting for each one to be committed before submitting the next one.
+    ///
+    /// The queue is also processed in the background when the OS boots, so
+    /// hosts typically only call this to await the processing.
+    ///
+    /// Returns the queue once processed.
+    pub async fn process_apply_shield_transactions_queue(
+        &self,
+    ) -> Result<Vec<ApplyShieldQueuedTransaction>> {
+        self.wrapped
+            .process_apply_shield_transactions_queue()
+            .await
+            .into_iter_result()
+    }
+}
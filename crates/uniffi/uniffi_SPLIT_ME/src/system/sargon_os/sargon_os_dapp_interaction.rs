@@ -0,0 +1,174 @@
+use crate::prelude::*;
+use sargon::DappInteractionAccountsDecision as InternalDappInteractionAccountsDecision;
+use sargon::DappInteractionDecision as InternalDappInteractionDecision;
+use sargon::DappInteractionPersonaDataDecision as InternalDappInteractionPersonaDataDecision;
+use sargon::DappInteractionPlan as InternalDappInteractionPlan;
+use sargon::DappInteractionStep as InternalDappInteractionStep;
+use sargon::DappOriginVerdict as InternalDappOriginVerdict;
+
+/// The result of validating a `DappToWalletInteractionUnvalidated` against the
+/// active Profile, telling the host what it must ask the user, if anything,
+/// before it can respond to the dApp.
+#[derive(Clone, PartialEq, InternalConversion, uniffi::Enum)]
+#[allow(clippy::large_enum_variant)]
+pub enum DappInteractionPlan {
+    /// The interaction cannot be fulfilled, the host should send `response`
+    /// to the dApp without involving the user.
+    Reject {
+        response: WalletToDappInteractionResponse,
+    },
+
+    /// A Transaction, batch of Transactions or Pre-Authorization, which is
+    /// handled by the transaction review flows.
+    Review {
+        interaction: DappToWalletInteraction,
+    },
+
+    /// An authorized or unauthorized request, the user must complete all
+    /// `steps` before the host responds using `respond_to_dapp_interaction`.
+    Request {
+        interaction: DappToWalletInteraction,
+        steps: Vec<DappInteractionStep>,
+    },
+}
+
+/// A single decision the user must make to fulfill a dApp request.
+#[derive(Clone, PartialEq, InternalConversion, uniffi::Enum)]
+pub enum DappInteractionStep {
+    Login {
+        remembered_persona: Option<IdentityAddress>,
+        challenge: Option<DappToWalletInteractionAuthChallengeNonce>,
+    },
+
+    ChooseAccounts {
+        request: DappToWalletInteractionAccountsRequestItem,
+        is_ongoing: bool,
+    },
+
+    ChoosePersonaData {
+        request: DappToWalletInteractionPersonaDataRequestItem,
+        is_ongoing: bool,
+    },
+
+    ProveOwnership {
+        request: DappToWalletInteractionProofOfOwnershipRequestItem,
+    },
+}
+
+/// What the user decided for the steps of a `DappInteractionPlan::Request`.
+#[derive(Clone, PartialEq, InternalConversion, uniffi::Record)]
+pub struct DappInteractionDecision {
+    pub persona: Option<IdentityAddress>,
+    pub auth_proof: Option<WalletToDappInteractionAuthProof>,
+    pub ongoing_accounts: Option<DappInteractionAccountsDecision>,
+    pub ongoing_persona_data: Option<DappInteractionPersonaDataDecision>,
+    pub one_time_accounts: Option<DappInteractionAccountsDecision>,
+    pub one_time_persona_data: Option<DappInteractionPersonaDataDecision>,
+    pub proof_of_ownership:
+        Option<WalletToDappInteractionProofOfOwnershipRequestResponseItem>,
+}
+
+/// The accounts the user chose to share with a dApp.
+#[derive(Clone, PartialEq, InternalConversion, uniffi::Record)]
+pub struct DappInteractionAccountsDecision {
+    pub accounts: Vec<AccountAddress>,
+    pub proofs: Option<Vec<WalletToDappInteractionAccountProof>>,
+}
+
+/// The Persona data entries the user chose to share with a dApp.
+#[derive(Clone, PartialEq, InternalConversion, uniffi::Record)]
+pub struct DappInteractionPersonaDataDecision {
+    pub name: Option<PersonaDataEntryID>,
+    pub email_addresses: Vec<PersonaDataEntryID>,
+    pub phone_numbers: Vec<PersonaDataEntryID>,
+    pub postal_addresses: Vec<PersonaDataEntryID>,
+    pub dates_of_birth: Vec<PersonaDataEntryID>,
+    pub companies: Vec<PersonaDataEntryID>,
+}
+
+/// The outcome of verifying that a `DappOrigin` and a `DappDefinitionAddress`
+/// claim each other, using the `.well-known/radix.json` file hosted on the
+/// origin and the on-ledger metadata of the dApp definition.
+#[derive(Clone, PartialEq, InternalConversion, uniffi::Enum)]
+pub enum DappOriginVerdict {
+    Verified { claimed_entities: Vec<Address> },
+    InvalidOrigin,
+    WellKnownFileMissing,
+    WellKnownFileInvalid,
+    DappDefinitionNotInWellKnownFile,
+    NotADappDefinition,
+    WebsiteNotClaimed,
+}
+
+#[uniffi::export]
+pub fn dapp_origin_verdict_failure_error_type(
+    verdict: &DappOriginVerdict,
+) -> Option<DappWalletInteractionErrorType> {
+    verdict
+        .into_internal()
+        .failure_error_type()
+        .map(DappWalletInteractionErrorType::from)
+}
+
+#[uniffi::export]
+impl SargonOS {
+    /// Validates `interaction` against the current network of the active
+    /// Profile and returns a plan of what the user must decide, if anything,
+    /// before the host can respond to the dApp.
+    ///
+    /// Interactions whose origin fails verification are rejected, unless
+    /// developer mode is enabled.
+    pub async fn prepare_dapp_interaction(
+        &self,
+        interaction: DappToWalletInteractionUnvalidated,
+    ) -> Result<DappInteractionPlan> {
+        self.wrapped
+            .prepare_dapp_interaction(interaction.into_internal())
+            .await
+            .into_result()
+    }
+
+    /// Verifies that `origin` and `dapp_definition_address` claim each other,
+    /// verdicts are cached for a few minutes.
+    pub async fn verify_dapp_origin(
+        &self,
+        origin: DappOrigin,
+        dapp_definition_address: DappDefinitionAddress,
+    ) -> Result<DappOriginVerdict> {
+        self.wrapped
+            .verify_dapp_origin(
+                origin.into_internal(),
+                dapp_definition_address.into_internal(),
+            )
+            .await
+            .into_result()
+    }
+
+    /// Removes the `AuthorizedDapp` from Profile and revokes all Radix Connect
+    /// Mobile sessions established with it.
+    pub async fn forget_authorized_dapp(
+        &self,
+        dapp_definition_address: DappDefinitionAddress,
+    ) -> Result<()> {
+        self.wrapped
+            .forget_authorized_dapp(dapp_definition_address.into_internal())
+            .await
+            .into_result()
+    }
+
+    /// Builds the response to the dApp from the `decision` of the user and,
+    /// for authorized requests, saves the updated `AuthorizedDapp` into Profile.
+    pub async fn respond_to_dapp_interaction(
+        &self,
+        interaction: DappToWalletInteraction,
+        decision: DappInteractionDecision,
+    ) -> Result<WalletToDappInteractionResponse> {
+        self.wrapped
+            .respond_to_dapp_interaction(
+                interaction.into_internal(),
+                decision.into_internal(),
+            )
+            .await
+            .into_result()
+    }
+}
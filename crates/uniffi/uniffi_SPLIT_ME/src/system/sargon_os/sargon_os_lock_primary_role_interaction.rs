@@ -0,0 +1,26 @@
+use crate::prelude::*;
+
+#[uniffi::export]
+impl SargonOS {
+    /// Creates a manifest locking the Primary role of the access controller
+    /// of the securified entity at `address`, signed with the Recovery role.
+    pub fn make_lock_primary_role_manifest(
+        &self,
+        address: AddressOfAccountOrPersona,
+    ) -> Result<TransactionManifest> {
+        self.wrapped
+            .make_lock_primary_role_manifest(address.into())
+            .into_result()
+    }
+
+    /// Creates a manifest unlocking the Primary role of the access controller
+    /// of the securified entity at `address`, signed with the Recovery role.
+    pub fn make_unlock_primary_role_manifest(
+        &self,
+        address: AddressOfAccountOrPersona,
+    ) -> Result<TransactionManifest> {
+        self.wrapped
+            .make_unlock_primary_role_manifest(address.into())
+            .into_result()
+    }
+}
@@ -8,6 +8,43 @@ impl SargonOS {
         self.wrapped.set_profile(profile.into()).await.into_result()
     }
 
+    /// Three-way merges the active Profile, being the local snapshot, with
+    /// the `remote` snapshot of the same Profile, e.g. from a backup, where
+    /// both have diverged from the common `base` snapshot.
+    ///
+    /// Does **not** change the active Profile, once the user has resolved
+    /// the conflicts of the merge, call `apply_profile_merge`.
+    pub fn merge_profile(
+        &self,
+        base: Profile,
+        remote: Profile,
+    ) -> Result<ProfileMerge> {
+        self.wrapped
+            .merge_profile(base.into_internal(), remote.into_internal())
+            .into_result()
+    }
+
+    /// Resolves the conflicts of `merge` with `resolutions`, one resolution
+    /// per conflict in the same order, and sets the resolved Profile as the
+    /// active one, saving it into secure storage.
+    ///
+    /// # Emits Event
+    /// Emits `Event::ProfileSaved` after having successfully written the JSON
+    /// of the active profile to secure storage.
+    pub async fn apply_profile_merge(
+        &self,
+        merge: ProfileMerge,
+        resolutions: Vec<ProfileMergeConflictResolution>,
+    ) -> Result<Profile> {
+        self.wrapped
+            .apply_profile_merge(
+                merge.into_internal(),
+                resolutions.into_internal(),
+            )
+            .await
+            .into_result()
+    }
+
     /// Checks if current Profile contains any `ProfileNetwork`s.
     pub fn has_any_network(&self) -> Result<bool> {
         self.wrapped.has_any_network().into_result()
@@ -11,4 +11,17 @@ impl SargonOS {
         let mobile = self.wrapped.radix_connect_mobile();
         RadixConnectMobile::from_internal(mobile)
     }
+
+    /// Handles a Radix Connect Mobile deep link, rejecting requests
+    /// establishing a new session whose origin fails verification, unless
+    /// developer mode is enabled.
+    pub async fn handle_radix_connect_mobile_deep_link(
+        &self,
+        url: String,
+    ) -> Result<RadixConnectMobileSessionRequest> {
+        self.wrapped
+            .handle_radix_connect_mobile_deep_link(url)
+            .await
+            .into_result()
+    }
 }
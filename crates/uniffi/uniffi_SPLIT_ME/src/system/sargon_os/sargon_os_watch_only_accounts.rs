@@ -0,0 +1,76 @@
+use crate::prelude::*;
+
+#[uniffi::export]
+impl SargonOS {
+    /// Returns the watch-only accounts on the current network, empty if no
+    /// watch-only accounts on the network.
+    pub fn watch_only_accounts_on_current_network(
+        &self,
+    ) -> Result<Vec<WatchOnlyAccount>> {
+        let watch_only_accounts: sargon::WatchOnlyAccounts = self
+            .wrapped
+            .watch_only_accounts_on_current_network()
+            .into_result()?;
+        Ok(watch_only_accounts.into_iter().map(Into::into).collect())
+    }
+}
+
+#[uniffi::export]
+impl SargonOS {
+    /// Starts watching the account of `address` on the current network.
+    ///
+    /// # Emits Event
+    /// Emits `Event::ProfileModified { change: EventProfileModified::WatchOnlyAccountAdded { address } }`
+    pub async fn add_watch_only_account(
+        &self,
+        address: AccountAddress,
+        display_name: DisplayName,
+        appearance_id: AppearanceID,
+    ) -> Result<()> {
+        self.wrapped
+            .add_watch_only_account(
+                address.into_internal(),
+                display_name.into_internal(),
+                appearance_id.into_internal(),
+            )
+            .await
+            .into_result()
+    }
+
+    /// Updates the display name and appearance of the watch-only account of
+    /// `address` on the current network. Returns false if no such account
+    /// is watched.
+    ///
+    /// # Emits Event
+    /// Emits `Event::ProfileModified { change: EventProfileModified::WatchOnlyAccountUpdated { address } }`
+    pub async fn update_watch_only_account(
+        &self,
+        address: AccountAddress,
+        display_name: DisplayName,
+        appearance_id: AppearanceID,
+    ) -> Result<bool> {
+        self.wrapped
+            .update_watch_only_account(
+                address.into_internal(),
+                display_name.into_internal(),
+                appearance_id.into_internal(),
+            )
+            .await
+            .into_result()
+    }
+
+    /// Stops watching the account of `address` on the current network.
+    /// Returns false if no such account is watched.
+    ///
+    /// # Emits Event
+    /// Emits `Event::ProfileModified { change: EventProfileModified::WatchOnlyAccountRemoved { address } }`
+    pub async fn remove_watch_only_account(
+        &self,
+        address: AccountAddress,
+    ) -> Result<bool> {
+        self.wrapped
+            .remove_watch_only_account(address.into_internal())
+            .await
+            .into_result()
+    }
+}
@@ -1,13 +1,21 @@
+mod sargon_os_pending_transactions;
+mod sargon_os_pool_manifests;
 mod sargon_os_subintent_manifest_modify;
 mod sargon_os_transaction_analysis;
+mod sargon_os_transaction_history;
 mod sargon_os_transaction_manifest_modify;
+mod sargon_os_transaction_message;
 mod sargon_os_transaction_status;
 mod sargon_os_transaction_submit;
 mod support;
 
+pub use sargon_os_pending_transactions::*;
+pub use sargon_os_pool_manifests::*;
 pub use sargon_os_subintent_manifest_modify::*;
 pub use sargon_os_transaction_analysis::*;
+pub use sargon_os_transaction_history::*;
 pub use sargon_os_transaction_manifest_modify::*;
+pub use sargon_os_transaction_message::*;
 pub use sargon_os_transaction_status::*;
 pub use sargon_os_transaction_submit::*;
 pub use support::*;
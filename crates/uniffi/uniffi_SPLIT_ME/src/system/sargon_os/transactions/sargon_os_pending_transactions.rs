@@ -0,0 +1,52 @@
+use sargon::OsPendingTransactions;
+
+use crate::prelude::*;
+
+// ==================
+// Pending Transactions (Public)
+// ==================
+#[uniffi::export]
+impl SargonOS {
+    /// Returns all the Transactions and Pre-Authorizations whose status is
+    /// still being tracked.
+    pub async fn pending_transactions(
+        &self,
+    ) -> Result<Vec<PendingTransaction>> {
+        self.wrapped.pending_transactions().await.into_iter_result()
+    }
+
+    /// Stops tracking the given pending transaction, which also cancels any
+    /// ongoing polling of its status.
+    pub async fn cancel_pending_transaction(
+        &self,
+        pending_transaction: PendingTransaction,
+    ) -> Result<()> {
+        self.wrapped
+            .cancel_pending_transaction(pending_transaction.into_internal())
+            .await
+            .into_result()
+    }
+
+    /// Polls the status of the given pending transaction with a bounded
+    /// exponential backoff, until it is committed, failed or expired.
+    pub async fn poll_pending_transaction(
+        &self,
+        pending_transaction: PendingTransaction,
+    ) -> Result<PendingTransactionOutcome> {
+        self.wrapped
+            .poll_pending_transaction(pending_transaction.into_internal())
+            .await
+            .into_result()
+    }
+
+    /// Resumes polling of all tracked pending transactions, typically called
+    /// after the app has been restarted.
+    pub async fn resume_polling_pending_transactions(
+        &self,
+    ) -> Result<Vec<PendingTransactionOutcome>> {
+        self.wrapped
+            .resume_polling_pending_transactions()
+            .await
+            .into_iter_result()
+    }
+}
@@ -0,0 +1,49 @@
+use sargon::OsPoolManifests;
+
+use crate::prelude::*;
+
+// ==================
+// Pool Contribution and Redemption Manifests (Public)
+// ==================
+#[uniffi::export]
+impl SargonOS {
+    /// Creates a manifest contributing `contributions` from `account_address`
+    /// to the pool of `pool_address`, guaranteeing the pool units expected at
+    /// the current ratio of the pool according to the
+    /// `default_deposit_guarantee` of the transaction preferences.
+    pub async fn create_pool_contribution_manifest(
+        &self,
+        account_address: AccountAddress,
+        pool_address: PoolAddress,
+        contributions: HashMap<ResourceAddress, Decimal192>,
+    ) -> Result<TransactionManifest> {
+        self.wrapped
+            .create_pool_contribution_manifest(
+                account_address.into_internal(),
+                pool_address.into_internal(),
+                contributions.into_internal(),
+            )
+            .await
+            .into_result()
+    }
+
+    /// Creates a manifest redeeming `pool_units` of the pool of
+    /// `pool_address` from `account_address`, guaranteeing the resources
+    /// expected at the current state of the pool according to the
+    /// `default_deposit_guarantee` of the transaction preferences.
+    pub async fn create_pool_redemption_manifest(
+        &self,
+        account_address: AccountAddress,
+        pool_address: PoolAddress,
+        pool_units: Decimal192,
+    ) -> Result<TransactionManifest> {
+        self.wrapped
+            .create_pool_redemption_manifest(
+                account_address.into_internal(),
+                pool_address.into_internal(),
+                pool_units.into_internal(),
+            )
+            .await
+            .into_result()
+    }
+}
@@ -0,0 +1,28 @@
+use sargon::OsTransactionHistory;
+
+use crate::prelude::*;
+
+// ==================
+// Fetch Transaction History (Public)
+// ==================
+#[uniffi::export]
+impl SargonOS {
+    /// Fetches a page of the transaction history of `account_address`, most
+    /// recent first. Pass the `next_cursor` of the previous page as `cursor`
+    /// to fetch the next page.
+    pub async fn fetch_transaction_history(
+        &self,
+        account_address: AccountAddress,
+        filters: TransactionHistoryFilters,
+        cursor: Option<String>,
+    ) -> Result<TransactionHistoryPage> {
+        self.wrapped
+            .fetch_transaction_history(
+                account_address.into_internal(),
+                filters.into_internal(),
+                cursor,
+            )
+            .await
+            .into_result()
+    }
+}
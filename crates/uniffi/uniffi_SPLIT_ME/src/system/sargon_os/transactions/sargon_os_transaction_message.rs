@@ -0,0 +1,53 @@
+use sargon::OsTransactionMessageDecryption;
+use sargon::OsTransactionMessageEncryption;
+
+use crate::prelude::*;
+
+// ==================
+// Encrypt Transaction Message (Public)
+// ==================
+#[uniffi::export]
+impl SargonOS {
+    /// Encrypts `message` for the accounts of `recipients`, e.g. the accounts
+    /// receiving a transfer, and sets it as the message of `intent`.
+    ///
+    /// Fails if no public key is known for any of the recipients.
+    pub async fn set_encrypted_transaction_message(
+        &self,
+        intent: TransactionIntent,
+        message: PlaintextMessage,
+        recipients: Vec<AccountAddress>,
+    ) -> Result<TransactionIntent> {
+        self.wrapped
+            .set_encrypted_transaction_message(
+                intent.into_internal(),
+                message.into_internal(),
+                recipients.into_internal(),
+            )
+            .await
+            .into_result()
+    }
+}
+
+// ==================
+// Decrypt Transaction Message (Public)
+// ==================
+#[uniffi::export]
+impl SargonOS {
+    /// Decrypts an encrypted transaction message received by the account
+    /// with `account_address`, using the private key of the factor instance
+    /// of the account which the message was encrypted for.
+    pub async fn decrypt_transaction_message(
+        &self,
+        message: EncryptedMessage,
+        account_address: AccountAddress,
+    ) -> Result<PlaintextMessage> {
+        self.wrapped
+            .decrypt_transaction_message(
+                message.into_internal(),
+                account_address.into_internal(),
+            )
+            .await
+            .into_result()
+    }
+}
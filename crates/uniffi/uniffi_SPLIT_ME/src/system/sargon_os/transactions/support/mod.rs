@@ -1,5 +1,9 @@
+mod pending_transaction;
 mod pre_auth_to_review;
+mod transaction_history;
 mod transaction_to_review;
 
+pub use pending_transaction::*;
 pub use pre_auth_to_review::*;
+pub use transaction_history::*;
 pub use transaction_to_review::*;
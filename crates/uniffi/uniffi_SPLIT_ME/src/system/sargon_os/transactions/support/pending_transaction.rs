@@ -0,0 +1,36 @@
+use crate::prelude::*;
+use sargon::PendingTransaction as InternalPendingTransaction;
+use sargon::PendingTransactionOutcome as InternalPendingTransactionOutcome;
+
+/// A submitted Transaction or Pre-Authorization which the wallet keeps
+/// polling the status of, persisted so that polling survives app restarts.
+#[derive(Clone, PartialEq, InternalConversion, uniffi::Enum)]
+pub enum PendingTransaction {
+    /// A Transaction submitted to the Gateway, which can no longer be
+    /// committed once the ledger has reached `end_epoch_exclusive`.
+    Transaction {
+        intent_hash: TransactionIntentHash,
+        end_epoch_exclusive: Epoch,
+    },
+
+    /// A Pre-Authorization sent to a dApp, which can no longer be
+    /// committed after `expiration_timestamp`.
+    PreAuthorization {
+        subintent_hash: SubintentHash,
+        expiration_timestamp: Timestamp,
+    },
+}
+
+/// The final outcome of polling a `PendingTransaction`.
+#[derive(Clone, PartialEq, InternalConversion, uniffi::Enum)]
+pub enum PendingTransactionOutcome {
+    Transaction {
+        intent_hash: TransactionIntentHash,
+        status: TransactionStatus,
+    },
+
+    PreAuthorization {
+        subintent_hash: SubintentHash,
+        status: PreAuthorizationStatus,
+    },
+}
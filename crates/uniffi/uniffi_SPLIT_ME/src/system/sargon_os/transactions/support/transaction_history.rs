@@ -0,0 +1,39 @@
+use crate::prelude::*;
+use sargon::TransactionHistoryFilters as InternalTransactionHistoryFilters;
+use sargon::TransactionHistoryItem as InternalTransactionHistoryItem;
+use sargon::TransactionHistoryPage as InternalTransactionHistoryPage;
+
+/// Filters applied when fetching the transaction history of an account.
+#[derive(Clone, PartialEq, Eq, InternalConversion, uniffi::Record)]
+pub struct TransactionHistoryFilters {
+    pub from_date: Option<Timestamp>,
+    pub to_date: Option<Timestamp>,
+    pub resource: Option<ResourceAddress>,
+    pub kind: Option<DetailedManifestClassKind>,
+}
+
+/// A committed transaction in the transaction history of an account.
+#[derive(Clone, PartialEq, Eq, InternalConversion, uniffi::Record)]
+pub struct TransactionHistoryItem {
+    pub intent_hash: TransactionIntentHash,
+    pub state_version: u64,
+    pub confirmed_at: Option<Timestamp>,
+    pub is_success: bool,
+    pub fee_paid: Decimal192,
+    pub message: Message,
+    pub manifest_class: Option<DetailedManifestClass>,
+    pub withdrawals: Vec<ResourceIndicator>,
+    pub deposits: Vec<ResourceIndicator>,
+}
+
+/// A page of the transaction history of an account, use `next_cursor` to
+/// fetch the next page, if any.
+#[derive(Clone, PartialEq, Eq, InternalConversion, uniffi::Record)]
+pub struct TransactionHistoryPage {
+    pub items: Vec<TransactionHistoryItem>,
+    pub next_cursor: Option<String>,
+}
+
+decl_conversion_tests_for!(TransactionHistoryFilters);
+decl_conversion_tests_for!(TransactionHistoryItem);
+decl_conversion_tests_for!(TransactionHistoryPage);
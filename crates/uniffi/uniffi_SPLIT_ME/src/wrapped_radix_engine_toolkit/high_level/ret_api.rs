@@ -10,6 +10,7 @@ use sargon::{
     TransactionManifestFaucet as _,
     TransactionManifestMetadataSetting as _,
     TransactionManifestStakeClaim as _,
+    TransactionManifestStaking as _,
     TransactionManifestThirdPartyDepositUpdating as _,
     TransactionManifestTokenCreating as _,
 };
@@ -127,6 +128,26 @@ pub fn manifest_stakes_claim(
     .into()
 }
 
+#[uniffi::export]
+pub fn manifest_stakes_to_validators(
+    account_stakes: Vec<AccountStakes>,
+) -> TransactionManifest {
+    InternalTransactionManifest::stakes_to_validators(
+        account_stakes.into_internal(),
+    )
+    .into()
+}
+
+#[uniffi::export]
+pub fn manifest_unstakes_from_validators(
+    account_unstakes: Vec<AccountUnstakes>,
+) -> TransactionManifest {
+    InternalTransactionManifest::unstakes_from_validators(
+        account_unstakes.into_internal(),
+    )
+    .into()
+}
+
 #[uniffi::export]
 pub fn manifest_third_party_deposit_update(
     account_address: &AccountAddress,
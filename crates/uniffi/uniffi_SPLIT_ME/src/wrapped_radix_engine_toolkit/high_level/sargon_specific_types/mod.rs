@@ -6,17 +6,23 @@ mod address_of_account_or_persona;
 mod assets_transfers;
 mod build_information;
 mod manifest_encountered_component_address;
+mod payment_request;
 mod stake_claim;
+mod stake_to_validator;
 mod transaction;
 mod transaction_guarantee;
+mod unstake_from_validator;
 
 pub use address_of_account_or_persona::*;
 pub use assets_transfers::*;
 pub use build_information::*;
 
 pub use stake_claim::*;
+pub use stake_to_validator::*;
 pub use transaction::*;
 pub use transaction_guarantee::*;
+pub use unstake_from_validator::*;
 
 pub use account_locker::*;
 pub use manifest_encountered_component_address::*;
+pub use payment_request::*;
@@ -0,0 +1,91 @@
+use crate::prelude::*;
+use sargon::PaymentRequest as InternalPaymentRequest;
+use sargon::PaymentRequestAsset as InternalPaymentRequestAsset;
+use sargon::PaymentRequestRecipient as InternalPaymentRequestRecipient;
+
+/// The recipient of a `PaymentRequest`, either an account address or an RNS
+/// domain which must be resolved to its configured receiver account before
+/// the payment can be made.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Enum)]
+pub enum PaymentRequestRecipient {
+    Account { value: AccountAddress },
+    RnsDomain { value: RnsDomain },
+}
+
+/// The asset requested by a `PaymentRequest`, either an amount of a fungible
+/// resource or a set of non fungibles of a non fungible resource.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Enum)]
+pub enum PaymentRequestAsset {
+    Fungible {
+        resource_address: ResourceAddress,
+        amount: Decimal192,
+    },
+    NonFungible {
+        resource_address: ResourceAddress,
+        local_ids: Vec<NonFungibleLocalId>,
+    },
+}
+
+/// A request to pay an asset to a recipient, typically shared by a merchant
+/// as a "scan to pay" QR code, encoded as a `radix:` URI.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct PaymentRequest {
+    pub recipient: PaymentRequestRecipient,
+    pub asset: PaymentRequestAsset,
+    pub message: Option<String>,
+    pub expiry: Option<Instant>,
+}
+
+#[uniffi::export]
+pub fn new_payment_request_sample() -> PaymentRequest {
+    InternalPaymentRequest::sample().into()
+}
+
+#[uniffi::export]
+pub fn new_payment_request_sample_other() -> PaymentRequest {
+    InternalPaymentRequest::sample_other().into()
+}
+
+/// Parses a `radix:` payment request URI, validating that all addresses in it
+/// are on `network_id`.
+#[uniffi::export]
+pub fn new_payment_request_from_uri(
+    uri: String,
+    network_id: NetworkID,
+) -> Result<PaymentRequest> {
+    InternalPaymentRequest::parse(uri, network_id.into_internal()).into_result()
+}
+
+#[uniffi::export]
+pub fn payment_request_to_uri(request: &PaymentRequest) -> Result<String> {
+    request.into_internal().to_uri().into_result()
+}
+
+#[uniffi::export]
+pub fn payment_request_is_expired(
+    request: &PaymentRequest,
+    now: Instant,
+) -> bool {
+    request.into_internal().is_expired(now.into_internal())
+}
+
+/// Converts the request into transfers, to be passed to
+/// `manifest_per_recipient_transfers`, failing if the request has expired at
+/// `now`. If the recipient is an RNS domain, `resolved_rns_receiver` must be
+/// the receiver the domain was resolved to.
+#[uniffi::export]
+pub fn payment_request_per_recipient_transfers(
+    request: &PaymentRequest,
+    address_of_sender: AccountAddress,
+    resolved_rns_receiver: Option<RnsDomainConfiguredReceiver>,
+    now: Instant,
+) -> Result<PerRecipientAssetTransfers> {
+    request
+        .into_internal()
+        .per_recipient_transfers(
+            address_of_sender.into_internal(),
+            resolved_rns_receiver.map(|r| r.into_internal()),
+            now.into_internal(),
+        )
+        .into_result()
+}
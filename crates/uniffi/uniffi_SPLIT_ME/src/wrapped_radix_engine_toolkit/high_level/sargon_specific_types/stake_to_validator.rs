@@ -0,0 +1,34 @@
+use crate::prelude::*;
+use sargon::AccountStakes as InternalAccountStakes;
+use sargon::StakeToValidator as InternalStakeToValidator;
+
+/// An amount of XRD to stake to a validator, the received Liquid Stake Units
+/// (LSU) are deposited back into the staking account.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct StakeToValidator {
+    pub validator_address: ValidatorAddress,
+    /// The address of the Liquid Stake Unit resource of the validator.
+    pub liquid_stake_unit_address: ResourceAddress,
+    /// The amount of XRD to stake.
+    pub xrd_amount: Decimal192,
+}
+
+/// The XRD `account_address` stakes to one or many validators.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct AccountStakes {
+    pub account_address: AccountAddress,
+    pub stakes: Vec<StakeToValidator>,
+}
+
+#[uniffi::export]
+pub fn new_account_stakes_sample() -> AccountStakes {
+    InternalAccountStakes::sample().into()
+}
+
+#[uniffi::export]
+pub fn new_account_stakes_sample_other() -> AccountStakes {
+    InternalAccountStakes::sample_other().into()
+}
+
+decl_conversion_tests_for!(StakeToValidator);
+decl_conversion_tests_for!(AccountStakes);
@@ -0,0 +1,37 @@
+use crate::prelude::*;
+use sargon::AccountUnstakes as InternalAccountUnstakes;
+use sargon::UnstakeFromValidator as InternalUnstakeFromValidator;
+
+/// An amount of Liquid Stake Units (LSU) to unstake from a validator, the
+/// received claim NFT is deposited back into the unstaking account.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct UnstakeFromValidator {
+    pub validator_address: ValidatorAddress,
+    /// The address of the Liquid Stake Unit resource of the validator.
+    pub liquid_stake_unit_address: ResourceAddress,
+    /// The address of the claim NFT resource of the validator.
+    pub claim_nft_address: NonFungibleResourceAddress,
+    /// The amount of LSU to unstake.
+    pub liquid_stake_unit_amount: Decimal192,
+}
+
+/// The Liquid Stake Units `account_address` unstakes from one or many
+/// validators.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct AccountUnstakes {
+    pub account_address: AccountAddress,
+    pub unstakes: Vec<UnstakeFromValidator>,
+}
+
+#[uniffi::export]
+pub fn new_account_unstakes_sample() -> AccountUnstakes {
+    InternalAccountUnstakes::sample().into()
+}
+
+#[uniffi::export]
+pub fn new_account_unstakes_sample_other() -> AccountUnstakes {
+    InternalAccountUnstakes::sample_other().into()
+}
+
+decl_conversion_tests_for!(UnstakeFromValidator);
+decl_conversion_tests_for!(AccountUnstakes);
@@ -6,6 +6,7 @@ mod newly_created_resource;
 mod reserved_instruction;
 mod resource_indicator;
 mod resource_specifier;
+mod transaction_fee;
 
 pub use execution_summary::*;
 pub use fee_locks::*;
@@ -15,3 +16,4 @@ pub use newly_created_resource::*;
 pub use reserved_instruction::*;
 pub use resource_indicator::*;
 pub use resource_specifier::*;
+pub use transaction_fee::*;
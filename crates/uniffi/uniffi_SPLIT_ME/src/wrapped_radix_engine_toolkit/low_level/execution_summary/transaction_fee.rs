@@ -0,0 +1,156 @@
+use crate::prelude::*;
+use sargon::AdvancedTransactionFeeCustomization as InternalAdvancedTransactionFeeCustomization;
+use sargon::ExecutionSummary as InternalExecutionSummary;
+use sargon::NormalTransactionFeeCustomization as InternalNormalTransactionFeeCustomization;
+use sargon::TransactionFee as InternalTransactionFee;
+use sargon::TransactionFeeCosts as InternalTransactionFeeCosts;
+use sargon::TransactionFeeMode as InternalTransactionFeeMode;
+
+/// The costs of a transaction, the network costs of the preview plus the
+/// costs of what the preview does not include.
+#[derive(Clone, PartialEq, Eq, InternalConversion, uniffi::Record)]
+pub struct TransactionFeeCosts {
+    pub execution_cost: Decimal192,
+    pub finalization_cost: Decimal192,
+    pub storage_expansion_cost: Decimal192,
+    pub royalty_cost: Decimal192,
+    pub guarantees_cost: Decimal192,
+    pub lock_fee_cost: Decimal192,
+    pub signatures_cost: Decimal192,
+    pub notarizing_cost: Decimal192,
+}
+
+/// The padded network fee and the royalties, each reduced by what dApps
+/// pay for.
+#[derive(Clone, PartialEq, Eq, InternalConversion, uniffi::Record)]
+pub struct NormalTransactionFeeCustomization {
+    pub network_fee: Decimal192,
+    pub royalty_fee: Decimal192,
+}
+
+/// The costs, the padding, the tip and what dApps pay for, shown separately.
+#[derive(Clone, PartialEq, Eq, InternalConversion, uniffi::Record)]
+pub struct AdvancedTransactionFeeCustomization {
+    pub costs: TransactionFeeCosts,
+    pub padding_fee: Decimal192,
+    pub tip_percentage: u16,
+    pub paid_by_dapps: Decimal192,
+}
+
+#[derive(Clone, PartialEq, Eq, InternalConversion, uniffi::Enum)]
+pub enum TransactionFeeMode {
+    Normal(NormalTransactionFeeCustomization),
+    Advanced(AdvancedTransactionFeeCustomization),
+}
+
+/// The fee of a transaction, built from the `ExecutionSummary` of its
+/// preview. Lock its `total_fee` using `modify_add_lock_fee`.
+#[derive(Clone, PartialEq, Eq, InternalConversion, uniffi::Record)]
+pub struct TransactionFee {
+    pub costs: TransactionFeeCosts,
+    pub fee_locks: FeeLocks,
+    pub mode: TransactionFeeMode,
+}
+
+#[uniffi::export]
+pub fn new_transaction_fee_sample() -> TransactionFee {
+    InternalTransactionFee::sample().into()
+}
+
+#[uniffi::export]
+pub fn new_transaction_fee_sample_other() -> TransactionFee {
+    InternalTransactionFee::sample_other().into()
+}
+
+#[uniffi::export]
+pub fn new_transaction_fee_from_execution_summary(
+    execution_summary: ExecutionSummary,
+    signatures_count: u64,
+    notary_is_signatory: bool,
+    include_lock_fee_instruction: bool,
+) -> TransactionFee {
+    InternalTransactionFee::new_from_execution_summary(
+        &execution_summary.into_internal(),
+        signatures_count,
+        notary_is_signatory,
+        include_lock_fee_instruction,
+    )
+    .into()
+}
+
+#[uniffi::export]
+pub fn transaction_fee_toggled_to_normal(
+    transaction_fee: &TransactionFee,
+) -> TransactionFee {
+    let mut internal = transaction_fee.into_internal();
+    internal.toggle_to_normal();
+    internal.into()
+}
+
+#[uniffi::export]
+pub fn transaction_fee_toggled_to_advanced(
+    transaction_fee: &TransactionFee,
+    tip_percentage: u16,
+) -> TransactionFee {
+    let mut internal = transaction_fee.into_internal();
+    internal.toggle_to_advanced(tip_percentage);
+    internal.into()
+}
+
+#[uniffi::export]
+pub fn transaction_fee_total_fee(
+    transaction_fee: &TransactionFee,
+) -> Decimal192 {
+    transaction_fee.into_internal().total_fee().into()
+}
+
+#[uniffi::export]
+pub fn advanced_transaction_fee_customization_tip_amount(
+    customization: &AdvancedTransactionFeeCustomization,
+) -> Decimal192 {
+    customization.into_internal().tip_amount().into()
+}
+
+decl_conversion_tests_for!(TransactionFee);
+
+#[cfg(test)]
+mod uniffi_tests {
+    use super::*;
+
+    #[test]
+    fn toggle_modes() {
+        let sut = new_transaction_fee_sample();
+        let advanced = transaction_fee_toggled_to_advanced(&sut, 10);
+
+        let mut expected = InternalTransactionFee::sample();
+        expected.toggle_to_advanced(10);
+        assert_eq!(
+            transaction_fee_total_fee(&advanced),
+            expected.total_fee().into()
+        );
+        assert_eq!(
+            advanced_transaction_fee_customization_tip_amount(
+                &expected.mode.as_advanced().unwrap().clone().into()
+            ),
+            expected.mode.as_advanced().unwrap().tip_amount().into()
+        );
+        assert_eq!(transaction_fee_toggled_to_normal(&advanced), sut);
+    }
+
+    #[test]
+    fn from_execution_summary() {
+        let summary = InternalExecutionSummary::sample();
+        assert_eq!(
+            new_transaction_fee_from_execution_summary(
+                summary.clone().into(),
+                2,
+                false,
+                true
+            ),
+            InternalTransactionFee::new_from_execution_summary(
+                &summary, 2, false, true
+            )
+            .into()
+        );
+    }
+}
@@ -114,6 +114,16 @@ pub enum DetailedManifestClass {
     AccessControllerConfirmTimedRecovery {
         ac_addresses: Vec<AccessControllerAddress>,
     },
+    /// A manifest that locks the Primary role of the access controllers,
+    /// an emergency freeze signed with the Recovery role.
+    AccessControllerLockPrimaryRole {
+        ac_addresses: Vec<AccessControllerAddress>,
+    },
+    /// A manifest that unlocks the Primary role of the access controllers,
+    /// signed with the Recovery role.
+    AccessControllerUnlockPrimaryRole {
+        ac_addresses: Vec<AccessControllerAddress>,
+    },
 }
 
 delegate_display_debug_into!(
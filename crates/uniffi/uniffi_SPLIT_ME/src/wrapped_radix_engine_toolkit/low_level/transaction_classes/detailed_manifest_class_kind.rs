@@ -17,6 +17,8 @@ pub enum DetailedManifestClassKind {
     AccessControllerRecovery,
     AccessControllerConfirmTimedRecovery,
     AccessControllerStopTimedRecovery,
+    AccessControllerLockPrimaryRole,
+    AccessControllerUnlockPrimaryRole,
 }
 
 delegate_display_debug_into!(
@@ -0,0 +1,10 @@
+use crate::prelude::*;
+use sargon::DecryptorsByCurve as InternalDecryptorsByCurve;
+
+/// All decryptors of an `EncryptedMessage` whose public keys are on the same
+/// curve as `dh_ephemeral_public_key`.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct DecryptorsByCurve {
+    pub dh_ephemeral_public_key: PublicKey,
+    pub decryptors: Vec<MessageDecryptor>,
+}
@@ -0,0 +1,30 @@
+use crate::prelude::*;
+use sargon::EncryptedMessage as InternalEncryptedMessage;
+
+/// A transaction message which only the recipients, i.e. the owners of the
+/// public keys it was encrypted for, can read.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct EncryptedMessage {
+    pub encrypted: BagOfBytes,
+    pub decryptors_by_curve: Vec<DecryptorsByCurve>,
+}
+
+#[uniffi::export]
+pub fn new_encrypted_message_sample() -> EncryptedMessage {
+    InternalEncryptedMessage::sample().into()
+}
+
+#[uniffi::export]
+pub fn new_encrypted_message_sample_other() -> EncryptedMessage {
+    InternalEncryptedMessage::sample_other().into()
+}
+
+#[uniffi::export]
+pub fn encrypted_message_can_be_decrypted_by(
+    message: &EncryptedMessage,
+    public_key: PublicKey,
+) -> bool {
+    message
+        .into_internal()
+        .can_be_decrypted_by(&public_key.into_internal())
+}
@@ -0,0 +1,9 @@
+use crate::prelude::*;
+use sargon::MessageDecryptor as InternalMessageDecryptor;
+
+/// The message key of an `EncryptedMessage` wrapped for a single recipient.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct MessageDecryptor {
+    pub public_key_fingerprint: PublicKeyFingerprint,
+    pub aes_wrapped_key: Exactly24Bytes,
+}
@@ -0,0 +1,9 @@
+mod decryptors_by_curve;
+mod encrypted_message;
+mod message_decryptor;
+mod public_key_fingerprint;
+
+pub use decryptors_by_curve::*;
+pub use encrypted_message::*;
+pub use message_decryptor::*;
+pub use public_key_fingerprint::*;
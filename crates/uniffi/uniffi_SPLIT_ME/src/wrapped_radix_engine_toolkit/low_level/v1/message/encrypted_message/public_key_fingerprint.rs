@@ -0,0 +1,16 @@
+use crate::prelude::*;
+use sargon::PublicKeyFingerprint as InternalPublicKeyFingerprint;
+
+/// The last 8 bytes of the Blake2b-256 hash of the public key bytes, used to
+/// identify the decryptor of an `EncryptedMessage`.
+#[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Record)]
+pub struct PublicKeyFingerprint {
+    pub bytes: Exactly8Bytes,
+}
+
+#[uniffi::export]
+pub fn new_public_key_fingerprint_from_public_key(
+    public_key: PublicKey,
+) -> PublicKeyFingerprint {
+    InternalPublicKeyFingerprint::from(public_key.into_internal()).into()
+}
@@ -4,6 +4,7 @@ use sargon::Message as InternalMessage;
 #[derive(Clone, PartialEq, Eq, Hash, InternalConversion, uniffi::Enum)]
 pub enum Message {
     PlainText { plaintext: PlaintextMessage },
+    Encrypted { encrypted: EncryptedMessage },
     None,
 }
 
@@ -22,6 +23,20 @@ pub fn new_message_plaintext_string(string: String) -> Message {
     InternalMessage::plain_text(string).into()
 }
 
+/// Encrypts `string` so that it can only be read by the owners of the
+/// `recipients` public keys, e.g. the accounts receiving a transfer.
+#[uniffi::export]
+pub fn new_message_encrypted_string(
+    string: String,
+    recipients: Vec<PublicKey>,
+) -> Result<Message> {
+    InternalMessage::encrypted(
+        string,
+        recipients.into_iter().map(|k| k.into_internal()),
+    )
+    .into_result()
+}
+
 #[uniffi::export]
 pub fn message_as_plaintext(message: &Message) -> Option<String> {
     message.into_internal().as_plaintext()
@@ -86,6 +86,12 @@ internal class AndroidStorageDriver(
             keystoreAccessRequest = KeystoreAccessRequest.ForRadixConnect,
             storage = encryptedPreferencesDatastore
         )
+
+        is SecureStorageKey.RadixConnectMobileSessionIDs -> ByteArrayKeyMapping(
+            key = this,
+            keystoreAccessRequest = KeystoreAccessRequest.ForRadixConnect,
+            storage = encryptedPreferencesDatastore
+        )
     }.let { mapping ->
         Result.success(mapping)
     }
@@ -14,7 +14,10 @@ import com.radixdlt.sargon.extensions.toBagOfBytes
 import com.radixdlt.sargon.extensions.toByteArray
 import com.radixdlt.sargon.os.storage.KeystoreAccessRequest
 import com.radixdlt.sargon.os.storage.read
+import com.radixdlt.sargon.os.storage.remove
 import com.radixdlt.sargon.os.storage.write
+import kotlinx.coroutines.flow.first
+import java.util.UUID
 
 internal class RadixConnectSessionStorage internal constructor(
     private val dataStore: DataStore<Preferences>
@@ -41,6 +44,15 @@ internal class RadixConnectSessionStorage internal constructor(
         keystoreAccessRequest = KeystoreAccessRequest.ForRadixConnect
     ).getOrNull()?.toBagOfBytes()
 
+    override suspend fun deleteSession(sessionId: SessionId) {
+        dataStore.remove(key = sessionId.key())
+    }
+
+    override suspend fun loadSessionIds(): List<SessionId> = dataStore.data.first()
+        .asMap()
+        .keys
+        .mapNotNull { key -> runCatching { UUID.fromString(key.name) }.getOrNull() }
+
     private fun SessionId.key() = byteArrayPreferencesKey(name = toString())
 
     companion object {
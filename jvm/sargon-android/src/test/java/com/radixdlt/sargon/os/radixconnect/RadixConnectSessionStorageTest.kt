@@ -56,6 +56,11 @@ class RadixConnectSessionStorageTest {
         assertNull(sut.loadSession(sessionId))
         sut.saveSession(sessionId, sessionBytes)
         assertEquals(sessionBytes, sut.loadSession(sessionId))
+        assertEquals(listOf(sessionId), sut.loadSessionIds())
+
+        sut.deleteSession(sessionId)
+        assertNull(sut.loadSession(sessionId))
+        assertEquals(emptyList<SessionId>(), sut.loadSessionIds())
     }
 
     @Test